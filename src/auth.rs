@@ -0,0 +1,210 @@
+use std::sync::mpsc::Receiver;
+
+use crate::client::Client;
+use crate::errors::*;
+use crate::types::*;
+
+/// How to log in when tdlib asks for a phone number
+#[derive(Debug, Clone)]
+pub enum Login {
+  /// Log in as a user, tdlib sends an authentication code to this phone number
+  PhoneNumber(String),
+  /// Log in as a bot
+  BotToken(String),
+  /// Log in by confirming a QR code link on another logged in device
+  QrCode,
+}
+
+/// User supplied answers for the authorization flow
+pub trait AuthHandler {
+  /// Parameters for `setTdlibParameters`
+  fn tdlib_parameters(&mut self) -> TdlibParameters;
+  /// Key of the local database, empty for an unencrypted database
+  fn encryption_key(&mut self, _state: &AuthorizationStateWaitEncryptionKey) -> String { String::new() }
+  /// Phone number, bot token or QR code login
+  fn login(&mut self) -> Login;
+  /// Settings sent with the phone number
+  fn phone_number_settings(&mut self) -> PhoneNumberAuthenticationSettings {
+    PhoneNumberAuthenticationSettings::builder().build()
+  }
+  /// Authentication code sent to the user
+  fn code(&mut self, state: &AuthorizationStateWaitCode) -> String;
+  /// Two-step verification password
+  fn password(&mut self, state: &AuthorizationStateWaitPassword) -> String;
+  /// First name and last name of a new user
+  fn registration(&mut self, state: &AuthorizationStateWaitRegistration) -> (String, String);
+  /// The QR code link to confirm on another device
+  fn other_device_confirmation(&mut self, _state: &AuthorizationStateWaitOtherDeviceConfirmation) {}
+  /// A request of the flow failed, return true to answer the current state again. By default wrong input (error code 400) is asked again
+  fn on_error(&mut self, err: &RTDError) -> bool {
+    match err {
+      RTDError::Td(err) => err.code() == 400,
+      _ => false,
+    }
+  }
+}
+
+/// Function to send for an authorization state, all of them return `Ok`
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum AuthRequest {
+  SetTdlibParameters(SetTdlibParameters),
+  CheckDatabaseEncryptionKey(CheckDatabaseEncryptionKey),
+  SetAuthenticationPhoneNumber(SetAuthenticationPhoneNumber),
+  CheckAuthenticationBotToken(CheckAuthenticationBotToken),
+  RequestQrCodeAuthentication(RequestQrCodeAuthentication),
+  CheckAuthenticationCode(CheckAuthenticationCode),
+  CheckAuthenticationPassword(CheckAuthenticationPassword),
+  RegisterUser(RegisterUser),
+}
+
+impl RObject for AuthRequest {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { self.function().td_name() }
+  fn extra(&self) -> Option<&serde_json::Value> { self.function().extra() }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+impl RFunction for AuthRequest {
+  type Response = Ok;
+}
+
+impl AuthRequest {
+  fn function(&self) -> &dyn RObject {
+    match self {
+      AuthRequest::SetTdlibParameters(t) => t,
+      AuthRequest::CheckDatabaseEncryptionKey(t) => t,
+      AuthRequest::SetAuthenticationPhoneNumber(t) => t,
+      AuthRequest::CheckAuthenticationBotToken(t) => t,
+      AuthRequest::RequestQrCodeAuthentication(t) => t,
+      AuthRequest::CheckAuthenticationCode(t) => t,
+      AuthRequest::CheckAuthenticationPassword(t) => t,
+      AuthRequest::RegisterUser(t) => t,
+    }
+  }
+}
+
+/// What the authorization flow needs after an authorization state
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthStep {
+  /// Send this request to tdlib
+  Send(AuthRequest),
+  /// Nothing to do, wait for the next authorization state
+  Wait,
+  /// The user is authorized
+  Ready,
+  /// Tdlib is closed, the flow can't continue
+  Closed,
+}
+
+/// Drive the login flow from `AuthorizationState` to `Ready` with answers of an `AuthHandler`
+pub struct Authenticator<H: AuthHandler> {
+  handler: H,
+  state: Option<AuthorizationState>,
+}
+
+impl<H: AuthHandler> Authenticator<H> {
+  pub fn new(handler: H) -> Self {
+    Self { handler, state: None }
+  }
+
+  pub fn handler(&self) -> &H { &self.handler }
+
+  pub fn handler_mut(&mut self) -> &mut H { &mut self.handler }
+
+  /// The last authorization state seen
+  pub fn state(&self) -> Option<&AuthorizationState> { self.state.as_ref() }
+
+  /// Step for an update, `None` if it isn't `UpdateAuthorizationState`
+  pub fn update(&mut self, update: &Update) -> Option<AuthStep> {
+    update.as_authorization_state().map(|u| self.handle(u.authorization_state()))
+  }
+
+  /// Step for a new authorization state
+  pub fn handle(&mut self, state: &AuthorizationState) -> AuthStep {
+    self.state = Some(state.clone());
+    self.step()
+  }
+
+  /// A request of the last step failed, step for the same state again if the handler wants to retry
+  pub fn retry(&mut self, err: RTDError) -> RTDResult<AuthStep> {
+    if !self.handler.on_error(&err) {
+      return Err(err.context("authorization"));
+    }
+    Ok(self.step())
+  }
+
+  fn step(&mut self) -> AuthStep {
+    let handler = &mut self.handler;
+    let state = match &self.state {
+      Some(state) => state,
+      None => return AuthStep::Wait,
+    };
+    let request = match state {
+      AuthorizationState::WaitTdlibParameters(_) => AuthRequest::SetTdlibParameters(
+        SetTdlibParameters::builder().parameters(handler.tdlib_parameters()).build()
+      ),
+      AuthorizationState::WaitEncryptionKey(t) => AuthRequest::CheckDatabaseEncryptionKey(
+        CheckDatabaseEncryptionKey::builder().encryption_key(handler.encryption_key(t)).build()
+      ),
+      AuthorizationState::WaitPhoneNumber(_) => match handler.login() {
+        Login::PhoneNumber(phone_number) => AuthRequest::SetAuthenticationPhoneNumber(
+          SetAuthenticationPhoneNumber::builder()
+            .phone_number(phone_number)
+            .settings(handler.phone_number_settings())
+            .build()
+        ),
+        Login::BotToken(token) => AuthRequest::CheckAuthenticationBotToken(
+          CheckAuthenticationBotToken::builder().token(token).build()
+        ),
+        Login::QrCode => AuthRequest::RequestQrCodeAuthentication(
+          RequestQrCodeAuthentication::builder().other_user_ids(vec![]).build()
+        ),
+      },
+      AuthorizationState::WaitOtherDeviceConfirmation(t) => {
+        handler.other_device_confirmation(t);
+        return AuthStep::Wait;
+      }
+      AuthorizationState::WaitCode(t) => AuthRequest::CheckAuthenticationCode(
+        CheckAuthenticationCode::builder().code(handler.code(t)).build()
+      ),
+      AuthorizationState::WaitPassword(t) => AuthRequest::CheckAuthenticationPassword(
+        CheckAuthenticationPassword::builder().password(handler.password(t)).build()
+      ),
+      AuthorizationState::WaitRegistration(t) => {
+        let (first_name, last_name) = handler.registration(t);
+        AuthRequest::RegisterUser(RegisterUser::builder().first_name(first_name).last_name(last_name).build())
+      }
+      AuthorizationState::Ready(_) => return AuthStep::Ready,
+      AuthorizationState::Closed(_) => return AuthStep::Closed,
+      _ => return AuthStep::Wait,
+    };
+    AuthStep::Send(request)
+  }
+
+  /// Run the flow over a client until the user is authorized, blocking the current thread on `updates` and
+  /// the responses. Updates not about authorization are passed to `other`.
+  pub fn run<F: FnMut(Update)>(&mut self, client: &Client, updates: &Receiver<Update>, mut other: F) -> RTDResult<()> {
+    loop {
+      let update = updates.recv().map_err(|_| RTDError::custom("updates channel is closed"))?;
+      let mut step = match self.update(&update) {
+        Some(step) => step,
+        None => {
+          other(update);
+          continue;
+        }
+      };
+      loop {
+        match step {
+          AuthStep::Send(request) => match client.send_blocking(request) {
+            Ok(_) => break,
+            Err(err) => step = self.retry(err)?,
+          },
+          AuthStep::Wait => break,
+          AuthStep::Ready => return Ok(()),
+          AuthStep::Closed => return Err(RTDError::custom("tdlib is closed before authorization")),
+        }
+      }
+    }
+  }
+}
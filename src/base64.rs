@@ -0,0 +1,47 @@
+//! Standard base64 with padding, tdlib sends `bytes` fields in this encoding.
+
+use crate::errors::*;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn encode<T: AsRef<[u8]>>(bytes: T) -> String {
+  let bytes = bytes.as_ref();
+  let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+  for chunk in bytes.chunks(3) {
+    let n = chunk.iter().enumerate().fold(0u32, |n, (index, &byte)| n | ((byte as u32) << (16 - 8 * index)));
+    for index in 0..4 {
+      if index <= chunk.len() {
+        out.push(ALPHABET[((n >> (18 - 6 * index)) & 0x3f) as usize] as char);
+      } else {
+        out.push('=');
+      }
+    }
+  }
+  out
+}
+
+/// Decode standard or url safe base64, padding is optional
+pub fn decode<T: AsRef<[u8]>>(text: T) -> RTDResult<Vec<u8>> {
+  let text = text.as_ref();
+  let text = &text[..text.len() - text.iter().rev().take(2).take_while(|&&c| c == b'=').count()];
+  if text.len() % 4 == 1 {
+    return Err(RTDError::message(format!("base64 of length {} is truncated", text.len())));
+  }
+  let mut out = Vec::with_capacity(text.len() * 3 / 4);
+  for chunk in text.chunks(4) {
+    let mut n = 0u32;
+    for (index, &c) in chunk.iter().enumerate() {
+      let value = match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' | b'-' => 62,
+        b'/' | b'_' => 63,
+        _ => return Err(RTDError::message(format!("invalid base64 character {:?}", c as char))),
+      };
+      n |= (value as u32) << (18 - 6 * index);
+    }
+    out.extend_from_slice(&n.to_be_bytes()[1..chunk.len()]);
+  }
+  Ok(out)
+}
@@ -0,0 +1,450 @@
+//! Typed data of callback buttons. Values are written in a compact binary form after a schema version byte,
+//! struct fields by position and integers as varints, so they fit the 64 bytes Telegram allows.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+
+use crate::errors::*;
+use crate::keyboard::MAX_CALLBACK_DATA_LEN;
+use crate::types::*;
+
+/// Encoder and decoder of callback data with a schema version, change the version when the encoded types change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallbackCodec {
+  version: u8,
+}
+
+impl CallbackCodec {
+  pub fn new(version: u8) -> Self { Self { version } }
+
+  pub fn version(&self) -> u8 { self.version }
+
+  /// Callback data of `value`, fails if it's longer than `MAX_CALLBACK_DATA_LEN`
+  pub fn encode<T: Serialize + ?Sized>(&self, value: &T) -> RTDResult<Vec<u8>> {
+    let mut encoder = Encoder { out: vec![self.version] };
+    value.serialize(&mut encoder).map_err(CodecError::into_rtd)?;
+    if encoder.out.len() > MAX_CALLBACK_DATA_LEN {
+      return Err(RTDError::message(format!(
+        "callback data has {} bytes, at most {} are allowed", encoder.out.len(), MAX_CALLBACK_DATA_LEN
+      )));
+    }
+    Ok(encoder.out)
+  }
+
+  pub fn decode<T: DeserializeOwned>(&self, data: &[u8]) -> RTDResult<T> {
+    match data.first() {
+      None => return Err(RTDError::custom("callback data is empty")),
+      Some(&version) if version != self.version => return Err(RTDError::message(format!(
+        "callback data has schema version {}, expected {}", version, self.version
+      ))),
+      _ => {}
+    }
+    let mut decoder = Decoder { input: &data[1..] };
+    let value = T::deserialize(&mut decoder).map_err(CodecError::into_rtd)?;
+    if !decoder.input.is_empty() {
+      return Err(RTDError::message(format!("{} unexpected bytes after callback data", decoder.input.len())));
+    }
+    Ok(value)
+  }
+
+  /// Decode the data of a callback query, game queries have no data
+  pub fn decode_payload<T: DeserializeOwned>(&self, payload: &CallbackQueryPayload) -> RTDResult<T> {
+    let data = payload.as_data().ok_or_else(|| RTDError::message(format!("callback query payload is {}", payload.td_name())))?;
+    self.decode(data.data())
+  }
+
+  pub fn decode_query<T: DeserializeOwned>(&self, query: &UpdateNewCallbackQuery) -> RTDResult<T> {
+    self.decode_payload(query.payload()).context("decode callback query")
+  }
+}
+
+#[derive(Debug)]
+struct CodecError(String);
+
+impl CodecError {
+  fn into_rtd(self) -> RTDError { RTDError::message(self.0) }
+}
+
+impl fmt::Display for CodecError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.0) }
+}
+
+impl std::error::Error for CodecError {}
+
+impl ser::Error for CodecError {
+  fn custom<T: fmt::Display>(msg: T) -> Self { CodecError(msg.to_string()) }
+}
+
+impl de::Error for CodecError {
+  fn custom<T: fmt::Display>(msg: T) -> Self { CodecError(msg.to_string()) }
+}
+
+type CodecResult<T> = Result<T, CodecError>;
+
+struct Encoder {
+  out: Vec<u8>,
+}
+
+impl Encoder {
+  fn varint(&mut self, mut n: u64) {
+    while n >= 0x80 {
+      self.out.push(n as u8 | 0x80);
+      n >>= 7;
+    }
+    self.out.push(n as u8);
+  }
+
+  fn signed(&mut self, n: i64) { self.varint(((n << 1) ^ (n >> 63)) as u64) }
+
+  fn len(&mut self, len: Option<usize>) -> CodecResult<()> {
+    let len = len.ok_or_else(|| CodecError("callback data sequences must have a known length".to_string()))?;
+    self.varint(len as u64);
+    Ok(())
+  }
+}
+
+impl ser::Serializer for &mut Encoder {
+  type Ok = ();
+  type Error = CodecError;
+  type SerializeSeq = Self;
+  type SerializeTuple = Self;
+  type SerializeTupleStruct = Self;
+  type SerializeTupleVariant = Self;
+  type SerializeMap = Self;
+  type SerializeStruct = Self;
+  type SerializeStructVariant = Self;
+
+  fn serialize_bool(self, v: bool) -> CodecResult<()> { self.out.push(v as u8); Ok(()) }
+  fn serialize_i8(self, v: i8) -> CodecResult<()> { self.signed(v as i64); Ok(()) }
+  fn serialize_i16(self, v: i16) -> CodecResult<()> { self.signed(v as i64); Ok(()) }
+  fn serialize_i32(self, v: i32) -> CodecResult<()> { self.signed(v as i64); Ok(()) }
+  fn serialize_i64(self, v: i64) -> CodecResult<()> { self.signed(v); Ok(()) }
+  fn serialize_u8(self, v: u8) -> CodecResult<()> { self.out.push(v); Ok(()) }
+  fn serialize_u16(self, v: u16) -> CodecResult<()> { self.varint(v as u64); Ok(()) }
+  fn serialize_u32(self, v: u32) -> CodecResult<()> { self.varint(v as u64); Ok(()) }
+  fn serialize_u64(self, v: u64) -> CodecResult<()> { self.varint(v); Ok(()) }
+  fn serialize_f32(self, v: f32) -> CodecResult<()> { self.out.extend_from_slice(&v.to_le_bytes()); Ok(()) }
+  fn serialize_f64(self, v: f64) -> CodecResult<()> { self.out.extend_from_slice(&v.to_le_bytes()); Ok(()) }
+  fn serialize_char(self, v: char) -> CodecResult<()> { self.varint(v as u64); Ok(()) }
+  fn serialize_str(self, v: &str) -> CodecResult<()> { self.serialize_bytes(v.as_bytes()) }
+
+  fn serialize_bytes(self, v: &[u8]) -> CodecResult<()> {
+    self.varint(v.len() as u64);
+    self.out.extend_from_slice(v);
+    Ok(())
+  }
+
+  fn serialize_none(self) -> CodecResult<()> { self.out.push(0); Ok(()) }
+
+  fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> CodecResult<()> {
+    self.out.push(1);
+    value.serialize(self)
+  }
+
+  fn serialize_unit(self) -> CodecResult<()> { Ok(()) }
+  fn serialize_unit_struct(self, _name: &'static str) -> CodecResult<()> { Ok(()) }
+
+  fn serialize_unit_variant(self, _name: &'static str, index: u32, _variant: &'static str) -> CodecResult<()> {
+    self.varint(index as u64);
+    Ok(())
+  }
+
+  fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> CodecResult<()> {
+    value.serialize(self)
+  }
+
+  fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, index: u32, _variant: &'static str, value: &T) -> CodecResult<()> {
+    self.varint(index as u64);
+    value.serialize(self)
+  }
+
+  fn serialize_seq(self, len: Option<usize>) -> CodecResult<Self> { self.len(len)?; Ok(self) }
+  fn serialize_tuple(self, _len: usize) -> CodecResult<Self> { Ok(self) }
+  fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> CodecResult<Self> { Ok(self) }
+
+  fn serialize_tuple_variant(self, _name: &'static str, index: u32, _variant: &'static str, _len: usize) -> CodecResult<Self> {
+    self.varint(index as u64);
+    Ok(self)
+  }
+
+  fn serialize_map(self, len: Option<usize>) -> CodecResult<Self> { self.len(len)?; Ok(self) }
+  fn serialize_struct(self, _name: &'static str, _len: usize) -> CodecResult<Self> { Ok(self) }
+
+  fn serialize_struct_variant(self, _name: &'static str, index: u32, _variant: &'static str, _len: usize) -> CodecResult<Self> {
+    self.varint(index as u64);
+    Ok(self)
+  }
+
+  fn is_human_readable(&self) -> bool { false }
+}
+
+macro_rules! compound {
+  ($trait_:ident, $method:ident $(, $key:ident)?) => {
+    impl ser::$trait_ for &mut Encoder {
+      type Ok = ();
+      type Error = CodecError;
+
+      fn $method<T: Serialize + ?Sized>(&mut self, $($key: &'static str,)? value: &T) -> CodecResult<()> {
+        $(let _ = $key;)?
+        value.serialize(&mut **self)
+      }
+
+      fn end(self) -> CodecResult<()> { Ok(()) }
+    }
+  };
+}
+
+compound!(SerializeSeq, serialize_element);
+compound!(SerializeTuple, serialize_element);
+compound!(SerializeTupleStruct, serialize_field);
+compound!(SerializeTupleVariant, serialize_field);
+compound!(SerializeStruct, serialize_field, key);
+compound!(SerializeStructVariant, serialize_field, key);
+
+impl ser::SerializeMap for &mut Encoder {
+  type Ok = ();
+  type Error = CodecError;
+
+  fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> CodecResult<()> { key.serialize(&mut **self) }
+  fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> CodecResult<()> { value.serialize(&mut **self) }
+  fn end(self) -> CodecResult<()> { Ok(()) }
+}
+
+struct Decoder<'de> {
+  input: &'de [u8],
+}
+
+impl<'de> Decoder<'de> {
+  fn take(&mut self, len: usize) -> CodecResult<&'de [u8]> {
+    if self.input.len() < len {
+      return Err(CodecError("callback data is truncated".to_string()));
+    }
+    let (bytes, rest) = self.input.split_at(len);
+    self.input = rest;
+    Ok(bytes)
+  }
+
+  fn byte(&mut self) -> CodecResult<u8> { Ok(self.take(1)?[0]) }
+
+  fn varint(&mut self) -> CodecResult<u64> {
+    let mut n = 0u64;
+    for shift in (0..64).step_by(7) {
+      let byte = self.byte()?;
+      n |= ((byte & 0x7f) as u64) << shift;
+      if byte < 0x80 {
+        return Ok(n);
+      }
+    }
+    Err(CodecError("callback data has a too long varint".to_string()))
+  }
+
+  fn signed(&mut self) -> CodecResult<i64> {
+    let n = self.varint()?;
+    Ok((n >> 1) as i64 ^ -((n & 1) as i64))
+  }
+
+  fn len(&mut self) -> CodecResult<usize> {
+    let len = self.varint()? as usize;
+    if len > self.input.len() {
+      // every element takes at least a byte, except units that callback data has no use for
+      return Err(CodecError("callback data is truncated".to_string()));
+    }
+    Ok(len)
+  }
+
+  fn array<const N: usize>(&mut self) -> CodecResult<[u8; N]> {
+    let mut array = [0; N];
+    array.copy_from_slice(self.take(N)?);
+    Ok(array)
+  }
+}
+
+fn out_of_range<T: fmt::Display>(value: T, type_: &str) -> CodecError {
+  CodecError(format!("{} is out of range of {}", value, type_))
+}
+
+macro_rules! unsigned {
+  ($method:ident, $visit:ident, $type_:ty) => {
+    fn $method<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+      let n = self.varint()?;
+      visitor.$visit(<$type_>::try_from(n).map_err(|_| out_of_range(n, stringify!($type_)))?)
+    }
+  };
+}
+
+macro_rules! signed {
+  ($method:ident, $visit:ident, $type_:ty) => {
+    fn $method<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+      let n = self.signed()?;
+      visitor.$visit(<$type_>::try_from(n).map_err(|_| out_of_range(n, stringify!($type_)))?)
+    }
+  };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Decoder<'de> {
+  type Error = CodecError;
+
+  fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> CodecResult<V::Value> {
+    Err(CodecError("callback data isn't self describing, types must be known".to_string()))
+  }
+
+  fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    match self.byte()? {
+      0 => visitor.visit_bool(false),
+      1 => visitor.visit_bool(true),
+      byte => Err(CodecError(format!("invalid bool {}", byte))),
+    }
+  }
+
+  signed!(deserialize_i8, visit_i8, i8);
+  signed!(deserialize_i16, visit_i16, i16);
+  signed!(deserialize_i32, visit_i32, i32);
+  signed!(deserialize_i64, visit_i64, i64);
+  unsigned!(deserialize_u16, visit_u16, u16);
+  unsigned!(deserialize_u32, visit_u32, u32);
+  unsigned!(deserialize_u64, visit_u64, u64);
+
+  fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { visitor.visit_u8(self.byte()?) }
+  fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { visitor.visit_f32(f32::from_le_bytes(self.array()?)) }
+  fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { visitor.visit_f64(f64::from_le_bytes(self.array()?)) }
+
+  fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    let n = self.varint()?;
+    let c = u32::try_from(n).ok().and_then(std::char::from_u32).ok_or_else(|| CodecError(format!("invalid char {}", n)))?;
+    visitor.visit_char(c)
+  }
+
+  fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    let len = self.len()?;
+    let bytes = self.take(len)?;
+    visitor.visit_borrowed_str(std::str::from_utf8(bytes).map_err(|e| CodecError(e.to_string()))?)
+  }
+
+  fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { self.deserialize_str(visitor) }
+
+  fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    let len = self.len()?;
+    visitor.visit_borrowed_bytes(self.take(len)?)
+  }
+
+  fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { self.deserialize_bytes(visitor) }
+
+  fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    match self.byte()? {
+      0 => visitor.visit_none(),
+      1 => visitor.visit_some(self),
+      byte => Err(CodecError(format!("invalid option tag {}", byte))),
+    }
+  }
+
+  fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { visitor.visit_unit() }
+
+  fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_unit()
+  }
+
+  fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_newtype_struct(self)
+  }
+
+  fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    let len = self.len()?;
+    visitor.visit_seq(Elements { decoder: self, len })
+  }
+
+  fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_seq(Elements { decoder: self, len })
+  }
+
+  fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, len: usize, visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_seq(Elements { decoder: self, len })
+  }
+
+  fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    let len = self.len()?;
+    visitor.visit_map(Elements { decoder: self, len })
+  }
+
+  fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_seq(Elements { decoder: self, len: fields.len() })
+  }
+
+  fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_enum(self)
+  }
+
+  fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> {
+    let n = self.varint()?;
+    visitor.visit_u32(u32::try_from(n).map_err(|_| out_of_range(n, "u32"))?)
+  }
+
+  fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> CodecResult<V::Value> { self.deserialize_any(visitor) }
+
+  fn is_human_readable(&self) -> bool { false }
+}
+
+struct Elements<'a, 'de> {
+  decoder: &'a mut Decoder<'de>,
+  len: usize,
+}
+
+impl<'a, 'de> de::SeqAccess<'de> for Elements<'a, 'de> {
+  type Error = CodecError;
+
+  fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> CodecResult<Option<T::Value>> {
+    if self.len == 0 {
+      return Ok(None);
+    }
+    self.len -= 1;
+    seed.deserialize(&mut *self.decoder).map(Some)
+  }
+
+  fn size_hint(&self) -> Option<usize> { Some(self.len) }
+}
+
+impl<'a, 'de> de::MapAccess<'de> for Elements<'a, 'de> {
+  type Error = CodecError;
+
+  fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> CodecResult<Option<K::Value>> {
+    if self.len == 0 {
+      return Ok(None);
+    }
+    self.len -= 1;
+    seed.deserialize(&mut *self.decoder).map(Some)
+  }
+
+  fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> CodecResult<V::Value> { seed.deserialize(&mut *self.decoder) }
+
+  fn size_hint(&self) -> Option<usize> { Some(self.len) }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Decoder<'de> {
+  type Error = CodecError;
+  type Variant = Self;
+
+  fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> CodecResult<(V::Value, Self)> {
+    let n = self.varint()?;
+    let index = u32::try_from(n).map_err(|_| out_of_range(n, "u32"))?;
+    let value = seed.deserialize(IntoDeserializer::<CodecError>::into_deserializer(index))?;
+    Ok((value, self))
+  }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Decoder<'de> {
+  type Error = CodecError;
+
+  fn unit_variant(self) -> CodecResult<()> { Ok(()) }
+
+  fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> CodecResult<T::Value> { seed.deserialize(self) }
+
+  fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_seq(Elements { decoder: self, len })
+  }
+
+  fn struct_variant<V: Visitor<'de>>(self, fields: &'static [&'static str], visitor: V) -> CodecResult<V::Value> {
+    visitor.visit_seq(Elements { decoder: self, len: fields.len() })
+  }
+}
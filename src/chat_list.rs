@@ -0,0 +1,143 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::types::*;
+
+/// Order of the first chat of a list, `offset_order` to load the first page of `GetChats`
+pub const FIRST_PAGE_ORDER: i64 = i64::MAX;
+
+/// Hashable identity of a `ChatList`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChatListKey {
+  Main,
+  Archive,
+  Filter(i64),
+}
+
+impl ChatListKey {
+  /// Key of a chat list, `None` for a list this crate doesn't know
+  pub fn of(list: &ChatList) -> Option<Self> {
+    match list {
+      ChatList::Main(_) => Some(ChatListKey::Main),
+      ChatList::Archive(_) => Some(ChatListKey::Archive),
+      ChatList::Filter(t) => Some(ChatListKey::Filter(t.chat_filter_id())),
+      _ => None,
+    }
+  }
+
+  pub fn chat_list(&self) -> ChatList {
+    match self {
+      ChatListKey::Main => ChatList::main(ChatListMain::builder().build()),
+      ChatListKey::Archive => ChatList::archive(ChatListArchive::builder().build()),
+      ChatListKey::Filter(id) => ChatList::filter(ChatListFilter::builder().chat_filter_id(*id).build()),
+    }
+  }
+}
+
+// chats of a list are sorted by descending (order, chat_id)
+type Entry = Reverse<(i64, i64)>;
+
+/// Ordered chats of every chat list, built from the positions tdlib sends in updates.
+/// A chat with order 0 isn't in the list.
+#[derive(Debug, Clone, Default)]
+pub struct ChatListIndex {
+  lists: HashMap<ChatListKey, BTreeSet<Entry>>,
+  positions: HashMap<i64, HashMap<ChatListKey, ChatPosition>>,
+}
+
+impl ChatListIndex {
+  pub fn new() -> Self { Self::default() }
+
+  /// Move chats by `UpdateNewChat`, `UpdateChatPosition`, `UpdateChatLastMessage` and `UpdateChatDraftMessage`,
+  /// returns true if the update has chat positions
+  pub fn update(&mut self, update: &Update) -> bool {
+    match update {
+      Update::NewChat(u) => self.set_positions(u.chat().id(), u.chat().positions()),
+      Update::ChatPosition(u) => self.set_position(u.chat_id(), u.position()),
+      Update::ChatLastMessage(u) => self.set_positions(u.chat_id(), u.positions()),
+      Update::ChatDraftMessage(u) => self.set_positions(u.chat_id(), u.positions()),
+      _ => return false,
+    }
+    true
+  }
+
+  /// Set positions of a chat in their chat lists, lists not in `positions` are left as is
+  pub fn set_positions(&mut self, chat_id: i64, positions: &[ChatPosition]) {
+    positions.iter().for_each(|position| self.set_position(chat_id, position));
+  }
+
+  /// Set the position of a chat in a chat list, order 0 removes the chat from the list
+  pub fn set_position(&mut self, chat_id: i64, position: &ChatPosition) {
+    let key = match ChatListKey::of(position.list()) {
+      Some(key) => key,
+      None => return,
+    };
+    let chat_positions = self.positions.entry(chat_id).or_default();
+    if let Some(old) = chat_positions.remove(&key) {
+      if let Some(list) = self.lists.get_mut(&key) {
+        list.remove(&Reverse((old.order(), chat_id)));
+      }
+    }
+    if position.order() != 0 {
+      chat_positions.insert(key, position.clone());
+      self.lists.entry(key).or_default().insert(Reverse((position.order(), chat_id)));
+    }
+    if chat_positions.is_empty() {
+      self.positions.remove(&chat_id);
+    }
+  }
+
+  /// Chat ids of a list in display order
+  pub fn chat_ids<'a>(&'a self, key: &ChatListKey) -> impl Iterator<Item = i64> + 'a {
+    self.lists.get(key).into_iter().flatten().map(|Reverse((_, chat_id))| *chat_id)
+  }
+
+  /// Pinned chat ids of a list in display order, pinned chats always come before the others
+  pub fn pinned_chat_ids<'a>(&'a self, key: &ChatListKey) -> impl Iterator<Item = i64> + 'a {
+    let key = *key;
+    self.chat_ids(&key).take_while(move |chat_id| self.position(*chat_id, &key).is_some_and(|p| p.is_pinned()))
+  }
+
+  /// Position of a chat in a list, `None` if the chat isn't in it
+  pub fn position(&self, chat_id: i64, key: &ChatListKey) -> Option<&ChatPosition> {
+    self.positions.get(&chat_id).and_then(|positions| positions.get(key))
+  }
+
+  /// Lists containing a chat
+  pub fn chat_lists(&self, chat_id: i64) -> Vec<ChatListKey> {
+    let mut keys: Vec<ChatListKey> = self.positions.get(&chat_id).into_iter().flat_map(|p| p.keys().copied()).collect();
+    keys.sort();
+    keys
+  }
+
+  /// Number of chats known in a list
+  pub fn len(&self, key: &ChatListKey) -> usize {
+    self.lists.get(key).map_or(0, |list| list.len())
+  }
+
+  pub fn is_empty(&self, key: &ChatListKey) -> bool { self.len(key) == 0 }
+
+  /// `GetChats` for the first `limit` chats of a list
+  pub fn first_page(key: &ChatListKey, limit: i64) -> GetChats {
+    GetChats::builder()
+      .chat_list(key.chat_list())
+      .offset_order(FIRST_PAGE_ORDER)
+      .offset_chat_id(0)
+      .limit(limit)
+      .build()
+  }
+
+  /// `GetChats` for `limit` chats after the last known chat of a list. Tdlib sends the positions of loaded
+  /// chats as updates, apply them before asking for the next page.
+  pub fn next_page(&self, key: &ChatListKey, limit: i64) -> GetChats {
+    match self.lists.get(key).and_then(|list| list.iter().next_back()) {
+      Some(Reverse((order, chat_id))) => GetChats::builder()
+        .chat_list(key.chat_list())
+        .offset_order(*order)
+        .offset_chat_id(*chat_id)
+        .limit(limit)
+        .build(),
+      None => Self::first_page(key, limit),
+    }
+  }
+}
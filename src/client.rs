@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::errors::*;
+use crate::types::*;
+
+/// Raw json channel to tdlib, `Client` only needs `send` and `receive`
+pub trait Transport: Send + Sync {
+  /// Send a request json to tdlib
+  fn send(&self, request: &str);
+  /// Receive the next response or update json, wait at most `timeout` seconds
+  fn receive(&self, timeout: f64) -> Option<String>;
+}
+
+#[cfg(feature = "sys")]
+impl Transport for crate::Tdlib {
+  fn send(&self, request: &str) { crate::Tdlib::send(self, request) }
+  fn receive(&self, timeout: f64) -> Option<String> { crate::Tdlib::receive(self, timeout) }
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+  fn send(&self, request: &str) { (**self).send(request) }
+  fn receive(&self, timeout: f64) -> Option<String> { (**self).receive(timeout) }
+}
+
+/// Request/response client, a receive loop thread matches tdlib responses to requests by `@extra`,
+/// updates are decoded as `Update` and sent to the updates channel, other responses are dropped.
+pub struct Client {
+  shared: Arc<Shared>,
+  worker: Option<thread::JoinHandle<()>>,
+}
+
+struct Shared {
+  transport: Box<dyn Transport>,
+  pending: Mutex<HashMap<String, Arc<Mutex<Slot>>>>,
+  updates: Mutex<Sender<Update>>,
+  errors: Mutex<Option<ErrorListener>>,
+  counter: AtomicU64,
+  stopped: AtomicBool,
+}
+
+type ErrorListener = Box<dyn FnMut(RTDError) + Send>;
+
+#[derive(Default)]
+struct Slot {
+  response: Option<String>,
+  waker: Option<Waker>,
+  closed: bool,
+}
+
+/// Seconds the receive loop waits for tdlib before checking if the client was dropped
+const RECEIVE_TIMEOUT: f64 = 1.0;
+
+impl Client {
+  /// Start the receive loop over `transport`, updates not belonging to any request are sent to `updates`
+  pub fn new<T: Transport + 'static>(transport: T, updates: Sender<Update>) -> Self {
+    let shared = Arc::new(Shared {
+      transport: Box::new(transport),
+      pending: Mutex::new(HashMap::new()),
+      updates: Mutex::new(updates),
+      errors: Mutex::new(None),
+      counter: AtomicU64::new(0),
+      stopped: AtomicBool::new(false),
+    });
+    let worker_shared = shared.clone();
+    let worker = thread::spawn(move || {
+      while !worker_shared.stopped.load(Ordering::SeqCst) {
+        if let Some(json) = worker_shared.transport.receive(RECEIVE_TIMEOUT) {
+          worker_shared.dispatch(json);
+        }
+      }
+      worker_shared.close_pending();
+    });
+    Self { shared, worker: Some(worker) }
+  }
+
+  /// Send a function to tdlib and wait for its response. The `@extra` of the function is replaced by the client.
+  pub async fn send<Fnc: RFunction>(&self, fnc: Fnc) -> RTDResult<Fnc::Response> {
+    let extra = self.shared.counter.fetch_add(1, Ordering::SeqCst).to_string();
+    let mut request: serde_json::Value = serde_json::from_str(&fnc.to_json()?)?;
+    match request.as_object_mut() {
+      Some(map) => { map.insert("@extra".to_string(), serde_json::Value::String(extra.clone())); }
+      None => return Err(RTDError::message(format!("{} is not a json object", fnc.td_name()))),
+    }
+
+    let slot = Arc::new(Mutex::new(Slot::default()));
+    self.shared.pending.lock().unwrap().insert(extra.clone(), slot.clone());
+    if self.shared.stopped.load(Ordering::SeqCst) {
+      self.shared.pending.lock().unwrap().remove(&extra);
+      return Err(RTDError::custom("client is closed"));
+    }
+    self.shared.transport.send(&request.to_string());
+
+    let td_name = fnc.td_name();
+    let json = ResponseFuture { shared: self.shared.clone(), extra, slot }.await?;
+    let value: serde_json::Value = serde_json::from_str(&json).with_context(|| format!("response of {}", td_name))?;
+    if value.get("@type").and_then(|t| t.as_str()) == Some("error") {
+      return Err(serde_json::from_value::<Error>(value).with_context(|| format!("error response of {}", td_name))?.into());
+    }
+    serde_path_to_error::deserialize(value).with_context(|| format!("response of {}", td_name))
+  }
+
+  /// Send a request and block the current thread until its response, for code without an async runtime
+  pub fn send_blocking<Fnc: RFunction>(&self, fnc: Fnc) -> RTDResult<Fnc::Response> {
+    block_on(self.send(fnc))
+  }
+
+  /// Called with the error of every update tdlib sent that could not be decoded
+  pub fn on_error<F: FnMut(RTDError) + Send + 'static>(&self, listener: F) {
+    *self.shared.errors.lock().unwrap() = Some(Box::new(listener));
+  }
+}
+
+impl Drop for Client {
+  fn drop(&mut self) {
+    self.shared.stopped.store(true, Ordering::SeqCst);
+    if let Some(worker) = self.worker.take() {
+      let _ = worker.join();
+    }
+  }
+}
+
+impl Shared {
+  fn dispatch(&self, json: String) {
+    let value = match serde_json::from_str::<serde_json::Value>(&json) {
+      Ok(value) => value,
+      Err(err) => return self.error(RTDError::from(err).context("tdlib json")),
+    };
+    let extra = value.get("@extra").and_then(|e| e.as_str());
+    let slot = extra.and_then(|extra| self.pending.lock().unwrap().remove(extra));
+    if let Some(slot) = slot {
+      let mut slot = slot.lock().unwrap();
+      slot.response = Some(json);
+      if let Some(waker) = slot.waker.take() { waker.wake() }
+      return;
+    }
+    // responses to requests not sent by this client are dropped
+    let td_type = value.get("@type").and_then(|t| t.as_str()).unwrap_or_default().to_string();
+    if !td_type.starts_with("update") {
+      return;
+    }
+    match serde_path_to_error::deserialize::<_, Update>(value) {
+      Ok(update) => { let _ = self.updates.lock().unwrap().send(update); }
+      Err(err) => self.error(RTDError::from(err).context(td_type)),
+    }
+  }
+
+  fn error(&self, err: RTDError) {
+    if let Some(listener) = self.errors.lock().unwrap().as_mut() {
+      listener(err)
+    }
+  }
+
+  fn close_pending(&self) {
+    for (_, slot) in self.pending.lock().unwrap().drain() {
+      let mut slot = slot.lock().unwrap();
+      slot.closed = true;
+      if let Some(waker) = slot.waker.take() { waker.wake() }
+    }
+  }
+}
+
+struct ResponseFuture {
+  shared: Arc<Shared>,
+  extra: String,
+  slot: Arc<Mutex<Slot>>,
+}
+
+impl Future for ResponseFuture {
+  type Output = RTDResult<String>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut slot = self.slot.lock().unwrap();
+    if let Some(response) = slot.response.take() {
+      return Poll::Ready(Ok(response));
+    }
+    if slot.closed {
+      return Poll::Ready(Err(RTDError::custom("client is closed")));
+    }
+    slot.waker = Some(cx.waker().clone());
+    Poll::Pending
+  }
+}
+
+impl Drop for ResponseFuture {
+  fn drop(&mut self) {
+    self.shared.pending.lock().unwrap().remove(&self.extra);
+  }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+  fn wake(self: Arc<Self>) { self.0.unpark() }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+  let mut future = Box::pin(future);
+  let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+  let mut cx = Context::from_waker(&waker);
+  loop {
+    if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+      return output;
+    }
+    thread::park();
+  }
+}
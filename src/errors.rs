@@ -1,17 +1,134 @@
 
 use std::{io, fmt, error};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum RTDError {
   Io(io::Error),
   SerdeJson(serde_json::Error),
   Custom(&'static str),
+  /// Error message built at runtime
+  Message(String),
+  /// `source` error happened while doing `context`, e.g. which field or `@type` was read
+  Context { context: String, source: Box<RTDError> },
+  /// Error object returned by tdlib for a request
+  Td(TdError),
+}
+
+/// Error returned by tdlib, well known failures have their own variant. Every variant keeps the code tdlib sent
+#[derive(Debug, Clone, PartialEq)]
+pub enum TdError {
+  /// FLOOD_WAIT_X, the request can be repeated after `retry_after`
+  FloodWait { code: i64, retry_after: Duration },
+  /// PHONE_CODE_INVALID, the authentication code is wrong
+  PhoneCodeInvalid { code: i64, message: String },
+  /// CHAT_WRITE_FORBIDDEN, no rights to send messages to the chat
+  ChatWriteForbidden { code: i64, message: String },
+  /// Error code 401, the user is not authorized or the session was terminated
+  Unauthorized { code: i64, message: String },
+  /// Error code 404, the requested object doesn't exist
+  NotFound { code: i64, message: String },
+  /// The request was cancelled, by another request or because tdlib is closing
+  Cancelled { code: i64, message: String },
+  /// Any other tdlib error
+  Other { code: i64, message: String },
 }
 
 pub type RTDResult<T> = Result<T, RTDError>;
 
 impl RTDError {
   pub fn custom(msg: &'static str) -> Self { RTDError::Custom(msg) }
+  pub fn message<S: Into<String>>(msg: S) -> Self { RTDError::Message(msg.into()) }
+
+  /// Wrap this error with what was being done when it happened
+  pub fn context<S: Into<String>>(self, context: S) -> Self {
+    RTDError::Context { context: context.into(), source: Box::new(self) }
+  }
+
+  /// The innermost error of a context chain
+  pub fn root(&self) -> &RTDError {
+    match self {
+      RTDError::Context { source, .. } => source.root(),
+      _ => self,
+    }
+  }
+}
+
+/// Add context to the error of a result
+pub trait RTDResultExt<T> {
+  fn context<S: Into<String>>(self, context: S) -> RTDResult<T>;
+  fn with_context<S: Into<String>, F: FnOnce() -> S>(self, context: F) -> RTDResult<T>;
+}
+
+impl<T, E: Into<RTDError>> RTDResultExt<T> for Result<T, E> {
+  fn context<S: Into<String>>(self, context: S) -> RTDResult<T> {
+    self.map_err(|e| e.into().context(context))
+  }
+
+  fn with_context<S: Into<String>, F: FnOnce() -> S>(self, context: F) -> RTDResult<T> {
+    self.map_err(|e| e.into().context(context()))
+  }
+}
+
+impl TdError {
+  /// Classify error `code` and `message` sent by tdlib
+  pub fn new<S: AsRef<str>>(code: i64, message: S) -> Self {
+    let message = message.as_ref();
+    if let Some(seconds) = flood_wait_seconds(message) {
+      return TdError::FloodWait { code, retry_after: Duration::from_secs(seconds) };
+    }
+    let message = message.to_string();
+    match (code, message.as_str()) {
+      (_, "PHONE_CODE_INVALID") => TdError::PhoneCodeInvalid { code, message },
+      (_, "CHAT_WRITE_FORBIDDEN") | (_, "Have no write access to the chat") => TdError::ChatWriteForbidden { code, message },
+      (_, "Request aborted") | (_, "Canceled") | (_, "Canceled by another downloadFile request") => TdError::Cancelled { code, message },
+      (401, _) => TdError::Unauthorized { code, message },
+      (404, _) => TdError::NotFound { code, message },
+      _ => TdError::Other { code, message },
+    }
+  }
+
+  /// Error code, the same as tdlib sent
+  pub fn code(&self) -> i64 {
+    match self {
+      TdError::FloodWait { code, .. } => *code,
+      TdError::PhoneCodeInvalid { code, .. } => *code,
+      TdError::ChatWriteForbidden { code, .. } => *code,
+      TdError::Unauthorized { code, .. } => *code,
+      TdError::NotFound { code, .. } => *code,
+      TdError::Cancelled { code, .. } => *code,
+      TdError::Other { code, .. } => *code,
+    }
+  }
+
+  /// Error message, the same as tdlib sent except for flood waits which are always `FLOOD_WAIT_X`
+  pub fn message(&self) -> String {
+    match self {
+      TdError::FloodWait { retry_after, .. } => format!("FLOOD_WAIT_{}", retry_after.as_secs()),
+      TdError::PhoneCodeInvalid { message, .. } => message.clone(),
+      TdError::ChatWriteForbidden { message, .. } => message.clone(),
+      TdError::Unauthorized { message, .. } => message.clone(),
+      TdError::NotFound { message, .. } => message.clone(),
+      TdError::Cancelled { message, .. } => message.clone(),
+      TdError::Other { message, .. } => message.clone(),
+    }
+  }
+}
+
+// telegram sends `FLOOD_WAIT_X`, tdlib rewrites it to `Too Many Requests: retry after X`
+fn flood_wait_seconds(message: &str) -> Option<u64> {
+  message.strip_prefix("FLOOD_WAIT_")
+    .or_else(|| message.strip_prefix("Too Many Requests: retry after "))
+    .and_then(|seconds| seconds.trim().parse().ok())
+}
+
+impl fmt::Display for TdError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TdError::FloodWait { retry_after, .. } => write!(f, "Too many requests, retry after {} seconds", retry_after.as_secs()),
+      _ => write!(f, "{} {}", self.code(), self.message()),
+    }
+  }
 }
 
 impl fmt::Display for RTDError {
@@ -20,24 +137,34 @@ impl fmt::Display for RTDError {
       RTDError::Io(ref err) => write!(f, "IO error: {}", err),
       RTDError::SerdeJson(ref err) => write!(f, "Serde json error: {}", err),
       RTDError::Custom(msg) => write!(f, "{}", msg),
+      RTDError::Message(ref msg) => write!(f, "{}", msg),
+      RTDError::Context { ref context, ref source } => write!(f, "{}: {}", context, source),
+      RTDError::Td(ref err) => write!(f, "TDLib error: {}", err),
     }
   }
 }
 
 impl error::Error for RTDError {
+  #[allow(deprecated)]
   fn description(&self) -> &str {
     match *self {
       RTDError::Io(ref err) => err.description(),
       RTDError::SerdeJson(ref err) => err.description(),
       RTDError::Custom(msg) => msg,
+      RTDError::Message(ref msg) => msg,
+      RTDError::Context { ref context, .. } => context,
+      RTDError::Td(_) => "TDLib error",
     }
   }
 
-  fn cause(&self) -> Option<&dyn error::Error> {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
     match *self {
       RTDError::Io(ref err) => Some(err),
       RTDError::SerdeJson(ref err) => Some(err),
-      RTDError::Custom(_) => None
+      RTDError::Custom(_) => None,
+      RTDError::Message(_) => None,
+      RTDError::Context { ref source, .. } => Some(source.as_ref()),
+      RTDError::Td(_) => None,
     }
   }
 }
@@ -54,6 +181,33 @@ impl From<serde_json::Error> for RTDError {
   }
 }
 
+/// The error of a nested field is the context of its json path, e.g. `field message.content`
+impl From<serde_path_to_error::Error<serde_json::Error>> for RTDError {
+  fn from(err: serde_path_to_error::Error<serde_json::Error>) -> RTDError {
+    let path = err.path().to_string();
+    let err = RTDError::SerdeJson(err.into_inner());
+    if path == "." { err } else { err.context(format!("field {}", path)) }
+  }
+}
+
+impl From<crate::types::Error> for TdError {
+  fn from(err: crate::types::Error) -> TdError {
+    TdError::new(err.code(), err.message())
+  }
+}
+
+impl From<crate::types::Error> for RTDError {
+  fn from(err: crate::types::Error) -> RTDError {
+    RTDError::Td(err.into())
+  }
+}
+
+impl From<String> for RTDError {
+  fn from(msg: String) -> RTDError {
+    RTDError::Message(msg)
+  }
+}
+
 //impl From<str> for
 
 
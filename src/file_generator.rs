@@ -0,0 +1,208 @@
+//! Files generated on demand for `InputFileGenerated`, streamed to tdlib by user generators.
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use crate::errors::*;
+use crate::types::*;
+
+/// Largest part of a generated file sent in one `WriteGeneratedFilePart`
+pub const MAX_PART_SIZE: usize = 512 * 1024;
+
+/// Function to send for a file generation, all of them return `Ok`
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum GenerationRequest {
+  WritePart(WriteGeneratedFilePart),
+  Progress(SetFileGenerationProgress),
+  Finish(FinishFileGeneration),
+}
+
+impl RObject for GenerationRequest {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { self.function().td_name() }
+  fn extra(&self) -> Option<&serde_json::Value> { self.function().extra() }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+impl RFunction for GenerationRequest {
+  type Response = Ok;
+}
+
+impl GenerationRequest {
+  pub fn generation_id(&self) -> i64 {
+    match self {
+      GenerationRequest::WritePart(t) => t.generation_id(),
+      GenerationRequest::Progress(t) => t.generation_id(),
+      GenerationRequest::Finish(t) => t.generation_id(),
+    }
+  }
+
+  fn function(&self) -> &dyn RObject {
+    match self {
+      GenerationRequest::WritePart(t) => t,
+      GenerationRequest::Progress(t) => t,
+      GenerationRequest::Finish(t) => t,
+    }
+  }
+}
+
+/// Produce files of a conversion. Runs on its own thread for every generation
+pub trait FileGenerator: Send + Sync + 'static {
+  /// Generate the file tdlib asked for in `start` and write it to `output`. An error fails the generation in tdlib
+  fn generate(&self, start: &UpdateFileGenerationStart, output: &mut GenerationOutput) -> RTDResult<()>;
+}
+
+impl<F> FileGenerator for F
+  where F: Fn(&UpdateFileGenerationStart, &mut GenerationOutput) -> RTDResult<()> + Send + Sync + 'static {
+  fn generate(&self, start: &UpdateFileGenerationStart, output: &mut GenerationOutput) -> RTDResult<()> {
+    self(start, output)
+  }
+}
+
+/// Generated file being written to tdlib, every write is sent as parts followed by the progress.
+/// Writes fail once tdlib stopped the generation.
+pub struct GenerationOutput {
+  generation_id: i64,
+  offset: i64,
+  expected_size: i64,
+  stopped: Arc<AtomicBool>,
+  requests: Sender<GenerationRequest>,
+}
+
+impl GenerationOutput {
+  pub fn generation_id(&self) -> i64 { self.generation_id }
+
+  /// Bytes written so far
+  pub fn written(&self) -> i64 { self.offset }
+
+  /// Size of the whole file if known, 0 otherwise. Sent with the progress
+  pub fn set_expected_size(&mut self, expected_size: i64) {
+    self.expected_size = expected_size;
+  }
+
+  /// Tdlib doesn't need the file anymore, the generator should return
+  pub fn is_stopped(&self) -> bool { self.stopped.load(Ordering::SeqCst) }
+
+  fn send(&self, request: GenerationRequest) -> io::Result<()> {
+    self.requests.send(request).map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "generation requests channel is closed"))
+  }
+}
+
+impl io::Write for GenerationOutput {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    if self.is_stopped() {
+      return Err(io::Error::new(io::ErrorKind::Other, "file generation is stopped"));
+    }
+    for part in buf.chunks(MAX_PART_SIZE) {
+      self.send(GenerationRequest::WritePart(
+        WriteGeneratedFilePart::builder().generation_id(self.generation_id).offset(self.offset).data(part).build()
+      ))?;
+      self.offset += part.len() as i64;
+    }
+    self.send(GenerationRequest::Progress(
+      SetFileGenerationProgress::builder()
+        .generation_id(self.generation_id)
+        .expected_size(self.expected_size)
+        .local_prefix_size(self.offset)
+        .build()
+    ))?;
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+/// Run registered generators on `UpdateFileGenerationStart` and stop them on `UpdateFileGenerationStop`.
+///
+/// Requests of running generations are sent to the channel given to `new`, the receiving side should send
+/// each of them with `Client::send` in order.
+pub struct FileGenerators {
+  generators: HashMap<String, Arc<dyn FileGenerator>>,
+  running: Arc<Mutex<HashMap<i64, Arc<AtomicBool>>>>,
+  requests: Sender<GenerationRequest>,
+}
+
+impl FileGenerators {
+  pub fn new(requests: Sender<GenerationRequest>) -> Self {
+    Self { generators: HashMap::new(), running: Arc::new(Mutex::new(HashMap::new())), requests }
+  }
+
+  /// Generate files of `conversion` with `generator`. A conversion `name:arguments` uses the generator of
+  /// `name` when none is registered for the whole conversion
+  pub fn register<S: AsRef<str>, G: FileGenerator>(&mut self, conversion: S, generator: G) -> &mut Self {
+    self.generators.insert(conversion.as_ref().to_string(), Arc::new(generator));
+    self
+  }
+
+  fn generator(&self, conversion: &str) -> Option<Arc<dyn FileGenerator>> {
+    self.generators.get(conversion)
+      .or_else(|| conversion.split(':').next().and_then(|name| self.generators.get(name)))
+      .cloned()
+  }
+
+  /// Generation `generation_id` is running
+  pub fn is_running(&self, generation_id: i64) -> bool {
+    self.running.lock().unwrap().contains_key(&generation_id)
+  }
+
+  /// Apply an update, true if it is about file generation
+  pub fn update(&self, update: &Update) -> bool {
+    match update {
+      Update::FileGenerationStart(t) => self.start(t),
+      Update::FileGenerationStop(t) => self.stop(t.generation_id()),
+      _ => return false,
+    }
+    true
+  }
+
+  /// Start a generation on a new thread, a conversion without generator fails right away
+  pub fn start(&self, start: &UpdateFileGenerationStart) {
+    let generation_id = start.generation_id();
+    let generator = match self.generator(start.conversion()) {
+      Some(generator) => generator,
+      None => {
+        let err = RTDError::message(format!("no file generator for conversion {:?}", start.conversion()));
+        let _ = self.requests.send(finish(generation_id, Err(err)));
+        return;
+      }
+    };
+    let stopped = Arc::new(AtomicBool::new(false));
+    self.running.lock().unwrap().insert(generation_id, stopped.clone());
+    let running = self.running.clone();
+    let start = start.clone();
+    let mut output = GenerationOutput { generation_id, offset: 0, expected_size: 0, stopped, requests: self.requests.clone() };
+    thread::spawn(move || {
+      let result = generator.generate(&start, &mut output)
+        .with_context(|| format!("generate {:?} with conversion {:?}", start.original_path(), start.conversion()));
+      running.lock().unwrap().remove(&generation_id);
+      // tdlib already forgot a stopped generation
+      if !output.is_stopped() {
+        let _ = output.requests.send(finish(generation_id, result));
+      }
+    });
+  }
+
+  /// Stop a running generation, its generator fails on the next write
+  pub fn stop(&self, generation_id: i64) {
+    if let Some(stopped) = self.running.lock().unwrap().remove(&generation_id) {
+      stopped.store(true, Ordering::SeqCst);
+    }
+  }
+}
+
+fn finish(generation_id: i64, result: RTDResult<()>) -> GenerationRequest {
+  let mut builder = FinishFileGeneration::builder();
+  builder.generation_id(generation_id);
+  if let Err(err) = result {
+    let code = match err.root() {
+      RTDError::Td(err) => err.code(),
+      _ => 400,
+    };
+    builder.error(Error::builder().code(code).message(err.to_string()).build());
+  }
+  GenerationRequest::Finish(builder.build())
+}
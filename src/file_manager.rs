@@ -0,0 +1,434 @@
+//! Downloads and uploads tracked through `UpdateFile`, with retries and a limit of concurrent downloads.
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::client::Client;
+use crate::errors::*;
+use crate::types::*;
+
+/// Default number of downloads running at the same time
+pub const DEFAULT_MAX_DOWNLOADS: usize = 4;
+/// Default number of times a stopped download is started again
+pub const DEFAULT_RETRIES: u32 = 2;
+
+/// Function the manager needs sent to tdlib, see `FileManager::take_requests`
+#[derive(Debug, Clone)]
+pub enum FileRequest {
+  Download(DownloadFile),
+  CancelDownload(CancelDownloadFile),
+  CancelUpload(CancelUploadFile),
+}
+
+impl FileRequest {
+  pub fn file_id(&self) -> i64 {
+    match self {
+      FileRequest::Download(t) => t.file_id(),
+      FileRequest::CancelDownload(t) => t.file_id(),
+      FileRequest::CancelUpload(t) => t.file_id(),
+    }
+  }
+}
+
+/// Bytes transferred of a file being downloaded or uploaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+  pub file_id: i64,
+  pub upload: bool,
+  pub transferred: i64,
+  /// Size of the file, 0 if it isn't known yet
+  pub total: i64,
+}
+
+impl Progress {
+  /// Transferred part between 0 and 1, `None` if the size isn't known
+  pub fn fraction(&self) -> Option<f64> {
+    if self.total <= 0 { return None }
+    Some((self.transferred as f64 / self.total as f64).min(1.0))
+  }
+}
+
+type ProgressListener = Arc<Mutex<dyn FnMut(&Progress) + Send>>;
+
+#[derive(Default)]
+struct Slot {
+  result: Option<RTDResult<File>>,
+  waker: Option<Waker>,
+}
+
+/// Result of a download or upload, resolves to the file once tdlib reports it completed
+pub struct Transfer {
+  file_id: i64,
+  slot: Arc<Mutex<Slot>>,
+}
+
+impl Transfer {
+  fn ready(file: File) -> Self {
+    let file_id = file.id();
+    Self { file_id, slot: Arc::new(Mutex::new(Slot { result: Some(Ok(file)), waker: None })) }
+  }
+
+  pub fn file_id(&self) -> i64 { self.file_id }
+
+  /// The transfer completed, failed or was cancelled
+  pub fn is_done(&self) -> bool { self.slot.lock().unwrap().result.is_some() }
+}
+
+impl Future for Transfer {
+  type Output = RTDResult<File>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut slot = self.slot.lock().unwrap();
+    match slot.result.take() {
+      Some(result) => Poll::Ready(result),
+      None => {
+        slot.waker = Some(cx.waker().clone());
+        Poll::Pending
+      }
+    }
+  }
+}
+
+/// Resolves once a transfer is done or the manager has requests to send
+struct RequestsOrDone {
+  inner: Arc<Mutex<Inner>>,
+  slot: Arc<Mutex<Slot>>,
+}
+
+impl Future for RequestsOrDone {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+    {
+      let mut slot = self.slot.lock().unwrap();
+      if slot.result.is_some() { return Poll::Ready(()) }
+      slot.waker = Some(cx.waker().clone());
+    }
+    let mut inner = self.inner.lock().unwrap();
+    if !inner.requests.is_empty() { return Poll::Ready(()) }
+    inner.requests_waker = Some(cx.waker().clone());
+    Poll::Pending
+  }
+}
+
+struct Task {
+  priority: i64,
+  /// A request was sent and not failed yet
+  active: bool,
+  /// Tdlib reported the transfer active since the last request
+  started: bool,
+  attempts: u32,
+  slots: Vec<Arc<Mutex<Slot>>>,
+}
+
+impl Task {
+  fn new(priority: i64) -> Self {
+    Self { priority, active: false, started: false, attempts: 0, slots: vec![] }
+  }
+
+  fn transfer(&mut self, file_id: i64) -> Transfer {
+    let slot = Arc::new(Mutex::new(Slot::default()));
+    self.slots.push(slot.clone());
+    Transfer { file_id, slot }
+  }
+
+  /// Resolve every transfer of the task, the last one gets the original error
+  fn finish(mut self, result: RTDResult<File>) {
+    let last = self.slots.pop();
+    for slot in self.slots {
+      let copy = match &result {
+        Ok(file) => Ok(file.clone()),
+        Err(err) => Err(RTDError::message(err.to_string())),
+      };
+      resolve(&slot, copy);
+    }
+    if let Some(slot) = last {
+      resolve(&slot, result);
+    }
+  }
+}
+
+fn resolve(slot: &Mutex<Slot>, result: RTDResult<File>) {
+  let mut slot = slot.lock().unwrap();
+  slot.result = Some(result);
+  if let Some(waker) = slot.waker.take() { waker.wake() }
+}
+
+struct Inner {
+  max_downloads: usize,
+  retries: u32,
+  files: HashMap<i64, File>,
+  downloads: HashMap<i64, Task>,
+  uploads: HashMap<i64, Task>,
+  /// Download order of tasks with the same priority
+  order: Vec<i64>,
+  requests: VecDeque<FileRequest>,
+  /// Woken when a request is queued
+  requests_waker: Option<Waker>,
+}
+
+/// Start downloads, track downloads and uploads and resolve their `Transfer` from `UpdateFile`.
+///
+/// The manager doesn't talk to tdlib itself: every update must be passed to `update`, and the requests it
+/// needs are taken with `take_requests` or sent with `flush`. Clones share the same state.
+#[derive(Clone)]
+pub struct FileManager {
+  inner: Arc<Mutex<Inner>>,
+  listeners: Arc<Mutex<Vec<ProgressListener>>>,
+}
+
+impl Default for FileManager {
+  fn default() -> Self { Self::new() }
+}
+
+impl FileManager {
+  pub fn new() -> Self {
+    Self {
+      inner: Arc::new(Mutex::new(Inner {
+        max_downloads: DEFAULT_MAX_DOWNLOADS,
+        retries: DEFAULT_RETRIES,
+        files: HashMap::new(),
+        downloads: HashMap::new(),
+        uploads: HashMap::new(),
+        order: vec![],
+        requests: VecDeque::new(),
+        requests_waker: None,
+      })),
+      listeners: Arc::new(Mutex::new(vec![])),
+    }
+  }
+
+  /// Downloads running at the same time, the others wait in priority order. At least 1
+  pub fn max_downloads(self, max_downloads: usize) -> Self {
+    self.inner.lock().unwrap().max_downloads = max_downloads.max(1);
+    self
+  }
+
+  /// Times a download stopped before completion is started again before its transfer fails
+  pub fn retries(self, retries: u32) -> Self {
+    self.inner.lock().unwrap().retries = retries;
+    self
+  }
+
+  /// Call `listener` on every progress of a tracked download or upload. Listeners are called without any lock
+  /// of the manager held, but a listener must not apply a file itself, it would wait for its own call to end
+  pub fn on_progress<F: FnMut(&Progress) + Send + 'static>(&self, listener: F) {
+    self.listeners.lock().unwrap().push(Arc::new(Mutex::new(listener)));
+  }
+
+  /// The last state of a file seen in updates
+  pub fn file(&self, file_id: i64) -> Option<File> {
+    self.inner.lock().unwrap().files.get(&file_id).cloned()
+  }
+
+  /// Download a file with priority from 1 to 32, the higher first. Downloading a file already being
+  /// downloaded shares the download and keeps the higher priority.
+  pub fn download(&self, file_id: i64, priority: i64) -> Transfer {
+    let mut inner = self.inner.lock().unwrap();
+    if let Some(file) = inner.files.get(&file_id).filter(|f| f.local().is_downloading_completed()) {
+      return Transfer::ready(file.clone());
+    }
+    let priority = priority.clamp(1, 32);
+    if !inner.downloads.contains_key(&file_id) {
+      inner.order.push(file_id);
+    }
+    let task = inner.downloads.entry(file_id).or_insert_with(|| Task::new(priority));
+    task.priority = task.priority.max(priority);
+    let transfer = task.transfer(file_id);
+    inner.schedule();
+    transfer
+  }
+
+  /// Track the upload of `file`, the response of `UploadFile`
+  pub fn upload(&self, file: &File) -> Transfer {
+    let mut inner = self.inner.lock().unwrap();
+    if file.remote().is_uploading_completed() {
+      return Transfer::ready(file.clone());
+    }
+    inner.files.insert(file.id(), file.clone());
+    let task = inner.uploads.entry(file.id()).or_insert_with(|| Task::new(0));
+    task.active = true;
+    task.started = file.remote().is_uploading_active();
+    task.transfer(file.id())
+  }
+
+  /// Cancel the download or upload of a file, its transfers fail. False if the file isn't tracked
+  pub fn cancel(&self, file_id: i64) -> bool {
+    let mut inner = self.inner.lock().unwrap();
+    if let Some(task) = inner.downloads.remove(&file_id) {
+      inner.order.retain(|id| *id != file_id);
+      if task.active {
+        inner.push_request(FileRequest::CancelDownload(
+          CancelDownloadFile::builder().file_id(file_id).only_if_pending(false).build()
+        ));
+      }
+      task.finish(Err(RTDError::message(format!("download of file {} is cancelled", file_id))));
+      inner.schedule();
+      return true;
+    }
+    if let Some(task) = inner.uploads.remove(&file_id) {
+      inner.push_request(FileRequest::CancelUpload(CancelUploadFile::builder().file_id(file_id).build()));
+      task.finish(Err(RTDError::message(format!("upload of file {} is cancelled", file_id))));
+      return true;
+    }
+    false
+  }
+
+  /// Apply an update, only `UpdateFile` is used
+  pub fn update(&self, update: &Update) {
+    if let Some(update) = update.as_file() {
+      self.update_file(update.file());
+    }
+  }
+
+  /// Apply a new state of a file, from `UpdateFile` or the response of a request
+  pub fn update_file(&self, file: &File) {
+    let progress = self.inner.lock().unwrap().update_file(file);
+    if let Some(progress) = progress {
+      let listeners = self.listeners.lock().unwrap().clone();
+      listeners.iter().for_each(|listener| (listener.lock().unwrap())(&progress));
+    }
+  }
+
+  /// A request for the file returned `err`. A download is started again while it has retries left
+  pub fn failed(&self, file_id: i64, err: RTDError) {
+    let mut inner = self.inner.lock().unwrap();
+    if inner.downloads.contains_key(&file_id) {
+      inner.stopped(file_id, err);
+    } else if let Some(task) = inner.uploads.remove(&file_id) {
+      task.finish(Err(err.context(format!("upload of file {}", file_id))));
+    }
+  }
+
+  /// Requests to send to tdlib, a failed request should be reported with `failed`
+  pub fn take_requests(&self) -> Vec<FileRequest> {
+    self.inner.lock().unwrap().requests.drain(..).collect()
+  }
+
+  /// Send every pending request through `client`, responses and errors are applied to the manager
+  pub async fn flush(&self, client: &Client) {
+    loop {
+      let requests = self.take_requests();
+      if requests.is_empty() { return }
+      for request in requests {
+        let file_id = request.file_id();
+        let result = match request {
+          FileRequest::Download(t) => client.send(t).await.map(|file| self.update_file(&file)),
+          FileRequest::CancelDownload(t) => client.send(t).await.map(|_| ()),
+          FileRequest::CancelUpload(t) => client.send(t).await.map(|_| ()),
+        };
+        if let Err(err) = result {
+          self.failed(file_id, err);
+        }
+      }
+    }
+  }
+
+  /// Download a file and return its local path. Updates must be passed to `update` meanwhile, e.g. by the
+  /// thread receiving them. Requests queued until the download ends are sent too, a queued or retried download
+  /// is only requested later.
+  pub async fn download_path(&self, client: &Client, file_id: i64, priority: i64) -> RTDResult<String> {
+    let transfer = self.download(file_id, priority);
+    loop {
+      self.flush(client).await;
+      if transfer.is_done() { break }
+      RequestsOrDone { inner: self.inner.clone(), slot: transfer.slot.clone() }.await;
+    }
+    Ok(transfer.await?.local().path().clone())
+  }
+}
+
+impl Inner {
+  fn push_request(&mut self, request: FileRequest) {
+    self.requests.push_back(request);
+    if let Some(waker) = self.requests_waker.take() { waker.wake() }
+  }
+
+  /// Start queued downloads while there is room, the highest priority first
+  fn schedule(&mut self) {
+    loop {
+      let active = self.downloads.values().filter(|task| task.active).count();
+      if active >= self.max_downloads { return }
+      let downloads = &self.downloads;
+      let next = self.order.iter()
+        .filter(|id| downloads.get(id).is_some_and(|task| !task.active))
+        .fold(None, |next: Option<i64>, id| match next {
+          Some(next) if downloads[&next].priority >= downloads[id].priority => Some(next),
+          _ => Some(*id),
+        });
+      let file_id = match next {
+        Some(file_id) => file_id,
+        None => return,
+      };
+      let task = self.downloads.get_mut(&file_id).unwrap();
+      task.active = true;
+      task.started = false;
+      task.attempts += 1;
+      let request = FileRequest::Download(
+        DownloadFile::builder().file_id(file_id).priority(task.priority).offset(0).limit(0).synchronous(false).build()
+      );
+      self.push_request(request);
+    }
+  }
+
+  fn update_file(&mut self, file: &File) -> Option<Progress> {
+    let file_id = file.id();
+    self.files.insert(file_id, file.clone());
+    let total = if file.size() > 0 { file.size() } else { file.expected_size() };
+
+    if let Some(task) = self.downloads.get_mut(&file_id).filter(|task| task.active) {
+      let local = file.local();
+      let progress = Progress { file_id, upload: false, transferred: local.downloaded_size(), total };
+      if local.is_downloading_completed() {
+        self.finish_download(file_id, Ok(file.clone()));
+      } else if local.is_downloading_active() {
+        task.started = true;
+      } else if task.started || !local.can_be_downloaded() {
+        self.stopped(file_id, RTDError::message(format!("download of file {} stopped", file_id)));
+      }
+      return Some(progress);
+    }
+
+    if let Some(task) = self.uploads.get_mut(&file_id) {
+      let remote = file.remote();
+      let progress = Progress { file_id, upload: true, transferred: remote.uploaded_size(), total };
+      if remote.is_uploading_completed() {
+        self.uploads.remove(&file_id).unwrap().finish(Ok(file.clone()));
+      } else if remote.is_uploading_active() {
+        task.started = true;
+      } else if task.started {
+        let err = RTDError::message(format!("upload of file {} stopped", file_id));
+        self.uploads.remove(&file_id).unwrap().finish(Err(err));
+      }
+      return Some(progress);
+    }
+    None
+  }
+
+  /// An active download stopped without completing, queue it again or fail it
+  fn stopped(&mut self, file_id: i64, err: RTDError) {
+    let retries = self.retries;
+    let task = match self.downloads.get_mut(&file_id) {
+      Some(task) => task,
+      None => return,
+    };
+    if task.attempts <= retries {
+      task.active = false;
+      self.schedule();
+    } else {
+      let attempts = task.attempts;
+      self.finish_download(file_id, Err(err.context(format!("download of file {} after {} attempts", file_id, attempts))));
+    }
+  }
+
+  fn finish_download(&mut self, file_id: i64, result: RTDResult<File>) {
+    if let Some(task) = self.downloads.remove(&file_id) {
+      self.order.retain(|id| *id != file_id);
+      task.finish(result);
+    }
+    self.schedule();
+  }
+}
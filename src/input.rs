@@ -0,0 +1,194 @@
+//! Short constructors of `InputMessageContent` and `InputFile`, filling the fields tdlib requires with defaults.
+
+use crate::types::*;
+
+/// Values usable as the text of a message, strings are sent without entities
+pub trait IntoFormattedText {
+  fn into_formatted_text(self) -> FormattedText;
+}
+
+impl IntoFormattedText for FormattedText {
+  fn into_formatted_text(self) -> FormattedText { self }
+}
+
+impl IntoFormattedText for &FormattedText {
+  fn into_formatted_text(self) -> FormattedText { self.clone() }
+}
+
+impl IntoFormattedText for &str {
+  fn into_formatted_text(self) -> FormattedText { FormattedText::builder().text(self).entities(vec![]).build() }
+}
+
+impl IntoFormattedText for String {
+  fn into_formatted_text(self) -> FormattedText { self.as_str().into_formatted_text() }
+}
+
+impl InputFile {
+  /// Local file by its path
+  pub fn path<S: AsRef<str>>(path: S) -> Self { InputFile::local(InputFileLocal::builder().path(path).build()) }
+
+  /// File known to tdlib by its identifier
+  pub fn file_id(id: i64) -> Self { InputFile::id(InputFileId::builder().id(id).build()) }
+
+  /// File already uploaded to the server, by its remote identifier
+  pub fn remote_id<S: AsRef<str>>(id: S) -> Self { InputFile::remote(InputFileRemote::builder().id(id).build()) }
+}
+
+fn empty_caption() -> FormattedText { "".into_formatted_text() }
+
+impl InputMessageContent {
+  /// Text message with web page preview enabled, clearing the chat draft
+  pub fn text<T: IntoFormattedText>(text: T) -> Self {
+    InputMessageContent::input_message_text(InputMessageText::builder()
+      .text(text.into_formatted_text())
+      .disable_web_page_preview(false)
+      .clear_draft(true)
+      .build())
+  }
+
+  pub fn animation<T: AsRef<InputFile>>(animation: T) -> Self {
+    InputMessageContent::input_message_animation(InputMessageAnimation::builder().animation(animation).caption(empty_caption()).build())
+  }
+
+  pub fn audio<T: AsRef<InputFile>>(audio: T) -> Self {
+    InputMessageContent::input_message_audio(InputMessageAudio::builder().audio(audio).caption(empty_caption()).build())
+  }
+
+  pub fn document<T: AsRef<InputFile>>(document: T) -> Self {
+    InputMessageContent::input_message_document(InputMessageDocument::builder().document(document).caption(empty_caption()).build())
+  }
+
+  pub fn photo<T: AsRef<InputFile>>(photo: T) -> Self {
+    InputMessageContent::input_message_photo(InputMessagePhoto::builder().photo(photo).caption(empty_caption()).build())
+  }
+
+  /// Video, marked as streamable like official clients send it
+  pub fn video<T: AsRef<InputFile>>(video: T) -> Self {
+    InputMessageContent::input_message_video(InputMessageVideo::builder().video(video).supports_streaming(true).caption(empty_caption()).build())
+  }
+
+  pub fn video_note<T: AsRef<InputFile>>(video_note: T) -> Self {
+    InputMessageContent::input_message_video_note(InputMessageVideoNote::builder().video_note(video_note).build())
+  }
+
+  pub fn voice_note<T: AsRef<InputFile>>(voice_note: T) -> Self {
+    InputMessageContent::input_message_voice_note(InputMessageVoiceNote::builder().voice_note(voice_note).caption(empty_caption()).build())
+  }
+
+  pub fn sticker<T: AsRef<InputFile>>(sticker: T) -> Self {
+    InputMessageContent::input_message_sticker(InputMessageSticker::builder().sticker(sticker).build())
+  }
+
+  /// Static location
+  pub fn location(latitude: f32, longitude: f32) -> Self {
+    let location = Location::builder().latitude(latitude).longitude(longitude).build();
+    InputMessageContent::input_message_location(InputMessageLocation::builder().location(location).live_period(0).build())
+  }
+
+  pub fn venue<S: AsRef<str>>(latitude: f32, longitude: f32, title: S, address: S) -> Self {
+    let location = Location::builder().latitude(latitude).longitude(longitude).build();
+    let venue = Venue::builder().location(location).title(title).address(address).build();
+    InputMessageContent::input_message_venue(InputMessageVenue::builder().venue(venue).build())
+  }
+
+  pub fn contact<S: AsRef<str>>(phone_number: S, first_name: S) -> Self {
+    let contact = Contact::builder().phone_number(phone_number).first_name(first_name).build();
+    InputMessageContent::input_message_contact(InputMessageContact::builder().contact(contact).build())
+  }
+
+  /// Anonymous regular poll without time limit
+  pub fn poll<S: AsRef<str>>(question: S, options: &[S]) -> Self {
+    InputMessageContent::input_message_poll(InputMessagePoll::builder()
+      .question(question)
+      .options(options.iter().map(|option| option.as_ref().to_string()).collect())
+      .is_anonymous(true)
+      .type_(PollType::regular(PollTypeRegular::builder().allow_multiple_answers(false).build()))
+      .build())
+  }
+
+  /// Dice with an emoji like 🎲 or 🎯, clearing the chat draft
+  pub fn dice<S: AsRef<str>>(emoji: S) -> Self {
+    InputMessageContent::input_message_dice(InputMessageDice::builder().emoji(emoji).clear_draft(true).build())
+  }
+
+  /// Forward of a message
+  pub fn forwarded(from_chat_id: i64, message_id: i64) -> Self {
+    InputMessageContent::input_message_forwarded(InputMessageForwarded::builder()
+      .from_chat_id(from_chat_id)
+      .message_id(message_id)
+      .copy_options(MessageCopyOptions::builder().new_caption(empty_caption()).build())
+      .build())
+  }
+
+  /// Replace the caption of a media message, content without caption is returned unchanged
+  pub fn caption<T: IntoFormattedText>(self, caption: T) -> Self {
+    let caption = caption.into_formatted_text();
+    match self {
+      InputMessageContent::InputMessageAnimation(m) => {
+        let mut builder = InputMessageAnimation::builder();
+        builder
+          .animation(m.animation())
+          .added_sticker_file_ids(m.added_sticker_file_ids().clone())
+          .duration(m.duration())
+          .width(m.width())
+          .height(m.height())
+          .caption(caption);
+        if let Some(thumbnail) = m.thumbnail() { builder.thumbnail(thumbnail); }
+        InputMessageContent::input_message_animation(builder.build())
+      }
+      InputMessageContent::InputMessageAudio(m) => {
+        let mut builder = InputMessageAudio::builder();
+        builder
+          .audio(m.audio())
+          .duration(m.duration())
+          .title(m.title())
+          .performer(m.performer())
+          .caption(caption);
+        if let Some(thumbnail) = m.album_cover_thumbnail() { builder.album_cover_thumbnail(thumbnail); }
+        InputMessageContent::input_message_audio(builder.build())
+      }
+      InputMessageContent::InputMessageDocument(m) => {
+        let mut builder = InputMessageDocument::builder();
+        builder
+          .document(m.document())
+          .force_file(m.force_file())
+          .caption(caption);
+        if let Some(thumbnail) = m.thumbnail() { builder.thumbnail(thumbnail); }
+        InputMessageContent::input_message_document(builder.build())
+      }
+      InputMessageContent::InputMessagePhoto(m) => {
+        let mut builder = InputMessagePhoto::builder();
+        builder
+          .photo(m.photo())
+          .added_sticker_file_ids(m.added_sticker_file_ids().clone())
+          .width(m.width())
+          .height(m.height())
+          .caption(caption)
+          .ttl(m.ttl());
+        if let Some(thumbnail) = m.thumbnail() { builder.thumbnail(thumbnail); }
+        InputMessageContent::input_message_photo(builder.build())
+      }
+      InputMessageContent::InputMessageVideo(m) => {
+        let mut builder = InputMessageVideo::builder();
+        builder
+          .video(m.video())
+          .added_sticker_file_ids(m.added_sticker_file_ids().clone())
+          .duration(m.duration())
+          .width(m.width())
+          .height(m.height())
+          .supports_streaming(m.supports_streaming())
+          .caption(caption)
+          .ttl(m.ttl());
+        if let Some(thumbnail) = m.thumbnail() { builder.thumbnail(thumbnail); }
+        InputMessageContent::input_message_video(builder.build())
+      }
+      InputMessageContent::InputMessageVoiceNote(m) => InputMessageContent::input_message_voice_note(InputMessageVoiceNote::builder()
+        .voice_note(m.voice_note())
+        .duration(m.duration())
+        .waveform(m.waveform())
+        .caption(caption)
+        .build()),
+      content => content,
+    }
+  }
+}
@@ -0,0 +1,575 @@
+//! Render a `WebPageInstantView` offline, as a standalone html document, markdown or plain text.
+
+use crate::types::*;
+
+/// Output of `InstantViewRenderer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+  Html,
+  Markdown,
+  PlainText,
+}
+
+type MediaSource = Box<dyn Fn(&File) -> Option<String>>;
+
+/// Renderer of instant view page blocks. Media files are linked by the source returned for them,
+/// by default the local path of downloaded files.
+pub struct InstantViewRenderer {
+  format: Format,
+  media: MediaSource,
+}
+
+/// Instant view as a standalone html document
+pub fn to_html(view: &WebPageInstantView) -> String { InstantViewRenderer::new(Format::Html).render(view) }
+
+/// Instant view as markdown, tables are GitHub flavored markdown tables
+pub fn to_markdown(view: &WebPageInstantView) -> String { InstantViewRenderer::new(Format::Markdown).render(view) }
+
+/// Instant view as plain text
+pub fn to_plain_text(view: &WebPageInstantView) -> String { InstantViewRenderer::new(Format::PlainText).render(view) }
+
+/// Text of a rich text without any formatting
+pub fn plain_text(text: &RichText) -> String {
+  InstantViewRenderer::new(Format::PlainText).render_text(text)
+}
+
+impl InstantViewRenderer {
+  pub fn new(format: Format) -> Self {
+    Self {
+      format,
+      media: Box::new(|file| {
+        if file.local().is_downloading_completed() { Some(file.local().path().clone()) } else { None }
+      }),
+    }
+  }
+
+  /// Source of a media file, `None` if the file isn't available
+  pub fn media<F: Fn(&File) -> Option<String> + 'static>(&mut self, media: F) -> &mut Self {
+    self.media = Box::new(media);
+    self
+  }
+
+  /// Render the whole instant view, html is a complete document
+  pub fn render(&self, view: &WebPageInstantView) -> String {
+    let body = self.render_blocks(view.page_blocks());
+    if self.format != Format::Html {
+      return body;
+    }
+    let title = view.page_blocks().iter()
+      .find_map(|block| block.as_title().map(|t| plain_text(t.title())))
+      .unwrap_or_default();
+    format!(
+      "<!DOCTYPE html>\n<html dir=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n<article>\n{}</article>\n</body>\n</html>\n",
+      if view.is_rtl() { "rtl" } else { "ltr" }, escape(&title), body,
+    )
+  }
+
+  pub fn render_blocks(&self, blocks: &[PageBlock]) -> String {
+    match self.format {
+      Format::Html => blocks.iter().map(|block| self.html_block(block)).collect(),
+      _ => {
+        let blocks: Vec<String> = blocks.iter().map(|block| self.text_block(block)).filter(|b| !b.is_empty()).collect();
+        if blocks.is_empty() { String::new() } else { blocks.join("\n\n") + "\n" }
+      }
+    }
+  }
+
+  pub fn render_text(&self, text: &RichText) -> String {
+    let mut out = String::new();
+    match self.format {
+      Format::Html => self.html_text(text, &mut out),
+      _ => self.text_text(text, &mut out),
+    }
+    out
+  }
+
+  fn source(&self, file: &File) -> Option<String> { (self.media)(file) }
+
+  fn html_text(&self, text: &RichText, out: &mut String) {
+    let wrap = |out: &mut String, open: &str, inner: &RichText, close: &str| {
+      out.push_str(open);
+      self.html_text(inner, out);
+      out.push_str(close);
+    };
+    match text {
+      RichText::Plain(t) => out.push_str(&escape(t.text())),
+      RichText::Bold(t) => wrap(out, "<b>", t.text(), "</b>"),
+      RichText::Italic(t) => wrap(out, "<i>", t.text(), "</i>"),
+      RichText::Underline(t) => wrap(out, "<u>", t.text(), "</u>"),
+      RichText::Strikethrough(t) => wrap(out, "<s>", t.text(), "</s>"),
+      RichText::Fixed(t) => wrap(out, "<code>", t.text(), "</code>"),
+      RichText::Subscript(t) => wrap(out, "<sub>", t.text(), "</sub>"),
+      RichText::Superscript(t) => wrap(out, "<sup>", t.text(), "</sup>"),
+      RichText::Marked(t) => wrap(out, "<mark>", t.text(), "</mark>"),
+      RichText::Url(t) => wrap(out, &format!("<a href=\"{}\">", escape(t.url())), t.text(), "</a>"),
+      RichText::EmailAddress(t) => wrap(out, &format!("<a href=\"mailto:{}\">", escape(t.email_address())), t.text(), "</a>"),
+      RichText::PhoneNumber(t) => wrap(out, &format!("<a href=\"tel:{}\">", escape(t.phone_number())), t.text(), "</a>"),
+      RichText::Reference(t) => wrap(out, &format!("<a href=\"{}\" title=\"{}\">", escape(t.url()), escape(&plain_text(t.reference_text()))), t.text(), "</a>"),
+      RichText::AnchorLink(t) => wrap(out, &format!("<a href=\"{}\">", escape(&anchor_href(t))), t.text(), "</a>"),
+      RichText::Anchor(t) => out.push_str(&format!("<a name=\"{}\"></a>", escape(t.name()))),
+      RichText::Icon(t) => {
+        if let Some(src) = self.source(t.document().document()) {
+          out.push_str(&format!("<img src=\"{}\" width=\"{}\" height=\"{}\">", escape(&src), t.width(), t.height()));
+        }
+      }
+      RichText::RichTexts(t) => t.texts().iter().for_each(|text| self.html_text(text, out)),
+      _ => {}
+    }
+  }
+
+  // markdown or plain text
+  fn text_text(&self, text: &RichText, out: &mut String) {
+    let markdown = self.format == Format::Markdown;
+    let wrap = |out: &mut String, marker: &str, inner: &RichText| {
+      if markdown { out.push_str(marker) }
+      self.text_text(inner, out);
+      if markdown { out.push_str(marker) }
+    };
+    let link = |out: &mut String, inner: &RichText, url: &str| {
+      if markdown { out.push('[') }
+      self.text_text(inner, out);
+      if markdown { out.push_str(&format!("]({})", markdown_url(url))) }
+    };
+    match text {
+      RichText::Plain(t) if markdown => out.push_str(&escape_markdown(t.text())),
+      RichText::Plain(t) => out.push_str(t.text()),
+      RichText::Bold(t) => wrap(out, "**", t.text()),
+      RichText::Italic(t) => wrap(out, "*", t.text()),
+      RichText::Strikethrough(t) => wrap(out, "~~", t.text()),
+      RichText::Fixed(t) if markdown => out.push_str(&code_span(&plain_text(t.text()))),
+      RichText::Url(t) => link(out, t.text(), t.url()),
+      RichText::EmailAddress(t) => link(out, t.text(), &format!("mailto:{}", t.email_address())),
+      RichText::PhoneNumber(t) => link(out, t.text(), &format!("tel:{}", t.phone_number())),
+      RichText::Reference(t) => link(out, t.text(), t.url()),
+      RichText::AnchorLink(t) => link(out, t.text(), &anchor_href(t)),
+      RichText::Fixed(_) | RichText::Underline(_) | RichText::Subscript(_) | RichText::Superscript(_) | RichText::Marked(_) => {
+        if let Some(inner) = inner_text(text) { self.text_text(inner, out) }
+      }
+      RichText::RichTexts(t) => t.texts().iter().for_each(|text| self.text_text(text, out)),
+      _ => {}
+    }
+  }
+
+  fn caption_html(&self, caption: &PageBlockCaption) -> String {
+    let text = self.render_text(caption.text());
+    let credit = self.render_text(caption.credit());
+    if text.is_empty() && credit.is_empty() {
+      return String::new();
+    }
+    let credit = if credit.is_empty() { credit } else { format!("<cite>{}</cite>", credit) };
+    format!("<figcaption>{}{}</figcaption>", text, credit)
+  }
+
+  fn caption_text(&self, caption: &PageBlockCaption) -> String {
+    let text = self.render_text(caption.text());
+    let credit = self.render_text(caption.credit());
+    match (text.is_empty(), credit.is_empty()) {
+      (_, true) => text,
+      (true, false) => format!("— {}", credit),
+      (false, false) => format!("{} — {}", text, credit),
+    }
+  }
+
+  fn html_block(&self, block: &PageBlock) -> String {
+    let text = |t: &RichText| self.render_text(t);
+    match block {
+      PageBlock::Title(b) => format!("<h1>{}</h1>\n", text(b.title())),
+      PageBlock::Subtitle(b) => format!("<h2 class=\"subtitle\">{}</h2>\n", text(b.subtitle())),
+      PageBlock::AuthorDate(b) => {
+        let date = if b.publish_date() > 0 { format!(" <time>{}</time>", date(b.publish_date())) } else { String::new() };
+        format!("<address>{}{}</address>\n", text(b.author()), date)
+      }
+      PageBlock::Header(b) => format!("<h2>{}</h2>\n", text(b.header())),
+      PageBlock::Subheader(b) => format!("<h3>{}</h3>\n", text(b.subheader())),
+      PageBlock::Kicker(b) => format!("<p class=\"kicker\">{}</p>\n", text(b.kicker())),
+      PageBlock::Paragraph(b) => format!("<p>{}</p>\n", text(b.text())),
+      PageBlock::Preformatted(b) if b.language().is_empty() => format!("<pre>{}</pre>\n", text(b.text())),
+      PageBlock::Preformatted(b) => format!("<pre><code class=\"language-{}\">{}</code></pre>\n", escape(b.language()), text(b.text())),
+      PageBlock::Footer(b) => format!("<footer>{}</footer>\n", text(b.footer())),
+      PageBlock::Divider(_) => "<hr>\n".to_string(),
+      PageBlock::Anchor(b) => format!("<a name=\"{}\"></a>\n", escape(b.name())),
+      PageBlock::List(b) => {
+        let items: String = b.items().iter()
+          .map(|item| format!("<li><span class=\"label\">{}</span>\n{}</li>\n", escape(item.label()), self.render_blocks(item.page_blocks())))
+          .collect();
+        format!("<ul class=\"list\">\n{}</ul>\n", items)
+      }
+      PageBlock::BlockQuote(b) => format!("<blockquote>{}{}</blockquote>\n", text(b.text()), cite(text(b.credit()))),
+      PageBlock::PullQuote(b) => format!("<aside class=\"pull-quote\">{}{}</aside>\n", text(b.text()), cite(text(b.credit()))),
+      PageBlock::Animation(b) => {
+        let media = match b.animation().as_ref().and_then(|a| self.source(a.animation())) {
+          Some(src) => format!("<video src=\"{}\" autoplay loop muted></video>", escape(&src)),
+          None => "<div class=\"missing\">Animation</div>".to_string(),
+        };
+        format!("<figure>{}{}</figure>\n", media, self.caption_html(b.caption()))
+      }
+      PageBlock::Audio(b) => self.html_audio(b.audio().as_ref().map(|a| a.audio()), "Audio", b.caption()),
+      PageBlock::VoiceNote(b) => self.html_audio(b.voice_note().as_ref().map(|v| v.voice()), "Voice note", b.caption()),
+      PageBlock::Photo(b) => {
+        let mut media = match b.photo().as_ref().and_then(largest_photo).and_then(|size| self.source(size.photo()).map(|src| (size, src))) {
+          Some((size, src)) => format!("<img src=\"{}\" width=\"{}\" height=\"{}\">", escape(&src), size.width(), size.height()),
+          None => "<div class=\"missing\">Photo</div>".to_string(),
+        };
+        if !b.url().is_empty() {
+          media = format!("<a href=\"{}\">{}</a>", escape(b.url()), media);
+        }
+        format!("<figure>{}{}</figure>\n", media, self.caption_html(b.caption()))
+      }
+      PageBlock::Video(b) => {
+        let flags = if b.need_autoplay() { " autoplay muted" } else { " controls" };
+        let flags = if b.is_looped() { format!("{} loop", flags) } else { flags.to_string() };
+        let media = match b.video().as_ref().and_then(|v| self.source(v.video())) {
+          Some(src) => format!("<video src=\"{}\"{}></video>", escape(&src), flags),
+          None => "<div class=\"missing\">Video</div>".to_string(),
+        };
+        format!("<figure>{}{}</figure>\n", media, self.caption_html(b.caption()))
+      }
+      PageBlock::Cover(b) => self.html_block(b.cover()),
+      PageBlock::Embedded(b) => {
+        let source = if b.url().is_empty() { format!("srcdoc=\"{}\"", escape(b.html())) } else { format!("src=\"{}\"", escape(b.url())) };
+        let width = if b.is_full_width() { "100%".to_string() } else { b.width().to_string() };
+        let scrolling = if b.allow_scrolling() { "" } else { " scrolling=\"no\"" };
+        format!("<figure><iframe {} width=\"{}\" height=\"{}\"{}></iframe>{}</figure>\n", source, width, b.height(), scrolling, self.caption_html(b.caption()))
+      }
+      PageBlock::EmbeddedPost(b) => {
+        let date = if b.date() > 0 { format!(" <time>{}</time>", date(b.date())) } else { String::new() };
+        format!(
+          "<figure><blockquote class=\"embedded-post\">\n<header><a href=\"{}\">{}</a>{}</header>\n{}</blockquote>{}</figure>\n",
+          escape(b.url()), escape(b.author()), date, self.render_blocks(b.page_blocks()), self.caption_html(b.caption()),
+        )
+      }
+      PageBlock::Collage(b) => format!("<figure class=\"collage\">\n{}{}</figure>\n", self.render_blocks(b.page_blocks()), self.caption_html(b.caption())),
+      PageBlock::Slideshow(b) => format!("<figure class=\"slideshow\">\n{}{}</figure>\n", self.render_blocks(b.page_blocks()), self.caption_html(b.caption())),
+      PageBlock::ChatLink(b) => format!("<p class=\"chat-link\"><a href=\"https://t.me/{}\">{}</a></p>\n", escape(b.username()), escape(b.title())),
+      PageBlock::Table(b) => self.html_table(b),
+      PageBlock::Details(b) => format!(
+        "<details{}>\n<summary>{}</summary>\n{}</details>\n",
+        if b.is_open() { " open" } else { "" }, text(b.header()), self.render_blocks(b.page_blocks()),
+      ),
+      PageBlock::RelatedArticles(b) => {
+        let articles: String = b.articles().iter().map(|article| {
+          let byline = byline(article.author(), article.publish_date());
+          format!(
+            "<li><a href=\"{}\">{}</a>{}{}</li>\n",
+            escape(article.url()), escape(if article.title().is_empty() { article.url() } else { article.title() }),
+            if article.description().is_empty() { String::new() } else { format!("<p>{}</p>", escape(article.description())) },
+            if byline.is_empty() { String::new() } else { format!("<small>{}</small>", escape(&byline)) },
+          )
+        }).collect();
+        format!("<section class=\"related-articles\">\n<h3>{}</h3>\n<ul>\n{}</ul>\n</section>\n", text(b.header()), articles)
+      }
+      PageBlock::Map(b) => format!(
+        "<figure><a href=\"{}\">Map</a>{}</figure>\n",
+        escape(&map_url(b)), self.caption_html(b.caption()),
+      ),
+      _ => String::new(),
+    }
+  }
+
+  fn html_audio(&self, file: Option<&File>, name: &str, caption: &PageBlockCaption) -> String {
+    let media = match file.and_then(|file| self.source(file)) {
+      Some(src) => format!("<audio src=\"{}\" controls></audio>", escape(&src)),
+      None => format!("<div class=\"missing\">{}</div>", name),
+    };
+    format!("<figure>{}{}</figure>\n", media, self.caption_html(caption))
+  }
+
+  fn html_table(&self, table: &PageBlockTable) -> String {
+    let mut classes = vec![];
+    if table.is_bordered() { classes.push("bordered") }
+    if table.is_striped() { classes.push("striped") }
+    let mut out = if classes.is_empty() { "<table>\n".to_string() } else { format!("<table class=\"{}\">\n", classes.join(" ")) };
+    let caption = self.render_text(table.caption());
+    if !caption.is_empty() {
+      out.push_str(&format!("<caption>{}</caption>\n", caption));
+    }
+    for row in table.cells() {
+      out.push_str("<tr>");
+      for cell in row {
+        let tag = if cell.is_header() { "th" } else { "td" };
+        let mut attributes = String::new();
+        if cell.colspan() > 1 { attributes.push_str(&format!(" colspan=\"{}\"", cell.colspan())) }
+        if cell.rowspan() > 1 { attributes.push_str(&format!(" rowspan=\"{}\"", cell.rowspan())) }
+        let mut style = vec![];
+        match cell.align() {
+          PageBlockHorizontalAlignment::Center(_) => style.push("text-align:center"),
+          PageBlockHorizontalAlignment::Right(_) => style.push("text-align:right"),
+          _ => {}
+        }
+        match cell.valign() {
+          PageBlockVerticalAlignment::Middle(_) => style.push("vertical-align:middle"),
+          PageBlockVerticalAlignment::Bottom(_) => style.push("vertical-align:bottom"),
+          _ => {}
+        }
+        if !style.is_empty() { attributes.push_str(&format!(" style=\"{}\"", style.join(";"))) }
+        let text = cell.text().as_ref().map(|t| self.render_text(t)).unwrap_or_default();
+        out.push_str(&format!("<{}{}>{}</{}>", tag, attributes, text, tag));
+      }
+      out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+    out
+  }
+
+  fn text_block(&self, block: &PageBlock) -> String {
+    let markdown = self.format == Format::Markdown;
+    let text = |t: &RichText| self.render_text(t);
+    let heading = |level: &str, t: &RichText| if markdown { format!("{} {}", level, text(t)) } else { text(t) };
+    let with_caption = |body: String, caption: &PageBlockCaption| {
+      let caption = self.caption_text(caption);
+      if caption.is_empty() { body } else { format!("{}\n\n{}", body, caption) }
+    };
+    // the caption follows the media, so images get `name` as alt text instead of repeating it
+    let media = |name: &str, image: bool, src: Option<String>, caption: &PageBlockCaption| {
+      let body = match src {
+        Some(src) if markdown && image => format!("![{}]({})", name, markdown_url(&src)),
+        Some(src) if markdown => format!("[{}]({})", name, markdown_url(&src)),
+        _ => format!("[{}]", name),
+      };
+      with_caption(body, caption)
+    };
+    match block {
+      PageBlock::Title(b) => heading("#", b.title()),
+      PageBlock::Subtitle(b) => heading("##", b.subtitle()),
+      PageBlock::Header(b) => heading("##", b.header()),
+      PageBlock::Subheader(b) => heading("###", b.subheader()),
+      PageBlock::Kicker(b) => text(b.kicker()),
+      PageBlock::AuthorDate(b) => byline(&text(b.author()), b.publish_date()),
+      PageBlock::Paragraph(b) => text(b.text()),
+      PageBlock::Footer(b) => text(b.footer()),
+      PageBlock::Preformatted(b) if markdown => {
+        let code = plain_text(b.text());
+        let fence = "`".repeat(longest_backtick_run(&code).max(2) + 1);
+        format!("{}{}\n{}\n{}", fence, b.language(), code, fence)
+      }
+      PageBlock::Preformatted(b) => plain_text(b.text()),
+      PageBlock::Divider(_) => "---".to_string(),
+      PageBlock::List(b) => b.items().iter().map(|item| {
+        let label = if markdown && !item.label().starts_with(|c: char| c.is_ascii_digit()) { "-" } else { item.label().as_str() };
+        let body = self.render_blocks(item.page_blocks());
+        indent(&format!("{} ", label), body.trim_end())
+      }).collect::<Vec<_>>().join("\n"),
+      PageBlock::BlockQuote(b) => self.quote(text(b.text()), text(b.credit())),
+      PageBlock::PullQuote(b) => self.quote(text(b.text()), text(b.credit())),
+      PageBlock::Animation(b) => media("Animation", false, b.animation().as_ref().and_then(|a| self.source(a.animation())), b.caption()),
+      PageBlock::Audio(b) => media("Audio", false, b.audio().as_ref().and_then(|a| self.source(a.audio())), b.caption()),
+      PageBlock::VoiceNote(b) => media("Voice note", false, b.voice_note().as_ref().and_then(|v| self.source(v.voice())), b.caption()),
+      PageBlock::Video(b) => media("Video", false, b.video().as_ref().and_then(|v| self.source(v.video())), b.caption()),
+      PageBlock::Photo(b) => media("Photo", true, b.photo().as_ref().and_then(largest_photo).and_then(|size| self.source(size.photo())), b.caption()),
+      PageBlock::Cover(b) => self.text_block(b.cover()),
+      PageBlock::Embedded(b) => {
+        let body = match (markdown, b.url().is_empty()) {
+          (_, true) => "[Embedded]".to_string(),
+          (true, false) => format!("[Embedded]({})", markdown_url(b.url())),
+          (false, false) => format!("[Embedded] {}", b.url()),
+        };
+        with_caption(body, b.caption())
+      }
+      PageBlock::EmbeddedPost(b) => {
+        let header = byline(b.author(), b.date());
+        let body = format!("{}\n\n{}", header, self.render_blocks(b.page_blocks()).trim_end());
+        with_caption(self.quote(body.trim().to_string(), String::new()), b.caption())
+      }
+      PageBlock::Collage(b) => with_caption(self.render_blocks(b.page_blocks()).trim_end().to_string(), b.caption()),
+      PageBlock::Slideshow(b) => with_caption(self.render_blocks(b.page_blocks()).trim_end().to_string(), b.caption()),
+      PageBlock::ChatLink(b) if markdown => format!("[{}](https://t.me/{})", escape_markdown(b.title()), markdown_url(b.username())),
+      PageBlock::ChatLink(b) => format!("{} (@{})", b.title(), b.username()),
+      PageBlock::Table(b) => self.text_table(b),
+      PageBlock::Details(b) => {
+        let header = if markdown { format!("**{}**", text(b.header())) } else { text(b.header()) };
+        format!("{}\n\n{}", header, self.render_blocks(b.page_blocks()).trim_end())
+      }
+      PageBlock::RelatedArticles(b) => {
+        let mut lines = vec![heading("###", b.header())];
+        for article in b.articles() {
+          let title = if article.title().is_empty() { article.url() } else { article.title() };
+          let mut line = if markdown { format!("- [{}]({})", escape_markdown(title), markdown_url(article.url())) } else { format!("- {} {}", title, article.url()) };
+          if !article.description().is_empty() {
+            line.push_str(&format!(" — {}", if markdown { escape_markdown(article.description()) } else { article.description().clone() }));
+          }
+          lines.push(line);
+        }
+        lines.join("\n")
+      }
+      PageBlock::Map(b) if markdown => with_caption(format!("[Map]({})", markdown_url(&map_url(b))), b.caption()),
+      PageBlock::Map(b) => with_caption(format!("[Map] {}, {}", b.location().latitude(), b.location().longitude()), b.caption()),
+      _ => String::new(),
+    }
+  }
+
+  fn quote(&self, text: String, credit: String) -> String {
+    let body = if credit.is_empty() { text } else { format!("{}\n— {}", text, credit) };
+    if self.format == Format::Markdown { indent_all("> ", &body) } else { body }
+  }
+
+  // tables become a grid with spanned cells repeated as empty cells
+  fn text_table(&self, table: &PageBlockTable) -> String {
+    let markdown = self.format == Format::Markdown;
+    let grid = table_grid(table.cells());
+    let mut lines = vec![];
+    let caption = self.render_text(table.caption());
+    if !caption.is_empty() {
+      lines.push(caption);
+      lines.push(String::new());
+    }
+    for (index, row) in grid.iter().enumerate() {
+      let cells: Vec<String> = row.iter().map(|cell| match cell {
+        Some(cell) => cell.text().as_ref().map(|t| self.render_text(t).replace('\n', " ")).unwrap_or_default(),
+        None => String::new(),
+      }).collect();
+      if markdown {
+        lines.push(format!("| {} |", cells.join(" | ")));
+        if index == 0 {
+          let aligns: Vec<&str> = row.iter().map(|cell| match cell.map(|c| c.align()) {
+            Some(PageBlockHorizontalAlignment::Center(_)) => ":---:",
+            Some(PageBlockHorizontalAlignment::Right(_)) => "---:",
+            _ => "---",
+          }).collect();
+          lines.push(format!("| {} |", aligns.join(" | ")));
+        }
+      } else {
+        lines.push(cells.join(" | "));
+      }
+    }
+    lines.join("\n")
+  }
+}
+
+/// Largest colspan of a table cell, the same limit browsers use
+const MAX_COLSPAN: usize = 1000;
+
+/// Cells of a table placed on a grid, `None` where a cell is covered by the colspan or rowspan of another cell.
+/// Colspans are clamped to 1000 and rowspans to the rows left in the table
+pub fn table_grid(rows: &[Vec<PageBlockTableCell>]) -> Vec<Vec<Option<&PageBlockTableCell>>> {
+  let mut grid: Vec<Vec<Option<Option<&PageBlockTableCell>>>> = vec![];
+  for (r, row) in rows.iter().enumerate() {
+    let mut c = 0;
+    for cell in row {
+      if grid.len() <= r { grid.resize(r + 1, vec![]) }
+      while grid[r].get(c).is_some_and(|taken| taken.is_some()) {
+        c += 1;
+      }
+      let colspan = (cell.colspan().max(1) as usize).min(MAX_COLSPAN);
+      let rowspan = (cell.rowspan().max(1) as usize).min(rows.len() - r);
+      for dr in 0..rowspan {
+        if grid.len() <= r + dr { grid.resize(r + dr + 1, vec![]) }
+        let line = &mut grid[r + dr];
+        if line.len() < c + colspan { line.resize(c + colspan, None) }
+        for (dc, slot) in line[c..c + colspan].iter_mut().enumerate() {
+          *slot = Some(if dr == 0 && dc == 0 { Some(cell) } else { None });
+        }
+      }
+      c += colspan;
+    }
+  }
+  let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+  grid.into_iter()
+    .map(|row| {
+      let mut row: Vec<Option<&PageBlockTableCell>> = row.into_iter().map(|slot| slot.flatten()).collect();
+      row.resize(width, None);
+      row
+    })
+    .collect()
+}
+
+fn inner_text(text: &RichText) -> Option<&RichText> {
+  Some(match text {
+    RichText::Fixed(t) => t.text(),
+    RichText::Underline(t) => t.text(),
+    RichText::Subscript(t) => t.text(),
+    RichText::Superscript(t) => t.text(),
+    RichText::Marked(t) => t.text(),
+    _ => return None,
+  })
+}
+
+fn anchor_href(link: &RichTextAnchorLink) -> String {
+  if link.name().is_empty() { link.url().clone() } else { format!("#{}", link.name()) }
+}
+
+fn largest_photo(photo: &Photo) -> Option<&PhotoSize> {
+  photo.sizes().iter().max_by_key(|size| size.width() * size.height())
+}
+
+fn map_url(map: &PageBlockMap) -> String {
+  let (latitude, longitude) = (map.location().latitude(), map.location().longitude());
+  format!("https://www.openstreetmap.org/?mlat={}&mlon={}#map={}/{}/{}", latitude, longitude, map.zoom(), latitude, longitude)
+}
+
+fn cite(credit: String) -> String {
+  if credit.is_empty() { credit } else { format!("<cite>{}</cite>", credit) }
+}
+
+fn byline(author: &str, date_: i64) -> String {
+  match (author.is_empty(), date_ > 0) {
+    (_, false) => author.to_string(),
+    (true, true) => date(date_),
+    (false, true) => format!("{} · {}", author, date(date_)),
+  }
+}
+
+/// Unix time as a `YYYY-MM-DD` UTC date
+fn date(unix_time: i64) -> String {
+  // days to civil date, from Howard Hinnant's date algorithms
+  let z = unix_time.div_euclid(86400) + 719_468;
+  let era = z.div_euclid(146_097);
+  let doe = z - era * 146_097;
+  let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp = (5 * doy + 2) / 153;
+  let day = doy - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+  format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn indent(first: &str, text: &str) -> String {
+  let pad = " ".repeat(first.chars().count());
+  text.lines().enumerate()
+    .map(|(index, line)| if index == 0 { format!("{}{}", first, line) } else if line.is_empty() { String::new() } else { format!("{}{}", pad, line) })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn indent_all(prefix: &str, text: &str) -> String {
+  text.lines().map(|line| format!("{}{}", prefix, line).trim_end().to_string()).collect::<Vec<_>>().join("\n")
+}
+
+fn escape(text: &str) -> String {
+  text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Markdown link destination, whitespace and parentheses would end it early
+fn markdown_url(url: &str) -> String {
+  let mut out = String::with_capacity(url.len());
+  for c in url.chars() {
+    if c.is_whitespace() || "()<>".contains(c) {
+      c.encode_utf8(&mut [0; 4]).bytes().for_each(|b| out.push_str(&format!("%{:02X}", b)));
+    } else {
+      out.push(c);
+    }
+  }
+  out
+}
+
+/// Markdown code span, delimited by more backticks than the longest run inside
+fn code_span(text: &str) -> String {
+  let fence = "`".repeat(longest_backtick_run(text) + 1);
+  let pad = if text.starts_with('`') || text.ends_with('`') { " " } else { "" };
+  format!("{}{}{}{}{}", fence, pad, text, pad, fence)
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+  text.split(|c| c != '`').map(|run| run.len()).max().unwrap_or(0)
+}
+
+fn escape_markdown(text: &str) -> String {
+  let mut out = String::with_capacity(text.len());
+  for c in text.chars() {
+    if "\\`*_[]<>#|~".contains(c) {
+      out.push('\\');
+    }
+    out.push(c);
+  }
+  out
+}
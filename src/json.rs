@@ -0,0 +1,114 @@
+//! Conversions between tdlib `JsonValue` and `serde_json::Value`, and user types through them.
+
+use std::convert::TryFrom;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+
+use crate::errors::*;
+use crate::types::*;
+
+// 2^63, the first f64 out of the i64 range
+const I64_END: f64 = 9_223_372_036_854_775_808.0;
+
+impl TryFrom<&JsonValue> for Value {
+  type Error = RTDError;
+
+  /// Fails for numbers that aren't finite, objects with a repeated key and values that aren't json
+  fn try_from(value: &JsonValue) -> RTDResult<Value> {
+    Ok(match value {
+      JsonValue::Null(_) => Value::Null,
+      JsonValue::Boolean(t) => Value::Bool(t.value()),
+      JsonValue::Number(t) => {
+        let number = t.value();
+        if number.fract() == 0.0 && (-I64_END..I64_END).contains(&number) {
+          Value::from(number as i64)
+        } else {
+          Number::from_f64(number).map(Value::Number)
+            .ok_or_else(|| RTDError::message(format!("json number {} is not finite", number)))?
+        }
+      }
+      JsonValue::String(t) => Value::String(t.value().clone()),
+      JsonValue::Array(t) => Value::Array(t.values().iter().map(Value::try_from).collect::<RTDResult<_>>()?),
+      JsonValue::Object(t) => {
+        let mut map = Map::new();
+        for member in t.members() {
+          let value = Value::try_from(member.value()).with_context(|| format!("json member {:?}", member.key()))?;
+          if map.insert(member.key().clone(), value).is_some() {
+            return Err(RTDError::message(format!("json object has key {:?} twice", member.key())));
+          }
+        }
+        Value::Object(map)
+      }
+      JsonValue::Unknown { td_type, .. } => return Err(RTDError::message(format!("{} is not a json value", td_type))),
+      _ => return Err(RTDError::message(format!("{} is not a json value", value.td_name()))),
+    })
+  }
+}
+
+impl TryFrom<JsonValue> for Value {
+  type Error = RTDError;
+
+  fn try_from(value: JsonValue) -> RTDResult<Value> { Value::try_from(&value) }
+}
+
+impl TryFrom<&Value> for JsonValue {
+  type Error = RTDError;
+
+  /// Fails for integers tdlib can't keep exactly, its numbers are doubles
+  fn try_from(value: &Value) -> RTDResult<JsonValue> {
+    Ok(match value {
+      Value::Null => JsonValue::null(JsonValueNull::builder().build()),
+      Value::Bool(value) => JsonValue::boolean(JsonValueBoolean::builder().value(*value).build()),
+      Value::Number(number) => JsonValue::number(JsonValueNumber::builder().value(to_f64(number)?).build()),
+      Value::String(value) => JsonValue::string(JsonValueString::builder().value(value).build()),
+      Value::Array(values) => JsonValue::array(
+        JsonValueArray::builder().values(values.iter().map(JsonValue::try_from).collect::<RTDResult<Vec<_>>>()?).build()
+      ),
+      Value::Object(map) => {
+        let members = map.iter()
+          .map(|(key, value)| {
+            let value = JsonValue::try_from(value).with_context(|| format!("json member {:?}", key))?;
+            Ok(JsonObjectMember::builder().key(key).value(value).build())
+          })
+          .collect::<RTDResult<Vec<_>>>()?;
+        JsonValue::object(JsonValueObject::builder().members(members).build())
+      }
+    })
+  }
+}
+
+impl TryFrom<Value> for JsonValue {
+  type Error = RTDError;
+
+  fn try_from(value: Value) -> RTDResult<JsonValue> { JsonValue::try_from(&value) }
+}
+
+fn to_f64(number: &Number) -> RTDResult<f64> {
+  let exact = if let Some(n) = number.as_i64() {
+    let f = n as f64;
+    (-I64_END..I64_END).contains(&f) && f as i64 == n
+  } else if let Some(n) = number.as_u64() {
+    let f = n as f64;
+    f < 2.0 * I64_END && f as u64 == n
+  } else {
+    true
+  };
+  match number.as_f64() {
+    Some(f) if exact => Ok(f),
+    _ => Err(RTDError::message(format!("json number {} can't be kept exactly as a double", number))),
+  }
+}
+
+impl JsonValue {
+  /// Deserialize into `T`, e.g. the response of `GetApplicationConfig`
+  pub fn decode<T: DeserializeOwned>(&self) -> RTDResult<T> {
+    serde_json::from_value(Value::try_from(self)?).with_context(|| format!("decode {}", std::any::type_name::<T>()))
+  }
+
+  /// Serialize `value`, e.g. the data of `SaveApplicationLogEvent`
+  pub fn encode<T: Serialize + ?Sized>(value: &T) -> RTDResult<JsonValue> {
+    JsonValue::try_from(serde_json::to_value(value)?)
+  }
+}
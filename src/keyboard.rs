@@ -0,0 +1,251 @@
+//! Builders of inline and reply keyboards, checked against the limits Telegram enforces.
+
+use crate::errors::*;
+use crate::types::*;
+
+/// Maximum size of the data of a callback button, in bytes
+pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+/// Maximum number of buttons in a row of an inline keyboard
+pub const MAX_INLINE_ROW_LEN: usize = 8;
+/// Maximum number of buttons in an inline keyboard
+pub const MAX_INLINE_BUTTONS: usize = 100;
+/// Maximum number of buttons in a row of a reply keyboard
+pub const MAX_REPLY_ROW_LEN: usize = 12;
+/// Maximum number of buttons in a reply keyboard
+pub const MAX_REPLY_BUTTONS: usize = 300;
+
+/// Builder of `ReplyMarkup::InlineKeyboard`, buttons are added to the last row
+#[derive(Debug, Clone, Default)]
+pub struct InlineKeyboard {
+  rows: Vec<Vec<InlineKeyboardButton>>,
+  columns: Option<usize>,
+}
+
+impl InlineKeyboard {
+  pub fn new() -> Self { Self::default() }
+
+  /// Start a new row
+  pub fn row(&mut self) -> &mut Self {
+    self.rows.push(vec![]);
+    self
+  }
+
+  /// Start a new row every `columns` buttons
+  pub fn columns(&mut self, columns: usize) -> &mut Self {
+    self.columns = Some(columns);
+    self
+  }
+
+  pub fn button<T: AsRef<InlineKeyboardButton>>(&mut self, button: T) -> &mut Self {
+    push(&mut self.rows, self.columns, button.as_ref().clone());
+    self
+  }
+
+  fn typed<S: AsRef<str>>(&mut self, text: S, type_: InlineKeyboardButtonType) -> &mut Self {
+    self.button(InlineKeyboardButton::builder().text(text).type_(type_).build())
+  }
+
+  /// Button sending `data` back to the bot in an `updateNewCallbackQuery`
+  pub fn callback<S: AsRef<str>, D: AsRef<[u8]>>(&mut self, text: S, data: D) -> &mut Self {
+    self.typed(text, InlineKeyboardButtonType::callback(InlineKeyboardButtonTypeCallback::builder().data(data).build()))
+  }
+
+  pub fn url<S: AsRef<str>, U: AsRef<str>>(&mut self, text: S, url: U) -> &mut Self {
+    self.typed(text, InlineKeyboardButtonType::url(InlineKeyboardButtonTypeUrl::builder().url(url).build()))
+  }
+
+  /// Button authorizing the user on a website with the bot's `id`
+  pub fn login_url<S: AsRef<str>, U: AsRef<str>>(&mut self, text: S, url: U, id: i64) -> &mut Self {
+    self.typed(text, InlineKeyboardButtonType::login_url(InlineKeyboardButtonTypeLoginUrl::builder().url(url).id(id).build()))
+  }
+
+  /// Button starting an inline query to the bot, in the current chat or in a chat chosen by the user
+  pub fn switch_inline<S: AsRef<str>, Q: AsRef<str>>(&mut self, text: S, query: Q, in_current_chat: bool) -> &mut Self {
+    let type_ = InlineKeyboardButtonTypeSwitchInline::builder().query(query).in_current_chat(in_current_chat).build();
+    self.typed(text, InlineKeyboardButtonType::switch_inline(type_))
+  }
+
+  pub fn callback_game<S: AsRef<str>>(&mut self, text: S) -> &mut Self {
+    self.typed(text, InlineKeyboardButtonType::callback_game(InlineKeyboardButtonTypeCallbackGame::builder().build()))
+  }
+
+  pub fn buy<S: AsRef<str>>(&mut self, text: S) -> &mut Self {
+    self.typed(text, InlineKeyboardButtonType::buy(InlineKeyboardButtonTypeBuy::builder().build()))
+  }
+
+  /// Keyboard markup, empty rows are dropped
+  pub fn build(&self) -> RTDResult<ReplyMarkup> {
+    let rows: Vec<Vec<InlineKeyboardButton>> = self.rows.iter().filter(|row| !row.is_empty()).cloned().collect();
+    let markup = ReplyMarkup::inline_keyboard(ReplyMarkupInlineKeyboard::builder().rows(rows).build());
+    validate(&markup)?;
+    Ok(markup)
+  }
+}
+
+/// Builder of `ReplyMarkup::ShowKeyboard`, buttons are added to the last row
+#[derive(Debug, Clone, Default)]
+pub struct ReplyKeyboard {
+  rows: Vec<Vec<KeyboardButton>>,
+  columns: Option<usize>,
+  resize_keyboard: bool,
+  one_time: bool,
+  is_personal: bool,
+}
+
+impl ReplyKeyboard {
+  pub fn new() -> Self { Self::default() }
+
+  /// Start a new row
+  pub fn row(&mut self) -> &mut Self {
+    self.rows.push(vec![]);
+    self
+  }
+
+  /// Start a new row every `columns` buttons
+  pub fn columns(&mut self, columns: usize) -> &mut Self {
+    self.columns = Some(columns);
+    self
+  }
+
+  /// Fit the keyboard height to its buttons
+  pub fn resize_keyboard(&mut self, resize_keyboard: bool) -> &mut Self {
+    self.resize_keyboard = resize_keyboard;
+    self
+  }
+
+  /// Hide the keyboard after a button is pressed
+  pub fn one_time(&mut self, one_time: bool) -> &mut Self {
+    self.one_time = one_time;
+    self
+  }
+
+  /// Show the keyboard only to mentioned users and to the author of the replied message
+  pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
+    self.is_personal = is_personal;
+    self
+  }
+
+  pub fn button<T: AsRef<KeyboardButton>>(&mut self, button: T) -> &mut Self {
+    push(&mut self.rows, self.columns, button.as_ref().clone());
+    self
+  }
+
+  fn typed<S: AsRef<str>>(&mut self, text: S, type_: KeyboardButtonType) -> &mut Self {
+    self.button(KeyboardButton::builder().text(text).type_(type_).build())
+  }
+
+  /// Button sending its text
+  pub fn text<S: AsRef<str>>(&mut self, text: S) -> &mut Self {
+    self.typed(text, KeyboardButtonType::text(KeyboardButtonTypeText::builder().build()))
+  }
+
+  pub fn request_phone_number<S: AsRef<str>>(&mut self, text: S) -> &mut Self {
+    self.typed(text, KeyboardButtonType::request_phone_number(KeyboardButtonTypeRequestPhoneNumber::builder().build()))
+  }
+
+  pub fn request_location<S: AsRef<str>>(&mut self, text: S) -> &mut Self {
+    self.typed(text, KeyboardButtonType::request_location(KeyboardButtonTypeRequestLocation::builder().build()))
+  }
+
+  /// Button asking the user to create a poll, at most one of `force_regular` and `force_quiz` can be set
+  pub fn request_poll<S: AsRef<str>>(&mut self, text: S, force_regular: bool, force_quiz: bool) -> &mut Self {
+    let type_ = KeyboardButtonTypeRequestPoll::builder().force_regular(force_regular).force_quiz(force_quiz).build();
+    self.typed(text, KeyboardButtonType::request_poll(type_))
+  }
+
+  /// Keyboard markup, empty rows are dropped
+  pub fn build(&self) -> RTDResult<ReplyMarkup> {
+    let rows: Vec<Vec<KeyboardButton>> = self.rows.iter().filter(|row| !row.is_empty()).cloned().collect();
+    let markup = ReplyMarkup::show_keyboard(ReplyMarkupShowKeyboard::builder()
+      .rows(rows)
+      .resize_keyboard(self.resize_keyboard)
+      .one_time(self.one_time)
+      .is_personal(self.is_personal)
+      .build());
+    validate(&markup)?;
+    Ok(markup)
+  }
+}
+
+/// Markup hiding the reply keyboard
+pub fn remove_keyboard(is_personal: bool) -> ReplyMarkup {
+  ReplyMarkup::remove_keyboard(ReplyMarkupRemoveKeyboard::builder().is_personal(is_personal).build())
+}
+
+/// Markup asking the client to reply to the message
+pub fn force_reply(is_personal: bool) -> ReplyMarkup {
+  ReplyMarkup::force_reply(ReplyMarkupForceReply::builder().is_personal(is_personal).build())
+}
+
+fn push<T>(rows: &mut Vec<Vec<T>>, columns: Option<usize>, button: T) {
+  match rows.last_mut() {
+    Some(row) if columns.map_or(true, |columns| row.len() < columns) => row.push(button),
+    _ => rows.push(vec![button]),
+  }
+}
+
+/// Check a keyboard against the limits Telegram enforces
+pub fn validate(markup: &ReplyMarkup) -> RTDResult<()> {
+  match markup {
+    ReplyMarkup::InlineKeyboard(keyboard) => {
+      check_layout(keyboard.rows(), MAX_INLINE_ROW_LEN, MAX_INLINE_BUTTONS)?;
+      for_each_button(keyboard.rows(), validate_inline_button)
+    }
+    ReplyMarkup::ShowKeyboard(keyboard) => {
+      check_layout(keyboard.rows(), MAX_REPLY_ROW_LEN, MAX_REPLY_BUTTONS)?;
+      for_each_button(keyboard.rows(), validate_button)
+    }
+    _ => Ok(()),
+  }
+}
+
+fn check_layout<T>(rows: &[Vec<T>], max_row_len: usize, max_buttons: usize) -> RTDResult<()> {
+  let buttons: usize = rows.iter().map(Vec::len).sum();
+  if buttons == 0 {
+    return Err(RTDError::custom("keyboard has no buttons"));
+  }
+  if buttons > max_buttons {
+    return Err(RTDError::message(format!("keyboard has {} buttons, at most {} are allowed", buttons, max_buttons)));
+  }
+  if let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() > max_row_len) {
+    return Err(RTDError::message(format!("row {} has {} buttons, at most {} are allowed", index, row.len(), max_row_len)));
+  }
+  Ok(())
+}
+
+fn for_each_button<T, F: Fn(&T) -> RTDResult<()>>(rows: &[Vec<T>], check: F) -> RTDResult<()> {
+  for (r, row) in rows.iter().enumerate() {
+    for (c, button) in row.iter().enumerate() {
+      check(button).with_context(|| format!("row {} button {}", r, c))?;
+    }
+  }
+  Ok(())
+}
+
+fn validate_inline_button(button: &InlineKeyboardButton) -> RTDResult<()> {
+  if button.text().is_empty() {
+    return Err(RTDError::custom("button text is empty"));
+  }
+  match button.type_() {
+    InlineKeyboardButtonType::Callback(t) if t.data().is_empty() || t.data().len() > MAX_CALLBACK_DATA_LEN => Err(RTDError::message(
+      format!("callback data has {} bytes, 1 to {} are allowed", t.data().len(), MAX_CALLBACK_DATA_LEN)
+    )),
+    InlineKeyboardButtonType::Url(t) if t.url().is_empty() => Err(RTDError::custom("button url is empty")),
+    InlineKeyboardButtonType::LoginUrl(t) if !t.url().starts_with("https://") => Err(RTDError::custom("login url must be https")),
+    InlineKeyboardButtonType::_Default(_) => Err(RTDError::custom("button has no type")),
+    _ => Ok(()),
+  }
+}
+
+fn validate_button(button: &KeyboardButton) -> RTDResult<()> {
+  if button.text().is_empty() {
+    return Err(RTDError::custom("button text is empty"));
+  }
+  match button.type_() {
+    KeyboardButtonType::RequestPoll(t) if t.force_regular() && t.force_quiz() => {
+      Err(RTDError::custom("poll button can't force both regular and quiz polls"))
+    }
+    KeyboardButtonType::_Default(_) => Err(RTDError::custom("button has no type")),
+    _ => Ok(()),
+  }
+}
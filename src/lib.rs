@@ -4,5 +4,23 @@ extern crate serde_derive;
 #[cfg(feature = "sys")]
 pub use rtdlib_sys::Tdlib;
 
+// generated code, keep the shape of the generator output
+#[allow(clippy::field_reassign_with_default, clippy::match_like_matches_macro, clippy::needless_lifetimes, clippy::borrowed_box, clippy::large_enum_variant,
+  clippy::four_forward_slashes, clippy::redundant_pattern_matching, clippy::option_map_or_none, clippy::useless_asref)]
 pub mod types;
 pub mod errors;
+pub mod client;
+pub mod auth;
+pub mod router;
+pub mod state;
+pub mod chat_list;
+pub mod text;
+pub mod instant_view;
+pub mod input;
+pub mod keyboard;
+pub mod callback_data;
+pub mod file_manager;
+pub mod file_generator;
+pub mod options;
+pub mod json;
+mod base64;
@@ -0,0 +1,209 @@
+//! Typed tdlib options, kept current from `UpdateOption`, see https://core.telegram.org/tdlib/options
+
+use std::collections::HashMap;
+
+use crate::types::*;
+
+impl From<bool> for OptionValue {
+  fn from(value: bool) -> Self { OptionValue::boolean(OptionValueBoolean::builder().value(value).build()) }
+}
+
+impl From<i64> for OptionValue {
+  fn from(value: i64) -> Self { OptionValue::integer(OptionValueInteger::builder().value(value).build()) }
+}
+
+impl From<&str> for OptionValue {
+  fn from(value: &str) -> Self { OptionValue::string(OptionValueString::builder().value(value).build()) }
+}
+
+impl From<String> for OptionValue {
+  fn from(value: String) -> Self { OptionValue::from(value.as_str()) }
+}
+
+macro_rules! read_options {
+  ($($(#[$doc:meta])* ($name:ident, $kind:ident, $ty:ty));*;) => {
+    impl Options {
+      $(
+        $(#[$doc])*
+        pub fn $name(&self) -> Option<$ty> { self.$kind(stringify!($name)) }
+      )*
+    }
+  }
+}
+
+macro_rules! write_options {
+  ($($(#[$doc:meta])* ($name:ident, $setter:ident, $kind:ident, $ty:ty));*;) => {
+    impl Options {
+      $(
+        $(#[$doc])*
+        pub fn $name(&self) -> Option<$ty> { self.$kind(stringify!($name)) }
+
+        #[doc = concat!("Request setting `", stringify!($name), "`")]
+        pub fn $setter(value: $ty) -> SetOption { Options::set(stringify!($name), value) }
+      )*
+    }
+  }
+}
+
+/// Values of tdlib options by name. Known options have typed accessors, `None` until tdlib sent them or
+/// when their value is empty, setters return the `SetOption` request to send.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+  values: HashMap<String, OptionValue>,
+}
+
+impl Options {
+  pub fn new() -> Self { Self::default() }
+
+  /// Apply an update, true if it is `UpdateOption`
+  pub fn update(&mut self, update: &Update) -> bool {
+    match update.as_option() {
+      Some(t) => {
+        self.insert(t.name(), t.value().clone());
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Store the value of an option, an empty value removes it
+  pub fn insert<S: AsRef<str>>(&mut self, name: S, value: OptionValue) {
+    match value {
+      OptionValue::Empty(_) => { self.values.remove(name.as_ref()); }
+      value => { self.values.insert(name.as_ref().to_string(), value); }
+    }
+  }
+
+  /// Value of any option by name
+  pub fn get<S: AsRef<str>>(&self, name: S) -> Option<&OptionValue> { self.values.get(name.as_ref()) }
+
+  /// Names and values of all options with a value
+  pub fn iter(&self) -> impl Iterator<Item = (&String, &OptionValue)> { self.values.iter() }
+
+  /// Value of a boolean option by name, `None` if it isn't set or not boolean
+  pub fn boolean<S: AsRef<str>>(&self, name: S) -> Option<bool> {
+    self.get(name).and_then(|v| v.as_boolean()).map(|v| v.value())
+  }
+
+  /// Value of an integer option by name, `None` if it isn't set or not integer
+  pub fn integer<S: AsRef<str>>(&self, name: S) -> Option<i64> {
+    self.get(name).and_then(|v| v.as_integer()).map(|v| v.value())
+  }
+
+  /// Value of a string option by name, `None` if it isn't set or not string
+  pub fn string<S: AsRef<str>>(&self, name: S) -> Option<&str> {
+    self.get(name).and_then(|v| v.as_string()).map(|v| v.value().as_str())
+  }
+
+  /// Request setting any option by name
+  pub fn set<S: AsRef<str>, V: Into<OptionValue>>(name: S, value: V) -> SetOption {
+    SetOption::builder().name(name).value(value.into()).build()
+  }
+
+  /// Request resetting any option by name to its default value
+  pub fn reset<S: AsRef<str>>(name: S) -> SetOption {
+    SetOption::builder().name(name).value(OptionValue::empty(OptionValueEmpty::builder().build())).build()
+  }
+}
+
+read_options!(
+  /// Username of the bot used for animation search
+  (animation_search_bot_username, string, &str);
+  /// Name of the sticker set of animated emojis
+  (animated_emoji_sticker_set_name, string, &str);
+  /// Point in time (Unix timestamp) when the user was authorized
+  (authorization_date, integer, i64);
+  /// Maximum number of members in a basic group
+  (basic_group_size_max, integer, i64);
+  /// Maximum time to wait for a call to be connected, in milliseconds
+  (call_connect_timeout_ms, integer, i64);
+  /// Maximum time to wait for a packet in a call, in milliseconds
+  (call_packet_timeout_ms, integer, i64);
+  /// The option `ignore_sensitive_content_restrictions` can be changed
+  (can_ignore_sensitive_content_restrictions, boolean, bool);
+  /// Commit hash of the tdlib build
+  (commit_hash, string, &str);
+  /// Identifier of the enabled proxy
+  (enabled_proxy_id, integer, i64);
+  /// The network is likely to be blocked
+  (expect_blocking, boolean, bool);
+  /// Maximum number of favorite stickers
+  (favorite_stickers_limit, integer, i64);
+  /// Maximum number of messages forwarded at once
+  (forwarded_message_count_max, integer, i64);
+  /// Maximum length of a message caption
+  (message_caption_length_max, integer, i64);
+  /// Maximum length of a message text
+  (message_text_length_max, integer, i64);
+  /// Identifier of the current user
+  (my_id, integer, i64);
+  /// Username of the bot used for photo search
+  (photo_search_bot_username, string, &str);
+  /// Maximum number of pinned chats in the archive chat list
+  (pinned_archived_chat_count_max, integer, i64);
+  /// Maximum number of pinned chats in the main chat list
+  (pinned_chat_count_max, integer, i64);
+  /// Identifier of the language pack suggested for the user by the server
+  (suggested_language_pack_id, string, &str);
+  /// Maximum number of members in a supergroup
+  (supergroup_size_max, integer, i64);
+  /// Base url of t.me links
+  (t_me_url, string, &str);
+  /// Tdlib connects to the test servers
+  (test_mode, boolean, bool);
+  /// Current Unix timestamp known to the server
+  (unix_time, integer, i64);
+  /// Offset of the local time from UTC, in seconds
+  (utc_time_offset, integer, i64);
+  /// Username of the bot used for venue search
+  (venue_search_bot_username, string, &str);
+  /// Version of tdlib
+  (version, string, &str);
+);
+
+write_options!(
+  /// Notifications about contacts joining telegram are disabled
+  (disable_contact_registered_notifications, set_disable_contact_registered_notifications, boolean, bool);
+  /// Network statistics are kept only in memory
+  (disable_persistent_network_statistics, set_disable_persistent_network_statistics, boolean, bool);
+  /// Notifications about sent scheduled messages are disabled
+  (disable_sent_scheduled_message_notifications, set_disable_sent_scheduled_message_notifications, boolean, bool);
+  /// Tdlib doesn't protect against changes of the system time
+  (disable_time_adjustment_protection, set_disable_time_adjustment_protection, boolean, bool);
+  /// Top chats are not collected
+  (disable_top_chats, set_disable_top_chats, boolean, bool);
+  /// Updates not needed for a bot or background work are not sent
+  (ignore_background_updates, set_ignore_background_updates, boolean, bool);
+  /// Original file names are ignored
+  (ignore_file_names, set_ignore_file_names, boolean, bool);
+  /// Thumbnails of inline query results are not sent
+  (ignore_inline_thumbnails, set_ignore_inline_thumbnails, boolean, bool);
+  /// Platform restrictions of chats are ignored
+  (ignore_platform_restrictions, set_ignore_platform_restrictions, boolean, bool);
+  /// Sensitive content is shown
+  (ignore_sensitive_content_restrictions, set_ignore_sensitive_content_restrictions, boolean, bool);
+  /// The location of the user is visible to users nearby
+  (is_location_visible, set_is_location_visible, boolean, bool);
+  /// Path of the language pack database
+  (language_pack_database_path, set_language_pack_database_path, string, &str);
+  /// Identifier of the current language pack
+  (language_pack_id, set_language_pack_id, string, &str);
+  /// Localization target of the application
+  (localization_target, set_localization_target, string, &str);
+  /// Seconds after which unused messages are unloaded from memory
+  (message_unload_delay, set_message_unload_delay, integer, i64);
+  /// Maximum number of notification groups shown
+  (notification_group_count_max, set_notification_group_count_max, integer, i64);
+  /// Maximum number of notifications shown in a group
+  (notification_group_size_max, set_notification_group_size_max, integer, i64);
+  /// The user is online
+  (online, set_online, boolean, bool);
+  /// IPv6 addresses are preferred
+  (prefer_ipv6, set_prefer_ipv6, boolean, bool);
+  /// Perfect forward secrecy is used for cloud connections
+  (use_pfs, set_use_pfs, boolean, bool);
+  /// Quick acknowledgements of messages are used
+  (use_quick_ack, set_use_quick_ack, boolean, bool);
+  /// Tdlib deletes old files to keep the storage small
+  (use_storage_optimizer, set_use_storage_optimizer, boolean, bool);
+);
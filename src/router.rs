@@ -0,0 +1,297 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+use crate::types::*;
+
+/// Future of an async update handler
+pub type HandlerFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Types a handler can be registered for, every `Update` variant and `Update` itself for all updates
+pub trait UpdateKind: 'static {
+  /// The value of this type inside `update`, if `update` is this variant
+  fn from_update(update: &Update) -> Option<&Self>;
+}
+
+impl UpdateKind for Update {
+  fn from_update(update: &Update) -> Option<&Self> { Some(update) }
+}
+
+macro_rules! update_kind {
+  ($(($variant:ident, $type_name:ident));*;) => {
+    $(
+      impl UpdateKind for $type_name {
+        fn from_update(update: &Update) -> Option<&Self> {
+          if let Update::$variant(t) = update { Some(t) } else { None }
+        }
+      }
+    )*
+  }
+}
+
+update_kind!(
+  (ActiveNotifications, UpdateActiveNotifications);
+  (AnimationSearchParameters, UpdateAnimationSearchParameters);
+  (AuthorizationState, UpdateAuthorizationState);
+  (BasicGroup, UpdateBasicGroup);
+  (BasicGroupFullInfo, UpdateBasicGroupFullInfo);
+  (Call, UpdateCall);
+  (ChatActionBar, UpdateChatActionBar);
+  (ChatDefaultDisableNotification, UpdateChatDefaultDisableNotification);
+  (ChatDraftMessage, UpdateChatDraftMessage);
+  (ChatFilters, UpdateChatFilters);
+  (ChatHasScheduledMessages, UpdateChatHasScheduledMessages);
+  (ChatIsMarkedAsUnread, UpdateChatIsMarkedAsUnread);
+  (ChatLastMessage, UpdateChatLastMessage);
+  (ChatNotificationSettings, UpdateChatNotificationSettings);
+  (ChatOnlineMemberCount, UpdateChatOnlineMemberCount);
+  (ChatPermissions, UpdateChatPermissions);
+  (ChatPhoto, UpdateChatPhoto);
+  (ChatPinnedMessage, UpdateChatPinnedMessage);
+  (ChatPosition, UpdateChatPosition);
+  (ChatReadInbox, UpdateChatReadInbox);
+  (ChatReadOutbox, UpdateChatReadOutbox);
+  (ChatReplyMarkup, UpdateChatReplyMarkup);
+  (ChatTitle, UpdateChatTitle);
+  (ChatUnreadMentionCount, UpdateChatUnreadMentionCount);
+  (ConnectionState, UpdateConnectionState);
+  (DeleteMessages, UpdateDeleteMessages);
+  (DiceEmojis, UpdateDiceEmojis);
+  (FavoriteStickers, UpdateFavoriteStickers);
+  (File, UpdateFile);
+  (FileGenerationStart, UpdateFileGenerationStart);
+  (FileGenerationStop, UpdateFileGenerationStop);
+  (HavePendingNotifications, UpdateHavePendingNotifications);
+  (InstalledStickerSets, UpdateInstalledStickerSets);
+  (LanguagePackStrings, UpdateLanguagePackStrings);
+  (MessageContent, UpdateMessageContent);
+  (MessageContentOpened, UpdateMessageContentOpened);
+  (MessageEdited, UpdateMessageEdited);
+  (MessageLiveLocationViewed, UpdateMessageLiveLocationViewed);
+  (MessageMentionRead, UpdateMessageMentionRead);
+  (MessageSendAcknowledged, UpdateMessageSendAcknowledged);
+  (MessageSendFailed, UpdateMessageSendFailed);
+  (MessageSendSucceeded, UpdateMessageSendSucceeded);
+  (MessageViews, UpdateMessageViews);
+  (NewCallSignalingData, UpdateNewCallSignalingData);
+  (NewCallbackQuery, UpdateNewCallbackQuery);
+  (NewChat, UpdateNewChat);
+  (NewChosenInlineResult, UpdateNewChosenInlineResult);
+  (NewCustomEvent, UpdateNewCustomEvent);
+  (NewCustomQuery, UpdateNewCustomQuery);
+  (NewInlineCallbackQuery, UpdateNewInlineCallbackQuery);
+  (NewInlineQuery, UpdateNewInlineQuery);
+  (NewMessage, UpdateNewMessage);
+  (NewPreCheckoutQuery, UpdateNewPreCheckoutQuery);
+  (NewShippingQuery, UpdateNewShippingQuery);
+  (Notification, UpdateNotification);
+  (NotificationGroup, UpdateNotificationGroup);
+  (Option, UpdateOption);
+  (Poll, UpdatePoll);
+  (PollAnswer, UpdatePollAnswer);
+  (RecentStickers, UpdateRecentStickers);
+  (SavedAnimations, UpdateSavedAnimations);
+  (ScopeNotificationSettings, UpdateScopeNotificationSettings);
+  (SecretChat, UpdateSecretChat);
+  (SelectedBackground, UpdateSelectedBackground);
+  (ServiceNotification, UpdateServiceNotification);
+  (StickerSet, UpdateStickerSet);
+  (SuggestedActions, UpdateSuggestedActions);
+  (Supergroup, UpdateSupergroup);
+  (SupergroupFullInfo, UpdateSupergroupFullInfo);
+  (TermsOfService, UpdateTermsOfService);
+  (TrendingStickerSets, UpdateTrendingStickerSets);
+  (UnreadChatCount, UpdateUnreadChatCount);
+  (UnreadMessageCount, UpdateUnreadMessageCount);
+  (User, UpdateUser);
+  (UserChatAction, UpdateUserChatAction);
+  (UserFullInfo, UpdateUserFullInfo);
+  (UserPrivacySettingRules, UpdateUserPrivacySettingRules);
+  (UserStatus, UpdateUserStatus);
+  (UsersNearby, UpdateUsersNearby);
+);
+
+type Filter = Box<dyn Fn(&Update) -> bool>;
+
+enum Call {
+  Sync(Box<dyn FnMut(&Update)>),
+  Async(Box<dyn FnMut(&Update) -> HandlerFuture>),
+}
+
+struct Handler {
+  priority: i32,
+  filters: Vec<Filter>,
+  call: Call,
+}
+
+impl Handler {
+  fn accept(&self, update: &Update) -> bool {
+    self.filters.iter().all(|filter| filter(update))
+  }
+}
+
+/// Dispatch updates to handlers registered per update type. Handlers run by priority, the higher first,
+/// handlers with the same priority run in registration order.
+#[derive(Default)]
+pub struct UpdateRouter {
+  handlers: Vec<Handler>,
+}
+
+/// Registered handler, set its priority and filters
+pub struct HandlerRef<'a, T: UpdateKind> {
+  handler: &'a mut Handler,
+  _kind: PhantomData<T>,
+}
+
+impl<'a, T: UpdateKind> HandlerRef<'a, T> {
+  /// Handlers with higher priority run first, default 0
+  pub fn priority(self, priority: i32) -> Self {
+    self.handler.priority = priority;
+    self
+  }
+
+  /// Only call the handler when `filter` accepts the update
+  pub fn filter<F: Fn(&T) -> bool + 'static>(self, filter: F) -> Self {
+    self.handler.filters.push(Box::new(move |update| T::from_update(update).is_some_and(&filter)));
+    self
+  }
+
+  /// Only call the handler when `filter` accepts the update, for filters working on any `Update`, see `filters`
+  pub fn when<F: Fn(&Update) -> bool + 'static>(self, filter: F) -> Self {
+    self.handler.filters.push(Box::new(filter));
+    self
+  }
+}
+
+impl UpdateRouter {
+  pub fn new() -> Self { Self::default() }
+
+  /// Register a handler for updates of type `T`
+  pub fn on<T: UpdateKind, F: FnMut(&T) + 'static>(&mut self, mut fnc: F) -> HandlerRef<'_, T> {
+    self.push(Call::Sync(Box::new(move |update| {
+      if let Some(t) = T::from_update(update) { fnc(t) }
+    })))
+  }
+
+  /// Register an async handler for updates of type `T`, the handler gets its own copy of the update
+  pub fn on_async<T, F, Fut>(&mut self, mut fnc: F) -> HandlerRef<'_, T>
+    where T: UpdateKind + Clone,
+          F: FnMut(T) -> Fut + 'static,
+          Fut: Future<Output = ()> + Send + 'static {
+    self.push(Call::Async(Box::new(move |update| {
+      match T::from_update(update) {
+        Some(t) => Box::pin(fnc(t.clone())),
+        None => Box::pin(async {}),
+      }
+    })))
+  }
+
+  fn push<T: UpdateKind>(&mut self, call: Call) -> HandlerRef<'_, T> {
+    self.handlers.push(Handler {
+      priority: 0,
+      filters: vec![Box::new(|update| T::from_update(update).is_some())],
+      call,
+    });
+    let handler = self.handlers.last_mut().unwrap();
+    HandlerRef { handler, _kind: PhantomData }
+  }
+
+  fn matched(&mut self, update: &Update) -> Vec<&mut Handler> {
+    let mut handlers: Vec<&mut Handler> = self.handlers.iter_mut().filter(|h| h.accept(update)).collect();
+    // stable sort, the same priority keeps registration order
+    handlers.sort_by_key(|h| std::cmp::Reverse(h.priority));
+    handlers
+  }
+
+  /// Run sync handlers matching `update`. Futures of matching async handlers are returned in priority order
+  /// for the caller to spawn or await.
+  pub fn dispatch(&mut self, update: &Update) -> Vec<HandlerFuture> {
+    let mut futures = vec![];
+    for handler in self.matched(update) {
+      match &mut handler.call {
+        Call::Sync(fnc) => fnc(update),
+        Call::Async(fnc) => futures.push(fnc(update)),
+      }
+    }
+    futures
+  }
+
+  /// Run all handlers matching `update` one by one, async handlers are awaited before the next handler runs
+  pub async fn dispatch_async(&mut self, update: &Update) {
+    for handler in self.matched(update) {
+      match &mut handler.call {
+        Call::Sync(fnc) => fnc(update),
+        Call::Async(fnc) => fnc(update).await,
+      }
+    }
+  }
+}
+
+/// Filters for `HandlerRef::when`
+pub mod filters {
+  use crate::types::*;
+
+  /// Chat the update belongs to
+  pub fn chat_id(update: &Update) -> Option<i64> {
+    match update {
+      Update::NewMessage(t) => Some(t.message().chat_id()),
+      Update::MessageSendSucceeded(t) => Some(t.message().chat_id()),
+      Update::MessageSendFailed(t) => Some(t.message().chat_id()),
+      Update::NewChat(t) => Some(t.chat().id()),
+      Update::ChatActionBar(t) => Some(t.chat_id()),
+      Update::ChatDefaultDisableNotification(t) => Some(t.chat_id()),
+      Update::ChatDraftMessage(t) => Some(t.chat_id()),
+      Update::ChatHasScheduledMessages(t) => Some(t.chat_id()),
+      Update::ChatIsMarkedAsUnread(t) => Some(t.chat_id()),
+      Update::ChatLastMessage(t) => Some(t.chat_id()),
+      Update::ChatNotificationSettings(t) => Some(t.chat_id()),
+      Update::ChatOnlineMemberCount(t) => Some(t.chat_id()),
+      Update::ChatPermissions(t) => Some(t.chat_id()),
+      Update::ChatPhoto(t) => Some(t.chat_id()),
+      Update::ChatPinnedMessage(t) => Some(t.chat_id()),
+      Update::ChatPosition(t) => Some(t.chat_id()),
+      Update::ChatReadInbox(t) => Some(t.chat_id()),
+      Update::ChatReadOutbox(t) => Some(t.chat_id()),
+      Update::ChatReplyMarkup(t) => Some(t.chat_id()),
+      Update::ChatTitle(t) => Some(t.chat_id()),
+      Update::ChatUnreadMentionCount(t) => Some(t.chat_id()),
+      Update::DeleteMessages(t) => Some(t.chat_id()),
+      Update::MessageContent(t) => Some(t.chat_id()),
+      Update::MessageContentOpened(t) => Some(t.chat_id()),
+      Update::MessageEdited(t) => Some(t.chat_id()),
+      Update::MessageLiveLocationViewed(t) => Some(t.chat_id()),
+      Update::MessageMentionRead(t) => Some(t.chat_id()),
+      Update::MessageSendAcknowledged(t) => Some(t.chat_id()),
+      Update::MessageViews(t) => Some(t.chat_id()),
+      Update::NewCallbackQuery(t) => Some(t.chat_id()),
+      Update::NotificationGroup(t) => Some(t.chat_id()),
+      Update::UserChatAction(t) => Some(t.chat_id()),
+      _ => None,
+    }
+  }
+
+  /// Message the update carries
+  pub fn message(update: &Update) -> Option<&Message> {
+    match update {
+      Update::NewMessage(t) => Some(t.message()),
+      Update::MessageSendSucceeded(t) => Some(t.message()),
+      Update::MessageSendFailed(t) => Some(t.message()),
+      _ => None,
+    }
+  }
+
+  /// Updates of the chat `id`
+  pub fn in_chat(id: i64) -> impl Fn(&Update) -> bool {
+    move |update| chat_id(update) == Some(id)
+  }
+
+  /// Updates carrying a message sent by the current user when `outgoing` is true, or received otherwise
+  pub fn outgoing(outgoing: bool) -> impl Fn(&Update) -> bool {
+    move |update| message(update).is_some_and(|m| m.is_outgoing() == outgoing)
+  }
+
+  /// Updates carrying a message with content accepted by `filter`, e.g. `content(MessageContent::is_message_text)`
+  pub fn content<F: Fn(&MessageContent) -> bool>(filter: F) -> impl Fn(&Update) -> bool {
+    move |update| message(update).is_some_and(|m| filter(m.content()))
+  }
+}
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use crate::chat_list::ChatListKey;
+use crate::types::*;
+
+/// Cached object changed by an update, with its id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateChange {
+  Chat(i64),
+  User(i64),
+  UserFullInfo(i64),
+  BasicGroup(i64),
+  BasicGroupFullInfo(i64),
+  Supergroup(i64),
+  SupergroupFullInfo(i64),
+  SecretChat(i64),
+  File(i64),
+}
+
+type Listener = Box<dyn FnMut(&StateStore, StateChange) + Send>;
+
+/// Cache of chats, users, groups, secret chats and files kept up to date by applying every `Update`.
+/// Only the updates are used, so replaying the same update stream always gives the same state.
+#[derive(Default)]
+pub struct StateStore {
+  chats: HashMap<i64, Chat>,
+  users: HashMap<i64, User>,
+  user_full_infos: HashMap<i64, UserFullInfo>,
+  basic_groups: HashMap<i64, BasicGroup>,
+  basic_group_full_infos: HashMap<i64, BasicGroupFullInfo>,
+  supergroups: HashMap<i64, Supergroup>,
+  supergroup_full_infos: HashMap<i64, SupergroupFullInfo>,
+  secret_chats: HashMap<i64, SecretChat>,
+  files: HashMap<i64, File>,
+  listeners: Vec<Listener>,
+}
+
+impl StateStore {
+  pub fn new() -> Self { Self::default() }
+
+  /// Call `listener` after every change, with the store already updated
+  pub fn subscribe<F: FnMut(&StateStore, StateChange) + Send + 'static>(&mut self, listener: F) {
+    self.listeners.push(Box::new(listener));
+  }
+
+  /// Apply an update, `None` if it doesn't change any cached object
+  pub fn apply(&mut self, update: &Update) -> Option<StateChange> {
+    let change = self.change(update);
+    if let Some(change) = change {
+      let mut listeners = std::mem::take(&mut self.listeners);
+      listeners.iter_mut().for_each(|listener| listener(self, change));
+      listeners.append(&mut self.listeners);
+      self.listeners = listeners;
+    }
+    change
+  }
+
+  /// Apply updates in order, returns all changes
+  pub fn apply_all<'a, I: IntoIterator<Item = &'a Update>>(&mut self, updates: I) -> Vec<StateChange> {
+    updates.into_iter().filter_map(|update| self.apply(update)).collect()
+  }
+
+  pub fn chat(&self, chat_id: i64) -> Option<&Chat> { self.chats.get(&chat_id) }
+  pub fn chats(&self) -> impl Iterator<Item = &Chat> { self.chats.values() }
+  pub fn user(&self, user_id: i64) -> Option<&User> { self.users.get(&user_id) }
+  pub fn users(&self) -> impl Iterator<Item = &User> { self.users.values() }
+  pub fn user_full_info(&self, user_id: i64) -> Option<&UserFullInfo> { self.user_full_infos.get(&user_id) }
+  pub fn basic_group(&self, basic_group_id: i64) -> Option<&BasicGroup> { self.basic_groups.get(&basic_group_id) }
+  pub fn basic_group_full_info(&self, basic_group_id: i64) -> Option<&BasicGroupFullInfo> { self.basic_group_full_infos.get(&basic_group_id) }
+  pub fn supergroup(&self, supergroup_id: i64) -> Option<&Supergroup> { self.supergroups.get(&supergroup_id) }
+  pub fn supergroup_full_info(&self, supergroup_id: i64) -> Option<&SupergroupFullInfo> { self.supergroup_full_infos.get(&supergroup_id) }
+  pub fn secret_chat(&self, secret_chat_id: i64) -> Option<&SecretChat> { self.secret_chats.get(&secret_chat_id) }
+  pub fn file(&self, file_id: i64) -> Option<&File> { self.files.get(&file_id) }
+
+  fn change(&mut self, update: &Update) -> Option<StateChange> {
+    let chat_id = match update {
+      Update::NewChat(u) => {
+        self.chats.insert(u.chat().id(), u.chat().clone());
+        return Some(StateChange::Chat(u.chat().id()));
+      }
+      Update::User(u) => {
+        self.users.insert(u.user().id(), u.user().clone());
+        return Some(StateChange::User(u.user().id()));
+      }
+      Update::UserStatus(u) => {
+        let user = self.users.get(&u.user_id())?;
+        let user = user_builder(user).status(u.status()).build();
+        self.users.insert(u.user_id(), user);
+        return Some(StateChange::User(u.user_id()));
+      }
+      Update::UserFullInfo(u) => {
+        self.user_full_infos.insert(u.user_id(), u.user_full_info().clone());
+        return Some(StateChange::UserFullInfo(u.user_id()));
+      }
+      Update::BasicGroup(u) => {
+        self.basic_groups.insert(u.basic_group().id(), u.basic_group().clone());
+        return Some(StateChange::BasicGroup(u.basic_group().id()));
+      }
+      Update::BasicGroupFullInfo(u) => {
+        self.basic_group_full_infos.insert(u.basic_group_id(), u.basic_group_full_info().clone());
+        return Some(StateChange::BasicGroupFullInfo(u.basic_group_id()));
+      }
+      Update::Supergroup(u) => {
+        self.supergroups.insert(u.supergroup().id(), u.supergroup().clone());
+        return Some(StateChange::Supergroup(u.supergroup().id()));
+      }
+      Update::SupergroupFullInfo(u) => {
+        self.supergroup_full_infos.insert(u.supergroup_id(), u.supergroup_full_info().clone());
+        return Some(StateChange::SupergroupFullInfo(u.supergroup_id()));
+      }
+      Update::SecretChat(u) => {
+        self.secret_chats.insert(u.secret_chat().id(), u.secret_chat().clone());
+        return Some(StateChange::SecretChat(u.secret_chat().id()));
+      }
+      Update::File(u) => {
+        self.files.insert(u.file().id(), u.file().clone());
+        return Some(StateChange::File(u.file().id()));
+      }
+      Update::ChatPosition(u) => u.chat_id(),
+      Update::ChatTitle(u) => u.chat_id(),
+      Update::ChatPhoto(u) => u.chat_id(),
+      Update::ChatPermissions(u) => u.chat_id(),
+      Update::ChatLastMessage(u) => u.chat_id(),
+      Update::ChatDraftMessage(u) => u.chat_id(),
+      Update::ChatReadInbox(u) => u.chat_id(),
+      Update::ChatReadOutbox(u) => u.chat_id(),
+      Update::ChatUnreadMentionCount(u) => u.chat_id(),
+      Update::MessageMentionRead(u) => u.chat_id(),
+      Update::ChatIsMarkedAsUnread(u) => u.chat_id(),
+      Update::ChatHasScheduledMessages(u) => u.chat_id(),
+      Update::ChatDefaultDisableNotification(u) => u.chat_id(),
+      Update::ChatNotificationSettings(u) => u.chat_id(),
+      Update::ChatActionBar(u) => u.chat_id(),
+      Update::ChatPinnedMessage(u) => u.chat_id(),
+      Update::ChatReplyMarkup(u) => u.chat_id(),
+      _ => return None,
+    };
+    let chat = self.chats.get(&chat_id)?;
+    // nullable fields can't be unset through a builder, so they are picked before it is created
+    let mut photo = chat.photo().as_ref();
+    let mut last_message = chat.last_message().as_ref();
+    let mut action_bar = chat.action_bar().as_ref();
+    let mut draft_message = chat.draft_message().as_ref();
+    match update {
+      Update::ChatPhoto(u) => photo = u.photo().as_ref(),
+      Update::ChatLastMessage(u) => last_message = u.last_message().as_ref(),
+      Update::ChatActionBar(u) => action_bar = u.action_bar().as_ref(),
+      Update::ChatDraftMessage(u) => draft_message = u.draft_message().as_ref(),
+      _ => {}
+    }
+    let mut builder = chat_builder(chat);
+    if let Some(photo) = photo { builder.photo(photo); }
+    if let Some(last_message) = last_message { builder.last_message(last_message); }
+    if let Some(action_bar) = action_bar { builder.action_bar(action_bar); }
+    if let Some(draft_message) = draft_message { builder.draft_message(draft_message); }
+    match update {
+      Update::ChatPosition(u) => { builder.positions(merge_positions(chat.positions(), std::slice::from_ref(u.position()))); }
+      Update::ChatTitle(u) => { builder.title(u.title()); }
+      Update::ChatPermissions(u) => { builder.permissions(u.permissions()); }
+      Update::ChatLastMessage(u) => { builder.positions(merge_positions(chat.positions(), u.positions())); }
+      Update::ChatDraftMessage(u) => { builder.positions(merge_positions(chat.positions(), u.positions())); }
+      Update::ChatReadInbox(u) => { builder.last_read_inbox_message_id(u.last_read_inbox_message_id()).unread_count(u.unread_count()); }
+      Update::ChatReadOutbox(u) => { builder.last_read_outbox_message_id(u.last_read_outbox_message_id()); }
+      Update::ChatUnreadMentionCount(u) => { builder.unread_mention_count(u.unread_mention_count()); }
+      Update::MessageMentionRead(u) => { builder.unread_mention_count(u.unread_mention_count()); }
+      Update::ChatIsMarkedAsUnread(u) => { builder.is_marked_as_unread(u.is_marked_as_unread()); }
+      Update::ChatHasScheduledMessages(u) => { builder.has_scheduled_messages(u.has_scheduled_messages()); }
+      Update::ChatDefaultDisableNotification(u) => { builder.default_disable_notification(u.default_disable_notification()); }
+      Update::ChatNotificationSettings(u) => { builder.notification_settings(u.notification_settings()); }
+      Update::ChatPinnedMessage(u) => { builder.pinned_message_id(u.pinned_message_id()); }
+      Update::ChatReplyMarkup(u) => { builder.reply_markup_message_id(u.reply_markup_message_id()); }
+      _ => {}
+    }
+    self.chats.insert(chat_id, builder.build());
+    Some(StateChange::Chat(chat_id))
+  }
+}
+
+/// Builder holding every field of `chat` except the nullable ones
+fn chat_builder(chat: &Chat) -> RTDChatBuilder {
+  let mut builder = Chat::builder();
+  if let Some(extra) = chat.extra() { builder.extra(extra.clone()); }
+  builder
+    .id(chat.id())
+    .type_(chat.type_())
+    .title(chat.title())
+    .permissions(chat.permissions())
+    .positions(chat.positions().clone())
+    .is_marked_as_unread(chat.is_marked_as_unread())
+    .has_scheduled_messages(chat.has_scheduled_messages())
+    .can_be_deleted_only_for_self(chat.can_be_deleted_only_for_self())
+    .can_be_deleted_for_all_users(chat.can_be_deleted_for_all_users())
+    .can_be_reported(chat.can_be_reported())
+    .default_disable_notification(chat.default_disable_notification())
+    .unread_count(chat.unread_count())
+    .last_read_inbox_message_id(chat.last_read_inbox_message_id())
+    .last_read_outbox_message_id(chat.last_read_outbox_message_id())
+    .unread_mention_count(chat.unread_mention_count())
+    .notification_settings(chat.notification_settings())
+    .pinned_message_id(chat.pinned_message_id())
+    .reply_markup_message_id(chat.reply_markup_message_id())
+    .client_data(chat.client_data());
+  builder
+}
+
+/// Builder holding every field of `user`
+fn user_builder(user: &User) -> RTDUserBuilder {
+  let mut builder = User::builder();
+  if let Some(extra) = user.extra() { builder.extra(extra.clone()); }
+  if let Some(profile_photo) = user.profile_photo() { builder.profile_photo(profile_photo); }
+  builder
+    .id(user.id())
+    .first_name(user.first_name())
+    .last_name(user.last_name())
+    .username(user.username())
+    .phone_number(user.phone_number())
+    .status(user.status())
+    .is_contact(user.is_contact())
+    .is_mutual_contact(user.is_mutual_contact())
+    .is_verified(user.is_verified())
+    .is_support(user.is_support())
+    .restriction_reason(user.restriction_reason())
+    .is_scam(user.is_scam())
+    .have_access(user.have_access())
+    .type_(user.type_())
+    .language_code(user.language_code());
+  builder
+}
+
+/// Replace positions of `current` in the chat lists of `changed`, a position with order 0 removes the chat from its list
+fn merge_positions(current: &[ChatPosition], changed: &[ChatPosition]) -> Vec<ChatPosition> {
+  let mut positions: Vec<ChatPosition> = current.iter()
+    .filter(|position| !changed.iter().any(|c| ChatListKey::of(c.list()) == ChatListKey::of(position.list())))
+    .cloned()
+    .collect();
+  positions.extend(changed.iter().filter(|position| position.order() != 0).cloned());
+  positions
+}
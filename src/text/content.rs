@@ -0,0 +1,203 @@
+//! Searchable text and one line summaries of `MessageContent`.
+
+use crate::state::StateStore;
+use crate::types::*;
+
+/// Names of users and chats mentioned by service messages
+pub trait NameResolver {
+  fn user_name(&self, user_id: i64) -> Option<String>;
+
+  fn chat_name(&self, _chat_id: i64) -> Option<String> { None }
+}
+
+/// Resolver that doesn't know any name, users and chats are shown by id
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoNames;
+
+impl NameResolver for NoNames {
+  fn user_name(&self, _user_id: i64) -> Option<String> { None }
+}
+
+impl NameResolver for StateStore {
+  fn user_name(&self, user_id: i64) -> Option<String> {
+    self.user(user_id).map(|user| {
+      if user.last_name().is_empty() { user.first_name().clone() } else { format!("{} {}", user.first_name(), user.last_name()) }
+    })
+  }
+
+  fn chat_name(&self, chat_id: i64) -> Option<String> { self.chat(chat_id).map(|chat| chat.title().clone()) }
+}
+
+/// Text of a `MessageContent`
+pub trait MessageContentExt {
+  /// All searchable text: message text, captions, poll question and options, venue title and address,
+  /// and a description of service messages
+  fn to_plain_text<R: NameResolver + ?Sized>(&self, names: &R) -> String;
+  /// One line describing the message, like a chat list preview
+  fn summary<R: NameResolver + ?Sized>(&self, names: &R) -> String;
+}
+
+impl MessageContentExt for MessageContent {
+  fn to_plain_text<R: NameResolver + ?Sized>(&self, names: &R) -> String {
+    let lines = |lines: Vec<&str>| lines.into_iter().filter(|line| !line.is_empty()).collect::<Vec<_>>().join("\n");
+    match self {
+      MessageContent::MessageText(m) => m.text().text().clone(),
+      MessageContent::MessageAnimation(m) => m.caption().text().clone(),
+      MessageContent::MessageAudio(m) => lines(vec![&audio_title(m.audio()), m.caption().text()]),
+      MessageContent::MessageDocument(m) => lines(vec![m.document().file_name(), m.caption().text()]),
+      MessageContent::MessagePhoto(m) => m.caption().text().clone(),
+      MessageContent::MessageVideo(m) => m.caption().text().clone(),
+      MessageContent::MessageVoiceNote(m) => m.caption().text().clone(),
+      MessageContent::MessageSticker(m) => m.sticker().emoji().clone(),
+      MessageContent::MessageDice(m) => m.emoji().clone(),
+      MessageContent::MessagePoll(m) => {
+        let mut text = vec![m.poll().question().as_str()];
+        text.extend(m.poll().options().iter().map(|option| option.text().as_str()));
+        lines(text)
+      }
+      MessageContent::MessageVenue(m) => lines(vec![m.venue().title(), m.venue().address()]),
+      MessageContent::MessageContact(m) => lines(vec![&contact_name(m.contact()), m.contact().phone_number()]),
+      MessageContent::MessageGame(m) => lines(vec![m.game().title(), m.game().text().text(), m.game().description()]),
+      MessageContent::MessageInvoice(m) => lines(vec![m.title(), m.description()]),
+      MessageContent::MessageLocation(_) | MessageContent::MessageVideoNote(_) => String::new(),
+      _ => service_text(self, names).unwrap_or_default(),
+    }
+  }
+
+  fn summary<R: NameResolver + ?Sized>(&self, names: &R) -> String {
+    let with_caption = |label: &str, caption: &FormattedText| match first_line(caption.text()) {
+      "" => label.to_string(),
+      caption => format!("{}, {}", label, caption),
+    };
+    match self {
+      MessageContent::MessageText(m) => first_line(m.text().text()).to_string(),
+      MessageContent::MessageAnimation(m) => with_caption("GIF", m.caption()),
+      MessageContent::MessageAudio(m) => match audio_title(m.audio()) {
+        title if title.is_empty() => with_caption("Audio", m.caption()),
+        title => with_caption(&format!("Audio: {}", title), m.caption()),
+      },
+      MessageContent::MessageDocument(m) if m.document().file_name().is_empty() => with_caption("File", m.caption()),
+      MessageContent::MessageDocument(m) => with_caption(&format!("File: {}", m.document().file_name()), m.caption()),
+      MessageContent::MessagePhoto(m) => with_caption("Photo", m.caption()),
+      MessageContent::MessageVideo(m) => with_caption("Video", m.caption()),
+      MessageContent::MessageVoiceNote(m) => with_caption("Voice message", m.caption()),
+      MessageContent::MessageVideoNote(_) => "Video message".to_string(),
+      MessageContent::MessageSticker(m) if m.sticker().emoji().is_empty() => "Sticker".to_string(),
+      MessageContent::MessageSticker(m) => format!("{} Sticker", m.sticker().emoji()),
+      MessageContent::MessageDice(m) if m.value() > 0 => format!("{} {}", m.emoji(), m.value()),
+      MessageContent::MessageDice(m) => m.emoji().clone(),
+      MessageContent::MessagePoll(m) => format!("Poll: {}", first_line(m.poll().question())),
+      MessageContent::MessageVenue(m) => format!("Venue: {}", m.venue().title()),
+      MessageContent::MessageLocation(m) if m.live_period() > 0 => "Live location".to_string(),
+      MessageContent::MessageLocation(_) => "Location".to_string(),
+      MessageContent::MessageContact(m) => format!("Contact: {}", contact_name(m.contact())),
+      MessageContent::MessageGame(m) => format!("Game: {}", m.game().title()),
+      MessageContent::MessageInvoice(m) => format!("Invoice: {}", m.title()),
+      _ => service_text(self, names).unwrap_or_default(),
+    }
+  }
+}
+
+// description of service messages, `None` for messages with content
+fn service_text<R: NameResolver + ?Sized>(content: &MessageContent, names: &R) -> Option<String> {
+  let user = |user_id: i64| names.user_name(user_id).unwrap_or_else(|| format!("user {}", user_id));
+  Some(match content {
+    MessageContent::MessageBasicGroupChatCreate(m) => format!("Group \"{}\" created", m.title()),
+    MessageContent::MessageSupergroupChatCreate(m) => format!("Supergroup \"{}\" created", m.title()),
+    MessageContent::MessageChatAddMembers(m) => {
+      let users: Vec<String> = m.member_user_ids().iter().map(|&user_id| user(user_id)).collect();
+      format!("{} joined the group", join_names(&users))
+    }
+    MessageContent::MessageChatDeleteMember(m) => format!("{} left the group", user(m.user_id())),
+    MessageContent::MessageChatJoinByLink(_) => "Joined the group via invite link".to_string(),
+    MessageContent::MessageChatChangeTitle(m) => format!("Group name changed to \"{}\"", m.title()),
+    MessageContent::MessageChatChangePhoto(_) => "Group photo changed".to_string(),
+    MessageContent::MessageChatDeletePhoto(_) => "Group photo removed".to_string(),
+    MessageContent::MessageChatSetTtl(m) if m.ttl() == 0 => "Self-destruct timer disabled".to_string(),
+    MessageContent::MessageChatSetTtl(m) => format!("Self-destruct timer set to {}", period(m.ttl())),
+    MessageContent::MessageChatUpgradeFrom(m) => format!("Group \"{}\" upgraded to a supergroup", m.title()),
+    MessageContent::MessageChatUpgradeTo(m) => match names.chat_name(supergroup_chat_id(m.supergroup_id())) {
+      Some(name) => format!("Group upgraded to the supergroup \"{}\"", name),
+      None => "Group upgraded to a supergroup".to_string(),
+    },
+    MessageContent::MessagePinMessage(_) => "Pinned a message".to_string(),
+    MessageContent::MessageScreenshotTaken(_) => "Took a screenshot".to_string(),
+    MessageContent::MessageContactRegistered(_) => "Joined Telegram".to_string(),
+    MessageContent::MessageCustomServiceAction(m) => m.text().clone(),
+    MessageContent::MessageGameScore(m) => format!("Scored {} in a game", m.score()),
+    MessageContent::MessageCall(m) => {
+      let call = if m.is_video() { "Video call" } else { "Call" };
+      match m.discard_reason() {
+        CallDiscardReason::Missed(_) => format!("Missed {}", call.to_lowercase()),
+        CallDiscardReason::Declined(_) => format!("Declined {}", call.to_lowercase()),
+        _ if m.duration() > 0 => format!("{} ({})", call, duration(m.duration())),
+        _ => call.to_string(),
+      }
+    }
+    MessageContent::MessagePaymentSuccessful(m) => format!("Payment of {}", amount(m.total_amount(), m.currency())),
+    MessageContent::MessagePaymentSuccessfulBot(m) => format!("Payment of {}", amount(m.total_amount(), m.currency())),
+    MessageContent::MessagePassportDataSent(_) => "Telegram Passport data sent".to_string(),
+    MessageContent::MessagePassportDataReceived(_) => "Telegram Passport data received".to_string(),
+    MessageContent::MessageWebsiteConnected(m) => format!("Logged in on {}", m.domain_name()),
+    MessageContent::MessageExpiredPhoto(_) => "Photo has expired".to_string(),
+    MessageContent::MessageExpiredVideo(_) => "Video has expired".to_string(),
+    MessageContent::MessageUnsupported(_) => "Unsupported message".to_string(),
+    _ => return None,
+  })
+}
+
+// chat id of a supergroup, like tdlib computes it
+fn supergroup_chat_id(supergroup_id: i64) -> i64 { -1_000_000_000_000 - supergroup_id }
+
+fn first_line(text: &str) -> &str { text.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or("") }
+
+fn audio_title(audio: &Audio) -> String {
+  match (audio.performer().is_empty(), audio.title().is_empty()) {
+    (true, true) => String::new(),
+    (false, true) => audio.performer().clone(),
+    (true, false) => audio.title().clone(),
+    (false, false) => format!("{} – {}", audio.performer(), audio.title()),
+  }
+}
+
+fn contact_name(contact: &Contact) -> String {
+  format!("{} {}", contact.first_name(), contact.last_name()).trim().to_string()
+}
+
+fn join_names(names: &[String]) -> String {
+  match names {
+    [] => String::new(),
+    [name] => name.clone(),
+    [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+  }
+}
+
+fn duration(seconds: i64) -> String {
+  match seconds {
+    s if s < 60 => format!("0:{:02}", s),
+    s if s < 3600 => format!("{}:{:02}", s / 60, s % 60),
+    s => format!("{}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60),
+  }
+}
+
+fn period(seconds: i64) -> String {
+  let (count, unit) = [(604_800, "week"), (86400, "day"), (3600, "hour"), (60, "minute")].iter()
+    .find(|(unit, _)| seconds % unit == 0)
+    .map(|&(unit, name)| (seconds / unit, name))
+    .unwrap_or((seconds, "second"));
+  format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+}
+
+// currencies without minor units, amounts of the others are in hundredths
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &[
+  "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "VND", "VUV", "XAF", "XOF", "XPF",
+];
+
+fn amount(total_amount: i64, currency: &str) -> String {
+  if ZERO_DECIMAL_CURRENCIES.contains(&currency) {
+    format!("{} {}", total_amount, currency)
+  } else {
+    let sign = if total_amount < 0 { "-" } else { "" };
+    format!("{}{}.{:02} {}", sign, total_amount.abs() / 100, total_amount.abs() % 100, currency)
+  }
+}
@@ -0,0 +1,358 @@
+//! Offline detection of the entities `getTextEntities` finds: mentions, hashtags, cashtags, bot commands, urls,
+//! email addresses, phone numbers and bank card numbers. The rules follow tdlib closely but not exactly,
+//! e.g. urls without a scheme are only detected for common top level domains.
+
+use std::cmp::Reverse;
+
+use crate::types::*;
+
+/// Entity kinds found by `detect`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+  Mention,
+  Hashtag,
+  Cashtag,
+  BotCommand,
+  Url,
+  EmailAddress,
+  PhoneNumber,
+  BankCardNumber,
+}
+
+impl EntityKind {
+  /// Kind of an entity type, `None` for types `detect` doesn't produce
+  pub fn of(type_: &TextEntityType) -> Option<Self> {
+    Some(match type_ {
+      TextEntityType::Mention(_) => EntityKind::Mention,
+      TextEntityType::Hashtag(_) => EntityKind::Hashtag,
+      TextEntityType::Cashtag(_) => EntityKind::Cashtag,
+      TextEntityType::BotCommand(_) => EntityKind::BotCommand,
+      TextEntityType::Url(_) => EntityKind::Url,
+      TextEntityType::EmailAddress(_) => EntityKind::EmailAddress,
+      TextEntityType::PhoneNumber(_) => EntityKind::PhoneNumber,
+      TextEntityType::BankCardNumber(_) => EntityKind::BankCardNumber,
+      _ => return None,
+    })
+  }
+
+  pub fn entity_type(&self) -> TextEntityType {
+    match self {
+      EntityKind::Mention => TextEntityType::mention(TextEntityTypeMention::builder().build()),
+      EntityKind::Hashtag => TextEntityType::hashtag(TextEntityTypeHashtag::builder().build()),
+      EntityKind::Cashtag => TextEntityType::cashtag(TextEntityTypeCashtag::builder().build()),
+      EntityKind::BotCommand => TextEntityType::bot_command(TextEntityTypeBotCommand::builder().build()),
+      EntityKind::Url => TextEntityType::url(TextEntityTypeUrl::builder().build()),
+      EntityKind::EmailAddress => TextEntityType::email_address(TextEntityTypeEmailAddress::builder().build()),
+      EntityKind::PhoneNumber => TextEntityType::phone_number(TextEntityTypePhoneNumber::builder().build()),
+      EntityKind::BankCardNumber => TextEntityType::bank_card_number(TextEntityTypeBankCardNumber::builder().build()),
+    }
+  }
+}
+
+/// Top level domains of urls without a scheme
+const TOP_LEVEL_DOMAINS: &[&str] = &[
+  "com", "org", "net", "edu", "gov", "int", "info", "biz", "io", "ai", "app", "dev", "me", "co", "tv", "cc",
+  "ru", "ua", "by", "kz", "uk", "de", "fr", "it", "es", "nl", "pl", "se", "no", "fi", "dk", "ch", "at", "be",
+  "cz", "us", "ca", "au", "jp", "cn", "in", "br", "mx", "tr", "ir", "eu", "xyz", "online", "site", "top", "pro",
+  "name", "tech", "store", "blog", "news", "live", "space", "club", "shop",
+];
+
+const SCHEMES: &[&str] = &["http://", "https://", "ftp://", "tg://", "ton://"];
+
+/// Entities of `text` in the order of their offsets, entities don't overlap
+pub fn detect(text: &str) -> Vec<TextEntity> {
+  let detector = Detector::new(text);
+  let mut found = detector.find();
+  found.sort_by_key(|(start, end, _)| (*start, Reverse(*end)));
+  let mut entities = vec![];
+  let mut last_end = 0;
+  for (start, end, kind) in found {
+    if start < last_end {
+      continue;
+    }
+    last_end = end;
+    let offset = detector.utf16[start];
+    entities.push(TextEntity::builder().offset(offset).length(detector.utf16[end] - offset).type_(kind.entity_type()).build());
+  }
+  entities
+}
+
+/// Entities of one kind with their text
+pub fn detect_kind(text: &str, kind: EntityKind) -> Vec<&str> {
+  let offsets = super::markup::utf16_to_byte(text);
+  detect(text).into_iter()
+    .filter(|entity| EntityKind::of(entity.type_()) == Some(kind))
+    .filter_map(|entity| {
+      let start = offsets[entity.offset() as usize]?;
+      let end = offsets[(entity.offset() + entity.length()) as usize]?;
+      Some(&text[start..end])
+    })
+    .collect()
+}
+
+/// Bot command at the start of a message, `/command@bot_username arguments`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BotCommand<'a> {
+  pub command: &'a str,
+  pub bot_username: Option<&'a str>,
+  pub args: &'a str,
+}
+
+/// Bot command the text starts with
+pub fn bot_command(text: &str) -> Option<BotCommand<'_>> {
+  let entity = detect(text).into_iter().next()?;
+  if entity.offset() != 0 || EntityKind::of(entity.type_()) != Some(EntityKind::BotCommand) {
+    return None;
+  }
+  let end = super::entities::byte_index(text, entity.length())?;
+  let (command, bot_username) = match text[1..end].split_once('@') {
+    Some((command, bot_username)) => (command, Some(bot_username)),
+    None => (&text[1..end], None),
+  };
+  Some(BotCommand { command, bot_username, args: text[end..].trim() })
+}
+
+fn is_word(c: char) -> bool { c.is_alphanumeric() || c == '_' }
+
+fn is_username(c: char) -> bool { c.is_ascii_alphanumeric() || c == '_' }
+
+type Found = (usize, usize, EntityKind);
+
+struct Detector {
+  chars: Vec<char>,
+  /// UTF-16 offset of every char index, and of the end of the text
+  utf16: Vec<i64>,
+}
+
+impl Detector {
+  fn new(text: &str) -> Self {
+    let chars: Vec<char> = text.chars().collect();
+    let mut utf16 = Vec::with_capacity(chars.len() + 1);
+    let mut offset = 0;
+    for c in &chars {
+      utf16.push(offset);
+      offset += c.len_utf16() as i64;
+    }
+    utf16.push(offset);
+    Self { chars, utf16 }
+  }
+
+  fn at(&self, i: usize) -> Option<char> { self.chars.get(i).copied() }
+
+  // char before `i` isn't part of a word
+  fn word_start(&self, i: usize) -> bool {
+    i == 0 || !is_word(self.chars[i - 1])
+  }
+
+  fn word_end(&self, i: usize) -> bool {
+    !self.at(i).is_some_and(is_word)
+  }
+
+  fn span(&self, from: usize, f: impl Fn(char) -> bool) -> usize {
+    let mut i = from;
+    while self.at(i).is_some_and(&f) {
+      i += 1;
+    }
+    i
+  }
+
+  fn find(&self) -> Vec<Found> {
+    let mut found = vec![];
+    for i in 0..self.chars.len() {
+      let entity = match self.chars[i] {
+        '@' => self.mention(i).or_else(|| self.email(i)),
+        '#' => self.hashtag(i),
+        '$' => self.cashtag(i),
+        '/' => self.bot_command(i),
+        '+' => self.phone_number(i),
+        c if c.is_ascii_digit() => self.bank_card_number(i).or_else(|| self.url(i)),
+        c if c.is_ascii_alphabetic() => self.url(i),
+        _ => None,
+      };
+      found.extend(entity);
+    }
+    found
+  }
+
+  fn mention(&self, i: usize) -> Option<Found> {
+    if !self.word_start(i) {
+      return None;
+    }
+    let end = self.span(i + 1, is_username);
+    if (5..=32).contains(&(end - i - 1)) && self.word_end(end) {
+      Some((i, end, EntityKind::Mention))
+    } else {
+      None
+    }
+  }
+
+  fn hashtag(&self, i: usize) -> Option<Found> {
+    if !self.word_start(i) {
+      return None;
+    }
+    let end = self.span(i + 1, is_word);
+    // a hashtag needs a char that isn't a digit
+    if self.chars[i + 1..end].iter().any(|c| !c.is_ascii_digit()) {
+      Some((i, end, EntityKind::Hashtag))
+    } else {
+      None
+    }
+  }
+
+  fn cashtag(&self, i: usize) -> Option<Found> {
+    if !self.word_start(i) {
+      return None;
+    }
+    let end = self.span(i + 1, |c| c.is_ascii_uppercase());
+    if (3..=8).contains(&(end - i - 1)) && self.word_end(end) {
+      Some((i, end, EntityKind::Cashtag))
+    } else {
+      None
+    }
+  }
+
+  fn bot_command(&self, i: usize) -> Option<Found> {
+    if i > 0 && !self.chars[i - 1].is_whitespace() {
+      return None;
+    }
+    let mut end = self.span(i + 1, is_username);
+    if !(1..=64).contains(&(end - i - 1)) {
+      return None;
+    }
+    if self.at(end) == Some('@') {
+      let username_end = self.span(end + 1, is_username);
+      if (3..=32).contains(&(username_end - end - 1)) {
+        end = username_end;
+      }
+    }
+    if self.word_end(end) && self.at(end) != Some('/') {
+      Some((i, end, EntityKind::BotCommand))
+    } else {
+      None
+    }
+  }
+
+  fn email(&self, i: usize) -> Option<Found> {
+    let is_local = |c: char| c.is_ascii_alphanumeric() || "._%+-".contains(c);
+    let mut start = i;
+    while start > 0 && is_local(self.chars[start - 1]) {
+      start -= 1;
+    }
+    if start == i || !self.word_start(start) || self.chars[start] == '.' || self.chars[i - 1] == '.' {
+      return None;
+    }
+    let end = self.domain(i + 1, false)?;
+    Some((start, end, EntityKind::EmailAddress))
+  }
+
+  // end of a domain name, the top level domain has to be known if `known_tld`
+  fn domain(&self, from: usize, known_tld: bool) -> Option<usize> {
+    let mut end = self.span(from, |c| c.is_ascii_alphanumeric() || c == '-' || c == '.');
+    while end > from && matches!(self.chars[end - 1], '.' | '-') {
+      end -= 1;
+    }
+    let domain: String = self.chars[from..end].iter().collect();
+    let labels: Vec<&str> = domain.split('.').collect();
+    let tld = labels.last()?.to_lowercase();
+    let valid = labels.len() >= 2
+      && labels.iter().all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+      && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic())
+      && (!known_tld || TOP_LEVEL_DOMAINS.contains(&tld.as_str()));
+    if valid && self.word_end(end) { Some(end) } else { None }
+  }
+
+  fn url(&self, i: usize) -> Option<Found> {
+    if !self.word_start(i) || (i > 0 && "@.-/:".contains(self.chars[i - 1])) {
+      return None;
+    }
+    let rest: String = self.chars[i..].iter().take(8).collect::<String>().to_lowercase();
+    let host_end = match SCHEMES.iter().find(|scheme| rest.starts_with(*scheme)) {
+      Some(scheme) => {
+        let from = i + scheme.len();
+        let end = self.span(from, |c| c.is_alphanumeric() || c == '-' || c == '.');
+        if end == from {
+          return None;
+        }
+        end
+      }
+      None if rest.starts_with("www.") => self.domain(i, false)?,
+      None => self.domain(i, true)?,
+    };
+    let mut end = self.trim_url(i, host_end);
+    if self.at(end) == Some(':') {
+      let port_end = self.span(end + 1, |c| c.is_ascii_digit());
+      if port_end > end + 1 {
+        end = port_end;
+      }
+    }
+    if matches!(self.at(end), Some('/') | Some('?') | Some('#')) {
+      end = self.span(end, |c| !c.is_whitespace());
+      end = self.trim_url(i, end);
+    }
+    if self.word_end(end) { Some((i, end, EntityKind::Url)) } else { None }
+  }
+
+  // drop trailing punctuation and unbalanced closing brackets of a url path
+  fn trim_url(&self, start: usize, mut end: usize) -> usize {
+    loop {
+      let last = self.chars[end - 1];
+      let unbalanced = |open: char, close: char| {
+        last == close && {
+          let url = &self.chars[start..end];
+          url.iter().filter(|c| **c == open).count() < url.iter().filter(|c| **c == close).count()
+        }
+      };
+      if ".,:;!?'\"".contains(last) || unbalanced('(', ')') || unbalanced('[', ']') || unbalanced('{', '}') {
+        end -= 1;
+      } else {
+        return end;
+      }
+    }
+  }
+
+  fn phone_number(&self, i: usize) -> Option<Found> {
+    if !self.word_start(i) || !self.at(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+      return None;
+    }
+    let (end, digits) = self.digits(i + 1, " -()");
+    if (7..=15).contains(&digits) && self.word_end(end) {
+      Some((i, end, EntityKind::PhoneNumber))
+    } else {
+      None
+    }
+  }
+
+  fn bank_card_number(&self, i: usize) -> Option<Found> {
+    if !self.word_start(i) || (i > 0 && "+-.,".contains(self.chars[i - 1])) {
+      return None;
+    }
+    let (end, digits) = self.digits(i, " -");
+    if !(13..=19).contains(&digits) || !self.word_end(end) {
+      return None;
+    }
+    let numbers: Vec<u32> = self.chars[i..end].iter().filter_map(|c| c.to_digit(10)).collect();
+    if luhn(&numbers) { Some((i, end, EntityKind::BankCardNumber)) } else { None }
+  }
+
+  // end after the last digit and the number of digits, digits can be separated by single `separators`
+  fn digits(&self, from: usize, separators: &str) -> (usize, usize) {
+    let (mut i, mut end, mut digits) = (from, from, 0);
+    while let Some(c) = self.at(i) {
+      if c.is_ascii_digit() {
+        digits += 1;
+        end = i + 1;
+      } else if !separators.contains(c) || self.at(i + 1).is_some_and(|next| next == c) {
+        break;
+      }
+      i += 1;
+    }
+    (end, digits)
+  }
+}
+
+fn luhn(digits: &[u32]) -> bool {
+  let sum: u32 = digits.iter().rev().enumerate()
+    .map(|(index, digit)| if index % 2 == 1 { let d = digit * 2; if d > 9 { d - 9 } else { d } } else { *digit })
+    .sum();
+  sum % 10 == 0
+}
@@ -0,0 +1,128 @@
+//! `TextEntity` offsets and lengths are UTF-16 code units, these helpers convert them to `&str` slices of the text.
+
+use std::convert::TryFrom;
+use std::ops::Range;
+
+use crate::errors::*;
+use crate::types::*;
+
+use super::markup::utf16_to_byte;
+
+/// Length of `text` in UTF-16 code units
+pub fn utf16_len(text: &str) -> i64 {
+  text.chars().map(|c| c.len_utf16() as i64).sum()
+}
+
+/// Byte index of a UTF-16 offset, `None` if the offset is out of the text or inside a surrogate pair
+pub fn byte_index(text: &str, offset: i64) -> Option<usize> {
+  let mut utf16 = 0;
+  for (index, c) in text.char_indices() {
+    if utf16 >= offset {
+      return if utf16 == offset { Some(index) } else { None };
+    }
+    utf16 += c.len_utf16() as i64;
+  }
+  if utf16 == offset { Some(text.len()) } else { None }
+}
+
+/// UTF-16 offset of a byte index, `None` if the index is out of the text or not a char boundary
+pub fn utf16_offset(text: &str, index: usize) -> Option<i64> {
+  if !text.is_char_boundary(index) {
+    return None;
+  }
+  Some(utf16_len(&text[..index]))
+}
+
+/// Byte range of an entity in `text`
+pub fn byte_range(text: &str, entity: &TextEntity) -> Option<Range<usize>> {
+  let start = byte_index(text, entity.offset())?;
+  let end = entity.offset().checked_add(entity.length()).and_then(|end| byte_index(text, end))?;
+  if start <= end { Some(start..end) } else { None }
+}
+
+/// Text of an entity, `None` if the entity isn't inside `text`
+pub fn entity_text<'a>(text: &'a str, entity: &TextEntity) -> Option<&'a str> {
+  byte_range(text, entity).map(|range| &text[range])
+}
+
+/// Entity of `type_` over a byte range of `text`
+pub fn entity_from_bytes<T: AsRef<TextEntityType>>(text: &str, range: Range<usize>, type_: T) -> RTDResult<TextEntity> {
+  let bounds = || RTDError::message(format!("byte range {:?} isn't inside the text or on char boundaries", range));
+  let offset = utf16_offset(text, range.start).ok_or_else(bounds)?;
+  let end = utf16_offset(text, range.end).ok_or_else(bounds)?;
+  if offset > end {
+    return Err(bounds());
+  }
+  Ok(TextEntity::builder().offset(offset).length(end - offset).type_(type_).build())
+}
+
+/// Entity of `type_` over a range of chars of `text`
+pub fn entity_from_chars<T: AsRef<TextEntityType>>(text: &str, range: Range<usize>, type_: T) -> RTDResult<TextEntity> {
+  let index = |n: usize| text.char_indices().map(|(index, _)| index).chain(Some(text.len())).nth(n);
+  match (index(range.start), index(range.end)) {
+    (Some(start), Some(end)) if start <= end => entity_from_bytes(text, start..end, type_),
+    _ => Err(RTDError::message(format!("char range {:?} isn't inside the text", range))),
+  }
+}
+
+/// Check that every entity is not empty, inside `text` and doesn't split a surrogate pair
+pub fn validate(text: &str, entities: &[TextEntity]) -> RTDResult<()> {
+  let offsets = utf16_to_byte(text);
+  for (index, entity) in entities.iter().enumerate() {
+    let boundary = |offset: i64| usize::try_from(offset).ok().and_then(|offset| offsets.get(offset).copied().flatten()).is_some();
+    let end = entity.offset().checked_add(entity.length());
+    if entity.length() <= 0 || !boundary(entity.offset()) || !end.is_some_and(boundary) {
+      return Err(RTDError::message(format!(
+        "entity {} {} at {} with length {} isn't inside the text of UTF-16 length {}",
+        index, entity.type_().td_name(), entity.offset(), entity.length(), offsets.len() - 1
+      )));
+    }
+  }
+  Ok(())
+}
+
+/// Entities of a formatted text with their text, entities not inside the text are skipped
+pub struct EntityTexts<'a> {
+  text: &'a str,
+  offsets: Vec<Option<usize>>,
+  entities: std::slice::Iter<'a, TextEntity>,
+}
+
+impl<'a> Iterator for EntityTexts<'a> {
+  type Item = (&'a TextEntity, &'a str);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let offsets = &self.offsets;
+    let byte = |offset: i64| usize::try_from(offset).ok().and_then(|offset| offsets.get(offset).copied().flatten());
+    for entity in self.entities.by_ref() {
+      let start = byte(entity.offset());
+      let end = entity.offset().checked_add(entity.length()).and_then(byte);
+      if let (Some(start), Some(end)) = (start, end) {
+        if start <= end {
+          return Some((entity, &self.text[start..end]));
+        }
+      }
+    }
+    None
+  }
+}
+
+/// UTF-16 aware access to the entities of a `FormattedText`
+pub trait FormattedTextExt {
+  /// Text of an entity, `None` if the entity isn't inside the text
+  fn entity_text(&self, entity: &TextEntity) -> Option<&str>;
+  /// Entities with their text
+  fn entity_texts(&self) -> EntityTexts<'_>;
+  /// Check that every entity is inside the text
+  fn validate_entities(&self) -> RTDResult<()>;
+}
+
+impl FormattedTextExt for FormattedText {
+  fn entity_text(&self, entity: &TextEntity) -> Option<&str> { entity_text(self.text(), entity) }
+
+  fn entity_texts(&self) -> EntityTexts<'_> {
+    EntityTexts { text: self.text(), offsets: utf16_to_byte(self.text()), entities: self.entities().iter() }
+  }
+
+  fn validate_entities(&self) -> RTDResult<()> { validate(self.text(), self.entities()) }
+}
@@ -0,0 +1,222 @@
+//! Telegram HTML: `<b>`, `<i>`, `<u>`, `<s>`, `<code>`, `<pre>`, `<pre><code class="language-x">`,
+//! `<a href="url">` and `<a href="tg://user?id=123">`.
+
+use crate::errors::*;
+use crate::types::*;
+
+use super::markup::{events, Event, Markup, TextBuilder};
+
+/// Render a formatted text as html, entities html can't express are rendered as plain text
+pub fn render(text: &FormattedText) -> String {
+  let mut out = String::with_capacity(text.text().len());
+  for event in events(text.text(), text.entities()) {
+    match event {
+      Event::Text(s) => escape(s, &mut out),
+      Event::Open(markup) => match markup {
+        Markup::Bold => out.push_str("<b>"),
+        Markup::Italic => out.push_str("<i>"),
+        Markup::Underline => out.push_str("<u>"),
+        Markup::Strikethrough => out.push_str("<s>"),
+        Markup::Code => out.push_str("<code>"),
+        Markup::Pre(language) if language.is_empty() => out.push_str("<pre>"),
+        Markup::Pre(language) => {
+          out.push_str("<pre><code class=\"language-");
+          escape(&language, &mut out);
+          out.push_str("\">");
+        }
+        Markup::TextUrl(_) | Markup::MentionName(_) => {
+          out.push_str("<a href=\"");
+          escape(&markup.url().unwrap_or_default(), &mut out);
+          out.push_str("\">");
+        }
+      },
+      Event::Close(markup) => out.push_str(match markup {
+        Markup::Bold => "</b>",
+        Markup::Italic => "</i>",
+        Markup::Underline => "</u>",
+        Markup::Strikethrough => "</s>",
+        Markup::Code => "</code>",
+        Markup::Pre(language) if language.is_empty() => "</pre>",
+        Markup::Pre(_) => "</code></pre>",
+        Markup::TextUrl(_) | Markup::MentionName(_) => "</a>",
+      }),
+    }
+  }
+  out
+}
+
+fn escape(text: &str, out: &mut String) {
+  for c in text.chars() {
+    match c {
+      '&' => out.push_str("&amp;"),
+      '<' => out.push_str("&lt;"),
+      '>' => out.push_str("&gt;"),
+      '"' => out.push_str("&quot;"),
+      c => out.push(c),
+    }
+  }
+}
+
+struct Tag {
+  name: String,
+  markup: Option<Markup>,
+  offset: i64,
+}
+
+/// Parse Telegram HTML into a formatted text, unknown tags are an error and unknown character entities are kept as text
+pub fn parse<S: AsRef<str>>(html: S) -> RTDResult<FormattedText> {
+  let chars: Vec<char> = html.as_ref().chars().collect();
+  let mut builder = TextBuilder::default();
+  let mut open: Vec<Tag> = vec![];
+  let mut i = 0;
+  while i < chars.len() {
+    match chars[i] {
+      '<' => {
+        let end = tag_end(&chars, i + 1).ok_or_else(|| RTDError::message(format!("html: unclosed tag at {}", i)))?;
+        let tag: String = chars[i + 1..end].iter().collect();
+        if let Some(name) = tag.strip_prefix('/') {
+          let name = name.trim().to_lowercase();
+          match open.pop() {
+            Some(top) if top.name == name => {
+              if let Some(markup) = top.markup {
+                builder.entity(top.offset, markup);
+              }
+            }
+            _ => return Err(RTDError::message(format!("html: unexpected end tag `{}` at {}", name, i))),
+          }
+        } else {
+          let (name, attributes) = split_tag(&tag);
+          let markup = match name.as_str() {
+            "b" | "strong" => Some(Markup::Bold),
+            "i" | "em" => Some(Markup::Italic),
+            "u" | "ins" => Some(Markup::Underline),
+            "s" | "strike" | "del" => Some(Markup::Strikethrough),
+            "a" => attribute(&attributes, "href").map(Markup::link),
+            "pre" => Some(Markup::Pre(String::new())),
+            "code" => {
+              let language = attribute(&attributes, "class").and_then(|class| class.strip_prefix("language-").map(|l| l.to_string()));
+              let offset = builder.offset();
+              let in_pre = open.iter().any(|tag| matches!(tag.markup, Some(Markup::Pre(_))));
+              match open.last_mut() {
+                // <pre><code class="language-x"> is a pre block with a language
+                Some(Tag { markup: Some(Markup::Pre(pre_language)), offset: pre_offset, .. }) if *pre_offset == offset => {
+                  if let Some(language) = language {
+                    *pre_language = language;
+                  }
+                  None
+                }
+                // code can't be inside a pre block
+                _ if in_pre => None,
+                _ => Some(Markup::Code),
+              }
+            }
+            _ => return Err(RTDError::message(format!("html: unsupported tag `{}` at {}", name, i))),
+          };
+          open.push(Tag { name, markup, offset: builder.offset() });
+        }
+        i = end + 1;
+      }
+      '&' => {
+        let (c, next) = character_entity(&chars, i + 1).unwrap_or(('&', i + 1));
+        builder.push(c);
+        i = next;
+      }
+      c => {
+        builder.push(c);
+        i += 1;
+      }
+    }
+  }
+  if let Some(tag) = open.last() {
+    return Err(RTDError::message(format!("html: unclosed tag `{}` opened at text offset {}", tag.name, tag.offset)));
+  }
+  Ok(builder.build())
+}
+
+// index of the `>` closing a tag, `>` inside quoted attributes doesn't close it
+fn tag_end(chars: &[char], mut i: usize) -> Option<usize> {
+  let mut quote = None;
+  while let Some(c) = chars.get(i) {
+    match (quote, c) {
+      (None, '>') => return Some(i),
+      (None, '"') | (None, '\'') => quote = Some(*c),
+      (Some(q), c) if q == *c => quote = None,
+      _ => {}
+    }
+    i += 1;
+  }
+  None
+}
+
+// lowercase tag name and its attributes with unescaped values
+fn split_tag(tag: &str) -> (String, Vec<(String, String)>) {
+  let chars: Vec<char> = tag.chars().collect();
+  let mut i = chars.iter().position(|c| c.is_whitespace() || *c == '/').unwrap_or(chars.len());
+  let name = chars[..i].iter().collect::<String>().to_lowercase();
+  let mut attributes = vec![];
+  loop {
+    while chars.get(i).is_some_and(|c| c.is_whitespace() || *c == '/') {
+      i += 1;
+    }
+    if i >= chars.len() {
+      return (name, attributes);
+    }
+    let start = i;
+    while chars.get(i).is_some_and(|c| !c.is_whitespace() && *c != '=') {
+      i += 1;
+    }
+    let key = chars[start..i].iter().collect::<String>().to_lowercase();
+    let mut value = String::new();
+    if chars.get(i) == Some(&'=') {
+      i += 1;
+      let raw: Vec<char> = match chars.get(i) {
+        Some(q @ '"') | Some(q @ '\'') => {
+          let end = chars[i + 1..].iter().position(|c| c == q).map_or(chars.len(), |p| i + 1 + p);
+          let raw = chars[i + 1..end].to_vec();
+          i = end + 1;
+          raw
+        }
+        _ => {
+          let start = i;
+          while chars.get(i).is_some_and(|c| !c.is_whitespace()) {
+            i += 1;
+          }
+          chars[start..i].to_vec()
+        }
+      };
+      let mut j = 0;
+      while j < raw.len() {
+        let (c, next) = if raw[j] == '&' { character_entity(&raw, j + 1).unwrap_or(('&', j + 1)) } else { (raw[j], j + 1) };
+        value.push(c);
+        j = next;
+      }
+    }
+    attributes.push((key, value));
+  }
+}
+
+fn attribute(attributes: &[(String, String)], key: &str) -> Option<String> {
+  attributes.iter().find(|(k, _)| k == key).map(|(_, value)| value.clone())
+}
+
+// character of an entity after `&`, with the index after its `;`
+fn character_entity(chars: &[char], i: usize) -> Option<(char, usize)> {
+  let end = i + chars[i..].iter().take(10).position(|c| *c == ';')?;
+  let name: String = chars[i..end].iter().collect();
+  let c = match name.as_str() {
+    "lt" => '<',
+    "gt" => '>',
+    "amp" => '&',
+    "quot" => '"',
+    "apos" => '\'',
+    "nbsp" => '\u{a0}',
+    _ => {
+      let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => name.strip_prefix('#')?.parse().ok()?,
+      };
+      char::from_u32(code)?
+    }
+  };
+  Some((c, end + 1))
+}
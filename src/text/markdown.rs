@@ -0,0 +1,220 @@
+//! Telegram MarkdownV2: `*bold*`, `_italic_`, `__underline__`, `~strikethrough~`, `` `code` ``,
+//! ```` ```language\npre``` ````, `[text](url)` and `[text](tg://user?id=123)`.
+
+use crate::errors::*;
+use crate::types::*;
+
+use super::markup::{events, Event, Markup, TextBuilder};
+
+// characters escaped in plain text, any ascii character can be escaped
+const RESERVED: &str = "_*[]()~`>#+-=|{}.!\\\r";
+
+/// Render a formatted text as MarkdownV2, entities markdown can't express are rendered as plain text
+pub fn render(text: &FormattedText) -> String {
+  let mut out = String::with_capacity(text.text().len());
+  let mut in_code = false;
+  // `_` markers next to each other are separated by `\r`, or `__` would be read as underline
+  let mut after_underscore = false;
+  for event in events(text.text(), text.entities()) {
+    let marker = match event {
+      Event::Text(s) => {
+        escape(s, in_code, &mut out);
+        after_underscore = false;
+        continue;
+      }
+      Event::Open(markup) => {
+        in_code = matches!(markup, Markup::Code | Markup::Pre(_));
+        match markup {
+          Markup::Pre(language) => format!("```{}\n", language),
+          Markup::TextUrl(_) | Markup::MentionName(_) => "[".to_string(),
+          markup => style_marker(&markup).to_string(),
+        }
+      }
+      Event::Close(markup) => {
+        in_code = false;
+        match markup {
+          Markup::Pre(_) => "```".to_string(),
+          Markup::TextUrl(_) | Markup::MentionName(_) => {
+            let mut link = "](".to_string();
+            markup.url().unwrap_or_default().chars().for_each(|c| {
+              if c == ')' || c == '\\' { link.push('\\') }
+              link.push(c);
+            });
+            link.push(')');
+            link
+          }
+          markup => style_marker(&markup).to_string(),
+        }
+      }
+    };
+    if after_underscore && marker.starts_with('_') {
+      out.push('\r');
+    }
+    after_underscore = marker.ends_with('_');
+    out.push_str(&marker);
+  }
+  out
+}
+
+fn style_marker(markup: &Markup) -> &'static str {
+  match markup {
+    Markup::Bold => "*",
+    Markup::Italic => "_",
+    Markup::Underline => "__",
+    Markup::Strikethrough => "~",
+    _ => "`",
+  }
+}
+
+fn escape(text: &str, in_code: bool, out: &mut String) {
+  for c in text.chars() {
+    let reserved = if in_code { c == '`' || c == '\\' } else { RESERVED.contains(c) };
+    if reserved {
+      out.push('\\');
+    }
+    out.push(c);
+  }
+}
+
+/// Parse MarkdownV2 into a formatted text, unescaped reserved characters without a meaning are kept as text
+pub fn parse<S: AsRef<str>>(markdown: S) -> RTDResult<FormattedText> {
+  let chars: Vec<char> = markdown.as_ref().chars().collect();
+  let mut builder = TextBuilder::default();
+  // open styles with their offset, `None` is a link
+  let mut open: Vec<(Option<Markup>, i64)> = vec![];
+  let mut i = 0;
+  while i < chars.len() {
+    let c = chars[i];
+    i += 1;
+    let style = match c {
+      '\\' => {
+        let escaped = chars.get(i).ok_or_else(|| RTDError::custom("markdown ends with an unescaped `\\`"))?;
+        builder.push(*escaped);
+        i += 1;
+        continue;
+      }
+      '*' => Markup::Bold,
+      '~' => Markup::Strikethrough,
+      '_' => {
+        let style = if chars.get(i) == Some(&'_') {
+          i += 1;
+          Markup::Underline
+        } else {
+          Markup::Italic
+        };
+        if chars.get(i) == Some(&'\r') {
+          i += 1;
+        }
+        style
+      }
+      '`' if chars[i..].starts_with(&['`', '`']) => {
+        i = pre(&chars, i + 2, &mut builder)?;
+        continue;
+      }
+      '`' => {
+        let offset = builder.offset();
+        i = code(&chars, i, &mut builder).ok_or_else(|| RTDError::message(format!("markdown: unclosed code at {}", i - 1)))?;
+        builder.entity(offset, Markup::Code);
+        continue;
+      }
+      '[' => {
+        open.push((None, builder.offset()));
+        continue;
+      }
+      ']' => {
+        let at = open.iter().rposition(|(markup, _)| markup.is_none())
+          .ok_or_else(|| RTDError::message(format!("markdown: unexpected `]` at {}", i - 1)))?;
+        let (_, offset) = open.remove(at);
+        if chars.get(i) != Some(&'(') {
+          return Err(RTDError::message(format!("markdown: expected `(` after `]` at {}", i - 1)));
+        }
+        let (url, next) = url(&chars, i + 1).ok_or_else(|| RTDError::message(format!("markdown: unclosed url at {}", i)))?;
+        i = next;
+        builder.entity(offset, Markup::link(url));
+        continue;
+      }
+      c => {
+        builder.push(c);
+        continue;
+      }
+    };
+    match open.iter().rposition(|(markup, _)| markup.as_ref() == Some(&style)) {
+      Some(at) => {
+        let (_, offset) = open.remove(at);
+        builder.entity(offset, style);
+      }
+      None => open.push((Some(style), builder.offset())),
+    }
+  }
+  if let Some((markup, offset)) = open.last() {
+    let name = markup.as_ref().map_or("link", |markup| style_marker(markup));
+    return Err(RTDError::message(format!("markdown: unclosed `{}` opened at text offset {}", name, offset)));
+  }
+  Ok(builder.build())
+}
+
+// code text until an unescaped backtick, index after the backtick
+fn code(chars: &[char], mut i: usize, builder: &mut TextBuilder) -> Option<usize> {
+  loop {
+    match chars.get(i)? {
+      '`' => return Some(i + 1),
+      '\\' if i + 1 < chars.len() => {
+        builder.push(chars[i + 1]);
+        i += 2;
+      }
+      c => {
+        builder.push(*c);
+        i += 1;
+      }
+    }
+  }
+}
+
+// pre block after the opening backticks, the first line is the language if the block has more than one line
+fn pre(chars: &[char], mut i: usize, builder: &mut TextBuilder) -> RTDResult<usize> {
+  let start = i;
+  let mut language = String::new();
+  let first_line = chars[i..].iter().position(|c| matches!(c, '\n' | '`' | '\\'));
+  if let Some(end) = first_line {
+    if chars[i + end] == '\n' {
+      language = chars[i..i + end].iter().collect();
+      i += end + 1;
+    }
+  }
+  let offset = builder.offset();
+  loop {
+    match chars.get(i) {
+      None => return Err(RTDError::message(format!("markdown: unclosed pre at {}", start - 3))),
+      Some('`') if chars[i..].starts_with(&['`', '`', '`']) => {
+        builder.entity(offset, Markup::Pre(language));
+        return Ok(i + 3);
+      }
+      Some('\\') if i + 1 < chars.len() => {
+        builder.push(chars[i + 1]);
+        i += 2;
+      }
+      Some(c) => {
+        builder.push(*c);
+        i += 1;
+      }
+    }
+  }
+}
+
+// url until an unescaped `)`, index after the `)`
+fn url(chars: &[char], mut i: usize) -> Option<(String, usize)> {
+  let mut url = String::new();
+  loop {
+    match chars.get(i)? {
+      ')' => return Some((url, i + 1)),
+      '\\' if i + 1 < chars.len() => {
+        url.push(chars[i + 1]);
+        i += 2;
+      }
+      c => {
+        url.push(*c);
+        i += 1;
+      }
+    }
+  }
+}
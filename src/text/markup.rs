@@ -0,0 +1,228 @@
+use std::cmp::Reverse;
+use std::convert::TryFrom;
+use std::mem::discriminant;
+
+use crate::types::*;
+
+/// Entity types that markdown and html can express
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Markup {
+  Bold,
+  Italic,
+  Underline,
+  Strikethrough,
+  Code,
+  /// Pre block with the language of the code, empty for `TextEntityTypePre`
+  Pre(String),
+  TextUrl(String),
+  MentionName(i64),
+}
+
+/// Link to a user without username, the url of a `MentionName`
+pub(crate) const MENTION_URL: &str = "tg://user?id=";
+
+impl Markup {
+  pub fn from_type(type_: &TextEntityType) -> Option<Self> {
+    Some(match type_ {
+      TextEntityType::Bold(_) => Markup::Bold,
+      TextEntityType::Italic(_) => Markup::Italic,
+      TextEntityType::Underline(_) => Markup::Underline,
+      TextEntityType::Strikethrough(_) => Markup::Strikethrough,
+      TextEntityType::Code(_) => Markup::Code,
+      TextEntityType::Pre(_) => Markup::Pre(String::new()),
+      TextEntityType::PreCode(t) => Markup::Pre(t.language().clone()),
+      TextEntityType::TextUrl(t) => Markup::TextUrl(t.url().clone()),
+      TextEntityType::MentionName(t) => Markup::MentionName(t.user_id()),
+      _ => return None,
+    })
+  }
+
+  pub fn to_type(&self) -> TextEntityType {
+    match self {
+      Markup::Bold => TextEntityType::bold(TextEntityTypeBold::builder().build()),
+      Markup::Italic => TextEntityType::italic(TextEntityTypeItalic::builder().build()),
+      Markup::Underline => TextEntityType::underline(TextEntityTypeUnderline::builder().build()),
+      Markup::Strikethrough => TextEntityType::strikethrough(TextEntityTypeStrikethrough::builder().build()),
+      Markup::Code => TextEntityType::code(TextEntityTypeCode::builder().build()),
+      Markup::Pre(language) if language.is_empty() => TextEntityType::pre(TextEntityTypePre::builder().build()),
+      Markup::Pre(language) => TextEntityType::pre_code(TextEntityTypePreCode::builder().language(language).build()),
+      Markup::TextUrl(url) => TextEntityType::text_url(TextEntityTypeTextUrl::builder().url(url).build()),
+      Markup::MentionName(user_id) => TextEntityType::mention_name(TextEntityTypeMentionName::builder().user_id(*user_id).build()),
+    }
+  }
+
+  /// Url of a link entity
+  pub fn url(&self) -> Option<String> {
+    match self {
+      Markup::TextUrl(url) => Some(url.clone()),
+      Markup::MentionName(user_id) => Some(format!("{}{}", MENTION_URL, user_id)),
+      _ => None,
+    }
+  }
+
+  /// Link entity from a url, `tg://user?id=` links are mentions
+  pub fn link(url: String) -> Self {
+    match url.strip_prefix(MENTION_URL).and_then(|id| id.parse().ok()) {
+      Some(user_id) => Markup::MentionName(user_id),
+      None => Markup::TextUrl(url),
+    }
+  }
+
+  fn is_code(&self) -> bool { matches!(self, Markup::Code | Markup::Pre(_)) }
+
+  fn is_link(&self) -> bool { matches!(self, Markup::TextUrl(_) | Markup::MentionName(_)) }
+
+  // entities with the same range are nested in this order, links first and code last as code can't contain other entities
+  fn rank(&self) -> u8 {
+    match self {
+      Markup::TextUrl(_) | Markup::MentionName(_) => 0,
+      Markup::Bold => 1,
+      Markup::Italic => 2,
+      Markup::Underline => 3,
+      Markup::Strikethrough => 4,
+      Markup::Code => 5,
+      Markup::Pre(_) => 6,
+    }
+  }
+
+  fn same_kind(&self, other: &Markup) -> bool {
+    discriminant(self) == discriminant(other) || (self.is_link() && other.is_link())
+  }
+}
+
+/// Piece of a formatted text in rendering order
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Event<'a> {
+  Text(&'a str),
+  Open(Markup),
+  Close(Markup),
+}
+
+#[derive(Debug, Clone)]
+struct Span {
+  start: usize,
+  end: usize,
+  markup: Markup,
+}
+
+impl Span {
+  fn key(&self) -> (usize, Reverse<usize>, u8) { (self.start, Reverse(self.end), self.markup.rank()) }
+}
+
+/// Byte index of every UTF-16 offset of `text`, `None` for an offset inside a surrogate pair
+pub(crate) fn utf16_to_byte(text: &str) -> Vec<Option<usize>> {
+  let mut offsets = Vec::with_capacity(text.len() + 1);
+  for (index, c) in text.char_indices() {
+    offsets.push(Some(index));
+    if c.len_utf16() == 2 {
+      offsets.push(None);
+    }
+  }
+  offsets.push(Some(text.len()));
+  offsets
+}
+
+/// Split a formatted text into text and properly nested open and close events. Overlapping entities are split
+/// at the end of the entity they cross, entities inside code or of the same kind as an enclosing one are dropped,
+/// entities out of the text or inside a surrogate pair are ignored.
+pub(crate) fn events<'a>(text: &'a str, entities: &[TextEntity]) -> Vec<Event<'a>> {
+  let offsets = utf16_to_byte(text);
+  let byte = |offset: i64| usize::try_from(offset).ok().and_then(|offset| offsets.get(offset).copied().flatten());
+  let mut spans: Vec<Span> = entities.iter()
+    .filter_map(|entity| {
+      let markup = Markup::from_type(entity.type_())?;
+      let start = byte(entity.offset())?;
+      let end = byte(entity.offset().checked_add(entity.length())?)?;
+      if start < end { Some(Span { start, end, markup }) } else { None }
+    })
+    .collect();
+  spans.sort_by_key(Span::key);
+
+  let mut nested: Vec<Span> = vec![];
+  let mut stack: Vec<Span> = vec![];
+  let mut index = 0;
+  while index < spans.len() {
+    let mut span = spans[index].clone();
+    index += 1;
+    while stack.last().is_some_and(|top| top.end <= span.start) {
+      stack.pop();
+    }
+    if let Some(top) = stack.last() {
+      if span.end > top.end {
+        let rest = Span { start: top.end, end: span.end, markup: span.markup.clone() };
+        let at = index + spans[index..].partition_point(|s| s.key() <= rest.key());
+        spans.insert(at, rest);
+        span.end = top.end;
+      }
+    }
+    if stack.iter().any(|s| s.markup.is_code() || s.markup.same_kind(&span.markup)) {
+      continue;
+    }
+    stack.push(span.clone());
+    nested.push(span);
+  }
+  // pieces of split entities can share a range with an entity they were nested in
+  nested.sort_by_key(Span::key);
+
+  let mut events = vec![];
+  let mut position = 0;
+  let mut open: Vec<Span> = vec![];
+  let text_to = |events: &mut Vec<Event<'a>>, position: &mut usize, to: usize| {
+    if *position < to {
+      events.push(Event::Text(&text[*position..to]));
+      *position = to;
+    }
+  };
+  for span in nested {
+    while open.last().is_some_and(|top| top.end <= span.start) {
+      let top = open.pop().unwrap();
+      text_to(&mut events, &mut position, top.end);
+      events.push(Event::Close(top.markup));
+    }
+    text_to(&mut events, &mut position, span.start);
+    events.push(Event::Open(span.markup.clone()));
+    open.push(span);
+  }
+  while let Some(top) = open.pop() {
+    text_to(&mut events, &mut position, top.end);
+    events.push(Event::Close(top.markup));
+  }
+  text_to(&mut events, &mut position, text.len());
+  events
+}
+
+/// Formatted text built by a parser, entities are added when they are closed
+#[derive(Debug, Default)]
+pub(crate) struct TextBuilder {
+  text: String,
+  utf16_len: i64,
+  entities: Vec<(TextEntity, u8)>,
+}
+
+impl TextBuilder {
+  /// Current UTF-16 offset, the offset of an entity opened now
+  pub fn offset(&self) -> i64 { self.utf16_len }
+
+  pub fn push(&mut self, c: char) {
+    self.text.push(c);
+    self.utf16_len += c.len_utf16() as i64;
+  }
+
+  /// Add an entity from `offset` to the current offset, empty entities are dropped
+  pub fn entity(&mut self, offset: i64, markup: Markup) {
+    if offset < self.utf16_len {
+      let rank = markup.rank();
+      let entity = TextEntity::builder().offset(offset).length(self.utf16_len - offset).type_(markup.to_type()).build();
+      self.entities.push((entity, rank));
+    }
+  }
+
+  pub fn build(self) -> FormattedText {
+    let mut entities = self.entities;
+    entities.sort_by_key(|(entity, rank)| (entity.offset(), Reverse(entity.length()), *rank));
+    FormattedText::builder()
+      .text(self.text)
+      .entities(entities.into_iter().map(|(entity, _)| entity).collect())
+      .build()
+  }
+}
@@ -0,0 +1,11 @@
+//! Offline helpers for `FormattedText`, entity offsets and lengths are in UTF-16 code units like tdlib sends them.
+
+mod markup;
+pub mod entities;
+pub mod detect;
+pub mod markdown;
+pub mod html;
+pub mod content;
+
+pub use self::content::{MessageContentExt, NameResolver, NoNames};
+pub use self::entities::FormattedTextExt;
@@ -1,10 +1,17 @@
 use std::fmt::Debug;
 
+use serde::{Serialize, Serializer};
+use serde::de::DeserializeOwned;
+
 use crate::errors::*;
 use crate::types::*;
 
 macro_rules! rtd_enum_deserialize {
   ($type_name:ident, $(($td_name:ident, $enum_item:ident));*;) => {
+    rtd_enum_deserialize!(@unknown Unknown, $type_name, $(($td_name, $enum_item));*;)
+  };
+  // `$unknown` is the variant keeping unknown types, named `UnknownType` when tdlib already has an `Unknown` variant
+  (@unknown $unknown:ident, $type_name:ident, $(($td_name:ident, $enum_item:ident));*;) => {
     // example json
     // {"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}
     |deserializer: D| -> Result<$type_name, D::Error> {
@@ -12,26 +19,29 @@ macro_rules! rtd_enum_deserialize {
       // the `rtd_trait_value` variable type is &serde_json::Value, tdlib trait will return a object, convert this type to object `&Map<String, Value>`
       let rtd_trait_map = match rtd_trait_value.as_object() {
         Some(map) => map,
-        None => return Err(D::Error::unknown_field(stringify!($type_name), &[stringify!("{} is not the correct type", $type_name)])) // &format!("{} is not the correct type", stringify!($field))[..]
+        None => return Err(D::Error::custom(format!("{} is not a json object: {}", stringify!($type_name), rtd_trait_value)))
       };
       // get `@type` value, detect specific types
       let rtd_trait_type = match rtd_trait_map.get("@type") {
         // the `t` variable type is `serde_json::Value`, convert `t` to str
         Some(t) => match t.as_str() {
           Some(s) => s,
-          None => return Err(D::Error::unknown_field(stringify!("{} -> @type", $field), &[stringify!("{} -> @type is not the correct type", $type_name)])) // &format!("{} -> @type is not the correct type", stringify!($field))[..]
+          None => return Err(D::Error::custom(format!("{} -> @type is not a string: {}", stringify!($type_name), t)))
         },
-        None => return Err(D::Error::missing_field(stringify!("{} -> @type", $field)))
+        None => return Err(D::Error::custom(format!("{} -> missing field `@type`", stringify!($type_name))))
       };
 
       let obj = match rtd_trait_type {
         $(
-          stringify!($td_name) => $type_name::$enum_item(match serde_json::from_value(rtd_trait_value.clone()) {
+          stringify!($td_name) => $type_name::$enum_item(match serde_path_to_error::deserialize(&rtd_trait_value) {
             Ok(t) => t,
-            Err(_e) => return Err(D::Error::unknown_field(stringify!("{} can't deserialize to {}::{}", $td_name, $type_name, $enum_item, _e), &[stringify!("{:?}", _e)]))
+            // keep the inner error and the path of the failing field, nested types chain to
+            // `Update::NewMessage(updateNewMessage) -> message.content: MessageContent::MessageText(messageText) -> ...`
+            Err(e) => return Err(D::Error::custom(format!("{}::{}({}) -> {}", stringify!($type_name), stringify!($enum_item), rtd_trait_type, e)))
           }),
         )*
-        _ => return Err(D::Error::missing_field(stringify!($field)))
+        // keep the object tdlib sent, a newer tdlib may add types this crate doesn't know
+        _ => $type_name::$unknown { td_type: rtd_trait_type.to_string(), raw: rtd_trait_value.clone() }
       };
       Ok(obj)
     }
@@ -64,6 +74,8 @@ macro_rules! rtd_enum_deserialize {
 //  };
 //}
 
+/// `@type` of a tdlib json object
+#[deprecated(note = "deserialize with `TdObject::from_json` instead")]
 pub fn detect_td_type<S: AsRef<str>>(json: S) -> Option<String> {
   let result: Result<serde_json::Value, serde_json::Error> = serde_json::from_str::<serde_json::Value>(json.as_ref());
   if let Err(_) = result { return None }
@@ -75,34 +87,130 @@ pub fn detect_td_type<S: AsRef<str>>(json: S) -> Option<String> {
   })
 }
 
+/// Serialize `Unknown` variant of tdlib enums back to the json tdlib sent
+pub(crate) fn serialize_unknown<S: Serializer>(_td_type: &str, raw: &serde_json::Value, serializer: S) -> Result<S::Ok, S::Error> {
+  raw.serialize(serializer)
+}
+
+/// TL `int64` is a json string in tdlib, read from string or number and always write string
+pub(crate) mod int64_str {
+  use serde::{Deserializer, Serializer};
+
+  pub fn serialize<S: Serializer>(number: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&number.to_string())
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    serde_aux::field_attributes::deserialize_number_from_string(deserializer)
+  }
+}
+
+/// TL `vector<int64>`, every item is the same as `int64_str`
+pub(crate) mod vec_int64_str {
+  use serde::{Deserialize, Deserializer, Serializer};
+  use serde::ser::SerializeSeq;
+
+  #[derive(Deserialize)]
+  struct Int64(#[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] i64);
+
+  pub fn serialize<S: Serializer>(numbers: &[i64], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(numbers.len()))?;
+    for number in numbers {
+      seq.serialize_element(&number.to_string())?;
+    }
+    seq.end()
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<i64>, D::Error> {
+    let numbers: Vec<Int64> = Deserialize::deserialize(deserializer)?;
+    Ok(numbers.into_iter().map(|n| n.0).collect())
+  }
+}
+
+/// TL `bytes`, tdlib sends and expects them as a base64 json string
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TdBytes(Vec<u8>);
+
+impl TdBytes {
+  pub fn new(bytes: Vec<u8>) -> Self { TdBytes(bytes) }
+  /// Decode base64, standard or url safe, with or without padding
+  pub fn from_base64<T: AsRef<[u8]>>(text: T) -> RTDResult<Self> { Ok(TdBytes(crate::base64::decode(text)?)) }
+  pub fn to_base64(&self) -> String { crate::base64::encode(&self.0) }
+  pub fn as_slice(&self) -> &[u8] { &self.0 }
+  pub fn into_vec(self) -> Vec<u8> { self.0 }
+}
+
+impl std::ops::Deref for TdBytes {
+  type Target = [u8];
+  fn deref(&self) -> &[u8] { &self.0 }
+}
+
+impl AsRef<[u8]> for TdBytes {
+  fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl From<Vec<u8>> for TdBytes {
+  fn from(bytes: Vec<u8>) -> Self { TdBytes(bytes) }
+}
+
+impl From<&[u8]> for TdBytes {
+  fn from(bytes: &[u8]) -> Self { TdBytes(bytes.to_vec()) }
+}
+
+impl From<TdBytes> for Vec<u8> {
+  fn from(bytes: TdBytes) -> Self { bytes.0 }
+}
+
+impl Serialize for TdBytes {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serializer.serialize_str(&self.to_base64()) }
+}
+
+impl<'de> serde::Deserialize<'de> for TdBytes {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let text: String = serde::Deserialize::deserialize(deserializer)?;
+    TdBytes::from_base64(&text).map_err(|e| serde::de::Error::custom(format!("invalid bytes {:?}: {}", text, e)))
+  }
+}
+
 pub fn from_json<'a, T>(json: &'a str) -> RTDResult<T> where T: serde::de::Deserialize<'a>, {
-  Ok(serde_json::from_str(json.as_ref())?)
+  let mut deserializer = serde_json::Deserializer::from_str(json);
+  let value = serde_path_to_error::deserialize(&mut deserializer)?;
+  deserializer.end()?;
+  Ok(value)
 }
 
 /// All tdlib type abstract class defined the same behavior
 pub trait RObject: Debug {
   #[doc(hidden)]
   fn td_name(&self) -> &'static str;
+  /// The `@extra` value of this object, any json value, tdlib sends back the `@extra` of a request with its response
+  fn extra(&self) -> Option<&serde_json::Value>;
   /// Return td type to json string
   fn to_json(&self) -> RTDResult<String>;
 }
 
-pub trait RFunction: Debug + RObject {}
+/// All tdlib functions, each function knows the type tdlib will send back for it
+pub trait RFunction: Debug + RObject {
+  /// The object tdlib returns when this function success
+  type Response: DeserializeOwned + RObject;
+}
 
 
 impl<'a, RObj: RObject> RObject for &'a RObj {
   fn td_name(&self) -> &'static str { (*self).td_name() }
+  fn extra(&self) -> Option<&serde_json::Value> { (*self).extra() }
   fn to_json(&self) -> RTDResult<String> { (*self).to_json() }
 }
 
 impl<'a, RObj: RObject> RObject for &'a mut RObj {
   fn td_name(&self) -> &'static str { (**self).td_name() }
+  fn extra(&self) -> Option<&serde_json::Value> { (**self).extra() }
   fn to_json(&self) -> RTDResult<String> { (**self).to_json() }
 }
 
 
-impl<'a, Fnc: RFunction> RFunction for &'a Fnc {}
-impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {}
+impl<'a, Fnc: RFunction> RFunction for &'a Fnc { type Response = Fnc::Response; }
+impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc { type Response = Fnc::Response; }
 
 
 impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType for &'a AUTHENTICATIONCODETYPE {}
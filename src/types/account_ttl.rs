@@ -11,6 +11,9 @@ pub struct AccountTtl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Number of days of inactivity before the account will be flagged for deletion; should range from 30-366 days
   days: i64,
   
@@ -18,6 +21,7 @@ pub struct AccountTtl {
 
 impl RObject for AccountTtl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "accountTtl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDAccountTtlBuilder {
 impl RTDAccountTtlBuilder {
   pub fn build(&self) -> AccountTtl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn days(&mut self, days: i64) -> &mut Self {
     self.inner.days = days;
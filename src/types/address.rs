@@ -11,6 +11,9 @@ pub struct Address {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A two-letter ISO 3166-1 alpha-2 country code
   country_code: String,
   /// State, if applicable
@@ -28,6 +31,7 @@ pub struct Address {
 
 impl RObject for Address {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "address" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -63,6 +67,11 @@ pub struct RTDAddressBuilder {
 impl RTDAddressBuilder {
   pub fn build(&self) -> Address { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
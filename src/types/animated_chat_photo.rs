@@ -11,6 +11,9 @@ pub struct AnimatedChatPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Animation width and height
   length: i64,
   /// Information about the animation file
@@ -22,6 +25,7 @@ pub struct AnimatedChatPhoto {
 
 impl RObject for AnimatedChatPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "animatedChatPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDAnimatedChatPhotoBuilder {
 impl RTDAnimatedChatPhotoBuilder {
   pub fn build(&self) -> AnimatedChatPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -11,6 +11,9 @@ pub struct Animation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Duration of the animation, in seconds; as defined by the sender
   duration: i64,
   /// Width of the animation
@@ -34,6 +37,7 @@ pub struct Animation {
 
 impl RObject for Animation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "animation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -75,6 +79,11 @@ pub struct RTDAnimationBuilder {
 impl RTDAnimationBuilder {
   pub fn build(&self) -> Animation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -11,6 +11,9 @@ pub struct Animations {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of animations
   animations: Vec<Animation>,
   
@@ -18,6 +21,7 @@ pub struct Animations {
 
 impl RObject for Animations {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "animations" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDAnimationsBuilder {
 impl RTDAnimationsBuilder {
   pub fn build(&self) -> Animations { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animations(&mut self, animations: Vec<Animation>) -> &mut Self {
     self.inner.animations = animations;
@@ -11,6 +11,9 @@ pub struct Audio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Duration of the audio, in seconds; as defined by the sender
   duration: i64,
   /// Title of the audio; as defined by the sender
@@ -32,6 +35,7 @@ pub struct Audio {
 
 impl RObject for Audio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "audio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -71,6 +75,11 @@ pub struct RTDAudioBuilder {
 impl RTDAudioBuilder {
   pub fn build(&self) -> Audio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -11,6 +11,9 @@ pub struct AuthenticationCodeInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A phone number that is being authenticated
   phone_number: String,
   /// Describes the way the code was sent to the user
@@ -24,6 +27,7 @@ pub struct AuthenticationCodeInfo {
 
 impl RObject for AuthenticationCodeInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDAuthenticationCodeInfoBuilder {
 impl RTDAuthenticationCodeInfoBuilder {
   pub fn build(&self) -> AuthenticationCodeInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDAuthenticationCodeType: Debug + RObject {}
 #[serde(untagged)]
 pub enum AuthenticationCodeType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An authentication code is delivered via a phone call to the specified phone number
   Call(AuthenticationCodeTypeCall),
   /// An authentication code is delivered by an immediately cancelled call to the specified phone number. The number from which the call was made is the code
@@ -58,12 +61,24 @@ impl RObject for AuthenticationCodeType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      AuthenticationCodeType::Call(t) => t.extra(),
+      AuthenticationCodeType::FlashCall(t) => t.extra(),
+      AuthenticationCodeType::Sms(t) => t.extra(),
+      AuthenticationCodeType::TelegramMessage(t) => t.extra(),
+
+      AuthenticationCodeType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl AuthenticationCodeType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthenticationCodeType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let AuthenticationCodeType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_call(&self) -> bool { if let AuthenticationCodeType::Call(_) = self { true } else { false } }
   pub fn is_flash_call(&self) -> bool { if let AuthenticationCodeType::FlashCall(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct AuthenticationCodeTypeCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Length of the code
   length: i64,
   
@@ -115,6 +133,7 @@ pub struct AuthenticationCodeTypeCall {
 
 impl RObject for AuthenticationCodeTypeCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +162,11 @@ pub struct RTDAuthenticationCodeTypeCallBuilder {
 impl RTDAuthenticationCodeTypeCallBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -171,6 +195,9 @@ pub struct AuthenticationCodeTypeFlashCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pattern of the phone number from which the call will be made
   pattern: String,
   
@@ -178,6 +205,7 @@ pub struct AuthenticationCodeTypeFlashCall {
 
 impl RObject for AuthenticationCodeTypeFlashCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeFlashCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -206,6 +234,11 @@ pub struct RTDAuthenticationCodeTypeFlashCallBuilder {
 impl RTDAuthenticationCodeTypeFlashCallBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeFlashCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn pattern<T: AsRef<str>>(&mut self, pattern: T) -> &mut Self {
     self.inner.pattern = pattern.as_ref().to_string();
@@ -234,6 +267,9 @@ pub struct AuthenticationCodeTypeSms {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Length of the code
   length: i64,
   
@@ -241,6 +277,7 @@ pub struct AuthenticationCodeTypeSms {
 
 impl RObject for AuthenticationCodeTypeSms {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeSms" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -269,6 +306,11 @@ pub struct RTDAuthenticationCodeTypeSmsBuilder {
 impl RTDAuthenticationCodeTypeSmsBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeSms { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -297,6 +339,9 @@ pub struct AuthenticationCodeTypeTelegramMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Length of the code
   length: i64,
   
@@ -304,6 +349,7 @@ pub struct AuthenticationCodeTypeTelegramMessage {
 
 impl RObject for AuthenticationCodeTypeTelegramMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeTelegramMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -332,6 +378,11 @@ pub struct RTDAuthenticationCodeTypeTelegramMessageBuilder {
 impl RTDAuthenticationCodeTypeTelegramMessageBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeTelegramMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
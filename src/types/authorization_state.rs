@@ -18,6 +18,9 @@ pub trait TDAuthorizationState: Debug + RObject {}
 #[serde(untagged)]
 pub enum AuthorizationState {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client
   Closed(AuthorizationStateClosed),
   /// TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received
@@ -40,8 +43,6 @@ pub enum AuthorizationState {
   WaitRegistration(AuthorizationStateWaitRegistration),
   /// TDLib needs TdlibParameters for initialization
   WaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
-  /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
-  GetAuthorizationState(GetAuthorizationState),
 
 }
 
@@ -65,7 +66,6 @@ impl<'de> Deserialize<'de> for AuthorizationState {
       (authorizationStateWaitPhoneNumber, WaitPhoneNumber);
       (authorizationStateWaitRegistration, WaitRegistration);
       (authorizationStateWaitTdlibParameters, WaitTdlibParameters);
-      (getAuthorizationState, GetAuthorizationState);
 
     )(deserializer)
   }
@@ -85,17 +85,35 @@ impl RObject for AuthorizationState {
       AuthorizationState::WaitPhoneNumber(t) => t.td_name(),
       AuthorizationState::WaitRegistration(t) => t.td_name(),
       AuthorizationState::WaitTdlibParameters(t) => t.td_name(),
-      AuthorizationState::GetAuthorizationState(t) => t.td_name(),
 
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      AuthorizationState::Closed(t) => t.extra(),
+      AuthorizationState::Closing(t) => t.extra(),
+      AuthorizationState::LoggingOut(t) => t.extra(),
+      AuthorizationState::Ready(t) => t.extra(),
+      AuthorizationState::WaitCode(t) => t.extra(),
+      AuthorizationState::WaitEncryptionKey(t) => t.extra(),
+      AuthorizationState::WaitOtherDeviceConfirmation(t) => t.extra(),
+      AuthorizationState::WaitPassword(t) => t.extra(),
+      AuthorizationState::WaitPhoneNumber(t) => t.extra(),
+      AuthorizationState::WaitRegistration(t) => t.extra(),
+      AuthorizationState::WaitTdlibParameters(t) => t.extra(),
+
+      AuthorizationState::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl AuthorizationState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthorizationState::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let AuthorizationState::Unknown { .. } = self { true } else { false } }
 
   pub fn is_closed(&self) -> bool { if let AuthorizationState::Closed(_) = self { true } else { false } }
   pub fn is_closing(&self) -> bool { if let AuthorizationState::Closing(_) = self { true } else { false } }
@@ -108,7 +126,6 @@ impl AuthorizationState {
   pub fn is_wait_phone_number(&self) -> bool { if let AuthorizationState::WaitPhoneNumber(_) = self { true } else { false } }
   pub fn is_wait_registration(&self) -> bool { if let AuthorizationState::WaitRegistration(_) = self { true } else { false } }
   pub fn is_wait_tdlib_parameters(&self) -> bool { if let AuthorizationState::WaitTdlibParameters(_) = self { true } else { false } }
-  pub fn is_get_authorization_state(&self) -> bool { if let AuthorizationState::GetAuthorizationState(_) = self { true } else { false } }
 
   pub fn on_closed<F: FnOnce(&AuthorizationStateClosed)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closed(t) = self { fnc(t) }; self }
   pub fn on_closing<F: FnOnce(&AuthorizationStateClosing)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closing(t) = self { fnc(t) }; self }
@@ -121,7 +138,6 @@ impl AuthorizationState {
   pub fn on_wait_phone_number<F: FnOnce(&AuthorizationStateWaitPhoneNumber)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitPhoneNumber(t) = self { fnc(t) }; self }
   pub fn on_wait_registration<F: FnOnce(&AuthorizationStateWaitRegistration)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitRegistration(t) = self { fnc(t) }; self }
   pub fn on_wait_tdlib_parameters<F: FnOnce(&AuthorizationStateWaitTdlibParameters)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitTdlibParameters(t) = self { fnc(t) }; self }
-  pub fn on_get_authorization_state<F: FnOnce(&GetAuthorizationState)>(&self, fnc: F) -> &Self { if let AuthorizationState::GetAuthorizationState(t) = self { fnc(t) }; self }
 
   pub fn as_closed(&self) -> Option<&AuthorizationStateClosed> { if let AuthorizationState::Closed(t) = self { return Some(t) } None }
   pub fn as_closing(&self) -> Option<&AuthorizationStateClosing> { if let AuthorizationState::Closing(t) = self { return Some(t) } None }
@@ -134,7 +150,6 @@ impl AuthorizationState {
   pub fn as_wait_phone_number(&self) -> Option<&AuthorizationStateWaitPhoneNumber> { if let AuthorizationState::WaitPhoneNumber(t) = self { return Some(t) } None }
   pub fn as_wait_registration(&self) -> Option<&AuthorizationStateWaitRegistration> { if let AuthorizationState::WaitRegistration(t) = self { return Some(t) } None }
   pub fn as_wait_tdlib_parameters(&self) -> Option<&AuthorizationStateWaitTdlibParameters> { if let AuthorizationState::WaitTdlibParameters(t) = self { return Some(t) } None }
-  pub fn as_get_authorization_state(&self) -> Option<&GetAuthorizationState> { if let AuthorizationState::GetAuthorizationState(t) = self { return Some(t) } None }
 
 
 
@@ -160,8 +175,6 @@ impl AuthorizationState {
 
   pub fn wait_tdlib_parameters<T: AsRef<AuthorizationStateWaitTdlibParameters>>(t: T) -> Self { AuthorizationState::WaitTdlibParameters(t.as_ref().clone()) }
 
-  pub fn get_authorization_state<T: AsRef<GetAuthorizationState>>(t: T) -> Self { AuthorizationState::GetAuthorizationState(t.as_ref().clone()) }
-
 }
 
 impl AsRef<AuthorizationState> for AuthorizationState {
@@ -180,11 +193,15 @@ pub struct AuthorizationStateClosed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for AuthorizationStateClosed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateClosed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -211,6 +228,11 @@ pub struct RTDAuthorizationStateClosedBuilder {
 impl RTDAuthorizationStateClosedBuilder {
   pub fn build(&self) -> AuthorizationStateClosed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateClosed> for AuthorizationStateClosed {
@@ -233,11 +255,15 @@ pub struct AuthorizationStateClosing {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for AuthorizationStateClosing {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateClosing" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -264,6 +290,11 @@ pub struct RTDAuthorizationStateClosingBuilder {
 impl RTDAuthorizationStateClosingBuilder {
   pub fn build(&self) -> AuthorizationStateClosing { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateClosing> for AuthorizationStateClosing {
@@ -286,11 +317,15 @@ pub struct AuthorizationStateLoggingOut {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for AuthorizationStateLoggingOut {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateLoggingOut" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -317,6 +352,11 @@ pub struct RTDAuthorizationStateLoggingOutBuilder {
 impl RTDAuthorizationStateLoggingOutBuilder {
   pub fn build(&self) -> AuthorizationStateLoggingOut { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateLoggingOut> for AuthorizationStateLoggingOut {
@@ -339,11 +379,15 @@ pub struct AuthorizationStateReady {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for AuthorizationStateReady {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateReady" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -370,6 +414,11 @@ pub struct RTDAuthorizationStateReadyBuilder {
 impl RTDAuthorizationStateReadyBuilder {
   pub fn build(&self) -> AuthorizationStateReady { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateReady> for AuthorizationStateReady {
@@ -392,6 +441,9 @@ pub struct AuthorizationStateWaitCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Information about the authorization code that was sent
   code_info: AuthenticationCodeInfo,
   
@@ -399,6 +451,7 @@ pub struct AuthorizationStateWaitCode {
 
 impl RObject for AuthorizationStateWaitCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -427,6 +480,11 @@ pub struct RTDAuthorizationStateWaitCodeBuilder {
 impl RTDAuthorizationStateWaitCodeBuilder {
   pub fn build(&self) -> AuthorizationStateWaitCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code_info<T: AsRef<AuthenticationCodeInfo>>(&mut self, code_info: T) -> &mut Self {
     self.inner.code_info = code_info.as_ref().clone();
@@ -455,6 +513,9 @@ pub struct AuthorizationStateWaitEncryptionKey {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the database is currently encrypted
   is_encrypted: bool,
   
@@ -462,6 +523,7 @@ pub struct AuthorizationStateWaitEncryptionKey {
 
 impl RObject for AuthorizationStateWaitEncryptionKey {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitEncryptionKey" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -490,6 +552,11 @@ pub struct RTDAuthorizationStateWaitEncryptionKeyBuilder {
 impl RTDAuthorizationStateWaitEncryptionKeyBuilder {
   pub fn build(&self) -> AuthorizationStateWaitEncryptionKey { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_encrypted(&mut self, is_encrypted: bool) -> &mut Self {
     self.inner.is_encrypted = is_encrypted;
@@ -518,6 +585,9 @@ pub struct AuthorizationStateWaitOtherDeviceConfirmation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A tg:// URL for the QR code. The link will be updated frequently
   link: String,
   
@@ -525,6 +595,7 @@ pub struct AuthorizationStateWaitOtherDeviceConfirmation {
 
 impl RObject for AuthorizationStateWaitOtherDeviceConfirmation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitOtherDeviceConfirmation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -553,6 +624,11 @@ pub struct RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
 impl RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
   pub fn build(&self) -> AuthorizationStateWaitOtherDeviceConfirmation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
@@ -581,6 +657,9 @@ pub struct AuthorizationStateWaitPassword {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Hint for the password; may be empty
   password_hint: String,
   /// True, if a recovery email address has been set up
@@ -592,6 +671,7 @@ pub struct AuthorizationStateWaitPassword {
 
 impl RObject for AuthorizationStateWaitPassword {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitPassword" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -624,6 +704,11 @@ pub struct RTDAuthorizationStateWaitPasswordBuilder {
 impl RTDAuthorizationStateWaitPasswordBuilder {
   pub fn build(&self) -> AuthorizationStateWaitPassword { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password_hint<T: AsRef<str>>(&mut self, password_hint: T) -> &mut Self {
     self.inner.password_hint = password_hint.as_ref().to_string();
@@ -664,11 +749,15 @@ pub struct AuthorizationStateWaitPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for AuthorizationStateWaitPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -695,6 +784,11 @@ pub struct RTDAuthorizationStateWaitPhoneNumberBuilder {
 impl RTDAuthorizationStateWaitPhoneNumberBuilder {
   pub fn build(&self) -> AuthorizationStateWaitPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateWaitPhoneNumber> for AuthorizationStateWaitPhoneNumber {
@@ -717,6 +811,9 @@ pub struct AuthorizationStateWaitRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Telegram terms of service
   terms_of_service: TermsOfService,
   
@@ -724,6 +821,7 @@ pub struct AuthorizationStateWaitRegistration {
 
 impl RObject for AuthorizationStateWaitRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -752,6 +850,11 @@ pub struct RTDAuthorizationStateWaitRegistrationBuilder {
 impl RTDAuthorizationStateWaitRegistrationBuilder {
   pub fn build(&self) -> AuthorizationStateWaitRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn terms_of_service<T: AsRef<TermsOfService>>(&mut self, terms_of_service: T) -> &mut Self {
     self.inner.terms_of_service = terms_of_service.as_ref().clone();
@@ -780,11 +883,15 @@ pub struct AuthorizationStateWaitTdlibParameters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for AuthorizationStateWaitTdlibParameters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitTdlibParameters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -811,6 +918,11 @@ pub struct RTDAuthorizationStateWaitTdlibParametersBuilder {
 impl RTDAuthorizationStateWaitTdlibParametersBuilder {
   pub fn build(&self) -> AuthorizationStateWaitTdlibParameters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateWaitTdlibParameters> for AuthorizationStateWaitTdlibParameters {
@@ -11,6 +11,9 @@ pub struct AutoDownloadSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the auto-download is enabled
   is_auto_download_enabled: bool,
   /// The maximum size of a photo file to be auto-downloaded
@@ -32,6 +35,7 @@ pub struct AutoDownloadSettings {
 
 impl RObject for AutoDownloadSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "autoDownloadSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -71,6 +75,11 @@ pub struct RTDAutoDownloadSettingsBuilder {
 impl RTDAutoDownloadSettingsBuilder {
   pub fn build(&self) -> AutoDownloadSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_auto_download_enabled(&mut self, is_auto_download_enabled: bool) -> &mut Self {
     self.inner.is_auto_download_enabled = is_auto_download_enabled;
@@ -11,6 +11,9 @@ pub struct AutoDownloadSettingsPresets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Preset with lowest settings; supposed to be used by default when roaming
   low: AutoDownloadSettings,
   /// Preset with medium settings; supposed to be used by default when using mobile data
@@ -22,6 +25,7 @@ pub struct AutoDownloadSettingsPresets {
 
 impl RObject for AutoDownloadSettingsPresets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "autoDownloadSettingsPresets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDAutoDownloadSettingsPresetsBuilder {
 impl RTDAutoDownloadSettingsPresetsBuilder {
   pub fn build(&self) -> AutoDownloadSettingsPresets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn low<T: AsRef<AutoDownloadSettings>>(&mut self, low: T) -> &mut Self {
     self.inner.low = low.as_ref().clone();
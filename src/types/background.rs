@@ -11,8 +11,11 @@ pub struct Background {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique background identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// True, if this is one of default backgrounds
   is_default: bool,
   /// True, if the background is dark and is recommended to be used with dark theme
@@ -28,6 +31,7 @@ pub struct Background {
 
 impl RObject for Background {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "background" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -41,7 +45,7 @@ impl Background {
     RTDBackgroundBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn is_default(&self) -> bool { self.is_default }
 
@@ -63,8 +67,13 @@ pub struct RTDBackgroundBuilder {
 impl RTDBackgroundBuilder {
   pub fn build(&self) -> Background { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
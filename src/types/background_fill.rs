@@ -18,6 +18,9 @@ pub trait TDBackgroundFill: Debug + RObject {}
 #[serde(untagged)]
 pub enum BackgroundFill {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Describes a gradient fill of a background
   Gradient(BackgroundFillGradient),
   /// Describes a solid fill of a background
@@ -50,12 +53,22 @@ impl RObject for BackgroundFill {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      BackgroundFill::Gradient(t) => t.extra(),
+      BackgroundFill::Solid(t) => t.extra(),
+
+      BackgroundFill::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl BackgroundFill {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BackgroundFill::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let BackgroundFill::Unknown { .. } = self { true } else { false } }
 
   pub fn is_gradient(&self) -> bool { if let BackgroundFill::Gradient(_) = self { true } else { false } }
   pub fn is_solid(&self) -> bool { if let BackgroundFill::Solid(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct BackgroundFillGradient {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A top color of the background in the RGB24 format
   top_color: i64,
   /// A bottom color of the background in the RGB24 format
@@ -101,6 +117,7 @@ pub struct BackgroundFillGradient {
 
 impl RObject for BackgroundFillGradient {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundFillGradient" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -133,6 +150,11 @@ pub struct RTDBackgroundFillGradientBuilder {
 impl RTDBackgroundFillGradientBuilder {
   pub fn build(&self) -> BackgroundFillGradient { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn top_color(&mut self, top_color: i64) -> &mut Self {
     self.inner.top_color = top_color;
@@ -173,6 +195,9 @@ pub struct BackgroundFillSolid {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A color of the background in the RGB24 format
   color: i64,
   
@@ -180,6 +205,7 @@ pub struct BackgroundFillSolid {
 
 impl RObject for BackgroundFillSolid {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundFillSolid" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -208,6 +234,11 @@ pub struct RTDBackgroundFillSolidBuilder {
 impl RTDBackgroundFillSolidBuilder {
   pub fn build(&self) -> BackgroundFillSolid { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn color(&mut self, color: i64) -> &mut Self {
     self.inner.color = color;
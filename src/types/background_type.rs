@@ -18,6 +18,9 @@ pub trait TDBackgroundType: Debug + RObject {}
 #[serde(untagged)]
 pub enum BackgroundType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A filled background
   Fill(BackgroundTypeFill),
   /// A PNG or TGV (gzipped subset of SVG with MIME type "application/x-tgwallpattern") pattern to be combined with the background fill chosen by the user
@@ -54,12 +57,23 @@ impl RObject for BackgroundType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      BackgroundType::Fill(t) => t.extra(),
+      BackgroundType::Pattern(t) => t.extra(),
+      BackgroundType::Wallpaper(t) => t.extra(),
+
+      BackgroundType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl BackgroundType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BackgroundType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let BackgroundType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_fill(&self) -> bool { if let BackgroundType::Fill(_) = self { true } else { false } }
   pub fn is_pattern(&self) -> bool { if let BackgroundType::Pattern(_) = self { true } else { false } }
@@ -99,6 +113,9 @@ pub struct BackgroundTypeFill {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Description of the background fill
   fill: BackgroundFill,
   
@@ -106,6 +123,7 @@ pub struct BackgroundTypeFill {
 
 impl RObject for BackgroundTypeFill {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundTypeFill" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -134,6 +152,11 @@ pub struct RTDBackgroundTypeFillBuilder {
 impl RTDBackgroundTypeFillBuilder {
   pub fn build(&self) -> BackgroundTypeFill { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
     self.inner.fill = fill.as_ref().clone();
@@ -162,6 +185,9 @@ pub struct BackgroundTypePattern {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Description of the background fill
   fill: BackgroundFill,
   /// Intensity of the pattern when it is shown above the filled background, 0-100
@@ -173,6 +199,7 @@ pub struct BackgroundTypePattern {
 
 impl RObject for BackgroundTypePattern {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundTypePattern" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,6 +232,11 @@ pub struct RTDBackgroundTypePatternBuilder {
 impl RTDBackgroundTypePatternBuilder {
   pub fn build(&self) -> BackgroundTypePattern { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
     self.inner.fill = fill.as_ref().clone();
@@ -245,6 +277,9 @@ pub struct BackgroundTypeWallpaper {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the wallpaper must be downscaled to fit in 450x450 square and then box-blurred with radius 12
   is_blurred: bool,
   /// True, if the background needs to be slightly moved when device is tilted
@@ -254,6 +289,7 @@ pub struct BackgroundTypeWallpaper {
 
 impl RObject for BackgroundTypeWallpaper {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundTypeWallpaper" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -284,6 +320,11 @@ pub struct RTDBackgroundTypeWallpaperBuilder {
 impl RTDBackgroundTypeWallpaperBuilder {
   pub fn build(&self) -> BackgroundTypeWallpaper { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_blurred(&mut self, is_blurred: bool) -> &mut Self {
     self.inner.is_blurred = is_blurred;
@@ -11,6 +11,9 @@ pub struct Backgrounds {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A list of backgrounds
   backgrounds: Vec<Background>,
   
@@ -18,6 +21,7 @@ pub struct Backgrounds {
 
 impl RObject for Backgrounds {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "backgrounds" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDBackgroundsBuilder {
 impl RTDBackgroundsBuilder {
   pub fn build(&self) -> Backgrounds { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn backgrounds(&mut self, backgrounds: Vec<Background>) -> &mut Self {
     self.inner.backgrounds = backgrounds;
@@ -11,6 +11,9 @@ pub struct BankCardActionOpenUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Action text
   text: String,
   /// The URL to be opened
@@ -20,6 +23,7 @@ pub struct BankCardActionOpenUrl {
 
 impl RObject for BankCardActionOpenUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "bankCardActionOpenUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDBankCardActionOpenUrlBuilder {
 impl RTDBankCardActionOpenUrlBuilder {
   pub fn build(&self) -> BankCardActionOpenUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct BankCardInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Title of the bank card description
   title: String,
   /// Actions that can be done with the bank card number
@@ -20,6 +23,7 @@ pub struct BankCardInfo {
 
 impl RObject for BankCardInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "bankCardInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDBankCardInfoBuilder {
 impl RTDBankCardInfoBuilder {
   pub fn build(&self) -> BankCardInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct BasicGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Group identifier
   id: i64,
   /// Number of members in the group
@@ -26,6 +29,7 @@ pub struct BasicGroup {
 
 impl RObject for BasicGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "basicGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDBasicGroupBuilder {
 impl RTDBasicGroupBuilder {
   pub fn build(&self) -> BasicGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -11,6 +11,9 @@ pub struct BotCommand {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text of the bot command
   command: String,
   /// Represents a command supported by a bot
@@ -20,6 +23,7 @@ pub struct BotCommand {
 
 impl RObject for BotCommand {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommand" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDBotCommandBuilder {
 impl RTDBotCommandBuilder {
   pub fn build(&self) -> BotCommand { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn command<T: AsRef<str>>(&mut self, command: T) -> &mut Self {
     self.inner.command = command.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct BotInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Provides information about a bot and its supported commands
   description: String,
   /// A list of commands supported by the bot
@@ -20,6 +23,7 @@ pub struct BotInfo {
 
 impl RObject for BotInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "botInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDBotInfoBuilder {
 impl RTDBotInfoBuilder {
   pub fn build(&self) -> BotInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
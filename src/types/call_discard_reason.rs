@@ -18,6 +18,9 @@ pub trait TDCallDiscardReason: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallDiscardReason {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The call was ended before the conversation started. It was declined by the other party
   Declined(CallDiscardReasonDeclined),
   /// The call was ended during the conversation because the users were disconnected
@@ -62,12 +65,25 @@ impl RObject for CallDiscardReason {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CallDiscardReason::Declined(t) => t.extra(),
+      CallDiscardReason::Disconnected(t) => t.extra(),
+      CallDiscardReason::Empty(t) => t.extra(),
+      CallDiscardReason::HungUp(t) => t.extra(),
+      CallDiscardReason::Missed(t) => t.extra(),
+
+      CallDiscardReason::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CallDiscardReason {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallDiscardReason::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CallDiscardReason::Unknown { .. } = self { true } else { false } }
 
   pub fn is_declined(&self) -> bool { if let CallDiscardReason::Declined(_) = self { true } else { false } }
   pub fn is_disconnected(&self) -> bool { if let CallDiscardReason::Disconnected(_) = self { true } else { false } }
@@ -117,11 +133,15 @@ pub struct CallDiscardReasonDeclined {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallDiscardReasonDeclined {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonDeclined" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -148,6 +168,11 @@ pub struct RTDCallDiscardReasonDeclinedBuilder {
 impl RTDCallDiscardReasonDeclinedBuilder {
   pub fn build(&self) -> CallDiscardReasonDeclined { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonDeclined> for CallDiscardReasonDeclined {
@@ -170,11 +195,15 @@ pub struct CallDiscardReasonDisconnected {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallDiscardReasonDisconnected {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonDisconnected" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -201,6 +230,11 @@ pub struct RTDCallDiscardReasonDisconnectedBuilder {
 impl RTDCallDiscardReasonDisconnectedBuilder {
   pub fn build(&self) -> CallDiscardReasonDisconnected { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonDisconnected> for CallDiscardReasonDisconnected {
@@ -223,11 +257,15 @@ pub struct CallDiscardReasonEmpty {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallDiscardReasonEmpty {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonEmpty" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -254,6 +292,11 @@ pub struct RTDCallDiscardReasonEmptyBuilder {
 impl RTDCallDiscardReasonEmptyBuilder {
   pub fn build(&self) -> CallDiscardReasonEmpty { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonEmpty> for CallDiscardReasonEmpty {
@@ -276,11 +319,15 @@ pub struct CallDiscardReasonHungUp {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallDiscardReasonHungUp {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonHungUp" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -307,6 +354,11 @@ pub struct RTDCallDiscardReasonHungUpBuilder {
 impl RTDCallDiscardReasonHungUpBuilder {
   pub fn build(&self) -> CallDiscardReasonHungUp { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonHungUp> for CallDiscardReasonHungUp {
@@ -329,11 +381,15 @@ pub struct CallDiscardReasonMissed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallDiscardReasonMissed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonMissed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -360,6 +416,11 @@ pub struct RTDCallDiscardReasonMissedBuilder {
 impl RTDCallDiscardReasonMissedBuilder {
   pub fn build(&self) -> CallDiscardReasonMissed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonMissed> for CallDiscardReasonMissed {
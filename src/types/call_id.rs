@@ -11,6 +11,9 @@ pub struct CallId {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   id: i64,
   
@@ -18,6 +21,7 @@ pub struct CallId {
 
 impl RObject for CallId {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callId" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDCallIdBuilder {
 impl RTDCallIdBuilder {
   pub fn build(&self) -> CallId { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -18,6 +18,9 @@ pub trait TDCallProblem: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallProblem {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The speech was distorted
   DistortedSpeech(CallProblemDistortedSpeech),
   /// The call ended unexpectedly
@@ -70,12 +73,27 @@ impl RObject for CallProblem {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CallProblem::DistortedSpeech(t) => t.extra(),
+      CallProblem::Dropped(t) => t.extra(),
+      CallProblem::Echo(t) => t.extra(),
+      CallProblem::Interruptions(t) => t.extra(),
+      CallProblem::Noise(t) => t.extra(),
+      CallProblem::SilentLocal(t) => t.extra(),
+      CallProblem::SilentRemote(t) => t.extra(),
+
+      CallProblem::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CallProblem {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallProblem::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CallProblem::Unknown { .. } = self { true } else { false } }
 
   pub fn is_distorted_speech(&self) -> bool { if let CallProblem::DistortedSpeech(_) = self { true } else { false } }
   pub fn is_dropped(&self) -> bool { if let CallProblem::Dropped(_) = self { true } else { false } }
@@ -135,11 +153,15 @@ pub struct CallProblemDistortedSpeech {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemDistortedSpeech {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemDistortedSpeech" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -166,6 +188,11 @@ pub struct RTDCallProblemDistortedSpeechBuilder {
 impl RTDCallProblemDistortedSpeechBuilder {
   pub fn build(&self) -> CallProblemDistortedSpeech { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemDistortedSpeech> for CallProblemDistortedSpeech {
@@ -188,11 +215,15 @@ pub struct CallProblemDropped {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemDropped {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemDropped" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -219,6 +250,11 @@ pub struct RTDCallProblemDroppedBuilder {
 impl RTDCallProblemDroppedBuilder {
   pub fn build(&self) -> CallProblemDropped { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemDropped> for CallProblemDropped {
@@ -241,11 +277,15 @@ pub struct CallProblemEcho {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemEcho {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemEcho" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -272,6 +312,11 @@ pub struct RTDCallProblemEchoBuilder {
 impl RTDCallProblemEchoBuilder {
   pub fn build(&self) -> CallProblemEcho { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemEcho> for CallProblemEcho {
@@ -294,11 +339,15 @@ pub struct CallProblemInterruptions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemInterruptions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemInterruptions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -325,6 +374,11 @@ pub struct RTDCallProblemInterruptionsBuilder {
 impl RTDCallProblemInterruptionsBuilder {
   pub fn build(&self) -> CallProblemInterruptions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemInterruptions> for CallProblemInterruptions {
@@ -347,11 +401,15 @@ pub struct CallProblemNoise {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemNoise {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemNoise" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -378,6 +436,11 @@ pub struct RTDCallProblemNoiseBuilder {
 impl RTDCallProblemNoiseBuilder {
   pub fn build(&self) -> CallProblemNoise { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemNoise> for CallProblemNoise {
@@ -400,11 +463,15 @@ pub struct CallProblemSilentLocal {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemSilentLocal {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemSilentLocal" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -431,6 +498,11 @@ pub struct RTDCallProblemSilentLocalBuilder {
 impl RTDCallProblemSilentLocalBuilder {
   pub fn build(&self) -> CallProblemSilentLocal { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemSilentLocal> for CallProblemSilentLocal {
@@ -453,11 +525,15 @@ pub struct CallProblemSilentRemote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallProblemSilentRemote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemSilentRemote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -484,6 +560,11 @@ pub struct RTDCallProblemSilentRemoteBuilder {
 impl RTDCallProblemSilentRemoteBuilder {
   pub fn build(&self) -> CallProblemSilentRemote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemSilentRemote> for CallProblemSilentRemote {
@@ -11,6 +11,9 @@ pub struct CallProtocol {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if UDP peer-to-peer connections are supported
   udp_p2p: bool,
   /// True, if connection through UDP reflectors is supported
@@ -26,6 +29,7 @@ pub struct CallProtocol {
 
 impl RObject for CallProtocol {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callProtocol" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDCallProtocolBuilder {
 impl RTDCallProtocolBuilder {
   pub fn build(&self) -> CallProtocol { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn udp_p2p(&mut self, udp_p2p: bool) -> &mut Self {
     self.inner.udp_p2p = udp_p2p;
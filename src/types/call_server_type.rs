@@ -18,6 +18,9 @@ pub trait TDCallServerType: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallServerType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram call reflector
   TelegramReflector(CallServerTypeTelegramReflector),
   /// A WebRTC server
@@ -50,12 +53,22 @@ impl RObject for CallServerType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CallServerType::TelegramReflector(t) => t.extra(),
+      CallServerType::Webrtc(t) => t.extra(),
+
+      CallServerType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CallServerType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallServerType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CallServerType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_telegram_reflector(&self) -> bool { if let CallServerType::TelegramReflector(_) = self { true } else { false } }
   pub fn is_webrtc(&self) -> bool { if let CallServerType::Webrtc(_) = self { true } else { false } }
@@ -90,13 +103,17 @@ pub struct CallServerTypeTelegramReflector {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A peer tag to be used with the reflector
-  peer_tag: String,
+  peer_tag: TdBytes,
   
 }
 
 impl RObject for CallServerTypeTelegramReflector {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callServerTypeTelegramReflector" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -113,7 +130,7 @@ impl CallServerTypeTelegramReflector {
     RTDCallServerTypeTelegramReflectorBuilder { inner }
   }
 
-  pub fn peer_tag(&self) -> &String { &self.peer_tag }
+  pub fn peer_tag(&self) -> &TdBytes { &self.peer_tag }
 
 }
 
@@ -125,9 +142,14 @@ pub struct RTDCallServerTypeTelegramReflectorBuilder {
 impl RTDCallServerTypeTelegramReflectorBuilder {
   pub fn build(&self) -> CallServerTypeTelegramReflector { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn peer_tag<T: AsRef<str>>(&mut self, peer_tag: T) -> &mut Self {
-    self.inner.peer_tag = peer_tag.as_ref().to_string();
+  pub fn peer_tag<T: AsRef<[u8]>>(&mut self, peer_tag: T) -> &mut Self {
+    self.inner.peer_tag = TdBytes::from(peer_tag.as_ref());
     self
   }
 
@@ -153,6 +175,9 @@ pub struct CallServerTypeWebrtc {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Username to be used for authentification
   username: String,
   /// Authentication password
@@ -166,6 +191,7 @@ pub struct CallServerTypeWebrtc {
 
 impl RObject for CallServerTypeWebrtc {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callServerTypeWebrtc" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -200,6 +226,11 @@ pub struct RTDCallServerTypeWebrtcBuilder {
 impl RTDCallServerTypeWebrtcBuilder {
   pub fn build(&self) -> CallServerTypeWebrtc { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
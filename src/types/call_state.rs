@@ -18,6 +18,9 @@ pub trait TDCallState: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallState {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The call has ended successfully
   Discarded(CallStateDiscarded),
   /// The call has ended with an error
@@ -66,12 +69,26 @@ impl RObject for CallState {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CallState::Discarded(t) => t.extra(),
+      CallState::Error(t) => t.extra(),
+      CallState::ExchangingKeys(t) => t.extra(),
+      CallState::HangingUp(t) => t.extra(),
+      CallState::Pending(t) => t.extra(),
+      CallState::Ready(t) => t.extra(),
+
+      CallState::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CallState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallState::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CallState::Unknown { .. } = self { true } else { false } }
 
   pub fn is_discarded(&self) -> bool { if let CallState::Discarded(_) = self { true } else { false } }
   pub fn is_error(&self) -> bool { if let CallState::Error(_) = self { true } else { false } }
@@ -126,6 +143,9 @@ pub struct CallStateDiscarded {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The reason, why the call has ended
   reason: CallDiscardReason,
   /// True, if the call rating should be sent to the server
@@ -137,6 +157,7 @@ pub struct CallStateDiscarded {
 
 impl RObject for CallStateDiscarded {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateDiscarded" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -169,6 +190,11 @@ pub struct RTDCallStateDiscardedBuilder {
 impl RTDCallStateDiscardedBuilder {
   pub fn build(&self) -> CallStateDiscarded { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reason<T: AsRef<CallDiscardReason>>(&mut self, reason: T) -> &mut Self {
     self.inner.reason = reason.as_ref().clone();
@@ -209,6 +235,9 @@ pub struct CallStateError {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Error. An error with the code 4005000 will be returned if an outgoing call is missed because of an expired timeout
   error: Error,
   
@@ -216,6 +245,7 @@ pub struct CallStateError {
 
 impl RObject for CallStateError {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateError" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -244,6 +274,11 @@ pub struct RTDCallStateErrorBuilder {
 impl RTDCallStateErrorBuilder {
   pub fn build(&self) -> CallStateError { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
     self.inner.error = error.as_ref().clone();
@@ -272,11 +307,15 @@ pub struct CallStateExchangingKeys {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallStateExchangingKeys {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateExchangingKeys" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -303,6 +342,11 @@ pub struct RTDCallStateExchangingKeysBuilder {
 impl RTDCallStateExchangingKeysBuilder {
   pub fn build(&self) -> CallStateExchangingKeys { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallStateExchangingKeys> for CallStateExchangingKeys {
@@ -325,11 +369,15 @@ pub struct CallStateHangingUp {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CallStateHangingUp {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateHangingUp" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -356,6 +404,11 @@ pub struct RTDCallStateHangingUpBuilder {
 impl RTDCallStateHangingUpBuilder {
   pub fn build(&self) -> CallStateHangingUp { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallStateHangingUp> for CallStateHangingUp {
@@ -378,6 +431,9 @@ pub struct CallStatePending {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the call has already been created by the server
   is_created: bool,
   /// True, if the call has already been received by the other party
@@ -387,6 +443,7 @@ pub struct CallStatePending {
 
 impl RObject for CallStatePending {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callStatePending" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -417,6 +474,11 @@ pub struct RTDCallStatePendingBuilder {
 impl RTDCallStatePendingBuilder {
   pub fn build(&self) -> CallStatePending { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_created(&mut self, is_created: bool) -> &mut Self {
     self.inner.is_created = is_created;
@@ -451,6 +513,9 @@ pub struct CallStateReady {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call protocols supported by the peer
   protocol: CallProtocol,
   /// List of available call servers
@@ -458,7 +523,7 @@ pub struct CallStateReady {
   /// A JSON-encoded call config
   config: String,
   /// Call encryption key
-  encryption_key: String,
+  encryption_key: TdBytes,
   /// Encryption key emojis fingerprint
   emojis: Vec<String>,
   /// True, if peer-to-peer connection is allowed by users privacy settings
@@ -468,6 +533,7 @@ pub struct CallStateReady {
 
 impl RObject for CallStateReady {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateReady" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -490,7 +556,7 @@ impl CallStateReady {
 
   pub fn config(&self) -> &String { &self.config }
 
-  pub fn encryption_key(&self) -> &String { &self.encryption_key }
+  pub fn encryption_key(&self) -> &TdBytes { &self.encryption_key }
 
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
 
@@ -506,6 +572,11 @@ pub struct RTDCallStateReadyBuilder {
 impl RTDCallStateReadyBuilder {
   pub fn build(&self) -> CallStateReady { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn protocol<T: AsRef<CallProtocol>>(&mut self, protocol: T) -> &mut Self {
     self.inner.protocol = protocol.as_ref().clone();
@@ -525,8 +596,8 @@ impl RTDCallStateReadyBuilder {
   }
 
    
-  pub fn encryption_key<T: AsRef<str>>(&mut self, encryption_key: T) -> &mut Self {
-    self.inner.encryption_key = encryption_key.as_ref().to_string();
+  pub fn encryption_key<T: AsRef<[u8]>>(&mut self, encryption_key: T) -> &mut Self {
+    self.inner.encryption_key = TdBytes::from(encryption_key.as_ref());
     self
   }
 
@@ -11,6 +11,9 @@ pub struct CallbackQueryAnswer {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text of the answer
   text: String,
   /// True, if an alert should be shown to the user instead of a toast notification
@@ -22,6 +25,7 @@ pub struct CallbackQueryAnswer {
 
 impl RObject for CallbackQueryAnswer {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryAnswer" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDCallbackQueryAnswerBuilder {
 impl RTDCallbackQueryAnswerBuilder {
   pub fn build(&self) -> CallbackQueryAnswer { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDCallbackQueryPayload: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallbackQueryPayload {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The payload from a general callback button
   Data(CallbackQueryPayloadData),
   /// The payload from a game callback button
@@ -50,12 +53,22 @@ impl RObject for CallbackQueryPayload {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CallbackQueryPayload::Data(t) => t.extra(),
+      CallbackQueryPayload::Game(t) => t.extra(),
+
+      CallbackQueryPayload::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CallbackQueryPayload {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallbackQueryPayload::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CallbackQueryPayload::Unknown { .. } = self { true } else { false } }
 
   pub fn is_data(&self) -> bool { if let CallbackQueryPayload::Data(_) = self { true } else { false } }
   pub fn is_game(&self) -> bool { if let CallbackQueryPayload::Game(_) = self { true } else { false } }
@@ -90,13 +103,17 @@ pub struct CallbackQueryPayloadData {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Data that was attached to the callback button
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for CallbackQueryPayloadData {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryPayloadData" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -113,7 +130,7 @@ impl CallbackQueryPayloadData {
     RTDCallbackQueryPayloadDataBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -125,9 +142,14 @@ pub struct RTDCallbackQueryPayloadDataBuilder {
 impl RTDCallbackQueryPayloadDataBuilder {
   pub fn build(&self) -> CallbackQueryPayloadData { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -153,6 +175,9 @@ pub struct CallbackQueryPayloadGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A short name of the game that was attached to the callback button
   game_short_name: String,
   
@@ -160,6 +185,7 @@ pub struct CallbackQueryPayloadGame {
 
 impl RObject for CallbackQueryPayloadGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryPayloadGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -188,6 +214,11 @@ pub struct RTDCallbackQueryPayloadGameBuilder {
 impl RTDCallbackQueryPayloadGameBuilder {
   pub fn build(&self) -> CallbackQueryPayloadGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
     self.inner.game_short_name = game_short_name.as_ref().to_string();
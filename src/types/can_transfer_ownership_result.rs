@@ -18,8 +18,9 @@ pub trait TDCanTransferOwnershipResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum CanTransferOwnershipResult {
   #[doc(hidden)] _Default(()),
-  /// Checks whether the current session can be used to transfer a chat ownership to another user
-  CanTransferOwnership(CanTransferOwnership),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The session can be used
   Ok(CanTransferOwnershipResultOk),
   /// The 2-step verification needs to be enabled first
@@ -40,7 +41,6 @@ impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
     use serde::de::Error;
     rtd_enum_deserialize!(
       CanTransferOwnershipResult,
-      (canTransferOwnership, CanTransferOwnership);
       (canTransferOwnershipResultOk, Ok);
       (canTransferOwnershipResultPasswordNeeded, PasswordNeeded);
       (canTransferOwnershipResultPasswordTooFresh, PasswordTooFresh);
@@ -53,7 +53,6 @@ impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
 impl RObject for CanTransferOwnershipResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      CanTransferOwnershipResult::CanTransferOwnership(t) => t.td_name(),
       CanTransferOwnershipResult::Ok(t) => t.td_name(),
       CanTransferOwnershipResult::PasswordNeeded(t) => t.td_name(),
       CanTransferOwnershipResult::PasswordTooFresh(t) => t.td_name(),
@@ -62,26 +61,35 @@ impl RObject for CanTransferOwnershipResult {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CanTransferOwnershipResult::Ok(t) => t.extra(),
+      CanTransferOwnershipResult::PasswordNeeded(t) => t.extra(),
+      CanTransferOwnershipResult::PasswordTooFresh(t) => t.extra(),
+      CanTransferOwnershipResult::SessionTooFresh(t) => t.extra(),
+
+      CanTransferOwnershipResult::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CanTransferOwnershipResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CanTransferOwnershipResult::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CanTransferOwnershipResult::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_can_transfer_ownership(&self) -> bool { if let CanTransferOwnershipResult::CanTransferOwnership(_) = self { true } else { false } }
   pub fn is_ok(&self) -> bool { if let CanTransferOwnershipResult::Ok(_) = self { true } else { false } }
   pub fn is_password_needed(&self) -> bool { if let CanTransferOwnershipResult::PasswordNeeded(_) = self { true } else { false } }
   pub fn is_password_too_fresh(&self) -> bool { if let CanTransferOwnershipResult::PasswordTooFresh(_) = self { true } else { false } }
   pub fn is_session_too_fresh(&self) -> bool { if let CanTransferOwnershipResult::SessionTooFresh(_) = self { true } else { false } }
 
-  pub fn on_can_transfer_ownership<F: FnOnce(&CanTransferOwnership)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::CanTransferOwnership(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CanTransferOwnershipResultOk)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::Ok(t) = self { fnc(t) }; self }
   pub fn on_password_needed<F: FnOnce(&CanTransferOwnershipResultPasswordNeeded)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::PasswordNeeded(t) = self { fnc(t) }; self }
   pub fn on_password_too_fresh<F: FnOnce(&CanTransferOwnershipResultPasswordTooFresh)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::PasswordTooFresh(t) = self { fnc(t) }; self }
   pub fn on_session_too_fresh<F: FnOnce(&CanTransferOwnershipResultSessionTooFresh)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::SessionTooFresh(t) = self { fnc(t) }; self }
 
-  pub fn as_can_transfer_ownership(&self) -> Option<&CanTransferOwnership> { if let CanTransferOwnershipResult::CanTransferOwnership(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CanTransferOwnershipResultOk> { if let CanTransferOwnershipResult::Ok(t) = self { return Some(t) } None }
   pub fn as_password_needed(&self) -> Option<&CanTransferOwnershipResultPasswordNeeded> { if let CanTransferOwnershipResult::PasswordNeeded(t) = self { return Some(t) } None }
   pub fn as_password_too_fresh(&self) -> Option<&CanTransferOwnershipResultPasswordTooFresh> { if let CanTransferOwnershipResult::PasswordTooFresh(t) = self { return Some(t) } None }
@@ -89,8 +97,6 @@ impl CanTransferOwnershipResult {
 
 
 
-  pub fn can_transfer_ownership<T: AsRef<CanTransferOwnership>>(t: T) -> Self { CanTransferOwnershipResult::CanTransferOwnership(t.as_ref().clone()) }
-
   pub fn ok<T: AsRef<CanTransferOwnershipResultOk>>(t: T) -> Self { CanTransferOwnershipResult::Ok(t.as_ref().clone()) }
 
   pub fn password_needed<T: AsRef<CanTransferOwnershipResultPasswordNeeded>>(t: T) -> Self { CanTransferOwnershipResult::PasswordNeeded(t.as_ref().clone()) }
@@ -117,11 +123,15 @@ pub struct CanTransferOwnershipResultOk {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CanTransferOwnershipResultOk {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultOk" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -148,6 +158,11 @@ pub struct RTDCanTransferOwnershipResultOkBuilder {
 impl RTDCanTransferOwnershipResultOkBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultOk { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CanTransferOwnershipResultOk> for CanTransferOwnershipResultOk {
@@ -170,11 +185,15 @@ pub struct CanTransferOwnershipResultPasswordNeeded {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CanTransferOwnershipResultPasswordNeeded {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultPasswordNeeded" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -201,6 +220,11 @@ pub struct RTDCanTransferOwnershipResultPasswordNeededBuilder {
 impl RTDCanTransferOwnershipResultPasswordNeededBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultPasswordNeeded { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CanTransferOwnershipResultPasswordNeeded> for CanTransferOwnershipResultPasswordNeeded {
@@ -223,6 +247,9 @@ pub struct CanTransferOwnershipResultPasswordTooFresh {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Time left before the session can be used to transfer ownership of a chat, in seconds
   retry_after: i64,
   
@@ -230,6 +257,7 @@ pub struct CanTransferOwnershipResultPasswordTooFresh {
 
 impl RObject for CanTransferOwnershipResultPasswordTooFresh {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultPasswordTooFresh" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -258,6 +286,11 @@ pub struct RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
 impl RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultPasswordTooFresh { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
     self.inner.retry_after = retry_after;
@@ -286,6 +319,9 @@ pub struct CanTransferOwnershipResultSessionTooFresh {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Time left before the session can be used to transfer ownership of a chat, in seconds
   retry_after: i64,
   
@@ -293,6 +329,7 @@ pub struct CanTransferOwnershipResultSessionTooFresh {
 
 impl RObject for CanTransferOwnershipResultSessionTooFresh {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultSessionTooFresh" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -321,6 +358,11 @@ pub struct RTDCanTransferOwnershipResultSessionTooFreshBuilder {
 impl RTDCanTransferOwnershipResultSessionTooFreshBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultSessionTooFresh { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
     self.inner.retry_after = retry_after;
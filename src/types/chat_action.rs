@@ -18,6 +18,9 @@ pub trait TDChatAction: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatAction {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The user has cancelled the previous action
   Cancel(ChatActionCancel),
   /// The user is picking a contact to send
@@ -94,12 +97,33 @@ impl RObject for ChatAction {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatAction::Cancel(t) => t.extra(),
+      ChatAction::ChoosingContact(t) => t.extra(),
+      ChatAction::ChoosingLocation(t) => t.extra(),
+      ChatAction::RecordingVideo(t) => t.extra(),
+      ChatAction::RecordingVideoNote(t) => t.extra(),
+      ChatAction::RecordingVoiceNote(t) => t.extra(),
+      ChatAction::StartPlayingGame(t) => t.extra(),
+      ChatAction::Typing(t) => t.extra(),
+      ChatAction::UploadingDocument(t) => t.extra(),
+      ChatAction::UploadingPhoto(t) => t.extra(),
+      ChatAction::UploadingVideo(t) => t.extra(),
+      ChatAction::UploadingVideoNote(t) => t.extra(),
+      ChatAction::UploadingVoiceNote(t) => t.extra(),
+
+      ChatAction::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatAction::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatAction::Unknown { .. } = self { true } else { false } }
 
   pub fn is_cancel(&self) -> bool { if let ChatAction::Cancel(_) = self { true } else { false } }
   pub fn is_choosing_contact(&self) -> bool { if let ChatAction::ChoosingContact(_) = self { true } else { false } }
@@ -189,11 +213,15 @@ pub struct ChatActionCancel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionCancel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionCancel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -220,6 +248,11 @@ pub struct RTDChatActionCancelBuilder {
 impl RTDChatActionCancelBuilder {
   pub fn build(&self) -> ChatActionCancel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionCancel> for ChatActionCancel {
@@ -242,11 +275,15 @@ pub struct ChatActionChoosingContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionChoosingContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionChoosingContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,6 +310,11 @@ pub struct RTDChatActionChoosingContactBuilder {
 impl RTDChatActionChoosingContactBuilder {
   pub fn build(&self) -> ChatActionChoosingContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionChoosingContact> for ChatActionChoosingContact {
@@ -295,11 +337,15 @@ pub struct ChatActionChoosingLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionChoosingLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionChoosingLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -326,6 +372,11 @@ pub struct RTDChatActionChoosingLocationBuilder {
 impl RTDChatActionChoosingLocationBuilder {
   pub fn build(&self) -> ChatActionChoosingLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionChoosingLocation> for ChatActionChoosingLocation {
@@ -348,11 +399,15 @@ pub struct ChatActionRecordingVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionRecordingVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionRecordingVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -379,6 +434,11 @@ pub struct RTDChatActionRecordingVideoBuilder {
 impl RTDChatActionRecordingVideoBuilder {
   pub fn build(&self) -> ChatActionRecordingVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionRecordingVideo> for ChatActionRecordingVideo {
@@ -401,11 +461,15 @@ pub struct ChatActionRecordingVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionRecordingVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionRecordingVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -432,6 +496,11 @@ pub struct RTDChatActionRecordingVideoNoteBuilder {
 impl RTDChatActionRecordingVideoNoteBuilder {
   pub fn build(&self) -> ChatActionRecordingVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionRecordingVideoNote> for ChatActionRecordingVideoNote {
@@ -454,11 +523,15 @@ pub struct ChatActionRecordingVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionRecordingVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionRecordingVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -485,6 +558,11 @@ pub struct RTDChatActionRecordingVoiceNoteBuilder {
 impl RTDChatActionRecordingVoiceNoteBuilder {
   pub fn build(&self) -> ChatActionRecordingVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionRecordingVoiceNote> for ChatActionRecordingVoiceNote {
@@ -507,11 +585,15 @@ pub struct ChatActionStartPlayingGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionStartPlayingGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionStartPlayingGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -538,6 +620,11 @@ pub struct RTDChatActionStartPlayingGameBuilder {
 impl RTDChatActionStartPlayingGameBuilder {
   pub fn build(&self) -> ChatActionStartPlayingGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionStartPlayingGame> for ChatActionStartPlayingGame {
@@ -560,11 +647,15 @@ pub struct ChatActionTyping {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionTyping {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionTyping" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -591,6 +682,11 @@ pub struct RTDChatActionTypingBuilder {
 impl RTDChatActionTypingBuilder {
   pub fn build(&self) -> ChatActionTyping { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionTyping> for ChatActionTyping {
@@ -613,6 +709,9 @@ pub struct ChatActionUploadingDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Upload progress, as a percentage
   progress: i64,
   
@@ -620,6 +719,7 @@ pub struct ChatActionUploadingDocument {
 
 impl RObject for ChatActionUploadingDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -648,6 +748,11 @@ pub struct RTDChatActionUploadingDocumentBuilder {
 impl RTDChatActionUploadingDocumentBuilder {
   pub fn build(&self) -> ChatActionUploadingDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -676,6 +781,9 @@ pub struct ChatActionUploadingPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Upload progress, as a percentage
   progress: i64,
   
@@ -683,6 +791,7 @@ pub struct ChatActionUploadingPhoto {
 
 impl RObject for ChatActionUploadingPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -711,6 +820,11 @@ pub struct RTDChatActionUploadingPhotoBuilder {
 impl RTDChatActionUploadingPhotoBuilder {
   pub fn build(&self) -> ChatActionUploadingPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -739,6 +853,9 @@ pub struct ChatActionUploadingVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Upload progress, as a percentage
   progress: i64,
   
@@ -746,6 +863,7 @@ pub struct ChatActionUploadingVideo {
 
 impl RObject for ChatActionUploadingVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -774,6 +892,11 @@ pub struct RTDChatActionUploadingVideoBuilder {
 impl RTDChatActionUploadingVideoBuilder {
   pub fn build(&self) -> ChatActionUploadingVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -802,6 +925,9 @@ pub struct ChatActionUploadingVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Upload progress, as a percentage
   progress: i64,
   
@@ -809,6 +935,7 @@ pub struct ChatActionUploadingVideoNote {
 
 impl RObject for ChatActionUploadingVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -837,6 +964,11 @@ pub struct RTDChatActionUploadingVideoNoteBuilder {
 impl RTDChatActionUploadingVideoNoteBuilder {
   pub fn build(&self) -> ChatActionUploadingVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -865,6 +997,9 @@ pub struct ChatActionUploadingVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Upload progress, as a percentage
   progress: i64,
   
@@ -872,6 +1007,7 @@ pub struct ChatActionUploadingVoiceNote {
 
 impl RObject for ChatActionUploadingVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -900,6 +1036,11 @@ pub struct RTDChatActionUploadingVoiceNoteBuilder {
 impl RTDChatActionUploadingVoiceNoteBuilder {
   pub fn build(&self) -> ChatActionUploadingVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
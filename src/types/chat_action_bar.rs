@@ -18,6 +18,9 @@ pub trait TDChatActionBar: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatActionBar {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat is a private or secret chat and the other user can be added to the contact list using the method addContact
   AddContact(ChatActionBarAddContact),
   /// The chat is a private or secret chat, which can be reported using the method reportChat, or the other user can be blocked using the method blockUser, or the other user can be added to the contact list using the method addContact
@@ -62,12 +65,25 @@ impl RObject for ChatActionBar {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatActionBar::AddContact(t) => t.extra(),
+      ChatActionBar::ReportAddBlock(t) => t.extra(),
+      ChatActionBar::ReportSpam(t) => t.extra(),
+      ChatActionBar::ReportUnrelatedLocation(t) => t.extra(),
+      ChatActionBar::SharePhoneNumber(t) => t.extra(),
+
+      ChatActionBar::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatActionBar {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatActionBar::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatActionBar::Unknown { .. } = self { true } else { false } }
 
   pub fn is_add_contact(&self) -> bool { if let ChatActionBar::AddContact(_) = self { true } else { false } }
   pub fn is_report_add_block(&self) -> bool { if let ChatActionBar::ReportAddBlock(_) = self { true } else { false } }
@@ -117,11 +133,15 @@ pub struct ChatActionBarAddContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionBarAddContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarAddContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -148,6 +168,11 @@ pub struct RTDChatActionBarAddContactBuilder {
 impl RTDChatActionBarAddContactBuilder {
   pub fn build(&self) -> ChatActionBarAddContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarAddContact> for ChatActionBarAddContact {
@@ -170,6 +195,9 @@ pub struct ChatActionBarReportAddBlock {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, the chat was automatically archived and can be moved back to the main chat list using addChatToList simultaneously with setting chat notification settings to default using setChatNotificationSettings
   can_unarchive: bool,
   /// If non-negative, the current user was found by the peer through searchChatsNearby and this is the distance between the users
@@ -179,6 +207,7 @@ pub struct ChatActionBarReportAddBlock {
 
 impl RObject for ChatActionBarReportAddBlock {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarReportAddBlock" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -209,6 +238,11 @@ pub struct RTDChatActionBarReportAddBlockBuilder {
 impl RTDChatActionBarReportAddBlockBuilder {
   pub fn build(&self) -> ChatActionBarReportAddBlock { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_unarchive(&mut self, can_unarchive: bool) -> &mut Self {
     self.inner.can_unarchive = can_unarchive;
@@ -243,6 +277,9 @@ pub struct ChatActionBarReportSpam {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, the chat was automatically archived and can be moved back to the main chat list using addChatToList simultaneously with setting chat notification settings to default using setChatNotificationSettings
   can_unarchive: bool,
   
@@ -250,6 +287,7 @@ pub struct ChatActionBarReportSpam {
 
 impl RObject for ChatActionBarReportSpam {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarReportSpam" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -278,6 +316,11 @@ pub struct RTDChatActionBarReportSpamBuilder {
 impl RTDChatActionBarReportSpamBuilder {
   pub fn build(&self) -> ChatActionBarReportSpam { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_unarchive(&mut self, can_unarchive: bool) -> &mut Self {
     self.inner.can_unarchive = can_unarchive;
@@ -306,11 +349,15 @@ pub struct ChatActionBarReportUnrelatedLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionBarReportUnrelatedLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarReportUnrelatedLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -337,6 +384,11 @@ pub struct RTDChatActionBarReportUnrelatedLocationBuilder {
 impl RTDChatActionBarReportUnrelatedLocationBuilder {
   pub fn build(&self) -> ChatActionBarReportUnrelatedLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarReportUnrelatedLocation> for ChatActionBarReportUnrelatedLocation {
@@ -359,11 +411,15 @@ pub struct ChatActionBarSharePhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatActionBarSharePhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarSharePhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -390,6 +446,11 @@ pub struct RTDChatActionBarSharePhoneNumberBuilder {
 impl RTDChatActionBarSharePhoneNumberBuilder {
   pub fn build(&self) -> ChatActionBarSharePhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarSharePhoneNumber> for ChatActionBarSharePhoneNumber {
@@ -11,6 +11,9 @@ pub struct ChatAdministrator {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier of the administrator
   user_id: i64,
   /// Custom title of the administrator
@@ -22,6 +25,7 @@ pub struct ChatAdministrator {
 
 impl RObject for ChatAdministrator {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatAdministrator" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDChatAdministratorBuilder {
 impl RTDChatAdministratorBuilder {
   pub fn build(&self) -> ChatAdministrator { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
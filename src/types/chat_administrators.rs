@@ -11,6 +11,9 @@ pub struct ChatAdministrators {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A list of chat administrators
   administrators: Vec<ChatAdministrator>,
   
@@ -18,6 +21,7 @@ pub struct ChatAdministrators {
 
 impl RObject for ChatAdministrators {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatAdministrators" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDChatAdministratorsBuilder {
 impl RTDChatAdministratorsBuilder {
   pub fn build(&self) -> ChatAdministrators { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn administrators(&mut self, administrators: Vec<ChatAdministrator>) -> &mut Self {
     self.inner.administrators = administrators;
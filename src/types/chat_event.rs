@@ -11,8 +11,11 @@ pub struct ChatEvent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat event identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Point in time (Unix timestamp) when the event happened
   date: i64,
   /// Identifier of the user who performed the action that triggered the event
@@ -24,6 +27,7 @@ pub struct ChatEvent {
 
 impl RObject for ChatEvent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEvent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -37,7 +41,7 @@ impl ChatEvent {
     RTDChatEventBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -55,8 +59,13 @@ pub struct RTDChatEventBuilder {
 impl RTDChatEventBuilder {
   pub fn build(&self) -> ChatEvent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -18,6 +18,9 @@ pub trait TDChatEventAction: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatEventAction {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat description was changed
   ChatEventDescriptionChanged(ChatEventDescriptionChanged),
   /// The can_invite_users permission of a supergroup chat was toggled
@@ -130,12 +133,42 @@ impl RObject for ChatEventAction {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatEventAction::ChatEventDescriptionChanged(t) => t.extra(),
+      ChatEventAction::ChatEventInvitesToggled(t) => t.extra(),
+      ChatEventAction::ChatEventIsAllHistoryAvailableToggled(t) => t.extra(),
+      ChatEventAction::ChatEventLinkedChatChanged(t) => t.extra(),
+      ChatEventAction::ChatEventLocationChanged(t) => t.extra(),
+      ChatEventAction::ChatEventMemberInvited(t) => t.extra(),
+      ChatEventAction::ChatEventMemberJoined(t) => t.extra(),
+      ChatEventAction::ChatEventMemberLeft(t) => t.extra(),
+      ChatEventAction::ChatEventMemberPromoted(t) => t.extra(),
+      ChatEventAction::ChatEventMemberRestricted(t) => t.extra(),
+      ChatEventAction::ChatEventMessageDeleted(t) => t.extra(),
+      ChatEventAction::ChatEventMessageEdited(t) => t.extra(),
+      ChatEventAction::ChatEventMessagePinned(t) => t.extra(),
+      ChatEventAction::ChatEventMessageUnpinned(t) => t.extra(),
+      ChatEventAction::ChatEventPermissionsChanged(t) => t.extra(),
+      ChatEventAction::ChatEventPhotoChanged(t) => t.extra(),
+      ChatEventAction::ChatEventPollStopped(t) => t.extra(),
+      ChatEventAction::ChatEventSignMessagesToggled(t) => t.extra(),
+      ChatEventAction::ChatEventSlowModeDelayChanged(t) => t.extra(),
+      ChatEventAction::ChatEventStickerSetChanged(t) => t.extra(),
+      ChatEventAction::ChatEventTitleChanged(t) => t.extra(),
+      ChatEventAction::ChatEventUsernameChanged(t) => t.extra(),
+
+      ChatEventAction::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatEventAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatEventAction::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatEventAction::Unknown { .. } = self { true } else { false } }
 
   pub fn is_chat_event_description_changed(&self) -> bool { if let ChatEventAction::ChatEventDescriptionChanged(_) = self { true } else { false } }
   pub fn is_chat_event_invites_toggled(&self) -> bool { if let ChatEventAction::ChatEventInvitesToggled(_) = self { true } else { false } }
@@ -270,6 +303,9 @@ pub struct ChatEventDescriptionChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous chat description
   old_description: String,
   /// New chat description
@@ -279,6 +315,7 @@ pub struct ChatEventDescriptionChanged {
 
 impl RObject for ChatEventDescriptionChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventDescriptionChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -309,6 +346,11 @@ pub struct RTDChatEventDescriptionChangedBuilder {
 impl RTDChatEventDescriptionChangedBuilder {
   pub fn build(&self) -> ChatEventDescriptionChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_description<T: AsRef<str>>(&mut self, old_description: T) -> &mut Self {
     self.inner.old_description = old_description.as_ref().to_string();
@@ -343,6 +385,9 @@ pub struct ChatEventInvitesToggled {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New value of can_invite_users permission
   can_invite_users: bool,
   
@@ -350,6 +395,7 @@ pub struct ChatEventInvitesToggled {
 
 impl RObject for ChatEventInvitesToggled {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventInvitesToggled" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -378,6 +424,11 @@ pub struct RTDChatEventInvitesToggledBuilder {
 impl RTDChatEventInvitesToggledBuilder {
   pub fn build(&self) -> ChatEventInvitesToggled { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_invite_users(&mut self, can_invite_users: bool) -> &mut Self {
     self.inner.can_invite_users = can_invite_users;
@@ -406,6 +457,9 @@ pub struct ChatEventIsAllHistoryAvailableToggled {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New value of is_all_history_available
   is_all_history_available: bool,
   
@@ -413,6 +467,7 @@ pub struct ChatEventIsAllHistoryAvailableToggled {
 
 impl RObject for ChatEventIsAllHistoryAvailableToggled {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventIsAllHistoryAvailableToggled" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -441,6 +496,11 @@ pub struct RTDChatEventIsAllHistoryAvailableToggledBuilder {
 impl RTDChatEventIsAllHistoryAvailableToggledBuilder {
   pub fn build(&self) -> ChatEventIsAllHistoryAvailableToggled { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_all_history_available(&mut self, is_all_history_available: bool) -> &mut Self {
     self.inner.is_all_history_available = is_all_history_available;
@@ -469,6 +529,9 @@ pub struct ChatEventLinkedChatChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous supergroup linked chat identifier
   old_linked_chat_id: i64,
   /// New supergroup linked chat identifier
@@ -478,6 +541,7 @@ pub struct ChatEventLinkedChatChanged {
 
 impl RObject for ChatEventLinkedChatChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventLinkedChatChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -508,6 +572,11 @@ pub struct RTDChatEventLinkedChatChangedBuilder {
 impl RTDChatEventLinkedChatChangedBuilder {
   pub fn build(&self) -> ChatEventLinkedChatChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_linked_chat_id(&mut self, old_linked_chat_id: i64) -> &mut Self {
     self.inner.old_linked_chat_id = old_linked_chat_id;
@@ -542,6 +611,9 @@ pub struct ChatEventLocationChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous location; may be null
   old_location: Option<ChatLocation>,
   /// New location; may be null
@@ -551,6 +623,7 @@ pub struct ChatEventLocationChanged {
 
 impl RObject for ChatEventLocationChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventLocationChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -581,6 +654,11 @@ pub struct RTDChatEventLocationChangedBuilder {
 impl RTDChatEventLocationChangedBuilder {
   pub fn build(&self) -> ChatEventLocationChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_location<T: AsRef<ChatLocation>>(&mut self, old_location: T) -> &mut Self {
     self.inner.old_location = Some(old_location.as_ref().clone());
@@ -615,6 +693,9 @@ pub struct ChatEventMemberInvited {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New member user identifier
   user_id: i64,
   /// New member status
@@ -624,6 +705,7 @@ pub struct ChatEventMemberInvited {
 
 impl RObject for ChatEventMemberInvited {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberInvited" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -654,6 +736,11 @@ pub struct RTDChatEventMemberInvitedBuilder {
 impl RTDChatEventMemberInvitedBuilder {
   pub fn build(&self) -> ChatEventMemberInvited { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -688,11 +775,15 @@ pub struct ChatEventMemberJoined {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatEventMemberJoined {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberJoined" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -719,6 +810,11 @@ pub struct RTDChatEventMemberJoinedBuilder {
 impl RTDChatEventMemberJoinedBuilder {
   pub fn build(&self) -> ChatEventMemberJoined { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatEventMemberJoined> for ChatEventMemberJoined {
@@ -741,11 +837,15 @@ pub struct ChatEventMemberLeft {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatEventMemberLeft {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberLeft" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -772,6 +872,11 @@ pub struct RTDChatEventMemberLeftBuilder {
 impl RTDChatEventMemberLeftBuilder {
   pub fn build(&self) -> ChatEventMemberLeft { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatEventMemberLeft> for ChatEventMemberLeft {
@@ -794,6 +899,9 @@ pub struct ChatEventMemberPromoted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat member user identifier
   user_id: i64,
   /// Previous status of the chat member
@@ -805,6 +913,7 @@ pub struct ChatEventMemberPromoted {
 
 impl RObject for ChatEventMemberPromoted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberPromoted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -837,6 +946,11 @@ pub struct RTDChatEventMemberPromotedBuilder {
 impl RTDChatEventMemberPromotedBuilder {
   pub fn build(&self) -> ChatEventMemberPromoted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -877,6 +991,9 @@ pub struct ChatEventMemberRestricted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat member user identifier
   user_id: i64,
   /// Previous status of the chat member
@@ -888,6 +1005,7 @@ pub struct ChatEventMemberRestricted {
 
 impl RObject for ChatEventMemberRestricted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberRestricted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -920,6 +1038,11 @@ pub struct RTDChatEventMemberRestrictedBuilder {
 impl RTDChatEventMemberRestrictedBuilder {
   pub fn build(&self) -> ChatEventMemberRestricted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -960,6 +1083,9 @@ pub struct ChatEventMessageDeleted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Deleted message
   message: Message,
   
@@ -967,6 +1093,7 @@ pub struct ChatEventMessageDeleted {
 
 impl RObject for ChatEventMessageDeleted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageDeleted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -995,6 +1122,11 @@ pub struct RTDChatEventMessageDeletedBuilder {
 impl RTDChatEventMessageDeletedBuilder {
   pub fn build(&self) -> ChatEventMessageDeleted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -1023,6 +1155,9 @@ pub struct ChatEventMessageEdited {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The original message before the edit
   old_message: Message,
   /// The message after it was edited
@@ -1032,6 +1167,7 @@ pub struct ChatEventMessageEdited {
 
 impl RObject for ChatEventMessageEdited {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageEdited" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1062,6 +1198,11 @@ pub struct RTDChatEventMessageEditedBuilder {
 impl RTDChatEventMessageEditedBuilder {
   pub fn build(&self) -> ChatEventMessageEdited { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_message<T: AsRef<Message>>(&mut self, old_message: T) -> &mut Self {
     self.inner.old_message = old_message.as_ref().clone();
@@ -1096,6 +1237,9 @@ pub struct ChatEventMessagePinned {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pinned message
   message: Message,
   
@@ -1103,6 +1247,7 @@ pub struct ChatEventMessagePinned {
 
 impl RObject for ChatEventMessagePinned {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessagePinned" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1131,6 +1276,11 @@ pub struct RTDChatEventMessagePinnedBuilder {
 impl RTDChatEventMessagePinnedBuilder {
   pub fn build(&self) -> ChatEventMessagePinned { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -1159,11 +1309,15 @@ pub struct ChatEventMessageUnpinned {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatEventMessageUnpinned {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageUnpinned" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1190,6 +1344,11 @@ pub struct RTDChatEventMessageUnpinnedBuilder {
 impl RTDChatEventMessageUnpinnedBuilder {
   pub fn build(&self) -> ChatEventMessageUnpinned { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatEventMessageUnpinned> for ChatEventMessageUnpinned {
@@ -1212,6 +1371,9 @@ pub struct ChatEventPermissionsChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous chat permissions
   old_permissions: ChatPermissions,
   /// New chat permissions
@@ -1221,6 +1383,7 @@ pub struct ChatEventPermissionsChanged {
 
 impl RObject for ChatEventPermissionsChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventPermissionsChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1251,6 +1414,11 @@ pub struct RTDChatEventPermissionsChangedBuilder {
 impl RTDChatEventPermissionsChangedBuilder {
   pub fn build(&self) -> ChatEventPermissionsChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_permissions<T: AsRef<ChatPermissions>>(&mut self, old_permissions: T) -> &mut Self {
     self.inner.old_permissions = old_permissions.as_ref().clone();
@@ -1285,6 +1453,9 @@ pub struct ChatEventPhotoChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous chat photo value; may be null
   old_photo: Option<ChatPhoto>,
   /// New chat photo value; may be null
@@ -1294,6 +1465,7 @@ pub struct ChatEventPhotoChanged {
 
 impl RObject for ChatEventPhotoChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventPhotoChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1324,6 +1496,11 @@ pub struct RTDChatEventPhotoChangedBuilder {
 impl RTDChatEventPhotoChangedBuilder {
   pub fn build(&self) -> ChatEventPhotoChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_photo<T: AsRef<ChatPhoto>>(&mut self, old_photo: T) -> &mut Self {
     self.inner.old_photo = Some(old_photo.as_ref().clone());
@@ -1358,6 +1535,9 @@ pub struct ChatEventPollStopped {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The message with the poll
   message: Message,
   
@@ -1365,6 +1545,7 @@ pub struct ChatEventPollStopped {
 
 impl RObject for ChatEventPollStopped {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventPollStopped" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1393,6 +1574,11 @@ pub struct RTDChatEventPollStoppedBuilder {
 impl RTDChatEventPollStoppedBuilder {
   pub fn build(&self) -> ChatEventPollStopped { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -1421,6 +1607,9 @@ pub struct ChatEventSignMessagesToggled {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New value of sign_messages
   sign_messages: bool,
   
@@ -1428,6 +1617,7 @@ pub struct ChatEventSignMessagesToggled {
 
 impl RObject for ChatEventSignMessagesToggled {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventSignMessagesToggled" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1456,6 +1646,11 @@ pub struct RTDChatEventSignMessagesToggledBuilder {
 impl RTDChatEventSignMessagesToggledBuilder {
   pub fn build(&self) -> ChatEventSignMessagesToggled { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sign_messages(&mut self, sign_messages: bool) -> &mut Self {
     self.inner.sign_messages = sign_messages;
@@ -1484,6 +1679,9 @@ pub struct ChatEventSlowModeDelayChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous value of slow_mode_delay
   old_slow_mode_delay: i64,
   /// New value of slow_mode_delay
@@ -1493,6 +1691,7 @@ pub struct ChatEventSlowModeDelayChanged {
 
 impl RObject for ChatEventSlowModeDelayChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventSlowModeDelayChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1523,6 +1722,11 @@ pub struct RTDChatEventSlowModeDelayChangedBuilder {
 impl RTDChatEventSlowModeDelayChangedBuilder {
   pub fn build(&self) -> ChatEventSlowModeDelayChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_slow_mode_delay(&mut self, old_slow_mode_delay: i64) -> &mut Self {
     self.inner.old_slow_mode_delay = old_slow_mode_delay;
@@ -1557,15 +1761,19 @@ pub struct ChatEventStickerSetChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous identifier of the chat sticker set; 0 if none
-  old_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] old_sticker_set_id: i64,
   /// New identifier of the chat sticker set; 0 if none
-  new_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] new_sticker_set_id: i64,
   
 }
 
 impl RObject for ChatEventStickerSetChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventStickerSetChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1582,9 +1790,9 @@ impl ChatEventStickerSetChanged {
     RTDChatEventStickerSetChangedBuilder { inner }
   }
 
-  pub fn old_sticker_set_id(&self) -> isize { self.old_sticker_set_id }
+  pub fn old_sticker_set_id(&self) -> i64 { self.old_sticker_set_id }
 
-  pub fn new_sticker_set_id(&self) -> isize { self.new_sticker_set_id }
+  pub fn new_sticker_set_id(&self) -> i64 { self.new_sticker_set_id }
 
 }
 
@@ -1596,14 +1804,19 @@ pub struct RTDChatEventStickerSetChangedBuilder {
 impl RTDChatEventStickerSetChangedBuilder {
   pub fn build(&self) -> ChatEventStickerSetChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn old_sticker_set_id(&mut self, old_sticker_set_id: isize) -> &mut Self {
+  pub fn old_sticker_set_id(&mut self, old_sticker_set_id: i64) -> &mut Self {
     self.inner.old_sticker_set_id = old_sticker_set_id;
     self
   }
 
    
-  pub fn new_sticker_set_id(&mut self, new_sticker_set_id: isize) -> &mut Self {
+  pub fn new_sticker_set_id(&mut self, new_sticker_set_id: i64) -> &mut Self {
     self.inner.new_sticker_set_id = new_sticker_set_id;
     self
   }
@@ -1630,6 +1843,9 @@ pub struct ChatEventTitleChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous chat title
   old_title: String,
   /// New chat title
@@ -1639,6 +1855,7 @@ pub struct ChatEventTitleChanged {
 
 impl RObject for ChatEventTitleChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventTitleChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1669,6 +1886,11 @@ pub struct RTDChatEventTitleChangedBuilder {
 impl RTDChatEventTitleChangedBuilder {
   pub fn build(&self) -> ChatEventTitleChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_title<T: AsRef<str>>(&mut self, old_title: T) -> &mut Self {
     self.inner.old_title = old_title.as_ref().to_string();
@@ -1703,6 +1925,9 @@ pub struct ChatEventUsernameChanged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous chat username
   old_username: String,
   /// New chat username
@@ -1712,6 +1937,7 @@ pub struct ChatEventUsernameChanged {
 
 impl RObject for ChatEventUsernameChanged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventUsernameChanged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1742,6 +1968,11 @@ pub struct RTDChatEventUsernameChangedBuilder {
 impl RTDChatEventUsernameChangedBuilder {
   pub fn build(&self) -> ChatEventUsernameChanged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_username<T: AsRef<str>>(&mut self, old_username: T) -> &mut Self {
     self.inner.old_username = old_username.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct ChatEventLogFilters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if message edits should be returned
   message_edits: bool,
   /// True, if message deletions should be returned
@@ -36,6 +39,7 @@ pub struct ChatEventLogFilters {
 
 impl RObject for ChatEventLogFilters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventLogFilters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -79,6 +83,11 @@ pub struct RTDChatEventLogFiltersBuilder {
 impl RTDChatEventLogFiltersBuilder {
   pub fn build(&self) -> ChatEventLogFilters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_edits(&mut self, message_edits: bool) -> &mut Self {
     self.inner.message_edits = message_edits;
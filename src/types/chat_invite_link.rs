@@ -11,6 +11,9 @@ pub struct ChatInviteLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat invite link
   invite_link: String,
   
@@ -18,6 +21,7 @@ pub struct ChatInviteLink {
 
 impl RObject for ChatInviteLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDChatInviteLinkBuilder {
 impl RTDChatInviteLinkBuilder {
   pub fn build(&self) -> ChatInviteLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct ChatInviteLinkInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the invite link; 0 if the user have no access to the chat before joining
   chat_id: i64,
   /// If non-zero, the remaining time for which read access is granted to the chat, in seconds
@@ -32,6 +35,7 @@ pub struct ChatInviteLinkInfo {
 
 impl RObject for ChatInviteLinkInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLinkInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -71,6 +75,11 @@ pub struct RTDChatInviteLinkInfoBuilder {
 impl RTDChatInviteLinkInfoBuilder {
   pub fn build(&self) -> ChatInviteLinkInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
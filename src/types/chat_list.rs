@@ -18,6 +18,9 @@ pub trait TDChatList: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatList {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives
   Archive(ChatListArchive),
   /// A list of chats belonging to a chat filter
@@ -54,12 +57,23 @@ impl RObject for ChatList {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatList::Archive(t) => t.extra(),
+      ChatList::Filter(t) => t.extra(),
+      ChatList::Main(t) => t.extra(),
+
+      ChatList::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatList {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatList::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatList::Unknown { .. } = self { true } else { false } }
 
   pub fn is_archive(&self) -> bool { if let ChatList::Archive(_) = self { true } else { false } }
   pub fn is_filter(&self) -> bool { if let ChatList::Filter(_) = self { true } else { false } }
@@ -99,11 +113,15 @@ pub struct ChatListArchive {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatListArchive {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatListArchive" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -130,6 +148,11 @@ pub struct RTDChatListArchiveBuilder {
 impl RTDChatListArchiveBuilder {
   pub fn build(&self) -> ChatListArchive { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatListArchive> for ChatListArchive {
@@ -152,6 +175,9 @@ pub struct ChatListFilter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat filter identifier
   chat_filter_id: i64,
   
@@ -159,6 +185,7 @@ pub struct ChatListFilter {
 
 impl RObject for ChatListFilter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatListFilter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -187,6 +214,11 @@ pub struct RTDChatListFilterBuilder {
 impl RTDChatListFilterBuilder {
   pub fn build(&self) -> ChatListFilter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
@@ -215,11 +247,15 @@ pub struct ChatListMain {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatListMain {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatListMain" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -246,6 +282,11 @@ pub struct RTDChatListMainBuilder {
 impl RTDChatListMainBuilder {
   pub fn build(&self) -> ChatListMain { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatListMain> for ChatListMain {
@@ -11,6 +11,9 @@ pub struct ChatLists {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of chat lists
   chat_lists: Vec<ChatList>,
   
@@ -18,6 +21,7 @@ pub struct ChatLists {
 
 impl RObject for ChatLists {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatLists" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDChatListsBuilder {
 impl RTDChatListsBuilder {
   pub fn build(&self) -> ChatLists { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_lists(&mut self, chat_lists: Vec<ChatList>) -> &mut Self {
     self.inner.chat_lists = chat_lists;
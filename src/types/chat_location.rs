@@ -11,6 +11,9 @@ pub struct ChatLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The location
   location: Location,
   /// Location address; 1-64 characters, as defined by the chat owner
@@ -20,6 +23,7 @@ pub struct ChatLocation {
 
 impl RObject for ChatLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDChatLocationBuilder {
 impl RTDChatLocationBuilder {
   pub fn build(&self) -> ChatLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct ChatMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier of the chat member
   user_id: i64,
   /// Identifier of a user that invited/promoted/banned this member in the chat; 0 if unknown
@@ -26,6 +29,7 @@ pub struct ChatMember {
 
 impl RObject for ChatMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDChatMemberBuilder {
 impl RTDChatMemberBuilder {
   pub fn build(&self) -> ChatMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
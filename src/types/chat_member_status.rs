@@ -18,6 +18,9 @@ pub trait TDChatMemberStatus: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatMemberStatus {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The user is a member of a chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, and ban unprivileged members. In supergroups and channels, there are more detailed options for administrator privileges
   Administrator(ChatMemberStatusAdministrator),
   /// The user was banned (and hence is not a member of the chat). Implies the user can't return to the chat or view messages
@@ -66,12 +69,26 @@ impl RObject for ChatMemberStatus {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatMemberStatus::Administrator(t) => t.extra(),
+      ChatMemberStatus::Banned(t) => t.extra(),
+      ChatMemberStatus::Creator(t) => t.extra(),
+      ChatMemberStatus::Left(t) => t.extra(),
+      ChatMemberStatus::Member(t) => t.extra(),
+      ChatMemberStatus::Restricted(t) => t.extra(),
+
+      ChatMemberStatus::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatMemberStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatMemberStatus::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatMemberStatus::Unknown { .. } = self { true } else { false } }
 
   pub fn is_administrator(&self) -> bool { if let ChatMemberStatus::Administrator(_) = self { true } else { false } }
   pub fn is_banned(&self) -> bool { if let ChatMemberStatus::Banned(_) = self { true } else { false } }
@@ -126,6 +143,9 @@ pub struct ChatMemberStatusAdministrator {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A custom title of the administrator; 0-16 characters without emojis; applicable to supergroups only
   custom_title: String,
   /// True, if the current user can edit the administrator privileges for the called user
@@ -151,6 +171,7 @@ pub struct ChatMemberStatusAdministrator {
 
 impl RObject for ChatMemberStatusAdministrator {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusAdministrator" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -197,6 +218,11 @@ pub struct RTDChatMemberStatusAdministratorBuilder {
 impl RTDChatMemberStatusAdministratorBuilder {
   pub fn build(&self) -> ChatMemberStatusAdministrator { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
@@ -279,6 +305,9 @@ pub struct ChatMemberStatusBanned {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever
   banned_until_date: i64,
   
@@ -286,6 +315,7 @@ pub struct ChatMemberStatusBanned {
 
 impl RObject for ChatMemberStatusBanned {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusBanned" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -314,6 +344,11 @@ pub struct RTDChatMemberStatusBannedBuilder {
 impl RTDChatMemberStatusBannedBuilder {
   pub fn build(&self) -> ChatMemberStatusBanned { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn banned_until_date(&mut self, banned_until_date: i64) -> &mut Self {
     self.inner.banned_until_date = banned_until_date;
@@ -342,6 +377,9 @@ pub struct ChatMemberStatusCreator {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A custom title of the owner; 0-16 characters without emojis; applicable to supergroups only
   custom_title: String,
   /// True, if the user is a member of the chat
@@ -351,6 +389,7 @@ pub struct ChatMemberStatusCreator {
 
 impl RObject for ChatMemberStatusCreator {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusCreator" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -381,6 +420,11 @@ pub struct RTDChatMemberStatusCreatorBuilder {
 impl RTDChatMemberStatusCreatorBuilder {
   pub fn build(&self) -> ChatMemberStatusCreator { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
@@ -415,11 +459,15 @@ pub struct ChatMemberStatusLeft {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMemberStatusLeft {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusLeft" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -446,6 +494,11 @@ pub struct RTDChatMemberStatusLeftBuilder {
 impl RTDChatMemberStatusLeftBuilder {
   pub fn build(&self) -> ChatMemberStatusLeft { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMemberStatusLeft> for ChatMemberStatusLeft {
@@ -468,11 +521,15 @@ pub struct ChatMemberStatusMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMemberStatusMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -499,6 +556,11 @@ pub struct RTDChatMemberStatusMemberBuilder {
 impl RTDChatMemberStatusMemberBuilder {
   pub fn build(&self) -> ChatMemberStatusMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMemberStatusMember> for ChatMemberStatusMember {
@@ -521,6 +583,9 @@ pub struct ChatMemberStatusRestricted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the user is a member of the chat
   is_member: bool,
   /// Point in time (Unix timestamp) when restrictions will be lifted from the user; 0 if never. If the user is restricted for more than 366 days or for less than 30 seconds from the current time, the user is considered to be restricted forever
@@ -532,6 +597,7 @@ pub struct ChatMemberStatusRestricted {
 
 impl RObject for ChatMemberStatusRestricted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusRestricted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -564,6 +630,11 @@ pub struct RTDChatMemberStatusRestrictedBuilder {
 impl RTDChatMemberStatusRestrictedBuilder {
   pub fn build(&self) -> ChatMemberStatusRestricted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_member(&mut self, is_member: bool) -> &mut Self {
     self.inner.is_member = is_member;
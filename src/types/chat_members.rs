@@ -11,6 +11,9 @@ pub struct ChatMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Approximate total count of chat members found
   total_count: i64,
   /// A list of chat members
@@ -20,6 +23,7 @@ pub struct ChatMembers {
 
 impl RObject for ChatMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDChatMembersBuilder {
 impl RTDChatMembersBuilder {
   pub fn build(&self) -> ChatMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -18,6 +18,9 @@ pub trait TDChatMembersFilter: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatMembersFilter {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Returns the owner and administrators
   Administrators(ChatMembersFilterAdministrators),
   /// Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel
@@ -66,12 +69,26 @@ impl RObject for ChatMembersFilter {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatMembersFilter::Administrators(t) => t.extra(),
+      ChatMembersFilter::Banned(t) => t.extra(),
+      ChatMembersFilter::Bots(t) => t.extra(),
+      ChatMembersFilter::Contacts(t) => t.extra(),
+      ChatMembersFilter::Members(t) => t.extra(),
+      ChatMembersFilter::Restricted(t) => t.extra(),
+
+      ChatMembersFilter::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatMembersFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatMembersFilter::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatMembersFilter::Unknown { .. } = self { true } else { false } }
 
   pub fn is_administrators(&self) -> bool { if let ChatMembersFilter::Administrators(_) = self { true } else { false } }
   pub fn is_banned(&self) -> bool { if let ChatMembersFilter::Banned(_) = self { true } else { false } }
@@ -126,11 +143,15 @@ pub struct ChatMembersFilterAdministrators {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMembersFilterAdministrators {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterAdministrators" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,6 +178,11 @@ pub struct RTDChatMembersFilterAdministratorsBuilder {
 impl RTDChatMembersFilterAdministratorsBuilder {
   pub fn build(&self) -> ChatMembersFilterAdministrators { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterAdministrators> for ChatMembersFilterAdministrators {
@@ -179,11 +205,15 @@ pub struct ChatMembersFilterBanned {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMembersFilterBanned {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterBanned" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -210,6 +240,11 @@ pub struct RTDChatMembersFilterBannedBuilder {
 impl RTDChatMembersFilterBannedBuilder {
   pub fn build(&self) -> ChatMembersFilterBanned { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterBanned> for ChatMembersFilterBanned {
@@ -232,11 +267,15 @@ pub struct ChatMembersFilterBots {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMembersFilterBots {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterBots" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,6 +302,11 @@ pub struct RTDChatMembersFilterBotsBuilder {
 impl RTDChatMembersFilterBotsBuilder {
   pub fn build(&self) -> ChatMembersFilterBots { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterBots> for ChatMembersFilterBots {
@@ -285,11 +329,15 @@ pub struct ChatMembersFilterContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMembersFilterContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -316,6 +364,11 @@ pub struct RTDChatMembersFilterContactsBuilder {
 impl RTDChatMembersFilterContactsBuilder {
   pub fn build(&self) -> ChatMembersFilterContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterContacts> for ChatMembersFilterContacts {
@@ -338,11 +391,15 @@ pub struct ChatMembersFilterMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMembersFilterMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -369,6 +426,11 @@ pub struct RTDChatMembersFilterMembersBuilder {
 impl RTDChatMembersFilterMembersBuilder {
   pub fn build(&self) -> ChatMembersFilterMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterMembers> for ChatMembersFilterMembers {
@@ -391,11 +453,15 @@ pub struct ChatMembersFilterRestricted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatMembersFilterRestricted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterRestricted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -422,6 +488,11 @@ pub struct RTDChatMembersFilterRestrictedBuilder {
 impl RTDChatMembersFilterRestrictedBuilder {
   pub fn build(&self) -> ChatMembersFilterRestricted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterRestricted> for ChatMembersFilterRestricted {
@@ -11,6 +11,9 @@ pub struct ChatNearby {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Distance to the chat location in meters
@@ -20,6 +23,7 @@ pub struct ChatNearby {
 
 impl RObject for ChatNearby {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatNearby" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDChatNearbyBuilder {
 impl RTDChatNearbyBuilder {
   pub fn build(&self) -> ChatNearby { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -11,6 +11,9 @@ pub struct ChatNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, mute_for is ignored and the value for the relevant type of chat is used instead
   use_default_mute_for: bool,
   /// Time left before notifications will be unmuted, in seconds
@@ -36,6 +39,7 @@ pub struct ChatNotificationSettings {
 
 impl RObject for ChatNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -79,6 +83,11 @@ pub struct RTDChatNotificationSettingsBuilder {
 impl RTDChatNotificationSettingsBuilder {
   pub fn build(&self) -> ChatNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn use_default_mute_for(&mut self, use_default_mute_for: bool) -> &mut Self {
     self.inner.use_default_mute_for = use_default_mute_for;
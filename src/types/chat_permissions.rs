@@ -11,6 +11,9 @@ pub struct ChatPermissions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the user can send text messages, contacts, locations, and venues
   can_send_messages: bool,
   /// True, if the user can send audio files, documents, photos, videos, video notes, and voice notes. Implies can_send_messages permissions
@@ -32,6 +35,7 @@ pub struct ChatPermissions {
 
 impl RObject for ChatPermissions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPermissions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -71,6 +75,11 @@ pub struct RTDChatPermissionsBuilder {
 impl RTDChatPermissionsBuilder {
   pub fn build(&self) -> ChatPermissions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_send_messages(&mut self, can_send_messages: bool) -> &mut Self {
     self.inner.can_send_messages = can_send_messages;
@@ -11,8 +11,11 @@ pub struct ChatPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique photo identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Point in time (Unix timestamp) when the photo has been added
   added_date: i64,
   /// Photo minithumbnail; may be null
@@ -26,6 +29,7 @@ pub struct ChatPhoto {
 
 impl RObject for ChatPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -39,7 +43,7 @@ impl ChatPhoto {
     RTDChatPhotoBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn added_date(&self) -> i64 { self.added_date }
 
@@ -59,8 +63,13 @@ pub struct RTDChatPhotoBuilder {
 impl RTDChatPhotoBuilder {
   pub fn build(&self) -> ChatPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -11,6 +11,9 @@ pub struct ChatPhotoInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A small (160x160) chat photo variant in JPEG format. The file can be downloaded only before the photo is changed
   small: File,
   /// A big (640x640) chat photo variant in JPEG format. The file can be downloaded only before the photo is changed
@@ -22,6 +25,7 @@ pub struct ChatPhotoInfo {
 
 impl RObject for ChatPhotoInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPhotoInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDChatPhotoInfoBuilder {
 impl RTDChatPhotoInfoBuilder {
   pub fn build(&self) -> ChatPhotoInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn small<T: AsRef<File>>(&mut self, small: T) -> &mut Self {
     self.inner.small = small.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct ChatPhotos {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Total number of photos
   total_count: i64,
   /// List of photos
@@ -20,6 +23,7 @@ pub struct ChatPhotos {
 
 impl RObject for ChatPhotos {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPhotos" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDChatPhotosBuilder {
 impl RTDChatPhotosBuilder {
   pub fn build(&self) -> ChatPhotos { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -11,10 +11,13 @@ pub struct ChatPosition {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat list
   list: ChatList,
   /// A parameter used to determine order of the chat in the chat list. Chats must be sorted by the pair (order, chat.id) in descending order
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] order: isize,
+  #[serde(with = "crate::types::_common::int64_str")] order: i64,
   /// True, if the chat is pinned in the chat list
   is_pinned: bool,
   /// Source of the chat in the chat list; may be null
@@ -24,6 +27,7 @@ pub struct ChatPosition {
 
 impl RObject for ChatPosition {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPosition" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -39,7 +43,7 @@ impl ChatPosition {
 
   pub fn list(&self) -> &ChatList { &self.list }
 
-  pub fn order(&self) -> isize { self.order }
+  pub fn order(&self) -> i64 { self.order }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
 
@@ -55,6 +59,11 @@ pub struct RTDChatPositionBuilder {
 impl RTDChatPositionBuilder {
   pub fn build(&self) -> ChatPosition { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn list<T: AsRef<ChatList>>(&mut self, list: T) -> &mut Self {
     self.inner.list = list.as_ref().clone();
@@ -62,7 +71,7 @@ impl RTDChatPositionBuilder {
   }
 
    
-  pub fn order(&mut self, order: isize) -> &mut Self {
+  pub fn order(&mut self, order: i64) -> &mut Self {
     self.inner.order = order;
     self
   }
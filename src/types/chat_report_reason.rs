@@ -18,6 +18,9 @@ pub trait TDChatReportReason: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatReportReason {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat has child abuse related content
   ChildAbuse(ChatReportReasonChildAbuse),
   /// The chat contains copyrighted content
@@ -70,12 +73,27 @@ impl RObject for ChatReportReason {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatReportReason::ChildAbuse(t) => t.extra(),
+      ChatReportReason::Copyright(t) => t.extra(),
+      ChatReportReason::Custom(t) => t.extra(),
+      ChatReportReason::Pornography(t) => t.extra(),
+      ChatReportReason::Spam(t) => t.extra(),
+      ChatReportReason::UnrelatedLocation(t) => t.extra(),
+      ChatReportReason::Violence(t) => t.extra(),
+
+      ChatReportReason::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatReportReason {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatReportReason::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatReportReason::Unknown { .. } = self { true } else { false } }
 
   pub fn is_child_abuse(&self) -> bool { if let ChatReportReason::ChildAbuse(_) = self { true } else { false } }
   pub fn is_copyright(&self) -> bool { if let ChatReportReason::Copyright(_) = self { true } else { false } }
@@ -135,11 +153,15 @@ pub struct ChatReportReasonChildAbuse {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatReportReasonChildAbuse {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonChildAbuse" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -166,6 +188,11 @@ pub struct RTDChatReportReasonChildAbuseBuilder {
 impl RTDChatReportReasonChildAbuseBuilder {
   pub fn build(&self) -> ChatReportReasonChildAbuse { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonChildAbuse> for ChatReportReasonChildAbuse {
@@ -188,11 +215,15 @@ pub struct ChatReportReasonCopyright {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatReportReasonCopyright {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonCopyright" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -219,6 +250,11 @@ pub struct RTDChatReportReasonCopyrightBuilder {
 impl RTDChatReportReasonCopyrightBuilder {
   pub fn build(&self) -> ChatReportReasonCopyright { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonCopyright> for ChatReportReasonCopyright {
@@ -241,6 +277,9 @@ pub struct ChatReportReasonCustom {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Report text
   text: String,
   
@@ -248,6 +287,7 @@ pub struct ChatReportReasonCustom {
 
 impl RObject for ChatReportReasonCustom {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonCustom" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -276,6 +316,11 @@ pub struct RTDChatReportReasonCustomBuilder {
 impl RTDChatReportReasonCustomBuilder {
   pub fn build(&self) -> ChatReportReasonCustom { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -304,11 +349,15 @@ pub struct ChatReportReasonPornography {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatReportReasonPornography {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonPornography" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -335,6 +384,11 @@ pub struct RTDChatReportReasonPornographyBuilder {
 impl RTDChatReportReasonPornographyBuilder {
   pub fn build(&self) -> ChatReportReasonPornography { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonPornography> for ChatReportReasonPornography {
@@ -357,11 +411,15 @@ pub struct ChatReportReasonSpam {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatReportReasonSpam {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonSpam" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -388,6 +446,11 @@ pub struct RTDChatReportReasonSpamBuilder {
 impl RTDChatReportReasonSpamBuilder {
   pub fn build(&self) -> ChatReportReasonSpam { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonSpam> for ChatReportReasonSpam {
@@ -410,11 +473,15 @@ pub struct ChatReportReasonUnrelatedLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatReportReasonUnrelatedLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonUnrelatedLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -441,6 +508,11 @@ pub struct RTDChatReportReasonUnrelatedLocationBuilder {
 impl RTDChatReportReasonUnrelatedLocationBuilder {
   pub fn build(&self) -> ChatReportReasonUnrelatedLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonUnrelatedLocation> for ChatReportReasonUnrelatedLocation {
@@ -463,11 +535,15 @@ pub struct ChatReportReasonViolence {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatReportReasonViolence {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonViolence" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -494,6 +570,11 @@ pub struct RTDChatReportReasonViolenceBuilder {
 impl RTDChatReportReasonViolenceBuilder {
   pub fn build(&self) -> ChatReportReasonViolence { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonViolence> for ChatReportReasonViolence {
@@ -18,6 +18,9 @@ pub trait TDChatSource: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatSource {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat is sponsored by the user's MTProxy server
   MtprotoProxy(ChatSourceMtprotoProxy),
   /// The chat contains a public service announcement
@@ -50,12 +53,22 @@ impl RObject for ChatSource {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatSource::MtprotoProxy(t) => t.extra(),
+      ChatSource::PublicServiceAnnouncement(t) => t.extra(),
+
+      ChatSource::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatSource {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatSource::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatSource::Unknown { .. } = self { true } else { false } }
 
   pub fn is_mtproto_proxy(&self) -> bool { if let ChatSource::MtprotoProxy(_) = self { true } else { false } }
   pub fn is_public_service_announcement(&self) -> bool { if let ChatSource::PublicServiceAnnouncement(_) = self { true } else { false } }
@@ -90,11 +103,15 @@ pub struct ChatSourceMtprotoProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ChatSourceMtprotoProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatSourceMtprotoProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,6 +138,11 @@ pub struct RTDChatSourceMtprotoProxyBuilder {
 impl RTDChatSourceMtprotoProxyBuilder {
   pub fn build(&self) -> ChatSourceMtprotoProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatSourceMtprotoProxy> for ChatSourceMtprotoProxy {
@@ -143,6 +165,9 @@ pub struct ChatSourcePublicServiceAnnouncement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The type of the announcement
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// The text of the announcement
@@ -152,6 +177,7 @@ pub struct ChatSourcePublicServiceAnnouncement {
 
 impl RObject for ChatSourcePublicServiceAnnouncement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatSourcePublicServiceAnnouncement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -182,6 +208,11 @@ pub struct RTDChatSourcePublicServiceAnnouncementBuilder {
 impl RTDChatSourcePublicServiceAnnouncementBuilder {
   pub fn build(&self) -> ChatSourcePublicServiceAnnouncement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -18,12 +18,13 @@ pub trait TDChatStatistics: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatStatistics {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A detailed statistics about a channel chat
   Channel(ChatStatisticsChannel),
   /// A detailed statistics about a supergroup chat
   Supergroup(ChatStatisticsSupergroup),
-  /// Returns detailed statistics about a chat. Currently this method can be used only for supergroups and channels. Requires administrator rights in the channel
-  GetChatStatistics(GetChatStatistics),
 
 }
 
@@ -38,7 +39,6 @@ impl<'de> Deserialize<'de> for ChatStatistics {
       ChatStatistics,
       (chatStatisticsChannel, Channel);
       (chatStatisticsSupergroup, Supergroup);
-      (getChatStatistics, GetChatStatistics);
 
     )(deserializer)
   }
@@ -49,29 +49,35 @@ impl RObject for ChatStatistics {
     match self {
       ChatStatistics::Channel(t) => t.td_name(),
       ChatStatistics::Supergroup(t) => t.td_name(),
-      ChatStatistics::GetChatStatistics(t) => t.td_name(),
 
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatStatistics::Channel(t) => t.extra(),
+      ChatStatistics::Supergroup(t) => t.extra(),
+
+      ChatStatistics::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatStatistics::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatStatistics::Unknown { .. } = self { true } else { false } }
 
   pub fn is_channel(&self) -> bool { if let ChatStatistics::Channel(_) = self { true } else { false } }
   pub fn is_supergroup(&self) -> bool { if let ChatStatistics::Supergroup(_) = self { true } else { false } }
-  pub fn is_get_chat_statistics(&self) -> bool { if let ChatStatistics::GetChatStatistics(_) = self { true } else { false } }
 
   pub fn on_channel<F: FnOnce(&ChatStatisticsChannel)>(&self, fnc: F) -> &Self { if let ChatStatistics::Channel(t) = self { fnc(t) }; self }
   pub fn on_supergroup<F: FnOnce(&ChatStatisticsSupergroup)>(&self, fnc: F) -> &Self { if let ChatStatistics::Supergroup(t) = self { fnc(t) }; self }
-  pub fn on_get_chat_statistics<F: FnOnce(&GetChatStatistics)>(&self, fnc: F) -> &Self { if let ChatStatistics::GetChatStatistics(t) = self { fnc(t) }; self }
 
   pub fn as_channel(&self) -> Option<&ChatStatisticsChannel> { if let ChatStatistics::Channel(t) = self { return Some(t) } None }
   pub fn as_supergroup(&self) -> Option<&ChatStatisticsSupergroup> { if let ChatStatistics::Supergroup(t) = self { return Some(t) } None }
-  pub fn as_get_chat_statistics(&self) -> Option<&GetChatStatistics> { if let ChatStatistics::GetChatStatistics(t) = self { return Some(t) } None }
 
 
 
@@ -79,8 +85,6 @@ impl ChatStatistics {
 
   pub fn supergroup<T: AsRef<ChatStatisticsSupergroup>>(t: T) -> Self { ChatStatistics::Supergroup(t.as_ref().clone()) }
 
-  pub fn get_chat_statistics<T: AsRef<GetChatStatistics>>(t: T) -> Self { ChatStatistics::GetChatStatistics(t.as_ref().clone()) }
-
 }
 
 impl AsRef<ChatStatistics> for ChatStatistics {
@@ -99,6 +103,9 @@ pub struct ChatStatisticsChannel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A period to which the statistics applies
   period: DateRange,
   /// Number of members in the chat
@@ -134,6 +141,7 @@ pub struct ChatStatisticsChannel {
 
 impl RObject for ChatStatisticsChannel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsChannel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -190,6 +198,11 @@ pub struct RTDChatStatisticsChannelBuilder {
 impl RTDChatStatisticsChannelBuilder {
   pub fn build(&self) -> ChatStatisticsChannel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn period<T: AsRef<DateRange>>(&mut self, period: T) -> &mut Self {
     self.inner.period = period.as_ref().clone();
@@ -302,6 +315,9 @@ pub struct ChatStatisticsSupergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A period to which the statistics applies
   period: DateRange,
   /// Number of members in the chat
@@ -339,6 +355,7 @@ pub struct ChatStatisticsSupergroup {
 
 impl RObject for ChatStatisticsSupergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsSupergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -397,6 +414,11 @@ pub struct RTDChatStatisticsSupergroupBuilder {
 impl RTDChatStatisticsSupergroupBuilder {
   pub fn build(&self) -> ChatStatisticsSupergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn period<T: AsRef<DateRange>>(&mut self, period: T) -> &mut Self {
     self.inner.period = period.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct ChatStatisticsAdministratorActionsInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Administrator user identifier
   user_id: i64,
   /// Number of messages deleted by the administrator
@@ -24,6 +27,7 @@ pub struct ChatStatisticsAdministratorActionsInfo {
 
 impl RObject for ChatStatisticsAdministratorActionsInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsAdministratorActionsInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDChatStatisticsAdministratorActionsInfoBuilder {
 impl RTDChatStatisticsAdministratorActionsInfoBuilder {
   pub fn build(&self) -> ChatStatisticsAdministratorActionsInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -11,6 +11,9 @@ pub struct ChatStatisticsInviterInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// Number of new members invited by the user
@@ -20,6 +23,7 @@ pub struct ChatStatisticsInviterInfo {
 
 impl RObject for ChatStatisticsInviterInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsInviterInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDChatStatisticsInviterInfoBuilder {
 impl RTDChatStatisticsInviterInfoBuilder {
   pub fn build(&self) -> ChatStatisticsInviterInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
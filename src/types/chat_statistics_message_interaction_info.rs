@@ -11,6 +11,9 @@ pub struct ChatStatisticsMessageInteractionInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message identifier
   message_id: i64,
   /// Number of times the message was viewed
@@ -22,6 +25,7 @@ pub struct ChatStatisticsMessageInteractionInfo {
 
 impl RObject for ChatStatisticsMessageInteractionInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsMessageInteractionInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDChatStatisticsMessageInteractionInfoBuilder {
 impl RTDChatStatisticsMessageInteractionInfoBuilder {
   pub fn build(&self) -> ChatStatisticsMessageInteractionInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
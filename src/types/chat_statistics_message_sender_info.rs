@@ -11,6 +11,9 @@ pub struct ChatStatisticsMessageSenderInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// Number of sent messages
@@ -22,6 +25,7 @@ pub struct ChatStatisticsMessageSenderInfo {
 
 impl RObject for ChatStatisticsMessageSenderInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsMessageSenderInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDChatStatisticsMessageSenderInfoBuilder {
 impl RTDChatStatisticsMessageSenderInfoBuilder {
   pub fn build(&self) -> ChatStatisticsMessageSenderInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
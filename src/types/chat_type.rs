@@ -18,6 +18,9 @@ pub trait TDChatType: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A basic group (i.e., a chat with 0-200 other users)
   BasicGroup(ChatTypeBasicGroup),
   /// An ordinary chat with a user
@@ -58,12 +61,24 @@ impl RObject for ChatType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ChatType::BasicGroup(t) => t.extra(),
+      ChatType::Private(t) => t.extra(),
+      ChatType::Secret(t) => t.extra(),
+      ChatType::Supergroup(t) => t.extra(),
+
+      ChatType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ChatType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ChatType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_basic_group(&self) -> bool { if let ChatType::BasicGroup(_) = self { true } else { false } }
   pub fn is_private(&self) -> bool { if let ChatType::Private(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct ChatTypeBasicGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Basic group identifier
   basic_group_id: i64,
   
@@ -115,6 +133,7 @@ pub struct ChatTypeBasicGroup {
 
 impl RObject for ChatTypeBasicGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypeBasicGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +162,11 @@ pub struct RTDChatTypeBasicGroupBuilder {
 impl RTDChatTypeBasicGroupBuilder {
   pub fn build(&self) -> ChatTypeBasicGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
@@ -171,6 +195,9 @@ pub struct ChatTypePrivate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   
@@ -178,6 +205,7 @@ pub struct ChatTypePrivate {
 
 impl RObject for ChatTypePrivate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypePrivate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -206,6 +234,11 @@ pub struct RTDChatTypePrivateBuilder {
 impl RTDChatTypePrivateBuilder {
   pub fn build(&self) -> ChatTypePrivate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -234,6 +267,9 @@ pub struct ChatTypeSecret {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Secret chat identifier
   secret_chat_id: i64,
   /// User identifier of the secret chat peer
@@ -243,6 +279,7 @@ pub struct ChatTypeSecret {
 
 impl RObject for ChatTypeSecret {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypeSecret" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,6 +310,11 @@ pub struct RTDChatTypeSecretBuilder {
 impl RTDChatTypeSecretBuilder {
   pub fn build(&self) -> ChatTypeSecret { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
@@ -307,6 +349,9 @@ pub struct ChatTypeSupergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Supergroup or channel identifier
   supergroup_id: i64,
   /// True, if the supergroup is a channel
@@ -316,6 +361,7 @@ pub struct ChatTypeSupergroup {
 
 impl RObject for ChatTypeSupergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypeSupergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -346,6 +392,11 @@ pub struct RTDChatTypeSupergroupBuilder {
 impl RTDChatTypeSupergroupBuilder {
   pub fn build(&self) -> ChatTypeSupergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -11,6 +11,9 @@ pub struct ChatsNearby {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of users nearby
   users_nearby: Vec<ChatNearby>,
   /// List of location-based supergroups nearby
@@ -20,6 +23,7 @@ pub struct ChatsNearby {
 
 impl RObject for ChatsNearby {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "chatsNearby" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDChatsNearbyBuilder {
 impl RTDChatsNearbyBuilder {
   pub fn build(&self) -> ChatsNearby { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
     self.inner.users_nearby = users_nearby;
@@ -18,8 +18,9 @@ pub trait TDCheckChatUsernameResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum CheckChatUsernameResult {
   #[doc(hidden)] _Default(()),
-  /// Checks whether a username can be set for a chat
-  CheckChatUsername(CheckChatUsername),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The username can be set
   Ok(CheckChatUsernameResultOk),
   /// The user has too much chats with username, one of them should be made private first
@@ -42,7 +43,6 @@ impl<'de> Deserialize<'de> for CheckChatUsernameResult {
     use serde::de::Error;
     rtd_enum_deserialize!(
       CheckChatUsernameResult,
-      (checkChatUsername, CheckChatUsername);
       (checkChatUsernameResultOk, Ok);
       (checkChatUsernameResultPublicChatsTooMuch, PublicChatsTooMuch);
       (checkChatUsernameResultPublicGroupsUnavailable, PublicGroupsUnavailable);
@@ -56,7 +56,6 @@ impl<'de> Deserialize<'de> for CheckChatUsernameResult {
 impl RObject for CheckChatUsernameResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      CheckChatUsernameResult::CheckChatUsername(t) => t.td_name(),
       CheckChatUsernameResult::Ok(t) => t.td_name(),
       CheckChatUsernameResult::PublicChatsTooMuch(t) => t.td_name(),
       CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.td_name(),
@@ -66,28 +65,38 @@ impl RObject for CheckChatUsernameResult {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      CheckChatUsernameResult::Ok(t) => t.extra(),
+      CheckChatUsernameResult::PublicChatsTooMuch(t) => t.extra(),
+      CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.extra(),
+      CheckChatUsernameResult::UsernameInvalid(t) => t.extra(),
+      CheckChatUsernameResult::UsernameOccupied(t) => t.extra(),
+
+      CheckChatUsernameResult::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl CheckChatUsernameResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CheckChatUsernameResult::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let CheckChatUsernameResult::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_check_chat_username(&self) -> bool { if let CheckChatUsernameResult::CheckChatUsername(_) = self { true } else { false } }
   pub fn is_ok(&self) -> bool { if let CheckChatUsernameResult::Ok(_) = self { true } else { false } }
   pub fn is_public_chats_too_much(&self) -> bool { if let CheckChatUsernameResult::PublicChatsTooMuch(_) = self { true } else { false } }
   pub fn is_public_groups_unavailable(&self) -> bool { if let CheckChatUsernameResult::PublicGroupsUnavailable(_) = self { true } else { false } }
   pub fn is_username_invalid(&self) -> bool { if let CheckChatUsernameResult::UsernameInvalid(_) = self { true } else { false } }
   pub fn is_username_occupied(&self) -> bool { if let CheckChatUsernameResult::UsernameOccupied(_) = self { true } else { false } }
 
-  pub fn on_check_chat_username<F: FnOnce(&CheckChatUsername)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::CheckChatUsername(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CheckChatUsernameResultOk)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::Ok(t) = self { fnc(t) }; self }
   pub fn on_public_chats_too_much<F: FnOnce(&CheckChatUsernameResultPublicChatsTooMuch)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::PublicChatsTooMuch(t) = self { fnc(t) }; self }
   pub fn on_public_groups_unavailable<F: FnOnce(&CheckChatUsernameResultPublicGroupsUnavailable)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::PublicGroupsUnavailable(t) = self { fnc(t) }; self }
   pub fn on_username_invalid<F: FnOnce(&CheckChatUsernameResultUsernameInvalid)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::UsernameInvalid(t) = self { fnc(t) }; self }
   pub fn on_username_occupied<F: FnOnce(&CheckChatUsernameResultUsernameOccupied)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::UsernameOccupied(t) = self { fnc(t) }; self }
 
-  pub fn as_check_chat_username(&self) -> Option<&CheckChatUsername> { if let CheckChatUsernameResult::CheckChatUsername(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CheckChatUsernameResultOk> { if let CheckChatUsernameResult::Ok(t) = self { return Some(t) } None }
   pub fn as_public_chats_too_much(&self) -> Option<&CheckChatUsernameResultPublicChatsTooMuch> { if let CheckChatUsernameResult::PublicChatsTooMuch(t) = self { return Some(t) } None }
   pub fn as_public_groups_unavailable(&self) -> Option<&CheckChatUsernameResultPublicGroupsUnavailable> { if let CheckChatUsernameResult::PublicGroupsUnavailable(t) = self { return Some(t) } None }
@@ -96,8 +105,6 @@ impl CheckChatUsernameResult {
 
 
 
-  pub fn check_chat_username<T: AsRef<CheckChatUsername>>(t: T) -> Self { CheckChatUsernameResult::CheckChatUsername(t.as_ref().clone()) }
-
   pub fn ok<T: AsRef<CheckChatUsernameResultOk>>(t: T) -> Self { CheckChatUsernameResult::Ok(t.as_ref().clone()) }
 
   pub fn public_chats_too_much<T: AsRef<CheckChatUsernameResultPublicChatsTooMuch>>(t: T) -> Self { CheckChatUsernameResult::PublicChatsTooMuch(t.as_ref().clone()) }
@@ -126,11 +133,15 @@ pub struct CheckChatUsernameResultOk {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CheckChatUsernameResultOk {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultOk" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,6 +168,11 @@ pub struct RTDCheckChatUsernameResultOkBuilder {
 impl RTDCheckChatUsernameResultOkBuilder {
   pub fn build(&self) -> CheckChatUsernameResultOk { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultOk> for CheckChatUsernameResultOk {
@@ -179,11 +195,15 @@ pub struct CheckChatUsernameResultPublicChatsTooMuch {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CheckChatUsernameResultPublicChatsTooMuch {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultPublicChatsTooMuch" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -210,6 +230,11 @@ pub struct RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
 impl RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
   pub fn build(&self) -> CheckChatUsernameResultPublicChatsTooMuch { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultPublicChatsTooMuch> for CheckChatUsernameResultPublicChatsTooMuch {
@@ -232,11 +257,15 @@ pub struct CheckChatUsernameResultPublicGroupsUnavailable {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CheckChatUsernameResultPublicGroupsUnavailable {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultPublicGroupsUnavailable" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,6 +292,11 @@ pub struct RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
 impl RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
   pub fn build(&self) -> CheckChatUsernameResultPublicGroupsUnavailable { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultPublicGroupsUnavailable> for CheckChatUsernameResultPublicGroupsUnavailable {
@@ -285,11 +319,15 @@ pub struct CheckChatUsernameResultUsernameInvalid {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CheckChatUsernameResultUsernameInvalid {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultUsernameInvalid" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -316,6 +354,11 @@ pub struct RTDCheckChatUsernameResultUsernameInvalidBuilder {
 impl RTDCheckChatUsernameResultUsernameInvalidBuilder {
   pub fn build(&self) -> CheckChatUsernameResultUsernameInvalid { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultUsernameInvalid> for CheckChatUsernameResultUsernameInvalid {
@@ -338,11 +381,15 @@ pub struct CheckChatUsernameResultUsernameOccupied {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CheckChatUsernameResultUsernameOccupied {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultUsernameOccupied" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -369,6 +416,11 @@ pub struct RTDCheckChatUsernameResultUsernameOccupiedBuilder {
 impl RTDCheckChatUsernameResultUsernameOccupiedBuilder {
   pub fn build(&self) -> CheckChatUsernameResultUsernameOccupied { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultUsernameOccupied> for CheckChatUsernameResultUsernameOccupied {
@@ -11,8 +11,11 @@ pub struct ConnectedWebsite {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Website identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// The domain name of the website
   domain_name: String,
   /// User identifier of a bot linked with the website
@@ -34,6 +37,7 @@ pub struct ConnectedWebsite {
 
 impl RObject for ConnectedWebsite {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "connectedWebsite" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,7 +51,7 @@ impl ConnectedWebsite {
     RTDConnectedWebsiteBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn domain_name(&self) -> &String { &self.domain_name }
 
@@ -75,8 +79,13 @@ pub struct RTDConnectedWebsiteBuilder {
 impl RTDConnectedWebsiteBuilder {
   pub fn build(&self) -> ConnectedWebsite { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -18,6 +18,9 @@ pub trait TDConnectionState: Debug + RObject {}
 #[serde(untagged)]
 pub enum ConnectionState {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Currently establishing a connection to the Telegram servers
   Connecting(ConnectionStateConnecting),
   /// Currently establishing a connection with a proxy server
@@ -62,12 +65,25 @@ impl RObject for ConnectionState {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ConnectionState::Connecting(t) => t.extra(),
+      ConnectionState::ConnectingToProxy(t) => t.extra(),
+      ConnectionState::Ready(t) => t.extra(),
+      ConnectionState::Updating(t) => t.extra(),
+      ConnectionState::WaitingForNetwork(t) => t.extra(),
+
+      ConnectionState::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ConnectionState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ConnectionState::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ConnectionState::Unknown { .. } = self { true } else { false } }
 
   pub fn is_connecting(&self) -> bool { if let ConnectionState::Connecting(_) = self { true } else { false } }
   pub fn is_connecting_to_proxy(&self) -> bool { if let ConnectionState::ConnectingToProxy(_) = self { true } else { false } }
@@ -117,11 +133,15 @@ pub struct ConnectionStateConnecting {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ConnectionStateConnecting {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateConnecting" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -148,6 +168,11 @@ pub struct RTDConnectionStateConnectingBuilder {
 impl RTDConnectionStateConnectingBuilder {
   pub fn build(&self) -> ConnectionStateConnecting { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateConnecting> for ConnectionStateConnecting {
@@ -170,11 +195,15 @@ pub struct ConnectionStateConnectingToProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ConnectionStateConnectingToProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateConnectingToProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -201,6 +230,11 @@ pub struct RTDConnectionStateConnectingToProxyBuilder {
 impl RTDConnectionStateConnectingToProxyBuilder {
   pub fn build(&self) -> ConnectionStateConnectingToProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateConnectingToProxy> for ConnectionStateConnectingToProxy {
@@ -223,11 +257,15 @@ pub struct ConnectionStateReady {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ConnectionStateReady {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateReady" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -254,6 +292,11 @@ pub struct RTDConnectionStateReadyBuilder {
 impl RTDConnectionStateReadyBuilder {
   pub fn build(&self) -> ConnectionStateReady { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateReady> for ConnectionStateReady {
@@ -276,11 +319,15 @@ pub struct ConnectionStateUpdating {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ConnectionStateUpdating {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateUpdating" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -307,6 +354,11 @@ pub struct RTDConnectionStateUpdatingBuilder {
 impl RTDConnectionStateUpdatingBuilder {
   pub fn build(&self) -> ConnectionStateUpdating { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateUpdating> for ConnectionStateUpdating {
@@ -329,11 +381,15 @@ pub struct ConnectionStateWaitingForNetwork {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ConnectionStateWaitingForNetwork {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateWaitingForNetwork" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -360,6 +416,11 @@ pub struct RTDConnectionStateWaitingForNetworkBuilder {
 impl RTDConnectionStateWaitingForNetworkBuilder {
   pub fn build(&self) -> ConnectionStateWaitingForNetwork { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateWaitingForNetwork> for ConnectionStateWaitingForNetwork {
@@ -11,6 +11,9 @@ pub struct Count {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Count
   count: i64,
   
@@ -18,6 +21,7 @@ pub struct Count {
 
 impl RObject for Count {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "count" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDCountBuilder {
 impl RTDCountBuilder {
   pub fn build(&self) -> Count { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
@@ -11,6 +11,9 @@ pub struct CustomRequestResult {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A JSON-serialized result
   result: String,
   
@@ -18,6 +21,7 @@ pub struct CustomRequestResult {
 
 impl RObject for CustomRequestResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "customRequestResult" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDCustomRequestResultBuilder {
 impl RTDCustomRequestResultBuilder {
   pub fn build(&self) -> CustomRequestResult { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn result<T: AsRef<str>>(&mut self, result: T) -> &mut Self {
     self.inner.result = result.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct DatabaseStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Database statistics in an unspecified human-readable format
   statistics: String,
   
@@ -18,6 +21,7 @@ pub struct DatabaseStatistics {
 
 impl RObject for DatabaseStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "databaseStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDDatabaseStatisticsBuilder {
 impl RTDDatabaseStatisticsBuilder {
   pub fn build(&self) -> DatabaseStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn statistics<T: AsRef<str>>(&mut self, statistics: T) -> &mut Self {
     self.inner.statistics = statistics.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct Date {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Day of the month, 1-31
   day: i64,
   /// Month, 1-12
@@ -22,6 +25,7 @@ pub struct Date {
 
 impl RObject for Date {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "date" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDDateBuilder {
 impl RTDDateBuilder {
   pub fn build(&self) -> Date { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn day(&mut self, day: i64) -> &mut Self {
     self.inner.day = day;
@@ -11,6 +11,9 @@ pub struct DateRange {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Point in time (Unix timestamp) at which the date range begins
   start_date: i64,
   /// Point in time (Unix timestamp) at which the date range ends
@@ -20,6 +23,7 @@ pub struct DateRange {
 
 impl RObject for DateRange {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "dateRange" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDDateRangeBuilder {
 impl RTDDateRangeBuilder {
   pub fn build(&self) -> DateRange { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn start_date(&mut self, start_date: i64) -> &mut Self {
     self.inner.start_date = start_date;
@@ -11,6 +11,9 @@ pub struct DatedFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The file
   file: File,
   /// Point in time (Unix timestamp) when the file was uploaded
@@ -20,6 +23,7 @@ pub struct DatedFile {
 
 impl RObject for DatedFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "datedFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDDatedFileBuilder {
 impl RTDDatedFileBuilder {
   pub fn build(&self) -> DatedFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
@@ -18,6 +18,9 @@ pub trait TDDeviceToken: Debug + RObject {}
 #[serde(untagged)]
 pub enum DeviceToken {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A token for Apple Push Notification service
   ApplePush(DeviceTokenApplePush),
   /// A token for Apple Push Notification service VoIP notifications
@@ -86,12 +89,31 @@ impl RObject for DeviceToken {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      DeviceToken::ApplePush(t) => t.extra(),
+      DeviceToken::ApplePushVoIP(t) => t.extra(),
+      DeviceToken::BlackBerryPush(t) => t.extra(),
+      DeviceToken::FirebaseCloudMessaging(t) => t.extra(),
+      DeviceToken::MicrosoftPush(t) => t.extra(),
+      DeviceToken::MicrosoftPushVoIP(t) => t.extra(),
+      DeviceToken::SimplePush(t) => t.extra(),
+      DeviceToken::TizenPush(t) => t.extra(),
+      DeviceToken::UbuntuPush(t) => t.extra(),
+      DeviceToken::WebPush(t) => t.extra(),
+      DeviceToken::WindowsPush(t) => t.extra(),
+
+      DeviceToken::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl DeviceToken {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let DeviceToken::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let DeviceToken::Unknown { .. } = self { true } else { false } }
 
   pub fn is_apple_push(&self) -> bool { if let DeviceToken::ApplePush(_) = self { true } else { false } }
   pub fn is_apple_push_vo_i_p(&self) -> bool { if let DeviceToken::ApplePushVoIP(_) = self { true } else { false } }
@@ -171,6 +193,9 @@ pub struct DeviceTokenApplePush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Device token; may be empty to de-register a device
   device_token: String,
   /// True, if App Sandbox is enabled
@@ -180,6 +205,7 @@ pub struct DeviceTokenApplePush {
 
 impl RObject for DeviceTokenApplePush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenApplePush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -210,6 +236,11 @@ pub struct RTDDeviceTokenApplePushBuilder {
 impl RTDDeviceTokenApplePushBuilder {
   pub fn build(&self) -> DeviceTokenApplePush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().to_string();
@@ -244,6 +275,9 @@ pub struct DeviceTokenApplePushVoIP {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Device token; may be empty to de-register a device
   device_token: String,
   /// True, if App Sandbox is enabled
@@ -255,6 +289,7 @@ pub struct DeviceTokenApplePushVoIP {
 
 impl RObject for DeviceTokenApplePushVoIP {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenApplePushVoIP" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -287,6 +322,11 @@ pub struct RTDDeviceTokenApplePushVoIPBuilder {
 impl RTDDeviceTokenApplePushVoIPBuilder {
   pub fn build(&self) -> DeviceTokenApplePushVoIP { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().to_string();
@@ -327,6 +367,9 @@ pub struct DeviceTokenBlackBerryPush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Token; may be empty to de-register a device
   token: String,
   
@@ -334,6 +377,7 @@ pub struct DeviceTokenBlackBerryPush {
 
 impl RObject for DeviceTokenBlackBerryPush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenBlackBerryPush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -362,6 +406,11 @@ pub struct RTDDeviceTokenBlackBerryPushBuilder {
 impl RTDDeviceTokenBlackBerryPushBuilder {
   pub fn build(&self) -> DeviceTokenBlackBerryPush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -390,6 +439,9 @@ pub struct DeviceTokenFirebaseCloudMessaging {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Device registration token; may be empty to de-register a device
   token: String,
   /// True, if push notifications should be additionally encrypted
@@ -399,6 +451,7 @@ pub struct DeviceTokenFirebaseCloudMessaging {
 
 impl RObject for DeviceTokenFirebaseCloudMessaging {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenFirebaseCloudMessaging" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -429,6 +482,11 @@ pub struct RTDDeviceTokenFirebaseCloudMessagingBuilder {
 impl RTDDeviceTokenFirebaseCloudMessagingBuilder {
   pub fn build(&self) -> DeviceTokenFirebaseCloudMessaging { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -463,6 +521,9 @@ pub struct DeviceTokenMicrosoftPush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Push notification channel URI; may be empty to de-register a device
   channel_uri: String,
   
@@ -470,6 +531,7 @@ pub struct DeviceTokenMicrosoftPush {
 
 impl RObject for DeviceTokenMicrosoftPush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenMicrosoftPush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -498,6 +560,11 @@ pub struct RTDDeviceTokenMicrosoftPushBuilder {
 impl RTDDeviceTokenMicrosoftPushBuilder {
   pub fn build(&self) -> DeviceTokenMicrosoftPush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
     self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -526,6 +593,9 @@ pub struct DeviceTokenMicrosoftPushVoIP {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Push notification channel URI; may be empty to de-register a device
   channel_uri: String,
   
@@ -533,6 +603,7 @@ pub struct DeviceTokenMicrosoftPushVoIP {
 
 impl RObject for DeviceTokenMicrosoftPushVoIP {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenMicrosoftPushVoIP" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -561,6 +632,11 @@ pub struct RTDDeviceTokenMicrosoftPushVoIPBuilder {
 impl RTDDeviceTokenMicrosoftPushVoIPBuilder {
   pub fn build(&self) -> DeviceTokenMicrosoftPushVoIP { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
     self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -589,6 +665,9 @@ pub struct DeviceTokenSimplePush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device
   endpoint: String,
   
@@ -596,6 +675,7 @@ pub struct DeviceTokenSimplePush {
 
 impl RObject for DeviceTokenSimplePush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenSimplePush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -624,6 +704,11 @@ pub struct RTDDeviceTokenSimplePushBuilder {
 impl RTDDeviceTokenSimplePushBuilder {
   pub fn build(&self) -> DeviceTokenSimplePush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
     self.inner.endpoint = endpoint.as_ref().to_string();
@@ -652,6 +737,9 @@ pub struct DeviceTokenTizenPush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Push service registration identifier; may be empty to de-register a device
   reg_id: String,
   
@@ -659,6 +747,7 @@ pub struct DeviceTokenTizenPush {
 
 impl RObject for DeviceTokenTizenPush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenTizenPush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -687,6 +776,11 @@ pub struct RTDDeviceTokenTizenPushBuilder {
 impl RTDDeviceTokenTizenPushBuilder {
   pub fn build(&self) -> DeviceTokenTizenPush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reg_id<T: AsRef<str>>(&mut self, reg_id: T) -> &mut Self {
     self.inner.reg_id = reg_id.as_ref().to_string();
@@ -715,6 +809,9 @@ pub struct DeviceTokenUbuntuPush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Token; may be empty to de-register a device
   token: String,
   
@@ -722,6 +819,7 @@ pub struct DeviceTokenUbuntuPush {
 
 impl RObject for DeviceTokenUbuntuPush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenUbuntuPush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -750,6 +848,11 @@ pub struct RTDDeviceTokenUbuntuPushBuilder {
 impl RTDDeviceTokenUbuntuPushBuilder {
   pub fn build(&self) -> DeviceTokenUbuntuPush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -778,6 +881,9 @@ pub struct DeviceTokenWebPush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device
   endpoint: String,
   /// Base64url-encoded P-256 elliptic curve Diffie-Hellman public key
@@ -789,6 +895,7 @@ pub struct DeviceTokenWebPush {
 
 impl RObject for DeviceTokenWebPush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenWebPush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -821,6 +928,11 @@ pub struct RTDDeviceTokenWebPushBuilder {
 impl RTDDeviceTokenWebPushBuilder {
   pub fn build(&self) -> DeviceTokenWebPush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
     self.inner.endpoint = endpoint.as_ref().to_string();
@@ -861,6 +973,9 @@ pub struct DeviceTokenWindowsPush {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The access token that will be used to send notifications; may be empty to de-register a device
   access_token: String,
   
@@ -868,6 +983,7 @@ pub struct DeviceTokenWindowsPush {
 
 impl RObject for DeviceTokenWindowsPush {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenWindowsPush" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -896,6 +1012,11 @@ pub struct RTDDeviceTokenWindowsPushBuilder {
 impl RTDDeviceTokenWindowsPushBuilder {
   pub fn build(&self) -> DeviceTokenWindowsPush { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn access_token<T: AsRef<str>>(&mut self, access_token: T) -> &mut Self {
     self.inner.access_token = access_token.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct DraftMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the message to reply to; 0 if none
   reply_to_message_id: i64,
   /// Point in time (Unix timestamp) when the draft was created
@@ -22,6 +25,7 @@ pub struct DraftMessage {
 
 impl RObject for DraftMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "draftMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDDraftMessageBuilder {
 impl RTDDraftMessageBuilder {
   pub fn build(&self) -> DraftMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
     self.inner.reply_to_message_id = reply_to_message_id;
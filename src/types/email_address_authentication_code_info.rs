@@ -11,6 +11,9 @@ pub struct EmailAddressAuthenticationCodeInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pattern of the email address to which an authentication code was sent
   email_address_pattern: String,
   /// Length of the code; 0 if unknown
@@ -20,6 +23,7 @@ pub struct EmailAddressAuthenticationCodeInfo {
 
 impl RObject for EmailAddressAuthenticationCodeInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "emailAddressAuthenticationCodeInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDEmailAddressAuthenticationCodeInfoBuilder {
 impl RTDEmailAddressAuthenticationCodeInfoBuilder {
   pub fn build(&self) -> EmailAddressAuthenticationCodeInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address_pattern<T: AsRef<str>>(&mut self, email_address_pattern: T) -> &mut Self {
     self.inner.email_address_pattern = email_address_pattern.as_ref().to_string();
@@ -11,17 +11,21 @@ pub struct EncryptedCredentials {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The encrypted credentials
-  data: String,
+  data: TdBytes,
   /// The decrypted data hash
-  hash: String,
+  hash: TdBytes,
   /// Secret for data decryption, encrypted with the service's public key
-  secret: String,
+  secret: TdBytes,
   
 }
 
 impl RObject for EncryptedCredentials {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "encryptedCredentials" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -35,11 +39,11 @@ impl EncryptedCredentials {
     RTDEncryptedCredentialsBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
-  pub fn hash(&self) -> &String { &self.hash }
+  pub fn hash(&self) -> &TdBytes { &self.hash }
 
-  pub fn secret(&self) -> &String { &self.secret }
+  pub fn secret(&self) -> &TdBytes { &self.secret }
 
 }
 
@@ -51,21 +55,26 @@ pub struct RTDEncryptedCredentialsBuilder {
 impl RTDEncryptedCredentialsBuilder {
   pub fn build(&self) -> EncryptedCredentials { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
    
-  pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
-    self.inner.hash = hash.as_ref().to_string();
+  pub fn hash<T: AsRef<[u8]>>(&mut self, hash: T) -> &mut Self {
+    self.inner.hash = TdBytes::from(hash.as_ref());
     self
   }
 
    
-  pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
-    self.inner.secret = secret.as_ref().to_string();
+  pub fn secret<T: AsRef<[u8]>>(&mut self, secret: T) -> &mut Self {
+    self.inner.secret = TdBytes::from(secret.as_ref());
     self
   }
 
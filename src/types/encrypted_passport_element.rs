@@ -11,10 +11,13 @@ pub struct EncryptedPassportElement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of Telegram Passport element
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// Encrypted JSON-encoded data about the user
-  data: String,
+  data: TdBytes,
   /// The front side of an identity document
   front_side: DatedFile,
   /// The reverse side of an identity document; may be null
@@ -34,6 +37,7 @@ pub struct EncryptedPassportElement {
 
 impl RObject for EncryptedPassportElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "encryptedPassportElement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -49,7 +53,7 @@ impl EncryptedPassportElement {
 
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
   pub fn front_side(&self) -> &DatedFile { &self.front_side }
 
@@ -75,6 +79,11 @@ pub struct RTDEncryptedPassportElementBuilder {
 impl RTDEncryptedPassportElementBuilder {
   pub fn build(&self) -> EncryptedPassportElement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -82,8 +91,8 @@ impl RTDEncryptedPassportElementBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -11,6 +11,9 @@ pub struct Error {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user
   code: i64,
   /// Error message; subject to future changes
@@ -20,6 +23,7 @@ pub struct Error {
 
 impl RObject for Error {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "error" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDErrorBuilder {
 impl RTDErrorBuilder {
   pub fn build(&self) -> Error { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code(&mut self, code: i64) -> &mut Self {
     self.inner.code = code;
@@ -11,13 +11,17 @@ pub struct FilePart {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// File bytes
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for FilePart {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "filePart" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -31,7 +35,7 @@ impl FilePart {
     RTDFilePartBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -43,9 +47,14 @@ pub struct RTDFilePartBuilder {
 impl RTDFilePartBuilder {
   pub fn build(&self) -> FilePart { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -18,6 +18,9 @@ pub trait TDFileType: Debug + RObject {}
 #[serde(untagged)]
 pub enum FileType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  UnknownType { td_type: String, raw: serde_json::Value },
   /// The file is an animation
   Animation(FileTypeAnimation),
   /// The file is an audio file
@@ -61,6 +64,7 @@ impl<'de> Deserialize<'de> for FileType {
   fn deserialize<D>(deserializer: D) -> Result<FileType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
+      @unknown UnknownType,
       FileType,
       (fileTypeAnimation, Animation);
       (fileTypeAudio, Audio);
@@ -106,12 +110,36 @@ impl RObject for FileType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      FileType::Animation(t) => t.extra(),
+      FileType::Audio(t) => t.extra(),
+      FileType::Document(t) => t.extra(),
+      FileType::None(t) => t.extra(),
+      FileType::Photo(t) => t.extra(),
+      FileType::ProfilePhoto(t) => t.extra(),
+      FileType::Secret(t) => t.extra(),
+      FileType::SecretThumbnail(t) => t.extra(),
+      FileType::Secure(t) => t.extra(),
+      FileType::Sticker(t) => t.extra(),
+      FileType::Thumbnail(t) => t.extra(),
+      FileType::Unknown(t) => t.extra(),
+      FileType::Video(t) => t.extra(),
+      FileType::VideoNote(t) => t.extra(),
+      FileType::VoiceNote(t) => t.extra(),
+      FileType::Wallpaper(t) => t.extra(),
+
+      FileType::UnknownType { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl FileType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let FileType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown_type(&self) -> bool { if let FileType::UnknownType { .. } = self { true } else { false } }
 
   pub fn is_animation(&self) -> bool { if let FileType::Animation(_) = self { true } else { false } }
   pub fn is_audio(&self) -> bool { if let FileType::Audio(_) = self { true } else { false } }
@@ -216,11 +244,15 @@ pub struct FileTypeAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -247,6 +279,11 @@ pub struct RTDFileTypeAnimationBuilder {
 impl RTDFileTypeAnimationBuilder {
   pub fn build(&self) -> FileTypeAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeAnimation> for FileTypeAnimation {
@@ -269,11 +306,15 @@ pub struct FileTypeAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -300,6 +341,11 @@ pub struct RTDFileTypeAudioBuilder {
 impl RTDFileTypeAudioBuilder {
   pub fn build(&self) -> FileTypeAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeAudio> for FileTypeAudio {
@@ -322,11 +368,15 @@ pub struct FileTypeDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -353,6 +403,11 @@ pub struct RTDFileTypeDocumentBuilder {
 impl RTDFileTypeDocumentBuilder {
   pub fn build(&self) -> FileTypeDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeDocument> for FileTypeDocument {
@@ -375,11 +430,15 @@ pub struct FileTypeNone {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeNone {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeNone" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -406,6 +465,11 @@ pub struct RTDFileTypeNoneBuilder {
 impl RTDFileTypeNoneBuilder {
   pub fn build(&self) -> FileTypeNone { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeNone> for FileTypeNone {
@@ -428,11 +492,15 @@ pub struct FileTypePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -459,6 +527,11 @@ pub struct RTDFileTypePhotoBuilder {
 impl RTDFileTypePhotoBuilder {
   pub fn build(&self) -> FileTypePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypePhoto> for FileTypePhoto {
@@ -481,11 +554,15 @@ pub struct FileTypeProfilePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeProfilePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeProfilePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -512,6 +589,11 @@ pub struct RTDFileTypeProfilePhotoBuilder {
 impl RTDFileTypeProfilePhotoBuilder {
   pub fn build(&self) -> FileTypeProfilePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeProfilePhoto> for FileTypeProfilePhoto {
@@ -534,11 +616,15 @@ pub struct FileTypeSecret {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeSecret {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSecret" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -565,6 +651,11 @@ pub struct RTDFileTypeSecretBuilder {
 impl RTDFileTypeSecretBuilder {
   pub fn build(&self) -> FileTypeSecret { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSecret> for FileTypeSecret {
@@ -587,11 +678,15 @@ pub struct FileTypeSecretThumbnail {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeSecretThumbnail {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSecretThumbnail" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -618,6 +713,11 @@ pub struct RTDFileTypeSecretThumbnailBuilder {
 impl RTDFileTypeSecretThumbnailBuilder {
   pub fn build(&self) -> FileTypeSecretThumbnail { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSecretThumbnail> for FileTypeSecretThumbnail {
@@ -640,11 +740,15 @@ pub struct FileTypeSecure {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeSecure {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSecure" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -671,6 +775,11 @@ pub struct RTDFileTypeSecureBuilder {
 impl RTDFileTypeSecureBuilder {
   pub fn build(&self) -> FileTypeSecure { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSecure> for FileTypeSecure {
@@ -693,11 +802,15 @@ pub struct FileTypeSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -724,6 +837,11 @@ pub struct RTDFileTypeStickerBuilder {
 impl RTDFileTypeStickerBuilder {
   pub fn build(&self) -> FileTypeSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSticker> for FileTypeSticker {
@@ -746,11 +864,15 @@ pub struct FileTypeThumbnail {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeThumbnail {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeThumbnail" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -777,6 +899,11 @@ pub struct RTDFileTypeThumbnailBuilder {
 impl RTDFileTypeThumbnailBuilder {
   pub fn build(&self) -> FileTypeThumbnail { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeThumbnail> for FileTypeThumbnail {
@@ -799,11 +926,15 @@ pub struct FileTypeUnknown {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeUnknown {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeUnknown" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -830,6 +961,11 @@ pub struct RTDFileTypeUnknownBuilder {
 impl RTDFileTypeUnknownBuilder {
   pub fn build(&self) -> FileTypeUnknown { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeUnknown> for FileTypeUnknown {
@@ -852,11 +988,15 @@ pub struct FileTypeVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -883,6 +1023,11 @@ pub struct RTDFileTypeVideoBuilder {
 impl RTDFileTypeVideoBuilder {
   pub fn build(&self) -> FileTypeVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeVideo> for FileTypeVideo {
@@ -905,11 +1050,15 @@ pub struct FileTypeVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -936,6 +1085,11 @@ pub struct RTDFileTypeVideoNoteBuilder {
 impl RTDFileTypeVideoNoteBuilder {
   pub fn build(&self) -> FileTypeVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeVideoNote> for FileTypeVideoNote {
@@ -958,11 +1112,15 @@ pub struct FileTypeVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -989,6 +1147,11 @@ pub struct RTDFileTypeVoiceNoteBuilder {
 impl RTDFileTypeVoiceNoteBuilder {
   pub fn build(&self) -> FileTypeVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeVoiceNote> for FileTypeVoiceNote {
@@ -1011,11 +1174,15 @@ pub struct FileTypeWallpaper {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for FileTypeWallpaper {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeWallpaper" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1042,6 +1209,11 @@ pub struct RTDFileTypeWallpaperBuilder {
 impl RTDFileTypeWallpaperBuilder {
   pub fn build(&self) -> FileTypeWallpaper { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeWallpaper> for FileTypeWallpaper {
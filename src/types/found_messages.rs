@@ -11,15 +11,19 @@ pub struct FoundMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of messages
   messages: Vec<Message>,
   /// Value to pass as from_search_id to get more results
-  next_from_search_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] next_from_search_id: i64,
   
 }
 
 impl RObject for FoundMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "foundMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -35,7 +39,7 @@ impl FoundMessages {
 
   pub fn messages(&self) -> &Vec<Message> { &self.messages }
 
-  pub fn next_from_search_id(&self) -> isize { self.next_from_search_id }
+  pub fn next_from_search_id(&self) -> i64 { self.next_from_search_id }
 
 }
 
@@ -47,6 +51,11 @@ pub struct RTDFoundMessagesBuilder {
 impl RTDFoundMessagesBuilder {
   pub fn build(&self) -> FoundMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn messages(&mut self, messages: Vec<Message>) -> &mut Self {
     self.inner.messages = messages;
@@ -54,7 +63,7 @@ impl RTDFoundMessagesBuilder {
   }
 
    
-  pub fn next_from_search_id(&mut self, next_from_search_id: isize) -> &mut Self {
+  pub fn next_from_search_id(&mut self, next_from_search_id: i64) -> &mut Self {
     self.inner.next_from_search_id = next_from_search_id;
     self
   }
@@ -11,6 +11,9 @@ pub struct AcceptCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   call_id: i64,
   /// Description of the call protocols supported by the application
@@ -20,13 +23,16 @@ pub struct AcceptCall {
 
 impl RObject for AcceptCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "acceptCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AcceptCall {}
+impl RFunction for AcceptCall {
+  type Response = Ok;
+}
 
 impl AcceptCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -50,6 +56,11 @@ pub struct RTDAcceptCallBuilder {
 impl RTDAcceptCallBuilder {
   pub fn build(&self) -> AcceptCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -84,6 +95,9 @@ pub struct AcceptTermsOfService {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Terms of service identifier
   terms_of_service_id: String,
   
@@ -91,13 +105,16 @@ pub struct AcceptTermsOfService {
 
 impl RObject for AcceptTermsOfService {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "acceptTermsOfService" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AcceptTermsOfService {}
+impl RFunction for AcceptTermsOfService {
+  type Response = Ok;
+}
 
 impl AcceptTermsOfService {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -119,6 +136,11 @@ pub struct RTDAcceptTermsOfServiceBuilder {
 impl RTDAcceptTermsOfServiceBuilder {
   pub fn build(&self) -> AcceptTermsOfService { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn terms_of_service_id<T: AsRef<str>>(&mut self, terms_of_service_id: T) -> &mut Self {
     self.inner.terms_of_service_id = terms_of_service_id.as_ref().to_string();
@@ -147,6 +169,9 @@ pub struct AddChatMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of the user
@@ -158,13 +183,16 @@ pub struct AddChatMember {
 
 impl RObject for AddChatMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addChatMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddChatMember {}
+impl RFunction for AddChatMember {
+  type Response = Ok;
+}
 
 impl AddChatMember {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -190,6 +218,11 @@ pub struct RTDAddChatMemberBuilder {
 impl RTDAddChatMemberBuilder {
   pub fn build(&self) -> AddChatMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -230,6 +263,9 @@ pub struct AddChatMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifiers of the users to be added to the chat
@@ -239,13 +275,16 @@ pub struct AddChatMembers {
 
 impl RObject for AddChatMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addChatMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddChatMembers {}
+impl RFunction for AddChatMembers {
+  type Response = Ok;
+}
 
 impl AddChatMembers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -269,6 +308,11 @@ pub struct RTDAddChatMembersBuilder {
 impl RTDAddChatMembersBuilder {
   pub fn build(&self) -> AddChatMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -303,6 +347,9 @@ pub struct AddChatToList {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The chat list. Use getChatListsToAddChat to get suitable chat lists
@@ -312,13 +359,16 @@ pub struct AddChatToList {
 
 impl RObject for AddChatToList {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addChatToList" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddChatToList {}
+impl RFunction for AddChatToList {
+  type Response = Ok;
+}
 
 impl AddChatToList {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -342,6 +392,11 @@ pub struct RTDAddChatToListBuilder {
 impl RTDAddChatToListBuilder {
   pub fn build(&self) -> AddChatToList { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -376,6 +431,9 @@ pub struct AddContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The contact to add or edit; phone number can be empty and needs to be specified only if known, vCard is ignored
   contact: Contact,
   /// True, if the new contact needs to be allowed to see current user's phone number. A corresponding rule to userPrivacySettingShowPhoneNumber will be added if needed. Use the field UserFullInfo.need_phone_number_privacy_exception to check whether the current user needs to be asked to share their phone number
@@ -385,13 +443,16 @@ pub struct AddContact {
 
 impl RObject for AddContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddContact {}
+impl RFunction for AddContact {
+  type Response = Ok;
+}
 
 impl AddContact {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -415,6 +476,11 @@ pub struct RTDAddContactBuilder {
 impl RTDAddContactBuilder {
   pub fn build(&self) -> AddContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
@@ -449,6 +515,9 @@ pub struct AddCustomServerLanguagePack {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of a language pack to be added; may be different from a name that is used in an "https://t.me/setlanguage/" link
   language_pack_id: String,
   
@@ -456,13 +525,16 @@ pub struct AddCustomServerLanguagePack {
 
 impl RObject for AddCustomServerLanguagePack {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addCustomServerLanguagePack" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddCustomServerLanguagePack {}
+impl RFunction for AddCustomServerLanguagePack {
+  type Response = Ok;
+}
 
 impl AddCustomServerLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -484,6 +556,11 @@ pub struct RTDAddCustomServerLanguagePackBuilder {
 impl RTDAddCustomServerLanguagePackBuilder {
   pub fn build(&self) -> AddCustomServerLanguagePack { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -512,6 +589,9 @@ pub struct AddFavoriteSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker file to add
   sticker: InputFile,
   
@@ -519,13 +599,16 @@ pub struct AddFavoriteSticker {
 
 impl RObject for AddFavoriteSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addFavoriteSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddFavoriteSticker {}
+impl RFunction for AddFavoriteSticker {
+  type Response = Ok;
+}
 
 impl AddFavoriteSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -547,6 +630,11 @@ pub struct RTDAddFavoriteStickerBuilder {
 impl RTDAddFavoriteStickerBuilder {
   pub fn build(&self) -> AddFavoriteSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -575,6 +663,9 @@ pub struct AddLocalMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Target chat
   chat_id: i64,
   /// Identifier of the user who will be shown as the sender of the message; may be 0 for channel posts
@@ -590,13 +681,16 @@ pub struct AddLocalMessage {
 
 impl RObject for AddLocalMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addLocalMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddLocalMessage {}
+impl RFunction for AddLocalMessage {
+  type Response = Message;
+}
 
 impl AddLocalMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -626,6 +720,11 @@ pub struct RTDAddLocalMessageBuilder {
 impl RTDAddLocalMessageBuilder {
   pub fn build(&self) -> AddLocalMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -678,6 +777,9 @@ pub struct AddLogMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The minimum verbosity level needed for the message to be logged, 0-1023
   verbosity_level: i64,
   /// Text of a message to log
@@ -687,13 +789,16 @@ pub struct AddLogMessage {
 
 impl RObject for AddLogMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addLogMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddLogMessage {}
+impl RFunction for AddLogMessage {
+  type Response = Ok;
+}
 
 impl AddLogMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -717,6 +822,11 @@ pub struct RTDAddLogMessageBuilder {
 impl RTDAddLogMessageBuilder {
   pub fn build(&self) -> AddLogMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn verbosity_level(&mut self, verbosity_level: i64) -> &mut Self {
     self.inner.verbosity_level = verbosity_level;
@@ -751,6 +861,9 @@ pub struct AddNetworkStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The network statistics entry with the data to be added to statistics
   entry: NetworkStatisticsEntry,
   
@@ -758,13 +871,16 @@ pub struct AddNetworkStatistics {
 
 impl RObject for AddNetworkStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addNetworkStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddNetworkStatistics {}
+impl RFunction for AddNetworkStatistics {
+  type Response = Ok;
+}
 
 impl AddNetworkStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -786,6 +902,11 @@ pub struct RTDAddNetworkStatisticsBuilder {
 impl RTDAddNetworkStatisticsBuilder {
   pub fn build(&self) -> AddNetworkStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn entry<T: AsRef<NetworkStatisticsEntry>>(&mut self, entry: T) -> &mut Self {
     self.inner.entry = entry.as_ref().clone();
@@ -814,6 +935,9 @@ pub struct AddProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy server IP address
   server: String,
   /// Proxy server port
@@ -827,13 +951,16 @@ pub struct AddProxy {
 
 impl RObject for AddProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddProxy {}
+impl RFunction for AddProxy {
+  type Response = Proxy;
+}
 
 impl AddProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -861,6 +988,11 @@ pub struct RTDAddProxyBuilder {
 impl RTDAddProxyBuilder {
   pub fn build(&self) -> AddProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
@@ -907,6 +1039,9 @@ pub struct AddRecentSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to add the sticker to the list of stickers recently attached to photo or video files; pass false to add the sticker to the list of recently sent stickers
   is_attached: bool,
   /// Sticker file to add
@@ -916,13 +1051,16 @@ pub struct AddRecentSticker {
 
 impl RObject for AddRecentSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addRecentSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddRecentSticker {}
+impl RFunction for AddRecentSticker {
+  type Response = Stickers;
+}
 
 impl AddRecentSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -946,6 +1084,11 @@ pub struct RTDAddRecentStickerBuilder {
 impl RTDAddRecentStickerBuilder {
   pub fn build(&self) -> AddRecentSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -980,6 +1123,9 @@ pub struct AddRecentlyFoundChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to add
   chat_id: i64,
   
@@ -987,13 +1133,16 @@ pub struct AddRecentlyFoundChat {
 
 impl RObject for AddRecentlyFoundChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addRecentlyFoundChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddRecentlyFoundChat {}
+impl RFunction for AddRecentlyFoundChat {
+  type Response = Ok;
+}
 
 impl AddRecentlyFoundChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1015,6 +1164,11 @@ pub struct RTDAddRecentlyFoundChatBuilder {
 impl RTDAddRecentlyFoundChatBuilder {
   pub fn build(&self) -> AddRecentlyFoundChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1043,6 +1197,9 @@ pub struct AddSavedAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The animation file to be added. Only animations known to the server (i.e. successfully sent via a message) can be added to the list
   animation: InputFile,
   
@@ -1050,13 +1207,16 @@ pub struct AddSavedAnimation {
 
 impl RObject for AddSavedAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addSavedAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddSavedAnimation {}
+impl RFunction for AddSavedAnimation {
+  type Response = Ok;
+}
 
 impl AddSavedAnimation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1078,6 +1238,11 @@ pub struct RTDAddSavedAnimationBuilder {
 impl RTDAddSavedAnimationBuilder {
   pub fn build(&self) -> AddSavedAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -1106,6 +1271,9 @@ pub struct AddStickerToSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker set owner
   user_id: i64,
   /// Sticker set name
@@ -1117,13 +1285,16 @@ pub struct AddStickerToSet {
 
 impl RObject for AddStickerToSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "addStickerToSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AddStickerToSet {}
+impl RFunction for AddStickerToSet {
+  type Response = StickerSet;
+}
 
 impl AddStickerToSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1149,6 +1320,11 @@ pub struct RTDAddStickerToSetBuilder {
 impl RTDAddStickerToSetBuilder {
   pub fn build(&self) -> AddStickerToSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -1189,8 +1365,11 @@ pub struct AnswerCallbackQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the callback query
-  callback_query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] callback_query_id: i64,
   /// Text of the answer
   text: String,
   /// If true, an alert should be shown to the user instead of a toast notification
@@ -1204,13 +1383,16 @@ pub struct AnswerCallbackQuery {
 
 impl RObject for AnswerCallbackQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "answerCallbackQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AnswerCallbackQuery {}
+impl RFunction for AnswerCallbackQuery {
+  type Response = Ok;
+}
 
 impl AnswerCallbackQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1220,7 +1402,7 @@ impl AnswerCallbackQuery {
     RTDAnswerCallbackQueryBuilder { inner }
   }
 
-  pub fn callback_query_id(&self) -> isize { self.callback_query_id }
+  pub fn callback_query_id(&self) -> i64 { self.callback_query_id }
 
   pub fn text(&self) -> &String { &self.text }
 
@@ -1240,8 +1422,13 @@ pub struct RTDAnswerCallbackQueryBuilder {
 impl RTDAnswerCallbackQueryBuilder {
   pub fn build(&self) -> AnswerCallbackQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn callback_query_id(&mut self, callback_query_id: isize) -> &mut Self {
+  pub fn callback_query_id(&mut self, callback_query_id: i64) -> &mut Self {
     self.inner.callback_query_id = callback_query_id;
     self
   }
@@ -1292,8 +1479,11 @@ pub struct AnswerCustomQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of a custom query
-  custom_query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] custom_query_id: i64,
   /// JSON-serialized answer to the query
   data: String,
   
@@ -1301,13 +1491,16 @@ pub struct AnswerCustomQuery {
 
 impl RObject for AnswerCustomQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "answerCustomQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AnswerCustomQuery {}
+impl RFunction for AnswerCustomQuery {
+  type Response = Ok;
+}
 
 impl AnswerCustomQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1317,7 +1510,7 @@ impl AnswerCustomQuery {
     RTDAnswerCustomQueryBuilder { inner }
   }
 
-  pub fn custom_query_id(&self) -> isize { self.custom_query_id }
+  pub fn custom_query_id(&self) -> i64 { self.custom_query_id }
 
   pub fn data(&self) -> &String { &self.data }
 
@@ -1331,8 +1524,13 @@ pub struct RTDAnswerCustomQueryBuilder {
 impl RTDAnswerCustomQueryBuilder {
   pub fn build(&self) -> AnswerCustomQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn custom_query_id(&mut self, custom_query_id: isize) -> &mut Self {
+  pub fn custom_query_id(&mut self, custom_query_id: i64) -> &mut Self {
     self.inner.custom_query_id = custom_query_id;
     self
   }
@@ -1365,8 +1563,11 @@ pub struct AnswerInlineQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the inline query
-  inline_query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] inline_query_id: i64,
   /// True, if the result of the query can be cached for the specified user
   is_personal: bool,
   /// The results of the query
@@ -1384,13 +1585,16 @@ pub struct AnswerInlineQuery {
 
 impl RObject for AnswerInlineQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "answerInlineQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AnswerInlineQuery {}
+impl RFunction for AnswerInlineQuery {
+  type Response = Ok;
+}
 
 impl AnswerInlineQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1400,7 +1604,7 @@ impl AnswerInlineQuery {
     RTDAnswerInlineQueryBuilder { inner }
   }
 
-  pub fn inline_query_id(&self) -> isize { self.inline_query_id }
+  pub fn inline_query_id(&self) -> i64 { self.inline_query_id }
 
   pub fn is_personal(&self) -> bool { self.is_personal }
 
@@ -1424,8 +1628,13 @@ pub struct RTDAnswerInlineQueryBuilder {
 impl RTDAnswerInlineQueryBuilder {
   pub fn build(&self) -> AnswerInlineQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn inline_query_id(&mut self, inline_query_id: isize) -> &mut Self {
+  pub fn inline_query_id(&mut self, inline_query_id: i64) -> &mut Self {
     self.inner.inline_query_id = inline_query_id;
     self
   }
@@ -1488,8 +1697,11 @@ pub struct AnswerPreCheckoutQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the pre-checkout query
-  pre_checkout_query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] pre_checkout_query_id: i64,
   /// An error message, empty on success
   error_message: String,
   
@@ -1497,13 +1709,16 @@ pub struct AnswerPreCheckoutQuery {
 
 impl RObject for AnswerPreCheckoutQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "answerPreCheckoutQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AnswerPreCheckoutQuery {}
+impl RFunction for AnswerPreCheckoutQuery {
+  type Response = Ok;
+}
 
 impl AnswerPreCheckoutQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1513,7 +1728,7 @@ impl AnswerPreCheckoutQuery {
     RTDAnswerPreCheckoutQueryBuilder { inner }
   }
 
-  pub fn pre_checkout_query_id(&self) -> isize { self.pre_checkout_query_id }
+  pub fn pre_checkout_query_id(&self) -> i64 { self.pre_checkout_query_id }
 
   pub fn error_message(&self) -> &String { &self.error_message }
 
@@ -1527,8 +1742,13 @@ pub struct RTDAnswerPreCheckoutQueryBuilder {
 impl RTDAnswerPreCheckoutQueryBuilder {
   pub fn build(&self) -> AnswerPreCheckoutQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn pre_checkout_query_id(&mut self, pre_checkout_query_id: isize) -> &mut Self {
+  pub fn pre_checkout_query_id(&mut self, pre_checkout_query_id: i64) -> &mut Self {
     self.inner.pre_checkout_query_id = pre_checkout_query_id;
     self
   }
@@ -1561,8 +1781,11 @@ pub struct AnswerShippingQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the shipping query
-  shipping_query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] shipping_query_id: i64,
   /// Available shipping options
   shipping_options: Vec<ShippingOption>,
   /// An error message, empty on success
@@ -1572,13 +1795,16 @@ pub struct AnswerShippingQuery {
 
 impl RObject for AnswerShippingQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "answerShippingQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for AnswerShippingQuery {}
+impl RFunction for AnswerShippingQuery {
+  type Response = Ok;
+}
 
 impl AnswerShippingQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1588,7 +1814,7 @@ impl AnswerShippingQuery {
     RTDAnswerShippingQueryBuilder { inner }
   }
 
-  pub fn shipping_query_id(&self) -> isize { self.shipping_query_id }
+  pub fn shipping_query_id(&self) -> i64 { self.shipping_query_id }
 
   pub fn shipping_options(&self) -> &Vec<ShippingOption> { &self.shipping_options }
 
@@ -1604,8 +1830,13 @@ pub struct RTDAnswerShippingQueryBuilder {
 impl RTDAnswerShippingQueryBuilder {
   pub fn build(&self) -> AnswerShippingQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn shipping_query_id(&mut self, shipping_query_id: isize) -> &mut Self {
+  pub fn shipping_query_id(&mut self, shipping_query_id: i64) -> &mut Self {
     self.inner.shipping_query_id = shipping_query_id;
     self
   }
@@ -1644,6 +1875,9 @@ pub struct BlockUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   
@@ -1651,13 +1885,16 @@ pub struct BlockUser {
 
 impl RObject for BlockUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "blockUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for BlockUser {}
+impl RFunction for BlockUser {
+  type Response = Ok;
+}
 
 impl BlockUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1679,6 +1916,11 @@ pub struct RTDBlockUserBuilder {
 impl RTDBlockUserBuilder {
   pub fn build(&self) -> BlockUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -1707,18 +1949,24 @@ pub struct CanTransferOwnership {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for CanTransferOwnership {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnership" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDCanTransferOwnershipResult for CanTransferOwnership {}
 
-impl RFunction for CanTransferOwnership {}
+
+impl RFunction for CanTransferOwnership {
+  type Response = CanTransferOwnershipResult;
+}
 
 impl CanTransferOwnership {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1738,6 +1986,11 @@ pub struct RTDCanTransferOwnershipBuilder {
 impl RTDCanTransferOwnershipBuilder {
   pub fn build(&self) -> CanTransferOwnership { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CanTransferOwnership> for CanTransferOwnership {
@@ -1760,6 +2013,9 @@ pub struct CancelDownloadFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of a file to stop downloading
   file_id: i64,
   /// Pass true to stop downloading only if it hasn't been started, i.e. request hasn't been sent to server
@@ -1769,13 +2025,16 @@ pub struct CancelDownloadFile {
 
 impl RObject for CancelDownloadFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "cancelDownloadFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CancelDownloadFile {}
+impl RFunction for CancelDownloadFile {
+  type Response = Ok;
+}
 
 impl CancelDownloadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1799,6 +2058,11 @@ pub struct RTDCancelDownloadFileBuilder {
 impl RTDCancelDownloadFileBuilder {
   pub fn build(&self) -> CancelDownloadFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -1833,6 +2097,9 @@ pub struct CancelUploadFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the file to stop uploading
   file_id: i64,
   
@@ -1840,13 +2107,16 @@ pub struct CancelUploadFile {
 
 impl RObject for CancelUploadFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "cancelUploadFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CancelUploadFile {}
+impl RFunction for CancelUploadFile {
+  type Response = Ok;
+}
 
 impl CancelUploadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1868,6 +2138,11 @@ pub struct RTDCancelUploadFileBuilder {
 impl RTDCancelUploadFileBuilder {
   pub fn build(&self) -> CancelUploadFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -1896,6 +2171,9 @@ pub struct ChangeImportedContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new list of contacts, contact's vCard are ignored and are not imported
   contacts: Vec<Contact>,
   
@@ -1903,13 +2181,16 @@ pub struct ChangeImportedContacts {
 
 impl RObject for ChangeImportedContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "changeImportedContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ChangeImportedContacts {}
+impl RFunction for ChangeImportedContacts {
+  type Response = ImportedContacts;
+}
 
 impl ChangeImportedContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1931,6 +2212,11 @@ pub struct RTDChangeImportedContactsBuilder {
 impl RTDChangeImportedContactsBuilder {
   pub fn build(&self) -> ChangeImportedContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contacts(&mut self, contacts: Vec<Contact>) -> &mut Self {
     self.inner.contacts = contacts;
@@ -1959,6 +2245,9 @@ pub struct ChangePhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new phone number of the user in international format
   phone_number: String,
   /// Settings for the authentication of the user's phone number
@@ -1968,13 +2257,16 @@ pub struct ChangePhoneNumber {
 
 impl RObject for ChangePhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "changePhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ChangePhoneNumber {}
+impl RFunction for ChangePhoneNumber {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ChangePhoneNumber {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1998,6 +2290,11 @@ pub struct RTDChangePhoneNumberBuilder {
 impl RTDChangePhoneNumberBuilder {
   pub fn build(&self) -> ChangePhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -2032,8 +2329,11 @@ pub struct ChangeStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the sticker set
-  set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] set_id: i64,
   /// The new value of is_installed
   is_installed: bool,
   /// The new value of is_archived. A sticker set can't be installed and archived simultaneously
@@ -2043,13 +2343,16 @@ pub struct ChangeStickerSet {
 
 impl RObject for ChangeStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "changeStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ChangeStickerSet {}
+impl RFunction for ChangeStickerSet {
+  type Response = Ok;
+}
 
 impl ChangeStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2059,7 +2362,7 @@ impl ChangeStickerSet {
     RTDChangeStickerSetBuilder { inner }
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  pub fn set_id(&self) -> i64 { self.set_id }
 
   pub fn is_installed(&self) -> bool { self.is_installed }
 
@@ -2075,8 +2378,13 @@ pub struct RTDChangeStickerSetBuilder {
 impl RTDChangeStickerSetBuilder {
   pub fn build(&self) -> ChangeStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
     self
   }
@@ -2115,6 +2423,9 @@ pub struct CheckAuthenticationBotToken {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The bot token
   token: String,
   
@@ -2122,13 +2433,16 @@ pub struct CheckAuthenticationBotToken {
 
 impl RObject for CheckAuthenticationBotToken {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkAuthenticationBotToken" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckAuthenticationBotToken {}
+impl RFunction for CheckAuthenticationBotToken {
+  type Response = Ok;
+}
 
 impl CheckAuthenticationBotToken {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2150,6 +2464,11 @@ pub struct RTDCheckAuthenticationBotTokenBuilder {
 impl RTDCheckAuthenticationBotTokenBuilder {
   pub fn build(&self) -> CheckAuthenticationBotToken { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -2178,6 +2497,9 @@ pub struct CheckAuthenticationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The verification code received via SMS, Telegram message, phone call, or flash call
   code: String,
   
@@ -2185,13 +2507,16 @@ pub struct CheckAuthenticationCode {
 
 impl RObject for CheckAuthenticationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkAuthenticationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckAuthenticationCode {}
+impl RFunction for CheckAuthenticationCode {
+  type Response = Ok;
+}
 
 impl CheckAuthenticationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2213,6 +2538,11 @@ pub struct RTDCheckAuthenticationCodeBuilder {
 impl RTDCheckAuthenticationCodeBuilder {
   pub fn build(&self) -> CheckAuthenticationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -2241,6 +2571,9 @@ pub struct CheckAuthenticationPassword {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The password to check
   password: String,
   
@@ -2248,13 +2581,16 @@ pub struct CheckAuthenticationPassword {
 
 impl RObject for CheckAuthenticationPassword {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkAuthenticationPassword" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckAuthenticationPassword {}
+impl RFunction for CheckAuthenticationPassword {
+  type Response = Ok;
+}
 
 impl CheckAuthenticationPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2276,6 +2612,11 @@ pub struct RTDCheckAuthenticationPasswordBuilder {
 impl RTDCheckAuthenticationPasswordBuilder {
   pub fn build(&self) -> CheckAuthenticationPassword { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
@@ -2304,6 +2645,9 @@ pub struct CheckChangePhoneNumberCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Verification code received by SMS, phone call or flash call
   code: String,
   
@@ -2311,13 +2655,16 @@ pub struct CheckChangePhoneNumberCode {
 
 impl RObject for CheckChangePhoneNumberCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChangePhoneNumberCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckChangePhoneNumberCode {}
+impl RFunction for CheckChangePhoneNumberCode {
+  type Response = Ok;
+}
 
 impl CheckChangePhoneNumberCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2339,6 +2686,11 @@ pub struct RTDCheckChangePhoneNumberCodeBuilder {
 impl RTDCheckChangePhoneNumberCodeBuilder {
   pub fn build(&self) -> CheckChangePhoneNumberCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -2367,6 +2719,9 @@ pub struct CheckChatInviteLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Invite link to be checked; should begin with "https://t.me/joinchat/", "https://telegram.me/joinchat/", or "https://telegram.dog/joinchat/"
   invite_link: String,
   
@@ -2374,13 +2729,16 @@ pub struct CheckChatInviteLink {
 
 impl RObject for CheckChatInviteLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatInviteLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckChatInviteLink {}
+impl RFunction for CheckChatInviteLink {
+  type Response = ChatInviteLinkInfo;
+}
 
 impl CheckChatInviteLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2402,6 +2760,11 @@ pub struct RTDCheckChatInviteLinkBuilder {
 impl RTDCheckChatInviteLinkBuilder {
   pub fn build(&self) -> CheckChatInviteLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
@@ -2430,6 +2793,9 @@ pub struct CheckChatUsername {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier; should be identifier of a supergroup chat, or a channel chat, or a private chat with self, or zero if chat is being created
   chat_id: i64,
   /// Username to be checked
@@ -2439,13 +2805,16 @@ pub struct CheckChatUsername {
 
 impl RObject for CheckChatUsername {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsername" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDCheckChatUsernameResult for CheckChatUsername {}
 
-impl RFunction for CheckChatUsername {}
+
+impl RFunction for CheckChatUsername {
+  type Response = CheckChatUsernameResult;
+}
 
 impl CheckChatUsername {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2469,6 +2838,11 @@ pub struct RTDCheckChatUsernameBuilder {
 impl RTDCheckChatUsernameBuilder {
   pub fn build(&self) -> CheckChatUsername { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2503,6 +2877,9 @@ pub struct CheckCreatedPublicChatsLimit {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of the public chats, for which to check the limit
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PublicChatType,
   
@@ -2510,13 +2887,16 @@ pub struct CheckCreatedPublicChatsLimit {
 
 impl RObject for CheckCreatedPublicChatsLimit {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkCreatedPublicChatsLimit" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckCreatedPublicChatsLimit {}
+impl RFunction for CheckCreatedPublicChatsLimit {
+  type Response = Ok;
+}
 
 impl CheckCreatedPublicChatsLimit {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2538,6 +2918,11 @@ pub struct RTDCheckCreatedPublicChatsLimitBuilder {
 impl RTDCheckCreatedPublicChatsLimitBuilder {
   pub fn build(&self) -> CheckCreatedPublicChatsLimit { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PublicChatType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -2566,20 +2951,26 @@ pub struct CheckDatabaseEncryptionKey {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Encryption key to check or set up
-  encryption_key: String,
+  encryption_key: TdBytes,
   
 }
 
 impl RObject for CheckDatabaseEncryptionKey {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkDatabaseEncryptionKey" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckDatabaseEncryptionKey {}
+impl RFunction for CheckDatabaseEncryptionKey {
+  type Response = Ok;
+}
 
 impl CheckDatabaseEncryptionKey {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2589,7 +2980,7 @@ impl CheckDatabaseEncryptionKey {
     RTDCheckDatabaseEncryptionKeyBuilder { inner }
   }
 
-  pub fn encryption_key(&self) -> &String { &self.encryption_key }
+  pub fn encryption_key(&self) -> &TdBytes { &self.encryption_key }
 
 }
 
@@ -2601,9 +2992,14 @@ pub struct RTDCheckDatabaseEncryptionKeyBuilder {
 impl RTDCheckDatabaseEncryptionKeyBuilder {
   pub fn build(&self) -> CheckDatabaseEncryptionKey { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn encryption_key<T: AsRef<str>>(&mut self, encryption_key: T) -> &mut Self {
-    self.inner.encryption_key = encryption_key.as_ref().to_string();
+  pub fn encryption_key<T: AsRef<[u8]>>(&mut self, encryption_key: T) -> &mut Self {
+    self.inner.encryption_key = TdBytes::from(encryption_key.as_ref());
     self
   }
 
@@ -2629,6 +3025,9 @@ pub struct CheckEmailAddressVerificationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Verification code
   code: String,
   
@@ -2636,13 +3035,16 @@ pub struct CheckEmailAddressVerificationCode {
 
 impl RObject for CheckEmailAddressVerificationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkEmailAddressVerificationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckEmailAddressVerificationCode {}
+impl RFunction for CheckEmailAddressVerificationCode {
+  type Response = Ok;
+}
 
 impl CheckEmailAddressVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2664,6 +3066,11 @@ pub struct RTDCheckEmailAddressVerificationCodeBuilder {
 impl RTDCheckEmailAddressVerificationCodeBuilder {
   pub fn build(&self) -> CheckEmailAddressVerificationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -2692,6 +3099,9 @@ pub struct CheckPhoneNumberConfirmationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The phone number confirmation code
   code: String,
   
@@ -2699,13 +3109,16 @@ pub struct CheckPhoneNumberConfirmationCode {
 
 impl RObject for CheckPhoneNumberConfirmationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkPhoneNumberConfirmationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckPhoneNumberConfirmationCode {}
+impl RFunction for CheckPhoneNumberConfirmationCode {
+  type Response = Ok;
+}
 
 impl CheckPhoneNumberConfirmationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2727,6 +3140,11 @@ pub struct RTDCheckPhoneNumberConfirmationCodeBuilder {
 impl RTDCheckPhoneNumberConfirmationCodeBuilder {
   pub fn build(&self) -> CheckPhoneNumberConfirmationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -2755,6 +3173,9 @@ pub struct CheckPhoneNumberVerificationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Verification code
   code: String,
   
@@ -2762,13 +3183,16 @@ pub struct CheckPhoneNumberVerificationCode {
 
 impl RObject for CheckPhoneNumberVerificationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkPhoneNumberVerificationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckPhoneNumberVerificationCode {}
+impl RFunction for CheckPhoneNumberVerificationCode {
+  type Response = Ok;
+}
 
 impl CheckPhoneNumberVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2790,6 +3214,11 @@ pub struct RTDCheckPhoneNumberVerificationCodeBuilder {
 impl RTDCheckPhoneNumberVerificationCodeBuilder {
   pub fn build(&self) -> CheckPhoneNumberVerificationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -2818,6 +3247,9 @@ pub struct CheckRecoveryEmailAddressCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Verification code
   code: String,
   
@@ -2825,13 +3257,16 @@ pub struct CheckRecoveryEmailAddressCode {
 
 impl RObject for CheckRecoveryEmailAddressCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "checkRecoveryEmailAddressCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CheckRecoveryEmailAddressCode {}
+impl RFunction for CheckRecoveryEmailAddressCode {
+  type Response = PasswordState;
+}
 
 impl CheckRecoveryEmailAddressCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2853,6 +3288,11 @@ pub struct RTDCheckRecoveryEmailAddressCodeBuilder {
 impl RTDCheckRecoveryEmailAddressCodeBuilder {
   pub fn build(&self) -> CheckRecoveryEmailAddressCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -2881,6 +3321,9 @@ pub struct CleanFileName {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// File name or path to the file
   file_name: String,
   
@@ -2888,13 +3331,16 @@ pub struct CleanFileName {
 
 impl RObject for CleanFileName {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "cleanFileName" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CleanFileName {}
+impl RFunction for CleanFileName {
+  type Response = Text;
+}
 
 impl CleanFileName {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2916,6 +3362,11 @@ pub struct RTDCleanFileNameBuilder {
 impl RTDCleanFileNameBuilder {
   pub fn build(&self) -> CleanFileName { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
@@ -2944,6 +3395,9 @@ pub struct ClearAllDraftMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, local draft messages in secret chats will not be cleared
   exclude_secret_chats: bool,
   
@@ -2951,13 +3405,16 @@ pub struct ClearAllDraftMessages {
 
 impl RObject for ClearAllDraftMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "clearAllDraftMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ClearAllDraftMessages {}
+impl RFunction for ClearAllDraftMessages {
+  type Response = Ok;
+}
 
 impl ClearAllDraftMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2979,6 +3436,11 @@ pub struct RTDClearAllDraftMessagesBuilder {
 impl RTDClearAllDraftMessagesBuilder {
   pub fn build(&self) -> ClearAllDraftMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn exclude_secret_chats(&mut self, exclude_secret_chats: bool) -> &mut Self {
     self.inner.exclude_secret_chats = exclude_secret_chats;
@@ -3007,18 +3469,24 @@ pub struct ClearImportedContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ClearImportedContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "clearImportedContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ClearImportedContacts {}
+impl RFunction for ClearImportedContacts {
+  type Response = Ok;
+}
 
 impl ClearImportedContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3038,6 +3506,11 @@ pub struct RTDClearImportedContactsBuilder {
 impl RTDClearImportedContactsBuilder {
   pub fn build(&self) -> ClearImportedContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ClearImportedContacts> for ClearImportedContacts {
@@ -3060,6 +3533,9 @@ pub struct ClearRecentStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to clear the list of stickers recently attached to photo or video files; pass false to clear the list of recently sent stickers
   is_attached: bool,
   
@@ -3067,13 +3543,16 @@ pub struct ClearRecentStickers {
 
 impl RObject for ClearRecentStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "clearRecentStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ClearRecentStickers {}
+impl RFunction for ClearRecentStickers {
+  type Response = Ok;
+}
 
 impl ClearRecentStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3095,6 +3574,11 @@ pub struct RTDClearRecentStickersBuilder {
 impl RTDClearRecentStickersBuilder {
   pub fn build(&self) -> ClearRecentStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -3123,18 +3607,24 @@ pub struct ClearRecentlyFoundChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ClearRecentlyFoundChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "clearRecentlyFoundChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ClearRecentlyFoundChats {}
+impl RFunction for ClearRecentlyFoundChats {
+  type Response = Ok;
+}
 
 impl ClearRecentlyFoundChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3154,6 +3644,11 @@ pub struct RTDClearRecentlyFoundChatsBuilder {
 impl RTDClearRecentlyFoundChatsBuilder {
   pub fn build(&self) -> ClearRecentlyFoundChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ClearRecentlyFoundChats> for ClearRecentlyFoundChats {
@@ -3176,18 +3671,24 @@ pub struct Close {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for Close {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "close" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for Close {}
+impl RFunction for Close {
+  type Response = Ok;
+}
 
 impl Close {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3207,6 +3708,11 @@ pub struct RTDCloseBuilder {
 impl RTDCloseBuilder {
   pub fn build(&self) -> Close { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<Close> for Close {
@@ -3229,6 +3735,9 @@ pub struct CloseChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -3236,13 +3745,16 @@ pub struct CloseChat {
 
 impl RObject for CloseChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "closeChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CloseChat {}
+impl RFunction for CloseChat {
+  type Response = Ok;
+}
 
 impl CloseChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3264,6 +3776,11 @@ pub struct RTDCloseChatBuilder {
 impl RTDCloseChatBuilder {
   pub fn build(&self) -> CloseChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3292,6 +3809,9 @@ pub struct CloseSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Secret chat identifier
   secret_chat_id: i64,
   
@@ -3299,13 +3819,16 @@ pub struct CloseSecretChat {
 
 impl RObject for CloseSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "closeSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CloseSecretChat {}
+impl RFunction for CloseSecretChat {
+  type Response = Ok;
+}
 
 impl CloseSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3327,6 +3850,11 @@ pub struct RTDCloseSecretChatBuilder {
 impl RTDCloseSecretChatBuilder {
   pub fn build(&self) -> CloseSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
@@ -3355,6 +3883,9 @@ pub struct ConfirmQrCodeAuthentication {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A link from a QR code. The link must be scanned by the in-app camera
   link: String,
   
@@ -3362,13 +3893,16 @@ pub struct ConfirmQrCodeAuthentication {
 
 impl RObject for ConfirmQrCodeAuthentication {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "confirmQrCodeAuthentication" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ConfirmQrCodeAuthentication {}
+impl RFunction for ConfirmQrCodeAuthentication {
+  type Response = Session;
+}
 
 impl ConfirmQrCodeAuthentication {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3390,6 +3924,11 @@ pub struct RTDConfirmQrCodeAuthenticationBuilder {
 impl RTDConfirmQrCodeAuthenticationBuilder {
   pub fn build(&self) -> ConfirmQrCodeAuthentication { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
@@ -3418,6 +3957,9 @@ pub struct CreateBasicGroupChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Basic group identifier
   basic_group_id: i64,
   /// If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect
@@ -3427,13 +3969,16 @@ pub struct CreateBasicGroupChat {
 
 impl RObject for CreateBasicGroupChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createBasicGroupChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateBasicGroupChat {}
+impl RFunction for CreateBasicGroupChat {
+  type Response = Chat;
+}
 
 impl CreateBasicGroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3457,6 +4002,11 @@ pub struct RTDCreateBasicGroupChatBuilder {
 impl RTDCreateBasicGroupChatBuilder {
   pub fn build(&self) -> CreateBasicGroupChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
@@ -3491,6 +4041,9 @@ pub struct CreateCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the user to be called
   user_id: i64,
   /// Description of the call protocols supported by the application
@@ -3502,13 +4055,16 @@ pub struct CreateCall {
 
 impl RObject for CreateCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateCall {}
+impl RFunction for CreateCall {
+  type Response = CallId;
+}
 
 impl CreateCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3534,8 +4090,13 @@ pub struct RTDCreateCallBuilder {
 impl RTDCreateCallBuilder {
   pub fn build(&self) -> CreateCall { self.inner.clone() }
 
-   
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
+   
+  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -3574,6 +4135,9 @@ pub struct CreateChatFilter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat filter
   filter: ChatFilter,
   
@@ -3581,13 +4145,16 @@ pub struct CreateChatFilter {
 
 impl RObject for CreateChatFilter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createChatFilter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateChatFilter {}
+impl RFunction for CreateChatFilter {
+  type Response = ChatFilterInfo;
+}
 
 impl CreateChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3609,6 +4176,11 @@ pub struct RTDCreateChatFilterBuilder {
 impl RTDCreateChatFilterBuilder {
   pub fn build(&self) -> CreateChatFilter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
@@ -3637,6 +4209,9 @@ pub struct CreateNewBasicGroupChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifiers of users to be added to the basic group
   user_ids: Vec<i64>,
   /// Title of the new basic group; 1-128 characters
@@ -3646,13 +4221,16 @@ pub struct CreateNewBasicGroupChat {
 
 impl RObject for CreateNewBasicGroupChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createNewBasicGroupChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateNewBasicGroupChat {}
+impl RFunction for CreateNewBasicGroupChat {
+  type Response = Chat;
+}
 
 impl CreateNewBasicGroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3676,6 +4254,11 @@ pub struct RTDCreateNewBasicGroupChatBuilder {
 impl RTDCreateNewBasicGroupChatBuilder {
   pub fn build(&self) -> CreateNewBasicGroupChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -3710,6 +4293,9 @@ pub struct CreateNewSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the target user
   user_id: i64,
   
@@ -3717,13 +4303,16 @@ pub struct CreateNewSecretChat {
 
 impl RObject for CreateNewSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createNewSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateNewSecretChat {}
+impl RFunction for CreateNewSecretChat {
+  type Response = Chat;
+}
 
 impl CreateNewSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3745,6 +4334,11 @@ pub struct RTDCreateNewSecretChatBuilder {
 impl RTDCreateNewSecretChatBuilder {
   pub fn build(&self) -> CreateNewSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -3773,6 +4367,9 @@ pub struct CreateNewStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker set owner
   user_id: i64,
   /// Sticker set title; 1-64 characters
@@ -3788,13 +4385,16 @@ pub struct CreateNewStickerSet {
 
 impl RObject for CreateNewStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createNewStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateNewStickerSet {}
+impl RFunction for CreateNewStickerSet {
+  type Response = StickerSet;
+}
 
 impl CreateNewStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3824,6 +4424,11 @@ pub struct RTDCreateNewStickerSetBuilder {
 impl RTDCreateNewStickerSetBuilder {
   pub fn build(&self) -> CreateNewStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -3876,6 +4481,9 @@ pub struct CreateNewSupergroupChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Title of the new chat; 1-128 characters
   title: String,
   /// True, if a channel chat should be created
@@ -3889,13 +4497,16 @@ pub struct CreateNewSupergroupChat {
 
 impl RObject for CreateNewSupergroupChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createNewSupergroupChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateNewSupergroupChat {}
+impl RFunction for CreateNewSupergroupChat {
+  type Response = Chat;
+}
 
 impl CreateNewSupergroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3923,6 +4534,11 @@ pub struct RTDCreateNewSupergroupChatBuilder {
 impl RTDCreateNewSupergroupChatBuilder {
   pub fn build(&self) -> CreateNewSupergroupChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -3969,6 +4585,9 @@ pub struct CreatePrivateChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect
@@ -3978,13 +4597,16 @@ pub struct CreatePrivateChat {
 
 impl RObject for CreatePrivateChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createPrivateChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreatePrivateChat {}
+impl RFunction for CreatePrivateChat {
+  type Response = Chat;
+}
 
 impl CreatePrivateChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4008,6 +4630,11 @@ pub struct RTDCreatePrivateChatBuilder {
 impl RTDCreatePrivateChatBuilder {
   pub fn build(&self) -> CreatePrivateChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -4042,6 +4669,9 @@ pub struct CreateSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Secret chat identifier
   secret_chat_id: i64,
   
@@ -4049,13 +4679,16 @@ pub struct CreateSecretChat {
 
 impl RObject for CreateSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateSecretChat {}
+impl RFunction for CreateSecretChat {
+  type Response = Chat;
+}
 
 impl CreateSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4077,6 +4710,11 @@ pub struct RTDCreateSecretChatBuilder {
 impl RTDCreateSecretChatBuilder {
   pub fn build(&self) -> CreateSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
@@ -4105,6 +4743,9 @@ pub struct CreateSupergroupChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Supergroup or channel identifier
   supergroup_id: i64,
   /// If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect
@@ -4114,13 +4755,16 @@ pub struct CreateSupergroupChat {
 
 impl RObject for CreateSupergroupChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createSupergroupChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateSupergroupChat {}
+impl RFunction for CreateSupergroupChat {
+  type Response = Chat;
+}
 
 impl CreateSupergroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4144,6 +4788,11 @@ pub struct RTDCreateSupergroupChatBuilder {
 impl RTDCreateSupergroupChatBuilder {
   pub fn build(&self) -> CreateSupergroupChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -4178,6 +4827,9 @@ pub struct CreateTemporaryPassword {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Persistent user password
   password: String,
   /// Time during which the temporary password will be valid, in seconds; should be between 60 and 86400
@@ -4187,13 +4839,16 @@ pub struct CreateTemporaryPassword {
 
 impl RObject for CreateTemporaryPassword {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "createTemporaryPassword" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for CreateTemporaryPassword {}
+impl RFunction for CreateTemporaryPassword {
+  type Response = TemporaryPasswordState;
+}
 
 impl CreateTemporaryPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4217,6 +4872,11 @@ pub struct RTDCreateTemporaryPasswordBuilder {
 impl RTDCreateTemporaryPasswordBuilder {
   pub fn build(&self) -> CreateTemporaryPassword { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
@@ -4251,6 +4911,9 @@ pub struct DeleteAccount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The reason why the account was deleted; optional
   reason: String,
   
@@ -4258,13 +4921,16 @@ pub struct DeleteAccount {
 
 impl RObject for DeleteAccount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteAccount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteAccount {}
+impl RFunction for DeleteAccount {
+  type Response = Ok;
+}
 
 impl DeleteAccount {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4286,6 +4952,11 @@ pub struct RTDDeleteAccountBuilder {
 impl RTDDeleteAccountBuilder {
   pub fn build(&self) -> DeleteAccount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reason<T: AsRef<str>>(&mut self, reason: T) -> &mut Self {
     self.inner.reason = reason.as_ref().to_string();
@@ -4314,6 +4985,9 @@ pub struct DeleteChatFilter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat filter identifier
   chat_filter_id: i64,
   
@@ -4321,13 +4995,16 @@ pub struct DeleteChatFilter {
 
 impl RObject for DeleteChatFilter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteChatFilter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteChatFilter {}
+impl RFunction for DeleteChatFilter {
+  type Response = Ok;
+}
 
 impl DeleteChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4349,6 +5026,11 @@ pub struct RTDDeleteChatFilterBuilder {
 impl RTDDeleteChatFilterBuilder {
   pub fn build(&self) -> DeleteChatFilter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
@@ -4377,6 +5059,9 @@ pub struct DeleteChatHistory {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Pass true if the chat should be removed from the chat list
@@ -4388,13 +5073,16 @@ pub struct DeleteChatHistory {
 
 impl RObject for DeleteChatHistory {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteChatHistory" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteChatHistory {}
+impl RFunction for DeleteChatHistory {
+  type Response = Ok;
+}
 
 impl DeleteChatHistory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4420,6 +5108,11 @@ pub struct RTDDeleteChatHistoryBuilder {
 impl RTDDeleteChatHistoryBuilder {
   pub fn build(&self) -> DeleteChatHistory { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -4460,6 +5153,9 @@ pub struct DeleteChatMessagesFromUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// User identifier
@@ -4469,13 +5165,16 @@ pub struct DeleteChatMessagesFromUser {
 
 impl RObject for DeleteChatMessagesFromUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteChatMessagesFromUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteChatMessagesFromUser {}
+impl RFunction for DeleteChatMessagesFromUser {
+  type Response = Ok;
+}
 
 impl DeleteChatMessagesFromUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4499,6 +5198,11 @@ pub struct RTDDeleteChatMessagesFromUserBuilder {
 impl RTDDeleteChatMessagesFromUserBuilder {
   pub fn build(&self) -> DeleteChatMessagesFromUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -4533,6 +5237,9 @@ pub struct DeleteChatReplyMarkup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The message identifier of the used keyboard
@@ -4542,13 +5249,16 @@ pub struct DeleteChatReplyMarkup {
 
 impl RObject for DeleteChatReplyMarkup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteChatReplyMarkup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteChatReplyMarkup {}
+impl RFunction for DeleteChatReplyMarkup {
+  type Response = Ok;
+}
 
 impl DeleteChatReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4572,6 +5282,11 @@ pub struct RTDDeleteChatReplyMarkupBuilder {
 impl RTDDeleteChatReplyMarkupBuilder {
   pub fn build(&self) -> DeleteChatReplyMarkup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -4606,6 +5321,9 @@ pub struct DeleteFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the file to delete
   file_id: i64,
   
@@ -4613,13 +5331,16 @@ pub struct DeleteFile {
 
 impl RObject for DeleteFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteFile {}
+impl RFunction for DeleteFile {
+  type Response = Ok;
+}
 
 impl DeleteFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4641,6 +5362,11 @@ pub struct RTDDeleteFileBuilder {
 impl RTDDeleteFileBuilder {
   pub fn build(&self) -> DeleteFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -4669,6 +5395,9 @@ pub struct DeleteLanguagePack {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the language pack to delete
   language_pack_id: String,
   
@@ -4676,13 +5405,16 @@ pub struct DeleteLanguagePack {
 
 impl RObject for DeleteLanguagePack {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteLanguagePack" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteLanguagePack {}
+impl RFunction for DeleteLanguagePack {
+  type Response = Ok;
+}
 
 impl DeleteLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4704,6 +5436,11 @@ pub struct RTDDeleteLanguagePackBuilder {
 impl RTDDeleteLanguagePackBuilder {
   pub fn build(&self) -> DeleteLanguagePack { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -4732,6 +5469,9 @@ pub struct DeleteMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifiers of the messages to be deleted
@@ -4743,13 +5483,16 @@ pub struct DeleteMessages {
 
 impl RObject for DeleteMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteMessages {}
+impl RFunction for DeleteMessages {
+  type Response = Ok;
+}
 
 impl DeleteMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4775,6 +5518,11 @@ pub struct RTDDeleteMessagesBuilder {
 impl RTDDeleteMessagesBuilder {
   pub fn build(&self) -> DeleteMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -4815,6 +5563,9 @@ pub struct DeletePassportElement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Element type
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   
@@ -4822,13 +5573,16 @@ pub struct DeletePassportElement {
 
 impl RObject for DeletePassportElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deletePassportElement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeletePassportElement {}
+impl RFunction for DeletePassportElement {
+  type Response = Ok;
+}
 
 impl DeletePassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4850,6 +5604,11 @@ pub struct RTDDeletePassportElementBuilder {
 impl RTDDeletePassportElementBuilder {
   pub fn build(&self) -> DeletePassportElement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -4878,20 +5637,26 @@ pub struct DeleteProfilePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the profile photo to delete
-  profile_photo_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] profile_photo_id: i64,
   
 }
 
 impl RObject for DeleteProfilePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteProfilePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteProfilePhoto {}
+impl RFunction for DeleteProfilePhoto {
+  type Response = Ok;
+}
 
 impl DeleteProfilePhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4901,7 +5666,7 @@ impl DeleteProfilePhoto {
     RTDDeleteProfilePhotoBuilder { inner }
   }
 
-  pub fn profile_photo_id(&self) -> isize { self.profile_photo_id }
+  pub fn profile_photo_id(&self) -> i64 { self.profile_photo_id }
 
 }
 
@@ -4913,8 +5678,13 @@ pub struct RTDDeleteProfilePhotoBuilder {
 impl RTDDeleteProfilePhotoBuilder {
   pub fn build(&self) -> DeleteProfilePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn profile_photo_id(&mut self, profile_photo_id: isize) -> &mut Self {
+  pub fn profile_photo_id(&mut self, profile_photo_id: i64) -> &mut Self {
     self.inner.profile_photo_id = profile_photo_id;
     self
   }
@@ -4941,18 +5711,24 @@ pub struct DeleteSavedCredentials {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for DeleteSavedCredentials {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteSavedCredentials" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteSavedCredentials {}
+impl RFunction for DeleteSavedCredentials {
+  type Response = Ok;
+}
 
 impl DeleteSavedCredentials {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4972,6 +5748,11 @@ pub struct RTDDeleteSavedCredentialsBuilder {
 impl RTDDeleteSavedCredentialsBuilder {
   pub fn build(&self) -> DeleteSavedCredentials { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<DeleteSavedCredentials> for DeleteSavedCredentials {
@@ -4994,18 +5775,24 @@ pub struct DeleteSavedOrderInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for DeleteSavedOrderInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteSavedOrderInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteSavedOrderInfo {}
+impl RFunction for DeleteSavedOrderInfo {
+  type Response = Ok;
+}
 
 impl DeleteSavedOrderInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5025,6 +5812,11 @@ pub struct RTDDeleteSavedOrderInfoBuilder {
 impl RTDDeleteSavedOrderInfoBuilder {
   pub fn build(&self) -> DeleteSavedOrderInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<DeleteSavedOrderInfo> for DeleteSavedOrderInfo {
@@ -5047,6 +5839,9 @@ pub struct DeleteSupergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup or channel
   supergroup_id: i64,
   
@@ -5054,13 +5849,16 @@ pub struct DeleteSupergroup {
 
 impl RObject for DeleteSupergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "deleteSupergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DeleteSupergroup {}
+impl RFunction for DeleteSupergroup {
+  type Response = Ok;
+}
 
 impl DeleteSupergroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5082,6 +5880,11 @@ pub struct RTDDeleteSupergroupBuilder {
 impl RTDDeleteSupergroupBuilder {
   pub fn build(&self) -> DeleteSupergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -5110,18 +5913,24 @@ pub struct Destroy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for Destroy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "destroy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for Destroy {}
+impl RFunction for Destroy {
+  type Response = Ok;
+}
 
 impl Destroy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5141,6 +5950,11 @@ pub struct RTDDestroyBuilder {
 impl RTDDestroyBuilder {
   pub fn build(&self) -> Destroy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<Destroy> for Destroy {
@@ -5163,18 +5977,24 @@ pub struct DisableProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for DisableProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "disableProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DisableProxy {}
+impl RFunction for DisableProxy {
+  type Response = Ok;
+}
 
 impl DisableProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5194,6 +6014,11 @@ pub struct RTDDisableProxyBuilder {
 impl RTDDisableProxyBuilder {
   pub fn build(&self) -> DisableProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<DisableProxy> for DisableProxy {
@@ -5216,6 +6041,9 @@ pub struct DiscardCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   call_id: i64,
   /// True, if the user was disconnected
@@ -5225,19 +6053,22 @@ pub struct DiscardCall {
   /// True, if the call was a video call
   is_video: bool,
   /// Identifier of the connection used during the call
-  connection_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] connection_id: i64,
   
 }
 
 impl RObject for DiscardCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "discardCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DiscardCall {}
+impl RFunction for DiscardCall {
+  type Response = Ok;
+}
 
 impl DiscardCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5255,7 +6086,7 @@ impl DiscardCall {
 
   pub fn is_video(&self) -> bool { self.is_video }
 
-  pub fn connection_id(&self) -> isize { self.connection_id }
+  pub fn connection_id(&self) -> i64 { self.connection_id }
 
 }
 
@@ -5267,6 +6098,11 @@ pub struct RTDDiscardCallBuilder {
 impl RTDDiscardCallBuilder {
   pub fn build(&self) -> DiscardCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -5292,7 +6128,7 @@ impl RTDDiscardCallBuilder {
   }
 
    
-  pub fn connection_id(&mut self, connection_id: isize) -> &mut Self {
+  pub fn connection_id(&mut self, connection_id: i64) -> &mut Self {
     self.inner.connection_id = connection_id;
     self
   }
@@ -5319,18 +6155,24 @@ pub struct DisconnectAllWebsites {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for DisconnectAllWebsites {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "disconnectAllWebsites" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DisconnectAllWebsites {}
+impl RFunction for DisconnectAllWebsites {
+  type Response = Ok;
+}
 
 impl DisconnectAllWebsites {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5350,6 +6192,11 @@ pub struct RTDDisconnectAllWebsitesBuilder {
 impl RTDDisconnectAllWebsitesBuilder {
   pub fn build(&self) -> DisconnectAllWebsites { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<DisconnectAllWebsites> for DisconnectAllWebsites {
@@ -5372,20 +6219,26 @@ pub struct DisconnectWebsite {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Website identifier
-  website_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] website_id: i64,
   
 }
 
 impl RObject for DisconnectWebsite {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "disconnectWebsite" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DisconnectWebsite {}
+impl RFunction for DisconnectWebsite {
+  type Response = Ok;
+}
 
 impl DisconnectWebsite {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5395,7 +6248,7 @@ impl DisconnectWebsite {
     RTDDisconnectWebsiteBuilder { inner }
   }
 
-  pub fn website_id(&self) -> isize { self.website_id }
+  pub fn website_id(&self) -> i64 { self.website_id }
 
 }
 
@@ -5407,8 +6260,13 @@ pub struct RTDDisconnectWebsiteBuilder {
 impl RTDDisconnectWebsiteBuilder {
   pub fn build(&self) -> DisconnectWebsite { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn website_id(&mut self, website_id: isize) -> &mut Self {
+  pub fn website_id(&mut self, website_id: i64) -> &mut Self {
     self.inner.website_id = website_id;
     self
   }
@@ -5435,6 +6293,9 @@ pub struct DownloadFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the file to download
   file_id: i64,
   /// Priority of the download (1-32). The higher the priority, the earlier the file will be downloaded. If the priorities of two files are equal, then the last one for which downloadFile was called will be downloaded first
@@ -5450,13 +6311,16 @@ pub struct DownloadFile {
 
 impl RObject for DownloadFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "downloadFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for DownloadFile {}
+impl RFunction for DownloadFile {
+  type Response = File;
+}
 
 impl DownloadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5486,6 +6350,11 @@ pub struct RTDDownloadFileBuilder {
 impl RTDDownloadFileBuilder {
   pub fn build(&self) -> DownloadFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -5538,6 +6407,9 @@ pub struct EditChatFilter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat filter identifier
   chat_filter_id: i64,
   /// The edited chat filter
@@ -5547,13 +6419,16 @@ pub struct EditChatFilter {
 
 impl RObject for EditChatFilter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editChatFilter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditChatFilter {}
+impl RFunction for EditChatFilter {
+  type Response = ChatFilterInfo;
+}
 
 impl EditChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5577,6 +6452,11 @@ pub struct RTDEditChatFilterBuilder {
 impl RTDEditChatFilterBuilder {
   pub fn build(&self) -> EditChatFilter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
@@ -5611,6 +6491,9 @@ pub struct EditCustomLanguagePackInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New information about the custom local language pack
   info: LanguagePackInfo,
   
@@ -5618,13 +6501,16 @@ pub struct EditCustomLanguagePackInfo {
 
 impl RObject for EditCustomLanguagePackInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editCustomLanguagePackInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditCustomLanguagePackInfo {}
+impl RFunction for EditCustomLanguagePackInfo {
+  type Response = Ok;
+}
 
 impl EditCustomLanguagePackInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5646,6 +6532,11 @@ pub struct RTDEditCustomLanguagePackInfoBuilder {
 impl RTDEditCustomLanguagePackInfoBuilder {
   pub fn build(&self) -> EditCustomLanguagePackInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn info<T: AsRef<LanguagePackInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
@@ -5674,6 +6565,9 @@ pub struct EditInlineMessageCaption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// The new message reply markup
@@ -5685,13 +6579,16 @@ pub struct EditInlineMessageCaption {
 
 impl RObject for EditInlineMessageCaption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editInlineMessageCaption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditInlineMessageCaption {}
+impl RFunction for EditInlineMessageCaption {
+  type Response = Ok;
+}
 
 impl EditInlineMessageCaption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5717,6 +6614,11 @@ pub struct RTDEditInlineMessageCaptionBuilder {
 impl RTDEditInlineMessageCaptionBuilder {
   pub fn build(&self) -> EditInlineMessageCaption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -5757,6 +6659,9 @@ pub struct EditInlineMessageLiveLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// The new message reply markup
@@ -5768,13 +6673,16 @@ pub struct EditInlineMessageLiveLocation {
 
 impl RObject for EditInlineMessageLiveLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editInlineMessageLiveLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditInlineMessageLiveLocation {}
+impl RFunction for EditInlineMessageLiveLocation {
+  type Response = Ok;
+}
 
 impl EditInlineMessageLiveLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5800,6 +6708,11 @@ pub struct RTDEditInlineMessageLiveLocationBuilder {
 impl RTDEditInlineMessageLiveLocationBuilder {
   pub fn build(&self) -> EditInlineMessageLiveLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -5840,6 +6753,9 @@ pub struct EditInlineMessageMedia {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// The new message reply markup; for bots only
@@ -5851,13 +6767,16 @@ pub struct EditInlineMessageMedia {
 
 impl RObject for EditInlineMessageMedia {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editInlineMessageMedia" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditInlineMessageMedia {}
+impl RFunction for EditInlineMessageMedia {
+  type Response = Ok;
+}
 
 impl EditInlineMessageMedia {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5883,6 +6802,11 @@ pub struct RTDEditInlineMessageMediaBuilder {
 impl RTDEditInlineMessageMediaBuilder {
   pub fn build(&self) -> EditInlineMessageMedia { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -5923,6 +6847,9 @@ pub struct EditInlineMessageReplyMarkup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// The new message reply markup
@@ -5932,13 +6859,16 @@ pub struct EditInlineMessageReplyMarkup {
 
 impl RObject for EditInlineMessageReplyMarkup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editInlineMessageReplyMarkup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditInlineMessageReplyMarkup {}
+impl RFunction for EditInlineMessageReplyMarkup {
+  type Response = Ok;
+}
 
 impl EditInlineMessageReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5962,6 +6892,11 @@ pub struct RTDEditInlineMessageReplyMarkupBuilder {
 impl RTDEditInlineMessageReplyMarkupBuilder {
   pub fn build(&self) -> EditInlineMessageReplyMarkup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -5996,6 +6931,9 @@ pub struct EditInlineMessageText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// The new message reply markup
@@ -6007,13 +6945,16 @@ pub struct EditInlineMessageText {
 
 impl RObject for EditInlineMessageText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editInlineMessageText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditInlineMessageText {}
+impl RFunction for EditInlineMessageText {
+  type Response = Ok;
+}
 
 impl EditInlineMessageText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6039,6 +6980,11 @@ pub struct RTDEditInlineMessageTextBuilder {
 impl RTDEditInlineMessageTextBuilder {
   pub fn build(&self) -> EditInlineMessageText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -6079,6 +7025,9 @@ pub struct EditMessageCaption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat the message belongs to
   chat_id: i64,
   /// Identifier of the message
@@ -6092,13 +7041,16 @@ pub struct EditMessageCaption {
 
 impl RObject for EditMessageCaption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editMessageCaption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditMessageCaption {}
+impl RFunction for EditMessageCaption {
+  type Response = Message;
+}
 
 impl EditMessageCaption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6126,6 +7078,11 @@ pub struct RTDEditMessageCaptionBuilder {
 impl RTDEditMessageCaptionBuilder {
   pub fn build(&self) -> EditMessageCaption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6172,6 +7129,9 @@ pub struct EditMessageLiveLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat the message belongs to
   chat_id: i64,
   /// Identifier of the message
@@ -6185,13 +7145,16 @@ pub struct EditMessageLiveLocation {
 
 impl RObject for EditMessageLiveLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editMessageLiveLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditMessageLiveLocation {}
+impl RFunction for EditMessageLiveLocation {
+  type Response = Message;
+}
 
 impl EditMessageLiveLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6219,6 +7182,11 @@ pub struct RTDEditMessageLiveLocationBuilder {
 impl RTDEditMessageLiveLocationBuilder {
   pub fn build(&self) -> EditMessageLiveLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6265,6 +7233,9 @@ pub struct EditMessageMedia {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat the message belongs to
   chat_id: i64,
   /// Identifier of the message
@@ -6278,13 +7249,16 @@ pub struct EditMessageMedia {
 
 impl RObject for EditMessageMedia {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editMessageMedia" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditMessageMedia {}
+impl RFunction for EditMessageMedia {
+  type Response = Message;
+}
 
 impl EditMessageMedia {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6312,6 +7286,11 @@ pub struct RTDEditMessageMediaBuilder {
 impl RTDEditMessageMediaBuilder {
   pub fn build(&self) -> EditMessageMedia { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6358,6 +7337,9 @@ pub struct EditMessageReplyMarkup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat the message belongs to
   chat_id: i64,
   /// Identifier of the message
@@ -6369,13 +7351,16 @@ pub struct EditMessageReplyMarkup {
 
 impl RObject for EditMessageReplyMarkup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editMessageReplyMarkup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditMessageReplyMarkup {}
+impl RFunction for EditMessageReplyMarkup {
+  type Response = Message;
+}
 
 impl EditMessageReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6401,6 +7386,11 @@ pub struct RTDEditMessageReplyMarkupBuilder {
 impl RTDEditMessageReplyMarkupBuilder {
   pub fn build(&self) -> EditMessageReplyMarkup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6441,6 +7431,9 @@ pub struct EditMessageSchedulingState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat the message belongs to
   chat_id: i64,
   /// Identifier of the message
@@ -6452,13 +7445,16 @@ pub struct EditMessageSchedulingState {
 
 impl RObject for EditMessageSchedulingState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editMessageSchedulingState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditMessageSchedulingState {}
+impl RFunction for EditMessageSchedulingState {
+  type Response = Ok;
+}
 
 impl EditMessageSchedulingState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6484,6 +7480,11 @@ pub struct RTDEditMessageSchedulingStateBuilder {
 impl RTDEditMessageSchedulingStateBuilder {
   pub fn build(&self) -> EditMessageSchedulingState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6524,6 +7525,9 @@ pub struct EditMessageText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat the message belongs to
   chat_id: i64,
   /// Identifier of the message
@@ -6537,13 +7541,16 @@ pub struct EditMessageText {
 
 impl RObject for EditMessageText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editMessageText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditMessageText {}
+impl RFunction for EditMessageText {
+  type Response = Message;
+}
 
 impl EditMessageText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6571,6 +7578,11 @@ pub struct RTDEditMessageTextBuilder {
 impl RTDEditMessageTextBuilder {
   pub fn build(&self) -> EditMessageText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6617,6 +7629,9 @@ pub struct EditProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy identifier
   proxy_id: i64,
   /// Proxy server IP address
@@ -6632,13 +7647,16 @@ pub struct EditProxy {
 
 impl RObject for EditProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "editProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EditProxy {}
+impl RFunction for EditProxy {
+  type Response = Proxy;
+}
 
 impl EditProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6668,6 +7686,11 @@ pub struct RTDEditProxyBuilder {
 impl RTDEditProxyBuilder {
   pub fn build(&self) -> EditProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
@@ -6720,6 +7743,9 @@ pub struct EnableProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy identifier
   proxy_id: i64,
   
@@ -6727,13 +7753,16 @@ pub struct EnableProxy {
 
 impl RObject for EnableProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "enableProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for EnableProxy {}
+impl RFunction for EnableProxy {
+  type Response = Ok;
+}
 
 impl EnableProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6755,6 +7784,11 @@ pub struct RTDEnableProxyBuilder {
 impl RTDEnableProxyBuilder {
   pub fn build(&self) -> EnableProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
@@ -6783,22 +7817,28 @@ pub struct FinishFileGeneration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identifier of the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] generation_id: i64,
   /// If set, means that file generation has failed and should be terminated
-  error: Error,
+  error: Option<Error>,
   
 }
 
 impl RObject for FinishFileGeneration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "finishFileGeneration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for FinishFileGeneration {}
+impl RFunction for FinishFileGeneration {
+  type Response = Ok;
+}
 
 impl FinishFileGeneration {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6808,9 +7848,9 @@ impl FinishFileGeneration {
     RTDFinishFileGenerationBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
-  pub fn error(&self) -> &Error { &self.error }
+  pub fn error(&self) -> &Option<Error> { &self.error }
 
 }
 
@@ -6822,15 +7862,20 @@ pub struct RTDFinishFileGenerationBuilder {
 impl RTDFinishFileGenerationBuilder {
   pub fn build(&self) -> FinishFileGeneration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
 
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
-    self.inner.error = error.as_ref().clone();
+    self.inner.error = Some(error.as_ref().clone());
     self
   }
 
@@ -6856,6 +7901,9 @@ pub struct ForwardMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to which to forward messages
   chat_id: i64,
   /// Identifier of the chat from which to forward messages
@@ -6875,13 +7923,16 @@ pub struct ForwardMessages {
 
 impl RObject for ForwardMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "forwardMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ForwardMessages {}
+impl RFunction for ForwardMessages {
+  type Response = Messages;
+}
 
 impl ForwardMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6915,6 +7966,11 @@ pub struct RTDForwardMessagesBuilder {
 impl RTDForwardMessagesBuilder {
   pub fn build(&self) -> ForwardMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6979,6 +8035,9 @@ pub struct GenerateChatInviteLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -6986,13 +8045,16 @@ pub struct GenerateChatInviteLink {
 
 impl RObject for GenerateChatInviteLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "generateChatInviteLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GenerateChatInviteLink {}
+impl RFunction for GenerateChatInviteLink {
+  type Response = ChatInviteLink;
+}
 
 impl GenerateChatInviteLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7014,6 +8076,11 @@ pub struct RTDGenerateChatInviteLinkBuilder {
 impl RTDGenerateChatInviteLinkBuilder {
   pub fn build(&self) -> GenerateChatInviteLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -7042,18 +8109,24 @@ pub struct GetAccountTtl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetAccountTtl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getAccountTtl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetAccountTtl {}
+impl RFunction for GetAccountTtl {
+  type Response = AccountTtl;
+}
 
 impl GetAccountTtl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7073,6 +8146,11 @@ pub struct RTDGetAccountTtlBuilder {
 impl RTDGetAccountTtlBuilder {
   pub fn build(&self) -> GetAccountTtl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetAccountTtl> for GetAccountTtl {
@@ -7095,18 +8173,24 @@ pub struct GetActiveLiveLocationMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetActiveLiveLocationMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getActiveLiveLocationMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetActiveLiveLocationMessages {}
+impl RFunction for GetActiveLiveLocationMessages {
+  type Response = Messages;
+}
 
 impl GetActiveLiveLocationMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7126,6 +8210,11 @@ pub struct RTDGetActiveLiveLocationMessagesBuilder {
 impl RTDGetActiveLiveLocationMessagesBuilder {
   pub fn build(&self) -> GetActiveLiveLocationMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetActiveLiveLocationMessages> for GetActiveLiveLocationMessages {
@@ -7148,18 +8237,24 @@ pub struct GetActiveSessions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetActiveSessions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getActiveSessions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetActiveSessions {}
+impl RFunction for GetActiveSessions {
+  type Response = Sessions;
+}
 
 impl GetActiveSessions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7179,6 +8274,11 @@ pub struct RTDGetActiveSessionsBuilder {
 impl RTDGetActiveSessionsBuilder {
   pub fn build(&self) -> GetActiveSessions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetActiveSessions> for GetActiveSessions {
@@ -7201,6 +8301,9 @@ pub struct GetAllPassportElements {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Password of the current user
   password: String,
   
@@ -7208,13 +8311,16 @@ pub struct GetAllPassportElements {
 
 impl RObject for GetAllPassportElements {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getAllPassportElements" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetAllPassportElements {}
+impl RFunction for GetAllPassportElements {
+  type Response = PassportElements;
+}
 
 impl GetAllPassportElements {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7236,7 +8342,12 @@ pub struct RTDGetAllPassportElementsBuilder {
 impl RTDGetAllPassportElementsBuilder {
   pub fn build(&self) -> GetAllPassportElements { self.inner.clone() }
 
-   
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
+   
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
     self
@@ -7264,18 +8375,24 @@ pub struct GetApplicationConfig {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetApplicationConfig {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getApplicationConfig" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDJsonValue for GetApplicationConfig {}
 
-impl RFunction for GetApplicationConfig {}
+
+impl RFunction for GetApplicationConfig {
+  type Response = JsonValue;
+}
 
 impl GetApplicationConfig {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7295,6 +8412,11 @@ pub struct RTDGetApplicationConfigBuilder {
 impl RTDGetApplicationConfigBuilder {
   pub fn build(&self) -> GetApplicationConfig { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetApplicationConfig> for GetApplicationConfig {
@@ -7317,10 +8439,13 @@ pub struct GetArchivedStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to return mask stickers sets; pass false to return ordinary sticker sets
   is_masks: bool,
   /// Identifier of the sticker set from which to return the result
-  offset_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] offset_sticker_set_id: i64,
   /// The maximum number of sticker sets to return
   limit: i64,
   
@@ -7328,13 +8453,16 @@ pub struct GetArchivedStickerSets {
 
 impl RObject for GetArchivedStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getArchivedStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetArchivedStickerSets {}
+impl RFunction for GetArchivedStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetArchivedStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7346,7 +8474,7 @@ impl GetArchivedStickerSets {
 
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn offset_sticker_set_id(&self) -> isize { self.offset_sticker_set_id }
+  pub fn offset_sticker_set_id(&self) -> i64 { self.offset_sticker_set_id }
 
   pub fn limit(&self) -> i64 { self.limit }
 
@@ -7360,6 +8488,11 @@ pub struct RTDGetArchivedStickerSetsBuilder {
 impl RTDGetArchivedStickerSetsBuilder {
   pub fn build(&self) -> GetArchivedStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -7367,7 +8500,7 @@ impl RTDGetArchivedStickerSetsBuilder {
   }
 
    
-  pub fn offset_sticker_set_id(&mut self, offset_sticker_set_id: isize) -> &mut Self {
+  pub fn offset_sticker_set_id(&mut self, offset_sticker_set_id: i64) -> &mut Self {
     self.inner.offset_sticker_set_id = offset_sticker_set_id;
     self
   }
@@ -7400,6 +8533,9 @@ pub struct GetAttachedStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// File identifier
   file_id: i64,
   
@@ -7407,13 +8543,16 @@ pub struct GetAttachedStickerSets {
 
 impl RObject for GetAttachedStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getAttachedStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetAttachedStickerSets {}
+impl RFunction for GetAttachedStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetAttachedStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7435,6 +8574,11 @@ pub struct RTDGetAttachedStickerSetsBuilder {
 impl RTDGetAttachedStickerSetsBuilder {
   pub fn build(&self) -> GetAttachedStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -7463,18 +8607,24 @@ pub struct GetAuthorizationState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetAuthorizationState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getAuthorizationState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDAuthorizationState for GetAuthorizationState {}
 
-impl RFunction for GetAuthorizationState {}
+
+impl RFunction for GetAuthorizationState {
+  type Response = AuthorizationState;
+}
 
 impl GetAuthorizationState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7494,6 +8644,11 @@ pub struct RTDGetAuthorizationStateBuilder {
 impl RTDGetAuthorizationStateBuilder {
   pub fn build(&self) -> GetAuthorizationState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetAuthorizationState> for GetAuthorizationState {
@@ -7516,18 +8671,24 @@ pub struct GetAutoDownloadSettingsPresets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetAutoDownloadSettingsPresets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getAutoDownloadSettingsPresets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetAutoDownloadSettingsPresets {}
+impl RFunction for GetAutoDownloadSettingsPresets {
+  type Response = AutoDownloadSettingsPresets;
+}
 
 impl GetAutoDownloadSettingsPresets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7547,6 +8708,11 @@ pub struct RTDGetAutoDownloadSettingsPresetsBuilder {
 impl RTDGetAutoDownloadSettingsPresetsBuilder {
   pub fn build(&self) -> GetAutoDownloadSettingsPresets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetAutoDownloadSettingsPresets> for GetAutoDownloadSettingsPresets {
@@ -7569,6 +8735,9 @@ pub struct GetBackgroundUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Background name
   name: String,
   /// Background type
@@ -7578,13 +8747,16 @@ pub struct GetBackgroundUrl {
 
 impl RObject for GetBackgroundUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getBackgroundUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetBackgroundUrl {}
+impl RFunction for GetBackgroundUrl {
+  type Response = HttpUrl;
+}
 
 impl GetBackgroundUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7608,6 +8780,11 @@ pub struct RTDGetBackgroundUrlBuilder {
 impl RTDGetBackgroundUrlBuilder {
   pub fn build(&self) -> GetBackgroundUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -7642,6 +8819,9 @@ pub struct GetBackgrounds {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the backgrounds needs to be ordered for dark theme
   for_dark_theme: bool,
   
@@ -7649,13 +8829,16 @@ pub struct GetBackgrounds {
 
 impl RObject for GetBackgrounds {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getBackgrounds" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetBackgrounds {}
+impl RFunction for GetBackgrounds {
+  type Response = Backgrounds;
+}
 
 impl GetBackgrounds {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7677,6 +8860,11 @@ pub struct RTDGetBackgroundsBuilder {
 impl RTDGetBackgroundsBuilder {
   pub fn build(&self) -> GetBackgrounds { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn for_dark_theme(&mut self, for_dark_theme: bool) -> &mut Self {
     self.inner.for_dark_theme = for_dark_theme;
@@ -7705,6 +8893,9 @@ pub struct GetBankCardInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The bank card number
   bank_card_number: String,
   
@@ -7712,13 +8903,16 @@ pub struct GetBankCardInfo {
 
 impl RObject for GetBankCardInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getBankCardInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetBankCardInfo {}
+impl RFunction for GetBankCardInfo {
+  type Response = BankCardInfo;
+}
 
 impl GetBankCardInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7740,6 +8934,11 @@ pub struct RTDGetBankCardInfoBuilder {
 impl RTDGetBankCardInfoBuilder {
   pub fn build(&self) -> GetBankCardInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bank_card_number<T: AsRef<str>>(&mut self, bank_card_number: T) -> &mut Self {
     self.inner.bank_card_number = bank_card_number.as_ref().to_string();
@@ -7768,6 +8967,9 @@ pub struct GetBasicGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Basic group identifier
   basic_group_id: i64,
   
@@ -7775,13 +8977,16 @@ pub struct GetBasicGroup {
 
 impl RObject for GetBasicGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getBasicGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetBasicGroup {}
+impl RFunction for GetBasicGroup {
+  type Response = BasicGroup;
+}
 
 impl GetBasicGroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7803,6 +9008,11 @@ pub struct RTDGetBasicGroupBuilder {
 impl RTDGetBasicGroupBuilder {
   pub fn build(&self) -> GetBasicGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
@@ -7831,6 +9041,9 @@ pub struct GetBasicGroupFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Basic group identifier
   basic_group_id: i64,
   
@@ -7838,13 +9051,16 @@ pub struct GetBasicGroupFullInfo {
 
 impl RObject for GetBasicGroupFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getBasicGroupFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetBasicGroupFullInfo {}
+impl RFunction for GetBasicGroupFullInfo {
+  type Response = BasicGroupFullInfo;
+}
 
 impl GetBasicGroupFullInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7866,6 +9082,11 @@ pub struct RTDGetBasicGroupFullInfoBuilder {
 impl RTDGetBasicGroupFullInfoBuilder {
   pub fn build(&self) -> GetBasicGroupFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
@@ -7894,6 +9115,9 @@ pub struct GetBlockedUsers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Number of users to skip in the result; must be non-negative
   offset: i64,
   /// The maximum number of users to return; up to 100
@@ -7903,13 +9127,16 @@ pub struct GetBlockedUsers {
 
 impl RObject for GetBlockedUsers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getBlockedUsers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetBlockedUsers {}
+impl RFunction for GetBlockedUsers {
+  type Response = Users;
+}
 
 impl GetBlockedUsers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7933,6 +9160,11 @@ pub struct RTDGetBlockedUsersBuilder {
 impl RTDGetBlockedUsersBuilder {
   pub fn build(&self) -> GetBlockedUsers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
@@ -7967,6 +9199,9 @@ pub struct GetCallbackQueryAnswer {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat with the message
   chat_id: i64,
   /// Identifier of the message from which the query originated
@@ -7978,13 +9213,16 @@ pub struct GetCallbackQueryAnswer {
 
 impl RObject for GetCallbackQueryAnswer {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getCallbackQueryAnswer" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetCallbackQueryAnswer {}
+impl RFunction for GetCallbackQueryAnswer {
+  type Response = CallbackQueryAnswer;
+}
 
 impl GetCallbackQueryAnswer {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8010,6 +9248,11 @@ pub struct RTDGetCallbackQueryAnswerBuilder {
 impl RTDGetCallbackQueryAnswerBuilder {
   pub fn build(&self) -> GetCallbackQueryAnswer { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8050,6 +9293,9 @@ pub struct GetChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -8057,13 +9303,16 @@ pub struct GetChat {
 
 impl RObject for GetChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChat {}
+impl RFunction for GetChat {
+  type Response = Chat;
+}
 
 impl GetChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8085,6 +9334,11 @@ pub struct RTDGetChatBuilder {
 impl RTDGetChatBuilder {
   pub fn build(&self) -> GetChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8113,6 +9367,9 @@ pub struct GetChatAdministrators {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -8120,13 +9377,16 @@ pub struct GetChatAdministrators {
 
 impl RObject for GetChatAdministrators {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatAdministrators" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatAdministrators {}
+impl RFunction for GetChatAdministrators {
+  type Response = ChatAdministrators;
+}
 
 impl GetChatAdministrators {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8148,6 +9408,11 @@ pub struct RTDGetChatAdministratorsBuilder {
 impl RTDGetChatAdministratorsBuilder {
   pub fn build(&self) -> GetChatAdministrators { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8176,12 +9441,15 @@ pub struct GetChatEventLog {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Search query by which to filter events
   query: String,
   /// Identifier of an event from which to return results. Use 0 to get results from the latest events
-  from_event_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] from_event_id: i64,
   /// The maximum number of events to return; up to 100
   limit: i64,
   /// The types of events to return. By default, all types will be returned
@@ -8193,13 +9461,16 @@ pub struct GetChatEventLog {
 
 impl RObject for GetChatEventLog {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatEventLog" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatEventLog {}
+impl RFunction for GetChatEventLog {
+  type Response = ChatEvents;
+}
 
 impl GetChatEventLog {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8213,7 +9484,7 @@ impl GetChatEventLog {
 
   pub fn query(&self) -> &String { &self.query }
 
-  pub fn from_event_id(&self) -> isize { self.from_event_id }
+  pub fn from_event_id(&self) -> i64 { self.from_event_id }
 
   pub fn limit(&self) -> i64 { self.limit }
 
@@ -8231,6 +9502,11 @@ pub struct RTDGetChatEventLogBuilder {
 impl RTDGetChatEventLogBuilder {
   pub fn build(&self) -> GetChatEventLog { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8244,7 +9520,7 @@ impl RTDGetChatEventLogBuilder {
   }
 
    
-  pub fn from_event_id(&mut self, from_event_id: isize) -> &mut Self {
+  pub fn from_event_id(&mut self, from_event_id: i64) -> &mut Self {
     self.inner.from_event_id = from_event_id;
     self
   }
@@ -8289,6 +9565,9 @@ pub struct GetChatFilter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat filter identifier
   chat_filter_id: i64,
   
@@ -8296,13 +9575,16 @@ pub struct GetChatFilter {
 
 impl RObject for GetChatFilter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatFilter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatFilter {}
+impl RFunction for GetChatFilter {
+  type Response = ChatFilter;
+}
 
 impl GetChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8324,6 +9606,11 @@ pub struct RTDGetChatFilterBuilder {
 impl RTDGetChatFilterBuilder {
   pub fn build(&self) -> GetChatFilter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
@@ -8352,6 +9639,9 @@ pub struct GetChatFilterDefaultIconName {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat filter
   filter: ChatFilter,
   
@@ -8359,13 +9649,16 @@ pub struct GetChatFilterDefaultIconName {
 
 impl RObject for GetChatFilterDefaultIconName {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatFilterDefaultIconName" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatFilterDefaultIconName {}
+impl RFunction for GetChatFilterDefaultIconName {
+  type Response = Text;
+}
 
 impl GetChatFilterDefaultIconName {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8387,6 +9680,11 @@ pub struct RTDGetChatFilterDefaultIconNameBuilder {
 impl RTDGetChatFilterDefaultIconNameBuilder {
   pub fn build(&self) -> GetChatFilterDefaultIconName { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
@@ -8415,6 +9713,9 @@ pub struct GetChatHistory {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of the message starting from which history must be fetched; use 0 to get results from the last message
@@ -8430,13 +9731,16 @@ pub struct GetChatHistory {
 
 impl RObject for GetChatHistory {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatHistory" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatHistory {}
+impl RFunction for GetChatHistory {
+  type Response = Messages;
+}
 
 impl GetChatHistory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8466,6 +9770,11 @@ pub struct RTDGetChatHistoryBuilder {
 impl RTDGetChatHistoryBuilder {
   pub fn build(&self) -> GetChatHistory { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8518,6 +9827,9 @@ pub struct GetChatListsToAddChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -8525,13 +9837,16 @@ pub struct GetChatListsToAddChat {
 
 impl RObject for GetChatListsToAddChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatListsToAddChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatListsToAddChat {}
+impl RFunction for GetChatListsToAddChat {
+  type Response = ChatLists;
+}
 
 impl GetChatListsToAddChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8553,6 +9868,11 @@ pub struct RTDGetChatListsToAddChatBuilder {
 impl RTDGetChatListsToAddChatBuilder {
   pub fn build(&self) -> GetChatListsToAddChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8581,6 +9901,9 @@ pub struct GetChatMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// User identifier
@@ -8590,13 +9913,16 @@ pub struct GetChatMember {
 
 impl RObject for GetChatMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatMember {}
+impl RFunction for GetChatMember {
+  type Response = ChatMember;
+}
 
 impl GetChatMember {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8620,6 +9946,11 @@ pub struct RTDGetChatMemberBuilder {
 impl RTDGetChatMemberBuilder {
   pub fn build(&self) -> GetChatMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8654,6 +9985,9 @@ pub struct GetChatMessageByDate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Point in time (Unix timestamp) relative to which to search for messages
@@ -8663,13 +9997,16 @@ pub struct GetChatMessageByDate {
 
 impl RObject for GetChatMessageByDate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatMessageByDate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatMessageByDate {}
+impl RFunction for GetChatMessageByDate {
+  type Response = Message;
+}
 
 impl GetChatMessageByDate {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8693,6 +10030,11 @@ pub struct RTDGetChatMessageByDateBuilder {
 impl RTDGetChatMessageByDateBuilder {
   pub fn build(&self) -> GetChatMessageByDate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8727,6 +10069,9 @@ pub struct GetChatMessageCount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat in which to count messages
   chat_id: i64,
   /// Filter for message content; searchMessagesFilterEmpty is unsupported in this function
@@ -8738,13 +10083,16 @@ pub struct GetChatMessageCount {
 
 impl RObject for GetChatMessageCount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatMessageCount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatMessageCount {}
+impl RFunction for GetChatMessageCount {
+  type Response = Count;
+}
 
 impl GetChatMessageCount {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8770,6 +10118,11 @@ pub struct RTDGetChatMessageCountBuilder {
 impl RTDGetChatMessageCountBuilder {
   pub fn build(&self) -> GetChatMessageCount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8810,6 +10163,9 @@ pub struct GetChatNotificationSettingsExceptions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If specified, only chats from the specified scope will be returned
   scope: NotificationSettingsScope,
   /// If true, also chats with non-default sound will be returned
@@ -8819,13 +10175,16 @@ pub struct GetChatNotificationSettingsExceptions {
 
 impl RObject for GetChatNotificationSettingsExceptions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatNotificationSettingsExceptions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatNotificationSettingsExceptions {}
+impl RFunction for GetChatNotificationSettingsExceptions {
+  type Response = Chats;
+}
 
 impl GetChatNotificationSettingsExceptions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8849,6 +10208,11 @@ pub struct RTDGetChatNotificationSettingsExceptionsBuilder {
 impl RTDGetChatNotificationSettingsExceptionsBuilder {
   pub fn build(&self) -> GetChatNotificationSettingsExceptions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -8883,6 +10247,9 @@ pub struct GetChatPinnedMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat the message belongs to
   chat_id: i64,
   
@@ -8890,13 +10257,16 @@ pub struct GetChatPinnedMessage {
 
 impl RObject for GetChatPinnedMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatPinnedMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatPinnedMessage {}
+impl RFunction for GetChatPinnedMessage {
+  type Response = Message;
+}
 
 impl GetChatPinnedMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8918,6 +10288,11 @@ pub struct RTDGetChatPinnedMessageBuilder {
 impl RTDGetChatPinnedMessageBuilder {
   pub fn build(&self) -> GetChatPinnedMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -8946,6 +10321,9 @@ pub struct GetChatScheduledMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -8953,13 +10331,16 @@ pub struct GetChatScheduledMessages {
 
 impl RObject for GetChatScheduledMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatScheduledMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatScheduledMessages {}
+impl RFunction for GetChatScheduledMessages {
+  type Response = Messages;
+}
 
 impl GetChatScheduledMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8981,6 +10362,11 @@ pub struct RTDGetChatScheduledMessagesBuilder {
 impl RTDGetChatScheduledMessagesBuilder {
   pub fn build(&self) -> GetChatScheduledMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -9009,6 +10395,9 @@ pub struct GetChatStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Pass true if a dark theme is used by the app
@@ -9018,13 +10407,16 @@ pub struct GetChatStatistics {
 
 impl RObject for GetChatStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDChatStatistics for GetChatStatistics {}
 
-impl RFunction for GetChatStatistics {}
+
+impl RFunction for GetChatStatistics {
+  type Response = ChatStatistics;
+}
 
 impl GetChatStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9048,6 +10440,11 @@ pub struct RTDGetChatStatisticsBuilder {
 impl RTDGetChatStatisticsBuilder {
   pub fn build(&self) -> GetChatStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -9082,6 +10479,9 @@ pub struct GetChatStatisticsGraph {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The token for graph loading
@@ -9093,13 +10493,16 @@ pub struct GetChatStatisticsGraph {
 
 impl RObject for GetChatStatisticsGraph {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatStatisticsGraph" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDStatisticsGraph for GetChatStatisticsGraph {}
 
-impl RFunction for GetChatStatisticsGraph {}
+
+impl RFunction for GetChatStatisticsGraph {
+  type Response = StatisticsGraph;
+}
 
 impl GetChatStatisticsGraph {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9125,6 +10528,11 @@ pub struct RTDGetChatStatisticsGraphBuilder {
 impl RTDGetChatStatisticsGraphBuilder {
   pub fn build(&self) -> GetChatStatisticsGraph { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -9165,6 +10573,9 @@ pub struct GetChatStatisticsUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Parameters from "tg://statsrefresh?params=******" link
@@ -9176,13 +10587,16 @@ pub struct GetChatStatisticsUrl {
 
 impl RObject for GetChatStatisticsUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChatStatisticsUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChatStatisticsUrl {}
+impl RFunction for GetChatStatisticsUrl {
+  type Response = HttpUrl;
+}
 
 impl GetChatStatisticsUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9208,6 +10622,11 @@ pub struct RTDGetChatStatisticsUrlBuilder {
 impl RTDGetChatStatisticsUrlBuilder {
   pub fn build(&self) -> GetChatStatisticsUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -9248,10 +10667,13 @@ pub struct GetChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat list in which to return chats
   chat_list: ChatList,
   /// Chat order to return chats from
-  offset_order: isize,
+  #[serde(with = "crate::types::_common::int64_str")] offset_order: i64,
   /// Chat identifier to return chats from
   offset_chat_id: i64,
   /// The maximum number of chats to be returned. It is possible that fewer chats than the limit are returned even if the end of the list is not reached
@@ -9261,13 +10683,16 @@ pub struct GetChats {
 
 impl RObject for GetChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetChats {}
+impl RFunction for GetChats {
+  type Response = Chats;
+}
 
 impl GetChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9279,7 +10704,7 @@ impl GetChats {
 
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
 
-  pub fn offset_order(&self) -> isize { self.offset_order }
+  pub fn offset_order(&self) -> i64 { self.offset_order }
 
   pub fn offset_chat_id(&self) -> i64 { self.offset_chat_id }
 
@@ -9295,6 +10720,11 @@ pub struct RTDGetChatsBuilder {
 impl RTDGetChatsBuilder {
   pub fn build(&self) -> GetChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -9302,7 +10732,7 @@ impl RTDGetChatsBuilder {
   }
 
    
-  pub fn offset_order(&mut self, offset_order: isize) -> &mut Self {
+  pub fn offset_order(&mut self, offset_order: i64) -> &mut Self {
     self.inner.offset_order = offset_order;
     self
   }
@@ -9341,18 +10771,24 @@ pub struct GetConnectedWebsites {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetConnectedWebsites {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getConnectedWebsites" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetConnectedWebsites {}
+impl RFunction for GetConnectedWebsites {
+  type Response = ConnectedWebsites;
+}
 
 impl GetConnectedWebsites {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9372,6 +10808,11 @@ pub struct RTDGetConnectedWebsitesBuilder {
 impl RTDGetConnectedWebsitesBuilder {
   pub fn build(&self) -> GetConnectedWebsites { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetConnectedWebsites> for GetConnectedWebsites {
@@ -9394,18 +10835,24 @@ pub struct GetContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetContacts {}
+impl RFunction for GetContacts {
+  type Response = Users;
+}
 
 impl GetContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9425,6 +10872,11 @@ pub struct RTDGetContactsBuilder {
 impl RTDGetContactsBuilder {
   pub fn build(&self) -> GetContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetContacts> for GetContacts {
@@ -9447,18 +10899,24 @@ pub struct GetCountryCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetCountryCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getCountryCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetCountryCode {}
+impl RFunction for GetCountryCode {
+  type Response = Text;
+}
 
 impl GetCountryCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9478,6 +10936,11 @@ pub struct RTDGetCountryCodeBuilder {
 impl RTDGetCountryCodeBuilder {
   pub fn build(&self) -> GetCountryCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetCountryCode> for GetCountryCode {
@@ -9500,6 +10963,9 @@ pub struct GetCreatedPublicChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of the public chats to return
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PublicChatType,
   
@@ -9507,13 +10973,16 @@ pub struct GetCreatedPublicChats {
 
 impl RObject for GetCreatedPublicChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getCreatedPublicChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetCreatedPublicChats {}
+impl RFunction for GetCreatedPublicChats {
+  type Response = Chats;
+}
 
 impl GetCreatedPublicChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9535,6 +11004,11 @@ pub struct RTDGetCreatedPublicChatsBuilder {
 impl RTDGetCreatedPublicChatsBuilder {
   pub fn build(&self) -> GetCreatedPublicChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PublicChatType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -9563,18 +11037,24 @@ pub struct GetCurrentState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetCurrentState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getCurrentState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetCurrentState {}
+impl RFunction for GetCurrentState {
+  type Response = Updates;
+}
 
 impl GetCurrentState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9594,6 +11074,11 @@ pub struct RTDGetCurrentStateBuilder {
 impl RTDGetCurrentStateBuilder {
   pub fn build(&self) -> GetCurrentState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetCurrentState> for GetCurrentState {
@@ -9616,18 +11101,24 @@ pub struct GetDatabaseStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetDatabaseStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getDatabaseStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetDatabaseStatistics {}
+impl RFunction for GetDatabaseStatistics {
+  type Response = DatabaseStatistics;
+}
 
 impl GetDatabaseStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9647,6 +11138,11 @@ pub struct RTDGetDatabaseStatisticsBuilder {
 impl RTDGetDatabaseStatisticsBuilder {
   pub fn build(&self) -> GetDatabaseStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetDatabaseStatistics> for GetDatabaseStatistics {
@@ -9669,6 +11165,9 @@ pub struct GetDeepLinkInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The link
   link: String,
   
@@ -9676,13 +11175,16 @@ pub struct GetDeepLinkInfo {
 
 impl RObject for GetDeepLinkInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getDeepLinkInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetDeepLinkInfo {}
+impl RFunction for GetDeepLinkInfo {
+  type Response = DeepLinkInfo;
+}
 
 impl GetDeepLinkInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9704,6 +11206,11 @@ pub struct RTDGetDeepLinkInfoBuilder {
 impl RTDGetDeepLinkInfoBuilder {
   pub fn build(&self) -> GetDeepLinkInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
@@ -9732,6 +11239,9 @@ pub struct GetEmojiSuggestionsUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Language code for which the emoji replacements will be suggested
   language_code: String,
   
@@ -9739,13 +11249,16 @@ pub struct GetEmojiSuggestionsUrl {
 
 impl RObject for GetEmojiSuggestionsUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getEmojiSuggestionsUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetEmojiSuggestionsUrl {}
+impl RFunction for GetEmojiSuggestionsUrl {
+  type Response = HttpUrl;
+}
 
 impl GetEmojiSuggestionsUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9767,6 +11280,11 @@ pub struct RTDGetEmojiSuggestionsUrlBuilder {
 impl RTDGetEmojiSuggestionsUrlBuilder {
   pub fn build(&self) -> GetEmojiSuggestionsUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_code<T: AsRef<str>>(&mut self, language_code: T) -> &mut Self {
     self.inner.language_code = language_code.as_ref().to_string();
@@ -9795,18 +11313,24 @@ pub struct GetFavoriteStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetFavoriteStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getFavoriteStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetFavoriteStickers {}
+impl RFunction for GetFavoriteStickers {
+  type Response = Stickers;
+}
 
 impl GetFavoriteStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9826,6 +11350,11 @@ pub struct RTDGetFavoriteStickersBuilder {
 impl RTDGetFavoriteStickersBuilder {
   pub fn build(&self) -> GetFavoriteStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetFavoriteStickers> for GetFavoriteStickers {
@@ -9848,6 +11377,9 @@ pub struct GetFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the file to get
   file_id: i64,
   
@@ -9855,13 +11387,16 @@ pub struct GetFile {
 
 impl RObject for GetFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetFile {}
+impl RFunction for GetFile {
+  type Response = File;
+}
 
 impl GetFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9883,6 +11418,11 @@ pub struct RTDGetFileBuilder {
 impl RTDGetFileBuilder {
   pub fn build(&self) -> GetFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -9911,6 +11451,9 @@ pub struct GetFileDownloadedPrefixSize {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the file
   file_id: i64,
   /// Offset from which downloaded prefix size should be calculated
@@ -9920,13 +11463,16 @@ pub struct GetFileDownloadedPrefixSize {
 
 impl RObject for GetFileDownloadedPrefixSize {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getFileDownloadedPrefixSize" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetFileDownloadedPrefixSize {}
+impl RFunction for GetFileDownloadedPrefixSize {
+  type Response = Count;
+}
 
 impl GetFileDownloadedPrefixSize {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9950,6 +11496,11 @@ pub struct RTDGetFileDownloadedPrefixSizeBuilder {
 impl RTDGetFileDownloadedPrefixSizeBuilder {
   pub fn build(&self) -> GetFileDownloadedPrefixSize { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -9984,6 +11535,9 @@ pub struct GetFileExtension {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The MIME type of the file
   mime_type: String,
   
@@ -9991,13 +11545,16 @@ pub struct GetFileExtension {
 
 impl RObject for GetFileExtension {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getFileExtension" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetFileExtension {}
+impl RFunction for GetFileExtension {
+  type Response = Text;
+}
 
 impl GetFileExtension {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10019,6 +11576,11 @@ pub struct RTDGetFileExtensionBuilder {
 impl RTDGetFileExtensionBuilder {
   pub fn build(&self) -> GetFileExtension { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
@@ -10047,6 +11609,9 @@ pub struct GetFileMimeType {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The name of the file or path to the file
   file_name: String,
   
@@ -10054,13 +11619,16 @@ pub struct GetFileMimeType {
 
 impl RObject for GetFileMimeType {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getFileMimeType" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetFileMimeType {}
+impl RFunction for GetFileMimeType {
+  type Response = Text;
+}
 
 impl GetFileMimeType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10082,6 +11650,11 @@ pub struct RTDGetFileMimeTypeBuilder {
 impl RTDGetFileMimeTypeBuilder {
   pub fn build(&self) -> GetFileMimeType { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
@@ -10110,6 +11683,9 @@ pub struct GetGameHighScores {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat that contains the message with the game
   chat_id: i64,
   /// Identifier of the message
@@ -10121,13 +11697,16 @@ pub struct GetGameHighScores {
 
 impl RObject for GetGameHighScores {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getGameHighScores" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetGameHighScores {}
+impl RFunction for GetGameHighScores {
+  type Response = GameHighScores;
+}
 
 impl GetGameHighScores {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10153,6 +11732,11 @@ pub struct RTDGetGameHighScoresBuilder {
 impl RTDGetGameHighScoresBuilder {
   pub fn build(&self) -> GetGameHighScores { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -10193,6 +11777,9 @@ pub struct GetGroupsInCommon {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// Chat identifier starting from which to return chats; use 0 for the first request
@@ -10204,13 +11791,16 @@ pub struct GetGroupsInCommon {
 
 impl RObject for GetGroupsInCommon {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getGroupsInCommon" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetGroupsInCommon {}
+impl RFunction for GetGroupsInCommon {
+  type Response = Chats;
+}
 
 impl GetGroupsInCommon {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10236,6 +11826,11 @@ pub struct RTDGetGroupsInCommonBuilder {
 impl RTDGetGroupsInCommonBuilder {
   pub fn build(&self) -> GetGroupsInCommon { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -10276,18 +11871,24 @@ pub struct GetImportedContactCount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetImportedContactCount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getImportedContactCount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetImportedContactCount {}
+impl RFunction for GetImportedContactCount {
+  type Response = Count;
+}
 
 impl GetImportedContactCount {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10307,6 +11908,11 @@ pub struct RTDGetImportedContactCountBuilder {
 impl RTDGetImportedContactCountBuilder {
   pub fn build(&self) -> GetImportedContactCount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetImportedContactCount> for GetImportedContactCount {
@@ -10329,18 +11935,24 @@ pub struct GetInactiveSupergroupChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetInactiveSupergroupChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getInactiveSupergroupChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetInactiveSupergroupChats {}
+impl RFunction for GetInactiveSupergroupChats {
+  type Response = Chats;
+}
 
 impl GetInactiveSupergroupChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10360,6 +11972,11 @@ pub struct RTDGetInactiveSupergroupChatsBuilder {
 impl RTDGetInactiveSupergroupChatsBuilder {
   pub fn build(&self) -> GetInactiveSupergroupChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetInactiveSupergroupChats> for GetInactiveSupergroupChats {
@@ -10382,6 +11999,9 @@ pub struct GetInlineGameHighScores {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// User identifier
@@ -10391,13 +12011,16 @@ pub struct GetInlineGameHighScores {
 
 impl RObject for GetInlineGameHighScores {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getInlineGameHighScores" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetInlineGameHighScores {}
+impl RFunction for GetInlineGameHighScores {
+  type Response = GameHighScores;
+}
 
 impl GetInlineGameHighScores {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10421,6 +12044,11 @@ pub struct RTDGetInlineGameHighScoresBuilder {
 impl RTDGetInlineGameHighScoresBuilder {
   pub fn build(&self) -> GetInlineGameHighScores { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -10455,6 +12083,9 @@ pub struct GetInlineQueryResults {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identifier of the target bot
   bot_user_id: i64,
   /// Identifier of the chat where the query was sent
@@ -10470,13 +12101,16 @@ pub struct GetInlineQueryResults {
 
 impl RObject for GetInlineQueryResults {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getInlineQueryResults" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetInlineQueryResults {}
+impl RFunction for GetInlineQueryResults {
+  type Response = InlineQueryResults;
+}
 
 impl GetInlineQueryResults {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10506,6 +12140,11 @@ pub struct RTDGetInlineQueryResultsBuilder {
 impl RTDGetInlineQueryResultsBuilder {
   pub fn build(&self) -> GetInlineQueryResults { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
@@ -10558,6 +12197,9 @@ pub struct GetInstalledStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to return mask sticker sets; pass false to return ordinary sticker sets
   is_masks: bool,
   
@@ -10565,13 +12207,16 @@ pub struct GetInstalledStickerSets {
 
 impl RObject for GetInstalledStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getInstalledStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetInstalledStickerSets {}
+impl RFunction for GetInstalledStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetInstalledStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10593,6 +12238,11 @@ pub struct RTDGetInstalledStickerSetsBuilder {
 impl RTDGetInstalledStickerSetsBuilder {
   pub fn build(&self) -> GetInstalledStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -10621,18 +12271,24 @@ pub struct GetInviteText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetInviteText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getInviteText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetInviteText {}
+impl RFunction for GetInviteText {
+  type Response = Text;
+}
 
 impl GetInviteText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10652,6 +12308,11 @@ pub struct RTDGetInviteTextBuilder {
 impl RTDGetInviteTextBuilder {
   pub fn build(&self) -> GetInviteText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetInviteText> for GetInviteText {
@@ -10674,6 +12335,9 @@ pub struct GetJsonString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The JsonValue object
   json_value: JsonValue,
   
@@ -10681,13 +12345,16 @@ pub struct GetJsonString {
 
 impl RObject for GetJsonString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getJsonString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetJsonString {}
+impl RFunction for GetJsonString {
+  type Response = Text;
+}
 
 impl GetJsonString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10709,6 +12376,11 @@ pub struct RTDGetJsonStringBuilder {
 impl RTDGetJsonStringBuilder {
   pub fn build(&self) -> GetJsonString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn json_value<T: AsRef<JsonValue>>(&mut self, json_value: T) -> &mut Self {
     self.inner.json_value = json_value.as_ref().clone();
@@ -10737,6 +12409,9 @@ pub struct GetJsonValue {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The JSON-serialized string
   json: String,
   
@@ -10744,13 +12419,16 @@ pub struct GetJsonValue {
 
 impl RObject for GetJsonValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getJsonValue" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDJsonValue for GetJsonValue {}
 
-impl RFunction for GetJsonValue {}
+
+impl RFunction for GetJsonValue {
+  type Response = JsonValue;
+}
 
 impl GetJsonValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10772,6 +12450,11 @@ pub struct RTDGetJsonValueBuilder {
 impl RTDGetJsonValueBuilder {
   pub fn build(&self) -> GetJsonValue { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn json<T: AsRef<str>>(&mut self, json: T) -> &mut Self {
     self.inner.json = json.as_ref().to_string();
@@ -10800,6 +12483,9 @@ pub struct GetLanguagePackInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Language pack identifier
   language_pack_id: String,
   
@@ -10807,13 +12493,16 @@ pub struct GetLanguagePackInfo {
 
 impl RObject for GetLanguagePackInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLanguagePackInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLanguagePackInfo {}
+impl RFunction for GetLanguagePackInfo {
+  type Response = LanguagePackInfo;
+}
 
 impl GetLanguagePackInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10835,6 +12524,11 @@ pub struct RTDGetLanguagePackInfoBuilder {
 impl RTDGetLanguagePackInfoBuilder {
   pub fn build(&self) -> GetLanguagePackInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -10863,6 +12557,9 @@ pub struct GetLanguagePackString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Path to the language pack database in which strings are stored
   language_pack_database_path: String,
   /// Localization target to which the language pack belongs
@@ -10876,13 +12573,16 @@ pub struct GetLanguagePackString {
 
 impl RObject for GetLanguagePackString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLanguagePackString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDLanguagePackStringValue for GetLanguagePackString {}
 
-impl RFunction for GetLanguagePackString {}
+
+impl RFunction for GetLanguagePackString {
+  type Response = LanguagePackStringValue;
+}
 
 impl GetLanguagePackString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10910,6 +12610,11 @@ pub struct RTDGetLanguagePackStringBuilder {
 impl RTDGetLanguagePackStringBuilder {
   pub fn build(&self) -> GetLanguagePackString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_database_path<T: AsRef<str>>(&mut self, language_pack_database_path: T) -> &mut Self {
     self.inner.language_pack_database_path = language_pack_database_path.as_ref().to_string();
@@ -10956,6 +12661,9 @@ pub struct GetLanguagePackStrings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Language pack identifier of the strings to be returned
   language_pack_id: String,
   /// Language pack keys of the strings to be returned; leave empty to request all available strings
@@ -10965,13 +12673,16 @@ pub struct GetLanguagePackStrings {
 
 impl RObject for GetLanguagePackStrings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLanguagePackStrings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLanguagePackStrings {}
+impl RFunction for GetLanguagePackStrings {
+  type Response = LanguagePackStrings;
+}
 
 impl GetLanguagePackStrings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10995,6 +12706,11 @@ pub struct RTDGetLanguagePackStringsBuilder {
 impl RTDGetLanguagePackStringsBuilder {
   pub fn build(&self) -> GetLanguagePackStrings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -11029,6 +12745,9 @@ pub struct GetLocalizationTargetInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, returns only locally available information without sending network requests
   only_local: bool,
   
@@ -11036,13 +12755,16 @@ pub struct GetLocalizationTargetInfo {
 
 impl RObject for GetLocalizationTargetInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLocalizationTargetInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLocalizationTargetInfo {}
+impl RFunction for GetLocalizationTargetInfo {
+  type Response = LocalizationTargetInfo;
+}
 
 impl GetLocalizationTargetInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11064,6 +12786,11 @@ pub struct RTDGetLocalizationTargetInfoBuilder {
 impl RTDGetLocalizationTargetInfoBuilder {
   pub fn build(&self) -> GetLocalizationTargetInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn only_local(&mut self, only_local: bool) -> &mut Self {
     self.inner.only_local = only_local;
@@ -11092,18 +12819,24 @@ pub struct GetLogStream {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetLogStream {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLogStream" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDLogStream for GetLogStream {}
 
-impl RFunction for GetLogStream {}
+
+impl RFunction for GetLogStream {
+  type Response = LogStream;
+}
 
 impl GetLogStream {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11123,6 +12856,11 @@ pub struct RTDGetLogStreamBuilder {
 impl RTDGetLogStreamBuilder {
   pub fn build(&self) -> GetLogStream { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetLogStream> for GetLogStream {
@@ -11145,6 +12883,9 @@ pub struct GetLogTagVerbosityLevel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Logging tag to change verbosity level
   tag: String,
   
@@ -11152,13 +12893,16 @@ pub struct GetLogTagVerbosityLevel {
 
 impl RObject for GetLogTagVerbosityLevel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLogTagVerbosityLevel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLogTagVerbosityLevel {}
+impl RFunction for GetLogTagVerbosityLevel {
+  type Response = LogVerbosityLevel;
+}
 
 impl GetLogTagVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11180,6 +12924,11 @@ pub struct RTDGetLogTagVerbosityLevelBuilder {
 impl RTDGetLogTagVerbosityLevelBuilder {
   pub fn build(&self) -> GetLogTagVerbosityLevel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn tag<T: AsRef<str>>(&mut self, tag: T) -> &mut Self {
     self.inner.tag = tag.as_ref().to_string();
@@ -11208,18 +12957,24 @@ pub struct GetLogTags {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetLogTags {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLogTags" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLogTags {}
+impl RFunction for GetLogTags {
+  type Response = LogTags;
+}
 
 impl GetLogTags {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11239,6 +12994,11 @@ pub struct RTDGetLogTagsBuilder {
 impl RTDGetLogTagsBuilder {
   pub fn build(&self) -> GetLogTags { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetLogTags> for GetLogTags {
@@ -11261,18 +13021,24 @@ pub struct GetLogVerbosityLevel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetLogVerbosityLevel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLogVerbosityLevel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLogVerbosityLevel {}
+impl RFunction for GetLogVerbosityLevel {
+  type Response = LogVerbosityLevel;
+}
 
 impl GetLogVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11292,6 +13058,11 @@ pub struct RTDGetLogVerbosityLevelBuilder {
 impl RTDGetLogVerbosityLevelBuilder {
   pub fn build(&self) -> GetLogVerbosityLevel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetLogVerbosityLevel> for GetLogVerbosityLevel {
@@ -11314,6 +13085,9 @@ pub struct GetLoginUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the message with the button
   chat_id: i64,
   /// Message identifier of the message with the button
@@ -11327,13 +13101,16 @@ pub struct GetLoginUrl {
 
 impl RObject for GetLoginUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLoginUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetLoginUrl {}
+impl RFunction for GetLoginUrl {
+  type Response = HttpUrl;
+}
 
 impl GetLoginUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11361,6 +13138,11 @@ pub struct RTDGetLoginUrlBuilder {
 impl RTDGetLoginUrlBuilder {
   pub fn build(&self) -> GetLoginUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -11407,6 +13189,9 @@ pub struct GetLoginUrlInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the message with the button
   chat_id: i64,
   /// Message identifier of the message with the button
@@ -11418,13 +13203,16 @@ pub struct GetLoginUrlInfo {
 
 impl RObject for GetLoginUrlInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getLoginUrlInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDLoginUrlInfo for GetLoginUrlInfo {}
 
-impl RFunction for GetLoginUrlInfo {}
+
+impl RFunction for GetLoginUrlInfo {
+  type Response = LoginUrlInfo;
+}
 
 impl GetLoginUrlInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11450,6 +13238,11 @@ pub struct RTDGetLoginUrlInfoBuilder {
 impl RTDGetLoginUrlInfoBuilder {
   pub fn build(&self) -> GetLoginUrlInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -11490,6 +13283,9 @@ pub struct GetMapThumbnailFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Location of the map center
   location: Location,
   /// Map zoom level; 13-20
@@ -11507,13 +13303,16 @@ pub struct GetMapThumbnailFile {
 
 impl RObject for GetMapThumbnailFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMapThumbnailFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMapThumbnailFile {}
+impl RFunction for GetMapThumbnailFile {
+  type Response = File;
+}
 
 impl GetMapThumbnailFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11545,6 +13344,11 @@ pub struct RTDGetMapThumbnailFileBuilder {
 impl RTDGetMapThumbnailFileBuilder {
   pub fn build(&self) -> GetMapThumbnailFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -11603,6 +13407,9 @@ pub struct GetMarkdownText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The text
   text: FormattedText,
   
@@ -11610,13 +13417,16 @@ pub struct GetMarkdownText {
 
 impl RObject for GetMarkdownText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMarkdownText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMarkdownText {}
+impl RFunction for GetMarkdownText {
+  type Response = FormattedText;
+}
 
 impl GetMarkdownText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11638,6 +13448,11 @@ pub struct RTDGetMarkdownTextBuilder {
 impl RTDGetMarkdownTextBuilder {
   pub fn build(&self) -> GetMarkdownText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -11666,18 +13481,24 @@ pub struct GetMe {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetMe {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMe" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMe {}
+impl RFunction for GetMe {
+  type Response = User;
+}
 
 impl GetMe {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11697,6 +13518,11 @@ pub struct RTDGetMeBuilder {
 impl RTDGetMeBuilder {
   pub fn build(&self) -> GetMe { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetMe> for GetMe {
@@ -11719,6 +13545,9 @@ pub struct GetMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat the message belongs to
   chat_id: i64,
   /// Identifier of the message to get
@@ -11728,13 +13557,16 @@ pub struct GetMessage {
 
 impl RObject for GetMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMessage {}
+impl RFunction for GetMessage {
+  type Response = Message;
+}
 
 impl GetMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11758,6 +13590,11 @@ pub struct RTDGetMessageBuilder {
 impl RTDGetMessageBuilder {
   pub fn build(&self) -> GetMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -11792,6 +13629,9 @@ pub struct GetMessageLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to which the message belongs
   chat_id: i64,
   /// Identifier of the message
@@ -11801,13 +13641,16 @@ pub struct GetMessageLink {
 
 impl RObject for GetMessageLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMessageLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMessageLink {}
+impl RFunction for GetMessageLink {
+  type Response = HttpUrl;
+}
 
 impl GetMessageLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11831,6 +13674,11 @@ pub struct RTDGetMessageLinkBuilder {
 impl RTDGetMessageLinkBuilder {
   pub fn build(&self) -> GetMessageLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -11865,6 +13713,9 @@ pub struct GetMessageLinkInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The message link in the format "https://t.me/c/...", or "tg://privatepost?...", or "https://t.me/username/...", or "tg://resolve?..."
   url: String,
   
@@ -11872,13 +13723,16 @@ pub struct GetMessageLinkInfo {
 
 impl RObject for GetMessageLinkInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMessageLinkInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMessageLinkInfo {}
+impl RFunction for GetMessageLinkInfo {
+  type Response = MessageLinkInfo;
+}
 
 impl GetMessageLinkInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11900,6 +13754,11 @@ pub struct RTDGetMessageLinkInfoBuilder {
 impl RTDGetMessageLinkInfoBuilder {
   pub fn build(&self) -> GetMessageLinkInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -11928,6 +13787,9 @@ pub struct GetMessageLocally {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat the message belongs to
   chat_id: i64,
   /// Identifier of the message to get
@@ -11937,13 +13799,16 @@ pub struct GetMessageLocally {
 
 impl RObject for GetMessageLocally {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMessageLocally" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMessageLocally {}
+impl RFunction for GetMessageLocally {
+  type Response = Message;
+}
 
 impl GetMessageLocally {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11967,6 +13832,11 @@ pub struct RTDGetMessageLocallyBuilder {
 impl RTDGetMessageLocallyBuilder {
   pub fn build(&self) -> GetMessageLocally { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -12001,6 +13871,9 @@ pub struct GetMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat the messages belong to
   chat_id: i64,
   /// Identifiers of the messages to get
@@ -12010,13 +13883,16 @@ pub struct GetMessages {
 
 impl RObject for GetMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetMessages {}
+impl RFunction for GetMessages {
+  type Response = Messages;
+}
 
 impl GetMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12040,6 +13916,11 @@ pub struct RTDGetMessagesBuilder {
 impl RTDGetMessagesBuilder {
   pub fn build(&self) -> GetMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -12074,6 +13955,9 @@ pub struct GetNetworkStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, returns only data for the current library launch
   only_current: bool,
   
@@ -12081,13 +13965,16 @@ pub struct GetNetworkStatistics {
 
 impl RObject for GetNetworkStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getNetworkStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetNetworkStatistics {}
+impl RFunction for GetNetworkStatistics {
+  type Response = NetworkStatistics;
+}
 
 impl GetNetworkStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12109,6 +13996,11 @@ pub struct RTDGetNetworkStatisticsBuilder {
 impl RTDGetNetworkStatisticsBuilder {
   pub fn build(&self) -> GetNetworkStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn only_current(&mut self, only_current: bool) -> &mut Self {
     self.inner.only_current = only_current;
@@ -12137,6 +14029,9 @@ pub struct GetOption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The name of the option
   name: String,
   
@@ -12144,13 +14039,16 @@ pub struct GetOption {
 
 impl RObject for GetOption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getOption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDOptionValue for GetOption {}
 
-impl RFunction for GetOption {}
+
+impl RFunction for GetOption {
+  type Response = OptionValue;
+}
 
 impl GetOption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12172,6 +14070,11 @@ pub struct RTDGetOptionBuilder {
 impl RTDGetOptionBuilder {
   pub fn build(&self) -> GetOption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -12200,6 +14103,9 @@ pub struct GetPassportAuthorizationForm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier of the service's bot
   bot_user_id: i64,
   /// Telegram Passport element types requested by the service
@@ -12213,13 +14119,16 @@ pub struct GetPassportAuthorizationForm {
 
 impl RObject for GetPassportAuthorizationForm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPassportAuthorizationForm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPassportAuthorizationForm {}
+impl RFunction for GetPassportAuthorizationForm {
+  type Response = PassportAuthorizationForm;
+}
 
 impl GetPassportAuthorizationForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12247,6 +14156,11 @@ pub struct RTDGetPassportAuthorizationFormBuilder {
 impl RTDGetPassportAuthorizationFormBuilder {
   pub fn build(&self) -> GetPassportAuthorizationForm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
@@ -12293,6 +14207,9 @@ pub struct GetPassportAuthorizationFormAvailableElements {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Authorization form identifier
   autorization_form_id: i64,
   /// Password of the current user
@@ -12302,13 +14219,16 @@ pub struct GetPassportAuthorizationFormAvailableElements {
 
 impl RObject for GetPassportAuthorizationFormAvailableElements {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPassportAuthorizationFormAvailableElements" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPassportAuthorizationFormAvailableElements {}
+impl RFunction for GetPassportAuthorizationFormAvailableElements {
+  type Response = PassportElementsWithErrors;
+}
 
 impl GetPassportAuthorizationFormAvailableElements {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12332,6 +14252,11 @@ pub struct RTDGetPassportAuthorizationFormAvailableElementsBuilder {
 impl RTDGetPassportAuthorizationFormAvailableElementsBuilder {
   pub fn build(&self) -> GetPassportAuthorizationFormAvailableElements { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn autorization_form_id(&mut self, autorization_form_id: i64) -> &mut Self {
     self.inner.autorization_form_id = autorization_form_id;
@@ -12366,6 +14291,9 @@ pub struct GetPassportElement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Telegram Passport element type
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// Password of the current user
@@ -12375,13 +14303,16 @@ pub struct GetPassportElement {
 
 impl RObject for GetPassportElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPassportElement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDPassportElement for GetPassportElement {}
 
-impl RFunction for GetPassportElement {}
+
+impl RFunction for GetPassportElement {
+  type Response = PassportElement;
+}
 
 impl GetPassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12405,6 +14336,11 @@ pub struct RTDGetPassportElementBuilder {
 impl RTDGetPassportElementBuilder {
   pub fn build(&self) -> GetPassportElement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -12439,18 +14375,24 @@ pub struct GetPasswordState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetPasswordState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPasswordState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPasswordState {}
+impl RFunction for GetPasswordState {
+  type Response = PasswordState;
+}
 
 impl GetPasswordState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12470,6 +14412,11 @@ pub struct RTDGetPasswordStateBuilder {
 impl RTDGetPasswordStateBuilder {
   pub fn build(&self) -> GetPasswordState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetPasswordState> for GetPasswordState {
@@ -12492,6 +14439,9 @@ pub struct GetPaymentForm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the Invoice message
   chat_id: i64,
   /// Message identifier
@@ -12501,13 +14451,16 @@ pub struct GetPaymentForm {
 
 impl RObject for GetPaymentForm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPaymentForm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPaymentForm {}
+impl RFunction for GetPaymentForm {
+  type Response = PaymentForm;
+}
 
 impl GetPaymentForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12531,6 +14484,11 @@ pub struct RTDGetPaymentFormBuilder {
 impl RTDGetPaymentFormBuilder {
   pub fn build(&self) -> GetPaymentForm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -12565,6 +14523,9 @@ pub struct GetPaymentReceipt {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the PaymentSuccessful message
   chat_id: i64,
   /// Message identifier
@@ -12574,13 +14535,16 @@ pub struct GetPaymentReceipt {
 
 impl RObject for GetPaymentReceipt {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPaymentReceipt" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPaymentReceipt {}
+impl RFunction for GetPaymentReceipt {
+  type Response = PaymentReceipt;
+}
 
 impl GetPaymentReceipt {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12604,6 +14568,11 @@ pub struct RTDGetPaymentReceiptBuilder {
 impl RTDGetPaymentReceiptBuilder {
   pub fn build(&self) -> GetPaymentReceipt { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -12638,6 +14607,9 @@ pub struct GetPollVoters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to which the poll belongs
   chat_id: i64,
   /// Identifier of the message containing the poll
@@ -12653,13 +14625,16 @@ pub struct GetPollVoters {
 
 impl RObject for GetPollVoters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPollVoters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPollVoters {}
+impl RFunction for GetPollVoters {
+  type Response = Users;
+}
 
 impl GetPollVoters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12689,6 +14664,11 @@ pub struct RTDGetPollVotersBuilder {
 impl RTDGetPollVotersBuilder {
   pub fn build(&self) -> GetPollVoters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -12741,6 +14721,9 @@ pub struct GetPreferredCountryLanguage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A two-letter ISO 3166-1 alpha-2 country code
   country_code: String,
   
@@ -12748,13 +14731,16 @@ pub struct GetPreferredCountryLanguage {
 
 impl RObject for GetPreferredCountryLanguage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPreferredCountryLanguage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPreferredCountryLanguage {}
+impl RFunction for GetPreferredCountryLanguage {
+  type Response = Text;
+}
 
 impl GetPreferredCountryLanguage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12776,6 +14762,11 @@ pub struct RTDGetPreferredCountryLanguageBuilder {
 impl RTDGetPreferredCountryLanguageBuilder {
   pub fn build(&self) -> GetPreferredCountryLanguage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
@@ -12804,18 +14795,24 @@ pub struct GetProxies {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetProxies {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getProxies" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetProxies {}
+impl RFunction for GetProxies {
+  type Response = Proxies;
+}
 
 impl GetProxies {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12835,6 +14832,11 @@ pub struct RTDGetProxiesBuilder {
 impl RTDGetProxiesBuilder {
   pub fn build(&self) -> GetProxies { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetProxies> for GetProxies {
@@ -12857,6 +14859,9 @@ pub struct GetProxyLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy identifier
   proxy_id: i64,
   
@@ -12864,13 +14869,16 @@ pub struct GetProxyLink {
 
 impl RObject for GetProxyLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getProxyLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetProxyLink {}
+impl RFunction for GetProxyLink {
+  type Response = Text;
+}
 
 impl GetProxyLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12892,6 +14900,11 @@ pub struct RTDGetProxyLinkBuilder {
 impl RTDGetProxyLinkBuilder {
   pub fn build(&self) -> GetProxyLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
@@ -12920,6 +14933,9 @@ pub struct GetPublicMessageLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to which the message belongs
   chat_id: i64,
   /// Identifier of the message
@@ -12931,13 +14947,16 @@ pub struct GetPublicMessageLink {
 
 impl RObject for GetPublicMessageLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPublicMessageLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPublicMessageLink {}
+impl RFunction for GetPublicMessageLink {
+  type Response = PublicMessageLink;
+}
 
 impl GetPublicMessageLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12963,6 +14982,11 @@ pub struct RTDGetPublicMessageLinkBuilder {
 impl RTDGetPublicMessageLinkBuilder {
   pub fn build(&self) -> GetPublicMessageLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -13003,6 +15027,9 @@ pub struct GetPushReceiverId {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// JSON-encoded push notification payload
   payload: String,
   
@@ -13010,13 +15037,16 @@ pub struct GetPushReceiverId {
 
 impl RObject for GetPushReceiverId {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getPushReceiverId" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetPushReceiverId {}
+impl RFunction for GetPushReceiverId {
+  type Response = PushReceiverId;
+}
 
 impl GetPushReceiverId {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13038,6 +15068,11 @@ pub struct RTDGetPushReceiverIdBuilder {
 impl RTDGetPushReceiverIdBuilder {
   pub fn build(&self) -> GetPushReceiverId { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().to_string();
@@ -13066,18 +15101,24 @@ pub struct GetRecentInlineBots {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetRecentInlineBots {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRecentInlineBots" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRecentInlineBots {}
+impl RFunction for GetRecentInlineBots {
+  type Response = Users;
+}
 
 impl GetRecentInlineBots {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13097,6 +15138,11 @@ pub struct RTDGetRecentInlineBotsBuilder {
 impl RTDGetRecentInlineBotsBuilder {
   pub fn build(&self) -> GetRecentInlineBots { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetRecentInlineBots> for GetRecentInlineBots {
@@ -13119,6 +15165,9 @@ pub struct GetRecentStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to return stickers and masks that were recently attached to photos or video files; pass false to return recently sent stickers
   is_attached: bool,
   
@@ -13126,13 +15175,16 @@ pub struct GetRecentStickers {
 
 impl RObject for GetRecentStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRecentStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRecentStickers {}
+impl RFunction for GetRecentStickers {
+  type Response = Stickers;
+}
 
 impl GetRecentStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13154,6 +15206,11 @@ pub struct RTDGetRecentStickersBuilder {
 impl RTDGetRecentStickersBuilder {
   pub fn build(&self) -> GetRecentStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -13182,6 +15239,9 @@ pub struct GetRecentlyVisitedTMeUrls {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Google Play referrer to identify the user
   referrer: String,
   
@@ -13189,13 +15249,16 @@ pub struct GetRecentlyVisitedTMeUrls {
 
 impl RObject for GetRecentlyVisitedTMeUrls {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRecentlyVisitedTMeUrls" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRecentlyVisitedTMeUrls {}
+impl RFunction for GetRecentlyVisitedTMeUrls {
+  type Response = TMeUrls;
+}
 
 impl GetRecentlyVisitedTMeUrls {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13217,6 +15280,11 @@ pub struct RTDGetRecentlyVisitedTMeUrlsBuilder {
 impl RTDGetRecentlyVisitedTMeUrlsBuilder {
   pub fn build(&self) -> GetRecentlyVisitedTMeUrls { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn referrer<T: AsRef<str>>(&mut self, referrer: T) -> &mut Self {
     self.inner.referrer = referrer.as_ref().to_string();
@@ -13245,18 +15313,24 @@ pub struct GetRecommendedChatFilters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetRecommendedChatFilters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRecommendedChatFilters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRecommendedChatFilters {}
+impl RFunction for GetRecommendedChatFilters {
+  type Response = RecommendedChatFilters;
+}
 
 impl GetRecommendedChatFilters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13276,6 +15350,11 @@ pub struct RTDGetRecommendedChatFiltersBuilder {
 impl RTDGetRecommendedChatFiltersBuilder {
   pub fn build(&self) -> GetRecommendedChatFilters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetRecommendedChatFilters> for GetRecommendedChatFilters {
@@ -13298,6 +15377,9 @@ pub struct GetRecoveryEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The password for the current user
   password: String,
   
@@ -13305,13 +15387,16 @@ pub struct GetRecoveryEmailAddress {
 
 impl RObject for GetRecoveryEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRecoveryEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRecoveryEmailAddress {}
+impl RFunction for GetRecoveryEmailAddress {
+  type Response = RecoveryEmailAddress;
+}
 
 impl GetRecoveryEmailAddress {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13333,6 +15418,11 @@ pub struct RTDGetRecoveryEmailAddressBuilder {
 impl RTDGetRecoveryEmailAddressBuilder {
   pub fn build(&self) -> GetRecoveryEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
@@ -13361,6 +15451,9 @@ pub struct GetRemoteFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Remote identifier of the file to get
   remote_file_id: String,
   /// File type, if known
@@ -13370,13 +15463,16 @@ pub struct GetRemoteFile {
 
 impl RObject for GetRemoteFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRemoteFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRemoteFile {}
+impl RFunction for GetRemoteFile {
+  type Response = File;
+}
 
 impl GetRemoteFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13400,6 +15496,11 @@ pub struct RTDGetRemoteFileBuilder {
 impl RTDGetRemoteFileBuilder {
   pub fn build(&self) -> GetRemoteFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn remote_file_id<T: AsRef<str>>(&mut self, remote_file_id: T) -> &mut Self {
     self.inner.remote_file_id = remote_file_id.as_ref().to_string();
@@ -13434,6 +15535,9 @@ pub struct GetRepliedMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat the message belongs to
   chat_id: i64,
   /// Identifier of the message reply to which get
@@ -13443,13 +15547,16 @@ pub struct GetRepliedMessage {
 
 impl RObject for GetRepliedMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getRepliedMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetRepliedMessage {}
+impl RFunction for GetRepliedMessage {
+  type Response = Message;
+}
 
 impl GetRepliedMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13473,6 +15580,11 @@ pub struct RTDGetRepliedMessageBuilder {
 impl RTDGetRepliedMessageBuilder {
   pub fn build(&self) -> GetRepliedMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -13507,18 +15619,24 @@ pub struct GetSavedAnimations {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetSavedAnimations {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSavedAnimations" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSavedAnimations {}
+impl RFunction for GetSavedAnimations {
+  type Response = Animations;
+}
 
 impl GetSavedAnimations {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13538,6 +15656,11 @@ pub struct RTDGetSavedAnimationsBuilder {
 impl RTDGetSavedAnimationsBuilder {
   pub fn build(&self) -> GetSavedAnimations { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetSavedAnimations> for GetSavedAnimations {
@@ -13560,18 +15683,24 @@ pub struct GetSavedOrderInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetSavedOrderInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSavedOrderInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSavedOrderInfo {}
+impl RFunction for GetSavedOrderInfo {
+  type Response = OrderInfo;
+}
 
 impl GetSavedOrderInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13591,6 +15720,11 @@ pub struct RTDGetSavedOrderInfoBuilder {
 impl RTDGetSavedOrderInfoBuilder {
   pub fn build(&self) -> GetSavedOrderInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetSavedOrderInfo> for GetSavedOrderInfo {
@@ -13613,6 +15747,9 @@ pub struct GetScopeNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Types of chats for which to return the notification settings information
   scope: NotificationSettingsScope,
   
@@ -13620,13 +15757,16 @@ pub struct GetScopeNotificationSettings {
 
 impl RObject for GetScopeNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getScopeNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetScopeNotificationSettings {}
+impl RFunction for GetScopeNotificationSettings {
+  type Response = ScopeNotificationSettings;
+}
 
 impl GetScopeNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13648,6 +15788,11 @@ pub struct RTDGetScopeNotificationSettingsBuilder {
 impl RTDGetScopeNotificationSettingsBuilder {
   pub fn build(&self) -> GetScopeNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -13676,6 +15821,9 @@ pub struct GetSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Secret chat identifier
   secret_chat_id: i64,
   
@@ -13683,13 +15831,16 @@ pub struct GetSecretChat {
 
 impl RObject for GetSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSecretChat {}
+impl RFunction for GetSecretChat {
+  type Response = SecretChat;
+}
 
 impl GetSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13711,6 +15862,11 @@ pub struct RTDGetSecretChatBuilder {
 impl RTDGetSecretChatBuilder {
   pub fn build(&self) -> GetSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
@@ -13739,6 +15895,9 @@ pub struct GetStickerEmojis {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker file identifier
   sticker: InputFile,
   
@@ -13746,13 +15905,16 @@ pub struct GetStickerEmojis {
 
 impl RObject for GetStickerEmojis {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getStickerEmojis" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetStickerEmojis {}
+impl RFunction for GetStickerEmojis {
+  type Response = Emojis;
+}
 
 impl GetStickerEmojis {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13774,6 +15936,11 @@ pub struct RTDGetStickerEmojisBuilder {
 impl RTDGetStickerEmojisBuilder {
   pub fn build(&self) -> GetStickerEmojis { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -13802,20 +15969,26 @@ pub struct GetStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the sticker set
-  set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] set_id: i64,
   
 }
 
 impl RObject for GetStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetStickerSet {}
+impl RFunction for GetStickerSet {
+  type Response = StickerSet;
+}
 
 impl GetStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13825,7 +15998,7 @@ impl GetStickerSet {
     RTDGetStickerSetBuilder { inner }
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  pub fn set_id(&self) -> i64 { self.set_id }
 
 }
 
@@ -13837,8 +16010,13 @@ pub struct RTDGetStickerSetBuilder {
 impl RTDGetStickerSetBuilder {
   pub fn build(&self) -> GetStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
     self
   }
@@ -13865,6 +16043,9 @@ pub struct GetStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// String representation of emoji. If empty, returns all known installed stickers
   emoji: String,
   /// The maximum number of stickers to be returned
@@ -13874,13 +16055,16 @@ pub struct GetStickers {
 
 impl RObject for GetStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetStickers {}
+impl RFunction for GetStickers {
+  type Response = Stickers;
+}
 
 impl GetStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13904,6 +16088,11 @@ pub struct RTDGetStickersBuilder {
 impl RTDGetStickersBuilder {
   pub fn build(&self) -> GetStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
@@ -13938,6 +16127,9 @@ pub struct GetStorageStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The maximum number of chats with the largest storage usage for which separate statistics should be returned. All other chats will be grouped in entries with chat_id == 0. If the chat info database is not used, the chat_limit is ignored and is always set to 0
   chat_limit: i64,
   
@@ -13945,13 +16137,16 @@ pub struct GetStorageStatistics {
 
 impl RObject for GetStorageStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getStorageStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetStorageStatistics {}
+impl RFunction for GetStorageStatistics {
+  type Response = StorageStatistics;
+}
 
 impl GetStorageStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13973,6 +16168,11 @@ pub struct RTDGetStorageStatisticsBuilder {
 impl RTDGetStorageStatisticsBuilder {
   pub fn build(&self) -> GetStorageStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_limit(&mut self, chat_limit: i64) -> &mut Self {
     self.inner.chat_limit = chat_limit;
@@ -14001,18 +16201,24 @@ pub struct GetStorageStatisticsFast {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetStorageStatisticsFast {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getStorageStatisticsFast" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetStorageStatisticsFast {}
+impl RFunction for GetStorageStatisticsFast {
+  type Response = StorageStatisticsFast;
+}
 
 impl GetStorageStatisticsFast {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14032,6 +16238,11 @@ pub struct RTDGetStorageStatisticsFastBuilder {
 impl RTDGetStorageStatisticsFastBuilder {
   pub fn build(&self) -> GetStorageStatisticsFast { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetStorageStatisticsFast> for GetStorageStatisticsFast {
@@ -14054,18 +16265,24 @@ pub struct GetSuitableDiscussionChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetSuitableDiscussionChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSuitableDiscussionChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSuitableDiscussionChats {}
+impl RFunction for GetSuitableDiscussionChats {
+  type Response = Chats;
+}
 
 impl GetSuitableDiscussionChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14085,6 +16302,11 @@ pub struct RTDGetSuitableDiscussionChatsBuilder {
 impl RTDGetSuitableDiscussionChatsBuilder {
   pub fn build(&self) -> GetSuitableDiscussionChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetSuitableDiscussionChats> for GetSuitableDiscussionChats {
@@ -14107,6 +16329,9 @@ pub struct GetSupergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Supergroup or channel identifier
   supergroup_id: i64,
   
@@ -14114,13 +16339,16 @@ pub struct GetSupergroup {
 
 impl RObject for GetSupergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSupergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSupergroup {}
+impl RFunction for GetSupergroup {
+  type Response = Supergroup;
+}
 
 impl GetSupergroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14142,6 +16370,11 @@ pub struct RTDGetSupergroupBuilder {
 impl RTDGetSupergroupBuilder {
   pub fn build(&self) -> GetSupergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -14170,6 +16403,9 @@ pub struct GetSupergroupFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Supergroup or channel identifier
   supergroup_id: i64,
   
@@ -14177,13 +16413,16 @@ pub struct GetSupergroupFullInfo {
 
 impl RObject for GetSupergroupFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSupergroupFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSupergroupFullInfo {}
+impl RFunction for GetSupergroupFullInfo {
+  type Response = SupergroupFullInfo;
+}
 
 impl GetSupergroupFullInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14205,6 +16444,11 @@ pub struct RTDGetSupergroupFullInfoBuilder {
 impl RTDGetSupergroupFullInfoBuilder {
   pub fn build(&self) -> GetSupergroupFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -14233,6 +16477,9 @@ pub struct GetSupergroupMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup or channel
   supergroup_id: i64,
   /// The type of users to return. By default, supergroupMembersRecent
@@ -14246,13 +16493,16 @@ pub struct GetSupergroupMembers {
 
 impl RObject for GetSupergroupMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSupergroupMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSupergroupMembers {}
+impl RFunction for GetSupergroupMembers {
+  type Response = ChatMembers;
+}
 
 impl GetSupergroupMembers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14280,6 +16530,11 @@ pub struct RTDGetSupergroupMembersBuilder {
 impl RTDGetSupergroupMembersBuilder {
   pub fn build(&self) -> GetSupergroupMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -14326,18 +16581,24 @@ pub struct GetSupportUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetSupportUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getSupportUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetSupportUser {}
+impl RFunction for GetSupportUser {
+  type Response = User;
+}
 
 impl GetSupportUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14357,6 +16618,11 @@ pub struct RTDGetSupportUserBuilder {
 impl RTDGetSupportUserBuilder {
   pub fn build(&self) -> GetSupportUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetSupportUser> for GetSupportUser {
@@ -14379,18 +16645,24 @@ pub struct GetTemporaryPasswordState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for GetTemporaryPasswordState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getTemporaryPasswordState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetTemporaryPasswordState {}
+impl RFunction for GetTemporaryPasswordState {
+  type Response = TemporaryPasswordState;
+}
 
 impl GetTemporaryPasswordState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14410,6 +16682,11 @@ pub struct RTDGetTemporaryPasswordStateBuilder {
 impl RTDGetTemporaryPasswordStateBuilder {
   pub fn build(&self) -> GetTemporaryPasswordState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GetTemporaryPasswordState> for GetTemporaryPasswordState {
@@ -14432,6 +16709,9 @@ pub struct GetTextEntities {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The text in which to look for entites
   text: String,
   
@@ -14439,13 +16719,16 @@ pub struct GetTextEntities {
 
 impl RObject for GetTextEntities {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getTextEntities" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetTextEntities {}
+impl RFunction for GetTextEntities {
+  type Response = TextEntities;
+}
 
 impl GetTextEntities {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14467,6 +16750,11 @@ pub struct RTDGetTextEntitiesBuilder {
 impl RTDGetTextEntitiesBuilder {
   pub fn build(&self) -> GetTextEntities { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -14495,6 +16783,9 @@ pub struct GetTopChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Category of chats to be returned
   category: TopChatCategory,
   /// The maximum number of chats to be returned; up to 30
@@ -14504,13 +16795,16 @@ pub struct GetTopChats {
 
 impl RObject for GetTopChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getTopChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetTopChats {}
+impl RFunction for GetTopChats {
+  type Response = Chats;
+}
 
 impl GetTopChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14534,6 +16828,11 @@ pub struct RTDGetTopChatsBuilder {
 impl RTDGetTopChatsBuilder {
   pub fn build(&self) -> GetTopChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn category<T: AsRef<TopChatCategory>>(&mut self, category: T) -> &mut Self {
     self.inner.category = category.as_ref().clone();
@@ -14568,6 +16867,9 @@ pub struct GetTrendingStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The offset from which to return the sticker sets; must be non-negative
   offset: i64,
   /// The maximum number of sticker sets to be returned; must be non-negative. Fewer sticker sets may be returned than specified by the limit, even if the end of the list has not been reached
@@ -14577,13 +16879,16 @@ pub struct GetTrendingStickerSets {
 
 impl RObject for GetTrendingStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getTrendingStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetTrendingStickerSets {}
+impl RFunction for GetTrendingStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetTrendingStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14607,6 +16912,11 @@ pub struct RTDGetTrendingStickerSetsBuilder {
 impl RTDGetTrendingStickerSetsBuilder {
   pub fn build(&self) -> GetTrendingStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
@@ -14641,6 +16951,9 @@ pub struct GetUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   
@@ -14648,13 +16961,16 @@ pub struct GetUser {
 
 impl RObject for GetUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetUser {}
+impl RFunction for GetUser {
+  type Response = User;
+}
 
 impl GetUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14676,6 +16992,11 @@ pub struct RTDGetUserBuilder {
 impl RTDGetUserBuilder {
   pub fn build(&self) -> GetUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -14704,6 +17025,9 @@ pub struct GetUserFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   
@@ -14711,13 +17035,16 @@ pub struct GetUserFullInfo {
 
 impl RObject for GetUserFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getUserFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetUserFullInfo {}
+impl RFunction for GetUserFullInfo {
+  type Response = UserFullInfo;
+}
 
 impl GetUserFullInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14739,6 +17066,11 @@ pub struct RTDGetUserFullInfoBuilder {
 impl RTDGetUserFullInfoBuilder {
   pub fn build(&self) -> GetUserFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -14767,6 +17099,9 @@ pub struct GetUserPrivacySettingRules {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The privacy setting
   setting: UserPrivacySetting,
   
@@ -14774,13 +17109,16 @@ pub struct GetUserPrivacySettingRules {
 
 impl RObject for GetUserPrivacySettingRules {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getUserPrivacySettingRules" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetUserPrivacySettingRules {}
+impl RFunction for GetUserPrivacySettingRules {
+  type Response = UserPrivacySettingRules;
+}
 
 impl GetUserPrivacySettingRules {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14802,6 +17140,11 @@ pub struct RTDGetUserPrivacySettingRulesBuilder {
 impl RTDGetUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> GetUserPrivacySettingRules { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -14830,6 +17173,9 @@ pub struct GetUserProfilePhotos {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// The number of photos to skip; must be non-negative
@@ -14841,13 +17187,16 @@ pub struct GetUserProfilePhotos {
 
 impl RObject for GetUserProfilePhotos {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getUserProfilePhotos" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetUserProfilePhotos {}
+impl RFunction for GetUserProfilePhotos {
+  type Response = ChatPhotos;
+}
 
 impl GetUserProfilePhotos {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14873,6 +17222,11 @@ pub struct RTDGetUserProfilePhotosBuilder {
 impl RTDGetUserProfilePhotosBuilder {
   pub fn build(&self) -> GetUserProfilePhotos { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -14913,6 +17267,9 @@ pub struct GetWebPageInstantView {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The web page URL
   url: String,
   /// If true, the full instant view for the web page will be returned
@@ -14922,13 +17279,16 @@ pub struct GetWebPageInstantView {
 
 impl RObject for GetWebPageInstantView {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getWebPageInstantView" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetWebPageInstantView {}
+impl RFunction for GetWebPageInstantView {
+  type Response = WebPageInstantView;
+}
 
 impl GetWebPageInstantView {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14952,6 +17312,11 @@ pub struct RTDGetWebPageInstantViewBuilder {
 impl RTDGetWebPageInstantViewBuilder {
   pub fn build(&self) -> GetWebPageInstantView { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -14986,6 +17351,9 @@ pub struct GetWebPagePreview {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message text with formatting
   text: FormattedText,
   
@@ -14993,13 +17361,16 @@ pub struct GetWebPagePreview {
 
 impl RObject for GetWebPagePreview {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "getWebPagePreview" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for GetWebPagePreview {}
+impl RFunction for GetWebPagePreview {
+  type Response = WebPage;
+}
 
 impl GetWebPagePreview {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15021,6 +17392,11 @@ pub struct RTDGetWebPagePreviewBuilder {
 impl RTDGetWebPagePreviewBuilder {
   pub fn build(&self) -> GetWebPagePreview { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -15049,6 +17425,9 @@ pub struct HideSuggestedAction {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Suggested action to hide
   action: SuggestedAction,
   
@@ -15056,13 +17435,16 @@ pub struct HideSuggestedAction {
 
 impl RObject for HideSuggestedAction {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "hideSuggestedAction" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for HideSuggestedAction {}
+impl RFunction for HideSuggestedAction {
+  type Response = Ok;
+}
 
 impl HideSuggestedAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15084,6 +17466,11 @@ pub struct RTDHideSuggestedActionBuilder {
 impl RTDHideSuggestedActionBuilder {
   pub fn build(&self) -> HideSuggestedAction { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn action<T: AsRef<SuggestedAction>>(&mut self, action: T) -> &mut Self {
     self.inner.action = action.as_ref().clone();
@@ -15112,6 +17499,9 @@ pub struct ImportContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The list of contacts to import or edit; contacts' vCard are ignored and are not imported
   contacts: Vec<Contact>,
   
@@ -15119,13 +17509,16 @@ pub struct ImportContacts {
 
 impl RObject for ImportContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "importContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ImportContacts {}
+impl RFunction for ImportContacts {
+  type Response = ImportedContacts;
+}
 
 impl ImportContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15147,6 +17540,11 @@ pub struct RTDImportContactsBuilder {
 impl RTDImportContactsBuilder {
   pub fn build(&self) -> ImportContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contacts(&mut self, contacts: Vec<Contact>) -> &mut Self {
     self.inner.contacts = contacts;
@@ -15175,6 +17573,9 @@ pub struct JoinChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -15182,13 +17583,16 @@ pub struct JoinChat {
 
 impl RObject for JoinChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "joinChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for JoinChat {}
+impl RFunction for JoinChat {
+  type Response = Ok;
+}
 
 impl JoinChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15210,6 +17614,11 @@ pub struct RTDJoinChatBuilder {
 impl RTDJoinChatBuilder {
   pub fn build(&self) -> JoinChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -15238,6 +17647,9 @@ pub struct JoinChatByInviteLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Invite link to import; should begin with "https://t.me/joinchat/", "https://telegram.me/joinchat/", or "https://telegram.dog/joinchat/"
   invite_link: String,
   
@@ -15245,13 +17657,16 @@ pub struct JoinChatByInviteLink {
 
 impl RObject for JoinChatByInviteLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "joinChatByInviteLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for JoinChatByInviteLink {}
+impl RFunction for JoinChatByInviteLink {
+  type Response = Chat;
+}
 
 impl JoinChatByInviteLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15273,6 +17688,11 @@ pub struct RTDJoinChatByInviteLinkBuilder {
 impl RTDJoinChatByInviteLinkBuilder {
   pub fn build(&self) -> JoinChatByInviteLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
@@ -15301,6 +17721,9 @@ pub struct LeaveChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -15308,13 +17731,16 @@ pub struct LeaveChat {
 
 impl RObject for LeaveChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "leaveChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for LeaveChat {}
+impl RFunction for LeaveChat {
+  type Response = Ok;
+}
 
 impl LeaveChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15336,6 +17762,11 @@ pub struct RTDLeaveChatBuilder {
 impl RTDLeaveChatBuilder {
   pub fn build(&self) -> LeaveChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -15364,18 +17795,24 @@ pub struct LogOut {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for LogOut {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "logOut" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for LogOut {}
+impl RFunction for LogOut {
+  type Response = Ok;
+}
 
 impl LogOut {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15395,6 +17832,11 @@ pub struct RTDLogOutBuilder {
 impl RTDLogOutBuilder {
   pub fn build(&self) -> LogOut { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LogOut> for LogOut {
@@ -15417,6 +17859,9 @@ pub struct OpenChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -15424,13 +17869,16 @@ pub struct OpenChat {
 
 impl RObject for OpenChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "openChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for OpenChat {}
+impl RFunction for OpenChat {
+  type Response = Ok;
+}
 
 impl OpenChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15452,6 +17900,11 @@ pub struct RTDOpenChatBuilder {
 impl RTDOpenChatBuilder {
   pub fn build(&self) -> OpenChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -15480,6 +17933,9 @@ pub struct OpenMessageContent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the message
   chat_id: i64,
   /// Identifier of the message with the opened content
@@ -15489,13 +17945,16 @@ pub struct OpenMessageContent {
 
 impl RObject for OpenMessageContent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "openMessageContent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for OpenMessageContent {}
+impl RFunction for OpenMessageContent {
+  type Response = Ok;
+}
 
 impl OpenMessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15519,6 +17978,11 @@ pub struct RTDOpenMessageContentBuilder {
 impl RTDOpenMessageContentBuilder {
   pub fn build(&self) -> OpenMessageContent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -15553,6 +18017,9 @@ pub struct OptimizeStorage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Limit on the total size of files after deletion. Pass 1 to use the default limit
   size: i64,
   /// Limit on the time that has passed since the last time a file was accessed (or creation time for some filesystems). Pass 1 to use the default limit
@@ -15576,13 +18043,16 @@ pub struct OptimizeStorage {
 
 impl RObject for OptimizeStorage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "optimizeStorage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for OptimizeStorage {}
+impl RFunction for OptimizeStorage {
+  type Response = StorageStatistics;
+}
 
 impl OptimizeStorage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15620,6 +18090,11 @@ pub struct RTDOptimizeStorageBuilder {
 impl RTDOptimizeStorageBuilder {
   pub fn build(&self) -> OptimizeStorage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
@@ -15696,6 +18171,9 @@ pub struct ParseMarkdown {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The text to parse. For example, "__italic__ ~~strikethrough~~ **bold** `code` ```pre``` __[italic__ text_url](telegram.org) __italic**bold italic__bold**"
   text: FormattedText,
   
@@ -15703,13 +18181,16 @@ pub struct ParseMarkdown {
 
 impl RObject for ParseMarkdown {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "parseMarkdown" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ParseMarkdown {}
+impl RFunction for ParseMarkdown {
+  type Response = FormattedText;
+}
 
 impl ParseMarkdown {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15731,6 +18212,11 @@ pub struct RTDParseMarkdownBuilder {
 impl RTDParseMarkdownBuilder {
   pub fn build(&self) -> ParseMarkdown { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -15759,6 +18245,9 @@ pub struct ParseTextEntities {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The text to parse
   text: String,
   /// Text parse mode
@@ -15768,13 +18257,16 @@ pub struct ParseTextEntities {
 
 impl RObject for ParseTextEntities {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "parseTextEntities" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ParseTextEntities {}
+impl RFunction for ParseTextEntities {
+  type Response = FormattedText;
+}
 
 impl ParseTextEntities {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15798,6 +18290,11 @@ pub struct RTDParseTextEntitiesBuilder {
 impl RTDParseTextEntitiesBuilder {
   pub fn build(&self) -> ParseTextEntities { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -15832,6 +18329,9 @@ pub struct PinChatMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat
   chat_id: i64,
   /// Identifier of the new pinned message
@@ -15843,13 +18343,16 @@ pub struct PinChatMessage {
 
 impl RObject for PinChatMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pinChatMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for PinChatMessage {}
+impl RFunction for PinChatMessage {
+  type Response = Ok;
+}
 
 impl PinChatMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15875,6 +18378,11 @@ pub struct RTDPinChatMessageBuilder {
 impl RTDPinChatMessageBuilder {
   pub fn build(&self) -> PinChatMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -15915,6 +18423,9 @@ pub struct PingProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy identifier. Use 0 to ping a Telegram server without a proxy
   proxy_id: i64,
   
@@ -15922,13 +18433,16 @@ pub struct PingProxy {
 
 impl RObject for PingProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pingProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for PingProxy {}
+impl RFunction for PingProxy {
+  type Response = Seconds;
+}
 
 impl PingProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15950,6 +18464,11 @@ pub struct RTDPingProxyBuilder {
 impl RTDPingProxyBuilder {
   pub fn build(&self) -> PingProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
@@ -15978,6 +18497,9 @@ pub struct ProcessPushNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// JSON-encoded push notification payload with all fields sent by the server, and "google.sent_time" and "google.notification.sound" fields added
   payload: String,
   
@@ -15985,13 +18507,16 @@ pub struct ProcessPushNotification {
 
 impl RObject for ProcessPushNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "processPushNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ProcessPushNotification {}
+impl RFunction for ProcessPushNotification {
+  type Response = Ok;
+}
 
 impl ProcessPushNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16013,6 +18538,11 @@ pub struct RTDProcessPushNotificationBuilder {
 impl RTDProcessPushNotificationBuilder {
   pub fn build(&self) -> ProcessPushNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().to_string();
@@ -16041,6 +18571,9 @@ pub struct ReadAllChatMentions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -16048,13 +18581,16 @@ pub struct ReadAllChatMentions {
 
 impl RObject for ReadAllChatMentions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "readAllChatMentions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ReadAllChatMentions {}
+impl RFunction for ReadAllChatMentions {
+  type Response = Ok;
+}
 
 impl ReadAllChatMentions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16076,6 +18612,11 @@ pub struct RTDReadAllChatMentionsBuilder {
 impl RTDReadAllChatMentionsBuilder {
   pub fn build(&self) -> ReadAllChatMentions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -16104,6 +18645,9 @@ pub struct ReadFilePart {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the file. The file must be located in the TDLib file cache
   file_id: i64,
   /// The offset from which to read the file
@@ -16115,13 +18659,16 @@ pub struct ReadFilePart {
 
 impl RObject for ReadFilePart {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "readFilePart" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ReadFilePart {}
+impl RFunction for ReadFilePart {
+  type Response = FilePart;
+}
 
 impl ReadFilePart {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16147,6 +18694,11 @@ pub struct RTDReadFilePartBuilder {
 impl RTDReadFilePartBuilder {
   pub fn build(&self) -> ReadFilePart { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
@@ -16187,6 +18739,9 @@ pub struct RecoverAuthenticationPassword {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Recovery code to check
   recovery_code: String,
   
@@ -16194,13 +18749,16 @@ pub struct RecoverAuthenticationPassword {
 
 impl RObject for RecoverAuthenticationPassword {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "recoverAuthenticationPassword" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RecoverAuthenticationPassword {}
+impl RFunction for RecoverAuthenticationPassword {
+  type Response = Ok;
+}
 
 impl RecoverAuthenticationPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16222,6 +18780,11 @@ pub struct RTDRecoverAuthenticationPasswordBuilder {
 impl RTDRecoverAuthenticationPasswordBuilder {
   pub fn build(&self) -> RecoverAuthenticationPassword { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn recovery_code<T: AsRef<str>>(&mut self, recovery_code: T) -> &mut Self {
     self.inner.recovery_code = recovery_code.as_ref().to_string();
@@ -16250,6 +18813,9 @@ pub struct RecoverPassword {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Recovery code to check
   recovery_code: String,
   
@@ -16257,13 +18823,16 @@ pub struct RecoverPassword {
 
 impl RObject for RecoverPassword {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "recoverPassword" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RecoverPassword {}
+impl RFunction for RecoverPassword {
+  type Response = PasswordState;
+}
 
 impl RecoverPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16285,6 +18854,11 @@ pub struct RTDRecoverPasswordBuilder {
 impl RTDRecoverPasswordBuilder {
   pub fn build(&self) -> RecoverPassword { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn recovery_code<T: AsRef<str>>(&mut self, recovery_code: T) -> &mut Self {
     self.inner.recovery_code = recovery_code.as_ref().to_string();
@@ -16313,6 +18887,9 @@ pub struct RegisterDevice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Device token
   device_token: DeviceToken,
   /// List of user identifiers of other users currently using the application
@@ -16322,13 +18899,16 @@ pub struct RegisterDevice {
 
 impl RObject for RegisterDevice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "registerDevice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RegisterDevice {}
+impl RFunction for RegisterDevice {
+  type Response = PushReceiverId;
+}
 
 impl RegisterDevice {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16352,6 +18932,11 @@ pub struct RTDRegisterDeviceBuilder {
 impl RTDRegisterDeviceBuilder {
   pub fn build(&self) -> RegisterDevice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn device_token<T: AsRef<DeviceToken>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().clone();
@@ -16386,6 +18971,9 @@ pub struct RegisterUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The first name of the user; 1-64 characters
   first_name: String,
   /// The last name of the user; 0-64 characters
@@ -16395,13 +18983,16 @@ pub struct RegisterUser {
 
 impl RObject for RegisterUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "registerUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RegisterUser {}
+impl RFunction for RegisterUser {
+  type Response = Ok;
+}
 
 impl RegisterUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16425,6 +19016,11 @@ pub struct RTDRegisterUserBuilder {
 impl RTDRegisterUserBuilder {
   pub fn build(&self) -> RegisterUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
@@ -16459,20 +19055,26 @@ pub struct RemoveBackground {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The background identifier
-  background_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] background_id: i64,
   
 }
 
 impl RObject for RemoveBackground {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeBackground" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveBackground {}
+impl RFunction for RemoveBackground {
+  type Response = Ok;
+}
 
 impl RemoveBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16482,7 +19084,7 @@ impl RemoveBackground {
     RTDRemoveBackgroundBuilder { inner }
   }
 
-  pub fn background_id(&self) -> isize { self.background_id }
+  pub fn background_id(&self) -> i64 { self.background_id }
 
 }
 
@@ -16494,8 +19096,13 @@ pub struct RTDRemoveBackgroundBuilder {
 impl RTDRemoveBackgroundBuilder {
   pub fn build(&self) -> RemoveBackground { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn background_id(&mut self, background_id: isize) -> &mut Self {
+  pub fn background_id(&mut self, background_id: i64) -> &mut Self {
     self.inner.background_id = background_id;
     self
   }
@@ -16522,6 +19129,9 @@ pub struct RemoveChatActionBar {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -16529,13 +19139,16 @@ pub struct RemoveChatActionBar {
 
 impl RObject for RemoveChatActionBar {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeChatActionBar" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveChatActionBar {}
+impl RFunction for RemoveChatActionBar {
+  type Response = Ok;
+}
 
 impl RemoveChatActionBar {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16557,6 +19170,11 @@ pub struct RTDRemoveChatActionBarBuilder {
 impl RTDRemoveChatActionBarBuilder {
   pub fn build(&self) -> RemoveChatActionBar { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -16585,6 +19203,9 @@ pub struct RemoveContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifiers of users to be deleted
   user_ids: Vec<i64>,
   
@@ -16592,13 +19213,16 @@ pub struct RemoveContacts {
 
 impl RObject for RemoveContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveContacts {}
+impl RFunction for RemoveContacts {
+  type Response = Ok;
+}
 
 impl RemoveContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16620,6 +19244,11 @@ pub struct RTDRemoveContactsBuilder {
 impl RTDRemoveContactsBuilder {
   pub fn build(&self) -> RemoveContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -16648,6 +19277,9 @@ pub struct RemoveFavoriteSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker file to delete from the list
   sticker: InputFile,
   
@@ -16655,13 +19287,16 @@ pub struct RemoveFavoriteSticker {
 
 impl RObject for RemoveFavoriteSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeFavoriteSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveFavoriteSticker {}
+impl RFunction for RemoveFavoriteSticker {
+  type Response = Ok;
+}
 
 impl RemoveFavoriteSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16683,6 +19318,11 @@ pub struct RTDRemoveFavoriteStickerBuilder {
 impl RTDRemoveFavoriteStickerBuilder {
   pub fn build(&self) -> RemoveFavoriteSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -16711,6 +19351,9 @@ pub struct RemoveNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of notification group to which the notification belongs
   notification_group_id: i64,
   /// Identifier of removed notification
@@ -16720,13 +19363,16 @@ pub struct RemoveNotification {
 
 impl RObject for RemoveNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveNotification {}
+impl RFunction for RemoveNotification {
+  type Response = Ok;
+}
 
 impl RemoveNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16750,6 +19396,11 @@ pub struct RTDRemoveNotificationBuilder {
 impl RTDRemoveNotificationBuilder {
   pub fn build(&self) -> RemoveNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
@@ -16784,6 +19435,9 @@ pub struct RemoveNotificationGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Notification group identifier
   notification_group_id: i64,
   /// The maximum identifier of removed notifications
@@ -16793,13 +19447,16 @@ pub struct RemoveNotificationGroup {
 
 impl RObject for RemoveNotificationGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeNotificationGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveNotificationGroup {}
+impl RFunction for RemoveNotificationGroup {
+  type Response = Ok;
+}
 
 impl RemoveNotificationGroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16823,6 +19480,11 @@ pub struct RTDRemoveNotificationGroupBuilder {
 impl RTDRemoveNotificationGroupBuilder {
   pub fn build(&self) -> RemoveNotificationGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
@@ -16857,6 +19519,9 @@ pub struct RemoveProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy identifier
   proxy_id: i64,
   
@@ -16864,13 +19529,16 @@ pub struct RemoveProxy {
 
 impl RObject for RemoveProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveProxy {}
+impl RFunction for RemoveProxy {
+  type Response = Ok;
+}
 
 impl RemoveProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16892,6 +19560,11 @@ pub struct RTDRemoveProxyBuilder {
 impl RTDRemoveProxyBuilder {
   pub fn build(&self) -> RemoveProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
@@ -16920,6 +19593,9 @@ pub struct RemoveRecentHashtag {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Hashtag to delete
   hashtag: String,
   
@@ -16927,13 +19603,16 @@ pub struct RemoveRecentHashtag {
 
 impl RObject for RemoveRecentHashtag {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeRecentHashtag" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveRecentHashtag {}
+impl RFunction for RemoveRecentHashtag {
+  type Response = Ok;
+}
 
 impl RemoveRecentHashtag {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16955,6 +19634,11 @@ pub struct RTDRemoveRecentHashtagBuilder {
 impl RTDRemoveRecentHashtagBuilder {
   pub fn build(&self) -> RemoveRecentHashtag { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn hashtag<T: AsRef<str>>(&mut self, hashtag: T) -> &mut Self {
     self.inner.hashtag = hashtag.as_ref().to_string();
@@ -16983,6 +19667,9 @@ pub struct RemoveRecentSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to remove the sticker from the list of stickers recently attached to photo or video files; pass false to remove the sticker from the list of recently sent stickers
   is_attached: bool,
   /// Sticker file to delete
@@ -16992,13 +19679,16 @@ pub struct RemoveRecentSticker {
 
 impl RObject for RemoveRecentSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeRecentSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveRecentSticker {}
+impl RFunction for RemoveRecentSticker {
+  type Response = Ok;
+}
 
 impl RemoveRecentSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17022,6 +19712,11 @@ pub struct RTDRemoveRecentStickerBuilder {
 impl RTDRemoveRecentStickerBuilder {
   pub fn build(&self) -> RemoveRecentSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -17056,6 +19751,9 @@ pub struct RemoveRecentlyFoundChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to be removed
   chat_id: i64,
   
@@ -17063,13 +19761,16 @@ pub struct RemoveRecentlyFoundChat {
 
 impl RObject for RemoveRecentlyFoundChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeRecentlyFoundChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveRecentlyFoundChat {}
+impl RFunction for RemoveRecentlyFoundChat {
+  type Response = Ok;
+}
 
 impl RemoveRecentlyFoundChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17091,6 +19792,11 @@ pub struct RTDRemoveRecentlyFoundChatBuilder {
 impl RTDRemoveRecentlyFoundChatBuilder {
   pub fn build(&self) -> RemoveRecentlyFoundChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -17119,6 +19825,9 @@ pub struct RemoveSavedAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Animation file to be removed
   animation: InputFile,
   
@@ -17126,13 +19835,16 @@ pub struct RemoveSavedAnimation {
 
 impl RObject for RemoveSavedAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeSavedAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveSavedAnimation {}
+impl RFunction for RemoveSavedAnimation {
+  type Response = Ok;
+}
 
 impl RemoveSavedAnimation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17154,6 +19866,11 @@ pub struct RTDRemoveSavedAnimationBuilder {
 impl RTDRemoveSavedAnimationBuilder {
   pub fn build(&self) -> RemoveSavedAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -17182,6 +19899,9 @@ pub struct RemoveStickerFromSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker
   sticker: InputFile,
   
@@ -17189,13 +19909,16 @@ pub struct RemoveStickerFromSet {
 
 impl RObject for RemoveStickerFromSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeStickerFromSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveStickerFromSet {}
+impl RFunction for RemoveStickerFromSet {
+  type Response = Ok;
+}
 
 impl RemoveStickerFromSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17217,6 +19940,11 @@ pub struct RTDRemoveStickerFromSetBuilder {
 impl RTDRemoveStickerFromSetBuilder {
   pub fn build(&self) -> RemoveStickerFromSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -17245,6 +19973,9 @@ pub struct RemoveTopChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Category of frequently used chats
   category: TopChatCategory,
   /// Chat identifier
@@ -17254,13 +19985,16 @@ pub struct RemoveTopChat {
 
 impl RObject for RemoveTopChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "removeTopChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RemoveTopChat {}
+impl RFunction for RemoveTopChat {
+  type Response = Ok;
+}
 
 impl RemoveTopChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17284,6 +20018,11 @@ pub struct RTDRemoveTopChatBuilder {
 impl RTDRemoveTopChatBuilder {
   pub fn build(&self) -> RemoveTopChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn category<T: AsRef<TopChatCategory>>(&mut self, category: T) -> &mut Self {
     self.inner.category = category.as_ref().clone();
@@ -17318,6 +20057,9 @@ pub struct ReorderChatFilters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifiers of chat filters in the new correct order
   chat_filter_ids: Vec<i64>,
   
@@ -17325,13 +20067,16 @@ pub struct ReorderChatFilters {
 
 impl RObject for ReorderChatFilters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "reorderChatFilters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ReorderChatFilters {}
+impl RFunction for ReorderChatFilters {
+  type Response = Ok;
+}
 
 impl ReorderChatFilters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17353,6 +20098,11 @@ pub struct RTDReorderChatFiltersBuilder {
 impl RTDReorderChatFiltersBuilder {
   pub fn build(&self) -> ReorderChatFilters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filter_ids(&mut self, chat_filter_ids: Vec<i64>) -> &mut Self {
     self.inner.chat_filter_ids = chat_filter_ids;
@@ -17381,22 +20131,28 @@ pub struct ReorderInstalledStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to change the order of mask sticker sets; pass false to change the order of ordinary sticker sets
   is_masks: bool,
   /// Identifiers of installed sticker sets in the new correct order
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64_str")] sticker_set_ids: Vec<i64>,
   
 }
 
 impl RObject for ReorderInstalledStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "reorderInstalledStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ReorderInstalledStickerSets {}
+impl RFunction for ReorderInstalledStickerSets {
+  type Response = Ok;
+}
 
 impl ReorderInstalledStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17408,7 +20164,7 @@ impl ReorderInstalledStickerSets {
 
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -17420,6 +20176,11 @@ pub struct RTDReorderInstalledStickerSetsBuilder {
 impl RTDReorderInstalledStickerSetsBuilder {
   pub fn build(&self) -> ReorderInstalledStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -17427,7 +20188,7 @@ impl RTDReorderInstalledStickerSetsBuilder {
   }
 
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -17454,6 +20215,9 @@ pub struct ReportChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The reason for reporting the chat
@@ -17465,13 +20229,16 @@ pub struct ReportChat {
 
 impl RObject for ReportChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "reportChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ReportChat {}
+impl RFunction for ReportChat {
+  type Response = Ok;
+}
 
 impl ReportChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17497,6 +20264,11 @@ pub struct RTDReportChatBuilder {
 impl RTDReportChatBuilder {
   pub fn build(&self) -> ReportChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -17537,6 +20309,9 @@ pub struct ReportSupergroupSpam {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Supergroup identifier
   supergroup_id: i64,
   /// User identifier
@@ -17548,13 +20323,16 @@ pub struct ReportSupergroupSpam {
 
 impl RObject for ReportSupergroupSpam {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "reportSupergroupSpam" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ReportSupergroupSpam {}
+impl RFunction for ReportSupergroupSpam {
+  type Response = Ok;
+}
 
 impl ReportSupergroupSpam {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17580,6 +20358,11 @@ pub struct RTDReportSupergroupSpamBuilder {
 impl RTDReportSupergroupSpamBuilder {
   pub fn build(&self) -> ReportSupergroupSpam { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -17620,18 +20403,24 @@ pub struct RequestAuthenticationPasswordRecovery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for RequestAuthenticationPasswordRecovery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "requestAuthenticationPasswordRecovery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RequestAuthenticationPasswordRecovery {}
+impl RFunction for RequestAuthenticationPasswordRecovery {
+  type Response = Ok;
+}
 
 impl RequestAuthenticationPasswordRecovery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17651,6 +20440,11 @@ pub struct RTDRequestAuthenticationPasswordRecoveryBuilder {
 impl RTDRequestAuthenticationPasswordRecoveryBuilder {
   pub fn build(&self) -> RequestAuthenticationPasswordRecovery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<RequestAuthenticationPasswordRecovery> for RequestAuthenticationPasswordRecovery {
@@ -17673,18 +20467,24 @@ pub struct RequestPasswordRecovery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for RequestPasswordRecovery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "requestPasswordRecovery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RequestPasswordRecovery {}
+impl RFunction for RequestPasswordRecovery {
+  type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl RequestPasswordRecovery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17704,6 +20504,11 @@ pub struct RTDRequestPasswordRecoveryBuilder {
 impl RTDRequestPasswordRecoveryBuilder {
   pub fn build(&self) -> RequestPasswordRecovery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<RequestPasswordRecovery> for RequestPasswordRecovery {
@@ -17726,6 +20531,9 @@ pub struct RequestQrCodeAuthentication {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of user identifiers of other users currently using the application
   other_user_ids: Vec<i64>,
   
@@ -17733,13 +20541,16 @@ pub struct RequestQrCodeAuthentication {
 
 impl RObject for RequestQrCodeAuthentication {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "requestQrCodeAuthentication" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for RequestQrCodeAuthentication {}
+impl RFunction for RequestQrCodeAuthentication {
+  type Response = Ok;
+}
 
 impl RequestQrCodeAuthentication {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17761,6 +20572,11 @@ pub struct RTDRequestQrCodeAuthenticationBuilder {
 impl RTDRequestQrCodeAuthenticationBuilder {
   pub fn build(&self) -> RequestQrCodeAuthentication { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn other_user_ids(&mut self, other_user_ids: Vec<i64>) -> &mut Self {
     self.inner.other_user_ids = other_user_ids;
@@ -17789,18 +20605,24 @@ pub struct ResendAuthenticationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResendAuthenticationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendAuthenticationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendAuthenticationCode {}
+impl RFunction for ResendAuthenticationCode {
+  type Response = Ok;
+}
 
 impl ResendAuthenticationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17820,6 +20642,11 @@ pub struct RTDResendAuthenticationCodeBuilder {
 impl RTDResendAuthenticationCodeBuilder {
   pub fn build(&self) -> ResendAuthenticationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResendAuthenticationCode> for ResendAuthenticationCode {
@@ -17842,18 +20669,24 @@ pub struct ResendChangePhoneNumberCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResendChangePhoneNumberCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendChangePhoneNumberCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendChangePhoneNumberCode {}
+impl RFunction for ResendChangePhoneNumberCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ResendChangePhoneNumberCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17873,6 +20706,11 @@ pub struct RTDResendChangePhoneNumberCodeBuilder {
 impl RTDResendChangePhoneNumberCodeBuilder {
   pub fn build(&self) -> ResendChangePhoneNumberCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResendChangePhoneNumberCode> for ResendChangePhoneNumberCode {
@@ -17895,18 +20733,24 @@ pub struct ResendEmailAddressVerificationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResendEmailAddressVerificationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendEmailAddressVerificationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendEmailAddressVerificationCode {}
+impl RFunction for ResendEmailAddressVerificationCode {
+  type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl ResendEmailAddressVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17926,6 +20770,11 @@ pub struct RTDResendEmailAddressVerificationCodeBuilder {
 impl RTDResendEmailAddressVerificationCodeBuilder {
   pub fn build(&self) -> ResendEmailAddressVerificationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResendEmailAddressVerificationCode> for ResendEmailAddressVerificationCode {
@@ -17948,6 +20797,9 @@ pub struct ResendMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to send messages
   chat_id: i64,
   /// Identifiers of the messages to resend. Message identifiers must be in a strictly increasing order
@@ -17957,13 +20809,16 @@ pub struct ResendMessages {
 
 impl RObject for ResendMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendMessages {}
+impl RFunction for ResendMessages {
+  type Response = Messages;
+}
 
 impl ResendMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17987,6 +20842,11 @@ pub struct RTDResendMessagesBuilder {
 impl RTDResendMessagesBuilder {
   pub fn build(&self) -> ResendMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -18021,18 +20881,24 @@ pub struct ResendPhoneNumberConfirmationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResendPhoneNumberConfirmationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendPhoneNumberConfirmationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendPhoneNumberConfirmationCode {}
+impl RFunction for ResendPhoneNumberConfirmationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ResendPhoneNumberConfirmationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18052,6 +20918,11 @@ pub struct RTDResendPhoneNumberConfirmationCodeBuilder {
 impl RTDResendPhoneNumberConfirmationCodeBuilder {
   pub fn build(&self) -> ResendPhoneNumberConfirmationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResendPhoneNumberConfirmationCode> for ResendPhoneNumberConfirmationCode {
@@ -18074,18 +20945,24 @@ pub struct ResendPhoneNumberVerificationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResendPhoneNumberVerificationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendPhoneNumberVerificationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendPhoneNumberVerificationCode {}
+impl RFunction for ResendPhoneNumberVerificationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ResendPhoneNumberVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18105,6 +20982,11 @@ pub struct RTDResendPhoneNumberVerificationCodeBuilder {
 impl RTDResendPhoneNumberVerificationCodeBuilder {
   pub fn build(&self) -> ResendPhoneNumberVerificationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResendPhoneNumberVerificationCode> for ResendPhoneNumberVerificationCode {
@@ -18127,18 +21009,24 @@ pub struct ResendRecoveryEmailAddressCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResendRecoveryEmailAddressCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resendRecoveryEmailAddressCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResendRecoveryEmailAddressCode {}
+impl RFunction for ResendRecoveryEmailAddressCode {
+  type Response = PasswordState;
+}
 
 impl ResendRecoveryEmailAddressCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18158,6 +21046,11 @@ pub struct RTDResendRecoveryEmailAddressCodeBuilder {
 impl RTDResendRecoveryEmailAddressCodeBuilder {
   pub fn build(&self) -> ResendRecoveryEmailAddressCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResendRecoveryEmailAddressCode> for ResendRecoveryEmailAddressCode {
@@ -18180,18 +21073,24 @@ pub struct ResetAllNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResetAllNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resetAllNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResetAllNotificationSettings {}
+impl RFunction for ResetAllNotificationSettings {
+  type Response = Ok;
+}
 
 impl ResetAllNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18211,6 +21110,11 @@ pub struct RTDResetAllNotificationSettingsBuilder {
 impl RTDResetAllNotificationSettingsBuilder {
   pub fn build(&self) -> ResetAllNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResetAllNotificationSettings> for ResetAllNotificationSettings {
@@ -18233,18 +21137,24 @@ pub struct ResetBackgrounds {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResetBackgrounds {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resetBackgrounds" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResetBackgrounds {}
+impl RFunction for ResetBackgrounds {
+  type Response = Ok;
+}
 
 impl ResetBackgrounds {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18264,6 +21174,11 @@ pub struct RTDResetBackgroundsBuilder {
 impl RTDResetBackgroundsBuilder {
   pub fn build(&self) -> ResetBackgrounds { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResetBackgrounds> for ResetBackgrounds {
@@ -18286,18 +21201,24 @@ pub struct ResetNetworkStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ResetNetworkStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "resetNetworkStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ResetNetworkStatistics {}
+impl RFunction for ResetNetworkStatistics {
+  type Response = Ok;
+}
 
 impl ResetNetworkStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18317,6 +21238,11 @@ pub struct RTDResetNetworkStatisticsBuilder {
 impl RTDResetNetworkStatisticsBuilder {
   pub fn build(&self) -> ResetNetworkStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResetNetworkStatistics> for ResetNetworkStatistics {
@@ -18339,6 +21265,9 @@ pub struct SaveApplicationLogEvent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Event type
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// Optional chat identifier, associated with the event
@@ -18350,13 +21279,16 @@ pub struct SaveApplicationLogEvent {
 
 impl RObject for SaveApplicationLogEvent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "saveApplicationLogEvent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SaveApplicationLogEvent {}
+impl RFunction for SaveApplicationLogEvent {
+  type Response = Ok;
+}
 
 impl SaveApplicationLogEvent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18382,6 +21314,11 @@ pub struct RTDSaveApplicationLogEventBuilder {
 impl RTDSaveApplicationLogEventBuilder {
   pub fn build(&self) -> SaveApplicationLogEvent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -18422,6 +21359,9 @@ pub struct SearchBackground {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The name of the background
   name: String,
   
@@ -18429,13 +21369,16 @@ pub struct SearchBackground {
 
 impl RObject for SearchBackground {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchBackground" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchBackground {}
+impl RFunction for SearchBackground {
+  type Response = Background;
+}
 
 impl SearchBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18457,6 +21400,11 @@ pub struct RTDSearchBackgroundBuilder {
 impl RTDSearchBackgroundBuilder {
   pub fn build(&self) -> SearchBackground { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -18485,6 +21433,9 @@ pub struct SearchCallMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the message from which to search; use 0 to get results from the last message
   from_message_id: i64,
   /// The maximum number of messages to be returned; up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached
@@ -18496,13 +21447,16 @@ pub struct SearchCallMessages {
 
 impl RObject for SearchCallMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchCallMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchCallMessages {}
+impl RFunction for SearchCallMessages {
+  type Response = Messages;
+}
 
 impl SearchCallMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18528,6 +21482,11 @@ pub struct RTDSearchCallMessagesBuilder {
 impl RTDSearchCallMessagesBuilder {
   pub fn build(&self) -> SearchCallMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
     self.inner.from_message_id = from_message_id;
@@ -18568,6 +21527,9 @@ pub struct SearchChatMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Query to search for
@@ -18581,13 +21543,16 @@ pub struct SearchChatMembers {
 
 impl RObject for SearchChatMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchChatMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchChatMembers {}
+impl RFunction for SearchChatMembers {
+  type Response = ChatMembers;
+}
 
 impl SearchChatMembers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18615,6 +21580,11 @@ pub struct RTDSearchChatMembersBuilder {
 impl RTDSearchChatMembersBuilder {
   pub fn build(&self) -> SearchChatMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -18661,6 +21631,9 @@ pub struct SearchChatMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat in which to search messages
   chat_id: i64,
   /// Query to search for
@@ -18680,13 +21653,16 @@ pub struct SearchChatMessages {
 
 impl RObject for SearchChatMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchChatMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchChatMessages {}
+impl RFunction for SearchChatMessages {
+  type Response = Messages;
+}
 
 impl SearchChatMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18720,6 +21696,11 @@ pub struct RTDSearchChatMessagesBuilder {
 impl RTDSearchChatMessagesBuilder {
   pub fn build(&self) -> SearchChatMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -18784,6 +21765,9 @@ pub struct SearchChatRecentLocationMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The maximum number of messages to be returned
@@ -18793,13 +21777,16 @@ pub struct SearchChatRecentLocationMessages {
 
 impl RObject for SearchChatRecentLocationMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchChatRecentLocationMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchChatRecentLocationMessages {}
+impl RFunction for SearchChatRecentLocationMessages {
+  type Response = Messages;
+}
 
 impl SearchChatRecentLocationMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18823,6 +21810,11 @@ pub struct RTDSearchChatRecentLocationMessagesBuilder {
 impl RTDSearchChatRecentLocationMessagesBuilder {
   pub fn build(&self) -> SearchChatRecentLocationMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -18857,6 +21849,9 @@ pub struct SearchChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for. If the query is empty, returns up to 20 recently found chats
   query: String,
   /// The maximum number of chats to be returned
@@ -18866,13 +21861,16 @@ pub struct SearchChats {
 
 impl RObject for SearchChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchChats {}
+impl RFunction for SearchChats {
+  type Response = Chats;
+}
 
 impl SearchChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18896,6 +21894,11 @@ pub struct RTDSearchChatsBuilder {
 impl RTDSearchChatsBuilder {
   pub fn build(&self) -> SearchChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -18930,6 +21933,9 @@ pub struct SearchChatsNearby {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current user location
   location: Location,
   
@@ -18937,13 +21943,16 @@ pub struct SearchChatsNearby {
 
 impl RObject for SearchChatsNearby {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchChatsNearby" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchChatsNearby {}
+impl RFunction for SearchChatsNearby {
+  type Response = ChatsNearby;
+}
 
 impl SearchChatsNearby {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18965,6 +21974,11 @@ pub struct RTDSearchChatsNearbyBuilder {
 impl RTDSearchChatsNearbyBuilder {
   pub fn build(&self) -> SearchChatsNearby { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -18993,6 +22007,9 @@ pub struct SearchChatsOnServer {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   /// The maximum number of chats to be returned
@@ -19002,13 +22019,16 @@ pub struct SearchChatsOnServer {
 
 impl RObject for SearchChatsOnServer {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchChatsOnServer" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchChatsOnServer {}
+impl RFunction for SearchChatsOnServer {
+  type Response = Chats;
+}
 
 impl SearchChatsOnServer {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19032,6 +22052,11 @@ pub struct RTDSearchChatsOnServerBuilder {
 impl RTDSearchChatsOnServerBuilder {
   pub fn build(&self) -> SearchChatsOnServer { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -19066,6 +22091,9 @@ pub struct SearchContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for; may be empty to return all contacts
   query: String,
   /// The maximum number of users to be returned
@@ -19075,13 +22103,16 @@ pub struct SearchContacts {
 
 impl RObject for SearchContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchContacts {}
+impl RFunction for SearchContacts {
+  type Response = Users;
+}
 
 impl SearchContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19105,6 +22136,11 @@ pub struct RTDSearchContactsBuilder {
 impl RTDSearchContactsBuilder {
   pub fn build(&self) -> SearchContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -19139,6 +22175,9 @@ pub struct SearchEmojis {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text to search for
   text: String,
   /// True, if only emojis, which exactly match text needs to be returned
@@ -19150,13 +22189,16 @@ pub struct SearchEmojis {
 
 impl RObject for SearchEmojis {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchEmojis" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchEmojis {}
+impl RFunction for SearchEmojis {
+  type Response = Emojis;
+}
 
 impl SearchEmojis {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19182,6 +22224,11 @@ pub struct RTDSearchEmojisBuilder {
 impl RTDSearchEmojisBuilder {
   pub fn build(&self) -> SearchEmojis { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -19222,6 +22269,9 @@ pub struct SearchHashtags {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Hashtag prefix to search for
   prefix: String,
   /// The maximum number of hashtags to be returned
@@ -19231,13 +22281,16 @@ pub struct SearchHashtags {
 
 impl RObject for SearchHashtags {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchHashtags" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchHashtags {}
+impl RFunction for SearchHashtags {
+  type Response = Hashtags;
+}
 
 impl SearchHashtags {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19261,6 +22314,11 @@ pub struct RTDSearchHashtagsBuilder {
 impl RTDSearchHashtagsBuilder {
   pub fn build(&self) -> SearchHashtags { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn prefix<T: AsRef<str>>(&mut self, prefix: T) -> &mut Self {
     self.inner.prefix = prefix.as_ref().to_string();
@@ -19295,6 +22353,9 @@ pub struct SearchInstalledStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to return mask sticker sets; pass false to return ordinary sticker sets
   is_masks: bool,
   /// Query to search for
@@ -19306,13 +22367,16 @@ pub struct SearchInstalledStickerSets {
 
 impl RObject for SearchInstalledStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchInstalledStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchInstalledStickerSets {}
+impl RFunction for SearchInstalledStickerSets {
+  type Response = StickerSets;
+}
 
 impl SearchInstalledStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19338,6 +22402,11 @@ pub struct RTDSearchInstalledStickerSetsBuilder {
 impl RTDSearchInstalledStickerSetsBuilder {
   pub fn build(&self) -> SearchInstalledStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -19378,6 +22447,9 @@ pub struct SearchMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat list in which to search messages; pass null to search in all chats regardless of their chat list
   chat_list: ChatList,
   /// Query to search for
@@ -19395,13 +22467,16 @@ pub struct SearchMessages {
 
 impl RObject for SearchMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchMessages {}
+impl RFunction for SearchMessages {
+  type Response = Messages;
+}
 
 impl SearchMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19433,6 +22508,11 @@ pub struct RTDSearchMessagesBuilder {
 impl RTDSearchMessagesBuilder {
   pub fn build(&self) -> SearchMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -19491,6 +22571,9 @@ pub struct SearchPublicChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Username to be resolved
   username: String,
   
@@ -19498,13 +22581,16 @@ pub struct SearchPublicChat {
 
 impl RObject for SearchPublicChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchPublicChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchPublicChat {}
+impl RFunction for SearchPublicChat {
+  type Response = Chat;
+}
 
 impl SearchPublicChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19526,6 +22612,11 @@ pub struct RTDSearchPublicChatBuilder {
 impl RTDSearchPublicChatBuilder {
   pub fn build(&self) -> SearchPublicChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -19554,6 +22645,9 @@ pub struct SearchPublicChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   
@@ -19561,13 +22655,16 @@ pub struct SearchPublicChats {
 
 impl RObject for SearchPublicChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchPublicChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchPublicChats {}
+impl RFunction for SearchPublicChats {
+  type Response = Chats;
+}
 
 impl SearchPublicChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19589,6 +22686,11 @@ pub struct RTDSearchPublicChatsBuilder {
 impl RTDSearchPublicChatsBuilder {
   pub fn build(&self) -> SearchPublicChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -19617,12 +22719,15 @@ pub struct SearchSecretMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat in which to search. Specify 0 to search in all secret chats
   chat_id: i64,
   /// Query to search for. If empty, searchChatMessages should be used instead
   query: String,
   /// The identifier from the result of a previous request, use 0 to get results from the last message
-  from_search_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] from_search_id: i64,
   /// The maximum number of messages to be returned; up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached
   limit: i64,
   /// A filter for the content of messages in the search results
@@ -19632,13 +22737,16 @@ pub struct SearchSecretMessages {
 
 impl RObject for SearchSecretMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchSecretMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchSecretMessages {}
+impl RFunction for SearchSecretMessages {
+  type Response = FoundMessages;
+}
 
 impl SearchSecretMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19652,7 +22760,7 @@ impl SearchSecretMessages {
 
   pub fn query(&self) -> &String { &self.query }
 
-  pub fn from_search_id(&self) -> isize { self.from_search_id }
+  pub fn from_search_id(&self) -> i64 { self.from_search_id }
 
   pub fn limit(&self) -> i64 { self.limit }
 
@@ -19668,6 +22776,11 @@ pub struct RTDSearchSecretMessagesBuilder {
 impl RTDSearchSecretMessagesBuilder {
   pub fn build(&self) -> SearchSecretMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -19681,7 +22794,7 @@ impl RTDSearchSecretMessagesBuilder {
   }
 
    
-  pub fn from_search_id(&mut self, from_search_id: isize) -> &mut Self {
+  pub fn from_search_id(&mut self, from_search_id: i64) -> &mut Self {
     self.inner.from_search_id = from_search_id;
     self
   }
@@ -19720,6 +22833,9 @@ pub struct SearchStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Name of the sticker set
   name: String,
   
@@ -19727,13 +22843,16 @@ pub struct SearchStickerSet {
 
 impl RObject for SearchStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchStickerSet {}
+impl RFunction for SearchStickerSet {
+  type Response = StickerSet;
+}
 
 impl SearchStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19755,6 +22874,11 @@ pub struct RTDSearchStickerSetBuilder {
 impl RTDSearchStickerSetBuilder {
   pub fn build(&self) -> SearchStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -19783,6 +22907,9 @@ pub struct SearchStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   
@@ -19790,13 +22917,16 @@ pub struct SearchStickerSets {
 
 impl RObject for SearchStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchStickerSets {}
+impl RFunction for SearchStickerSets {
+  type Response = StickerSets;
+}
 
 impl SearchStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19818,6 +22948,11 @@ pub struct RTDSearchStickerSetsBuilder {
 impl RTDSearchStickerSetsBuilder {
   pub fn build(&self) -> SearchStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -19846,6 +22981,9 @@ pub struct SearchStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// String representation of emoji; must be non-empty
   emoji: String,
   /// The maximum number of stickers to be returned
@@ -19855,13 +22993,16 @@ pub struct SearchStickers {
 
 impl RObject for SearchStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SearchStickers {}
+impl RFunction for SearchStickers {
+  type Response = Stickers;
+}
 
 impl SearchStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19885,6 +23026,11 @@ pub struct RTDSearchStickersBuilder {
 impl RTDSearchStickersBuilder {
   pub fn build(&self) -> SearchStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
@@ -19919,6 +23065,9 @@ pub struct SendBotStartMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the bot
   bot_user_id: i64,
   /// Identifier of the target chat
@@ -19930,13 +23079,16 @@ pub struct SendBotStartMessage {
 
 impl RObject for SendBotStartMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendBotStartMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendBotStartMessage {}
+impl RFunction for SendBotStartMessage {
+  type Response = Message;
+}
 
 impl SendBotStartMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19962,6 +23114,11 @@ pub struct RTDSendBotStartMessageBuilder {
 impl RTDSendBotStartMessageBuilder {
   pub fn build(&self) -> SendBotStartMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
@@ -20002,6 +23159,9 @@ pub struct SendCallDebugInformation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   call_id: i64,
   /// Debug information in application-specific format
@@ -20011,13 +23171,16 @@ pub struct SendCallDebugInformation {
 
 impl RObject for SendCallDebugInformation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendCallDebugInformation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendCallDebugInformation {}
+impl RFunction for SendCallDebugInformation {
+  type Response = Ok;
+}
 
 impl SendCallDebugInformation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20041,6 +23204,11 @@ pub struct RTDSendCallDebugInformationBuilder {
 impl RTDSendCallDebugInformationBuilder {
   pub fn build(&self) -> SendCallDebugInformation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -20075,6 +23243,9 @@ pub struct SendCallRating {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   call_id: i64,
   /// Call rating; 1-5
@@ -20088,13 +23259,16 @@ pub struct SendCallRating {
 
 impl RObject for SendCallRating {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendCallRating" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendCallRating {}
+impl RFunction for SendCallRating {
+  type Response = Ok;
+}
 
 impl SendCallRating {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20122,6 +23296,11 @@ pub struct RTDSendCallRatingBuilder {
 impl RTDSendCallRatingBuilder {
   pub fn build(&self) -> SendCallRating { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -20168,22 +23347,28 @@ pub struct SendCallSignalingData {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   call_id: i64,
   /// The data
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for SendCallSignalingData {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendCallSignalingData" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendCallSignalingData {}
+impl RFunction for SendCallSignalingData {
+  type Response = Ok;
+}
 
 impl SendCallSignalingData {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20195,7 +23380,7 @@ impl SendCallSignalingData {
 
   pub fn call_id(&self) -> i64 { self.call_id }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -20207,6 +23392,11 @@ pub struct RTDSendCallSignalingDataBuilder {
 impl RTDSendCallSignalingDataBuilder {
   pub fn build(&self) -> SendCallSignalingData { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -20214,8 +23404,8 @@ impl RTDSendCallSignalingDataBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -20241,6 +23431,9 @@ pub struct SendChatAction {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The action description
@@ -20250,13 +23443,16 @@ pub struct SendChatAction {
 
 impl RObject for SendChatAction {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendChatAction" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendChatAction {}
+impl RFunction for SendChatAction {
+  type Response = Ok;
+}
 
 impl SendChatAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20280,6 +23476,11 @@ pub struct RTDSendChatActionBuilder {
 impl RTDSendChatActionBuilder {
   pub fn build(&self) -> SendChatAction { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -20314,6 +23515,9 @@ pub struct SendChatScreenshotTakenNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   
@@ -20321,13 +23525,16 @@ pub struct SendChatScreenshotTakenNotification {
 
 impl RObject for SendChatScreenshotTakenNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendChatScreenshotTakenNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendChatScreenshotTakenNotification {}
+impl RFunction for SendChatScreenshotTakenNotification {
+  type Response = Ok;
+}
 
 impl SendChatScreenshotTakenNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20349,6 +23556,11 @@ pub struct RTDSendChatScreenshotTakenNotificationBuilder {
 impl RTDSendChatScreenshotTakenNotificationBuilder {
   pub fn build(&self) -> SendChatScreenshotTakenNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -20377,6 +23589,9 @@ pub struct SendChatSetTtlMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New TTL value, in seconds
@@ -20386,13 +23601,16 @@ pub struct SendChatSetTtlMessage {
 
 impl RObject for SendChatSetTtlMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendChatSetTtlMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendChatSetTtlMessage {}
+impl RFunction for SendChatSetTtlMessage {
+  type Response = Message;
+}
 
 impl SendChatSetTtlMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20416,6 +23634,11 @@ pub struct RTDSendChatSetTtlMessageBuilder {
 impl RTDSendChatSetTtlMessageBuilder {
   pub fn build(&self) -> SendChatSetTtlMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -20450,6 +23673,9 @@ pub struct SendCustomRequest {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The method name
   method: String,
   /// JSON-serialized method parameters
@@ -20459,13 +23685,16 @@ pub struct SendCustomRequest {
 
 impl RObject for SendCustomRequest {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendCustomRequest" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendCustomRequest {}
+impl RFunction for SendCustomRequest {
+  type Response = CustomRequestResult;
+}
 
 impl SendCustomRequest {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20489,6 +23718,11 @@ pub struct RTDSendCustomRequestBuilder {
 impl RTDSendCustomRequestBuilder {
   pub fn build(&self) -> SendCustomRequest { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn method<T: AsRef<str>>(&mut self, method: T) -> &mut Self {
     self.inner.method = method.as_ref().to_string();
@@ -20523,6 +23757,9 @@ pub struct SendEmailAddressVerificationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Email address
   email_address: String,
   
@@ -20530,13 +23767,16 @@ pub struct SendEmailAddressVerificationCode {
 
 impl RObject for SendEmailAddressVerificationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendEmailAddressVerificationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendEmailAddressVerificationCode {}
+impl RFunction for SendEmailAddressVerificationCode {
+  type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl SendEmailAddressVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20558,6 +23798,11 @@ pub struct RTDSendEmailAddressVerificationCodeBuilder {
 impl RTDSendEmailAddressVerificationCodeBuilder {
   pub fn build(&self) -> SendEmailAddressVerificationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
@@ -20586,6 +23831,9 @@ pub struct SendInlineQueryResultMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Target chat
   chat_id: i64,
   /// Identifier of a message to reply to or 0
@@ -20593,7 +23841,7 @@ pub struct SendInlineQueryResultMessage {
   /// Options to be used to send the message
   options: MessageSendOptions,
   /// Identifier of the inline query
-  query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] query_id: i64,
   /// Identifier of the inline result
   result_id: String,
   /// If true, there will be no mention of a bot, via which the message is sent. Can be used only for bots GetOption("animation_search_bot_username"), GetOption("photo_search_bot_username") and GetOption("venue_search_bot_username")
@@ -20603,13 +23851,16 @@ pub struct SendInlineQueryResultMessage {
 
 impl RObject for SendInlineQueryResultMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendInlineQueryResultMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendInlineQueryResultMessage {}
+impl RFunction for SendInlineQueryResultMessage {
+  type Response = Message;
+}
 
 impl SendInlineQueryResultMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20625,7 +23876,7 @@ impl SendInlineQueryResultMessage {
 
   pub fn options(&self) -> &MessageSendOptions { &self.options }
 
-  pub fn query_id(&self) -> isize { self.query_id }
+  pub fn query_id(&self) -> i64 { self.query_id }
 
   pub fn result_id(&self) -> &String { &self.result_id }
 
@@ -20641,6 +23892,11 @@ pub struct RTDSendInlineQueryResultMessageBuilder {
 impl RTDSendInlineQueryResultMessageBuilder {
   pub fn build(&self) -> SendInlineQueryResultMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -20660,7 +23916,7 @@ impl RTDSendInlineQueryResultMessageBuilder {
   }
 
    
-  pub fn query_id(&mut self, query_id: isize) -> &mut Self {
+  pub fn query_id(&mut self, query_id: i64) -> &mut Self {
     self.inner.query_id = query_id;
     self
   }
@@ -20699,6 +23955,9 @@ pub struct SendMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Target chat
   chat_id: i64,
   /// Identifier of the message to reply to or 0
@@ -20714,13 +23973,16 @@ pub struct SendMessage {
 
 impl RObject for SendMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendMessage {}
+impl RFunction for SendMessage {
+  type Response = Message;
+}
 
 impl SendMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20750,6 +24012,11 @@ pub struct RTDSendMessageBuilder {
 impl RTDSendMessageBuilder {
   pub fn build(&self) -> SendMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -20802,6 +24069,9 @@ pub struct SendMessageAlbum {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Target chat
   chat_id: i64,
   /// Identifier of a message to reply to or 0
@@ -20815,13 +24085,16 @@ pub struct SendMessageAlbum {
 
 impl RObject for SendMessageAlbum {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendMessageAlbum" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendMessageAlbum {}
+impl RFunction for SendMessageAlbum {
+  type Response = Messages;
+}
 
 impl SendMessageAlbum {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20849,6 +24122,11 @@ pub struct RTDSendMessageAlbumBuilder {
 impl RTDSendMessageAlbumBuilder {
   pub fn build(&self) -> SendMessageAlbum { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -20895,6 +24173,9 @@ pub struct SendPassportAuthorizationForm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Authorization form identifier
   autorization_form_id: i64,
   /// Types of Telegram Passport elements chosen by user to complete the authorization form
@@ -20904,13 +24185,16 @@ pub struct SendPassportAuthorizationForm {
 
 impl RObject for SendPassportAuthorizationForm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendPassportAuthorizationForm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendPassportAuthorizationForm {}
+impl RFunction for SendPassportAuthorizationForm {
+  type Response = Ok;
+}
 
 impl SendPassportAuthorizationForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20934,6 +24218,11 @@ pub struct RTDSendPassportAuthorizationFormBuilder {
 impl RTDSendPassportAuthorizationFormBuilder {
   pub fn build(&self) -> SendPassportAuthorizationForm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn autorization_form_id(&mut self, autorization_form_id: i64) -> &mut Self {
     self.inner.autorization_form_id = autorization_form_id;
@@ -20968,6 +24257,9 @@ pub struct SendPaymentForm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the Invoice message
   chat_id: i64,
   /// Message identifier
@@ -20983,13 +24275,16 @@ pub struct SendPaymentForm {
 
 impl RObject for SendPaymentForm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendPaymentForm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendPaymentForm {}
+impl RFunction for SendPaymentForm {
+  type Response = PaymentResult;
+}
 
 impl SendPaymentForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21019,6 +24314,11 @@ pub struct RTDSendPaymentFormBuilder {
 impl RTDSendPaymentFormBuilder {
   pub fn build(&self) -> SendPaymentForm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -21071,6 +24371,9 @@ pub struct SendPhoneNumberConfirmationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Value of the "hash" parameter from the link
   hash: String,
   /// Value of the "phone" parameter from the link
@@ -21082,13 +24385,16 @@ pub struct SendPhoneNumberConfirmationCode {
 
 impl RObject for SendPhoneNumberConfirmationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendPhoneNumberConfirmationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendPhoneNumberConfirmationCode {}
+impl RFunction for SendPhoneNumberConfirmationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl SendPhoneNumberConfirmationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21114,6 +24420,11 @@ pub struct RTDSendPhoneNumberConfirmationCodeBuilder {
 impl RTDSendPhoneNumberConfirmationCodeBuilder {
   pub fn build(&self) -> SendPhoneNumberConfirmationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
     self.inner.hash = hash.as_ref().to_string();
@@ -21154,6 +24465,9 @@ pub struct SendPhoneNumberVerificationCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The phone number of the user, in international format
   phone_number: String,
   /// Settings for the authentication of the user's phone number
@@ -21163,13 +24477,16 @@ pub struct SendPhoneNumberVerificationCode {
 
 impl RObject for SendPhoneNumberVerificationCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sendPhoneNumberVerificationCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SendPhoneNumberVerificationCode {}
+impl RFunction for SendPhoneNumberVerificationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl SendPhoneNumberVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21193,6 +24510,11 @@ pub struct RTDSendPhoneNumberVerificationCodeBuilder {
 impl RTDSendPhoneNumberVerificationCodeBuilder {
   pub fn build(&self) -> SendPhoneNumberVerificationCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -21227,6 +24549,9 @@ pub struct SetAccountTtl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New account TTL
   ttl: AccountTtl,
   
@@ -21234,13 +24559,16 @@ pub struct SetAccountTtl {
 
 impl RObject for SetAccountTtl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setAccountTtl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetAccountTtl {}
+impl RFunction for SetAccountTtl {
+  type Response = Ok;
+}
 
 impl SetAccountTtl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21262,6 +24590,11 @@ pub struct RTDSetAccountTtlBuilder {
 impl RTDSetAccountTtlBuilder {
   pub fn build(&self) -> SetAccountTtl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn ttl<T: AsRef<AccountTtl>>(&mut self, ttl: T) -> &mut Self {
     self.inner.ttl = ttl.as_ref().clone();
@@ -21290,6 +24623,9 @@ pub struct SetAlarm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Number of seconds before the function returns
   seconds: f32,
   
@@ -21297,13 +24633,16 @@ pub struct SetAlarm {
 
 impl RObject for SetAlarm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setAlarm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetAlarm {}
+impl RFunction for SetAlarm {
+  type Response = Ok;
+}
 
 impl SetAlarm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21325,6 +24664,11 @@ pub struct RTDSetAlarmBuilder {
 impl RTDSetAlarmBuilder {
   pub fn build(&self) -> SetAlarm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn seconds(&mut self, seconds: f32) -> &mut Self {
     self.inner.seconds = seconds;
@@ -21353,6 +24697,9 @@ pub struct SetAuthenticationPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The phone number of the user, in international format
   phone_number: String,
   /// Settings for the authentication of the user's phone number
@@ -21362,13 +24709,16 @@ pub struct SetAuthenticationPhoneNumber {
 
 impl RObject for SetAuthenticationPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setAuthenticationPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetAuthenticationPhoneNumber {}
+impl RFunction for SetAuthenticationPhoneNumber {
+  type Response = Ok;
+}
 
 impl SetAuthenticationPhoneNumber {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21392,6 +24742,11 @@ pub struct RTDSetAuthenticationPhoneNumberBuilder {
 impl RTDSetAuthenticationPhoneNumberBuilder {
   pub fn build(&self) -> SetAuthenticationPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -21426,6 +24781,9 @@ pub struct SetAutoDownloadSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New user auto-download settings
   settings: AutoDownloadSettings,
   /// Type of the network for which the new settings are applied
@@ -21435,13 +24793,16 @@ pub struct SetAutoDownloadSettings {
 
 impl RObject for SetAutoDownloadSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setAutoDownloadSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetAutoDownloadSettings {}
+impl RFunction for SetAutoDownloadSettings {
+  type Response = Ok;
+}
 
 impl SetAutoDownloadSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21465,6 +24826,11 @@ pub struct RTDSetAutoDownloadSettingsBuilder {
 impl RTDSetAutoDownloadSettingsBuilder {
   pub fn build(&self) -> SetAutoDownloadSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn settings<T: AsRef<AutoDownloadSettings>>(&mut self, settings: T) -> &mut Self {
     self.inner.settings = settings.as_ref().clone();
@@ -21499,6 +24865,9 @@ pub struct SetBackground {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The input background to use, null for filled backgrounds
   background: InputBackground,
   /// Background type; null for default background. The method will return error 404 if type is null
@@ -21510,13 +24879,16 @@ pub struct SetBackground {
 
 impl RObject for SetBackground {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setBackground" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetBackground {}
+impl RFunction for SetBackground {
+  type Response = Background;
+}
 
 impl SetBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21542,6 +24914,11 @@ pub struct RTDSetBackgroundBuilder {
 impl RTDSetBackgroundBuilder {
   pub fn build(&self) -> SetBackground { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn background<T: AsRef<InputBackground>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
@@ -21582,6 +24959,9 @@ pub struct SetBio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new value of the user bio; 0-70 characters without line feeds
   bio: String,
   
@@ -21589,13 +24969,16 @@ pub struct SetBio {
 
 impl RObject for SetBio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setBio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetBio {}
+impl RFunction for SetBio {
+  type Response = Ok;
+}
 
 impl SetBio {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21617,6 +25000,11 @@ pub struct RTDSetBioBuilder {
 impl RTDSetBioBuilder {
   pub fn build(&self) -> SetBio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bio<T: AsRef<str>>(&mut self, bio: T) -> &mut Self {
     self.inner.bio = bio.as_ref().to_string();
@@ -21645,6 +25033,9 @@ pub struct SetBotUpdatesStatus {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The number of pending updates
   pending_update_count: i64,
   /// The last error message
@@ -21654,13 +25045,16 @@ pub struct SetBotUpdatesStatus {
 
 impl RObject for SetBotUpdatesStatus {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setBotUpdatesStatus" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetBotUpdatesStatus {}
+impl RFunction for SetBotUpdatesStatus {
+  type Response = Ok;
+}
 
 impl SetBotUpdatesStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21684,6 +25078,11 @@ pub struct RTDSetBotUpdatesStatusBuilder {
 impl RTDSetBotUpdatesStatusBuilder {
   pub fn build(&self) -> SetBotUpdatesStatus { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn pending_update_count(&mut self, pending_update_count: i64) -> &mut Self {
     self.inner.pending_update_count = pending_update_count;
@@ -21718,6 +25117,9 @@ pub struct SetChatClientData {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New value of client_data
@@ -21727,13 +25129,16 @@ pub struct SetChatClientData {
 
 impl RObject for SetChatClientData {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatClientData" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatClientData {}
+impl RFunction for SetChatClientData {
+  type Response = Ok;
+}
 
 impl SetChatClientData {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21757,6 +25162,11 @@ pub struct RTDSetChatClientDataBuilder {
 impl RTDSetChatClientDataBuilder {
   pub fn build(&self) -> SetChatClientData { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -21791,6 +25201,9 @@ pub struct SetChatDescription {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat
   chat_id: i64,
   /// Changes information about a chat. Available for basic groups, supergroups, and channels. Requires can_change_info rights
@@ -21800,13 +25213,16 @@ pub struct SetChatDescription {
 
 impl RObject for SetChatDescription {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatDescription" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatDescription {}
+impl RFunction for SetChatDescription {
+  type Response = Ok;
+}
 
 impl SetChatDescription {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21830,6 +25246,11 @@ pub struct RTDSetChatDescriptionBuilder {
 impl RTDSetChatDescriptionBuilder {
   pub fn build(&self) -> SetChatDescription { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -21864,6 +25285,9 @@ pub struct SetChatDiscussionGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the channel chat. Pass 0 to remove a link from the supergroup passed in the second argument to a linked channel chat (requires can_pin_messages rights in the supergroup)
   chat_id: i64,
   /// Identifier of a new channel's discussion group. Use 0 to remove the discussion group. Use the method getSuitableDiscussionChats to find all suitable groups. Basic group chats needs to be first upgraded to supergroup chats. If new chat members don't have access to old messages in the supergroup, then toggleSupergroupIsAllHistoryAvailable needs to be used first to change that
@@ -21873,13 +25297,16 @@ pub struct SetChatDiscussionGroup {
 
 impl RObject for SetChatDiscussionGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatDiscussionGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatDiscussionGroup {}
+impl RFunction for SetChatDiscussionGroup {
+  type Response = Ok;
+}
 
 impl SetChatDiscussionGroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21903,6 +25330,11 @@ pub struct RTDSetChatDiscussionGroupBuilder {
 impl RTDSetChatDiscussionGroupBuilder {
   pub fn build(&self) -> SetChatDiscussionGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -21937,6 +25369,9 @@ pub struct SetChatDraftMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New draft message; may be null
@@ -21946,13 +25381,16 @@ pub struct SetChatDraftMessage {
 
 impl RObject for SetChatDraftMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatDraftMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatDraftMessage {}
+impl RFunction for SetChatDraftMessage {
+  type Response = Ok;
+}
 
 impl SetChatDraftMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21976,6 +25414,11 @@ pub struct RTDSetChatDraftMessageBuilder {
 impl RTDSetChatDraftMessageBuilder {
   pub fn build(&self) -> SetChatDraftMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22010,6 +25453,9 @@ pub struct SetChatLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New location for the chat; must be valid and not null
@@ -22019,13 +25465,16 @@ pub struct SetChatLocation {
 
 impl RObject for SetChatLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatLocation {}
+impl RFunction for SetChatLocation {
+  type Response = Ok;
+}
 
 impl SetChatLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22049,6 +25498,11 @@ pub struct RTDSetChatLocationBuilder {
 impl RTDSetChatLocationBuilder {
   pub fn build(&self) -> SetChatLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22083,6 +25537,9 @@ pub struct SetChatMemberStatus {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// User identifier
@@ -22094,13 +25551,16 @@ pub struct SetChatMemberStatus {
 
 impl RObject for SetChatMemberStatus {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatMemberStatus" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatMemberStatus {}
+impl RFunction for SetChatMemberStatus {
+  type Response = Ok;
+}
 
 impl SetChatMemberStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22126,6 +25586,11 @@ pub struct RTDSetChatMemberStatusBuilder {
 impl RTDSetChatMemberStatusBuilder {
   pub fn build(&self) -> SetChatMemberStatus { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22166,6 +25631,9 @@ pub struct SetChatNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New notification settings for the chat. If the chat is muted for more than 1 week, it is considered to be muted forever
@@ -22175,13 +25643,16 @@ pub struct SetChatNotificationSettings {
 
 impl RObject for SetChatNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatNotificationSettings {}
+impl RFunction for SetChatNotificationSettings {
+  type Response = Ok;
+}
 
 impl SetChatNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22205,6 +25676,11 @@ pub struct RTDSetChatNotificationSettingsBuilder {
 impl RTDSetChatNotificationSettingsBuilder {
   pub fn build(&self) -> SetChatNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22239,6 +25715,9 @@ pub struct SetChatPermissions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New non-administrator members permissions in the chat
@@ -22248,13 +25727,16 @@ pub struct SetChatPermissions {
 
 impl RObject for SetChatPermissions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatPermissions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatPermissions {}
+impl RFunction for SetChatPermissions {
+  type Response = Ok;
+}
 
 impl SetChatPermissions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22278,6 +25760,11 @@ pub struct RTDSetChatPermissionsBuilder {
 impl RTDSetChatPermissionsBuilder {
   pub fn build(&self) -> SetChatPermissions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22312,6 +25799,9 @@ pub struct SetChatPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New chat photo. You can pass null to delete the chat photo
@@ -22321,13 +25811,16 @@ pub struct SetChatPhoto {
 
 impl RObject for SetChatPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatPhoto {}
+impl RFunction for SetChatPhoto {
+  type Response = Ok;
+}
 
 impl SetChatPhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22351,6 +25844,11 @@ pub struct RTDSetChatPhotoBuilder {
 impl RTDSetChatPhotoBuilder {
   pub fn build(&self) -> SetChatPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22385,6 +25883,9 @@ pub struct SetChatSlowModeDelay {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New slow mode delay for the chat; must be one of 0, 10, 30, 60, 300, 900, 3600
@@ -22394,13 +25895,16 @@ pub struct SetChatSlowModeDelay {
 
 impl RObject for SetChatSlowModeDelay {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatSlowModeDelay" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatSlowModeDelay {}
+impl RFunction for SetChatSlowModeDelay {
+  type Response = Ok;
+}
 
 impl SetChatSlowModeDelay {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22424,6 +25928,11 @@ pub struct RTDSetChatSlowModeDelayBuilder {
 impl RTDSetChatSlowModeDelayBuilder {
   pub fn build(&self) -> SetChatSlowModeDelay { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22458,6 +25967,9 @@ pub struct SetChatTitle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New title of the chat; 1-128 characters
@@ -22467,13 +25979,16 @@ pub struct SetChatTitle {
 
 impl RObject for SetChatTitle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setChatTitle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetChatTitle {}
+impl RFunction for SetChatTitle {
+  type Response = Ok;
+}
 
 impl SetChatTitle {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22497,6 +26012,11 @@ pub struct RTDSetChatTitleBuilder {
 impl RTDSetChatTitleBuilder {
   pub fn build(&self) -> SetChatTitle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22531,6 +26051,9 @@ pub struct SetCommands {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of the bot's commands
   commands: Vec<BotCommand>,
   
@@ -22538,13 +26061,16 @@ pub struct SetCommands {
 
 impl RObject for SetCommands {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setCommands" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetCommands {}
+impl RFunction for SetCommands {
+  type Response = Ok;
+}
 
 impl SetCommands {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22566,6 +26092,11 @@ pub struct RTDSetCommandsBuilder {
 impl RTDSetCommandsBuilder {
   pub fn build(&self) -> SetCommands { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn commands(&mut self, commands: Vec<BotCommand>) -> &mut Self {
     self.inner.commands = commands;
@@ -22594,6 +26125,9 @@ pub struct SetCustomLanguagePack {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Information about the language pack. Language pack ID must start with 'X', consist only of English letters, digits and hyphens, and must not exceed 64 characters. Can be called before authorization
   info: LanguagePackInfo,
   /// Strings of the new language pack
@@ -22603,13 +26137,16 @@ pub struct SetCustomLanguagePack {
 
 impl RObject for SetCustomLanguagePack {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setCustomLanguagePack" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetCustomLanguagePack {}
+impl RFunction for SetCustomLanguagePack {
+  type Response = Ok;
+}
 
 impl SetCustomLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22633,6 +26170,11 @@ pub struct RTDSetCustomLanguagePackBuilder {
 impl RTDSetCustomLanguagePackBuilder {
   pub fn build(&self) -> SetCustomLanguagePack { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn info<T: AsRef<LanguagePackInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
@@ -22667,6 +26209,9 @@ pub struct SetCustomLanguagePackString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of a previously added custom local language pack in the current localization target
   language_pack_id: String,
   /// New language pack string
@@ -22676,13 +26221,16 @@ pub struct SetCustomLanguagePackString {
 
 impl RObject for SetCustomLanguagePackString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setCustomLanguagePackString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetCustomLanguagePackString {}
+impl RFunction for SetCustomLanguagePackString {
+  type Response = Ok;
+}
 
 impl SetCustomLanguagePackString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22706,6 +26254,11 @@ pub struct RTDSetCustomLanguagePackStringBuilder {
 impl RTDSetCustomLanguagePackStringBuilder {
   pub fn build(&self) -> SetCustomLanguagePackString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -22740,20 +26293,26 @@ pub struct SetDatabaseEncryptionKey {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New encryption key
-  new_encryption_key: String,
+  new_encryption_key: TdBytes,
   
 }
 
 impl RObject for SetDatabaseEncryptionKey {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setDatabaseEncryptionKey" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetDatabaseEncryptionKey {}
+impl RFunction for SetDatabaseEncryptionKey {
+  type Response = Ok;
+}
 
 impl SetDatabaseEncryptionKey {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22763,7 +26322,7 @@ impl SetDatabaseEncryptionKey {
     RTDSetDatabaseEncryptionKeyBuilder { inner }
   }
 
-  pub fn new_encryption_key(&self) -> &String { &self.new_encryption_key }
+  pub fn new_encryption_key(&self) -> &TdBytes { &self.new_encryption_key }
 
 }
 
@@ -22775,9 +26334,14 @@ pub struct RTDSetDatabaseEncryptionKeyBuilder {
 impl RTDSetDatabaseEncryptionKeyBuilder {
   pub fn build(&self) -> SetDatabaseEncryptionKey { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn new_encryption_key<T: AsRef<str>>(&mut self, new_encryption_key: T) -> &mut Self {
-    self.inner.new_encryption_key = new_encryption_key.as_ref().to_string();
+  pub fn new_encryption_key<T: AsRef<[u8]>>(&mut self, new_encryption_key: T) -> &mut Self {
+    self.inner.new_encryption_key = TdBytes::from(new_encryption_key.as_ref());
     self
   }
 
@@ -22803,8 +26367,11 @@ pub struct SetFileGenerationProgress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identifier of the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] generation_id: i64,
   /// Expected size of the generated file, in bytes; 0 if unknown
   expected_size: i64,
   /// The number of bytes already generated
@@ -22814,13 +26381,16 @@ pub struct SetFileGenerationProgress {
 
 impl RObject for SetFileGenerationProgress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setFileGenerationProgress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetFileGenerationProgress {}
+impl RFunction for SetFileGenerationProgress {
+  type Response = Ok;
+}
 
 impl SetFileGenerationProgress {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22830,7 +26400,7 @@ impl SetFileGenerationProgress {
     RTDSetFileGenerationProgressBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn expected_size(&self) -> i64 { self.expected_size }
 
@@ -22846,8 +26416,13 @@ pub struct RTDSetFileGenerationProgressBuilder {
 impl RTDSetFileGenerationProgressBuilder {
   pub fn build(&self) -> SetFileGenerationProgress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
@@ -22886,6 +26461,9 @@ pub struct SetGameScore {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat to which the message with the game belongs
   chat_id: i64,
   /// Identifier of the message
@@ -22903,13 +26481,16 @@ pub struct SetGameScore {
 
 impl RObject for SetGameScore {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setGameScore" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetGameScore {}
+impl RFunction for SetGameScore {
+  type Response = Message;
+}
 
 impl SetGameScore {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22941,6 +26522,11 @@ pub struct RTDSetGameScoreBuilder {
 impl RTDSetGameScoreBuilder {
   pub fn build(&self) -> SetGameScore { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -22999,6 +26585,9 @@ pub struct SetInlineGameScore {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline message identifier
   inline_message_id: String,
   /// True, if the message should be edited
@@ -23014,13 +26603,16 @@ pub struct SetInlineGameScore {
 
 impl RObject for SetInlineGameScore {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setInlineGameScore" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetInlineGameScore {}
+impl RFunction for SetInlineGameScore {
+  type Response = Ok;
+}
 
 impl SetInlineGameScore {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23050,6 +26642,11 @@ pub struct RTDSetInlineGameScoreBuilder {
 impl RTDSetInlineGameScoreBuilder {
   pub fn build(&self) -> SetInlineGameScore { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
@@ -23102,6 +26699,9 @@ pub struct SetLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new location of the user
   location: Location,
   
@@ -23109,13 +26709,16 @@ pub struct SetLocation {
 
 impl RObject for SetLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetLocation {}
+impl RFunction for SetLocation {
+  type Response = Ok;
+}
 
 impl SetLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23137,6 +26740,11 @@ pub struct RTDSetLocationBuilder {
 impl RTDSetLocationBuilder {
   pub fn build(&self) -> SetLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -23165,6 +26773,9 @@ pub struct SetLogStream {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New log stream
   log_stream: LogStream,
   
@@ -23172,13 +26783,16 @@ pub struct SetLogStream {
 
 impl RObject for SetLogStream {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setLogStream" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetLogStream {}
+impl RFunction for SetLogStream {
+  type Response = Ok;
+}
 
 impl SetLogStream {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23200,6 +26814,11 @@ pub struct RTDSetLogStreamBuilder {
 impl RTDSetLogStreamBuilder {
   pub fn build(&self) -> SetLogStream { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn log_stream<T: AsRef<LogStream>>(&mut self, log_stream: T) -> &mut Self {
     self.inner.log_stream = log_stream.as_ref().clone();
@@ -23228,6 +26847,9 @@ pub struct SetLogTagVerbosityLevel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Logging tag to change verbosity level
   tag: String,
   /// New verbosity level; 1-1024
@@ -23237,13 +26859,16 @@ pub struct SetLogTagVerbosityLevel {
 
 impl RObject for SetLogTagVerbosityLevel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setLogTagVerbosityLevel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetLogTagVerbosityLevel {}
+impl RFunction for SetLogTagVerbosityLevel {
+  type Response = Ok;
+}
 
 impl SetLogTagVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23267,6 +26892,11 @@ pub struct RTDSetLogTagVerbosityLevelBuilder {
 impl RTDSetLogTagVerbosityLevelBuilder {
   pub fn build(&self) -> SetLogTagVerbosityLevel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn tag<T: AsRef<str>>(&mut self, tag: T) -> &mut Self {
     self.inner.tag = tag.as_ref().to_string();
@@ -23301,6 +26931,9 @@ pub struct SetLogVerbosityLevel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New value of the verbosity level for logging. Value 0 corresponds to fatal errors, value 1 corresponds to errors, value 2 corresponds to warnings and debug warnings, value 3 corresponds to informational, value 4 corresponds to debug, value 5 corresponds to verbose debug, value greater than 5 and up to 1023 can be used to enable even more logging
   new_verbosity_level: i64,
   
@@ -23308,13 +26941,16 @@ pub struct SetLogVerbosityLevel {
 
 impl RObject for SetLogVerbosityLevel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setLogVerbosityLevel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetLogVerbosityLevel {}
+impl RFunction for SetLogVerbosityLevel {
+  type Response = Ok;
+}
 
 impl SetLogVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23336,6 +26972,11 @@ pub struct RTDSetLogVerbosityLevelBuilder {
 impl RTDSetLogVerbosityLevelBuilder {
   pub fn build(&self) -> SetLogVerbosityLevel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn new_verbosity_level(&mut self, new_verbosity_level: i64) -> &mut Self {
     self.inner.new_verbosity_level = new_verbosity_level;
@@ -23364,6 +27005,9 @@ pub struct SetName {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new value of the first name for the user; 1-64 characters
   first_name: String,
   /// The new value of the optional last name for the user; 0-64 characters
@@ -23373,13 +27017,16 @@ pub struct SetName {
 
 impl RObject for SetName {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setName" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetName {}
+impl RFunction for SetName {
+  type Response = Ok;
+}
 
 impl SetName {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23403,6 +27050,11 @@ pub struct RTDSetNameBuilder {
 impl RTDSetNameBuilder {
   pub fn build(&self) -> SetName { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
@@ -23437,6 +27089,9 @@ pub struct SetNetworkType {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new network type. By default, networkTypeOther
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: NetworkType,
   
@@ -23444,13 +27099,16 @@ pub struct SetNetworkType {
 
 impl RObject for SetNetworkType {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setNetworkType" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetNetworkType {}
+impl RFunction for SetNetworkType {
+  type Response = Ok;
+}
 
 impl SetNetworkType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23472,6 +27130,11 @@ pub struct RTDSetNetworkTypeBuilder {
 impl RTDSetNetworkTypeBuilder {
   pub fn build(&self) -> SetNetworkType { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<NetworkType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -23500,6 +27163,9 @@ pub struct SetOption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The name of the option
   name: String,
   /// The new value of the option
@@ -23509,13 +27175,16 @@ pub struct SetOption {
 
 impl RObject for SetOption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setOption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetOption {}
+impl RFunction for SetOption {
+  type Response = Ok;
+}
 
 impl SetOption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23539,6 +27208,11 @@ pub struct RTDSetOptionBuilder {
 impl RTDSetOptionBuilder {
   pub fn build(&self) -> SetOption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -23573,6 +27247,9 @@ pub struct SetPassportElement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Input Telegram Passport element
   element: InputPassportElement,
   /// Password of the current user
@@ -23582,13 +27259,16 @@ pub struct SetPassportElement {
 
 impl RObject for SetPassportElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setPassportElement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDPassportElement for SetPassportElement {}
 
-impl RFunction for SetPassportElement {}
+
+impl RFunction for SetPassportElement {
+  type Response = PassportElement;
+}
 
 impl SetPassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23612,6 +27292,11 @@ pub struct RTDSetPassportElementBuilder {
 impl RTDSetPassportElementBuilder {
   pub fn build(&self) -> SetPassportElement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn element<T: AsRef<InputPassportElement>>(&mut self, element: T) -> &mut Self {
     self.inner.element = element.as_ref().clone();
@@ -23646,6 +27331,9 @@ pub struct SetPassportElementErrors {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// The errors
@@ -23655,13 +27343,16 @@ pub struct SetPassportElementErrors {
 
 impl RObject for SetPassportElementErrors {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setPassportElementErrors" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetPassportElementErrors {}
+impl RFunction for SetPassportElementErrors {
+  type Response = Ok;
+}
 
 impl SetPassportElementErrors {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23685,6 +27376,11 @@ pub struct RTDSetPassportElementErrorsBuilder {
 impl RTDSetPassportElementErrorsBuilder {
   pub fn build(&self) -> SetPassportElementErrors { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -23719,6 +27415,9 @@ pub struct SetPassword {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Previous password of the user
   old_password: String,
   /// New password of the user; may be empty to remove the password
@@ -23734,13 +27433,16 @@ pub struct SetPassword {
 
 impl RObject for SetPassword {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setPassword" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetPassword {}
+impl RFunction for SetPassword {
+  type Response = PasswordState;
+}
 
 impl SetPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23770,6 +27472,11 @@ pub struct RTDSetPasswordBuilder {
 impl RTDSetPasswordBuilder {
   pub fn build(&self) -> SetPassword { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_password<T: AsRef<str>>(&mut self, old_password: T) -> &mut Self {
     self.inner.old_password = old_password.as_ref().to_string();
@@ -23822,6 +27529,9 @@ pub struct SetPinnedChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat list in which to change the order of pinned chats
   chat_list: ChatList,
   /// The new list of pinned chats
@@ -23831,13 +27541,16 @@ pub struct SetPinnedChats {
 
 impl RObject for SetPinnedChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setPinnedChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetPinnedChats {}
+impl RFunction for SetPinnedChats {
+  type Response = Ok;
+}
 
 impl SetPinnedChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23861,6 +27574,11 @@ pub struct RTDSetPinnedChatsBuilder {
 impl RTDSetPinnedChatsBuilder {
   pub fn build(&self) -> SetPinnedChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -23895,6 +27613,9 @@ pub struct SetPollAnswer {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to which the poll belongs
   chat_id: i64,
   /// Identifier of the message containing the poll
@@ -23906,13 +27627,16 @@ pub struct SetPollAnswer {
 
 impl RObject for SetPollAnswer {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setPollAnswer" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetPollAnswer {}
+impl RFunction for SetPollAnswer {
+  type Response = Ok;
+}
 
 impl SetPollAnswer {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23938,6 +27662,11 @@ pub struct RTDSetPollAnswerBuilder {
 impl RTDSetPollAnswerBuilder {
   pub fn build(&self) -> SetPollAnswer { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -23978,6 +27707,9 @@ pub struct SetProfilePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Profile photo to set
   photo: InputChatPhoto,
   
@@ -23985,13 +27717,16 @@ pub struct SetProfilePhoto {
 
 impl RObject for SetProfilePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setProfilePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetProfilePhoto {}
+impl RFunction for SetProfilePhoto {
+  type Response = Ok;
+}
 
 impl SetProfilePhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24013,6 +27748,11 @@ pub struct RTDSetProfilePhotoBuilder {
 impl RTDSetProfilePhotoBuilder {
   pub fn build(&self) -> SetProfilePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<InputChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -24041,6 +27781,9 @@ pub struct SetRecoveryEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Password of the current user
   password: String,
   /// New recovery email address
@@ -24050,13 +27793,16 @@ pub struct SetRecoveryEmailAddress {
 
 impl RObject for SetRecoveryEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setRecoveryEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetRecoveryEmailAddress {}
+impl RFunction for SetRecoveryEmailAddress {
+  type Response = PasswordState;
+}
 
 impl SetRecoveryEmailAddress {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24080,6 +27826,11 @@ pub struct RTDSetRecoveryEmailAddressBuilder {
 impl RTDSetRecoveryEmailAddressBuilder {
   pub fn build(&self) -> SetRecoveryEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
@@ -24114,6 +27865,9 @@ pub struct SetScopeNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Types of chats for which to change the notification settings
   scope: NotificationSettingsScope,
   /// The new notification settings for the given scope
@@ -24123,13 +27877,16 @@ pub struct SetScopeNotificationSettings {
 
 impl RObject for SetScopeNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setScopeNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetScopeNotificationSettings {}
+impl RFunction for SetScopeNotificationSettings {
+  type Response = Ok;
+}
 
 impl SetScopeNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24153,6 +27910,11 @@ pub struct RTDSetScopeNotificationSettingsBuilder {
 impl RTDSetScopeNotificationSettingsBuilder {
   pub fn build(&self) -> SetScopeNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -24187,6 +27949,9 @@ pub struct SetStickerPositionInSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker
   sticker: InputFile,
   /// New position of the sticker in the set, zero-based
@@ -24196,13 +27961,16 @@ pub struct SetStickerPositionInSet {
 
 impl RObject for SetStickerPositionInSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setStickerPositionInSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetStickerPositionInSet {}
+impl RFunction for SetStickerPositionInSet {
+  type Response = Ok;
+}
 
 impl SetStickerPositionInSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24226,6 +27994,11 @@ pub struct RTDSetStickerPositionInSetBuilder {
 impl RTDSetStickerPositionInSetBuilder {
   pub fn build(&self) -> SetStickerPositionInSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -24260,6 +28033,9 @@ pub struct SetStickerSetThumbnail {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker set owner
   user_id: i64,
   /// Sticker set name
@@ -24271,13 +28047,16 @@ pub struct SetStickerSetThumbnail {
 
 impl RObject for SetStickerSetThumbnail {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setStickerSetThumbnail" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetStickerSetThumbnail {}
+impl RFunction for SetStickerSetThumbnail {
+  type Response = StickerSet;
+}
 
 impl SetStickerSetThumbnail {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24303,6 +28082,11 @@ pub struct RTDSetStickerSetThumbnailBuilder {
 impl RTDSetStickerSetThumbnailBuilder {
   pub fn build(&self) -> SetStickerSetThumbnail { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -24343,22 +28127,28 @@ pub struct SetSupergroupStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup
   supergroup_id: i64,
   /// New value of the supergroup sticker set identifier. Use 0 to remove the supergroup sticker set
-  sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] sticker_set_id: i64,
   
 }
 
 impl RObject for SetSupergroupStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setSupergroupStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetSupergroupStickerSet {}
+impl RFunction for SetSupergroupStickerSet {
+  type Response = Ok;
+}
 
 impl SetSupergroupStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24370,7 +28160,7 @@ impl SetSupergroupStickerSet {
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
 }
 
@@ -24382,6 +28172,11 @@ pub struct RTDSetSupergroupStickerSetBuilder {
 impl RTDSetSupergroupStickerSetBuilder {
   pub fn build(&self) -> SetSupergroupStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -24389,7 +28184,7 @@ impl RTDSetSupergroupStickerSetBuilder {
   }
 
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
     self
   }
@@ -24416,6 +28211,9 @@ pub struct SetSupergroupUsername {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup or channel
   supergroup_id: i64,
   /// New value of the username. Use an empty string to remove the username
@@ -24425,13 +28223,16 @@ pub struct SetSupergroupUsername {
 
 impl RObject for SetSupergroupUsername {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setSupergroupUsername" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetSupergroupUsername {}
+impl RFunction for SetSupergroupUsername {
+  type Response = Ok;
+}
 
 impl SetSupergroupUsername {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24455,6 +28256,11 @@ pub struct RTDSetSupergroupUsernameBuilder {
 impl RTDSetSupergroupUsernameBuilder {
   pub fn build(&self) -> SetSupergroupUsername { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -24489,6 +28295,9 @@ pub struct SetTdlibParameters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Parameters
   parameters: TdlibParameters,
   
@@ -24496,13 +28305,16 @@ pub struct SetTdlibParameters {
 
 impl RObject for SetTdlibParameters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setTdlibParameters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetTdlibParameters {}
+impl RFunction for SetTdlibParameters {
+  type Response = Ok;
+}
 
 impl SetTdlibParameters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24524,6 +28336,11 @@ pub struct RTDSetTdlibParametersBuilder {
 impl RTDSetTdlibParametersBuilder {
   pub fn build(&self) -> SetTdlibParameters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn parameters<T: AsRef<TdlibParameters>>(&mut self, parameters: T) -> &mut Self {
     self.inner.parameters = parameters.as_ref().clone();
@@ -24552,6 +28369,9 @@ pub struct SetUserPrivacySettingRules {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The privacy setting
   setting: UserPrivacySetting,
   /// The new privacy rules
@@ -24561,13 +28381,16 @@ pub struct SetUserPrivacySettingRules {
 
 impl RObject for SetUserPrivacySettingRules {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setUserPrivacySettingRules" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetUserPrivacySettingRules {}
+impl RFunction for SetUserPrivacySettingRules {
+  type Response = Ok;
+}
 
 impl SetUserPrivacySettingRules {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24591,6 +28414,11 @@ pub struct RTDSetUserPrivacySettingRulesBuilder {
 impl RTDSetUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> SetUserPrivacySettingRules { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -24625,6 +28453,9 @@ pub struct SetUsername {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new value of the username. Use an empty string to remove the username
   username: String,
   
@@ -24632,13 +28463,16 @@ pub struct SetUsername {
 
 impl RObject for SetUsername {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "setUsername" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SetUsername {}
+impl RFunction for SetUsername {
+  type Response = Ok;
+}
 
 impl SetUsername {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24660,6 +28494,11 @@ pub struct RTDSetUsernameBuilder {
 impl RTDSetUsernameBuilder {
   pub fn build(&self) -> SetUsername { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -24688,6 +28527,9 @@ pub struct SharePhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the user with whom to share the phone number. The user must be a mutual contact
   user_id: i64,
   
@@ -24695,13 +28537,16 @@ pub struct SharePhoneNumber {
 
 impl RObject for SharePhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sharePhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SharePhoneNumber {}
+impl RFunction for SharePhoneNumber {
+  type Response = Ok;
+}
 
 impl SharePhoneNumber {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24723,6 +28568,11 @@ pub struct RTDSharePhoneNumberBuilder {
 impl RTDSharePhoneNumberBuilder {
   pub fn build(&self) -> SharePhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -24751,6 +28601,9 @@ pub struct StopPoll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to which the poll belongs
   chat_id: i64,
   /// Identifier of the message containing the poll
@@ -24762,13 +28615,16 @@ pub struct StopPoll {
 
 impl RObject for StopPoll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "stopPoll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for StopPoll {}
+impl RFunction for StopPoll {
+  type Response = Ok;
+}
 
 impl StopPoll {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24794,6 +28650,11 @@ pub struct RTDStopPollBuilder {
 impl RTDStopPollBuilder {
   pub fn build(&self) -> StopPoll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -24834,6 +28695,9 @@ pub struct SynchronizeLanguagePack {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Language pack identifier
   language_pack_id: String,
   
@@ -24841,13 +28705,16 @@ pub struct SynchronizeLanguagePack {
 
 impl RObject for SynchronizeLanguagePack {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "synchronizeLanguagePack" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for SynchronizeLanguagePack {}
+impl RFunction for SynchronizeLanguagePack {
+  type Response = Ok;
+}
 
 impl SynchronizeLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24869,6 +28736,11 @@ pub struct RTDSynchronizeLanguagePackBuilder {
 impl RTDSynchronizeLanguagePackBuilder {
   pub fn build(&self) -> SynchronizeLanguagePack { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -24897,18 +28769,24 @@ pub struct TerminateAllOtherSessions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TerminateAllOtherSessions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "terminateAllOtherSessions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TerminateAllOtherSessions {}
+impl RFunction for TerminateAllOtherSessions {
+  type Response = Ok;
+}
 
 impl TerminateAllOtherSessions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24928,6 +28806,11 @@ pub struct RTDTerminateAllOtherSessionsBuilder {
 impl RTDTerminateAllOtherSessionsBuilder {
   pub fn build(&self) -> TerminateAllOtherSessions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TerminateAllOtherSessions> for TerminateAllOtherSessions {
@@ -24950,20 +28833,26 @@ pub struct TerminateSession {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Session identifier
-  session_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] session_id: i64,
   
 }
 
 impl RObject for TerminateSession {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "terminateSession" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TerminateSession {}
+impl RFunction for TerminateSession {
+  type Response = Ok;
+}
 
 impl TerminateSession {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24973,7 +28862,7 @@ impl TerminateSession {
     RTDTerminateSessionBuilder { inner }
   }
 
-  pub fn session_id(&self) -> isize { self.session_id }
+  pub fn session_id(&self) -> i64 { self.session_id }
 
 }
 
@@ -24985,8 +28874,13 @@ pub struct RTDTerminateSessionBuilder {
 impl RTDTerminateSessionBuilder {
   pub fn build(&self) -> TerminateSession { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn session_id(&mut self, session_id: isize) -> &mut Self {
+  pub fn session_id(&mut self, session_id: i64) -> &mut Self {
     self.inner.session_id = session_id;
     self
   }
@@ -25013,20 +28907,26 @@ pub struct TestCallBytes {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Bytes to return
-  x: String,
+  x: TdBytes,
   
 }
 
 impl RObject for TestCallBytes {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallBytes" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallBytes {}
+impl RFunction for TestCallBytes {
+  type Response = TestBytes;
+}
 
 impl TestCallBytes {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25036,7 +28936,7 @@ impl TestCallBytes {
     RTDTestCallBytesBuilder { inner }
   }
 
-  pub fn x(&self) -> &String { &self.x }
+  pub fn x(&self) -> &TdBytes { &self.x }
 
 }
 
@@ -25048,9 +28948,14 @@ pub struct RTDTestCallBytesBuilder {
 impl RTDTestCallBytesBuilder {
   pub fn build(&self) -> TestCallBytes { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn x<T: AsRef<str>>(&mut self, x: T) -> &mut Self {
-    self.inner.x = x.as_ref().to_string();
+  pub fn x<T: AsRef<[u8]>>(&mut self, x: T) -> &mut Self {
+    self.inner.x = TdBytes::from(x.as_ref());
     self
   }
 
@@ -25076,18 +28981,24 @@ pub struct TestCallEmpty {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TestCallEmpty {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallEmpty" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallEmpty {}
+impl RFunction for TestCallEmpty {
+  type Response = Ok;
+}
 
 impl TestCallEmpty {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25107,6 +29018,11 @@ pub struct RTDTestCallEmptyBuilder {
 impl RTDTestCallEmptyBuilder {
   pub fn build(&self) -> TestCallEmpty { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TestCallEmpty> for TestCallEmpty {
@@ -25129,6 +29045,9 @@ pub struct TestCallString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// String to return
   x: String,
   
@@ -25136,13 +29055,16 @@ pub struct TestCallString {
 
 impl RObject for TestCallString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallString {}
+impl RFunction for TestCallString {
+  type Response = TestString;
+}
 
 impl TestCallString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25164,6 +29086,11 @@ pub struct RTDTestCallStringBuilder {
 impl RTDTestCallStringBuilder {
   pub fn build(&self) -> TestCallString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x<T: AsRef<str>>(&mut self, x: T) -> &mut Self {
     self.inner.x = x.as_ref().to_string();
@@ -25192,6 +29119,9 @@ pub struct TestCallVectorInt {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Vector of numbers to return
   x: Vec<i64>,
   
@@ -25199,13 +29129,16 @@ pub struct TestCallVectorInt {
 
 impl RObject for TestCallVectorInt {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallVectorInt" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallVectorInt {}
+impl RFunction for TestCallVectorInt {
+  type Response = TestVectorInt;
+}
 
 impl TestCallVectorInt {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25227,6 +29160,11 @@ pub struct RTDTestCallVectorIntBuilder {
 impl RTDTestCallVectorIntBuilder {
   pub fn build(&self) -> TestCallVectorInt { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x(&mut self, x: Vec<i64>) -> &mut Self {
     self.inner.x = x;
@@ -25255,6 +29193,9 @@ pub struct TestCallVectorIntObject {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Vector of objects to return
   x: Vec<TestInt>,
   
@@ -25262,13 +29203,16 @@ pub struct TestCallVectorIntObject {
 
 impl RObject for TestCallVectorIntObject {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallVectorIntObject" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallVectorIntObject {}
+impl RFunction for TestCallVectorIntObject {
+  type Response = TestVectorIntObject;
+}
 
 impl TestCallVectorIntObject {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25290,6 +29234,11 @@ pub struct RTDTestCallVectorIntObjectBuilder {
 impl RTDTestCallVectorIntObjectBuilder {
   pub fn build(&self) -> TestCallVectorIntObject { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x(&mut self, x: Vec<TestInt>) -> &mut Self {
     self.inner.x = x;
@@ -25318,6 +29267,9 @@ pub struct TestCallVectorString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Vector of strings to return
   x: Vec<String>,
   
@@ -25325,13 +29277,16 @@ pub struct TestCallVectorString {
 
 impl RObject for TestCallVectorString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallVectorString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallVectorString {}
+impl RFunction for TestCallVectorString {
+  type Response = TestVectorString;
+}
 
 impl TestCallVectorString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25353,6 +29308,11 @@ pub struct RTDTestCallVectorStringBuilder {
 impl RTDTestCallVectorStringBuilder {
   pub fn build(&self) -> TestCallVectorString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x(&mut self, x: Vec<String>) -> &mut Self {
     self.inner.x = x;
@@ -25381,6 +29341,9 @@ pub struct TestCallVectorStringObject {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Vector of objects to return
   x: Vec<TestString>,
   
@@ -25388,13 +29351,16 @@ pub struct TestCallVectorStringObject {
 
 impl RObject for TestCallVectorStringObject {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testCallVectorStringObject" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestCallVectorStringObject {}
+impl RFunction for TestCallVectorStringObject {
+  type Response = TestVectorStringObject;
+}
 
 impl TestCallVectorStringObject {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25416,6 +29382,11 @@ pub struct RTDTestCallVectorStringObjectBuilder {
 impl RTDTestCallVectorStringObjectBuilder {
   pub fn build(&self) -> TestCallVectorStringObject { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x(&mut self, x: Vec<TestString>) -> &mut Self {
     self.inner.x = x;
@@ -25444,18 +29415,24 @@ pub struct TestGetDifference {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TestGetDifference {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testGetDifference" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestGetDifference {}
+impl RFunction for TestGetDifference {
+  type Response = Ok;
+}
 
 impl TestGetDifference {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25475,6 +29452,11 @@ pub struct RTDTestGetDifferenceBuilder {
 impl RTDTestGetDifferenceBuilder {
   pub fn build(&self) -> TestGetDifference { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TestGetDifference> for TestGetDifference {
@@ -25497,18 +29479,24 @@ pub struct TestNetwork {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TestNetwork {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testNetwork" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestNetwork {}
+impl RFunction for TestNetwork {
+  type Response = Ok;
+}
 
 impl TestNetwork {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25528,6 +29516,11 @@ pub struct RTDTestNetworkBuilder {
 impl RTDTestNetworkBuilder {
   pub fn build(&self) -> TestNetwork { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TestNetwork> for TestNetwork {
@@ -25550,6 +29543,9 @@ pub struct TestProxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Proxy server IP address
   server: String,
   /// Proxy server port
@@ -25565,13 +29561,16 @@ pub struct TestProxy {
 
 impl RObject for TestProxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testProxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestProxy {}
+impl RFunction for TestProxy {
+  type Response = Ok;
+}
 
 impl TestProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25601,6 +29600,11 @@ pub struct RTDTestProxyBuilder {
 impl RTDTestProxyBuilder {
   pub fn build(&self) -> TestProxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
@@ -25653,6 +29657,9 @@ pub struct TestReturnError {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The error to be returned
   error: Error,
   
@@ -25660,13 +29667,16 @@ pub struct TestReturnError {
 
 impl RObject for TestReturnError {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testReturnError" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestReturnError {}
+impl RFunction for TestReturnError {
+  type Response = Error;
+}
 
 impl TestReturnError {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25688,6 +29698,11 @@ pub struct RTDTestReturnErrorBuilder {
 impl RTDTestReturnErrorBuilder {
   pub fn build(&self) -> TestReturnError { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
     self.inner.error = error.as_ref().clone();
@@ -25716,6 +29731,9 @@ pub struct TestSquareInt {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Number to square
   x: i64,
   
@@ -25723,13 +29741,16 @@ pub struct TestSquareInt {
 
 impl RObject for TestSquareInt {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testSquareInt" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TestSquareInt {}
+impl RFunction for TestSquareInt {
+  type Response = TestInt;
+}
 
 impl TestSquareInt {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25751,6 +29772,11 @@ pub struct RTDTestSquareIntBuilder {
 impl RTDTestSquareIntBuilder {
   pub fn build(&self) -> TestSquareInt { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x(&mut self, x: i64) -> &mut Self {
     self.inner.x = x;
@@ -25779,18 +29805,24 @@ pub struct TestUseUpdate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TestUseUpdate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testUseUpdate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
-impl TDUpdate for TestUseUpdate {}
 
-impl RFunction for TestUseUpdate {}
+
+impl RFunction for TestUseUpdate {
+  type Response = Update;
+}
 
 impl TestUseUpdate {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25810,6 +29842,11 @@ pub struct RTDTestUseUpdateBuilder {
 impl RTDTestUseUpdateBuilder {
   pub fn build(&self) -> TestUseUpdate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TestUseUpdate> for TestUseUpdate {
@@ -25832,6 +29869,9 @@ pub struct ToggleChatDefaultDisableNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New value of default_disable_notification
@@ -25841,13 +29881,16 @@ pub struct ToggleChatDefaultDisableNotification {
 
 impl RObject for ToggleChatDefaultDisableNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "toggleChatDefaultDisableNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ToggleChatDefaultDisableNotification {}
+impl RFunction for ToggleChatDefaultDisableNotification {
+  type Response = Ok;
+}
 
 impl ToggleChatDefaultDisableNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25871,6 +29914,11 @@ pub struct RTDToggleChatDefaultDisableNotificationBuilder {
 impl RTDToggleChatDefaultDisableNotificationBuilder {
   pub fn build(&self) -> ToggleChatDefaultDisableNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -25905,6 +29953,9 @@ pub struct ToggleChatIsMarkedAsUnread {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New value of is_marked_as_unread
@@ -25914,13 +29965,16 @@ pub struct ToggleChatIsMarkedAsUnread {
 
 impl RObject for ToggleChatIsMarkedAsUnread {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "toggleChatIsMarkedAsUnread" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ToggleChatIsMarkedAsUnread {}
+impl RFunction for ToggleChatIsMarkedAsUnread {
+  type Response = Ok;
+}
 
 impl ToggleChatIsMarkedAsUnread {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25944,6 +29998,11 @@ pub struct RTDToggleChatIsMarkedAsUnreadBuilder {
 impl RTDToggleChatIsMarkedAsUnreadBuilder {
   pub fn build(&self) -> ToggleChatIsMarkedAsUnread { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -25978,6 +30037,9 @@ pub struct ToggleChatIsPinned {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat list in which to change the pinned state of the chat
   chat_list: ChatList,
   /// Chat identifier
@@ -25989,13 +30051,16 @@ pub struct ToggleChatIsPinned {
 
 impl RObject for ToggleChatIsPinned {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "toggleChatIsPinned" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ToggleChatIsPinned {}
+impl RFunction for ToggleChatIsPinned {
+  type Response = Ok;
+}
 
 impl ToggleChatIsPinned {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26021,6 +30086,11 @@ pub struct RTDToggleChatIsPinnedBuilder {
 impl RTDToggleChatIsPinnedBuilder {
   pub fn build(&self) -> ToggleChatIsPinned { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -26061,6 +30131,9 @@ pub struct ToggleSupergroupIsAllHistoryAvailable {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identifier of the supergroup
   supergroup_id: i64,
   /// The new value of is_all_history_available
@@ -26070,13 +30143,16 @@ pub struct ToggleSupergroupIsAllHistoryAvailable {
 
 impl RObject for ToggleSupergroupIsAllHistoryAvailable {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "toggleSupergroupIsAllHistoryAvailable" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ToggleSupergroupIsAllHistoryAvailable {}
+impl RFunction for ToggleSupergroupIsAllHistoryAvailable {
+  type Response = Ok;
+}
 
 impl ToggleSupergroupIsAllHistoryAvailable {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26100,6 +30176,11 @@ pub struct RTDToggleSupergroupIsAllHistoryAvailableBuilder {
 impl RTDToggleSupergroupIsAllHistoryAvailableBuilder {
   pub fn build(&self) -> ToggleSupergroupIsAllHistoryAvailable { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -26134,6 +30215,9 @@ pub struct ToggleSupergroupSignMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the channel
   supergroup_id: i64,
   /// New value of sign_messages
@@ -26143,13 +30227,16 @@ pub struct ToggleSupergroupSignMessages {
 
 impl RObject for ToggleSupergroupSignMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "toggleSupergroupSignMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ToggleSupergroupSignMessages {}
+impl RFunction for ToggleSupergroupSignMessages {
+  type Response = Ok;
+}
 
 impl ToggleSupergroupSignMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26173,6 +30260,11 @@ pub struct RTDToggleSupergroupSignMessagesBuilder {
 impl RTDToggleSupergroupSignMessagesBuilder {
   pub fn build(&self) -> ToggleSupergroupSignMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -26207,6 +30299,9 @@ pub struct TransferChatOwnership {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of the user to which transfer the ownership. The ownership can't be transferred to a bot or to a deleted user
@@ -26218,13 +30313,16 @@ pub struct TransferChatOwnership {
 
 impl RObject for TransferChatOwnership {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "transferChatOwnership" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for TransferChatOwnership {}
+impl RFunction for TransferChatOwnership {
+  type Response = Ok;
+}
 
 impl TransferChatOwnership {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26250,6 +30348,11 @@ pub struct RTDTransferChatOwnershipBuilder {
 impl RTDTransferChatOwnershipBuilder {
   pub fn build(&self) -> TransferChatOwnership { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -26290,6 +30393,9 @@ pub struct UnblockUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   
@@ -26297,13 +30403,16 @@ pub struct UnblockUser {
 
 impl RObject for UnblockUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "unblockUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for UnblockUser {}
+impl RFunction for UnblockUser {
+  type Response = Ok;
+}
 
 impl UnblockUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26325,6 +30434,11 @@ pub struct RTDUnblockUserBuilder {
 impl RTDUnblockUserBuilder {
   pub fn build(&self) -> UnblockUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -26353,6 +30467,9 @@ pub struct UnpinChatMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat
   chat_id: i64,
   
@@ -26360,13 +30477,16 @@ pub struct UnpinChatMessage {
 
 impl RObject for UnpinChatMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "unpinChatMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for UnpinChatMessage {}
+impl RFunction for UnpinChatMessage {
+  type Response = Ok;
+}
 
 impl UnpinChatMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26388,6 +30508,11 @@ pub struct RTDUnpinChatMessageBuilder {
 impl RTDUnpinChatMessageBuilder {
   pub fn build(&self) -> UnpinChatMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -26416,6 +30541,9 @@ pub struct UpgradeBasicGroupChatToSupergroupChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat to upgrade
   chat_id: i64,
   
@@ -26423,13 +30551,16 @@ pub struct UpgradeBasicGroupChatToSupergroupChat {
 
 impl RObject for UpgradeBasicGroupChatToSupergroupChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "upgradeBasicGroupChatToSupergroupChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for UpgradeBasicGroupChatToSupergroupChat {}
+impl RFunction for UpgradeBasicGroupChatToSupergroupChat {
+  type Response = Chat;
+}
 
 impl UpgradeBasicGroupChatToSupergroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26451,6 +30582,11 @@ pub struct RTDUpgradeBasicGroupChatToSupergroupChatBuilder {
 impl RTDUpgradeBasicGroupChatToSupergroupChatBuilder {
   pub fn build(&self) -> UpgradeBasicGroupChatToSupergroupChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -26479,6 +30615,9 @@ pub struct UploadFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// File to upload
   file: InputFile,
   /// File type
@@ -26490,13 +30629,16 @@ pub struct UploadFile {
 
 impl RObject for UploadFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "uploadFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for UploadFile {}
+impl RFunction for UploadFile {
+  type Response = File;
+}
 
 impl UploadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26522,6 +30664,11 @@ pub struct RTDUploadFileBuilder {
 impl RTDUploadFileBuilder {
   pub fn build(&self) -> UploadFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file<T: AsRef<InputFile>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
@@ -26562,6 +30709,9 @@ pub struct UploadStickerFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker file owner
   user_id: i64,
   /// PNG image with the sticker; must be up to 512 KB in size and fit in 512x512 square
@@ -26571,13 +30721,16 @@ pub struct UploadStickerFile {
 
 impl RObject for UploadStickerFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "uploadStickerFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for UploadStickerFile {}
+impl RFunction for UploadStickerFile {
+  type Response = File;
+}
 
 impl UploadStickerFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26601,6 +30754,11 @@ pub struct RTDUploadStickerFileBuilder {
 impl RTDUploadStickerFileBuilder {
   pub fn build(&self) -> UploadStickerFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -26635,6 +30793,9 @@ pub struct ValidateOrderInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier of the Invoice message
   chat_id: i64,
   /// Message identifier
@@ -26648,13 +30809,16 @@ pub struct ValidateOrderInfo {
 
 impl RObject for ValidateOrderInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "validateOrderInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ValidateOrderInfo {}
+impl RFunction for ValidateOrderInfo {
+  type Response = ValidatedOrderInfo;
+}
 
 impl ValidateOrderInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26682,6 +30846,11 @@ pub struct RTDValidateOrderInfoBuilder {
 impl RTDValidateOrderInfoBuilder {
   pub fn build(&self) -> ValidateOrderInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -26728,6 +30897,9 @@ pub struct ViewMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The identifiers of the messages being viewed
@@ -26739,13 +30911,16 @@ pub struct ViewMessages {
 
 impl RObject for ViewMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "viewMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ViewMessages {}
+impl RFunction for ViewMessages {
+  type Response = Ok;
+}
 
 impl ViewMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26771,6 +30946,11 @@ pub struct RTDViewMessagesBuilder {
 impl RTDViewMessagesBuilder {
   pub fn build(&self) -> ViewMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -26811,20 +30991,26 @@ pub struct ViewTrendingStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifiers of viewed trending sticker sets
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64_str")] sticker_set_ids: Vec<i64>,
   
 }
 
 impl RObject for ViewTrendingStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "viewTrendingStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for ViewTrendingStickerSets {}
+impl RFunction for ViewTrendingStickerSets {
+  type Response = Ok;
+}
 
 impl ViewTrendingStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26834,7 +31020,7 @@ impl ViewTrendingStickerSets {
     RTDViewTrendingStickerSetsBuilder { inner }
   }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -26846,8 +31032,13 @@ pub struct RTDViewTrendingStickerSetsBuilder {
 impl RTDViewTrendingStickerSetsBuilder {
   pub fn build(&self) -> ViewTrendingStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -26874,24 +31065,30 @@ pub struct WriteGeneratedFilePart {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identifier of the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] generation_id: i64,
   /// The offset from which to write the data to the file
   offset: i64,
   /// The data to write
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for WriteGeneratedFilePart {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "writeGeneratedFilePart" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 
-impl RFunction for WriteGeneratedFilePart {}
+impl RFunction for WriteGeneratedFilePart {
+  type Response = Ok;
+}
 
 impl WriteGeneratedFilePart {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26901,11 +31098,11 @@ impl WriteGeneratedFilePart {
     RTDWriteGeneratedFilePartBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn offset(&self) -> i64 { self.offset }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -26917,8 +31114,13 @@ pub struct RTDWriteGeneratedFilePartBuilder {
 impl RTDWriteGeneratedFilePartBuilder {
   pub fn build(&self) -> WriteGeneratedFilePart { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
@@ -26930,8 +31132,8 @@ impl RTDWriteGeneratedFilePartBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
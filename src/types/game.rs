@@ -11,8 +11,11 @@ pub struct Game {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Game ID
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Game short name. To share a game use the URL https://t.me/{bot_username}?game={game_short_name}
   short_name: String,
   /// Game title
@@ -30,6 +33,7 @@ pub struct Game {
 
 impl RObject for Game {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "game" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,7 +47,7 @@ impl Game {
     RTDGameBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn short_name(&self) -> &String { &self.short_name }
 
@@ -67,8 +71,13 @@ pub struct RTDGameBuilder {
 impl RTDGameBuilder {
   pub fn build(&self) -> Game { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -11,6 +11,9 @@ pub struct GameHighScore {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Position in the high score table
   position: i64,
   /// User identifier
@@ -22,6 +25,7 @@ pub struct GameHighScore {
 
 impl RObject for GameHighScore {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "gameHighScore" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDGameHighScoreBuilder {
 impl RTDGameHighScoreBuilder {
   pub fn build(&self) -> GameHighScore { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn position(&mut self, position: i64) -> &mut Self {
     self.inner.position = position;
@@ -11,6 +11,9 @@ pub struct ImportedContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifiers of the imported contacts in the same order as they were specified in the request; 0 if the contact is not yet a registered user
   user_ids: Vec<i64>,
   /// The number of users that imported the corresponding contact; 0 for already registered users or if unavailable
@@ -20,6 +23,7 @@ pub struct ImportedContacts {
 
 impl RObject for ImportedContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "importedContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDImportedContactsBuilder {
 impl RTDImportedContactsBuilder {
   pub fn build(&self) -> ImportedContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -18,6 +18,9 @@ pub trait TDInlineKeyboardButtonType: Debug + RObject {}
 #[serde(untagged)]
 pub enum InlineKeyboardButtonType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice
   Buy(InlineKeyboardButtonTypeBuy),
   /// A button that sends a special callback query to a bot
@@ -66,12 +69,26 @@ impl RObject for InlineKeyboardButtonType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InlineKeyboardButtonType::Buy(t) => t.extra(),
+      InlineKeyboardButtonType::Callback(t) => t.extra(),
+      InlineKeyboardButtonType::CallbackGame(t) => t.extra(),
+      InlineKeyboardButtonType::LoginUrl(t) => t.extra(),
+      InlineKeyboardButtonType::SwitchInline(t) => t.extra(),
+      InlineKeyboardButtonType::Url(t) => t.extra(),
+
+      InlineKeyboardButtonType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InlineKeyboardButtonType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InlineKeyboardButtonType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InlineKeyboardButtonType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_buy(&self) -> bool { if let InlineKeyboardButtonType::Buy(_) = self { true } else { false } }
   pub fn is_callback(&self) -> bool { if let InlineKeyboardButtonType::Callback(_) = self { true } else { false } }
@@ -126,11 +143,15 @@ pub struct InlineKeyboardButtonTypeBuy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeBuy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeBuy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,6 +178,11 @@ pub struct RTDInlineKeyboardButtonTypeBuyBuilder {
 impl RTDInlineKeyboardButtonTypeBuyBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeBuy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InlineKeyboardButtonTypeBuy> for InlineKeyboardButtonTypeBuy {
@@ -179,13 +205,17 @@ pub struct InlineKeyboardButtonTypeCallback {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Data to be sent to the bot via a callback query
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeCallback {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeCallback" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -202,7 +232,7 @@ impl InlineKeyboardButtonTypeCallback {
     RTDInlineKeyboardButtonTypeCallbackBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -214,9 +244,14 @@ pub struct RTDInlineKeyboardButtonTypeCallbackBuilder {
 impl RTDInlineKeyboardButtonTypeCallbackBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallback { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -242,11 +277,15 @@ pub struct InlineKeyboardButtonTypeCallbackGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeCallbackGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeCallbackGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,6 +312,11 @@ pub struct RTDInlineKeyboardButtonTypeCallbackGameBuilder {
 impl RTDInlineKeyboardButtonTypeCallbackGameBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallbackGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InlineKeyboardButtonTypeCallbackGame> for InlineKeyboardButtonTypeCallbackGame {
@@ -295,6 +339,9 @@ pub struct InlineKeyboardButtonTypeLoginUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// An HTTP URL to open
   url: String,
   /// Unique button identifier
@@ -306,6 +353,7 @@ pub struct InlineKeyboardButtonTypeLoginUrl {
 
 impl RObject for InlineKeyboardButtonTypeLoginUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeLoginUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -338,6 +386,11 @@ pub struct RTDInlineKeyboardButtonTypeLoginUrlBuilder {
 impl RTDInlineKeyboardButtonTypeLoginUrlBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeLoginUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -378,6 +431,9 @@ pub struct InlineKeyboardButtonTypeSwitchInline {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Inline query to be sent to the bot
   query: String,
   /// True, if the inline query should be sent from the current chat
@@ -387,6 +443,7 @@ pub struct InlineKeyboardButtonTypeSwitchInline {
 
 impl RObject for InlineKeyboardButtonTypeSwitchInline {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeSwitchInline" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -417,6 +474,11 @@ pub struct RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
 impl RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeSwitchInline { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -451,6 +513,9 @@ pub struct InlineKeyboardButtonTypeUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// HTTP or tg:// URL to open
   url: String,
   
@@ -458,6 +523,7 @@ pub struct InlineKeyboardButtonTypeUrl {
 
 impl RObject for InlineKeyboardButtonTypeUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -486,6 +552,11 @@ pub struct RTDInlineKeyboardButtonTypeUrlBuilder {
 impl RTDInlineKeyboardButtonTypeUrlBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
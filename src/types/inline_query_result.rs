@@ -18,6 +18,9 @@ pub trait TDInlineQueryResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum InlineQueryResult {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents an animation file
   Animation(InlineQueryResultAnimation),
   /// Represents a link to an article or web page
@@ -90,12 +93,32 @@ impl RObject for InlineQueryResult {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InlineQueryResult::Animation(t) => t.extra(),
+      InlineQueryResult::Article(t) => t.extra(),
+      InlineQueryResult::Audio(t) => t.extra(),
+      InlineQueryResult::Contact(t) => t.extra(),
+      InlineQueryResult::Document(t) => t.extra(),
+      InlineQueryResult::Game(t) => t.extra(),
+      InlineQueryResult::Location(t) => t.extra(),
+      InlineQueryResult::Photo(t) => t.extra(),
+      InlineQueryResult::Sticker(t) => t.extra(),
+      InlineQueryResult::Venue(t) => t.extra(),
+      InlineQueryResult::Video(t) => t.extra(),
+      InlineQueryResult::VoiceNote(t) => t.extra(),
+
+      InlineQueryResult::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InlineQueryResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InlineQueryResult::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InlineQueryResult::Unknown { .. } = self { true } else { false } }
 
   pub fn is_animation(&self) -> bool { if let InlineQueryResult::Animation(_) = self { true } else { false } }
   pub fn is_article(&self) -> bool { if let InlineQueryResult::Article(_) = self { true } else { false } }
@@ -180,6 +203,9 @@ pub struct InlineQueryResultAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Animation file
@@ -191,6 +217,7 @@ pub struct InlineQueryResultAnimation {
 
 impl RObject for InlineQueryResultAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -223,6 +250,11 @@ pub struct RTDInlineQueryResultAnimationBuilder {
 impl RTDInlineQueryResultAnimationBuilder {
   pub fn build(&self) -> InlineQueryResultAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -263,6 +295,9 @@ pub struct InlineQueryResultArticle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// URL of the result, if it exists
@@ -280,6 +315,7 @@ pub struct InlineQueryResultArticle {
 
 impl RObject for InlineQueryResultArticle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultArticle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -318,6 +354,11 @@ pub struct RTDInlineQueryResultArticleBuilder {
 impl RTDInlineQueryResultArticleBuilder {
   pub fn build(&self) -> InlineQueryResultArticle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -376,6 +417,9 @@ pub struct InlineQueryResultAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Audio file
@@ -385,6 +429,7 @@ pub struct InlineQueryResultAudio {
 
 impl RObject for InlineQueryResultAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -415,6 +460,11 @@ pub struct RTDInlineQueryResultAudioBuilder {
 impl RTDInlineQueryResultAudioBuilder {
   pub fn build(&self) -> InlineQueryResultAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -449,6 +499,9 @@ pub struct InlineQueryResultContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// A user contact
@@ -460,6 +513,7 @@ pub struct InlineQueryResultContact {
 
 impl RObject for InlineQueryResultContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -492,6 +546,11 @@ pub struct RTDInlineQueryResultContactBuilder {
 impl RTDInlineQueryResultContactBuilder {
   pub fn build(&self) -> InlineQueryResultContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -532,6 +591,9 @@ pub struct InlineQueryResultDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Document
@@ -545,6 +607,7 @@ pub struct InlineQueryResultDocument {
 
 impl RObject for InlineQueryResultDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -579,6 +642,11 @@ pub struct RTDInlineQueryResultDocumentBuilder {
 impl RTDInlineQueryResultDocumentBuilder {
   pub fn build(&self) -> InlineQueryResultDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -625,6 +693,9 @@ pub struct InlineQueryResultGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Game result
@@ -634,6 +705,7 @@ pub struct InlineQueryResultGame {
 
 impl RObject for InlineQueryResultGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -664,6 +736,11 @@ pub struct RTDInlineQueryResultGameBuilder {
 impl RTDInlineQueryResultGameBuilder {
   pub fn build(&self) -> InlineQueryResultGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -698,6 +775,9 @@ pub struct InlineQueryResultLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Location result
@@ -711,6 +791,7 @@ pub struct InlineQueryResultLocation {
 
 impl RObject for InlineQueryResultLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -745,6 +826,11 @@ pub struct RTDInlineQueryResultLocationBuilder {
 impl RTDInlineQueryResultLocationBuilder {
   pub fn build(&self) -> InlineQueryResultLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -791,6 +877,9 @@ pub struct InlineQueryResultPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Photo
@@ -804,6 +893,7 @@ pub struct InlineQueryResultPhoto {
 
 impl RObject for InlineQueryResultPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -838,6 +928,11 @@ pub struct RTDInlineQueryResultPhotoBuilder {
 impl RTDInlineQueryResultPhotoBuilder {
   pub fn build(&self) -> InlineQueryResultPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -884,6 +979,9 @@ pub struct InlineQueryResultSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Sticker
@@ -893,6 +991,7 @@ pub struct InlineQueryResultSticker {
 
 impl RObject for InlineQueryResultSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -923,6 +1022,11 @@ pub struct RTDInlineQueryResultStickerBuilder {
 impl RTDInlineQueryResultStickerBuilder {
   pub fn build(&self) -> InlineQueryResultSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -957,6 +1061,9 @@ pub struct InlineQueryResultVenue {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Venue result
@@ -968,6 +1075,7 @@ pub struct InlineQueryResultVenue {
 
 impl RObject for InlineQueryResultVenue {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultVenue" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1000,6 +1108,11 @@ pub struct RTDInlineQueryResultVenueBuilder {
 impl RTDInlineQueryResultVenueBuilder {
   pub fn build(&self) -> InlineQueryResultVenue { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1040,6 +1153,9 @@ pub struct InlineQueryResultVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Video
@@ -1053,6 +1169,7 @@ pub struct InlineQueryResultVideo {
 
 impl RObject for InlineQueryResultVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1087,6 +1204,11 @@ pub struct RTDInlineQueryResultVideoBuilder {
 impl RTDInlineQueryResultVideoBuilder {
   pub fn build(&self) -> InlineQueryResultVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1133,6 +1255,9 @@ pub struct InlineQueryResultVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Voice note
@@ -1144,6 +1269,7 @@ pub struct InlineQueryResultVoiceNote {
 
 impl RObject for InlineQueryResultVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1176,6 +1302,11 @@ pub struct RTDInlineQueryResultVoiceNoteBuilder {
 impl RTDInlineQueryResultVoiceNoteBuilder {
   pub fn build(&self) -> InlineQueryResultVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -11,8 +11,11 @@ pub struct InlineQueryResults {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the inline query
-  inline_query_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] inline_query_id: i64,
   /// The offset for the next request. If empty, there are no more results
   next_offset: String,
   /// Results of the query
@@ -26,6 +29,7 @@ pub struct InlineQueryResults {
 
 impl RObject for InlineQueryResults {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResults" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -39,7 +43,7 @@ impl InlineQueryResults {
     RTDInlineQueryResultsBuilder { inner }
   }
 
-  pub fn inline_query_id(&self) -> isize { self.inline_query_id }
+  pub fn inline_query_id(&self) -> i64 { self.inline_query_id }
 
   pub fn next_offset(&self) -> &String { &self.next_offset }
 
@@ -59,8 +63,13 @@ pub struct RTDInlineQueryResultsBuilder {
 impl RTDInlineQueryResultsBuilder {
   pub fn build(&self) -> InlineQueryResults { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn inline_query_id(&mut self, inline_query_id: isize) -> &mut Self {
+  pub fn inline_query_id(&mut self, inline_query_id: i64) -> &mut Self {
     self.inner.inline_query_id = inline_query_id;
     self
   }
@@ -18,6 +18,9 @@ pub trait TDInputBackground: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputBackground {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A background from a local file
   Local(InputBackgroundLocal),
   /// A background from the server
@@ -50,12 +53,22 @@ impl RObject for InputBackground {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputBackground::Local(t) => t.extra(),
+      InputBackground::Remote(t) => t.extra(),
+
+      InputBackground::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputBackground::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputBackground::Unknown { .. } = self { true } else { false } }
 
   pub fn is_local(&self) -> bool { if let InputBackground::Local(_) = self { true } else { false } }
   pub fn is_remote(&self) -> bool { if let InputBackground::Remote(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct InputBackgroundLocal {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Background file to use. Only inputFileLocal and inputFileGenerated are supported. The file must be in JPEG format for wallpapers and in PNG format for patterns
   background: InputFile,
   
@@ -97,6 +113,7 @@ pub struct InputBackgroundLocal {
 
 impl RObject for InputBackgroundLocal {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputBackgroundLocal" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -125,6 +142,11 @@ pub struct RTDInputBackgroundLocalBuilder {
 impl RTDInputBackgroundLocalBuilder {
   pub fn build(&self) -> InputBackgroundLocal { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn background<T: AsRef<InputFile>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
@@ -153,13 +175,17 @@ pub struct InputBackgroundRemote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The background identifier
-  background_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] background_id: i64,
   
 }
 
 impl RObject for InputBackgroundRemote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputBackgroundRemote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -176,7 +202,7 @@ impl InputBackgroundRemote {
     RTDInputBackgroundRemoteBuilder { inner }
   }
 
-  pub fn background_id(&self) -> isize { self.background_id }
+  pub fn background_id(&self) -> i64 { self.background_id }
 
 }
 
@@ -188,8 +214,13 @@ pub struct RTDInputBackgroundRemoteBuilder {
 impl RTDInputBackgroundRemoteBuilder {
   pub fn build(&self) -> InputBackgroundRemote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn background_id(&mut self, background_id: isize) -> &mut Self {
+  pub fn background_id(&mut self, background_id: i64) -> &mut Self {
     self.inner.background_id = background_id;
     self
   }
@@ -18,6 +18,9 @@ pub trait TDInputChatPhoto: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputChatPhoto {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation in MPEG4 format; must be square, shorter than 10 seconds, have width between 160 and 800 and be at most 2MB in size
   Animation(InputChatPhotoAnimation),
   /// A previously used profile photo of the current user
@@ -54,12 +57,23 @@ impl RObject for InputChatPhoto {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputChatPhoto::Animation(t) => t.extra(),
+      InputChatPhoto::Previous(t) => t.extra(),
+      InputChatPhoto::Static(t) => t.extra(),
+
+      InputChatPhoto::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputChatPhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputChatPhoto::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputChatPhoto::Unknown { .. } = self { true } else { false } }
 
   pub fn is_animation(&self) -> bool { if let InputChatPhoto::Animation(_) = self { true } else { false } }
   pub fn is_previous(&self) -> bool { if let InputChatPhoto::Previous(_) = self { true } else { false } }
@@ -99,6 +113,9 @@ pub struct InputChatPhotoAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Animation to be set as profile photo. Only inputFileLocal and inputFileGenerated are allowed
   animation: InputFile,
   /// Timestamp of the frame, which will be used as static chat photo
@@ -108,6 +125,7 @@ pub struct InputChatPhotoAnimation {
 
 impl RObject for InputChatPhotoAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputChatPhotoAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -138,6 +156,11 @@ pub struct RTDInputChatPhotoAnimationBuilder {
 impl RTDInputChatPhotoAnimationBuilder {
   pub fn build(&self) -> InputChatPhotoAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -172,13 +195,17 @@ pub struct InputChatPhotoPrevious {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the profile photo to reuse
-  chat_photo_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] chat_photo_id: i64,
   
 }
 
 impl RObject for InputChatPhotoPrevious {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputChatPhotoPrevious" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,7 +222,7 @@ impl InputChatPhotoPrevious {
     RTDInputChatPhotoPreviousBuilder { inner }
   }
 
-  pub fn chat_photo_id(&self) -> isize { self.chat_photo_id }
+  pub fn chat_photo_id(&self) -> i64 { self.chat_photo_id }
 
 }
 
@@ -207,8 +234,13 @@ pub struct RTDInputChatPhotoPreviousBuilder {
 impl RTDInputChatPhotoPreviousBuilder {
   pub fn build(&self) -> InputChatPhotoPrevious { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_photo_id(&mut self, chat_photo_id: isize) -> &mut Self {
+  pub fn chat_photo_id(&mut self, chat_photo_id: i64) -> &mut Self {
     self.inner.chat_photo_id = chat_photo_id;
     self
   }
@@ -235,6 +267,9 @@ pub struct InputChatPhotoStatic {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Photo to be set as profile photo. Only inputFileLocal and inputFileGenerated are allowed
   photo: InputFile,
   
@@ -242,6 +277,7 @@ pub struct InputChatPhotoStatic {
 
 impl RObject for InputChatPhotoStatic {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputChatPhotoStatic" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -270,6 +306,11 @@ pub struct RTDInputChatPhotoStaticBuilder {
 impl RTDInputChatPhotoStaticBuilder {
   pub fn build(&self) -> InputChatPhotoStatic { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -18,6 +18,9 @@ pub trait TDInputCredentials: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputCredentials {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Applies if a user enters new credentials using Android Pay
   AndroidPay(InputCredentialsAndroidPay),
   /// Applies if a user enters new credentials using Apple Pay
@@ -58,12 +61,24 @@ impl RObject for InputCredentials {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputCredentials::AndroidPay(t) => t.extra(),
+      InputCredentials::ApplePay(t) => t.extra(),
+      InputCredentials::New(t) => t.extra(),
+      InputCredentials::Saved(t) => t.extra(),
+
+      InputCredentials::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputCredentials {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputCredentials::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputCredentials::Unknown { .. } = self { true } else { false } }
 
   pub fn is_android_pay(&self) -> bool { if let InputCredentials::AndroidPay(_) = self { true } else { false } }
   pub fn is_apple_pay(&self) -> bool { if let InputCredentials::ApplePay(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct InputCredentialsAndroidPay {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// JSON-encoded data with the credential identifier
   data: String,
   
@@ -115,6 +133,7 @@ pub struct InputCredentialsAndroidPay {
 
 impl RObject for InputCredentialsAndroidPay {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsAndroidPay" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +162,11 @@ pub struct RTDInputCredentialsAndroidPayBuilder {
 impl RTDInputCredentialsAndroidPayBuilder {
   pub fn build(&self) -> InputCredentialsAndroidPay { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
@@ -171,6 +195,9 @@ pub struct InputCredentialsApplePay {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// JSON-encoded data with the credential identifier
   data: String,
   
@@ -178,6 +205,7 @@ pub struct InputCredentialsApplePay {
 
 impl RObject for InputCredentialsApplePay {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsApplePay" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -206,6 +234,11 @@ pub struct RTDInputCredentialsApplePayBuilder {
 impl RTDInputCredentialsApplePayBuilder {
   pub fn build(&self) -> InputCredentialsApplePay { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
@@ -234,6 +267,9 @@ pub struct InputCredentialsNew {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Contains JSON-encoded data with a credential identifier from the payment provider
   data: String,
   /// True, if the credential identifier can be saved on the server side
@@ -243,6 +279,7 @@ pub struct InputCredentialsNew {
 
 impl RObject for InputCredentialsNew {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsNew" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,6 +310,11 @@ pub struct RTDInputCredentialsNewBuilder {
 impl RTDInputCredentialsNewBuilder {
   pub fn build(&self) -> InputCredentialsNew { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
@@ -307,6 +349,9 @@ pub struct InputCredentialsSaved {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the saved credentials
   saved_credentials_id: String,
   
@@ -314,6 +359,7 @@ pub struct InputCredentialsSaved {
 
 impl RObject for InputCredentialsSaved {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsSaved" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -342,6 +388,11 @@ pub struct RTDInputCredentialsSavedBuilder {
 impl RTDInputCredentialsSavedBuilder {
   pub fn build(&self) -> InputCredentialsSaved { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn saved_credentials_id<T: AsRef<str>>(&mut self, saved_credentials_id: T) -> &mut Self {
     self.inner.saved_credentials_id = saved_credentials_id.as_ref().to_string();
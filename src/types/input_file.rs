@@ -18,6 +18,9 @@ pub trait TDInputFile: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputFile {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A file generated by the application
   Generated(InputFileGenerated),
   /// A file defined by its unique ID
@@ -58,12 +61,24 @@ impl RObject for InputFile {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputFile::Generated(t) => t.extra(),
+      InputFile::Id(t) => t.extra(),
+      InputFile::Local(t) => t.extra(),
+      InputFile::Remote(t) => t.extra(),
+
+      InputFile::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputFile::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputFile::Unknown { .. } = self { true } else { false } }
 
   pub fn is_generated(&self) -> bool { if let InputFile::Generated(_) = self { true } else { false } }
   pub fn is_id(&self) -> bool { if let InputFile::Id(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct InputFileGenerated {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Local path to a file from which the file is generated; may be empty if there is no such file
   original_path: String,
   /// String specifying the conversion applied to the original file; should be persistent across application restarts. Conversions beginning with '#' are reserved for internal TDLib usage
@@ -119,6 +137,7 @@ pub struct InputFileGenerated {
 
 impl RObject for InputFileGenerated {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileGenerated" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -151,6 +170,11 @@ pub struct RTDInputFileGeneratedBuilder {
 impl RTDInputFileGeneratedBuilder {
   pub fn build(&self) -> InputFileGenerated { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn original_path<T: AsRef<str>>(&mut self, original_path: T) -> &mut Self {
     self.inner.original_path = original_path.as_ref().to_string();
@@ -191,6 +215,9 @@ pub struct InputFileId {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique file identifier
   id: i64,
   
@@ -198,6 +225,7 @@ pub struct InputFileId {
 
 impl RObject for InputFileId {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileId" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -226,6 +254,11 @@ pub struct RTDInputFileIdBuilder {
 impl RTDInputFileIdBuilder {
   pub fn build(&self) -> InputFileId { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -254,6 +287,9 @@ pub struct InputFileLocal {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Local path to the file
   path: String,
   
@@ -261,6 +297,7 @@ pub struct InputFileLocal {
 
 impl RObject for InputFileLocal {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileLocal" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -289,6 +326,11 @@ pub struct RTDInputFileLocalBuilder {
 impl RTDInputFileLocalBuilder {
   pub fn build(&self) -> InputFileLocal { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
@@ -317,6 +359,9 @@ pub struct InputFileRemote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Remote file identifier
   id: String,
   
@@ -324,6 +369,7 @@ pub struct InputFileRemote {
 
 impl RObject for InputFileRemote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileRemote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -352,6 +398,11 @@ pub struct RTDInputFileRemoteBuilder {
 impl RTDInputFileRemoteBuilder {
   pub fn build(&self) -> InputFileRemote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct InputIdentityDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Document number; 1-24 characters
   number: String,
   /// Document expiry date, if available
@@ -28,6 +31,7 @@ pub struct InputIdentityDocument {
 
 impl RObject for InputIdentityDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputIdentityDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -63,6 +67,11 @@ pub struct RTDInputIdentityDocumentBuilder {
 impl RTDInputIdentityDocumentBuilder {
   pub fn build(&self) -> InputIdentityDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn number<T: AsRef<str>>(&mut self, number: T) -> &mut Self {
     self.inner.number = number.as_ref().to_string();
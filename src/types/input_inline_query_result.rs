@@ -18,6 +18,9 @@ pub trait TDInputInlineQueryResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputInlineQueryResult {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents a link to an animated GIF or an animated (i.e. without sound) H.264/MPEG-4 AVC video
   Animation(InputInlineQueryResultAnimation),
   /// Represents a link to an article or web page
@@ -90,12 +93,32 @@ impl RObject for InputInlineQueryResult {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputInlineQueryResult::Animation(t) => t.extra(),
+      InputInlineQueryResult::Article(t) => t.extra(),
+      InputInlineQueryResult::Audio(t) => t.extra(),
+      InputInlineQueryResult::Contact(t) => t.extra(),
+      InputInlineQueryResult::Document(t) => t.extra(),
+      InputInlineQueryResult::Game(t) => t.extra(),
+      InputInlineQueryResult::Location(t) => t.extra(),
+      InputInlineQueryResult::Photo(t) => t.extra(),
+      InputInlineQueryResult::Sticker(t) => t.extra(),
+      InputInlineQueryResult::Venue(t) => t.extra(),
+      InputInlineQueryResult::Video(t) => t.extra(),
+      InputInlineQueryResult::VoiceNote(t) => t.extra(),
+
+      InputInlineQueryResult::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputInlineQueryResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputInlineQueryResult::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputInlineQueryResult::Unknown { .. } = self { true } else { false } }
 
   pub fn is_animation(&self) -> bool { if let InputInlineQueryResult::Animation(_) = self { true } else { false } }
   pub fn is_article(&self) -> bool { if let InputInlineQueryResult::Article(_) = self { true } else { false } }
@@ -180,6 +203,9 @@ pub struct InputInlineQueryResultAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the query result
@@ -207,6 +233,7 @@ pub struct InputInlineQueryResultAnimation {
 
 impl RObject for InputInlineQueryResultAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -255,6 +282,11 @@ pub struct RTDInputInlineQueryResultAnimationBuilder {
 impl RTDInputInlineQueryResultAnimationBuilder {
   pub fn build(&self) -> InputInlineQueryResultAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -343,6 +375,9 @@ pub struct InputInlineQueryResultArticle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// URL of the result, if it exists
@@ -368,6 +403,7 @@ pub struct InputInlineQueryResultArticle {
 
 impl RObject for InputInlineQueryResultArticle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultArticle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -414,6 +450,11 @@ pub struct RTDInputInlineQueryResultArticleBuilder {
 impl RTDInputInlineQueryResultArticleBuilder {
   pub fn build(&self) -> InputInlineQueryResultArticle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -496,6 +537,9 @@ pub struct InputInlineQueryResultAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the audio file
@@ -515,6 +559,7 @@ pub struct InputInlineQueryResultAudio {
 
 impl RObject for InputInlineQueryResultAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -555,6 +600,11 @@ pub struct RTDInputInlineQueryResultAudioBuilder {
 impl RTDInputInlineQueryResultAudioBuilder {
   pub fn build(&self) -> InputInlineQueryResultAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -619,6 +669,9 @@ pub struct InputInlineQueryResultContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// User contact
@@ -638,6 +691,7 @@ pub struct InputInlineQueryResultContact {
 
 impl RObject for InputInlineQueryResultContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -678,6 +732,11 @@ pub struct RTDInputInlineQueryResultContactBuilder {
 impl RTDInputInlineQueryResultContactBuilder {
   pub fn build(&self) -> InputInlineQueryResultContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -742,6 +801,9 @@ pub struct InputInlineQueryResultDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the resulting file
@@ -767,6 +829,7 @@ pub struct InputInlineQueryResultDocument {
 
 impl RObject for InputInlineQueryResultDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -813,6 +876,11 @@ pub struct RTDInputInlineQueryResultDocumentBuilder {
 impl RTDInputInlineQueryResultDocumentBuilder {
   pub fn build(&self) -> InputInlineQueryResultDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -895,6 +963,9 @@ pub struct InputInlineQueryResultGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Short name of the game
@@ -906,6 +977,7 @@ pub struct InputInlineQueryResultGame {
 
 impl RObject for InputInlineQueryResultGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -938,6 +1010,11 @@ pub struct RTDInputInlineQueryResultGameBuilder {
 impl RTDInputInlineQueryResultGameBuilder {
   pub fn build(&self) -> InputInlineQueryResultGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -978,6 +1055,9 @@ pub struct InputInlineQueryResultLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Location result
@@ -1001,6 +1081,7 @@ pub struct InputInlineQueryResultLocation {
 
 impl RObject for InputInlineQueryResultLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1045,6 +1126,11 @@ pub struct RTDInputInlineQueryResultLocationBuilder {
 impl RTDInputInlineQueryResultLocationBuilder {
   pub fn build(&self) -> InputInlineQueryResultLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1121,6 +1207,9 @@ pub struct InputInlineQueryResultPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the result, if known
@@ -1144,6 +1233,7 @@ pub struct InputInlineQueryResultPhoto {
 
 impl RObject for InputInlineQueryResultPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1188,6 +1278,11 @@ pub struct RTDInputInlineQueryResultPhotoBuilder {
 impl RTDInputInlineQueryResultPhotoBuilder {
   pub fn build(&self) -> InputInlineQueryResultPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1264,6 +1359,9 @@ pub struct InputInlineQueryResultSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// URL of the sticker thumbnail, if it exists
@@ -1283,6 +1381,7 @@ pub struct InputInlineQueryResultSticker {
 
 impl RObject for InputInlineQueryResultSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1323,6 +1422,11 @@ pub struct RTDInputInlineQueryResultStickerBuilder {
 impl RTDInputInlineQueryResultStickerBuilder {
   pub fn build(&self) -> InputInlineQueryResultSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1387,6 +1491,9 @@ pub struct InputInlineQueryResultVenue {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Venue result
@@ -1406,6 +1513,7 @@ pub struct InputInlineQueryResultVenue {
 
 impl RObject for InputInlineQueryResultVenue {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultVenue" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1446,6 +1554,11 @@ pub struct RTDInputInlineQueryResultVenueBuilder {
 impl RTDInputInlineQueryResultVenueBuilder {
   pub fn build(&self) -> InputInlineQueryResultVenue { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1510,6 +1623,9 @@ pub struct InputInlineQueryResultVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the result
@@ -1537,6 +1653,7 @@ pub struct InputInlineQueryResultVideo {
 
 impl RObject for InputInlineQueryResultVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1585,6 +1702,11 @@ pub struct RTDInputInlineQueryResultVideoBuilder {
 impl RTDInputInlineQueryResultVideoBuilder {
   pub fn build(&self) -> InputInlineQueryResultVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1673,6 +1795,9 @@ pub struct InputInlineQueryResultVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the voice note
@@ -1690,6 +1815,7 @@ pub struct InputInlineQueryResultVoiceNote {
 
 impl RObject for InputInlineQueryResultVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1728,6 +1854,11 @@ pub struct RTDInputInlineQueryResultVoiceNoteBuilder {
 impl RTDInputInlineQueryResultVoiceNoteBuilder {
   pub fn build(&self) -> InputInlineQueryResultVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDInputMessageContent: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputMessageContent {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation message (GIF-style).
   InputMessageAnimation(InputMessageAnimation),
   /// An audio message
@@ -110,12 +113,37 @@ impl RObject for InputMessageContent {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputMessageContent::InputMessageAnimation(t) => t.extra(),
+      InputMessageContent::InputMessageAudio(t) => t.extra(),
+      InputMessageContent::InputMessageContact(t) => t.extra(),
+      InputMessageContent::InputMessageDice(t) => t.extra(),
+      InputMessageContent::InputMessageDocument(t) => t.extra(),
+      InputMessageContent::InputMessageForwarded(t) => t.extra(),
+      InputMessageContent::InputMessageGame(t) => t.extra(),
+      InputMessageContent::InputMessageInvoice(t) => t.extra(),
+      InputMessageContent::InputMessageLocation(t) => t.extra(),
+      InputMessageContent::InputMessagePhoto(t) => t.extra(),
+      InputMessageContent::InputMessagePoll(t) => t.extra(),
+      InputMessageContent::InputMessageSticker(t) => t.extra(),
+      InputMessageContent::InputMessageText(t) => t.extra(),
+      InputMessageContent::InputMessageVenue(t) => t.extra(),
+      InputMessageContent::InputMessageVideo(t) => t.extra(),
+      InputMessageContent::InputMessageVideoNote(t) => t.extra(),
+      InputMessageContent::InputMessageVoiceNote(t) => t.extra(),
+
+      InputMessageContent::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputMessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputMessageContent::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputMessageContent::Unknown { .. } = self { true } else { false } }
 
   pub fn is_input_message_animation(&self) -> bool { if let InputMessageContent::InputMessageAnimation(_) = self { true } else { false } }
   pub fn is_input_message_audio(&self) -> bool { if let InputMessageContent::InputMessageAudio(_) = self { true } else { false } }
@@ -225,10 +253,13 @@ pub struct InputMessageAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Animation file to be sent
   animation: InputFile,
   /// Animation thumbnail, if available
-  thumbnail: InputThumbnail,
+  thumbnail: Option<InputThumbnail>,
   /// File identifiers of the stickers added to the animation, if applicable
   added_sticker_file_ids: Vec<i64>,
   /// Duration of the animation, in seconds
@@ -244,6 +275,7 @@ pub struct InputMessageAnimation {
 
 impl RObject for InputMessageAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -262,7 +294,7 @@ impl InputMessageAnimation {
 
   pub fn animation(&self) -> &InputFile { &self.animation }
 
-  pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
+  pub fn thumbnail(&self) -> &Option<InputThumbnail> { &self.thumbnail }
 
   pub fn added_sticker_file_ids(&self) -> &Vec<i64> { &self.added_sticker_file_ids }
 
@@ -284,6 +316,11 @@ pub struct RTDInputMessageAnimationBuilder {
 impl RTDInputMessageAnimationBuilder {
   pub fn build(&self) -> InputMessageAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -292,7 +329,7 @@ impl RTDInputMessageAnimationBuilder {
 
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
-    self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.inner.thumbnail = Some(thumbnail.as_ref().clone());
     self
   }
 
@@ -348,10 +385,13 @@ pub struct InputMessageAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Audio file to be sent
   audio: InputFile,
   /// Thumbnail of the cover for the album, if available
-  album_cover_thumbnail: InputThumbnail,
+  album_cover_thumbnail: Option<InputThumbnail>,
   /// Duration of the audio, in seconds; may be replaced by the server
   duration: i64,
   /// Title of the audio; 0-64 characters; may be replaced by the server
@@ -365,6 +405,7 @@ pub struct InputMessageAudio {
 
 impl RObject for InputMessageAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -383,7 +424,7 @@ impl InputMessageAudio {
 
   pub fn audio(&self) -> &InputFile { &self.audio }
 
-  pub fn album_cover_thumbnail(&self) -> &InputThumbnail { &self.album_cover_thumbnail }
+  pub fn album_cover_thumbnail(&self) -> &Option<InputThumbnail> { &self.album_cover_thumbnail }
 
   pub fn duration(&self) -> i64 { self.duration }
 
@@ -403,6 +444,11 @@ pub struct RTDInputMessageAudioBuilder {
 impl RTDInputMessageAudioBuilder {
   pub fn build(&self) -> InputMessageAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<InputFile>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
@@ -411,7 +457,7 @@ impl RTDInputMessageAudioBuilder {
 
    
   pub fn album_cover_thumbnail<T: AsRef<InputThumbnail>>(&mut self, album_cover_thumbnail: T) -> &mut Self {
-    self.inner.album_cover_thumbnail = album_cover_thumbnail.as_ref().clone();
+    self.inner.album_cover_thumbnail = Some(album_cover_thumbnail.as_ref().clone());
     self
   }
 
@@ -461,6 +507,9 @@ pub struct InputMessageContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Contact to send
   contact: Contact,
   
@@ -468,6 +517,7 @@ pub struct InputMessageContact {
 
 impl RObject for InputMessageContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -496,6 +546,11 @@ pub struct RTDInputMessageContactBuilder {
 impl RTDInputMessageContactBuilder {
   pub fn build(&self) -> InputMessageContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
@@ -524,6 +579,9 @@ pub struct InputMessageDice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Emoji on which the dice throw animation is based
   emoji: String,
   /// True, if a chat message draft should be deleted
@@ -533,6 +591,7 @@ pub struct InputMessageDice {
 
 impl RObject for InputMessageDice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageDice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -563,6 +622,11 @@ pub struct RTDInputMessageDiceBuilder {
 impl RTDInputMessageDiceBuilder {
   pub fn build(&self) -> InputMessageDice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
@@ -597,10 +661,13 @@ pub struct InputMessageDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Document to be sent
   document: InputFile,
   /// Document thumbnail, if available
-  thumbnail: InputThumbnail,
+  thumbnail: Option<InputThumbnail>,
   /// If true, automatic file type detection will be disabled and the document will be always sent as file. Always true for files sent to secret chats
   force_file: bool,
   /// Document caption; 0-GetOption("message_caption_length_max") characters
@@ -610,6 +677,7 @@ pub struct InputMessageDocument {
 
 impl RObject for InputMessageDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -628,7 +696,7 @@ impl InputMessageDocument {
 
   pub fn document(&self) -> &InputFile { &self.document }
 
-  pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
+  pub fn thumbnail(&self) -> &Option<InputThumbnail> { &self.thumbnail }
 
   pub fn force_file(&self) -> bool { self.force_file }
 
@@ -644,6 +712,11 @@ pub struct RTDInputMessageDocumentBuilder {
 impl RTDInputMessageDocumentBuilder {
   pub fn build(&self) -> InputMessageDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<InputFile>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -652,7 +725,7 @@ impl RTDInputMessageDocumentBuilder {
 
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
-    self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.inner.thumbnail = Some(thumbnail.as_ref().clone());
     self
   }
 
@@ -690,6 +763,9 @@ pub struct InputMessageForwarded {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier for the chat this forwarded message came from
   from_chat_id: i64,
   /// Identifier of the message to forward
@@ -703,6 +779,7 @@ pub struct InputMessageForwarded {
 
 impl RObject for InputMessageForwarded {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageForwarded" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -737,6 +814,11 @@ pub struct RTDInputMessageForwardedBuilder {
 impl RTDInputMessageForwardedBuilder {
   pub fn build(&self) -> InputMessageForwarded { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
     self.inner.from_chat_id = from_chat_id;
@@ -783,6 +865,9 @@ pub struct InputMessageGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier of the bot that owns the game
   bot_user_id: i64,
   /// Short name of the game
@@ -792,6 +877,7 @@ pub struct InputMessageGame {
 
 impl RObject for InputMessageGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -822,6 +908,11 @@ pub struct RTDInputMessageGameBuilder {
 impl RTDInputMessageGameBuilder {
   pub fn build(&self) -> InputMessageGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
@@ -856,6 +947,9 @@ pub struct InputMessageInvoice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Invoice
   invoice: Invoice,
   /// Product title; 1-32 characters
@@ -871,7 +965,7 @@ pub struct InputMessageInvoice {
   /// Product photo height
   photo_height: i64,
   /// The invoice payload
-  payload: String,
+  payload: TdBytes,
   /// Payment provider token
   provider_token: String,
   /// JSON-encoded data about the invoice, which will be shared with the payment provider
@@ -883,6 +977,7 @@ pub struct InputMessageInvoice {
 
 impl RObject for InputMessageInvoice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageInvoice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -913,7 +1008,7 @@ impl InputMessageInvoice {
 
   pub fn photo_height(&self) -> i64 { self.photo_height }
 
-  pub fn payload(&self) -> &String { &self.payload }
+  pub fn payload(&self) -> &TdBytes { &self.payload }
 
   pub fn provider_token(&self) -> &String { &self.provider_token }
 
@@ -931,6 +1026,11 @@ pub struct RTDInputMessageInvoiceBuilder {
 impl RTDInputMessageInvoiceBuilder {
   pub fn build(&self) -> InputMessageInvoice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
     self.inner.invoice = invoice.as_ref().clone();
@@ -974,8 +1074,8 @@ impl RTDInputMessageInvoiceBuilder {
   }
 
    
-  pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
-    self.inner.payload = payload.as_ref().to_string();
+  pub fn payload<T: AsRef<[u8]>>(&mut self, payload: T) -> &mut Self {
+    self.inner.payload = TdBytes::from(payload.as_ref());
     self
   }
 
@@ -1019,6 +1119,9 @@ pub struct InputMessageLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Location to be sent
   location: Location,
   /// Period for which the location can be updated, in seconds; should be between 60 and 86400 for a live location and 0 otherwise
@@ -1028,6 +1131,7 @@ pub struct InputMessageLocation {
 
 impl RObject for InputMessageLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1058,6 +1162,11 @@ pub struct RTDInputMessageLocationBuilder {
 impl RTDInputMessageLocationBuilder {
   pub fn build(&self) -> InputMessageLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -1092,10 +1201,13 @@ pub struct InputMessagePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Photo to send
   photo: InputFile,
   /// Photo thumbnail to be sent, this is sent to the other party in secret chats only
-  thumbnail: InputThumbnail,
+  thumbnail: Option<InputThumbnail>,
   /// File identifiers of the stickers added to the photo, if applicable
   added_sticker_file_ids: Vec<i64>,
   /// Photo width
@@ -1111,6 +1223,7 @@ pub struct InputMessagePhoto {
 
 impl RObject for InputMessagePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessagePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1129,7 +1242,7 @@ impl InputMessagePhoto {
 
   pub fn photo(&self) -> &InputFile { &self.photo }
 
-  pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
+  pub fn thumbnail(&self) -> &Option<InputThumbnail> { &self.thumbnail }
 
   pub fn added_sticker_file_ids(&self) -> &Vec<i64> { &self.added_sticker_file_ids }
 
@@ -1151,6 +1264,11 @@ pub struct RTDInputMessagePhotoBuilder {
 impl RTDInputMessagePhotoBuilder {
   pub fn build(&self) -> InputMessagePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -1159,7 +1277,7 @@ impl RTDInputMessagePhotoBuilder {
 
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
-    self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.inner.thumbnail = Some(thumbnail.as_ref().clone());
     self
   }
 
@@ -1215,6 +1333,9 @@ pub struct InputMessagePoll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Poll question, 1-255 characters (up to 300 characters for bots)
   question: String,
   /// List of poll answer options, 2-10 strings 1-100 characters each
@@ -1234,6 +1355,7 @@ pub struct InputMessagePoll {
 
 impl RObject for InputMessagePoll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessagePoll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1274,6 +1396,11 @@ pub struct RTDInputMessagePollBuilder {
 impl RTDInputMessagePollBuilder {
   pub fn build(&self) -> InputMessagePoll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
@@ -1338,10 +1465,13 @@ pub struct InputMessageSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Sticker to be sent
   sticker: InputFile,
   /// Sticker thumbnail, if available
-  thumbnail: InputThumbnail,
+  thumbnail: Option<InputThumbnail>,
   /// Sticker width
   width: i64,
   /// Sticker height
@@ -1351,6 +1481,7 @@ pub struct InputMessageSticker {
 
 impl RObject for InputMessageSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1369,7 +1500,7 @@ impl InputMessageSticker {
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
-  pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
+  pub fn thumbnail(&self) -> &Option<InputThumbnail> { &self.thumbnail }
 
   pub fn width(&self) -> i64 { self.width }
 
@@ -1385,6 +1516,11 @@ pub struct RTDInputMessageStickerBuilder {
 impl RTDInputMessageStickerBuilder {
   pub fn build(&self) -> InputMessageSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -1393,7 +1529,7 @@ impl RTDInputMessageStickerBuilder {
 
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
-    self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.inner.thumbnail = Some(thumbnail.as_ref().clone());
     self
   }
 
@@ -1431,6 +1567,9 @@ pub struct InputMessageText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Formatted text to be sent; 1-GetOption("message_text_length_max") characters. Only Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities are allowed to be specified manually
   text: FormattedText,
   /// True, if rich web page previews for URLs in the message text should be disabled
@@ -1442,6 +1581,7 @@ pub struct InputMessageText {
 
 impl RObject for InputMessageText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1474,6 +1614,11 @@ pub struct RTDInputMessageTextBuilder {
 impl RTDInputMessageTextBuilder {
   pub fn build(&self) -> InputMessageText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1514,6 +1659,9 @@ pub struct InputMessageVenue {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Venue to send
   venue: Venue,
   
@@ -1521,6 +1669,7 @@ pub struct InputMessageVenue {
 
 impl RObject for InputMessageVenue {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVenue" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1549,6 +1698,11 @@ pub struct RTDInputMessageVenueBuilder {
 impl RTDInputMessageVenueBuilder {
   pub fn build(&self) -> InputMessageVenue { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
@@ -1577,10 +1731,13 @@ pub struct InputMessageVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Video to be sent
   video: InputFile,
   /// Video thumbnail, if available
-  thumbnail: InputThumbnail,
+  thumbnail: Option<InputThumbnail>,
   /// File identifiers of the stickers added to the video, if applicable
   added_sticker_file_ids: Vec<i64>,
   /// Duration of the video, in seconds
@@ -1600,6 +1757,7 @@ pub struct InputMessageVideo {
 
 impl RObject for InputMessageVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1618,7 +1776,7 @@ impl InputMessageVideo {
 
   pub fn video(&self) -> &InputFile { &self.video }
 
-  pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
+  pub fn thumbnail(&self) -> &Option<InputThumbnail> { &self.thumbnail }
 
   pub fn added_sticker_file_ids(&self) -> &Vec<i64> { &self.added_sticker_file_ids }
 
@@ -1644,6 +1802,11 @@ pub struct RTDInputMessageVideoBuilder {
 impl RTDInputMessageVideoBuilder {
   pub fn build(&self) -> InputMessageVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<InputFile>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
@@ -1652,7 +1815,7 @@ impl RTDInputMessageVideoBuilder {
 
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
-    self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.inner.thumbnail = Some(thumbnail.as_ref().clone());
     self
   }
 
@@ -1720,10 +1883,13 @@ pub struct InputMessageVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Video note to be sent
   video_note: InputFile,
   /// Video thumbnail, if available
-  thumbnail: InputThumbnail,
+  thumbnail: Option<InputThumbnail>,
   /// Duration of the video, in seconds
   duration: i64,
   /// Video width and height; must be positive and not greater than 640
@@ -1733,6 +1899,7 @@ pub struct InputMessageVideoNote {
 
 impl RObject for InputMessageVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1751,7 +1918,7 @@ impl InputMessageVideoNote {
 
   pub fn video_note(&self) -> &InputFile { &self.video_note }
 
-  pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
+  pub fn thumbnail(&self) -> &Option<InputThumbnail> { &self.thumbnail }
 
   pub fn duration(&self) -> i64 { self.duration }
 
@@ -1767,6 +1934,11 @@ pub struct RTDInputMessageVideoNoteBuilder {
 impl RTDInputMessageVideoNoteBuilder {
   pub fn build(&self) -> InputMessageVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video_note<T: AsRef<InputFile>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = video_note.as_ref().clone();
@@ -1775,7 +1947,7 @@ impl RTDInputMessageVideoNoteBuilder {
 
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
-    self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.inner.thumbnail = Some(thumbnail.as_ref().clone());
     self
   }
 
@@ -1813,12 +1985,15 @@ pub struct InputMessageVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Voice note to be sent
   voice_note: InputFile,
   /// Duration of the voice note, in seconds
   duration: i64,
   /// Waveform representation of the voice note, in 5-bit format
-  waveform: String,
+  waveform: TdBytes,
   /// Voice note caption; 0-GetOption("message_caption_length_max") characters
   caption: FormattedText,
   
@@ -1826,6 +2001,7 @@ pub struct InputMessageVoiceNote {
 
 impl RObject for InputMessageVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1846,7 +2022,7 @@ impl InputMessageVoiceNote {
 
   pub fn duration(&self) -> i64 { self.duration }
 
-  pub fn waveform(&self) -> &String { &self.waveform }
+  pub fn waveform(&self) -> &TdBytes { &self.waveform }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
 
@@ -1860,6 +2036,11 @@ pub struct RTDInputMessageVoiceNoteBuilder {
 impl RTDInputMessageVoiceNoteBuilder {
   pub fn build(&self) -> InputMessageVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<InputFile>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
@@ -1873,8 +2054,8 @@ impl RTDInputMessageVoiceNoteBuilder {
   }
 
    
-  pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
-    self.inner.waveform = waveform.as_ref().to_string();
+  pub fn waveform<T: AsRef<[u8]>>(&mut self, waveform: T) -> &mut Self {
+    self.inner.waveform = TdBytes::from(waveform.as_ref());
     self
   }
 
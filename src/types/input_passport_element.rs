@@ -18,6 +18,9 @@ pub trait TDInputPassportElement: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputPassportElement {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram Passport element to be saved containing the user's address
   Address(InputPassportElementAddress),
   /// A Telegram Passport element to be saved containing the user's bank statement
@@ -94,12 +97,33 @@ impl RObject for InputPassportElement {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputPassportElement::Address(t) => t.extra(),
+      InputPassportElement::BankStatement(t) => t.extra(),
+      InputPassportElement::DriverLicense(t) => t.extra(),
+      InputPassportElement::EmailAddress(t) => t.extra(),
+      InputPassportElement::IdentityCard(t) => t.extra(),
+      InputPassportElement::InternalPassport(t) => t.extra(),
+      InputPassportElement::Passport(t) => t.extra(),
+      InputPassportElement::PassportRegistration(t) => t.extra(),
+      InputPassportElement::PersonalDetails(t) => t.extra(),
+      InputPassportElement::PhoneNumber(t) => t.extra(),
+      InputPassportElement::RentalAgreement(t) => t.extra(),
+      InputPassportElement::TemporaryRegistration(t) => t.extra(),
+      InputPassportElement::UtilityBill(t) => t.extra(),
+
+      InputPassportElement::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputPassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputPassportElement::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputPassportElement::Unknown { .. } = self { true } else { false } }
 
   pub fn is_address(&self) -> bool { if let InputPassportElement::Address(_) = self { true } else { false } }
   pub fn is_bank_statement(&self) -> bool { if let InputPassportElement::BankStatement(_) = self { true } else { false } }
@@ -189,6 +213,9 @@ pub struct InputPassportElementAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The address to be saved
   address: Address,
   
@@ -196,6 +223,7 @@ pub struct InputPassportElementAddress {
 
 impl RObject for InputPassportElementAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -224,6 +252,11 @@ pub struct RTDInputPassportElementAddressBuilder {
 impl RTDInputPassportElementAddressBuilder {
   pub fn build(&self) -> InputPassportElementAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().clone();
@@ -252,6 +285,9 @@ pub struct InputPassportElementBankStatement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The bank statement to be saved
   bank_statement: InputPersonalDocument,
   
@@ -259,6 +295,7 @@ pub struct InputPassportElementBankStatement {
 
 impl RObject for InputPassportElementBankStatement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementBankStatement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -287,6 +324,11 @@ pub struct RTDInputPassportElementBankStatementBuilder {
 impl RTDInputPassportElementBankStatementBuilder {
   pub fn build(&self) -> InputPassportElementBankStatement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bank_statement<T: AsRef<InputPersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
     self.inner.bank_statement = bank_statement.as_ref().clone();
@@ -315,6 +357,9 @@ pub struct InputPassportElementDriverLicense {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The driver license to be saved
   driver_license: InputIdentityDocument,
   
@@ -322,6 +367,7 @@ pub struct InputPassportElementDriverLicense {
 
 impl RObject for InputPassportElementDriverLicense {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementDriverLicense" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -350,6 +396,11 @@ pub struct RTDInputPassportElementDriverLicenseBuilder {
 impl RTDInputPassportElementDriverLicenseBuilder {
   pub fn build(&self) -> InputPassportElementDriverLicense { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn driver_license<T: AsRef<InputIdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
     self.inner.driver_license = driver_license.as_ref().clone();
@@ -378,6 +429,9 @@ pub struct InputPassportElementEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The email address to be saved
   email_address: String,
   
@@ -385,6 +439,7 @@ pub struct InputPassportElementEmailAddress {
 
 impl RObject for InputPassportElementEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -413,6 +468,11 @@ pub struct RTDInputPassportElementEmailAddressBuilder {
 impl RTDInputPassportElementEmailAddressBuilder {
   pub fn build(&self) -> InputPassportElementEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
@@ -441,6 +501,9 @@ pub struct InputPassportElementIdentityCard {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identity card to be saved
   identity_card: InputIdentityDocument,
   
@@ -448,6 +511,7 @@ pub struct InputPassportElementIdentityCard {
 
 impl RObject for InputPassportElementIdentityCard {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementIdentityCard" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -476,6 +540,11 @@ pub struct RTDInputPassportElementIdentityCardBuilder {
 impl RTDInputPassportElementIdentityCardBuilder {
   pub fn build(&self) -> InputPassportElementIdentityCard { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn identity_card<T: AsRef<InputIdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
     self.inner.identity_card = identity_card.as_ref().clone();
@@ -504,6 +573,9 @@ pub struct InputPassportElementInternalPassport {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The internal passport to be saved
   internal_passport: InputIdentityDocument,
   
@@ -511,6 +583,7 @@ pub struct InputPassportElementInternalPassport {
 
 impl RObject for InputPassportElementInternalPassport {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementInternalPassport" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -539,6 +612,11 @@ pub struct RTDInputPassportElementInternalPassportBuilder {
 impl RTDInputPassportElementInternalPassportBuilder {
   pub fn build(&self) -> InputPassportElementInternalPassport { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn internal_passport<T: AsRef<InputIdentityDocument>>(&mut self, internal_passport: T) -> &mut Self {
     self.inner.internal_passport = internal_passport.as_ref().clone();
@@ -567,6 +645,9 @@ pub struct InputPassportElementPassport {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The passport to be saved
   passport: InputIdentityDocument,
   
@@ -574,6 +655,7 @@ pub struct InputPassportElementPassport {
 
 impl RObject for InputPassportElementPassport {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPassport" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -602,6 +684,11 @@ pub struct RTDInputPassportElementPassportBuilder {
 impl RTDInputPassportElementPassportBuilder {
   pub fn build(&self) -> InputPassportElementPassport { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport<T: AsRef<InputIdentityDocument>>(&mut self, passport: T) -> &mut Self {
     self.inner.passport = passport.as_ref().clone();
@@ -630,6 +717,9 @@ pub struct InputPassportElementPassportRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The passport registration page to be saved
   passport_registration: InputPersonalDocument,
   
@@ -637,6 +727,7 @@ pub struct InputPassportElementPassportRegistration {
 
 impl RObject for InputPassportElementPassportRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPassportRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -665,6 +756,11 @@ pub struct RTDInputPassportElementPassportRegistrationBuilder {
 impl RTDInputPassportElementPassportRegistrationBuilder {
   pub fn build(&self) -> InputPassportElementPassportRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport_registration<T: AsRef<InputPersonalDocument>>(&mut self, passport_registration: T) -> &mut Self {
     self.inner.passport_registration = passport_registration.as_ref().clone();
@@ -693,6 +789,9 @@ pub struct InputPassportElementPersonalDetails {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Personal details of the user
   personal_details: PersonalDetails,
   
@@ -700,6 +799,7 @@ pub struct InputPassportElementPersonalDetails {
 
 impl RObject for InputPassportElementPersonalDetails {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPersonalDetails" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -728,6 +828,11 @@ pub struct RTDInputPassportElementPersonalDetailsBuilder {
 impl RTDInputPassportElementPersonalDetailsBuilder {
   pub fn build(&self) -> InputPassportElementPersonalDetails { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn personal_details<T: AsRef<PersonalDetails>>(&mut self, personal_details: T) -> &mut Self {
     self.inner.personal_details = personal_details.as_ref().clone();
@@ -756,6 +861,9 @@ pub struct InputPassportElementPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The phone number to be saved
   phone_number: String,
   
@@ -763,6 +871,7 @@ pub struct InputPassportElementPhoneNumber {
 
 impl RObject for InputPassportElementPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -791,6 +900,11 @@ pub struct RTDInputPassportElementPhoneNumberBuilder {
 impl RTDInputPassportElementPhoneNumberBuilder {
   pub fn build(&self) -> InputPassportElementPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -819,6 +933,9 @@ pub struct InputPassportElementRentalAgreement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The rental agreement to be saved
   rental_agreement: InputPersonalDocument,
   
@@ -826,6 +943,7 @@ pub struct InputPassportElementRentalAgreement {
 
 impl RObject for InputPassportElementRentalAgreement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementRentalAgreement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -854,6 +972,11 @@ pub struct RTDInputPassportElementRentalAgreementBuilder {
 impl RTDInputPassportElementRentalAgreementBuilder {
   pub fn build(&self) -> InputPassportElementRentalAgreement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rental_agreement<T: AsRef<InputPersonalDocument>>(&mut self, rental_agreement: T) -> &mut Self {
     self.inner.rental_agreement = rental_agreement.as_ref().clone();
@@ -882,6 +1005,9 @@ pub struct InputPassportElementTemporaryRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The temporary registration document to be saved
   temporary_registration: InputPersonalDocument,
   
@@ -889,6 +1015,7 @@ pub struct InputPassportElementTemporaryRegistration {
 
 impl RObject for InputPassportElementTemporaryRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementTemporaryRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -917,6 +1044,11 @@ pub struct RTDInputPassportElementTemporaryRegistrationBuilder {
 impl RTDInputPassportElementTemporaryRegistrationBuilder {
   pub fn build(&self) -> InputPassportElementTemporaryRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn temporary_registration<T: AsRef<InputPersonalDocument>>(&mut self, temporary_registration: T) -> &mut Self {
     self.inner.temporary_registration = temporary_registration.as_ref().clone();
@@ -945,6 +1077,9 @@ pub struct InputPassportElementUtilityBill {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The utility bill to be saved
   utility_bill: InputPersonalDocument,
   
@@ -952,6 +1087,7 @@ pub struct InputPassportElementUtilityBill {
 
 impl RObject for InputPassportElementUtilityBill {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementUtilityBill" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -980,6 +1116,11 @@ pub struct RTDInputPassportElementUtilityBillBuilder {
 impl RTDInputPassportElementUtilityBillBuilder {
   pub fn build(&self) -> InputPassportElementUtilityBill { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn utility_bill<T: AsRef<InputPersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
     self.inner.utility_bill = utility_bill.as_ref().clone();
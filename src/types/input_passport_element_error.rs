@@ -11,6 +11,9 @@ pub struct InputPassportElementError {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of Telegram Passport element that has the error
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// Error message
@@ -22,6 +25,7 @@ pub struct InputPassportElementError {
 
 impl RObject for InputPassportElementError {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementError" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDInputPassportElementErrorBuilder {
 impl RTDInputPassportElementErrorBuilder {
   pub fn build(&self) -> InputPassportElementError { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
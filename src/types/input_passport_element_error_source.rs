@@ -18,6 +18,9 @@ pub trait TDInputPassportElementErrorSource: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputPassportElementErrorSource {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A data field contains an error. The error is considered resolved when the field's value changes
   DataField(InputPassportElementErrorSourceDataField),
   /// The file contains an error. The error is considered resolved when the file changes
@@ -78,12 +81,29 @@ impl RObject for InputPassportElementErrorSource {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputPassportElementErrorSource::DataField(t) => t.extra(),
+      InputPassportElementErrorSource::File(t) => t.extra(),
+      InputPassportElementErrorSource::Files(t) => t.extra(),
+      InputPassportElementErrorSource::FrontSide(t) => t.extra(),
+      InputPassportElementErrorSource::ReverseSide(t) => t.extra(),
+      InputPassportElementErrorSource::Selfie(t) => t.extra(),
+      InputPassportElementErrorSource::TranslationFile(t) => t.extra(),
+      InputPassportElementErrorSource::TranslationFiles(t) => t.extra(),
+      InputPassportElementErrorSource::Unspecified(t) => t.extra(),
+
+      InputPassportElementErrorSource::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputPassportElementErrorSource {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputPassportElementErrorSource::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputPassportElementErrorSource::Unknown { .. } = self { true } else { false } }
 
   pub fn is_data_field(&self) -> bool { if let InputPassportElementErrorSource::DataField(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let InputPassportElementErrorSource::File(_) = self { true } else { false } }
@@ -153,15 +173,19 @@ pub struct InputPassportElementErrorSourceDataField {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Field name
   field_name: String,
   /// Current data hash
-  data_hash: String,
+  data_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceDataField {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceDataField" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -180,7 +204,7 @@ impl InputPassportElementErrorSourceDataField {
 
   pub fn field_name(&self) -> &String { &self.field_name }
 
-  pub fn data_hash(&self) -> &String { &self.data_hash }
+  pub fn data_hash(&self) -> &TdBytes { &self.data_hash }
 
 }
 
@@ -192,6 +216,11 @@ pub struct RTDInputPassportElementErrorSourceDataFieldBuilder {
 impl RTDInputPassportElementErrorSourceDataFieldBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceDataField { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
     self.inner.field_name = field_name.as_ref().to_string();
@@ -199,8 +228,8 @@ impl RTDInputPassportElementErrorSourceDataFieldBuilder {
   }
 
    
-  pub fn data_hash<T: AsRef<str>>(&mut self, data_hash: T) -> &mut Self {
-    self.inner.data_hash = data_hash.as_ref().to_string();
+  pub fn data_hash<T: AsRef<[u8]>>(&mut self, data_hash: T) -> &mut Self {
+    self.inner.data_hash = TdBytes::from(data_hash.as_ref());
     self
   }
 
@@ -226,13 +255,17 @@ pub struct InputPassportElementErrorSourceFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hash of the file which has the error
-  file_hash: String,
+  file_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -249,7 +282,7 @@ impl InputPassportElementErrorSourceFile {
     RTDInputPassportElementErrorSourceFileBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  pub fn file_hash(&self) -> &TdBytes { &self.file_hash }
 
 }
 
@@ -261,9 +294,14 @@ pub struct RTDInputPassportElementErrorSourceFileBuilder {
 impl RTDInputPassportElementErrorSourceFileBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = TdBytes::from(file_hash.as_ref());
     self
   }
 
@@ -289,13 +327,17 @@ pub struct InputPassportElementErrorSourceFiles {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hashes of all attached files
-  file_hashes: Vec<String>,
+  file_hashes: Vec<TdBytes>,
   
 }
 
 impl RObject for InputPassportElementErrorSourceFiles {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceFiles" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -312,7 +354,7 @@ impl InputPassportElementErrorSourceFiles {
     RTDInputPassportElementErrorSourceFilesBuilder { inner }
   }
 
-  pub fn file_hashes(&self) -> &Vec<String> { &self.file_hashes }
+  pub fn file_hashes(&self) -> &Vec<TdBytes> { &self.file_hashes }
 
 }
 
@@ -324,8 +366,13 @@ pub struct RTDInputPassportElementErrorSourceFilesBuilder {
 impl RTDInputPassportElementErrorSourceFilesBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFiles { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
+  pub fn file_hashes(&mut self, file_hashes: Vec<TdBytes>) -> &mut Self {
     self.inner.file_hashes = file_hashes;
     self
   }
@@ -352,13 +399,17 @@ pub struct InputPassportElementErrorSourceFrontSide {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hash of the file containing the front side
-  file_hash: String,
+  file_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceFrontSide {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceFrontSide" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -375,7 +426,7 @@ impl InputPassportElementErrorSourceFrontSide {
     RTDInputPassportElementErrorSourceFrontSideBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  pub fn file_hash(&self) -> &TdBytes { &self.file_hash }
 
 }
 
@@ -387,9 +438,14 @@ pub struct RTDInputPassportElementErrorSourceFrontSideBuilder {
 impl RTDInputPassportElementErrorSourceFrontSideBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFrontSide { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = TdBytes::from(file_hash.as_ref());
     self
   }
 
@@ -415,13 +471,17 @@ pub struct InputPassportElementErrorSourceReverseSide {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hash of the file containing the reverse side
-  file_hash: String,
+  file_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceReverseSide {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceReverseSide" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -438,7 +498,7 @@ impl InputPassportElementErrorSourceReverseSide {
     RTDInputPassportElementErrorSourceReverseSideBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  pub fn file_hash(&self) -> &TdBytes { &self.file_hash }
 
 }
 
@@ -450,9 +510,14 @@ pub struct RTDInputPassportElementErrorSourceReverseSideBuilder {
 impl RTDInputPassportElementErrorSourceReverseSideBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceReverseSide { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = TdBytes::from(file_hash.as_ref());
     self
   }
 
@@ -478,13 +543,17 @@ pub struct InputPassportElementErrorSourceSelfie {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hash of the file containing the selfie
-  file_hash: String,
+  file_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceSelfie {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceSelfie" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -501,7 +570,7 @@ impl InputPassportElementErrorSourceSelfie {
     RTDInputPassportElementErrorSourceSelfieBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  pub fn file_hash(&self) -> &TdBytes { &self.file_hash }
 
 }
 
@@ -513,9 +582,14 @@ pub struct RTDInputPassportElementErrorSourceSelfieBuilder {
 impl RTDInputPassportElementErrorSourceSelfieBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceSelfie { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = TdBytes::from(file_hash.as_ref());
     self
   }
 
@@ -541,13 +615,17 @@ pub struct InputPassportElementErrorSourceTranslationFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hash of the file containing the translation
-  file_hash: String,
+  file_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceTranslationFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceTranslationFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -564,7 +642,7 @@ impl InputPassportElementErrorSourceTranslationFile {
     RTDInputPassportElementErrorSourceTranslationFileBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  pub fn file_hash(&self) -> &TdBytes { &self.file_hash }
 
 }
 
@@ -576,9 +654,14 @@ pub struct RTDInputPassportElementErrorSourceTranslationFileBuilder {
 impl RTDInputPassportElementErrorSourceTranslationFileBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceTranslationFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = TdBytes::from(file_hash.as_ref());
     self
   }
 
@@ -604,13 +687,17 @@ pub struct InputPassportElementErrorSourceTranslationFiles {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hashes of all files with the translation
-  file_hashes: Vec<String>,
+  file_hashes: Vec<TdBytes>,
   
 }
 
 impl RObject for InputPassportElementErrorSourceTranslationFiles {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceTranslationFiles" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -627,7 +714,7 @@ impl InputPassportElementErrorSourceTranslationFiles {
     RTDInputPassportElementErrorSourceTranslationFilesBuilder { inner }
   }
 
-  pub fn file_hashes(&self) -> &Vec<String> { &self.file_hashes }
+  pub fn file_hashes(&self) -> &Vec<TdBytes> { &self.file_hashes }
 
 }
 
@@ -639,8 +726,13 @@ pub struct RTDInputPassportElementErrorSourceTranslationFilesBuilder {
 impl RTDInputPassportElementErrorSourceTranslationFilesBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceTranslationFiles { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
+  pub fn file_hashes(&mut self, file_hashes: Vec<TdBytes>) -> &mut Self {
     self.inner.file_hashes = file_hashes;
     self
   }
@@ -667,13 +759,17 @@ pub struct InputPassportElementErrorSourceUnspecified {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Current hash of the entire element
-  element_hash: String,
+  element_hash: TdBytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceUnspecified {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceUnspecified" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -690,7 +786,7 @@ impl InputPassportElementErrorSourceUnspecified {
     RTDInputPassportElementErrorSourceUnspecifiedBuilder { inner }
   }
 
-  pub fn element_hash(&self) -> &String { &self.element_hash }
+  pub fn element_hash(&self) -> &TdBytes { &self.element_hash }
 
 }
 
@@ -702,9 +798,14 @@ pub struct RTDInputPassportElementErrorSourceUnspecifiedBuilder {
 impl RTDInputPassportElementErrorSourceUnspecifiedBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceUnspecified { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn element_hash<T: AsRef<str>>(&mut self, element_hash: T) -> &mut Self {
-    self.inner.element_hash = element_hash.as_ref().to_string();
+  pub fn element_hash<T: AsRef<[u8]>>(&mut self, element_hash: T) -> &mut Self {
+    self.inner.element_hash = TdBytes::from(element_hash.as_ref());
     self
   }
 
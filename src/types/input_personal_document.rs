@@ -11,6 +11,9 @@ pub struct InputPersonalDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of files containing the pages of the document
   files: Vec<InputFile>,
   /// List of files containing a certified English translation of the document
@@ -20,6 +23,7 @@ pub struct InputPersonalDocument {
 
 impl RObject for InputPersonalDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPersonalDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDInputPersonalDocumentBuilder {
 impl RTDInputPersonalDocumentBuilder {
   pub fn build(&self) -> InputPersonalDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn files(&mut self, files: Vec<InputFile>) -> &mut Self {
     self.inner.files = files;
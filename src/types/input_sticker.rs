@@ -18,6 +18,9 @@ pub trait TDInputSticker: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputSticker {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animated sticker in TGS format
   Animated(InputStickerAnimated),
   /// A static sticker in PNG format, which will be converted to WEBP server-side
@@ -50,12 +53,22 @@ impl RObject for InputSticker {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      InputSticker::Animated(t) => t.extra(),
+      InputSticker::Static(t) => t.extra(),
+
+      InputSticker::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl InputSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputSticker::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let InputSticker::Unknown { .. } = self { true } else { false } }
 
   pub fn is_animated(&self) -> bool { if let InputSticker::Animated(_) = self { true } else { false } }
   pub fn is_static(&self) -> bool { if let InputSticker::Static(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct InputStickerAnimated {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// File with the animated sticker. Only local or uploaded within a week files are supported. See https://core.telegram.org/animated_stickers#technical-requirements for technical requirements
   sticker: InputFile,
   /// Emojis corresponding to the sticker
@@ -99,6 +115,7 @@ pub struct InputStickerAnimated {
 
 impl RObject for InputStickerAnimated {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputStickerAnimated" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -129,6 +146,11 @@ pub struct RTDInputStickerAnimatedBuilder {
 impl RTDInputStickerAnimatedBuilder {
   pub fn build(&self) -> InputStickerAnimated { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -163,6 +185,9 @@ pub struct InputStickerStatic {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// PNG image with the sticker; must be up to 512 KB in size and fit in a 512x512 square
   sticker: InputFile,
   /// Emojis corresponding to the sticker
@@ -174,6 +199,7 @@ pub struct InputStickerStatic {
 
 impl RObject for InputStickerStatic {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputStickerStatic" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -206,6 +232,11 @@ pub struct RTDInputStickerStaticBuilder {
 impl RTDInputStickerStaticBuilder {
   pub fn build(&self) -> InputStickerStatic { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
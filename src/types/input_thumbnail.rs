@@ -11,6 +11,9 @@ pub struct InputThumbnail {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Thumbnail file to send. Sending thumbnails by file_id is currently not supported
   thumbnail: InputFile,
   /// Thumbnail width, usually shouldn't exceed 320. Use 0 if unknown
@@ -22,6 +25,7 @@ pub struct InputThumbnail {
 
 impl RObject for InputThumbnail {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "inputThumbnail" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDInputThumbnailBuilder {
 impl RTDInputThumbnailBuilder {
   pub fn build(&self) -> InputThumbnail { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn thumbnail<T: AsRef<InputFile>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
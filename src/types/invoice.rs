@@ -11,6 +11,9 @@ pub struct Invoice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// ISO 4217 currency code
   currency: String,
   /// A list of objects used to calculate the total price of the product
@@ -36,6 +39,7 @@ pub struct Invoice {
 
 impl RObject for Invoice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "invoice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -79,6 +83,11 @@ pub struct RTDInvoiceBuilder {
 impl RTDInvoiceBuilder {
   pub fn build(&self) -> Invoice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct JsonObjectMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Member's key
   key: String,
   /// Member's value
@@ -20,6 +23,7 @@ pub struct JsonObjectMember {
 
 impl RObject for JsonObjectMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonObjectMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDJsonObjectMemberBuilder {
 impl RTDJsonObjectMemberBuilder {
   pub fn build(&self) -> JsonObjectMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
     self.inner.key = key.as_ref().to_string();
@@ -18,10 +18,9 @@ pub trait TDJsonValue: Debug + RObject {}
 #[serde(untagged)]
 pub enum JsonValue {
   #[doc(hidden)] _Default(()),
-  /// Returns application config, provided by the server. Can be called before authorization
-  GetApplicationConfig(GetApplicationConfig),
-  /// Converts a JSON-serialized string to corresponding JsonValue object. Can be called synchronously
-  GetJsonValue(GetJsonValue),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents a JSON array
   Array(JsonValueArray),
   /// Represents a boolean JSON value
@@ -46,8 +45,6 @@ impl<'de> Deserialize<'de> for JsonValue {
     use serde::de::Error;
     rtd_enum_deserialize!(
       JsonValue,
-      (getApplicationConfig, GetApplicationConfig);
-      (getJsonValue, GetJsonValue);
       (jsonValueArray, Array);
       (jsonValueBoolean, Boolean);
       (jsonValueNull, Null);
@@ -62,8 +59,6 @@ impl<'de> Deserialize<'de> for JsonValue {
 impl RObject for JsonValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      JsonValue::GetApplicationConfig(t) => t.td_name(),
-      JsonValue::GetJsonValue(t) => t.td_name(),
       JsonValue::Array(t) => t.td_name(),
       JsonValue::Boolean(t) => t.td_name(),
       JsonValue::Null(t) => t.td_name(),
@@ -74,15 +69,27 @@ impl RObject for JsonValue {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      JsonValue::Array(t) => t.extra(),
+      JsonValue::Boolean(t) => t.extra(),
+      JsonValue::Null(t) => t.extra(),
+      JsonValue::Number(t) => t.extra(),
+      JsonValue::Object(t) => t.extra(),
+      JsonValue::String(t) => t.extra(),
+
+      JsonValue::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl JsonValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let JsonValue::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let JsonValue::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_application_config(&self) -> bool { if let JsonValue::GetApplicationConfig(_) = self { true } else { false } }
-  pub fn is_get_json_value(&self) -> bool { if let JsonValue::GetJsonValue(_) = self { true } else { false } }
   pub fn is_array(&self) -> bool { if let JsonValue::Array(_) = self { true } else { false } }
   pub fn is_boolean(&self) -> bool { if let JsonValue::Boolean(_) = self { true } else { false } }
   pub fn is_null(&self) -> bool { if let JsonValue::Null(_) = self { true } else { false } }
@@ -90,8 +97,6 @@ impl JsonValue {
   pub fn is_object(&self) -> bool { if let JsonValue::Object(_) = self { true } else { false } }
   pub fn is_string(&self) -> bool { if let JsonValue::String(_) = self { true } else { false } }
 
-  pub fn on_get_application_config<F: FnOnce(&GetApplicationConfig)>(&self, fnc: F) -> &Self { if let JsonValue::GetApplicationConfig(t) = self { fnc(t) }; self }
-  pub fn on_get_json_value<F: FnOnce(&GetJsonValue)>(&self, fnc: F) -> &Self { if let JsonValue::GetJsonValue(t) = self { fnc(t) }; self }
   pub fn on_array<F: FnOnce(&JsonValueArray)>(&self, fnc: F) -> &Self { if let JsonValue::Array(t) = self { fnc(t) }; self }
   pub fn on_boolean<F: FnOnce(&JsonValueBoolean)>(&self, fnc: F) -> &Self { if let JsonValue::Boolean(t) = self { fnc(t) }; self }
   pub fn on_null<F: FnOnce(&JsonValueNull)>(&self, fnc: F) -> &Self { if let JsonValue::Null(t) = self { fnc(t) }; self }
@@ -99,8 +104,6 @@ impl JsonValue {
   pub fn on_object<F: FnOnce(&JsonValueObject)>(&self, fnc: F) -> &Self { if let JsonValue::Object(t) = self { fnc(t) }; self }
   pub fn on_string<F: FnOnce(&JsonValueString)>(&self, fnc: F) -> &Self { if let JsonValue::String(t) = self { fnc(t) }; self }
 
-  pub fn as_get_application_config(&self) -> Option<&GetApplicationConfig> { if let JsonValue::GetApplicationConfig(t) = self { return Some(t) } None }
-  pub fn as_get_json_value(&self) -> Option<&GetJsonValue> { if let JsonValue::GetJsonValue(t) = self { return Some(t) } None }
   pub fn as_array(&self) -> Option<&JsonValueArray> { if let JsonValue::Array(t) = self { return Some(t) } None }
   pub fn as_boolean(&self) -> Option<&JsonValueBoolean> { if let JsonValue::Boolean(t) = self { return Some(t) } None }
   pub fn as_null(&self) -> Option<&JsonValueNull> { if let JsonValue::Null(t) = self { return Some(t) } None }
@@ -110,10 +113,6 @@ impl JsonValue {
 
 
 
-  pub fn get_application_config<T: AsRef<GetApplicationConfig>>(t: T) -> Self { JsonValue::GetApplicationConfig(t.as_ref().clone()) }
-
-  pub fn get_json_value<T: AsRef<GetJsonValue>>(t: T) -> Self { JsonValue::GetJsonValue(t.as_ref().clone()) }
-
   pub fn array<T: AsRef<JsonValueArray>>(t: T) -> Self { JsonValue::Array(t.as_ref().clone()) }
 
   pub fn boolean<T: AsRef<JsonValueBoolean>>(t: T) -> Self { JsonValue::Boolean(t.as_ref().clone()) }
@@ -144,6 +143,9 @@ pub struct JsonValueArray {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The list of array elements
   values: Vec<JsonValue>,
   
@@ -151,6 +153,7 @@ pub struct JsonValueArray {
 
 impl RObject for JsonValueArray {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueArray" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -179,6 +182,11 @@ pub struct RTDJsonValueArrayBuilder {
 impl RTDJsonValueArrayBuilder {
   pub fn build(&self) -> JsonValueArray { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn values(&mut self, values: Vec<JsonValue>) -> &mut Self {
     self.inner.values = values;
@@ -207,6 +215,9 @@ pub struct JsonValueBoolean {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value
   value: bool,
   
@@ -214,6 +225,7 @@ pub struct JsonValueBoolean {
 
 impl RObject for JsonValueBoolean {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueBoolean" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -242,6 +254,11 @@ pub struct RTDJsonValueBooleanBuilder {
 impl RTDJsonValueBooleanBuilder {
   pub fn build(&self) -> JsonValueBoolean { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: bool) -> &mut Self {
     self.inner.value = value;
@@ -270,11 +287,15 @@ pub struct JsonValueNull {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for JsonValueNull {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueNull" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -301,6 +322,11 @@ pub struct RTDJsonValueNullBuilder {
 impl RTDJsonValueNullBuilder {
   pub fn build(&self) -> JsonValueNull { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<JsonValueNull> for JsonValueNull {
@@ -323,13 +349,17 @@ pub struct JsonValueNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value
-  value: f32,
+  value: f64,
   
 }
 
 impl RObject for JsonValueNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -346,7 +376,7 @@ impl JsonValueNumber {
     RTDJsonValueNumberBuilder { inner }
   }
 
-  pub fn value(&self) -> f32 { self.value }
+  pub fn value(&self) -> f64 { self.value }
 
 }
 
@@ -358,8 +388,13 @@ pub struct RTDJsonValueNumberBuilder {
 impl RTDJsonValueNumberBuilder {
   pub fn build(&self) -> JsonValueNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn value(&mut self, value: f32) -> &mut Self {
+  pub fn value(&mut self, value: f64) -> &mut Self {
     self.inner.value = value;
     self
   }
@@ -386,6 +421,9 @@ pub struct JsonValueObject {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The list of object members
   members: Vec<JsonObjectMember>,
   
@@ -393,6 +431,7 @@ pub struct JsonValueObject {
 
 impl RObject for JsonValueObject {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueObject" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -421,6 +460,11 @@ pub struct RTDJsonValueObjectBuilder {
 impl RTDJsonValueObjectBuilder {
   pub fn build(&self) -> JsonValueObject { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn members(&mut self, members: Vec<JsonObjectMember>) -> &mut Self {
     self.inner.members = members;
@@ -449,6 +493,9 @@ pub struct JsonValueString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value
   value: String,
   
@@ -456,6 +503,7 @@ pub struct JsonValueString {
 
 impl RObject for JsonValueString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -484,6 +532,11 @@ pub struct RTDJsonValueStringBuilder {
 impl RTDJsonValueStringBuilder {
   pub fn build(&self) -> JsonValueString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct KeyboardButton {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text of the button
   text: String,
   /// Type of the button
@@ -20,6 +23,7 @@ pub struct KeyboardButton {
 
 impl RObject for KeyboardButton {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButton" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDKeyboardButtonBuilder {
 impl RTDKeyboardButtonBuilder {
   pub fn build(&self) -> KeyboardButton { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
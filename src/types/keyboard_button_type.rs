@@ -18,6 +18,9 @@ pub trait TDKeyboardButtonType: Debug + RObject {}
 #[serde(untagged)]
 pub enum KeyboardButtonType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A button that sends the user's location when pressed; available only in private chats
   RequestLocation(KeyboardButtonTypeRequestLocation),
   /// A button that sends the user's phone number when pressed; available only in private chats
@@ -58,12 +61,24 @@ impl RObject for KeyboardButtonType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      KeyboardButtonType::RequestLocation(t) => t.extra(),
+      KeyboardButtonType::RequestPhoneNumber(t) => t.extra(),
+      KeyboardButtonType::RequestPoll(t) => t.extra(),
+      KeyboardButtonType::Text(t) => t.extra(),
+
+      KeyboardButtonType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl KeyboardButtonType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let KeyboardButtonType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let KeyboardButtonType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_request_location(&self) -> bool { if let KeyboardButtonType::RequestLocation(_) = self { true } else { false } }
   pub fn is_request_phone_number(&self) -> bool { if let KeyboardButtonType::RequestPhoneNumber(_) = self { true } else { false } }
@@ -108,11 +123,15 @@ pub struct KeyboardButtonTypeRequestLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for KeyboardButtonTypeRequestLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,6 +158,11 @@ pub struct RTDKeyboardButtonTypeRequestLocationBuilder {
 impl RTDKeyboardButtonTypeRequestLocationBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<KeyboardButtonTypeRequestLocation> for KeyboardButtonTypeRequestLocation {
@@ -161,11 +185,15 @@ pub struct KeyboardButtonTypeRequestPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for KeyboardButtonTypeRequestPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -192,6 +220,11 @@ pub struct RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
 impl RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<KeyboardButtonTypeRequestPhoneNumber> for KeyboardButtonTypeRequestPhoneNumber {
@@ -214,6 +247,9 @@ pub struct KeyboardButtonTypeRequestPoll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If true, only regular polls must be allowed to create
   force_regular: bool,
   /// If true, only polls in quiz mode must be allowed to create
@@ -223,6 +259,7 @@ pub struct KeyboardButtonTypeRequestPoll {
 
 impl RObject for KeyboardButtonTypeRequestPoll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestPoll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -253,6 +290,11 @@ pub struct RTDKeyboardButtonTypeRequestPollBuilder {
 impl RTDKeyboardButtonTypeRequestPollBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestPoll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn force_regular(&mut self, force_regular: bool) -> &mut Self {
     self.inner.force_regular = force_regular;
@@ -287,11 +329,15 @@ pub struct KeyboardButtonTypeText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for KeyboardButtonTypeText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -318,6 +364,11 @@ pub struct RTDKeyboardButtonTypeTextBuilder {
 impl RTDKeyboardButtonTypeTextBuilder {
   pub fn build(&self) -> KeyboardButtonTypeText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<KeyboardButtonTypeText> for KeyboardButtonTypeText {
@@ -11,6 +11,9 @@ pub struct LabeledPricePart {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Label for this portion of the product price
   label: String,
   /// Currency amount in minimal quantity of the currency
@@ -20,6 +23,7 @@ pub struct LabeledPricePart {
 
 impl RObject for LabeledPricePart {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "labeledPricePart" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDLabeledPricePartBuilder {
 impl RTDLabeledPricePartBuilder {
   pub fn build(&self) -> LabeledPricePart { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
     self.inner.label = label.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct LanguagePackInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique language pack identifier
   id: String,
   /// Identifier of a base language pack; may be empty. If a string is missed in the language pack, then it should be fetched from base language pack. Unsupported in custom language packs
@@ -42,6 +45,7 @@ pub struct LanguagePackInfo {
 
 impl RObject for LanguagePackInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -91,6 +95,11 @@ pub struct RTDLanguagePackInfoBuilder {
 impl RTDLanguagePackInfoBuilder {
   pub fn build(&self) -> LanguagePackInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -18,8 +18,9 @@ pub trait TDLanguagePackStringValue: Debug + RObject {}
 #[serde(untagged)]
 pub enum LanguagePackStringValue {
   #[doc(hidden)] _Default(()),
-  /// Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. Can be called synchronously
-  GetLanguagePackString(GetLanguagePackString),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A deleted language pack string, the value should be taken from the built-in english language pack
   Deleted(LanguagePackStringValueDeleted),
   /// An ordinary language pack string
@@ -38,7 +39,6 @@ impl<'de> Deserialize<'de> for LanguagePackStringValue {
     use serde::de::Error;
     rtd_enum_deserialize!(
       LanguagePackStringValue,
-      (getLanguagePackString, GetLanguagePackString);
       (languagePackStringValueDeleted, Deleted);
       (languagePackStringValueOrdinary, Ordinary);
       (languagePackStringValuePluralized, Pluralized);
@@ -50,7 +50,6 @@ impl<'de> Deserialize<'de> for LanguagePackStringValue {
 impl RObject for LanguagePackStringValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      LanguagePackStringValue::GetLanguagePackString(t) => t.td_name(),
       LanguagePackStringValue::Deleted(t) => t.td_name(),
       LanguagePackStringValue::Ordinary(t) => t.td_name(),
       LanguagePackStringValue::Pluralized(t) => t.td_name(),
@@ -58,32 +57,38 @@ impl RObject for LanguagePackStringValue {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      LanguagePackStringValue::Deleted(t) => t.extra(),
+      LanguagePackStringValue::Ordinary(t) => t.extra(),
+      LanguagePackStringValue::Pluralized(t) => t.extra(),
+
+      LanguagePackStringValue::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl LanguagePackStringValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LanguagePackStringValue::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let LanguagePackStringValue::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_language_pack_string(&self) -> bool { if let LanguagePackStringValue::GetLanguagePackString(_) = self { true } else { false } }
   pub fn is_deleted(&self) -> bool { if let LanguagePackStringValue::Deleted(_) = self { true } else { false } }
   pub fn is_ordinary(&self) -> bool { if let LanguagePackStringValue::Ordinary(_) = self { true } else { false } }
   pub fn is_pluralized(&self) -> bool { if let LanguagePackStringValue::Pluralized(_) = self { true } else { false } }
 
-  pub fn on_get_language_pack_string<F: FnOnce(&GetLanguagePackString)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::GetLanguagePackString(t) = self { fnc(t) }; self }
   pub fn on_deleted<F: FnOnce(&LanguagePackStringValueDeleted)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Deleted(t) = self { fnc(t) }; self }
   pub fn on_ordinary<F: FnOnce(&LanguagePackStringValueOrdinary)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Ordinary(t) = self { fnc(t) }; self }
   pub fn on_pluralized<F: FnOnce(&LanguagePackStringValuePluralized)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Pluralized(t) = self { fnc(t) }; self }
 
-  pub fn as_get_language_pack_string(&self) -> Option<&GetLanguagePackString> { if let LanguagePackStringValue::GetLanguagePackString(t) = self { return Some(t) } None }
   pub fn as_deleted(&self) -> Option<&LanguagePackStringValueDeleted> { if let LanguagePackStringValue::Deleted(t) = self { return Some(t) } None }
   pub fn as_ordinary(&self) -> Option<&LanguagePackStringValueOrdinary> { if let LanguagePackStringValue::Ordinary(t) = self { return Some(t) } None }
   pub fn as_pluralized(&self) -> Option<&LanguagePackStringValuePluralized> { if let LanguagePackStringValue::Pluralized(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_language_pack_string<T: AsRef<GetLanguagePackString>>(t: T) -> Self { LanguagePackStringValue::GetLanguagePackString(t.as_ref().clone()) }
-
   pub fn deleted<T: AsRef<LanguagePackStringValueDeleted>>(t: T) -> Self { LanguagePackStringValue::Deleted(t.as_ref().clone()) }
 
   pub fn ordinary<T: AsRef<LanguagePackStringValueOrdinary>>(t: T) -> Self { LanguagePackStringValue::Ordinary(t.as_ref().clone()) }
@@ -108,11 +113,15 @@ pub struct LanguagePackStringValueDeleted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for LanguagePackStringValueDeleted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStringValueDeleted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,6 +148,11 @@ pub struct RTDLanguagePackStringValueDeletedBuilder {
 impl RTDLanguagePackStringValueDeletedBuilder {
   pub fn build(&self) -> LanguagePackStringValueDeleted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LanguagePackStringValueDeleted> for LanguagePackStringValueDeleted {
@@ -161,6 +175,9 @@ pub struct LanguagePackStringValueOrdinary {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// String value
   value: String,
   
@@ -168,6 +185,7 @@ pub struct LanguagePackStringValueOrdinary {
 
 impl RObject for LanguagePackStringValueOrdinary {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStringValueOrdinary" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -196,6 +214,11 @@ pub struct RTDLanguagePackStringValueOrdinaryBuilder {
 impl RTDLanguagePackStringValueOrdinaryBuilder {
   pub fn build(&self) -> LanguagePackStringValueOrdinary { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -224,6 +247,9 @@ pub struct LanguagePackStringValuePluralized {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Value for zero objects
   zero_value: String,
   /// Value for one object
@@ -241,6 +267,7 @@ pub struct LanguagePackStringValuePluralized {
 
 impl RObject for LanguagePackStringValuePluralized {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStringValuePluralized" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -279,6 +306,11 @@ pub struct RTDLanguagePackStringValuePluralizedBuilder {
 impl RTDLanguagePackStringValuePluralizedBuilder {
   pub fn build(&self) -> LanguagePackStringValuePluralized { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn zero_value<T: AsRef<str>>(&mut self, zero_value: T) -> &mut Self {
     self.inner.zero_value = zero_value.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct LanguagePackStrings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A list of language pack strings
   strings: Vec<LanguagePackString>,
   
@@ -18,6 +21,7 @@ pub struct LanguagePackStrings {
 
 impl RObject for LanguagePackStrings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStrings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDLanguagePackStringsBuilder {
 impl RTDLanguagePackStringsBuilder {
   pub fn build(&self) -> LanguagePackStrings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn strings(&mut self, strings: Vec<LanguagePackString>) -> &mut Self {
     self.inner.strings = strings;
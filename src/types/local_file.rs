@@ -11,6 +11,9 @@ pub struct LocalFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Local path to the locally available file part; may be empty
   path: String,
   /// True, if it is possible to try to download or generate the file
@@ -32,6 +35,7 @@ pub struct LocalFile {
 
 impl RObject for LocalFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "localFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -71,6 +75,11 @@ pub struct RTDLocalFileBuilder {
 impl RTDLocalFileBuilder {
   pub fn build(&self) -> LocalFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
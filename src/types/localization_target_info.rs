@@ -11,6 +11,9 @@ pub struct LocalizationTargetInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of available language packs for this application
   language_packs: Vec<LanguagePackInfo>,
   
@@ -18,6 +21,7 @@ pub struct LocalizationTargetInfo {
 
 impl RObject for LocalizationTargetInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "localizationTargetInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDLocalizationTargetInfoBuilder {
 impl RTDLocalizationTargetInfoBuilder {
   pub fn build(&self) -> LocalizationTargetInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_packs(&mut self, language_packs: Vec<LanguagePackInfo>) -> &mut Self {
     self.inner.language_packs = language_packs;
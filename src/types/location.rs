@@ -11,6 +11,9 @@ pub struct Location {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Latitude of the location in degrees; as defined by the sender
   latitude: f32,
   /// Longitude of the location, in degrees; as defined by the sender
@@ -20,6 +23,7 @@ pub struct Location {
 
 impl RObject for Location {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "location" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDLocationBuilder {
 impl RTDLocationBuilder {
   pub fn build(&self) -> Location { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn latitude(&mut self, latitude: f32) -> &mut Self {
     self.inner.latitude = latitude;
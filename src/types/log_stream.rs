@@ -18,8 +18,9 @@ pub trait TDLogStream: Debug + RObject {}
 #[serde(untagged)]
 pub enum LogStream {
   #[doc(hidden)] _Default(()),
-  /// Returns information about currently used log stream for internal logging of TDLib. Can be called synchronously
-  GetLogStream(GetLogStream),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The log is written to stderr or an OS specific log
   Default(LogStreamDefault),
   /// The log is written nowhere
@@ -38,7 +39,6 @@ impl<'de> Deserialize<'de> for LogStream {
     use serde::de::Error;
     rtd_enum_deserialize!(
       LogStream,
-      (getLogStream, GetLogStream);
       (logStreamDefault, Default);
       (logStreamEmpty, Empty);
       (logStreamFile, File);
@@ -50,7 +50,6 @@ impl<'de> Deserialize<'de> for LogStream {
 impl RObject for LogStream {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      LogStream::GetLogStream(t) => t.td_name(),
       LogStream::Default(t) => t.td_name(),
       LogStream::Empty(t) => t.td_name(),
       LogStream::File(t) => t.td_name(),
@@ -58,32 +57,38 @@ impl RObject for LogStream {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      LogStream::Default(t) => t.extra(),
+      LogStream::Empty(t) => t.extra(),
+      LogStream::File(t) => t.extra(),
+
+      LogStream::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl LogStream {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LogStream::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let LogStream::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_log_stream(&self) -> bool { if let LogStream::GetLogStream(_) = self { true } else { false } }
   pub fn is_default(&self) -> bool { if let LogStream::Default(_) = self { true } else { false } }
   pub fn is_empty(&self) -> bool { if let LogStream::Empty(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let LogStream::File(_) = self { true } else { false } }
 
-  pub fn on_get_log_stream<F: FnOnce(&GetLogStream)>(&self, fnc: F) -> &Self { if let LogStream::GetLogStream(t) = self { fnc(t) }; self }
   pub fn on_default<F: FnOnce(&LogStreamDefault)>(&self, fnc: F) -> &Self { if let LogStream::Default(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&LogStreamEmpty)>(&self, fnc: F) -> &Self { if let LogStream::Empty(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&LogStreamFile)>(&self, fnc: F) -> &Self { if let LogStream::File(t) = self { fnc(t) }; self }
 
-  pub fn as_get_log_stream(&self) -> Option<&GetLogStream> { if let LogStream::GetLogStream(t) = self { return Some(t) } None }
   pub fn as_default(&self) -> Option<&LogStreamDefault> { if let LogStream::Default(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&LogStreamEmpty> { if let LogStream::Empty(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&LogStreamFile> { if let LogStream::File(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_log_stream<T: AsRef<GetLogStream>>(t: T) -> Self { LogStream::GetLogStream(t.as_ref().clone()) }
-
   pub fn default<T: AsRef<LogStreamDefault>>(t: T) -> Self { LogStream::Default(t.as_ref().clone()) }
 
   pub fn empty<T: AsRef<LogStreamEmpty>>(t: T) -> Self { LogStream::Empty(t.as_ref().clone()) }
@@ -108,11 +113,15 @@ pub struct LogStreamDefault {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for LogStreamDefault {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "logStreamDefault" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,6 +148,11 @@ pub struct RTDLogStreamDefaultBuilder {
 impl RTDLogStreamDefaultBuilder {
   pub fn build(&self) -> LogStreamDefault { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LogStreamDefault> for LogStreamDefault {
@@ -161,11 +175,15 @@ pub struct LogStreamEmpty {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for LogStreamEmpty {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "logStreamEmpty" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -192,6 +210,11 @@ pub struct RTDLogStreamEmptyBuilder {
 impl RTDLogStreamEmptyBuilder {
   pub fn build(&self) -> LogStreamEmpty { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LogStreamEmpty> for LogStreamEmpty {
@@ -214,6 +237,9 @@ pub struct LogStreamFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Path to the file to where the internal TDLib log will be written
   path: String,
   /// The maximum size of the file to where the internal TDLib log is written before the file will be auto-rotated
@@ -223,6 +249,7 @@ pub struct LogStreamFile {
 
 impl RObject for LogStreamFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "logStreamFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -253,6 +280,11 @@ pub struct RTDLogStreamFileBuilder {
 impl RTDLogStreamFileBuilder {
   pub fn build(&self) -> LogStreamFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct LogTags {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of log tags
   tags: Vec<String>,
   
@@ -18,6 +21,7 @@ pub struct LogTags {
 
 impl RObject for LogTags {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "logTags" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDLogTagsBuilder {
 impl RTDLogTagsBuilder {
   pub fn build(&self) -> LogTags { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn tags(&mut self, tags: Vec<String>) -> &mut Self {
     self.inner.tags = tags;
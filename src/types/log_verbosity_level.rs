@@ -11,6 +11,9 @@ pub struct LogVerbosityLevel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Log verbosity level
   verbosity_level: i64,
   
@@ -18,6 +21,7 @@ pub struct LogVerbosityLevel {
 
 impl RObject for LogVerbosityLevel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "logVerbosityLevel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDLogVerbosityLevelBuilder {
 impl RTDLogVerbosityLevelBuilder {
   pub fn build(&self) -> LogVerbosityLevel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn verbosity_level(&mut self, verbosity_level: i64) -> &mut Self {
     self.inner.verbosity_level = verbosity_level;
@@ -18,8 +18,9 @@ pub trait TDLoginUrlInfo: Debug + RObject {}
 #[serde(untagged)]
 pub enum LoginUrlInfo {
   #[doc(hidden)] _Default(()),
-  /// Returns information about a button of type inlineKeyboardButtonTypeLoginUrl. The method needs to be called when the user presses the button
-  GetLoginUrlInfo(GetLoginUrlInfo),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An HTTP url needs to be open
   Open(LoginUrlInfoOpen),
   /// An authorization confirmation dialog needs to be shown to the user
@@ -36,7 +37,6 @@ impl<'de> Deserialize<'de> for LoginUrlInfo {
     use serde::de::Error;
     rtd_enum_deserialize!(
       LoginUrlInfo,
-      (getLoginUrlInfo, GetLoginUrlInfo);
       (loginUrlInfoOpen, Open);
       (loginUrlInfoRequestConfirmation, RequestConfirmation);
 
@@ -47,36 +47,40 @@ impl<'de> Deserialize<'de> for LoginUrlInfo {
 impl RObject for LoginUrlInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      LoginUrlInfo::GetLoginUrlInfo(t) => t.td_name(),
       LoginUrlInfo::Open(t) => t.td_name(),
       LoginUrlInfo::RequestConfirmation(t) => t.td_name(),
 
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      LoginUrlInfo::Open(t) => t.extra(),
+      LoginUrlInfo::RequestConfirmation(t) => t.extra(),
+
+      LoginUrlInfo::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl LoginUrlInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LoginUrlInfo::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let LoginUrlInfo::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_login_url_info(&self) -> bool { if let LoginUrlInfo::GetLoginUrlInfo(_) = self { true } else { false } }
   pub fn is_open(&self) -> bool { if let LoginUrlInfo::Open(_) = self { true } else { false } }
   pub fn is_request_confirmation(&self) -> bool { if let LoginUrlInfo::RequestConfirmation(_) = self { true } else { false } }
 
-  pub fn on_get_login_url_info<F: FnOnce(&GetLoginUrlInfo)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::GetLoginUrlInfo(t) = self { fnc(t) }; self }
   pub fn on_open<F: FnOnce(&LoginUrlInfoOpen)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::Open(t) = self { fnc(t) }; self }
   pub fn on_request_confirmation<F: FnOnce(&LoginUrlInfoRequestConfirmation)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::RequestConfirmation(t) = self { fnc(t) }; self }
 
-  pub fn as_get_login_url_info(&self) -> Option<&GetLoginUrlInfo> { if let LoginUrlInfo::GetLoginUrlInfo(t) = self { return Some(t) } None }
   pub fn as_open(&self) -> Option<&LoginUrlInfoOpen> { if let LoginUrlInfo::Open(t) = self { return Some(t) } None }
   pub fn as_request_confirmation(&self) -> Option<&LoginUrlInfoRequestConfirmation> { if let LoginUrlInfo::RequestConfirmation(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_login_url_info<T: AsRef<GetLoginUrlInfo>>(t: T) -> Self { LoginUrlInfo::GetLoginUrlInfo(t.as_ref().clone()) }
-
   pub fn open<T: AsRef<LoginUrlInfoOpen>>(t: T) -> Self { LoginUrlInfo::Open(t.as_ref().clone()) }
 
   pub fn request_confirmation<T: AsRef<LoginUrlInfoRequestConfirmation>>(t: T) -> Self { LoginUrlInfo::RequestConfirmation(t.as_ref().clone()) }
@@ -99,6 +103,9 @@ pub struct LoginUrlInfoOpen {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The URL to open
   url: String,
   /// True, if there is no need to show an ordinary open URL confirm
@@ -108,6 +115,7 @@ pub struct LoginUrlInfoOpen {
 
 impl RObject for LoginUrlInfoOpen {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "loginUrlInfoOpen" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -138,6 +146,11 @@ pub struct RTDLoginUrlInfoOpenBuilder {
 impl RTDLoginUrlInfoOpenBuilder {
   pub fn build(&self) -> LoginUrlInfoOpen { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -172,6 +185,9 @@ pub struct LoginUrlInfoRequestConfirmation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// An HTTP URL to be opened
   url: String,
   /// A domain of the URL
@@ -185,6 +201,7 @@ pub struct LoginUrlInfoRequestConfirmation {
 
 impl RObject for LoginUrlInfoRequestConfirmation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "loginUrlInfoRequestConfirmation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -219,6 +236,11 @@ pub struct RTDLoginUrlInfoRequestConfirmationBuilder {
 impl RTDLoginUrlInfoRequestConfirmationBuilder {
   pub fn build(&self) -> LoginUrlInfoRequestConfirmation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDMaskPoint: Debug + RObject {}
 #[serde(untagged)]
 pub enum MaskPoint {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A mask should be placed relatively to the chin
   Chin(MaskPointChin),
   /// A mask should be placed relatively to the eyes
@@ -58,12 +61,24 @@ impl RObject for MaskPoint {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      MaskPoint::Chin(t) => t.extra(),
+      MaskPoint::Eyes(t) => t.extra(),
+      MaskPoint::Forehead(t) => t.extra(),
+      MaskPoint::Mouth(t) => t.extra(),
+
+      MaskPoint::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl MaskPoint {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MaskPoint::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let MaskPoint::Unknown { .. } = self { true } else { false } }
 
   pub fn is_chin(&self) -> bool { if let MaskPoint::Chin(_) = self { true } else { false } }
   pub fn is_eyes(&self) -> bool { if let MaskPoint::Eyes(_) = self { true } else { false } }
@@ -108,11 +123,15 @@ pub struct MaskPointChin {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MaskPointChin {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointChin" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,6 +158,11 @@ pub struct RTDMaskPointChinBuilder {
 impl RTDMaskPointChinBuilder {
   pub fn build(&self) -> MaskPointChin { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointChin> for MaskPointChin {
@@ -161,11 +185,15 @@ pub struct MaskPointEyes {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MaskPointEyes {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointEyes" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -192,6 +220,11 @@ pub struct RTDMaskPointEyesBuilder {
 impl RTDMaskPointEyesBuilder {
   pub fn build(&self) -> MaskPointEyes { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointEyes> for MaskPointEyes {
@@ -214,11 +247,15 @@ pub struct MaskPointForehead {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MaskPointForehead {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointForehead" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -245,6 +282,11 @@ pub struct RTDMaskPointForeheadBuilder {
 impl RTDMaskPointForeheadBuilder {
   pub fn build(&self) -> MaskPointForehead { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointForehead> for MaskPointForehead {
@@ -267,11 +309,15 @@ pub struct MaskPointMouth {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MaskPointMouth {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointMouth" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -298,6 +344,11 @@ pub struct RTDMaskPointMouthBuilder {
 impl RTDMaskPointMouthBuilder {
   pub fn build(&self) -> MaskPointMouth { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointMouth> for MaskPointMouth {
@@ -11,6 +11,9 @@ pub struct MaskPosition {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Part of the face, relative to which the mask should be placed
   point: MaskPoint,
   /// Shift by X-axis measured in widths of the mask scaled to the face size, from left to right. (For example, 1.0 will place the mask just to the left of the default mask position)
@@ -24,6 +27,7 @@ pub struct MaskPosition {
 
 impl RObject for MaskPosition {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPosition" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDMaskPositionBuilder {
 impl RTDMaskPositionBuilder {
   pub fn build(&self) -> MaskPosition { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn point<T: AsRef<MaskPoint>>(&mut self, point: T) -> &mut Self {
     self.inner.point = point.as_ref().clone();
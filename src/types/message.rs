@@ -11,6 +11,9 @@ pub struct Message {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message identifier, unique for the chat to which the message belongs
   id: i64,
   /// Identifier of the user who sent the message; 0 if unknown. Currently, it is unknown for channel posts and for channel posts automatically forwarded to discussion group
@@ -54,7 +57,7 @@ pub struct Message {
   /// Number of times this message was viewed
   views: i64,
   /// Unique identifier of an album this message belongs to. Only photos and videos can be grouped together in albums
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] media_album_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] media_album_id: i64,
   /// If non-empty, contains a human-readable description of the reason why access to this message must be restricted
   restriction_reason: String,
   /// Content of the message
@@ -66,6 +69,7 @@ pub struct Message {
 
 impl RObject for Message {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "message" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,7 +125,7 @@ impl Message {
 
   pub fn views(&self) -> i64 { self.views }
 
-  pub fn media_album_id(&self) -> isize { self.media_album_id }
+  pub fn media_album_id(&self) -> i64 { self.media_album_id }
 
   pub fn restriction_reason(&self) -> &String { &self.restriction_reason }
 
@@ -139,6 +143,11 @@ pub struct RTDMessageBuilder {
 impl RTDMessageBuilder {
   pub fn build(&self) -> Message { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -266,7 +275,7 @@ impl RTDMessageBuilder {
   }
 
    
-  pub fn media_album_id(&mut self, media_album_id: isize) -> &mut Self {
+  pub fn media_album_id(&mut self, media_album_id: i64) -> &mut Self {
     self.inner.media_album_id = media_album_id;
     self
   }
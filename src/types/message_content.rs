@@ -18,6 +18,9 @@ pub trait TDMessageContent: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageContent {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation message (GIF-style).
   MessageAnimation(MessageAnimation),
   /// An audio message
@@ -206,12 +209,61 @@ impl RObject for MessageContent {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      MessageContent::MessageAnimation(t) => t.extra(),
+      MessageContent::MessageAudio(t) => t.extra(),
+      MessageContent::MessageBasicGroupChatCreate(t) => t.extra(),
+      MessageContent::MessageCall(t) => t.extra(),
+      MessageContent::MessageChatAddMembers(t) => t.extra(),
+      MessageContent::MessageChatChangePhoto(t) => t.extra(),
+      MessageContent::MessageChatChangeTitle(t) => t.extra(),
+      MessageContent::MessageChatDeleteMember(t) => t.extra(),
+      MessageContent::MessageChatDeletePhoto(t) => t.extra(),
+      MessageContent::MessageChatJoinByLink(t) => t.extra(),
+      MessageContent::MessageChatSetTtl(t) => t.extra(),
+      MessageContent::MessageChatUpgradeFrom(t) => t.extra(),
+      MessageContent::MessageChatUpgradeTo(t) => t.extra(),
+      MessageContent::MessageContact(t) => t.extra(),
+      MessageContent::MessageContactRegistered(t) => t.extra(),
+      MessageContent::MessageCustomServiceAction(t) => t.extra(),
+      MessageContent::MessageDice(t) => t.extra(),
+      MessageContent::MessageDocument(t) => t.extra(),
+      MessageContent::MessageExpiredPhoto(t) => t.extra(),
+      MessageContent::MessageExpiredVideo(t) => t.extra(),
+      MessageContent::MessageGame(t) => t.extra(),
+      MessageContent::MessageGameScore(t) => t.extra(),
+      MessageContent::MessageInvoice(t) => t.extra(),
+      MessageContent::MessageLocation(t) => t.extra(),
+      MessageContent::MessagePassportDataReceived(t) => t.extra(),
+      MessageContent::MessagePassportDataSent(t) => t.extra(),
+      MessageContent::MessagePaymentSuccessful(t) => t.extra(),
+      MessageContent::MessagePaymentSuccessfulBot(t) => t.extra(),
+      MessageContent::MessagePhoto(t) => t.extra(),
+      MessageContent::MessagePinMessage(t) => t.extra(),
+      MessageContent::MessagePoll(t) => t.extra(),
+      MessageContent::MessageScreenshotTaken(t) => t.extra(),
+      MessageContent::MessageSticker(t) => t.extra(),
+      MessageContent::MessageSupergroupChatCreate(t) => t.extra(),
+      MessageContent::MessageText(t) => t.extra(),
+      MessageContent::MessageUnsupported(t) => t.extra(),
+      MessageContent::MessageVenue(t) => t.extra(),
+      MessageContent::MessageVideo(t) => t.extra(),
+      MessageContent::MessageVideoNote(t) => t.extra(),
+      MessageContent::MessageVoiceNote(t) => t.extra(),
+      MessageContent::MessageWebsiteConnected(t) => t.extra(),
+
+      MessageContent::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl MessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageContent::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let MessageContent::Unknown { .. } = self { true } else { false } }
 
   pub fn is_message_animation(&self) -> bool { if let MessageContent::MessageAnimation(_) = self { true } else { false } }
   pub fn is_message_audio(&self) -> bool { if let MessageContent::MessageAudio(_) = self { true } else { false } }
@@ -441,6 +493,9 @@ pub struct MessageAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The animation description
   animation: Animation,
   /// Animation caption
@@ -452,6 +507,7 @@ pub struct MessageAnimation {
 
 impl RObject for MessageAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -484,6 +540,11 @@ pub struct RTDMessageAnimationBuilder {
 impl RTDMessageAnimationBuilder {
   pub fn build(&self) -> MessageAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -524,6 +585,9 @@ pub struct MessageAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The audio description
   audio: Audio,
   /// Audio caption
@@ -533,6 +597,7 @@ pub struct MessageAudio {
 
 impl RObject for MessageAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -563,6 +628,11 @@ pub struct RTDMessageAudioBuilder {
 impl RTDMessageAudioBuilder {
   pub fn build(&self) -> MessageAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
@@ -597,6 +667,9 @@ pub struct MessageBasicGroupChatCreate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Title of the basic group
   title: String,
   /// User identifiers of members in the basic group
@@ -606,6 +679,7 @@ pub struct MessageBasicGroupChatCreate {
 
 impl RObject for MessageBasicGroupChatCreate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageBasicGroupChatCreate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -636,6 +710,11 @@ pub struct RTDMessageBasicGroupChatCreateBuilder {
 impl RTDMessageBasicGroupChatCreateBuilder {
   pub fn build(&self) -> MessageBasicGroupChatCreate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -670,6 +749,9 @@ pub struct MessageCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the call was a video call
   is_video: bool,
   /// Reason why the call was discarded
@@ -681,6 +763,7 @@ pub struct MessageCall {
 
 impl RObject for MessageCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -713,6 +796,11 @@ pub struct RTDMessageCallBuilder {
 impl RTDMessageCallBuilder {
   pub fn build(&self) -> MessageCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_video(&mut self, is_video: bool) -> &mut Self {
     self.inner.is_video = is_video;
@@ -753,6 +841,9 @@ pub struct MessageChatAddMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifiers of the new members
   member_user_ids: Vec<i64>,
   
@@ -760,6 +851,7 @@ pub struct MessageChatAddMembers {
 
 impl RObject for MessageChatAddMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatAddMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -788,6 +880,11 @@ pub struct RTDMessageChatAddMembersBuilder {
 impl RTDMessageChatAddMembersBuilder {
   pub fn build(&self) -> MessageChatAddMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
     self.inner.member_user_ids = member_user_ids;
@@ -816,6 +913,9 @@ pub struct MessageChatChangePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New chat photo
   photo: ChatPhoto,
   
@@ -823,6 +923,7 @@ pub struct MessageChatChangePhoto {
 
 impl RObject for MessageChatChangePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatChangePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -851,6 +952,11 @@ pub struct RTDMessageChatChangePhotoBuilder {
 impl RTDMessageChatChangePhotoBuilder {
   pub fn build(&self) -> MessageChatChangePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -879,6 +985,9 @@ pub struct MessageChatChangeTitle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New chat title
   title: String,
   
@@ -886,6 +995,7 @@ pub struct MessageChatChangeTitle {
 
 impl RObject for MessageChatChangeTitle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatChangeTitle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -914,6 +1024,11 @@ pub struct RTDMessageChatChangeTitleBuilder {
 impl RTDMessageChatChangeTitleBuilder {
   pub fn build(&self) -> MessageChatChangeTitle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -942,6 +1057,9 @@ pub struct MessageChatDeleteMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier of the deleted chat member
   user_id: i64,
   
@@ -949,6 +1067,7 @@ pub struct MessageChatDeleteMember {
 
 impl RObject for MessageChatDeleteMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatDeleteMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -977,6 +1096,11 @@ pub struct RTDMessageChatDeleteMemberBuilder {
 impl RTDMessageChatDeleteMemberBuilder {
   pub fn build(&self) -> MessageChatDeleteMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -1005,11 +1129,15 @@ pub struct MessageChatDeletePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageChatDeletePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatDeletePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1036,6 +1164,11 @@ pub struct RTDMessageChatDeletePhotoBuilder {
 impl RTDMessageChatDeletePhotoBuilder {
   pub fn build(&self) -> MessageChatDeletePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageChatDeletePhoto> for MessageChatDeletePhoto {
@@ -1058,11 +1191,15 @@ pub struct MessageChatJoinByLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageChatJoinByLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatJoinByLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1089,6 +1226,11 @@ pub struct RTDMessageChatJoinByLinkBuilder {
 impl RTDMessageChatJoinByLinkBuilder {
   pub fn build(&self) -> MessageChatJoinByLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageChatJoinByLink> for MessageChatJoinByLink {
@@ -1111,6 +1253,9 @@ pub struct MessageChatSetTtl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New TTL
   ttl: i64,
   
@@ -1118,6 +1263,7 @@ pub struct MessageChatSetTtl {
 
 impl RObject for MessageChatSetTtl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatSetTtl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1146,6 +1292,11 @@ pub struct RTDMessageChatSetTtlBuilder {
 impl RTDMessageChatSetTtlBuilder {
   pub fn build(&self) -> MessageChatSetTtl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
@@ -1174,6 +1325,9 @@ pub struct MessageChatUpgradeFrom {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Title of the newly created supergroup
   title: String,
   /// The identifier of the original basic group
@@ -1183,6 +1337,7 @@ pub struct MessageChatUpgradeFrom {
 
 impl RObject for MessageChatUpgradeFrom {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatUpgradeFrom" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1213,6 +1368,11 @@ pub struct RTDMessageChatUpgradeFromBuilder {
 impl RTDMessageChatUpgradeFromBuilder {
   pub fn build(&self) -> MessageChatUpgradeFrom { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1247,6 +1407,9 @@ pub struct MessageChatUpgradeTo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup to which the basic group was upgraded
   supergroup_id: i64,
   
@@ -1254,6 +1417,7 @@ pub struct MessageChatUpgradeTo {
 
 impl RObject for MessageChatUpgradeTo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatUpgradeTo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1282,6 +1446,11 @@ pub struct RTDMessageChatUpgradeToBuilder {
 impl RTDMessageChatUpgradeToBuilder {
   pub fn build(&self) -> MessageChatUpgradeTo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -1310,6 +1479,9 @@ pub struct MessageContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The contact description
   contact: Contact,
   
@@ -1317,6 +1489,7 @@ pub struct MessageContact {
 
 impl RObject for MessageContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1345,6 +1518,11 @@ pub struct RTDMessageContactBuilder {
 impl RTDMessageContactBuilder {
   pub fn build(&self) -> MessageContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
@@ -1373,11 +1551,15 @@ pub struct MessageContactRegistered {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageContactRegistered {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageContactRegistered" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1404,6 +1586,11 @@ pub struct RTDMessageContactRegisteredBuilder {
 impl RTDMessageContactRegisteredBuilder {
   pub fn build(&self) -> MessageContactRegistered { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageContactRegistered> for MessageContactRegistered {
@@ -1426,6 +1613,9 @@ pub struct MessageCustomServiceAction {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message text to be shown in the chat
   text: String,
   
@@ -1433,6 +1623,7 @@ pub struct MessageCustomServiceAction {
 
 impl RObject for MessageCustomServiceAction {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCustomServiceAction" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1461,6 +1652,11 @@ pub struct RTDMessageCustomServiceActionBuilder {
 impl RTDMessageCustomServiceActionBuilder {
   pub fn build(&self) -> MessageCustomServiceAction { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -1489,6 +1685,9 @@ pub struct MessageDice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The animated sticker with the initial dice animation; may be null if unknown. updateMessageContent will be sent when the sticker became known
   initial_state_sticker: Option<Sticker>,
   /// The animated sticker with the final dice animation; may be null if unknown. updateMessageContent will be sent when the sticker became known
@@ -1504,6 +1703,7 @@ pub struct MessageDice {
 
 impl RObject for MessageDice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageDice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1540,6 +1740,11 @@ pub struct RTDMessageDiceBuilder {
 impl RTDMessageDiceBuilder {
   pub fn build(&self) -> MessageDice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn initial_state_sticker<T: AsRef<Sticker>>(&mut self, initial_state_sticker: T) -> &mut Self {
     self.inner.initial_state_sticker = Some(initial_state_sticker.as_ref().clone());
@@ -1592,6 +1797,9 @@ pub struct MessageDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The document description
   document: Document,
   /// Document caption
@@ -1601,6 +1809,7 @@ pub struct MessageDocument {
 
 impl RObject for MessageDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1631,6 +1840,11 @@ pub struct RTDMessageDocumentBuilder {
 impl RTDMessageDocumentBuilder {
   pub fn build(&self) -> MessageDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -1665,11 +1879,15 @@ pub struct MessageExpiredPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageExpiredPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageExpiredPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1696,6 +1914,11 @@ pub struct RTDMessageExpiredPhotoBuilder {
 impl RTDMessageExpiredPhotoBuilder {
   pub fn build(&self) -> MessageExpiredPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageExpiredPhoto> for MessageExpiredPhoto {
@@ -1718,11 +1941,15 @@ pub struct MessageExpiredVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageExpiredVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageExpiredVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1749,6 +1976,11 @@ pub struct RTDMessageExpiredVideoBuilder {
 impl RTDMessageExpiredVideoBuilder {
   pub fn build(&self) -> MessageExpiredVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageExpiredVideo> for MessageExpiredVideo {
@@ -1771,6 +2003,9 @@ pub struct MessageGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The game description
   game: Game,
   
@@ -1778,6 +2013,7 @@ pub struct MessageGame {
 
 impl RObject for MessageGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1806,6 +2042,11 @@ pub struct RTDMessageGameBuilder {
 impl RTDMessageGameBuilder {
   pub fn build(&self) -> MessageGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn game<T: AsRef<Game>>(&mut self, game: T) -> &mut Self {
     self.inner.game = game.as_ref().clone();
@@ -1834,10 +2075,13 @@ pub struct MessageGameScore {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the message with the game, can be an identifier of a deleted message
   game_message_id: i64,
   /// Identifier of the game; may be different from the games presented in the message with the game
-  game_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] game_id: i64,
   /// New score
   score: i64,
   
@@ -1845,6 +2089,7 @@ pub struct MessageGameScore {
 
 impl RObject for MessageGameScore {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageGameScore" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1863,7 +2108,7 @@ impl MessageGameScore {
 
   pub fn game_message_id(&self) -> i64 { self.game_message_id }
 
-  pub fn game_id(&self) -> isize { self.game_id }
+  pub fn game_id(&self) -> i64 { self.game_id }
 
   pub fn score(&self) -> i64 { self.score }
 
@@ -1877,6 +2122,11 @@ pub struct RTDMessageGameScoreBuilder {
 impl RTDMessageGameScoreBuilder {
   pub fn build(&self) -> MessageGameScore { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn game_message_id(&mut self, game_message_id: i64) -> &mut Self {
     self.inner.game_message_id = game_message_id;
@@ -1884,7 +2134,7 @@ impl RTDMessageGameScoreBuilder {
   }
 
    
-  pub fn game_id(&mut self, game_id: isize) -> &mut Self {
+  pub fn game_id(&mut self, game_id: i64) -> &mut Self {
     self.inner.game_id = game_id;
     self
   }
@@ -1917,6 +2167,9 @@ pub struct MessageInvoice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Product title
   title: String,
   /// A message with an invoice from a bot
@@ -1940,6 +2193,7 @@ pub struct MessageInvoice {
 
 impl RObject for MessageInvoice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageInvoice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1984,6 +2238,11 @@ pub struct RTDMessageInvoiceBuilder {
 impl RTDMessageInvoiceBuilder {
   pub fn build(&self) -> MessageInvoice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -2060,6 +2319,9 @@ pub struct MessageLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The location description
   location: Location,
   /// Time relative to the message sent date until which the location can be updated, in seconds
@@ -2071,6 +2333,7 @@ pub struct MessageLocation {
 
 impl RObject for MessageLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2103,6 +2366,11 @@ pub struct RTDMessageLocationBuilder {
 impl RTDMessageLocationBuilder {
   pub fn build(&self) -> MessageLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -2143,6 +2411,9 @@ pub struct MessagePassportDataReceived {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of received Telegram Passport elements
   elements: Vec<EncryptedPassportElement>,
   /// Encrypted data credentials
@@ -2152,6 +2423,7 @@ pub struct MessagePassportDataReceived {
 
 impl RObject for MessagePassportDataReceived {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePassportDataReceived" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2182,6 +2454,11 @@ pub struct RTDMessagePassportDataReceivedBuilder {
 impl RTDMessagePassportDataReceivedBuilder {
   pub fn build(&self) -> MessagePassportDataReceived { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn elements(&mut self, elements: Vec<EncryptedPassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -2216,6 +2493,9 @@ pub struct MessagePassportDataSent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of Telegram Passport element types sent
   types: Vec<PassportElementType>,
   
@@ -2223,6 +2503,7 @@ pub struct MessagePassportDataSent {
 
 impl RObject for MessagePassportDataSent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePassportDataSent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2251,6 +2532,11 @@ pub struct RTDMessagePassportDataSentBuilder {
 impl RTDMessagePassportDataSentBuilder {
   pub fn build(&self) -> MessagePassportDataSent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn types(&mut self, types: Vec<PassportElementType>) -> &mut Self {
     self.inner.types = types;
@@ -2279,6 +2565,9 @@ pub struct MessagePaymentSuccessful {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the message with the corresponding invoice; can be an identifier of a deleted message
   invoice_message_id: i64,
   /// Currency for the price of the product
@@ -2290,6 +2579,7 @@ pub struct MessagePaymentSuccessful {
 
 impl RObject for MessagePaymentSuccessful {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePaymentSuccessful" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2322,6 +2612,11 @@ pub struct RTDMessagePaymentSuccessfulBuilder {
 impl RTDMessagePaymentSuccessfulBuilder {
   pub fn build(&self) -> MessagePaymentSuccessful { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
     self.inner.invoice_message_id = invoice_message_id;
@@ -2362,6 +2657,9 @@ pub struct MessagePaymentSuccessfulBot {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the message with the corresponding invoice; can be an identifier of a deleted message
   invoice_message_id: i64,
   /// Currency for price of the product
@@ -2369,7 +2667,7 @@ pub struct MessagePaymentSuccessfulBot {
   /// Total price for the product, in the minimal quantity of the currency
   total_amount: i64,
   /// Invoice payload
-  invoice_payload: String,
+  invoice_payload: TdBytes,
   /// Identifier of the shipping option chosen by the user; may be empty if not applicable
   shipping_option_id: String,
   /// Information about the order; may be null
@@ -2383,6 +2681,7 @@ pub struct MessagePaymentSuccessfulBot {
 
 impl RObject for MessagePaymentSuccessfulBot {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePaymentSuccessfulBot" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2405,7 +2704,7 @@ impl MessagePaymentSuccessfulBot {
 
   pub fn total_amount(&self) -> i64 { self.total_amount }
 
-  pub fn invoice_payload(&self) -> &String { &self.invoice_payload }
+  pub fn invoice_payload(&self) -> &TdBytes { &self.invoice_payload }
 
   pub fn shipping_option_id(&self) -> &String { &self.shipping_option_id }
 
@@ -2425,6 +2724,11 @@ pub struct RTDMessagePaymentSuccessfulBotBuilder {
 impl RTDMessagePaymentSuccessfulBotBuilder {
   pub fn build(&self) -> MessagePaymentSuccessfulBot { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
     self.inner.invoice_message_id = invoice_message_id;
@@ -2444,8 +2748,8 @@ impl RTDMessagePaymentSuccessfulBotBuilder {
   }
 
    
-  pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-    self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+  pub fn invoice_payload<T: AsRef<[u8]>>(&mut self, invoice_payload: T) -> &mut Self {
+    self.inner.invoice_payload = TdBytes::from(invoice_payload.as_ref());
     self
   }
 
@@ -2495,6 +2799,9 @@ pub struct MessagePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The photo description
   photo: Photo,
   /// Photo caption
@@ -2506,6 +2813,7 @@ pub struct MessagePhoto {
 
 impl RObject for MessagePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2538,6 +2846,11 @@ pub struct RTDMessagePhotoBuilder {
 impl RTDMessagePhotoBuilder {
   pub fn build(&self) -> MessagePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -2578,6 +2891,9 @@ pub struct MessagePinMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the pinned message, can be an identifier of a deleted message or 0
   message_id: i64,
   
@@ -2585,6 +2901,7 @@ pub struct MessagePinMessage {
 
 impl RObject for MessagePinMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePinMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2613,6 +2930,11 @@ pub struct RTDMessagePinMessageBuilder {
 impl RTDMessagePinMessageBuilder {
   pub fn build(&self) -> MessagePinMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
@@ -2641,6 +2963,9 @@ pub struct MessagePoll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The poll description
   poll: Poll,
   
@@ -2648,6 +2973,7 @@ pub struct MessagePoll {
 
 impl RObject for MessagePoll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePoll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2676,6 +3002,11 @@ pub struct RTDMessagePollBuilder {
 impl RTDMessagePollBuilder {
   pub fn build(&self) -> MessagePoll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
     self.inner.poll = poll.as_ref().clone();
@@ -2704,11 +3035,15 @@ pub struct MessageScreenshotTaken {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageScreenshotTaken {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageScreenshotTaken" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2735,6 +3070,11 @@ pub struct RTDMessageScreenshotTakenBuilder {
 impl RTDMessageScreenshotTakenBuilder {
   pub fn build(&self) -> MessageScreenshotTaken { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageScreenshotTaken> for MessageScreenshotTaken {
@@ -2757,6 +3097,9 @@ pub struct MessageSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The sticker description
   sticker: Sticker,
   
@@ -2764,6 +3107,7 @@ pub struct MessageSticker {
 
 impl RObject for MessageSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2792,6 +3136,11 @@ pub struct RTDMessageStickerBuilder {
 impl RTDMessageStickerBuilder {
   pub fn build(&self) -> MessageSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -2820,6 +3169,9 @@ pub struct MessageSupergroupChatCreate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Title of the supergroup or channel
   title: String,
   
@@ -2827,6 +3179,7 @@ pub struct MessageSupergroupChatCreate {
 
 impl RObject for MessageSupergroupChatCreate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSupergroupChatCreate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2855,6 +3208,11 @@ pub struct RTDMessageSupergroupChatCreateBuilder {
 impl RTDMessageSupergroupChatCreateBuilder {
   pub fn build(&self) -> MessageSupergroupChatCreate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -2883,6 +3241,9 @@ pub struct MessageText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text of the message
   text: FormattedText,
   /// A preview of the web page that's mentioned in the text; may be null
@@ -2892,6 +3253,7 @@ pub struct MessageText {
 
 impl RObject for MessageText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2922,6 +3284,11 @@ pub struct RTDMessageTextBuilder {
 impl RTDMessageTextBuilder {
   pub fn build(&self) -> MessageText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -2956,11 +3323,15 @@ pub struct MessageUnsupported {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageUnsupported {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageUnsupported" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2987,6 +3358,11 @@ pub struct RTDMessageUnsupportedBuilder {
 impl RTDMessageUnsupportedBuilder {
   pub fn build(&self) -> MessageUnsupported { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageUnsupported> for MessageUnsupported {
@@ -3009,6 +3385,9 @@ pub struct MessageVenue {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The venue description
   venue: Venue,
   
@@ -3016,6 +3395,7 @@ pub struct MessageVenue {
 
 impl RObject for MessageVenue {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVenue" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3044,6 +3424,11 @@ pub struct RTDMessageVenueBuilder {
 impl RTDMessageVenueBuilder {
   pub fn build(&self) -> MessageVenue { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
@@ -3072,6 +3457,9 @@ pub struct MessageVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The video description
   video: Video,
   /// Video caption
@@ -3083,6 +3471,7 @@ pub struct MessageVideo {
 
 impl RObject for MessageVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3115,6 +3504,11 @@ pub struct RTDMessageVideoBuilder {
 impl RTDMessageVideoBuilder {
   pub fn build(&self) -> MessageVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
@@ -3155,6 +3549,9 @@ pub struct MessageVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The video note description
   video_note: VideoNote,
   /// True, if at least one of the recipients has viewed the video note
@@ -3166,6 +3563,7 @@ pub struct MessageVideoNote {
 
 impl RObject for MessageVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3198,6 +3596,11 @@ pub struct RTDMessageVideoNoteBuilder {
 impl RTDMessageVideoNoteBuilder {
   pub fn build(&self) -> MessageVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = video_note.as_ref().clone();
@@ -3238,6 +3641,9 @@ pub struct MessageVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The voice note description
   voice_note: VoiceNote,
   /// Voice note caption
@@ -3249,6 +3655,7 @@ pub struct MessageVoiceNote {
 
 impl RObject for MessageVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3281,6 +3688,11 @@ pub struct RTDMessageVoiceNoteBuilder {
 impl RTDMessageVoiceNoteBuilder {
   pub fn build(&self) -> MessageVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
@@ -3321,6 +3733,9 @@ pub struct MessageWebsiteConnected {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Domain name of the connected website
   domain_name: String,
   
@@ -3328,6 +3743,7 @@ pub struct MessageWebsiteConnected {
 
 impl RObject for MessageWebsiteConnected {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageWebsiteConnected" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3356,6 +3772,11 @@ pub struct RTDMessageWebsiteConnectedBuilder {
 impl RTDMessageWebsiteConnectedBuilder {
   pub fn build(&self) -> MessageWebsiteConnected { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn domain_name<T: AsRef<str>>(&mut self, domain_name: T) -> &mut Self {
     self.inner.domain_name = domain_name.as_ref().to_string();
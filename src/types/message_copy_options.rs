@@ -11,6 +11,9 @@ pub struct MessageCopyOptions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if content of the message needs to be copied without a link to the original message. Always true if the message is forwarded to a secret chat
   send_copy: bool,
   /// True, if media caption of the message copy needs to be replaced. Ignored if send_copy is false
@@ -22,6 +25,7 @@ pub struct MessageCopyOptions {
 
 impl RObject for MessageCopyOptions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCopyOptions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDMessageCopyOptionsBuilder {
 impl RTDMessageCopyOptionsBuilder {
   pub fn build(&self) -> MessageCopyOptions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn send_copy(&mut self, send_copy: bool) -> &mut Self {
     self.inner.send_copy = send_copy;
@@ -11,6 +11,9 @@ pub struct MessageForwardInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Origin of a forwarded message
   origin: MessageForwardOrigin,
   /// Point in time (Unix timestamp) when the message was originally sent
@@ -26,6 +29,7 @@ pub struct MessageForwardInfo {
 
 impl RObject for MessageForwardInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDMessageForwardInfoBuilder {
 impl RTDMessageForwardInfoBuilder {
   pub fn build(&self) -> MessageForwardInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn origin<T: AsRef<MessageForwardOrigin>>(&mut self, origin: T) -> &mut Self {
     self.inner.origin = origin.as_ref().clone();
@@ -18,6 +18,9 @@ pub trait TDMessageForwardOrigin: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageForwardOrigin {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message was originally a post in a channel
   Channel(MessageForwardOriginChannel),
   /// The message was originally written by a user, which is hidden by their privacy settings
@@ -54,12 +57,23 @@ impl RObject for MessageForwardOrigin {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      MessageForwardOrigin::Channel(t) => t.extra(),
+      MessageForwardOrigin::HiddenUser(t) => t.extra(),
+      MessageForwardOrigin::User(t) => t.extra(),
+
+      MessageForwardOrigin::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl MessageForwardOrigin {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageForwardOrigin::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let MessageForwardOrigin::Unknown { .. } = self { true } else { false } }
 
   pub fn is_channel(&self) -> bool { if let MessageForwardOrigin::Channel(_) = self { true } else { false } }
   pub fn is_hidden_user(&self) -> bool { if let MessageForwardOrigin::HiddenUser(_) = self { true } else { false } }
@@ -99,6 +113,9 @@ pub struct MessageForwardOriginChannel {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat from which the message was originally forwarded
   chat_id: i64,
   /// Message identifier of the original message; 0 if unknown
@@ -110,6 +127,7 @@ pub struct MessageForwardOriginChannel {
 
 impl RObject for MessageForwardOriginChannel {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginChannel" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -142,6 +160,11 @@ pub struct RTDMessageForwardOriginChannelBuilder {
 impl RTDMessageForwardOriginChannelBuilder {
   pub fn build(&self) -> MessageForwardOriginChannel { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -182,6 +205,9 @@ pub struct MessageForwardOriginHiddenUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Name of the sender
   sender_name: String,
   
@@ -189,6 +215,7 @@ pub struct MessageForwardOriginHiddenUser {
 
 impl RObject for MessageForwardOriginHiddenUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginHiddenUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -217,6 +244,11 @@ pub struct RTDMessageForwardOriginHiddenUserBuilder {
 impl RTDMessageForwardOriginHiddenUserBuilder {
   pub fn build(&self) -> MessageForwardOriginHiddenUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
     self.inner.sender_name = sender_name.as_ref().to_string();
@@ -245,6 +277,9 @@ pub struct MessageForwardOriginUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the user that originally sent the message
   sender_user_id: i64,
   
@@ -252,6 +287,7 @@ pub struct MessageForwardOriginUser {
 
 impl RObject for MessageForwardOriginUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -280,6 +316,11 @@ pub struct RTDMessageForwardOriginUserBuilder {
 impl RTDMessageForwardOriginUserBuilder {
   pub fn build(&self) -> MessageForwardOriginUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
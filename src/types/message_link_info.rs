@@ -11,6 +11,9 @@ pub struct MessageLinkInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the link is a public link for a message in a chat
   is_public: bool,
   /// If found, identifier of the chat to which the message belongs, 0 otherwise
@@ -24,6 +27,7 @@ pub struct MessageLinkInfo {
 
 impl RObject for MessageLinkInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageLinkInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDMessageLinkInfoBuilder {
 impl RTDMessageLinkInfoBuilder {
   pub fn build(&self) -> MessageLinkInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_public(&mut self, is_public: bool) -> &mut Self {
     self.inner.is_public = is_public;
@@ -18,6 +18,9 @@ pub trait TDMessageSchedulingState: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageSchedulingState {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message will be sent at the specified date
   SendAtDate(MessageSchedulingStateSendAtDate),
   /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
@@ -50,12 +53,22 @@ impl RObject for MessageSchedulingState {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      MessageSchedulingState::SendAtDate(t) => t.extra(),
+      MessageSchedulingState::SendWhenOnline(t) => t.extra(),
+
+      MessageSchedulingState::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl MessageSchedulingState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSchedulingState::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let MessageSchedulingState::Unknown { .. } = self { true } else { false } }
 
   pub fn is_send_at_date(&self) -> bool { if let MessageSchedulingState::SendAtDate(_) = self { true } else { false } }
   pub fn is_send_when_online(&self) -> bool { if let MessageSchedulingState::SendWhenOnline(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct MessageSchedulingStateSendAtDate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Date the message will be sent. The date must be within 367 days in the future
   send_date: i64,
   
@@ -97,6 +113,7 @@ pub struct MessageSchedulingStateSendAtDate {
 
 impl RObject for MessageSchedulingStateSendAtDate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSchedulingStateSendAtDate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -125,6 +142,11 @@ pub struct RTDMessageSchedulingStateSendAtDateBuilder {
 impl RTDMessageSchedulingStateSendAtDateBuilder {
   pub fn build(&self) -> MessageSchedulingStateSendAtDate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn send_date(&mut self, send_date: i64) -> &mut Self {
     self.inner.send_date = send_date;
@@ -153,11 +175,15 @@ pub struct MessageSchedulingStateSendWhenOnline {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageSchedulingStateSendWhenOnline {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSchedulingStateSendWhenOnline" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -184,6 +210,11 @@ pub struct RTDMessageSchedulingStateSendWhenOnlineBuilder {
 impl RTDMessageSchedulingStateSendWhenOnlineBuilder {
   pub fn build(&self) -> MessageSchedulingStateSendWhenOnline { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageSchedulingStateSendWhenOnline> for MessageSchedulingStateSendWhenOnline {
@@ -11,6 +11,9 @@ pub struct MessageSendOptions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true to disable notification for the message. Must be false if the message is sent to a secret chat
   disable_notification: bool,
   /// Pass true if the message is sent from the background
@@ -22,6 +25,7 @@ pub struct MessageSendOptions {
 
 impl RObject for MessageSendOptions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSendOptions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDMessageSendOptionsBuilder {
 impl RTDMessageSendOptionsBuilder {
   pub fn build(&self) -> MessageSendOptions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn disable_notification(&mut self, disable_notification: bool) -> &mut Self {
     self.inner.disable_notification = disable_notification;
@@ -18,6 +18,9 @@ pub trait TDMessageSendingState: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageSendingState {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message failed to be sent
   Failed(MessageSendingStateFailed),
   /// The message is being sent now, but has not yet been delivered to the server
@@ -50,12 +53,22 @@ impl RObject for MessageSendingState {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      MessageSendingState::Failed(t) => t.extra(),
+      MessageSendingState::Pending(t) => t.extra(),
+
+      MessageSendingState::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl MessageSendingState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSendingState::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let MessageSendingState::Unknown { .. } = self { true } else { false } }
 
   pub fn is_failed(&self) -> bool { if let MessageSendingState::Failed(_) = self { true } else { false } }
   pub fn is_pending(&self) -> bool { if let MessageSendingState::Pending(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct MessageSendingStateFailed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// An error code; 0 if unknown
   error_code: i64,
   /// Error message
@@ -103,6 +119,7 @@ pub struct MessageSendingStateFailed {
 
 impl RObject for MessageSendingStateFailed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSendingStateFailed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,6 +154,11 @@ pub struct RTDMessageSendingStateFailedBuilder {
 impl RTDMessageSendingStateFailedBuilder {
   pub fn build(&self) -> MessageSendingStateFailed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error_code(&mut self, error_code: i64) -> &mut Self {
     self.inner.error_code = error_code;
@@ -183,11 +205,15 @@ pub struct MessageSendingStatePending {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for MessageSendingStatePending {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSendingStatePending" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -214,6 +240,11 @@ pub struct RTDMessageSendingStatePendingBuilder {
 impl RTDMessageSendingStatePendingBuilder {
   pub fn build(&self) -> MessageSendingStatePending { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageSendingStatePending> for MessageSendingStatePending {
@@ -11,17 +11,21 @@ pub struct Minithumbnail {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Thumbnail width, usually doesn't exceed 40
   width: i64,
   /// Thumbnail height, usually doesn't exceed 40
   height: i64,
   /// The thumbnail in JPEG format
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for Minithumbnail {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "minithumbnail" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -39,7 +43,7 @@ impl Minithumbnail {
 
   pub fn height(&self) -> i64 { self.height }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -51,6 +55,11 @@ pub struct RTDMinithumbnailBuilder {
 impl RTDMinithumbnailBuilder {
   pub fn build(&self) -> Minithumbnail { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
@@ -64,8 +73,8 @@ impl RTDMinithumbnailBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -2,9 +2,11 @@
 pub use self::_common::{
   RObject,
   RFunction,
-  detect_td_type,
   from_json,
+  TdBytes,
 };
+#[allow(deprecated)]
+pub use self::_common::detect_td_type;
 
 #[macro_use] mod _common;
 
@@ -238,6 +240,7 @@ pub use self::supergroup_members_filter::*;
 pub use self::t_me_url::*;
 pub use self::t_me_url_type::*;
 pub use self::t_me_urls::*;
+pub use self::td_object::*;
 pub use self::tdlib_parameters::*;
 pub use self::temporary_password_state::*;
 pub use self::terms_of_service::*;
@@ -507,6 +510,7 @@ mod supergroup_members_filter;
 mod t_me_url;
 mod t_me_url_type;
 mod t_me_urls;
+mod td_object;
 mod tdlib_parameters;
 mod temporary_password_state;
 mod terms_of_service;
@@ -11,6 +11,9 @@ pub struct NetworkStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Point in time (Unix timestamp) when the app began collecting statistics
   since_date: i64,
   /// Network statistics entries
@@ -20,6 +23,7 @@ pub struct NetworkStatistics {
 
 impl RObject for NetworkStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDNetworkStatisticsBuilder {
 impl RTDNetworkStatisticsBuilder {
   pub fn build(&self) -> NetworkStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn since_date(&mut self, since_date: i64) -> &mut Self {
     self.inner.since_date = since_date;
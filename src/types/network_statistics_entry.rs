@@ -18,6 +18,9 @@ pub trait TDNetworkStatisticsEntry: Debug + RObject {}
 #[serde(untagged)]
 pub enum NetworkStatisticsEntry {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Contains information about the total amount of data that was used for calls
   Call(NetworkStatisticsEntryCall),
   /// Contains information about the total amount of data that was used to send and receive files
@@ -50,12 +53,22 @@ impl RObject for NetworkStatisticsEntry {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      NetworkStatisticsEntry::Call(t) => t.extra(),
+      NetworkStatisticsEntry::File(t) => t.extra(),
+
+      NetworkStatisticsEntry::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl NetworkStatisticsEntry {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NetworkStatisticsEntry::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let NetworkStatisticsEntry::Unknown { .. } = self { true } else { false } }
 
   pub fn is_call(&self) -> bool { if let NetworkStatisticsEntry::Call(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let NetworkStatisticsEntry::File(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct NetworkStatisticsEntryCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of the network the data was sent through. Call setNetworkType to maintain the actual network type
   network_type: NetworkType,
   /// Total number of bytes sent
@@ -103,6 +119,7 @@ pub struct NetworkStatisticsEntryCall {
 
 impl RObject for NetworkStatisticsEntryCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkStatisticsEntryCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,6 +154,11 @@ pub struct RTDNetworkStatisticsEntryCallBuilder {
 impl RTDNetworkStatisticsEntryCallBuilder {
   pub fn build(&self) -> NetworkStatisticsEntryCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn network_type<T: AsRef<NetworkType>>(&mut self, network_type: T) -> &mut Self {
     self.inner.network_type = network_type.as_ref().clone();
@@ -183,6 +205,9 @@ pub struct NetworkStatisticsEntryFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of the file the data is part of
   file_type: FileType,
   /// Type of the network the data was sent through. Call setNetworkType to maintain the actual network type
@@ -196,6 +221,7 @@ pub struct NetworkStatisticsEntryFile {
 
 impl RObject for NetworkStatisticsEntryFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkStatisticsEntryFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -230,6 +256,11 @@ pub struct RTDNetworkStatisticsEntryFileBuilder {
 impl RTDNetworkStatisticsEntryFileBuilder {
   pub fn build(&self) -> NetworkStatisticsEntryFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
     self.inner.file_type = file_type.as_ref().clone();
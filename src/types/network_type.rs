@@ -18,6 +18,9 @@ pub trait TDNetworkType: Debug + RObject {}
 #[serde(untagged)]
 pub enum NetworkType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A mobile network
   Mobile(NetworkTypeMobile),
   /// A mobile roaming network
@@ -62,12 +65,25 @@ impl RObject for NetworkType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      NetworkType::Mobile(t) => t.extra(),
+      NetworkType::MobileRoaming(t) => t.extra(),
+      NetworkType::None(t) => t.extra(),
+      NetworkType::Other(t) => t.extra(),
+      NetworkType::WiFi(t) => t.extra(),
+
+      NetworkType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl NetworkType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NetworkType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let NetworkType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_mobile(&self) -> bool { if let NetworkType::Mobile(_) = self { true } else { false } }
   pub fn is_mobile_roaming(&self) -> bool { if let NetworkType::MobileRoaming(_) = self { true } else { false } }
@@ -117,11 +133,15 @@ pub struct NetworkTypeMobile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NetworkTypeMobile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeMobile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -148,6 +168,11 @@ pub struct RTDNetworkTypeMobileBuilder {
 impl RTDNetworkTypeMobileBuilder {
   pub fn build(&self) -> NetworkTypeMobile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeMobile> for NetworkTypeMobile {
@@ -170,11 +195,15 @@ pub struct NetworkTypeMobileRoaming {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NetworkTypeMobileRoaming {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeMobileRoaming" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -201,6 +230,11 @@ pub struct RTDNetworkTypeMobileRoamingBuilder {
 impl RTDNetworkTypeMobileRoamingBuilder {
   pub fn build(&self) -> NetworkTypeMobileRoaming { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeMobileRoaming> for NetworkTypeMobileRoaming {
@@ -223,11 +257,15 @@ pub struct NetworkTypeNone {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NetworkTypeNone {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeNone" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -254,6 +292,11 @@ pub struct RTDNetworkTypeNoneBuilder {
 impl RTDNetworkTypeNoneBuilder {
   pub fn build(&self) -> NetworkTypeNone { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeNone> for NetworkTypeNone {
@@ -276,11 +319,15 @@ pub struct NetworkTypeOther {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NetworkTypeOther {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeOther" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -307,6 +354,11 @@ pub struct RTDNetworkTypeOtherBuilder {
 impl RTDNetworkTypeOtherBuilder {
   pub fn build(&self) -> NetworkTypeOther { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeOther> for NetworkTypeOther {
@@ -329,11 +381,15 @@ pub struct NetworkTypeWiFi {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NetworkTypeWiFi {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeWiFi" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -360,6 +416,11 @@ pub struct RTDNetworkTypeWiFiBuilder {
 impl RTDNetworkTypeWiFiBuilder {
   pub fn build(&self) -> NetworkTypeWiFi { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeWiFi> for NetworkTypeWiFi {
@@ -11,6 +11,9 @@ pub struct Notification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique persistent identifier of this notification
   id: i64,
   /// Notification date
@@ -24,6 +27,7 @@ pub struct Notification {
 
 impl RObject for Notification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDNotificationBuilder {
 impl RTDNotificationBuilder {
   pub fn build(&self) -> Notification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
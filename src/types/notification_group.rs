@@ -11,6 +11,9 @@ pub struct NotificationGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique persistent auto-incremented from 1 identifier of the notification group
   id: i64,
   /// Type of the group
@@ -26,6 +29,7 @@ pub struct NotificationGroup {
 
 impl RObject for NotificationGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDNotificationGroupBuilder {
 impl RTDNotificationGroupBuilder {
   pub fn build(&self) -> NotificationGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -18,6 +18,9 @@ pub trait TDNotificationGroupType: Debug + RObject {}
 #[serde(untagged)]
 pub enum NotificationGroupType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A group containing notifications of type notificationTypeNewCall
   Calls(NotificationGroupTypeCalls),
   /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message
@@ -58,12 +61,24 @@ impl RObject for NotificationGroupType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      NotificationGroupType::Calls(t) => t.extra(),
+      NotificationGroupType::Mentions(t) => t.extra(),
+      NotificationGroupType::Messages(t) => t.extra(),
+      NotificationGroupType::SecretChat(t) => t.extra(),
+
+      NotificationGroupType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl NotificationGroupType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationGroupType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let NotificationGroupType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_calls(&self) -> bool { if let NotificationGroupType::Calls(_) = self { true } else { false } }
   pub fn is_mentions(&self) -> bool { if let NotificationGroupType::Mentions(_) = self { true } else { false } }
@@ -108,11 +123,15 @@ pub struct NotificationGroupTypeCalls {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationGroupTypeCalls {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeCalls" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,6 +158,11 @@ pub struct RTDNotificationGroupTypeCallsBuilder {
 impl RTDNotificationGroupTypeCallsBuilder {
   pub fn build(&self) -> NotificationGroupTypeCalls { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeCalls> for NotificationGroupTypeCalls {
@@ -161,11 +185,15 @@ pub struct NotificationGroupTypeMentions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationGroupTypeMentions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeMentions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -192,6 +220,11 @@ pub struct RTDNotificationGroupTypeMentionsBuilder {
 impl RTDNotificationGroupTypeMentionsBuilder {
   pub fn build(&self) -> NotificationGroupTypeMentions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeMentions> for NotificationGroupTypeMentions {
@@ -214,11 +247,15 @@ pub struct NotificationGroupTypeMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationGroupTypeMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -245,6 +282,11 @@ pub struct RTDNotificationGroupTypeMessagesBuilder {
 impl RTDNotificationGroupTypeMessagesBuilder {
   pub fn build(&self) -> NotificationGroupTypeMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeMessages> for NotificationGroupTypeMessages {
@@ -267,11 +309,15 @@ pub struct NotificationGroupTypeSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationGroupTypeSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -298,6 +344,11 @@ pub struct RTDNotificationGroupTypeSecretChatBuilder {
 impl RTDNotificationGroupTypeSecretChatBuilder {
   pub fn build(&self) -> NotificationGroupTypeSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeSecretChat> for NotificationGroupTypeSecretChat {
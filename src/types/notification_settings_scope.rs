@@ -18,6 +18,9 @@ pub trait TDNotificationSettingsScope: Debug + RObject {}
 #[serde(untagged)]
 pub enum NotificationSettingsScope {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Notification settings applied to all channels when the corresponding chat setting has a default value
   ChannelChats(NotificationSettingsScopeChannelChats),
   /// Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value
@@ -54,12 +57,23 @@ impl RObject for NotificationSettingsScope {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      NotificationSettingsScope::ChannelChats(t) => t.extra(),
+      NotificationSettingsScope::GroupChats(t) => t.extra(),
+      NotificationSettingsScope::PrivateChats(t) => t.extra(),
+
+      NotificationSettingsScope::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl NotificationSettingsScope {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationSettingsScope::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let NotificationSettingsScope::Unknown { .. } = self { true } else { false } }
 
   pub fn is_channel_chats(&self) -> bool { if let NotificationSettingsScope::ChannelChats(_) = self { true } else { false } }
   pub fn is_group_chats(&self) -> bool { if let NotificationSettingsScope::GroupChats(_) = self { true } else { false } }
@@ -99,11 +113,15 @@ pub struct NotificationSettingsScopeChannelChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationSettingsScopeChannelChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationSettingsScopeChannelChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -130,6 +148,11 @@ pub struct RTDNotificationSettingsScopeChannelChatsBuilder {
 impl RTDNotificationSettingsScopeChannelChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopeChannelChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationSettingsScopeChannelChats> for NotificationSettingsScopeChannelChats {
@@ -152,11 +175,15 @@ pub struct NotificationSettingsScopeGroupChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationSettingsScopeGroupChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationSettingsScopeGroupChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -183,6 +210,11 @@ pub struct RTDNotificationSettingsScopeGroupChatsBuilder {
 impl RTDNotificationSettingsScopeGroupChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopeGroupChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationSettingsScopeGroupChats> for NotificationSettingsScopeGroupChats {
@@ -205,11 +237,15 @@ pub struct NotificationSettingsScopePrivateChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationSettingsScopePrivateChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationSettingsScopePrivateChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -236,6 +272,11 @@ pub struct RTDNotificationSettingsScopePrivateChatsBuilder {
 impl RTDNotificationSettingsScopePrivateChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopePrivateChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationSettingsScopePrivateChats> for NotificationSettingsScopePrivateChats {
@@ -18,6 +18,9 @@ pub trait TDNotificationType: Debug + RObject {}
 #[serde(untagged)]
 pub enum NotificationType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// New call was received
   NewCall(NotificationTypeNewCall),
   /// New message was received
@@ -58,12 +61,24 @@ impl RObject for NotificationType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      NotificationType::NewCall(t) => t.extra(),
+      NotificationType::NewMessage(t) => t.extra(),
+      NotificationType::NewPushMessage(t) => t.extra(),
+      NotificationType::NewSecretChat(t) => t.extra(),
+
+      NotificationType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl NotificationType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let NotificationType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_new_call(&self) -> bool { if let NotificationType::NewCall(_) = self { true } else { false } }
   pub fn is_new_message(&self) -> bool { if let NotificationType::NewMessage(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct NotificationTypeNewCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Call identifier
   call_id: i64,
   
@@ -115,6 +133,7 @@ pub struct NotificationTypeNewCall {
 
 impl RObject for NotificationTypeNewCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +162,11 @@ pub struct RTDNotificationTypeNewCallBuilder {
 impl RTDNotificationTypeNewCallBuilder {
   pub fn build(&self) -> NotificationTypeNewCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -171,6 +195,9 @@ pub struct NotificationTypeNewMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The message
   message: Message,
   
@@ -178,6 +205,7 @@ pub struct NotificationTypeNewMessage {
 
 impl RObject for NotificationTypeNewMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -206,6 +234,11 @@ pub struct RTDNotificationTypeNewMessageBuilder {
 impl RTDNotificationTypeNewMessageBuilder {
   pub fn build(&self) -> NotificationTypeNewMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -234,6 +267,9 @@ pub struct NotificationTypeNewPushMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The message identifier. The message will not be available in the chat history, but the ID can be used in viewMessages and as reply_to_message_id
   message_id: i64,
   /// Sender of the message; 0 if unknown. Corresponding user may be inaccessible
@@ -249,6 +285,7 @@ pub struct NotificationTypeNewPushMessage {
 
 impl RObject for NotificationTypeNewPushMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewPushMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -285,6 +322,11 @@ pub struct RTDNotificationTypeNewPushMessageBuilder {
 impl RTDNotificationTypeNewPushMessageBuilder {
   pub fn build(&self) -> NotificationTypeNewPushMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
@@ -337,11 +379,15 @@ pub struct NotificationTypeNewSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for NotificationTypeNewSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -368,6 +414,11 @@ pub struct RTDNotificationTypeNewSecretChatBuilder {
 impl RTDNotificationTypeNewSecretChatBuilder {
   pub fn build(&self) -> NotificationTypeNewSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationTypeNewSecretChat> for NotificationTypeNewSecretChat {
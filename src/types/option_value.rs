@@ -18,8 +18,9 @@ pub trait TDOptionValue: Debug + RObject {}
 #[serde(untagged)]
 pub enum OptionValue {
   #[doc(hidden)] _Default(()),
-  /// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
-  GetOption(GetOption),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents a boolean option
   Boolean(OptionValueBoolean),
   /// Represents an unknown option or an option which has a default value
@@ -40,7 +41,6 @@ impl<'de> Deserialize<'de> for OptionValue {
     use serde::de::Error;
     rtd_enum_deserialize!(
       OptionValue,
-      (getOption, GetOption);
       (optionValueBoolean, Boolean);
       (optionValueEmpty, Empty);
       (optionValueInteger, Integer);
@@ -53,7 +53,6 @@ impl<'de> Deserialize<'de> for OptionValue {
 impl RObject for OptionValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      OptionValue::GetOption(t) => t.td_name(),
       OptionValue::Boolean(t) => t.td_name(),
       OptionValue::Empty(t) => t.td_name(),
       OptionValue::Integer(t) => t.td_name(),
@@ -62,26 +61,35 @@ impl RObject for OptionValue {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      OptionValue::Boolean(t) => t.extra(),
+      OptionValue::Empty(t) => t.extra(),
+      OptionValue::Integer(t) => t.extra(),
+      OptionValue::String(t) => t.extra(),
+
+      OptionValue::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl OptionValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let OptionValue::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let OptionValue::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_option(&self) -> bool { if let OptionValue::GetOption(_) = self { true } else { false } }
   pub fn is_boolean(&self) -> bool { if let OptionValue::Boolean(_) = self { true } else { false } }
   pub fn is_empty(&self) -> bool { if let OptionValue::Empty(_) = self { true } else { false } }
   pub fn is_integer(&self) -> bool { if let OptionValue::Integer(_) = self { true } else { false } }
   pub fn is_string(&self) -> bool { if let OptionValue::String(_) = self { true } else { false } }
 
-  pub fn on_get_option<F: FnOnce(&GetOption)>(&self, fnc: F) -> &Self { if let OptionValue::GetOption(t) = self { fnc(t) }; self }
   pub fn on_boolean<F: FnOnce(&OptionValueBoolean)>(&self, fnc: F) -> &Self { if let OptionValue::Boolean(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&OptionValueEmpty)>(&self, fnc: F) -> &Self { if let OptionValue::Empty(t) = self { fnc(t) }; self }
   pub fn on_integer<F: FnOnce(&OptionValueInteger)>(&self, fnc: F) -> &Self { if let OptionValue::Integer(t) = self { fnc(t) }; self }
   pub fn on_string<F: FnOnce(&OptionValueString)>(&self, fnc: F) -> &Self { if let OptionValue::String(t) = self { fnc(t) }; self }
 
-  pub fn as_get_option(&self) -> Option<&GetOption> { if let OptionValue::GetOption(t) = self { return Some(t) } None }
   pub fn as_boolean(&self) -> Option<&OptionValueBoolean> { if let OptionValue::Boolean(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&OptionValueEmpty> { if let OptionValue::Empty(t) = self { return Some(t) } None }
   pub fn as_integer(&self) -> Option<&OptionValueInteger> { if let OptionValue::Integer(t) = self { return Some(t) } None }
@@ -89,8 +97,6 @@ impl OptionValue {
 
 
 
-  pub fn get_option<T: AsRef<GetOption>>(t: T) -> Self { OptionValue::GetOption(t.as_ref().clone()) }
-
   pub fn boolean<T: AsRef<OptionValueBoolean>>(t: T) -> Self { OptionValue::Boolean(t.as_ref().clone()) }
 
   pub fn empty<T: AsRef<OptionValueEmpty>>(t: T) -> Self { OptionValue::Empty(t.as_ref().clone()) }
@@ -117,6 +123,9 @@ pub struct OptionValueBoolean {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value of the option
   value: bool,
   
@@ -124,6 +133,7 @@ pub struct OptionValueBoolean {
 
 impl RObject for OptionValueBoolean {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueBoolean" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -152,6 +162,11 @@ pub struct RTDOptionValueBooleanBuilder {
 impl RTDOptionValueBooleanBuilder {
   pub fn build(&self) -> OptionValueBoolean { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: bool) -> &mut Self {
     self.inner.value = value;
@@ -180,11 +195,15 @@ pub struct OptionValueEmpty {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for OptionValueEmpty {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueEmpty" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -211,6 +230,11 @@ pub struct RTDOptionValueEmptyBuilder {
 impl RTDOptionValueEmptyBuilder {
   pub fn build(&self) -> OptionValueEmpty { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<OptionValueEmpty> for OptionValueEmpty {
@@ -233,6 +257,9 @@ pub struct OptionValueInteger {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value of the option
   value: i64,
   
@@ -240,6 +267,7 @@ pub struct OptionValueInteger {
 
 impl RObject for OptionValueInteger {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueInteger" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -268,6 +296,11 @@ pub struct RTDOptionValueIntegerBuilder {
 impl RTDOptionValueIntegerBuilder {
   pub fn build(&self) -> OptionValueInteger { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: i64) -> &mut Self {
     self.inner.value = value;
@@ -296,6 +329,9 @@ pub struct OptionValueString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value of the option
   value: String,
   
@@ -303,6 +339,7 @@ pub struct OptionValueString {
 
 impl RObject for OptionValueString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -331,6 +368,11 @@ pub struct RTDOptionValueStringBuilder {
 impl RTDOptionValueStringBuilder {
   pub fn build(&self) -> OptionValueString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDPageBlock: Debug + RObject {}
 #[serde(untagged)]
 pub enum PageBlock {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor
   Anchor(PageBlockAnchor),
   /// An animation
@@ -158,12 +161,49 @@ impl RObject for PageBlock {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PageBlock::Anchor(t) => t.extra(),
+      PageBlock::Animation(t) => t.extra(),
+      PageBlock::Audio(t) => t.extra(),
+      PageBlock::AuthorDate(t) => t.extra(),
+      PageBlock::BlockQuote(t) => t.extra(),
+      PageBlock::ChatLink(t) => t.extra(),
+      PageBlock::Collage(t) => t.extra(),
+      PageBlock::Cover(t) => t.extra(),
+      PageBlock::Details(t) => t.extra(),
+      PageBlock::Divider(t) => t.extra(),
+      PageBlock::Embedded(t) => t.extra(),
+      PageBlock::EmbeddedPost(t) => t.extra(),
+      PageBlock::Footer(t) => t.extra(),
+      PageBlock::Header(t) => t.extra(),
+      PageBlock::Kicker(t) => t.extra(),
+      PageBlock::List(t) => t.extra(),
+      PageBlock::Map(t) => t.extra(),
+      PageBlock::Paragraph(t) => t.extra(),
+      PageBlock::Photo(t) => t.extra(),
+      PageBlock::Preformatted(t) => t.extra(),
+      PageBlock::PullQuote(t) => t.extra(),
+      PageBlock::RelatedArticles(t) => t.extra(),
+      PageBlock::Slideshow(t) => t.extra(),
+      PageBlock::Subheader(t) => t.extra(),
+      PageBlock::Subtitle(t) => t.extra(),
+      PageBlock::Table(t) => t.extra(),
+      PageBlock::Title(t) => t.extra(),
+      PageBlock::Video(t) => t.extra(),
+      PageBlock::VoiceNote(t) => t.extra(),
+
+      PageBlock::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PageBlock {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlock::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PageBlock::Unknown { .. } = self { true } else { false } }
 
   pub fn is_anchor(&self) -> bool { if let PageBlock::Anchor(_) = self { true } else { false } }
   pub fn is_animation(&self) -> bool { if let PageBlock::Animation(_) = self { true } else { false } }
@@ -333,6 +373,9 @@ pub struct PageBlockAnchor {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Name of the anchor
   name: String,
   
@@ -340,6 +383,7 @@ pub struct PageBlockAnchor {
 
 impl RObject for PageBlockAnchor {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAnchor" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -368,6 +412,11 @@ pub struct RTDPageBlockAnchorBuilder {
 impl RTDPageBlockAnchorBuilder {
   pub fn build(&self) -> PageBlockAnchor { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -396,6 +445,9 @@ pub struct PageBlockAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Animation file; may be null
   animation: Option<Animation>,
   /// Animation caption
@@ -407,6 +459,7 @@ pub struct PageBlockAnimation {
 
 impl RObject for PageBlockAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -439,6 +492,11 @@ pub struct RTDPageBlockAnimationBuilder {
 impl RTDPageBlockAnimationBuilder {
   pub fn build(&self) -> PageBlockAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = Some(animation.as_ref().clone());
@@ -479,6 +537,9 @@ pub struct PageBlockAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Audio file; may be null
   audio: Option<Audio>,
   /// Audio file caption
@@ -488,6 +549,7 @@ pub struct PageBlockAudio {
 
 impl RObject for PageBlockAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -518,6 +580,11 @@ pub struct RTDPageBlockAudioBuilder {
 impl RTDPageBlockAudioBuilder {
   pub fn build(&self) -> PageBlockAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = Some(audio.as_ref().clone());
@@ -552,6 +619,9 @@ pub struct PageBlockAuthorDate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Author
   author: RichText,
   /// Point in time (Unix timestamp) when the article was published; 0 if unknown
@@ -561,6 +631,7 @@ pub struct PageBlockAuthorDate {
 
 impl RObject for PageBlockAuthorDate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAuthorDate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -591,6 +662,11 @@ pub struct RTDPageBlockAuthorDateBuilder {
 impl RTDPageBlockAuthorDateBuilder {
   pub fn build(&self) -> PageBlockAuthorDate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn author<T: AsRef<RichText>>(&mut self, author: T) -> &mut Self {
     self.inner.author = author.as_ref().clone();
@@ -625,6 +701,9 @@ pub struct PageBlockBlockQuote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Quote text
   text: RichText,
   /// Quote credit
@@ -634,6 +713,7 @@ pub struct PageBlockBlockQuote {
 
 impl RObject for PageBlockBlockQuote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockBlockQuote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -664,6 +744,11 @@ pub struct RTDPageBlockBlockQuoteBuilder {
 impl RTDPageBlockBlockQuoteBuilder {
   pub fn build(&self) -> PageBlockBlockQuote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -698,6 +783,9 @@ pub struct PageBlockChatLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat title
   title: String,
   /// Chat photo; may be null
@@ -709,6 +797,7 @@ pub struct PageBlockChatLink {
 
 impl RObject for PageBlockChatLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockChatLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -741,6 +830,11 @@ pub struct RTDPageBlockChatLinkBuilder {
 impl RTDPageBlockChatLinkBuilder {
   pub fn build(&self) -> PageBlockChatLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -781,6 +875,9 @@ pub struct PageBlockCollage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Collage item contents
   page_blocks: Vec<PageBlock>,
   /// Block caption
@@ -790,6 +887,7 @@ pub struct PageBlockCollage {
 
 impl RObject for PageBlockCollage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockCollage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -820,6 +918,11 @@ pub struct RTDPageBlockCollageBuilder {
 impl RTDPageBlockCollageBuilder {
   pub fn build(&self) -> PageBlockCollage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -854,6 +957,9 @@ pub struct PageBlockCover {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Cover
   cover: Box<PageBlock>,
   
@@ -861,6 +967,7 @@ pub struct PageBlockCover {
 
 impl RObject for PageBlockCover {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockCover" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -889,6 +996,11 @@ pub struct RTDPageBlockCoverBuilder {
 impl RTDPageBlockCoverBuilder {
   pub fn build(&self) -> PageBlockCover { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn cover<T: AsRef<Box<PageBlock>>>(&mut self, cover: T) -> &mut Self {
     self.inner.cover = cover.as_ref().clone();
@@ -917,6 +1029,9 @@ pub struct PageBlockDetails {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Always visible heading for the block
   header: RichText,
   /// Block contents
@@ -928,6 +1043,7 @@ pub struct PageBlockDetails {
 
 impl RObject for PageBlockDetails {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockDetails" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -960,6 +1076,11 @@ pub struct RTDPageBlockDetailsBuilder {
 impl RTDPageBlockDetailsBuilder {
   pub fn build(&self) -> PageBlockDetails { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -1000,11 +1121,15 @@ pub struct PageBlockDivider {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockDivider {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockDivider" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1031,6 +1156,11 @@ pub struct RTDPageBlockDividerBuilder {
 impl RTDPageBlockDividerBuilder {
   pub fn build(&self) -> PageBlockDivider { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockDivider> for PageBlockDivider {
@@ -1053,6 +1183,9 @@ pub struct PageBlockEmbedded {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Web page URL, if available
   url: String,
   /// HTML-markup of the embedded page
@@ -1074,6 +1207,7 @@ pub struct PageBlockEmbedded {
 
 impl RObject for PageBlockEmbedded {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockEmbedded" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1116,6 +1250,11 @@ pub struct RTDPageBlockEmbeddedBuilder {
 impl RTDPageBlockEmbeddedBuilder {
   pub fn build(&self) -> PageBlockEmbedded { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1186,6 +1325,9 @@ pub struct PageBlockEmbeddedPost {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Web page URL
   url: String,
   /// Post author
@@ -1203,6 +1345,7 @@ pub struct PageBlockEmbeddedPost {
 
 impl RObject for PageBlockEmbeddedPost {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockEmbeddedPost" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1241,6 +1384,11 @@ pub struct RTDPageBlockEmbeddedPostBuilder {
 impl RTDPageBlockEmbeddedPostBuilder {
   pub fn build(&self) -> PageBlockEmbeddedPost { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1299,6 +1447,9 @@ pub struct PageBlockFooter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Footer
   footer: RichText,
   
@@ -1306,6 +1457,7 @@ pub struct PageBlockFooter {
 
 impl RObject for PageBlockFooter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockFooter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1334,6 +1486,11 @@ pub struct RTDPageBlockFooterBuilder {
 impl RTDPageBlockFooterBuilder {
   pub fn build(&self) -> PageBlockFooter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn footer<T: AsRef<RichText>>(&mut self, footer: T) -> &mut Self {
     self.inner.footer = footer.as_ref().clone();
@@ -1362,6 +1519,9 @@ pub struct PageBlockHeader {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Header
   header: RichText,
   
@@ -1369,6 +1529,7 @@ pub struct PageBlockHeader {
 
 impl RObject for PageBlockHeader {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHeader" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1397,6 +1558,11 @@ pub struct RTDPageBlockHeaderBuilder {
 impl RTDPageBlockHeaderBuilder {
   pub fn build(&self) -> PageBlockHeader { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -1425,6 +1591,9 @@ pub struct PageBlockKicker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Kicker
   kicker: RichText,
   
@@ -1432,6 +1601,7 @@ pub struct PageBlockKicker {
 
 impl RObject for PageBlockKicker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockKicker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1460,6 +1630,11 @@ pub struct RTDPageBlockKickerBuilder {
 impl RTDPageBlockKickerBuilder {
   pub fn build(&self) -> PageBlockKicker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn kicker<T: AsRef<RichText>>(&mut self, kicker: T) -> &mut Self {
     self.inner.kicker = kicker.as_ref().clone();
@@ -1488,6 +1663,9 @@ pub struct PageBlockList {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The items of the list
   items: Vec<PageBlockListItem>,
   
@@ -1495,6 +1673,7 @@ pub struct PageBlockList {
 
 impl RObject for PageBlockList {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockList" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1523,6 +1702,11 @@ pub struct RTDPageBlockListBuilder {
 impl RTDPageBlockListBuilder {
   pub fn build(&self) -> PageBlockList { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn items(&mut self, items: Vec<PageBlockListItem>) -> &mut Self {
     self.inner.items = items;
@@ -1551,6 +1735,9 @@ pub struct PageBlockMap {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Location of the map center
   location: Location,
   /// Map zoom level
@@ -1566,6 +1753,7 @@ pub struct PageBlockMap {
 
 impl RObject for PageBlockMap {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockMap" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1602,6 +1790,11 @@ pub struct RTDPageBlockMapBuilder {
 impl RTDPageBlockMapBuilder {
   pub fn build(&self) -> PageBlockMap { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -1654,6 +1847,9 @@ pub struct PageBlockParagraph {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Paragraph text
   text: RichText,
   
@@ -1661,6 +1857,7 @@ pub struct PageBlockParagraph {
 
 impl RObject for PageBlockParagraph {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockParagraph" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1689,6 +1886,11 @@ pub struct RTDPageBlockParagraphBuilder {
 impl RTDPageBlockParagraphBuilder {
   pub fn build(&self) -> PageBlockParagraph { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1717,6 +1919,9 @@ pub struct PageBlockPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Photo file; may be null
   photo: Option<Photo>,
   /// Photo caption
@@ -1728,6 +1933,7 @@ pub struct PageBlockPhoto {
 
 impl RObject for PageBlockPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1760,6 +1966,11 @@ pub struct RTDPageBlockPhotoBuilder {
 impl RTDPageBlockPhotoBuilder {
   pub fn build(&self) -> PageBlockPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -1800,6 +2011,9 @@ pub struct PageBlockPreformatted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Paragraph text
   text: RichText,
   /// Programming language for which the text should be formatted
@@ -1809,6 +2023,7 @@ pub struct PageBlockPreformatted {
 
 impl RObject for PageBlockPreformatted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockPreformatted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1839,6 +2054,11 @@ pub struct RTDPageBlockPreformattedBuilder {
 impl RTDPageBlockPreformattedBuilder {
   pub fn build(&self) -> PageBlockPreformatted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1873,6 +2093,9 @@ pub struct PageBlockPullQuote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Quote text
   text: RichText,
   /// Quote credit
@@ -1882,6 +2105,7 @@ pub struct PageBlockPullQuote {
 
 impl RObject for PageBlockPullQuote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockPullQuote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1912,6 +2136,11 @@ pub struct RTDPageBlockPullQuoteBuilder {
 impl RTDPageBlockPullQuoteBuilder {
   pub fn build(&self) -> PageBlockPullQuote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1946,6 +2175,9 @@ pub struct PageBlockRelatedArticles {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Block header
   header: RichText,
   /// List of related articles
@@ -1955,6 +2187,7 @@ pub struct PageBlockRelatedArticles {
 
 impl RObject for PageBlockRelatedArticles {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockRelatedArticles" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1985,6 +2218,11 @@ pub struct RTDPageBlockRelatedArticlesBuilder {
 impl RTDPageBlockRelatedArticlesBuilder {
   pub fn build(&self) -> PageBlockRelatedArticles { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -2019,6 +2257,9 @@ pub struct PageBlockSlideshow {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Slideshow item contents
   page_blocks: Vec<PageBlock>,
   /// Block caption
@@ -2028,6 +2269,7 @@ pub struct PageBlockSlideshow {
 
 impl RObject for PageBlockSlideshow {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockSlideshow" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2058,6 +2300,11 @@ pub struct RTDPageBlockSlideshowBuilder {
 impl RTDPageBlockSlideshowBuilder {
   pub fn build(&self) -> PageBlockSlideshow { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -2092,6 +2339,9 @@ pub struct PageBlockSubheader {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Subheader
   subheader: RichText,
   
@@ -2099,6 +2349,7 @@ pub struct PageBlockSubheader {
 
 impl RObject for PageBlockSubheader {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockSubheader" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2127,6 +2378,11 @@ pub struct RTDPageBlockSubheaderBuilder {
 impl RTDPageBlockSubheaderBuilder {
   pub fn build(&self) -> PageBlockSubheader { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn subheader<T: AsRef<RichText>>(&mut self, subheader: T) -> &mut Self {
     self.inner.subheader = subheader.as_ref().clone();
@@ -2155,6 +2411,9 @@ pub struct PageBlockSubtitle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Subtitle
   subtitle: RichText,
   
@@ -2162,6 +2421,7 @@ pub struct PageBlockSubtitle {
 
 impl RObject for PageBlockSubtitle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockSubtitle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2190,6 +2450,11 @@ pub struct RTDPageBlockSubtitleBuilder {
 impl RTDPageBlockSubtitleBuilder {
   pub fn build(&self) -> PageBlockSubtitle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn subtitle<T: AsRef<RichText>>(&mut self, subtitle: T) -> &mut Self {
     self.inner.subtitle = subtitle.as_ref().clone();
@@ -2218,6 +2483,9 @@ pub struct PageBlockTable {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Table caption
   caption: RichText,
   /// Table cells
@@ -2231,6 +2499,7 @@ pub struct PageBlockTable {
 
 impl RObject for PageBlockTable {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockTable" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2265,6 +2534,11 @@ pub struct RTDPageBlockTableBuilder {
 impl RTDPageBlockTableBuilder {
   pub fn build(&self) -> PageBlockTable { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn caption<T: AsRef<RichText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
@@ -2311,6 +2585,9 @@ pub struct PageBlockTitle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Title
   title: RichText,
   
@@ -2318,6 +2595,7 @@ pub struct PageBlockTitle {
 
 impl RObject for PageBlockTitle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockTitle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2346,6 +2624,11 @@ pub struct RTDPageBlockTitleBuilder {
 impl RTDPageBlockTitleBuilder {
   pub fn build(&self) -> PageBlockTitle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<RichText>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().clone();
@@ -2374,6 +2657,9 @@ pub struct PageBlockVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Video file; may be null
   video: Option<Video>,
   /// Video caption
@@ -2387,6 +2673,7 @@ pub struct PageBlockVideo {
 
 impl RObject for PageBlockVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2421,6 +2708,11 @@ pub struct RTDPageBlockVideoBuilder {
 impl RTDPageBlockVideoBuilder {
   pub fn build(&self) -> PageBlockVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = Some(video.as_ref().clone());
@@ -2467,6 +2759,9 @@ pub struct PageBlockVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Voice note; may be null
   voice_note: Option<VoiceNote>,
   /// Voice note caption
@@ -2476,6 +2771,7 @@ pub struct PageBlockVoiceNote {
 
 impl RObject for PageBlockVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2506,6 +2802,11 @@ pub struct RTDPageBlockVoiceNoteBuilder {
 impl RTDPageBlockVoiceNoteBuilder {
   pub fn build(&self) -> PageBlockVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -18,6 +18,9 @@ pub trait TDPageBlockHorizontalAlignment: Debug + RObject {}
 #[serde(untagged)]
 pub enum PageBlockHorizontalAlignment {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The content should be center-aligned
   Center(PageBlockHorizontalAlignmentCenter),
   /// The content should be left-aligned
@@ -54,12 +57,23 @@ impl RObject for PageBlockHorizontalAlignment {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PageBlockHorizontalAlignment::Center(t) => t.extra(),
+      PageBlockHorizontalAlignment::Left(t) => t.extra(),
+      PageBlockHorizontalAlignment::Right(t) => t.extra(),
+
+      PageBlockHorizontalAlignment::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PageBlockHorizontalAlignment {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlockHorizontalAlignment::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PageBlockHorizontalAlignment::Unknown { .. } = self { true } else { false } }
 
   pub fn is_center(&self) -> bool { if let PageBlockHorizontalAlignment::Center(_) = self { true } else { false } }
   pub fn is_left(&self) -> bool { if let PageBlockHorizontalAlignment::Left(_) = self { true } else { false } }
@@ -99,11 +113,15 @@ pub struct PageBlockHorizontalAlignmentCenter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockHorizontalAlignmentCenter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHorizontalAlignmentCenter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -130,6 +148,11 @@ pub struct RTDPageBlockHorizontalAlignmentCenterBuilder {
 impl RTDPageBlockHorizontalAlignmentCenterBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentCenter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentCenter> for PageBlockHorizontalAlignmentCenter {
@@ -152,11 +175,15 @@ pub struct PageBlockHorizontalAlignmentLeft {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockHorizontalAlignmentLeft {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHorizontalAlignmentLeft" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -183,6 +210,11 @@ pub struct RTDPageBlockHorizontalAlignmentLeftBuilder {
 impl RTDPageBlockHorizontalAlignmentLeftBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentLeft { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentLeft> for PageBlockHorizontalAlignmentLeft {
@@ -205,11 +237,15 @@ pub struct PageBlockHorizontalAlignmentRight {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockHorizontalAlignmentRight {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHorizontalAlignmentRight" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -236,6 +272,11 @@ pub struct RTDPageBlockHorizontalAlignmentRightBuilder {
 impl RTDPageBlockHorizontalAlignmentRightBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentRight { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentRight> for PageBlockHorizontalAlignmentRight {
@@ -11,6 +11,9 @@ pub struct PageBlockRelatedArticle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Related article URL
   url: String,
   /// Article title; may be empty
@@ -28,6 +31,7 @@ pub struct PageBlockRelatedArticle {
 
 impl RObject for PageBlockRelatedArticle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockRelatedArticle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -63,6 +67,11 @@ pub struct RTDPageBlockRelatedArticleBuilder {
 impl RTDPageBlockRelatedArticleBuilder {
   pub fn build(&self) -> PageBlockRelatedArticle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
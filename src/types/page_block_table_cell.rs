@@ -11,6 +11,9 @@ pub struct PageBlockTableCell {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Cell text; may be null. If the text is null, then the cell should be invisible
   text: Option<RichText>,
   /// True, if it is a header cell
@@ -28,6 +31,7 @@ pub struct PageBlockTableCell {
 
 impl RObject for PageBlockTableCell {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockTableCell" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -63,6 +67,11 @@ pub struct RTDPageBlockTableCellBuilder {
 impl RTDPageBlockTableCellBuilder {
   pub fn build(&self) -> PageBlockTableCell { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = Some(text.as_ref().clone());
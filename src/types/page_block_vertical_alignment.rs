@@ -18,6 +18,9 @@ pub trait TDPageBlockVerticalAlignment: Debug + RObject {}
 #[serde(untagged)]
 pub enum PageBlockVerticalAlignment {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The content should be bottom-aligned
   Bottom(PageBlockVerticalAlignmentBottom),
   /// The content should be middle-aligned
@@ -54,12 +57,23 @@ impl RObject for PageBlockVerticalAlignment {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PageBlockVerticalAlignment::Bottom(t) => t.extra(),
+      PageBlockVerticalAlignment::Middle(t) => t.extra(),
+      PageBlockVerticalAlignment::Top(t) => t.extra(),
+
+      PageBlockVerticalAlignment::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PageBlockVerticalAlignment {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlockVerticalAlignment::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PageBlockVerticalAlignment::Unknown { .. } = self { true } else { false } }
 
   pub fn is_bottom(&self) -> bool { if let PageBlockVerticalAlignment::Bottom(_) = self { true } else { false } }
   pub fn is_middle(&self) -> bool { if let PageBlockVerticalAlignment::Middle(_) = self { true } else { false } }
@@ -99,11 +113,15 @@ pub struct PageBlockVerticalAlignmentBottom {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockVerticalAlignmentBottom {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVerticalAlignmentBottom" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -130,6 +148,11 @@ pub struct RTDPageBlockVerticalAlignmentBottomBuilder {
 impl RTDPageBlockVerticalAlignmentBottomBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentBottom { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentBottom> for PageBlockVerticalAlignmentBottom {
@@ -152,11 +175,15 @@ pub struct PageBlockVerticalAlignmentMiddle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockVerticalAlignmentMiddle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVerticalAlignmentMiddle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -183,6 +210,11 @@ pub struct RTDPageBlockVerticalAlignmentMiddleBuilder {
 impl RTDPageBlockVerticalAlignmentMiddleBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentMiddle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentMiddle> for PageBlockVerticalAlignmentMiddle {
@@ -205,11 +237,15 @@ pub struct PageBlockVerticalAlignmentTop {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PageBlockVerticalAlignmentTop {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVerticalAlignmentTop" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -236,6 +272,11 @@ pub struct RTDPageBlockVerticalAlignmentTopBuilder {
 impl RTDPageBlockVerticalAlignmentTopBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentTop { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentTop> for PageBlockVerticalAlignmentTop {
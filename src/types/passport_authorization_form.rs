@@ -11,6 +11,9 @@ pub struct PassportAuthorizationForm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the authorization form
   id: i64,
   /// Information about the Telegram Passport elements that need to be provided to complete the form
@@ -22,6 +25,7 @@ pub struct PassportAuthorizationForm {
 
 impl RObject for PassportAuthorizationForm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportAuthorizationForm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDPassportAuthorizationFormBuilder {
 impl RTDPassportAuthorizationFormBuilder {
   pub fn build(&self) -> PassportAuthorizationForm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
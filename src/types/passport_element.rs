@@ -18,8 +18,9 @@ pub trait TDPassportElement: Debug + RObject {}
 #[serde(untagged)]
 pub enum PassportElement {
   #[doc(hidden)] _Default(()),
-  /// Returns one of the available Telegram Passport elements
-  GetPassportElement(GetPassportElement),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram Passport element containing the user's address
   Address(PassportElementAddress),
   /// A Telegram Passport element containing the user's bank statement
@@ -46,8 +47,6 @@ pub enum PassportElement {
   TemporaryRegistration(PassportElementTemporaryRegistration),
   /// A Telegram Passport element containing the user's utility bill
   UtilityBill(PassportElementUtilityBill),
-  /// Adds an element to the user's Telegram Passport. May return an error with a message "PHONE_VERIFICATION_NEEDED" or "EMAIL_VERIFICATION_NEEDED" if the chosen phone number or the chosen email address must be verified first
-  SetPassportElement(SetPassportElement),
 
 }
 
@@ -60,7 +59,6 @@ impl<'de> Deserialize<'de> for PassportElement {
     use serde::de::Error;
     rtd_enum_deserialize!(
       PassportElement,
-      (getPassportElement, GetPassportElement);
       (passportElementAddress, Address);
       (passportElementBankStatement, BankStatement);
       (passportElementDriverLicense, DriverLicense);
@@ -74,7 +72,6 @@ impl<'de> Deserialize<'de> for PassportElement {
       (passportElementRentalAgreement, RentalAgreement);
       (passportElementTemporaryRegistration, TemporaryRegistration);
       (passportElementUtilityBill, UtilityBill);
-      (setPassportElement, SetPassportElement);
 
     )(deserializer)
   }
@@ -83,7 +80,6 @@ impl<'de> Deserialize<'de> for PassportElement {
 impl RObject for PassportElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      PassportElement::GetPassportElement(t) => t.td_name(),
       PassportElement::Address(t) => t.td_name(),
       PassportElement::BankStatement(t) => t.td_name(),
       PassportElement::DriverLicense(t) => t.td_name(),
@@ -97,19 +93,38 @@ impl RObject for PassportElement {
       PassportElement::RentalAgreement(t) => t.td_name(),
       PassportElement::TemporaryRegistration(t) => t.td_name(),
       PassportElement::UtilityBill(t) => t.td_name(),
-      PassportElement::SetPassportElement(t) => t.td_name(),
 
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PassportElement::Address(t) => t.extra(),
+      PassportElement::BankStatement(t) => t.extra(),
+      PassportElement::DriverLicense(t) => t.extra(),
+      PassportElement::EmailAddress(t) => t.extra(),
+      PassportElement::IdentityCard(t) => t.extra(),
+      PassportElement::InternalPassport(t) => t.extra(),
+      PassportElement::Passport(t) => t.extra(),
+      PassportElement::PassportRegistration(t) => t.extra(),
+      PassportElement::PersonalDetails(t) => t.extra(),
+      PassportElement::PhoneNumber(t) => t.extra(),
+      PassportElement::RentalAgreement(t) => t.extra(),
+      PassportElement::TemporaryRegistration(t) => t.extra(),
+      PassportElement::UtilityBill(t) => t.extra(),
+
+      PassportElement::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElement::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PassportElement::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_passport_element(&self) -> bool { if let PassportElement::GetPassportElement(_) = self { true } else { false } }
   pub fn is_address(&self) -> bool { if let PassportElement::Address(_) = self { true } else { false } }
   pub fn is_bank_statement(&self) -> bool { if let PassportElement::BankStatement(_) = self { true } else { false } }
   pub fn is_driver_license(&self) -> bool { if let PassportElement::DriverLicense(_) = self { true } else { false } }
@@ -123,9 +138,7 @@ impl PassportElement {
   pub fn is_rental_agreement(&self) -> bool { if let PassportElement::RentalAgreement(_) = self { true } else { false } }
   pub fn is_temporary_registration(&self) -> bool { if let PassportElement::TemporaryRegistration(_) = self { true } else { false } }
   pub fn is_utility_bill(&self) -> bool { if let PassportElement::UtilityBill(_) = self { true } else { false } }
-  pub fn is_set_passport_element(&self) -> bool { if let PassportElement::SetPassportElement(_) = self { true } else { false } }
 
-  pub fn on_get_passport_element<F: FnOnce(&GetPassportElement)>(&self, fnc: F) -> &Self { if let PassportElement::GetPassportElement(t) = self { fnc(t) }; self }
   pub fn on_address<F: FnOnce(&PassportElementAddress)>(&self, fnc: F) -> &Self { if let PassportElement::Address(t) = self { fnc(t) }; self }
   pub fn on_bank_statement<F: FnOnce(&PassportElementBankStatement)>(&self, fnc: F) -> &Self { if let PassportElement::BankStatement(t) = self { fnc(t) }; self }
   pub fn on_driver_license<F: FnOnce(&PassportElementDriverLicense)>(&self, fnc: F) -> &Self { if let PassportElement::DriverLicense(t) = self { fnc(t) }; self }
@@ -139,9 +152,7 @@ impl PassportElement {
   pub fn on_rental_agreement<F: FnOnce(&PassportElementRentalAgreement)>(&self, fnc: F) -> &Self { if let PassportElement::RentalAgreement(t) = self { fnc(t) }; self }
   pub fn on_temporary_registration<F: FnOnce(&PassportElementTemporaryRegistration)>(&self, fnc: F) -> &Self { if let PassportElement::TemporaryRegistration(t) = self { fnc(t) }; self }
   pub fn on_utility_bill<F: FnOnce(&PassportElementUtilityBill)>(&self, fnc: F) -> &Self { if let PassportElement::UtilityBill(t) = self { fnc(t) }; self }
-  pub fn on_set_passport_element<F: FnOnce(&SetPassportElement)>(&self, fnc: F) -> &Self { if let PassportElement::SetPassportElement(t) = self { fnc(t) }; self }
 
-  pub fn as_get_passport_element(&self) -> Option<&GetPassportElement> { if let PassportElement::GetPassportElement(t) = self { return Some(t) } None }
   pub fn as_address(&self) -> Option<&PassportElementAddress> { if let PassportElement::Address(t) = self { return Some(t) } None }
   pub fn as_bank_statement(&self) -> Option<&PassportElementBankStatement> { if let PassportElement::BankStatement(t) = self { return Some(t) } None }
   pub fn as_driver_license(&self) -> Option<&PassportElementDriverLicense> { if let PassportElement::DriverLicense(t) = self { return Some(t) } None }
@@ -155,11 +166,8 @@ impl PassportElement {
   pub fn as_rental_agreement(&self) -> Option<&PassportElementRentalAgreement> { if let PassportElement::RentalAgreement(t) = self { return Some(t) } None }
   pub fn as_temporary_registration(&self) -> Option<&PassportElementTemporaryRegistration> { if let PassportElement::TemporaryRegistration(t) = self { return Some(t) } None }
   pub fn as_utility_bill(&self) -> Option<&PassportElementUtilityBill> { if let PassportElement::UtilityBill(t) = self { return Some(t) } None }
-  pub fn as_set_passport_element(&self) -> Option<&SetPassportElement> { if let PassportElement::SetPassportElement(t) = self { return Some(t) } None }
-
 
 
-  pub fn get_passport_element<T: AsRef<GetPassportElement>>(t: T) -> Self { PassportElement::GetPassportElement(t.as_ref().clone()) }
 
   pub fn address<T: AsRef<PassportElementAddress>>(t: T) -> Self { PassportElement::Address(t.as_ref().clone()) }
 
@@ -187,8 +195,6 @@ impl PassportElement {
 
   pub fn utility_bill<T: AsRef<PassportElementUtilityBill>>(t: T) -> Self { PassportElement::UtilityBill(t.as_ref().clone()) }
 
-  pub fn set_passport_element<T: AsRef<SetPassportElement>>(t: T) -> Self { PassportElement::SetPassportElement(t.as_ref().clone()) }
-
 }
 
 impl AsRef<PassportElement> for PassportElement {
@@ -207,6 +213,9 @@ pub struct PassportElementAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Address
   address: Address,
   
@@ -214,6 +223,7 @@ pub struct PassportElementAddress {
 
 impl RObject for PassportElementAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -242,6 +252,11 @@ pub struct RTDPassportElementAddressBuilder {
 impl RTDPassportElementAddressBuilder {
   pub fn build(&self) -> PassportElementAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().clone();
@@ -270,6 +285,9 @@ pub struct PassportElementBankStatement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Bank statement
   bank_statement: PersonalDocument,
   
@@ -277,6 +295,7 @@ pub struct PassportElementBankStatement {
 
 impl RObject for PassportElementBankStatement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementBankStatement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -305,6 +324,11 @@ pub struct RTDPassportElementBankStatementBuilder {
 impl RTDPassportElementBankStatementBuilder {
   pub fn build(&self) -> PassportElementBankStatement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bank_statement<T: AsRef<PersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
     self.inner.bank_statement = bank_statement.as_ref().clone();
@@ -333,6 +357,9 @@ pub struct PassportElementDriverLicense {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Driver license
   driver_license: IdentityDocument,
   
@@ -340,6 +367,7 @@ pub struct PassportElementDriverLicense {
 
 impl RObject for PassportElementDriverLicense {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementDriverLicense" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -368,6 +396,11 @@ pub struct RTDPassportElementDriverLicenseBuilder {
 impl RTDPassportElementDriverLicenseBuilder {
   pub fn build(&self) -> PassportElementDriverLicense { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn driver_license<T: AsRef<IdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
     self.inner.driver_license = driver_license.as_ref().clone();
@@ -396,6 +429,9 @@ pub struct PassportElementEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Email address
   email_address: String,
   
@@ -403,6 +439,7 @@ pub struct PassportElementEmailAddress {
 
 impl RObject for PassportElementEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -431,6 +468,11 @@ pub struct RTDPassportElementEmailAddressBuilder {
 impl RTDPassportElementEmailAddressBuilder {
   pub fn build(&self) -> PassportElementEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
@@ -459,6 +501,9 @@ pub struct PassportElementIdentityCard {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identity card
   identity_card: IdentityDocument,
   
@@ -466,6 +511,7 @@ pub struct PassportElementIdentityCard {
 
 impl RObject for PassportElementIdentityCard {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementIdentityCard" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -494,6 +540,11 @@ pub struct RTDPassportElementIdentityCardBuilder {
 impl RTDPassportElementIdentityCardBuilder {
   pub fn build(&self) -> PassportElementIdentityCard { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn identity_card<T: AsRef<IdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
     self.inner.identity_card = identity_card.as_ref().clone();
@@ -522,6 +573,9 @@ pub struct PassportElementInternalPassport {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Internal passport
   internal_passport: IdentityDocument,
   
@@ -529,6 +583,7 @@ pub struct PassportElementInternalPassport {
 
 impl RObject for PassportElementInternalPassport {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementInternalPassport" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -557,6 +612,11 @@ pub struct RTDPassportElementInternalPassportBuilder {
 impl RTDPassportElementInternalPassportBuilder {
   pub fn build(&self) -> PassportElementInternalPassport { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn internal_passport<T: AsRef<IdentityDocument>>(&mut self, internal_passport: T) -> &mut Self {
     self.inner.internal_passport = internal_passport.as_ref().clone();
@@ -585,6 +645,9 @@ pub struct PassportElementPassport {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Passport
   passport: IdentityDocument,
   
@@ -592,6 +655,7 @@ pub struct PassportElementPassport {
 
 impl RObject for PassportElementPassport {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPassport" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -620,6 +684,11 @@ pub struct RTDPassportElementPassportBuilder {
 impl RTDPassportElementPassportBuilder {
   pub fn build(&self) -> PassportElementPassport { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport<T: AsRef<IdentityDocument>>(&mut self, passport: T) -> &mut Self {
     self.inner.passport = passport.as_ref().clone();
@@ -648,6 +717,9 @@ pub struct PassportElementPassportRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Passport registration pages
   passport_registration: PersonalDocument,
   
@@ -655,6 +727,7 @@ pub struct PassportElementPassportRegistration {
 
 impl RObject for PassportElementPassportRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPassportRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -683,6 +756,11 @@ pub struct RTDPassportElementPassportRegistrationBuilder {
 impl RTDPassportElementPassportRegistrationBuilder {
   pub fn build(&self) -> PassportElementPassportRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport_registration<T: AsRef<PersonalDocument>>(&mut self, passport_registration: T) -> &mut Self {
     self.inner.passport_registration = passport_registration.as_ref().clone();
@@ -711,6 +789,9 @@ pub struct PassportElementPersonalDetails {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Personal details of the user
   personal_details: PersonalDetails,
   
@@ -718,6 +799,7 @@ pub struct PassportElementPersonalDetails {
 
 impl RObject for PassportElementPersonalDetails {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPersonalDetails" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -746,6 +828,11 @@ pub struct RTDPassportElementPersonalDetailsBuilder {
 impl RTDPassportElementPersonalDetailsBuilder {
   pub fn build(&self) -> PassportElementPersonalDetails { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn personal_details<T: AsRef<PersonalDetails>>(&mut self, personal_details: T) -> &mut Self {
     self.inner.personal_details = personal_details.as_ref().clone();
@@ -774,6 +861,9 @@ pub struct PassportElementPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Phone number
   phone_number: String,
   
@@ -781,6 +871,7 @@ pub struct PassportElementPhoneNumber {
 
 impl RObject for PassportElementPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -809,6 +900,11 @@ pub struct RTDPassportElementPhoneNumberBuilder {
 impl RTDPassportElementPhoneNumberBuilder {
   pub fn build(&self) -> PassportElementPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -837,6 +933,9 @@ pub struct PassportElementRentalAgreement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Rental agreement
   rental_agreement: PersonalDocument,
   
@@ -844,6 +943,7 @@ pub struct PassportElementRentalAgreement {
 
 impl RObject for PassportElementRentalAgreement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementRentalAgreement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -872,6 +972,11 @@ pub struct RTDPassportElementRentalAgreementBuilder {
 impl RTDPassportElementRentalAgreementBuilder {
   pub fn build(&self) -> PassportElementRentalAgreement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rental_agreement<T: AsRef<PersonalDocument>>(&mut self, rental_agreement: T) -> &mut Self {
     self.inner.rental_agreement = rental_agreement.as_ref().clone();
@@ -900,6 +1005,9 @@ pub struct PassportElementTemporaryRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Temporary registration
   temporary_registration: PersonalDocument,
   
@@ -907,6 +1015,7 @@ pub struct PassportElementTemporaryRegistration {
 
 impl RObject for PassportElementTemporaryRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTemporaryRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -935,6 +1044,11 @@ pub struct RTDPassportElementTemporaryRegistrationBuilder {
 impl RTDPassportElementTemporaryRegistrationBuilder {
   pub fn build(&self) -> PassportElementTemporaryRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn temporary_registration<T: AsRef<PersonalDocument>>(&mut self, temporary_registration: T) -> &mut Self {
     self.inner.temporary_registration = temporary_registration.as_ref().clone();
@@ -963,6 +1077,9 @@ pub struct PassportElementUtilityBill {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Utility bill
   utility_bill: PersonalDocument,
   
@@ -970,6 +1087,7 @@ pub struct PassportElementUtilityBill {
 
 impl RObject for PassportElementUtilityBill {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementUtilityBill" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -998,6 +1116,11 @@ pub struct RTDPassportElementUtilityBillBuilder {
 impl RTDPassportElementUtilityBillBuilder {
   pub fn build(&self) -> PassportElementUtilityBill { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn utility_bill<T: AsRef<PersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
     self.inner.utility_bill = utility_bill.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct PassportElementError {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of the Telegram Passport element which has the error
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// Error message
@@ -22,6 +25,7 @@ pub struct PassportElementError {
 
 impl RObject for PassportElementError {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementError" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDPassportElementErrorBuilder {
 impl RTDPassportElementErrorBuilder {
   pub fn build(&self) -> PassportElementError { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -18,6 +18,9 @@ pub trait TDPassportElementErrorSource: Debug + RObject {}
 #[serde(untagged)]
 pub enum PassportElementErrorSource {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// One of the data fields contains an error. The error will be considered resolved when the value of the field changes
   DataField(PassportElementErrorSourceDataField),
   /// The file contains an error. The error will be considered resolved when the file changes
@@ -78,12 +81,29 @@ impl RObject for PassportElementErrorSource {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PassportElementErrorSource::DataField(t) => t.extra(),
+      PassportElementErrorSource::File(t) => t.extra(),
+      PassportElementErrorSource::Files(t) => t.extra(),
+      PassportElementErrorSource::FrontSide(t) => t.extra(),
+      PassportElementErrorSource::ReverseSide(t) => t.extra(),
+      PassportElementErrorSource::Selfie(t) => t.extra(),
+      PassportElementErrorSource::TranslationFile(t) => t.extra(),
+      PassportElementErrorSource::TranslationFiles(t) => t.extra(),
+      PassportElementErrorSource::Unspecified(t) => t.extra(),
+
+      PassportElementErrorSource::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PassportElementErrorSource {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElementErrorSource::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PassportElementErrorSource::Unknown { .. } = self { true } else { false } }
 
   pub fn is_data_field(&self) -> bool { if let PassportElementErrorSource::DataField(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let PassportElementErrorSource::File(_) = self { true } else { false } }
@@ -153,6 +173,9 @@ pub struct PassportElementErrorSourceDataField {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Field name
   field_name: String,
   
@@ -160,6 +183,7 @@ pub struct PassportElementErrorSourceDataField {
 
 impl RObject for PassportElementErrorSourceDataField {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceDataField" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -188,6 +212,11 @@ pub struct RTDPassportElementErrorSourceDataFieldBuilder {
 impl RTDPassportElementErrorSourceDataFieldBuilder {
   pub fn build(&self) -> PassportElementErrorSourceDataField { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
     self.inner.field_name = field_name.as_ref().to_string();
@@ -216,6 +245,9 @@ pub struct PassportElementErrorSourceFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Index of a file with the error
   file_index: i64,
   
@@ -223,6 +255,7 @@ pub struct PassportElementErrorSourceFile {
 
 impl RObject for PassportElementErrorSourceFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -251,6 +284,11 @@ pub struct RTDPassportElementErrorSourceFileBuilder {
 impl RTDPassportElementErrorSourceFileBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_index(&mut self, file_index: i64) -> &mut Self {
     self.inner.file_index = file_index;
@@ -279,11 +317,15 @@ pub struct PassportElementErrorSourceFiles {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementErrorSourceFiles {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceFiles" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -310,6 +352,11 @@ pub struct RTDPassportElementErrorSourceFilesBuilder {
 impl RTDPassportElementErrorSourceFilesBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFiles { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceFiles> for PassportElementErrorSourceFiles {
@@ -332,11 +379,15 @@ pub struct PassportElementErrorSourceFrontSide {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementErrorSourceFrontSide {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceFrontSide" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -363,6 +414,11 @@ pub struct RTDPassportElementErrorSourceFrontSideBuilder {
 impl RTDPassportElementErrorSourceFrontSideBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFrontSide { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceFrontSide> for PassportElementErrorSourceFrontSide {
@@ -385,11 +441,15 @@ pub struct PassportElementErrorSourceReverseSide {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementErrorSourceReverseSide {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceReverseSide" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -416,6 +476,11 @@ pub struct RTDPassportElementErrorSourceReverseSideBuilder {
 impl RTDPassportElementErrorSourceReverseSideBuilder {
   pub fn build(&self) -> PassportElementErrorSourceReverseSide { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceReverseSide> for PassportElementErrorSourceReverseSide {
@@ -438,11 +503,15 @@ pub struct PassportElementErrorSourceSelfie {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementErrorSourceSelfie {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceSelfie" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -469,6 +538,11 @@ pub struct RTDPassportElementErrorSourceSelfieBuilder {
 impl RTDPassportElementErrorSourceSelfieBuilder {
   pub fn build(&self) -> PassportElementErrorSourceSelfie { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceSelfie> for PassportElementErrorSourceSelfie {
@@ -491,6 +565,9 @@ pub struct PassportElementErrorSourceTranslationFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Index of a file with the error
   file_index: i64,
   
@@ -498,6 +575,7 @@ pub struct PassportElementErrorSourceTranslationFile {
 
 impl RObject for PassportElementErrorSourceTranslationFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceTranslationFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -526,6 +604,11 @@ pub struct RTDPassportElementErrorSourceTranslationFileBuilder {
 impl RTDPassportElementErrorSourceTranslationFileBuilder {
   pub fn build(&self) -> PassportElementErrorSourceTranslationFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_index(&mut self, file_index: i64) -> &mut Self {
     self.inner.file_index = file_index;
@@ -554,11 +637,15 @@ pub struct PassportElementErrorSourceTranslationFiles {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementErrorSourceTranslationFiles {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceTranslationFiles" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -585,6 +672,11 @@ pub struct RTDPassportElementErrorSourceTranslationFilesBuilder {
 impl RTDPassportElementErrorSourceTranslationFilesBuilder {
   pub fn build(&self) -> PassportElementErrorSourceTranslationFiles { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceTranslationFiles> for PassportElementErrorSourceTranslationFiles {
@@ -607,11 +699,15 @@ pub struct PassportElementErrorSourceUnspecified {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementErrorSourceUnspecified {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceUnspecified" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -638,6 +734,11 @@ pub struct RTDPassportElementErrorSourceUnspecifiedBuilder {
 impl RTDPassportElementErrorSourceUnspecifiedBuilder {
   pub fn build(&self) -> PassportElementErrorSourceUnspecified { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceUnspecified> for PassportElementErrorSourceUnspecified {
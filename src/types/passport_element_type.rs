@@ -18,6 +18,9 @@ pub trait TDPassportElementType: Debug + RObject {}
 #[serde(untagged)]
 pub enum PassportElementType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram Passport element containing the user's address
   Address(PassportElementTypeAddress),
   /// A Telegram Passport element containing the user's bank statement
@@ -94,12 +97,33 @@ impl RObject for PassportElementType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PassportElementType::Address(t) => t.extra(),
+      PassportElementType::BankStatement(t) => t.extra(),
+      PassportElementType::DriverLicense(t) => t.extra(),
+      PassportElementType::EmailAddress(t) => t.extra(),
+      PassportElementType::IdentityCard(t) => t.extra(),
+      PassportElementType::InternalPassport(t) => t.extra(),
+      PassportElementType::Passport(t) => t.extra(),
+      PassportElementType::PassportRegistration(t) => t.extra(),
+      PassportElementType::PersonalDetails(t) => t.extra(),
+      PassportElementType::PhoneNumber(t) => t.extra(),
+      PassportElementType::RentalAgreement(t) => t.extra(),
+      PassportElementType::TemporaryRegistration(t) => t.extra(),
+      PassportElementType::UtilityBill(t) => t.extra(),
+
+      PassportElementType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PassportElementType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElementType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PassportElementType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_address(&self) -> bool { if let PassportElementType::Address(_) = self { true } else { false } }
   pub fn is_bank_statement(&self) -> bool { if let PassportElementType::BankStatement(_) = self { true } else { false } }
@@ -189,11 +213,15 @@ pub struct PassportElementTypeAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -220,6 +248,11 @@ pub struct RTDPassportElementTypeAddressBuilder {
 impl RTDPassportElementTypeAddressBuilder {
   pub fn build(&self) -> PassportElementTypeAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeAddress> for PassportElementTypeAddress {
@@ -242,11 +275,15 @@ pub struct PassportElementTypeBankStatement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeBankStatement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeBankStatement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,6 +310,11 @@ pub struct RTDPassportElementTypeBankStatementBuilder {
 impl RTDPassportElementTypeBankStatementBuilder {
   pub fn build(&self) -> PassportElementTypeBankStatement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeBankStatement> for PassportElementTypeBankStatement {
@@ -295,11 +337,15 @@ pub struct PassportElementTypeDriverLicense {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeDriverLicense {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeDriverLicense" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -326,6 +372,11 @@ pub struct RTDPassportElementTypeDriverLicenseBuilder {
 impl RTDPassportElementTypeDriverLicenseBuilder {
   pub fn build(&self) -> PassportElementTypeDriverLicense { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeDriverLicense> for PassportElementTypeDriverLicense {
@@ -348,11 +399,15 @@ pub struct PassportElementTypeEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -379,6 +434,11 @@ pub struct RTDPassportElementTypeEmailAddressBuilder {
 impl RTDPassportElementTypeEmailAddressBuilder {
   pub fn build(&self) -> PassportElementTypeEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeEmailAddress> for PassportElementTypeEmailAddress {
@@ -401,11 +461,15 @@ pub struct PassportElementTypeIdentityCard {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeIdentityCard {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeIdentityCard" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -432,6 +496,11 @@ pub struct RTDPassportElementTypeIdentityCardBuilder {
 impl RTDPassportElementTypeIdentityCardBuilder {
   pub fn build(&self) -> PassportElementTypeIdentityCard { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeIdentityCard> for PassportElementTypeIdentityCard {
@@ -454,11 +523,15 @@ pub struct PassportElementTypeInternalPassport {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeInternalPassport {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeInternalPassport" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -485,6 +558,11 @@ pub struct RTDPassportElementTypeInternalPassportBuilder {
 impl RTDPassportElementTypeInternalPassportBuilder {
   pub fn build(&self) -> PassportElementTypeInternalPassport { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeInternalPassport> for PassportElementTypeInternalPassport {
@@ -507,11 +585,15 @@ pub struct PassportElementTypePassport {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypePassport {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePassport" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -538,6 +620,11 @@ pub struct RTDPassportElementTypePassportBuilder {
 impl RTDPassportElementTypePassportBuilder {
   pub fn build(&self) -> PassportElementTypePassport { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePassport> for PassportElementTypePassport {
@@ -560,11 +647,15 @@ pub struct PassportElementTypePassportRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypePassportRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePassportRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -591,6 +682,11 @@ pub struct RTDPassportElementTypePassportRegistrationBuilder {
 impl RTDPassportElementTypePassportRegistrationBuilder {
   pub fn build(&self) -> PassportElementTypePassportRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePassportRegistration> for PassportElementTypePassportRegistration {
@@ -613,11 +709,15 @@ pub struct PassportElementTypePersonalDetails {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypePersonalDetails {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePersonalDetails" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -644,6 +744,11 @@ pub struct RTDPassportElementTypePersonalDetailsBuilder {
 impl RTDPassportElementTypePersonalDetailsBuilder {
   pub fn build(&self) -> PassportElementTypePersonalDetails { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePersonalDetails> for PassportElementTypePersonalDetails {
@@ -666,11 +771,15 @@ pub struct PassportElementTypePhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypePhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -697,6 +806,11 @@ pub struct RTDPassportElementTypePhoneNumberBuilder {
 impl RTDPassportElementTypePhoneNumberBuilder {
   pub fn build(&self) -> PassportElementTypePhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePhoneNumber> for PassportElementTypePhoneNumber {
@@ -719,11 +833,15 @@ pub struct PassportElementTypeRentalAgreement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeRentalAgreement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeRentalAgreement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -750,6 +868,11 @@ pub struct RTDPassportElementTypeRentalAgreementBuilder {
 impl RTDPassportElementTypeRentalAgreementBuilder {
   pub fn build(&self) -> PassportElementTypeRentalAgreement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeRentalAgreement> for PassportElementTypeRentalAgreement {
@@ -772,11 +895,15 @@ pub struct PassportElementTypeTemporaryRegistration {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeTemporaryRegistration {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeTemporaryRegistration" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -803,6 +930,11 @@ pub struct RTDPassportElementTypeTemporaryRegistrationBuilder {
 impl RTDPassportElementTypeTemporaryRegistrationBuilder {
   pub fn build(&self) -> PassportElementTypeTemporaryRegistration { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeTemporaryRegistration> for PassportElementTypeTemporaryRegistration {
@@ -825,11 +957,15 @@ pub struct PassportElementTypeUtilityBill {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PassportElementTypeUtilityBill {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeUtilityBill" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -856,6 +992,11 @@ pub struct RTDPassportElementTypeUtilityBillBuilder {
 impl RTDPassportElementTypeUtilityBillBuilder {
   pub fn build(&self) -> PassportElementTypeUtilityBill { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeUtilityBill> for PassportElementTypeUtilityBill {
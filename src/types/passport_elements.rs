@@ -11,6 +11,9 @@ pub struct PassportElements {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Telegram Passport elements
   elements: Vec<PassportElement>,
   
@@ -18,6 +21,7 @@ pub struct PassportElements {
 
 impl RObject for PassportElements {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElements" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDPassportElementsBuilder {
 impl RTDPassportElementsBuilder {
   pub fn build(&self) -> PassportElements { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -11,6 +11,9 @@ pub struct PassportElementsWithErrors {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Telegram Passport elements
   elements: Vec<PassportElement>,
   /// Errors in the elements that are already available
@@ -20,6 +23,7 @@ pub struct PassportElementsWithErrors {
 
 impl RObject for PassportElementsWithErrors {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementsWithErrors" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDPassportElementsWithErrorsBuilder {
 impl RTDPassportElementsWithErrorsBuilder {
   pub fn build(&self) -> PassportElementsWithErrors { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
     self.inner.elements = elements;
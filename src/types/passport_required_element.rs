@@ -11,6 +11,9 @@ pub struct PassportRequiredElement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of Telegram Passport elements any of which is enough to provide
   suitable_elements: Vec<PassportSuitableElement>,
   
@@ -18,6 +21,7 @@ pub struct PassportRequiredElement {
 
 impl RObject for PassportRequiredElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportRequiredElement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDPassportRequiredElementBuilder {
 impl RTDPassportRequiredElementBuilder {
   pub fn build(&self) -> PassportRequiredElement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn suitable_elements(&mut self, suitable_elements: Vec<PassportSuitableElement>) -> &mut Self {
     self.inner.suitable_elements = suitable_elements;
@@ -11,6 +11,9 @@ pub struct PassportSuitableElement {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Type of the element
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// True, if a selfie is required with the identity document
@@ -24,6 +27,7 @@ pub struct PassportSuitableElement {
 
 impl RObject for PassportSuitableElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passportSuitableElement" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDPassportSuitableElementBuilder {
 impl RTDPassportSuitableElementBuilder {
   pub fn build(&self) -> PassportSuitableElement { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct PasswordState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if a 2-step verification password is set
   has_password: bool,
   /// Hint for the password; may be empty
@@ -26,6 +29,7 @@ pub struct PasswordState {
 
 impl RObject for PasswordState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "passwordState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDPasswordStateBuilder {
 impl RTDPasswordStateBuilder {
   pub fn build(&self) -> PasswordState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn has_password(&mut self, has_password: bool) -> &mut Self {
     self.inner.has_password = has_password;
@@ -11,6 +11,9 @@ pub struct PaymentForm {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Full information of the invoice
   invoice: Invoice,
   /// Payment form URL
@@ -30,6 +33,7 @@ pub struct PaymentForm {
 
 impl RObject for PaymentForm {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentForm" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -67,6 +71,11 @@ pub struct RTDPaymentFormBuilder {
 impl RTDPaymentFormBuilder {
   pub fn build(&self) -> PaymentForm { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
     self.inner.invoice = invoice.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct PaymentReceipt {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Point in time (Unix timestamp) when the payment was made
   date: i64,
   /// User identifier of the payment provider bot
@@ -28,6 +31,7 @@ pub struct PaymentReceipt {
 
 impl RObject for PaymentReceipt {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentReceipt" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -63,6 +67,11 @@ pub struct RTDPaymentReceiptBuilder {
 impl RTDPaymentReceiptBuilder {
   pub fn build(&self) -> PaymentReceipt { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
@@ -11,6 +11,9 @@ pub struct PaymentResult {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the payment request was successful; otherwise the verification_url will be not empty
   success: bool,
   /// URL for additional payment credentials verification
@@ -20,6 +23,7 @@ pub struct PaymentResult {
 
 impl RObject for PaymentResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentResult" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDPaymentResultBuilder {
 impl RTDPaymentResultBuilder {
   pub fn build(&self) -> PaymentResult { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn success(&mut self, success: bool) -> &mut Self {
     self.inner.success = success;
@@ -11,6 +11,9 @@ pub struct PaymentsProviderStripe {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Stripe API publishable key
   publishable_key: String,
   /// True, if the user country must be provided
@@ -24,6 +27,7 @@ pub struct PaymentsProviderStripe {
 
 impl RObject for PaymentsProviderStripe {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentsProviderStripe" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDPaymentsProviderStripeBuilder {
 impl RTDPaymentsProviderStripeBuilder {
   pub fn build(&self) -> PaymentsProviderStripe { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn publishable_key<T: AsRef<str>>(&mut self, publishable_key: T) -> &mut Self {
     self.inner.publishable_key = publishable_key.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct PersonalDetails {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// First name of the user written in English; 1-255 characters
   first_name: String,
   /// Middle name of the user written in English; 0-255 characters
@@ -36,6 +39,7 @@ pub struct PersonalDetails {
 
 impl RObject for PersonalDetails {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "personalDetails" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -79,6 +83,11 @@ pub struct RTDPersonalDetailsBuilder {
 impl RTDPersonalDetailsBuilder {
   pub fn build(&self) -> PersonalDetails { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
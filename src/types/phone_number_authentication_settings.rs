@@ -11,6 +11,9 @@ pub struct PhoneNumberAuthenticationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Pass true if the authentication code may be sent via flash call to the specified phone number
   allow_flash_call: bool,
   /// Pass true if the authenticated phone number is used on the current device
@@ -22,6 +25,7 @@ pub struct PhoneNumberAuthenticationSettings {
 
 impl RObject for PhoneNumberAuthenticationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "phoneNumberAuthenticationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDPhoneNumberAuthenticationSettingsBuilder {
 impl RTDPhoneNumberAuthenticationSettingsBuilder {
   pub fn build(&self) -> PhoneNumberAuthenticationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn allow_flash_call(&mut self, allow_flash_call: bool) -> &mut Self {
     self.inner.allow_flash_call = allow_flash_call;
@@ -11,6 +11,9 @@ pub struct Photo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if stickers were added to the photo. The list of corresponding sticker sets can be received using getAttachedStickerSets
   has_stickers: bool,
   /// Photo minithumbnail; may be null
@@ -22,6 +25,7 @@ pub struct Photo {
 
 impl RObject for Photo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "photo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDPhotoBuilder {
 impl RTDPhotoBuilder {
   pub fn build(&self) -> Photo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn has_stickers(&mut self, has_stickers: bool) -> &mut Self {
     self.inner.has_stickers = has_stickers;
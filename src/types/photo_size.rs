@@ -11,6 +11,9 @@ pub struct PhotoSize {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Thumbnail type (see https://core.telegram.org/constructor/photoSize)
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// Information about the photo file
@@ -24,6 +27,7 @@ pub struct PhotoSize {
 
 impl RObject for PhotoSize {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "photoSize" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDPhotoSizeBuilder {
 impl RTDPhotoSizeBuilder {
   pub fn build(&self) -> PhotoSize { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -11,8 +11,11 @@ pub struct Poll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique poll identifier
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Poll question, 1-300 characters
   question: String,
   /// List of poll answer options
@@ -36,6 +39,7 @@ pub struct Poll {
 
 impl RObject for Poll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "poll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -49,7 +53,7 @@ impl Poll {
     RTDPollBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn question(&self) -> &String { &self.question }
 
@@ -79,8 +83,13 @@ pub struct RTDPollBuilder {
 impl RTDPollBuilder {
   pub fn build(&self) -> Poll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -11,6 +11,9 @@ pub struct PollOption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Option text, 1-100 characters
   text: String,
   /// Number of voters for this option, available only for closed or voted polls
@@ -26,6 +29,7 @@ pub struct PollOption {
 
 impl RObject for PollOption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pollOption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDPollOptionBuilder {
 impl RTDPollOptionBuilder {
   pub fn build(&self) -> PollOption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDPollType: Debug + RObject {}
 #[serde(untagged)]
 pub enum PollType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A poll in quiz mode, which has exactly one correct answer option and can be answered only once
   Quiz(PollTypeQuiz),
   /// A regular poll
@@ -50,12 +53,22 @@ impl RObject for PollType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PollType::Quiz(t) => t.extra(),
+      PollType::Regular(t) => t.extra(),
+
+      PollType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PollType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PollType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PollType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_quiz(&self) -> bool { if let PollType::Quiz(_) = self { true } else { false } }
   pub fn is_regular(&self) -> bool { if let PollType::Regular(_) = self { true } else { false } }
@@ -90,6 +103,9 @@ pub struct PollTypeQuiz {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// 0-based identifier of the correct answer option; 1 for a yet unanswered poll
   correct_option_id: i64,
   /// Text that is shown when the user chooses an incorrect answer or taps on the lamp icon, 0-200 characters with at most 2 line feeds; empty for a yet unanswered poll
@@ -99,6 +115,7 @@ pub struct PollTypeQuiz {
 
 impl RObject for PollTypeQuiz {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pollTypeQuiz" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -129,6 +146,11 @@ pub struct RTDPollTypeQuizBuilder {
 impl RTDPollTypeQuizBuilder {
   pub fn build(&self) -> PollTypeQuiz { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn correct_option_id(&mut self, correct_option_id: i64) -> &mut Self {
     self.inner.correct_option_id = correct_option_id;
@@ -163,6 +185,9 @@ pub struct PollTypeRegular {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if multiple answer options can be chosen simultaneously
   allow_multiple_answers: bool,
   
@@ -170,6 +195,7 @@ pub struct PollTypeRegular {
 
 impl RObject for PollTypeRegular {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pollTypeRegular" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -198,6 +224,11 @@ pub struct RTDPollTypeRegularBuilder {
 impl RTDPollTypeRegularBuilder {
   pub fn build(&self) -> PollTypeRegular { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn allow_multiple_answers(&mut self, allow_multiple_answers: bool) -> &mut Self {
     self.inner.allow_multiple_answers = allow_multiple_answers;
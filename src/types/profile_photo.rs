@@ -11,8 +11,11 @@ pub struct ProfilePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Photo identifier; 0 for an empty photo. Can be used to find a photo in a list of user profile photos
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// A small (160x160) user profile photo. The file can be downloaded only before the photo is changed
   small: File,
   /// A big (640x640) user profile photo. The file can be downloaded only before the photo is changed
@@ -24,6 +27,7 @@ pub struct ProfilePhoto {
 
 impl RObject for ProfilePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "profilePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -37,7 +41,7 @@ impl ProfilePhoto {
     RTDProfilePhotoBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn small(&self) -> &File { &self.small }
 
@@ -55,8 +59,13 @@ pub struct RTDProfilePhotoBuilder {
 impl RTDProfilePhotoBuilder {
   pub fn build(&self) -> ProfilePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
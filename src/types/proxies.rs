@@ -11,6 +11,9 @@ pub struct Proxies {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of proxy servers
   proxies: Vec<Proxy>,
   
@@ -18,6 +21,7 @@ pub struct Proxies {
 
 impl RObject for Proxies {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "proxies" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDProxiesBuilder {
 impl RTDProxiesBuilder {
   pub fn build(&self) -> Proxies { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxies(&mut self, proxies: Vec<Proxy>) -> &mut Self {
     self.inner.proxies = proxies;
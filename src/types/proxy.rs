@@ -11,6 +11,9 @@ pub struct Proxy {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the proxy
   id: i64,
   /// Proxy server IP address
@@ -28,6 +31,7 @@ pub struct Proxy {
 
 impl RObject for Proxy {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "proxy" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -63,6 +67,11 @@ pub struct RTDProxyBuilder {
 impl RTDProxyBuilder {
   pub fn build(&self) -> Proxy { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
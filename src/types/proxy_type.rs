@@ -18,6 +18,9 @@ pub trait TDProxyType: Debug + RObject {}
 #[serde(untagged)]
 pub enum ProxyType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A HTTP transparent proxy server
   Http(ProxyTypeHttp),
   /// An MTProto proxy server
@@ -54,12 +57,23 @@ impl RObject for ProxyType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ProxyType::Http(t) => t.extra(),
+      ProxyType::Mtproto(t) => t.extra(),
+      ProxyType::Socks5(t) => t.extra(),
+
+      ProxyType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ProxyType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ProxyType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ProxyType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_http(&self) -> bool { if let ProxyType::Http(_) = self { true } else { false } }
   pub fn is_mtproto(&self) -> bool { if let ProxyType::Mtproto(_) = self { true } else { false } }
@@ -99,6 +113,9 @@ pub struct ProxyTypeHttp {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Username for logging in; may be empty
   username: String,
   /// Password for logging in; may be empty
@@ -110,6 +127,7 @@ pub struct ProxyTypeHttp {
 
 impl RObject for ProxyTypeHttp {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "proxyTypeHttp" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -142,6 +160,11 @@ pub struct RTDProxyTypeHttpBuilder {
 impl RTDProxyTypeHttpBuilder {
   pub fn build(&self) -> ProxyTypeHttp { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -182,6 +205,9 @@ pub struct ProxyTypeMtproto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The proxy's secret in hexadecimal encoding
   secret: String,
   
@@ -189,6 +215,7 @@ pub struct ProxyTypeMtproto {
 
 impl RObject for ProxyTypeMtproto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "proxyTypeMtproto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -217,6 +244,11 @@ pub struct RTDProxyTypeMtprotoBuilder {
 impl RTDProxyTypeMtprotoBuilder {
   pub fn build(&self) -> ProxyTypeMtproto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
     self.inner.secret = secret.as_ref().to_string();
@@ -245,6 +277,9 @@ pub struct ProxyTypeSocks5 {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Username for logging in; may be empty
   username: String,
   /// Password for logging in; may be empty
@@ -254,6 +289,7 @@ pub struct ProxyTypeSocks5 {
 
 impl RObject for ProxyTypeSocks5 {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "proxyTypeSocks5" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -284,6 +320,11 @@ pub struct RTDProxyTypeSocks5Builder {
 impl RTDProxyTypeSocks5Builder {
   pub fn build(&self) -> ProxyTypeSocks5 { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDPublicChatType: Debug + RObject {}
 #[serde(untagged)]
 pub enum PublicChatType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat is public, because it has username
   HasUsername(PublicChatTypeHasUsername),
   /// The chat is public, because it is a location-based supergroup
@@ -50,12 +53,22 @@ impl RObject for PublicChatType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PublicChatType::HasUsername(t) => t.extra(),
+      PublicChatType::IsLocationBased(t) => t.extra(),
+
+      PublicChatType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PublicChatType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PublicChatType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PublicChatType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_has_username(&self) -> bool { if let PublicChatType::HasUsername(_) = self { true } else { false } }
   pub fn is_is_location_based(&self) -> bool { if let PublicChatType::IsLocationBased(_) = self { true } else { false } }
@@ -90,11 +103,15 @@ pub struct PublicChatTypeHasUsername {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PublicChatTypeHasUsername {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "publicChatTypeHasUsername" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,6 +138,11 @@ pub struct RTDPublicChatTypeHasUsernameBuilder {
 impl RTDPublicChatTypeHasUsernameBuilder {
   pub fn build(&self) -> PublicChatTypeHasUsername { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PublicChatTypeHasUsername> for PublicChatTypeHasUsername {
@@ -143,11 +165,15 @@ pub struct PublicChatTypeIsLocationBased {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PublicChatTypeIsLocationBased {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "publicChatTypeIsLocationBased" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -174,6 +200,11 @@ pub struct RTDPublicChatTypeIsLocationBasedBuilder {
 impl RTDPublicChatTypeIsLocationBasedBuilder {
   pub fn build(&self) -> PublicChatTypeIsLocationBased { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PublicChatTypeIsLocationBased> for PublicChatTypeIsLocationBased {
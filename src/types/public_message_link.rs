@@ -11,6 +11,9 @@ pub struct PublicMessageLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message link
   link: String,
   /// HTML-code for embedding the message
@@ -20,6 +23,7 @@ pub struct PublicMessageLink {
 
 impl RObject for PublicMessageLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "publicMessageLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDPublicMessageLinkBuilder {
 impl RTDPublicMessageLinkBuilder {
   pub fn build(&self) -> PublicMessageLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
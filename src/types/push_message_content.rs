@@ -18,6 +18,9 @@ pub trait TDPushMessageContent: Debug + RObject {}
 #[serde(untagged)]
 pub enum PushMessageContent {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation message (GIF-style).
   Animation(PushMessageContentAnimation),
   /// An audio message
@@ -146,12 +149,46 @@ impl RObject for PushMessageContent {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      PushMessageContent::Animation(t) => t.extra(),
+      PushMessageContent::Audio(t) => t.extra(),
+      PushMessageContent::BasicGroupChatCreate(t) => t.extra(),
+      PushMessageContent::ChatAddMembers(t) => t.extra(),
+      PushMessageContent::ChatChangePhoto(t) => t.extra(),
+      PushMessageContent::ChatChangeTitle(t) => t.extra(),
+      PushMessageContent::ChatDeleteMember(t) => t.extra(),
+      PushMessageContent::ChatJoinByLink(t) => t.extra(),
+      PushMessageContent::Contact(t) => t.extra(),
+      PushMessageContent::ContactRegistered(t) => t.extra(),
+      PushMessageContent::Document(t) => t.extra(),
+      PushMessageContent::Game(t) => t.extra(),
+      PushMessageContent::GameScore(t) => t.extra(),
+      PushMessageContent::Hidden(t) => t.extra(),
+      PushMessageContent::Invoice(t) => t.extra(),
+      PushMessageContent::Location(t) => t.extra(),
+      PushMessageContent::MediaAlbum(t) => t.extra(),
+      PushMessageContent::MessageForwards(t) => t.extra(),
+      PushMessageContent::Photo(t) => t.extra(),
+      PushMessageContent::Poll(t) => t.extra(),
+      PushMessageContent::ScreenshotTaken(t) => t.extra(),
+      PushMessageContent::Sticker(t) => t.extra(),
+      PushMessageContent::Text(t) => t.extra(),
+      PushMessageContent::Video(t) => t.extra(),
+      PushMessageContent::VideoNote(t) => t.extra(),
+      PushMessageContent::VoiceNote(t) => t.extra(),
+
+      PushMessageContent::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl PushMessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PushMessageContent::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let PushMessageContent::Unknown { .. } = self { true } else { false } }
 
   pub fn is_animation(&self) -> bool { if let PushMessageContent::Animation(_) = self { true } else { false } }
   pub fn is_audio(&self) -> bool { if let PushMessageContent::Audio(_) = self { true } else { false } }
@@ -306,6 +343,9 @@ pub struct PushMessageContentAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   animation: Option<Animation>,
   /// Animation caption
@@ -317,6 +357,7 @@ pub struct PushMessageContentAnimation {
 
 impl RObject for PushMessageContentAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -349,6 +390,11 @@ pub struct RTDPushMessageContentAnimationBuilder {
 impl RTDPushMessageContentAnimationBuilder {
   pub fn build(&self) -> PushMessageContentAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = Some(animation.as_ref().clone());
@@ -389,6 +435,9 @@ pub struct PushMessageContentAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   audio: Option<Audio>,
   /// True, if the message is a pinned message with the specified content
@@ -398,6 +447,7 @@ pub struct PushMessageContentAudio {
 
 impl RObject for PushMessageContentAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -428,6 +478,11 @@ pub struct RTDPushMessageContentAudioBuilder {
 impl RTDPushMessageContentAudioBuilder {
   pub fn build(&self) -> PushMessageContentAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = Some(audio.as_ref().clone());
@@ -462,11 +517,15 @@ pub struct PushMessageContentBasicGroupChatCreate {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PushMessageContentBasicGroupChatCreate {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentBasicGroupChatCreate" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -493,6 +552,11 @@ pub struct RTDPushMessageContentBasicGroupChatCreateBuilder {
 impl RTDPushMessageContentBasicGroupChatCreateBuilder {
   pub fn build(&self) -> PushMessageContentBasicGroupChatCreate { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentBasicGroupChatCreate> for PushMessageContentBasicGroupChatCreate {
@@ -515,6 +579,9 @@ pub struct PushMessageContentChatAddMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Name of the added member
   member_name: String,
   /// True, if the current user was added to the group
@@ -526,6 +593,7 @@ pub struct PushMessageContentChatAddMembers {
 
 impl RObject for PushMessageContentChatAddMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatAddMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -558,6 +626,11 @@ pub struct RTDPushMessageContentChatAddMembersBuilder {
 impl RTDPushMessageContentChatAddMembersBuilder {
   pub fn build(&self) -> PushMessageContentChatAddMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
     self.inner.member_name = member_name.as_ref().to_string();
@@ -598,11 +671,15 @@ pub struct PushMessageContentChatChangePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PushMessageContentChatChangePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatChangePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -629,6 +706,11 @@ pub struct RTDPushMessageContentChatChangePhotoBuilder {
 impl RTDPushMessageContentChatChangePhotoBuilder {
   pub fn build(&self) -> PushMessageContentChatChangePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentChatChangePhoto> for PushMessageContentChatChangePhoto {
@@ -651,6 +733,9 @@ pub struct PushMessageContentChatChangeTitle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New chat title
   title: String,
   
@@ -658,6 +743,7 @@ pub struct PushMessageContentChatChangeTitle {
 
 impl RObject for PushMessageContentChatChangeTitle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatChangeTitle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -686,6 +772,11 @@ pub struct RTDPushMessageContentChatChangeTitleBuilder {
 impl RTDPushMessageContentChatChangeTitleBuilder {
   pub fn build(&self) -> PushMessageContentChatChangeTitle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -714,6 +805,9 @@ pub struct PushMessageContentChatDeleteMember {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Name of the deleted member
   member_name: String,
   /// True, if the current user was deleted from the group
@@ -725,6 +819,7 @@ pub struct PushMessageContentChatDeleteMember {
 
 impl RObject for PushMessageContentChatDeleteMember {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatDeleteMember" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -757,6 +852,11 @@ pub struct RTDPushMessageContentChatDeleteMemberBuilder {
 impl RTDPushMessageContentChatDeleteMemberBuilder {
   pub fn build(&self) -> PushMessageContentChatDeleteMember { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
     self.inner.member_name = member_name.as_ref().to_string();
@@ -797,11 +897,15 @@ pub struct PushMessageContentChatJoinByLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PushMessageContentChatJoinByLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatJoinByLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -828,6 +932,11 @@ pub struct RTDPushMessageContentChatJoinByLinkBuilder {
 impl RTDPushMessageContentChatJoinByLinkBuilder {
   pub fn build(&self) -> PushMessageContentChatJoinByLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentChatJoinByLink> for PushMessageContentChatJoinByLink {
@@ -850,6 +959,9 @@ pub struct PushMessageContentContact {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Contact's name
   name: String,
   /// True, if the message is a pinned message with the specified content
@@ -859,6 +971,7 @@ pub struct PushMessageContentContact {
 
 impl RObject for PushMessageContentContact {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentContact" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -889,6 +1002,11 @@ pub struct RTDPushMessageContentContactBuilder {
 impl RTDPushMessageContentContactBuilder {
   pub fn build(&self) -> PushMessageContentContact { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -923,11 +1041,15 @@ pub struct PushMessageContentContactRegistered {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PushMessageContentContactRegistered {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentContactRegistered" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -954,6 +1076,11 @@ pub struct RTDPushMessageContentContactRegisteredBuilder {
 impl RTDPushMessageContentContactRegisteredBuilder {
   pub fn build(&self) -> PushMessageContentContactRegistered { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentContactRegistered> for PushMessageContentContactRegistered {
@@ -976,6 +1103,9 @@ pub struct PushMessageContentDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   document: Option<Document>,
   /// True, if the message is a pinned message with the specified content
@@ -985,6 +1115,7 @@ pub struct PushMessageContentDocument {
 
 impl RObject for PushMessageContentDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1015,6 +1146,11 @@ pub struct RTDPushMessageContentDocumentBuilder {
 impl RTDPushMessageContentDocumentBuilder {
   pub fn build(&self) -> PushMessageContentDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = Some(document.as_ref().clone());
@@ -1049,6 +1185,9 @@ pub struct PushMessageContentGame {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Game title, empty for pinned game message
   title: String,
   /// True, if the message is a pinned message with the specified content
@@ -1058,6 +1197,7 @@ pub struct PushMessageContentGame {
 
 impl RObject for PushMessageContentGame {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentGame" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1088,6 +1228,11 @@ pub struct RTDPushMessageContentGameBuilder {
 impl RTDPushMessageContentGameBuilder {
   pub fn build(&self) -> PushMessageContentGame { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1122,6 +1267,9 @@ pub struct PushMessageContentGameScore {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Game title, empty for pinned message
   title: String,
   /// New score, 0 for pinned message
@@ -1133,6 +1281,7 @@ pub struct PushMessageContentGameScore {
 
 impl RObject for PushMessageContentGameScore {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentGameScore" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1165,6 +1314,11 @@ pub struct RTDPushMessageContentGameScoreBuilder {
 impl RTDPushMessageContentGameScoreBuilder {
   pub fn build(&self) -> PushMessageContentGameScore { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1205,6 +1359,9 @@ pub struct PushMessageContentHidden {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the message is a pinned message with the specified content
   is_pinned: bool,
   
@@ -1212,6 +1369,7 @@ pub struct PushMessageContentHidden {
 
 impl RObject for PushMessageContentHidden {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentHidden" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1240,6 +1398,11 @@ pub struct RTDPushMessageContentHiddenBuilder {
 impl RTDPushMessageContentHiddenBuilder {
   pub fn build(&self) -> PushMessageContentHidden { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_pinned(&mut self, is_pinned: bool) -> &mut Self {
     self.inner.is_pinned = is_pinned;
@@ -1268,6 +1431,9 @@ pub struct PushMessageContentInvoice {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Product price
   price: String,
   /// True, if the message is a pinned message with the specified content
@@ -1277,6 +1443,7 @@ pub struct PushMessageContentInvoice {
 
 impl RObject for PushMessageContentInvoice {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentInvoice" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1307,6 +1474,11 @@ pub struct RTDPushMessageContentInvoiceBuilder {
 impl RTDPushMessageContentInvoiceBuilder {
   pub fn build(&self) -> PushMessageContentInvoice { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn price<T: AsRef<str>>(&mut self, price: T) -> &mut Self {
     self.inner.price = price.as_ref().to_string();
@@ -1341,6 +1513,9 @@ pub struct PushMessageContentLocation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the location is live
   is_live: bool,
   /// True, if the message is a pinned message with the specified content
@@ -1350,6 +1525,7 @@ pub struct PushMessageContentLocation {
 
 impl RObject for PushMessageContentLocation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentLocation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1380,6 +1556,11 @@ pub struct RTDPushMessageContentLocationBuilder {
 impl RTDPushMessageContentLocationBuilder {
   pub fn build(&self) -> PushMessageContentLocation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_live(&mut self, is_live: bool) -> &mut Self {
     self.inner.is_live = is_live;
@@ -1414,6 +1595,9 @@ pub struct PushMessageContentMediaAlbum {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Number of messages in the album
   total_count: i64,
   /// True, if the album has at least one photo
@@ -1425,6 +1609,7 @@ pub struct PushMessageContentMediaAlbum {
 
 impl RObject for PushMessageContentMediaAlbum {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentMediaAlbum" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1457,6 +1642,11 @@ pub struct RTDPushMessageContentMediaAlbumBuilder {
 impl RTDPushMessageContentMediaAlbumBuilder {
   pub fn build(&self) -> PushMessageContentMediaAlbum { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -1497,6 +1687,9 @@ pub struct PushMessageContentMessageForwards {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Number of forwarded messages
   total_count: i64,
   
@@ -1504,6 +1697,7 @@ pub struct PushMessageContentMessageForwards {
 
 impl RObject for PushMessageContentMessageForwards {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentMessageForwards" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1532,6 +1726,11 @@ pub struct RTDPushMessageContentMessageForwardsBuilder {
 impl RTDPushMessageContentMessageForwardsBuilder {
   pub fn build(&self) -> PushMessageContentMessageForwards { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -1560,6 +1759,9 @@ pub struct PushMessageContentPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   photo: Option<Photo>,
   /// Photo caption
@@ -1573,6 +1775,7 @@ pub struct PushMessageContentPhoto {
 
 impl RObject for PushMessageContentPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1607,6 +1810,11 @@ pub struct RTDPushMessageContentPhotoBuilder {
 impl RTDPushMessageContentPhotoBuilder {
   pub fn build(&self) -> PushMessageContentPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -1653,6 +1861,9 @@ pub struct PushMessageContentPoll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Poll question
   question: String,
   /// True, if the poll is regular and not in quiz mode
@@ -1664,6 +1875,7 @@ pub struct PushMessageContentPoll {
 
 impl RObject for PushMessageContentPoll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentPoll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1696,6 +1908,11 @@ pub struct RTDPushMessageContentPollBuilder {
 impl RTDPushMessageContentPollBuilder {
   pub fn build(&self) -> PushMessageContentPoll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
@@ -1736,11 +1953,15 @@ pub struct PushMessageContentScreenshotTaken {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for PushMessageContentScreenshotTaken {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentScreenshotTaken" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1767,6 +1988,11 @@ pub struct RTDPushMessageContentScreenshotTakenBuilder {
 impl RTDPushMessageContentScreenshotTakenBuilder {
   pub fn build(&self) -> PushMessageContentScreenshotTaken { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentScreenshotTaken> for PushMessageContentScreenshotTaken {
@@ -1789,6 +2015,9 @@ pub struct PushMessageContentSticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   sticker: Option<Sticker>,
   /// Emoji corresponding to the sticker; may be empty
@@ -1800,6 +2029,7 @@ pub struct PushMessageContentSticker {
 
 impl RObject for PushMessageContentSticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentSticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1832,6 +2062,11 @@ pub struct RTDPushMessageContentStickerBuilder {
 impl RTDPushMessageContentStickerBuilder {
   pub fn build(&self) -> PushMessageContentSticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = Some(sticker.as_ref().clone());
@@ -1872,6 +2107,9 @@ pub struct PushMessageContentText {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message text
   text: String,
   /// True, if the message is a pinned message with the specified content
@@ -1881,6 +2119,7 @@ pub struct PushMessageContentText {
 
 impl RObject for PushMessageContentText {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentText" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1911,6 +2150,11 @@ pub struct RTDPushMessageContentTextBuilder {
 impl RTDPushMessageContentTextBuilder {
   pub fn build(&self) -> PushMessageContentText { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -1945,6 +2189,9 @@ pub struct PushMessageContentVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   video: Option<Video>,
   /// Video caption
@@ -1958,6 +2205,7 @@ pub struct PushMessageContentVideo {
 
 impl RObject for PushMessageContentVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1992,6 +2240,11 @@ pub struct RTDPushMessageContentVideoBuilder {
 impl RTDPushMessageContentVideoBuilder {
   pub fn build(&self) -> PushMessageContentVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = Some(video.as_ref().clone());
@@ -2038,6 +2291,9 @@ pub struct PushMessageContentVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   video_note: Option<VideoNote>,
   /// True, if the message is a pinned message with the specified content
@@ -2047,6 +2303,7 @@ pub struct PushMessageContentVideoNote {
 
 impl RObject for PushMessageContentVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2077,6 +2334,11 @@ pub struct RTDPushMessageContentVideoNoteBuilder {
 impl RTDPushMessageContentVideoNoteBuilder {
   pub fn build(&self) -> PushMessageContentVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = Some(video_note.as_ref().clone());
@@ -2111,6 +2373,9 @@ pub struct PushMessageContentVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Message content; may be null
   voice_note: Option<VoiceNote>,
   /// True, if the message is a pinned message with the specified content
@@ -2120,6 +2385,7 @@ pub struct PushMessageContentVoiceNote {
 
 impl RObject for PushMessageContentVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2150,6 +2416,11 @@ pub struct RTDPushMessageContentVoiceNoteBuilder {
 impl RTDPushMessageContentVoiceNoteBuilder {
   pub fn build(&self) -> PushMessageContentVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -11,13 +11,17 @@ pub struct PushReceiverId {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The globally unique identifier of push notification subscription
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   
 }
 
 impl RObject for PushReceiverId {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "pushReceiverId" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -31,7 +35,7 @@ impl PushReceiverId {
     RTDPushReceiverIdBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
 }
 
@@ -43,8 +47,13 @@ pub struct RTDPushReceiverIdBuilder {
 impl RTDPushReceiverIdBuilder {
   pub fn build(&self) -> PushReceiverId { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
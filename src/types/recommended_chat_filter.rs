@@ -11,6 +11,9 @@ pub struct RecommendedChatFilter {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat filter
   filter: ChatFilter,
   /// Describes a recommended chat filter
@@ -20,6 +23,7 @@ pub struct RecommendedChatFilter {
 
 impl RObject for RecommendedChatFilter {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "recommendedChatFilter" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDRecommendedChatFilterBuilder {
 impl RTDRecommendedChatFilterBuilder {
   pub fn build(&self) -> RecommendedChatFilter { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
@@ -11,6 +11,9 @@ pub struct RecommendedChatFilters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of recommended chat filters
   chat_filters: Vec<RecommendedChatFilter>,
   
@@ -18,6 +21,7 @@ pub struct RecommendedChatFilters {
 
 impl RObject for RecommendedChatFilters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "recommendedChatFilters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDRecommendedChatFiltersBuilder {
 impl RTDRecommendedChatFiltersBuilder {
   pub fn build(&self) -> RecommendedChatFilters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filters(&mut self, chat_filters: Vec<RecommendedChatFilter>) -> &mut Self {
     self.inner.chat_filters = chat_filters;
@@ -11,6 +11,9 @@ pub struct RecoveryEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Recovery email address
   recovery_email_address: String,
   
@@ -18,6 +21,7 @@ pub struct RecoveryEmailAddress {
 
 impl RObject for RecoveryEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "recoveryEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDRecoveryEmailAddressBuilder {
 impl RTDRecoveryEmailAddressBuilder {
   pub fn build(&self) -> RecoveryEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn recovery_email_address<T: AsRef<str>>(&mut self, recovery_email_address: T) -> &mut Self {
     self.inner.recovery_email_address = recovery_email_address.as_ref().to_string();
@@ -18,6 +18,9 @@ pub trait TDReplyMarkup: Debug + RObject {}
 #[serde(untagged)]
 pub enum ReplyMarkup {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Instructs application to force a reply to this message
   ForceReply(ReplyMarkupForceReply),
   /// Contains an inline keyboard layout
@@ -58,12 +61,24 @@ impl RObject for ReplyMarkup {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ReplyMarkup::ForceReply(t) => t.extra(),
+      ReplyMarkup::InlineKeyboard(t) => t.extra(),
+      ReplyMarkup::RemoveKeyboard(t) => t.extra(),
+      ReplyMarkup::ShowKeyboard(t) => t.extra(),
+
+      ReplyMarkup::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ReplyMarkup::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ReplyMarkup::Unknown { .. } = self { true } else { false } }
 
   pub fn is_force_reply(&self) -> bool { if let ReplyMarkup::ForceReply(_) = self { true } else { false } }
   pub fn is_inline_keyboard(&self) -> bool { if let ReplyMarkup::InlineKeyboard(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct ReplyMarkupForceReply {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if a forced reply must automatically be shown to the current user. For outgoing messages, specify true to show the forced reply only for the mentioned users and for the target user of a reply
   is_personal: bool,
   
@@ -115,6 +133,7 @@ pub struct ReplyMarkupForceReply {
 
 impl RObject for ReplyMarkupForceReply {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupForceReply" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +162,11 @@ pub struct RTDReplyMarkupForceReplyBuilder {
 impl RTDReplyMarkupForceReplyBuilder {
   pub fn build(&self) -> ReplyMarkupForceReply { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
     self.inner.is_personal = is_personal;
@@ -171,6 +195,9 @@ pub struct ReplyMarkupInlineKeyboard {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A list of rows of inline keyboard buttons
   rows: Vec<Vec<InlineKeyboardButton>>,
   
@@ -178,6 +205,7 @@ pub struct ReplyMarkupInlineKeyboard {
 
 impl RObject for ReplyMarkupInlineKeyboard {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupInlineKeyboard" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -206,6 +234,11 @@ pub struct RTDReplyMarkupInlineKeyboardBuilder {
 impl RTDReplyMarkupInlineKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupInlineKeyboard { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rows(&mut self, rows: Vec<Vec<InlineKeyboardButton>>) -> &mut Self {
     self.inner.rows = rows;
@@ -234,6 +267,9 @@ pub struct ReplyMarkupRemoveKeyboard {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the keyboard is removed only for the mentioned users or the target user of a reply
   is_personal: bool,
   
@@ -241,6 +277,7 @@ pub struct ReplyMarkupRemoveKeyboard {
 
 impl RObject for ReplyMarkupRemoveKeyboard {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupRemoveKeyboard" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -269,6 +306,11 @@ pub struct RTDReplyMarkupRemoveKeyboardBuilder {
 impl RTDReplyMarkupRemoveKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupRemoveKeyboard { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
     self.inner.is_personal = is_personal;
@@ -297,6 +339,9 @@ pub struct ReplyMarkupShowKeyboard {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A list of rows of bot keyboard buttons
   rows: Vec<Vec<KeyboardButton>>,
   /// True, if the application needs to resize the keyboard vertically
@@ -310,6 +355,7 @@ pub struct ReplyMarkupShowKeyboard {
 
 impl RObject for ReplyMarkupShowKeyboard {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupShowKeyboard" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -344,6 +390,11 @@ pub struct RTDReplyMarkupShowKeyboardBuilder {
 impl RTDReplyMarkupShowKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupShowKeyboard { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rows(&mut self, rows: Vec<Vec<KeyboardButton>>) -> &mut Self {
     self.inner.rows = rows;
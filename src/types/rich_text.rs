@@ -18,6 +18,9 @@ pub trait TDRichText: Debug + RObject {}
 #[serde(untagged)]
 pub enum RichText {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An anchor
   Anchor(RichTextAnchor),
   /// A link to an anchor on the same web page
@@ -110,12 +113,37 @@ impl RObject for RichText {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      RichText::Anchor(t) => t.extra(),
+      RichText::AnchorLink(t) => t.extra(),
+      RichText::Bold(t) => t.extra(),
+      RichText::EmailAddress(t) => t.extra(),
+      RichText::Fixed(t) => t.extra(),
+      RichText::Icon(t) => t.extra(),
+      RichText::Italic(t) => t.extra(),
+      RichText::Marked(t) => t.extra(),
+      RichText::PhoneNumber(t) => t.extra(),
+      RichText::Plain(t) => t.extra(),
+      RichText::Reference(t) => t.extra(),
+      RichText::Strikethrough(t) => t.extra(),
+      RichText::Subscript(t) => t.extra(),
+      RichText::Superscript(t) => t.extra(),
+      RichText::Underline(t) => t.extra(),
+      RichText::Url(t) => t.extra(),
+      RichText::RichTexts(t) => t.extra(),
+
+      RichText::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl RichText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let RichText::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let RichText::Unknown { .. } = self { true } else { false } }
 
   pub fn is_anchor(&self) -> bool { if let RichText::Anchor(_) = self { true } else { false } }
   pub fn is_anchor_link(&self) -> bool { if let RichText::AnchorLink(_) = self { true } else { false } }
@@ -225,6 +253,9 @@ pub struct RichTextAnchor {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Anchor name
   name: String,
   
@@ -232,6 +263,7 @@ pub struct RichTextAnchor {
 
 impl RObject for RichTextAnchor {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextAnchor" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -260,6 +292,11 @@ pub struct RTDRichTextAnchorBuilder {
 impl RTDRichTextAnchorBuilder {
   pub fn build(&self) -> RichTextAnchor { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -288,6 +325,9 @@ pub struct RichTextAnchorLink {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The link text
   text: Box<RichText>,
   /// The anchor name. If the name is empty, the link should bring back to top
@@ -299,6 +339,7 @@ pub struct RichTextAnchorLink {
 
 impl RObject for RichTextAnchorLink {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextAnchorLink" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -331,6 +372,11 @@ pub struct RTDRichTextAnchorLinkBuilder {
 impl RTDRichTextAnchorLinkBuilder {
   pub fn build(&self) -> RichTextAnchorLink { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -371,6 +417,9 @@ pub struct RichTextBold {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -378,6 +427,7 @@ pub struct RichTextBold {
 
 impl RObject for RichTextBold {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextBold" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -406,6 +456,11 @@ pub struct RTDRichTextBoldBuilder {
 impl RTDRichTextBoldBuilder {
   pub fn build(&self) -> RichTextBold { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -434,6 +489,9 @@ pub struct RichTextEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   /// Email address
@@ -443,6 +501,7 @@ pub struct RichTextEmailAddress {
 
 impl RObject for RichTextEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -473,6 +532,11 @@ pub struct RTDRichTextEmailAddressBuilder {
 impl RTDRichTextEmailAddressBuilder {
   pub fn build(&self) -> RichTextEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -507,6 +571,9 @@ pub struct RichTextFixed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -514,6 +581,7 @@ pub struct RichTextFixed {
 
 impl RObject for RichTextFixed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextFixed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -542,6 +610,11 @@ pub struct RTDRichTextFixedBuilder {
 impl RTDRichTextFixedBuilder {
   pub fn build(&self) -> RichTextFixed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -570,6 +643,9 @@ pub struct RichTextIcon {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The image represented as a document. The image can be in GIF, JPEG or PNG format
   document: Document,
   /// Width of a bounding box in which the image should be shown; 0 if unknown
@@ -581,6 +657,7 @@ pub struct RichTextIcon {
 
 impl RObject for RichTextIcon {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextIcon" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -613,6 +690,11 @@ pub struct RTDRichTextIconBuilder {
 impl RTDRichTextIconBuilder {
   pub fn build(&self) -> RichTextIcon { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -653,6 +735,9 @@ pub struct RichTextItalic {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -660,6 +745,7 @@ pub struct RichTextItalic {
 
 impl RObject for RichTextItalic {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextItalic" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -688,6 +774,11 @@ pub struct RTDRichTextItalicBuilder {
 impl RTDRichTextItalicBuilder {
   pub fn build(&self) -> RichTextItalic { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -716,6 +807,9 @@ pub struct RichTextMarked {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -723,6 +817,7 @@ pub struct RichTextMarked {
 
 impl RObject for RichTextMarked {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextMarked" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -751,6 +846,11 @@ pub struct RTDRichTextMarkedBuilder {
 impl RTDRichTextMarkedBuilder {
   pub fn build(&self) -> RichTextMarked { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -779,6 +879,9 @@ pub struct RichTextPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   /// Phone number
@@ -788,6 +891,7 @@ pub struct RichTextPhoneNumber {
 
 impl RObject for RichTextPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -818,6 +922,11 @@ pub struct RTDRichTextPhoneNumberBuilder {
 impl RTDRichTextPhoneNumberBuilder {
   pub fn build(&self) -> RichTextPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -852,13 +961,17 @@ pub struct RichTextPlain {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
-  text: Box<RichText>,
+  text: String,
   
 }
 
 impl RObject for RichTextPlain {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextPlain" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -875,7 +988,7 @@ impl RichTextPlain {
     RTDRichTextPlainBuilder { inner }
   }
 
-  pub fn text(&self) -> &Box<RichText> { &self.text }
+  pub fn text(&self) -> &String { &self.text }
 
 }
 
@@ -887,9 +1000,14 @@ pub struct RTDRichTextPlainBuilder {
 impl RTDRichTextPlainBuilder {
   pub fn build(&self) -> RichTextPlain { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-    self.inner.text = text.as_ref().clone();
+  pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().to_string();
     self
   }
 
@@ -915,6 +1033,9 @@ pub struct RichTextReference {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The text
   text: Box<RichText>,
   /// The text to show on click
@@ -926,6 +1047,7 @@ pub struct RichTextReference {
 
 impl RObject for RichTextReference {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextReference" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -958,6 +1080,11 @@ pub struct RTDRichTextReferenceBuilder {
 impl RTDRichTextReferenceBuilder {
   pub fn build(&self) -> RichTextReference { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -998,6 +1125,9 @@ pub struct RichTextStrikethrough {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -1005,6 +1135,7 @@ pub struct RichTextStrikethrough {
 
 impl RObject for RichTextStrikethrough {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextStrikethrough" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1033,6 +1164,11 @@ pub struct RTDRichTextStrikethroughBuilder {
 impl RTDRichTextStrikethroughBuilder {
   pub fn build(&self) -> RichTextStrikethrough { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1061,6 +1197,9 @@ pub struct RichTextSubscript {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -1068,6 +1207,7 @@ pub struct RichTextSubscript {
 
 impl RObject for RichTextSubscript {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextSubscript" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1096,6 +1236,11 @@ pub struct RTDRichTextSubscriptBuilder {
 impl RTDRichTextSubscriptBuilder {
   pub fn build(&self) -> RichTextSubscript { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1124,6 +1269,9 @@ pub struct RichTextSuperscript {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -1131,6 +1279,7 @@ pub struct RichTextSuperscript {
 
 impl RObject for RichTextSuperscript {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextSuperscript" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1159,6 +1308,11 @@ pub struct RTDRichTextSuperscriptBuilder {
 impl RTDRichTextSuperscriptBuilder {
   pub fn build(&self) -> RichTextSuperscript { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1187,6 +1341,9 @@ pub struct RichTextUnderline {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   
@@ -1194,6 +1351,7 @@ pub struct RichTextUnderline {
 
 impl RObject for RichTextUnderline {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextUnderline" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1222,6 +1380,11 @@ pub struct RTDRichTextUnderlineBuilder {
 impl RTDRichTextUnderlineBuilder {
   pub fn build(&self) -> RichTextUnderline { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1250,6 +1413,9 @@ pub struct RichTextUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text
   text: Box<RichText>,
   /// URL
@@ -1261,6 +1427,7 @@ pub struct RichTextUrl {
 
 impl RObject for RichTextUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1293,6 +1460,11 @@ pub struct RTDRichTextUrlBuilder {
 impl RTDRichTextUrlBuilder {
   pub fn build(&self) -> RichTextUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1333,6 +1505,9 @@ pub struct RichTexts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Texts
   texts: Vec<RichText>,
   
@@ -1340,6 +1515,7 @@ pub struct RichTexts {
 
 impl RObject for RichTexts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "richTexts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1368,6 +1544,11 @@ pub struct RTDRichTextsBuilder {
 impl RTDRichTextsBuilder {
   pub fn build(&self) -> RichTexts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn texts(&mut self, texts: Vec<RichText>) -> &mut Self {
     self.inner.texts = texts;
@@ -11,6 +11,9 @@ pub struct SavedCredentials {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier of the saved credentials
   id: String,
   /// Title of the saved credentials
@@ -20,6 +23,7 @@ pub struct SavedCredentials {
 
 impl RObject for SavedCredentials {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "savedCredentials" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDSavedCredentialsBuilder {
 impl RTDSavedCredentialsBuilder {
   pub fn build(&self) -> SavedCredentials { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -11,6 +11,9 @@ pub struct ScopeNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Time left before notifications will be unmuted, in seconds
   mute_for: i64,
   /// The name of an audio file to be used for notification sounds; only applies to iOS applications
@@ -26,6 +29,7 @@ pub struct ScopeNotificationSettings {
 
 impl RObject for ScopeNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "scopeNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDScopeNotificationSettingsBuilder {
 impl RTDScopeNotificationSettingsBuilder {
   pub fn build(&self) -> ScopeNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn mute_for(&mut self, mute_for: i64) -> &mut Self {
     self.inner.mute_for = mute_for;
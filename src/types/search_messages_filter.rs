@@ -18,6 +18,9 @@ pub trait TDSearchMessagesFilter: Debug + RObject {}
 #[serde(untagged)]
 pub enum SearchMessagesFilter {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Returns only animation messages
   Animation(SearchMessagesFilterAnimation),
   /// Returns only audio messages
@@ -110,12 +113,37 @@ impl RObject for SearchMessagesFilter {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      SearchMessagesFilter::Animation(t) => t.extra(),
+      SearchMessagesFilter::Audio(t) => t.extra(),
+      SearchMessagesFilter::Call(t) => t.extra(),
+      SearchMessagesFilter::ChatPhoto(t) => t.extra(),
+      SearchMessagesFilter::Document(t) => t.extra(),
+      SearchMessagesFilter::Empty(t) => t.extra(),
+      SearchMessagesFilter::FailedToSend(t) => t.extra(),
+      SearchMessagesFilter::Mention(t) => t.extra(),
+      SearchMessagesFilter::MissedCall(t) => t.extra(),
+      SearchMessagesFilter::Photo(t) => t.extra(),
+      SearchMessagesFilter::PhotoAndVideo(t) => t.extra(),
+      SearchMessagesFilter::UnreadMention(t) => t.extra(),
+      SearchMessagesFilter::Url(t) => t.extra(),
+      SearchMessagesFilter::Video(t) => t.extra(),
+      SearchMessagesFilter::VideoNote(t) => t.extra(),
+      SearchMessagesFilter::VoiceAndVideoNote(t) => t.extra(),
+      SearchMessagesFilter::VoiceNote(t) => t.extra(),
+
+      SearchMessagesFilter::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl SearchMessagesFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SearchMessagesFilter::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let SearchMessagesFilter::Unknown { .. } = self { true } else { false } }
 
   pub fn is_animation(&self) -> bool { if let SearchMessagesFilter::Animation(_) = self { true } else { false } }
   pub fn is_audio(&self) -> bool { if let SearchMessagesFilter::Audio(_) = self { true } else { false } }
@@ -225,11 +253,15 @@ pub struct SearchMessagesFilterAnimation {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterAnimation {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterAnimation" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -256,6 +288,11 @@ pub struct RTDSearchMessagesFilterAnimationBuilder {
 impl RTDSearchMessagesFilterAnimationBuilder {
   pub fn build(&self) -> SearchMessagesFilterAnimation { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterAnimation> for SearchMessagesFilterAnimation {
@@ -278,11 +315,15 @@ pub struct SearchMessagesFilterAudio {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterAudio {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterAudio" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -309,6 +350,11 @@ pub struct RTDSearchMessagesFilterAudioBuilder {
 impl RTDSearchMessagesFilterAudioBuilder {
   pub fn build(&self) -> SearchMessagesFilterAudio { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterAudio> for SearchMessagesFilterAudio {
@@ -331,11 +377,15 @@ pub struct SearchMessagesFilterCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -362,6 +412,11 @@ pub struct RTDSearchMessagesFilterCallBuilder {
 impl RTDSearchMessagesFilterCallBuilder {
   pub fn build(&self) -> SearchMessagesFilterCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterCall> for SearchMessagesFilterCall {
@@ -384,11 +439,15 @@ pub struct SearchMessagesFilterChatPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterChatPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterChatPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -415,6 +474,11 @@ pub struct RTDSearchMessagesFilterChatPhotoBuilder {
 impl RTDSearchMessagesFilterChatPhotoBuilder {
   pub fn build(&self) -> SearchMessagesFilterChatPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterChatPhoto> for SearchMessagesFilterChatPhoto {
@@ -437,11 +501,15 @@ pub struct SearchMessagesFilterDocument {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterDocument {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterDocument" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -468,6 +536,11 @@ pub struct RTDSearchMessagesFilterDocumentBuilder {
 impl RTDSearchMessagesFilterDocumentBuilder {
   pub fn build(&self) -> SearchMessagesFilterDocument { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterDocument> for SearchMessagesFilterDocument {
@@ -490,11 +563,15 @@ pub struct SearchMessagesFilterEmpty {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterEmpty {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterEmpty" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -521,6 +598,11 @@ pub struct RTDSearchMessagesFilterEmptyBuilder {
 impl RTDSearchMessagesFilterEmptyBuilder {
   pub fn build(&self) -> SearchMessagesFilterEmpty { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterEmpty> for SearchMessagesFilterEmpty {
@@ -543,11 +625,15 @@ pub struct SearchMessagesFilterFailedToSend {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterFailedToSend {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterFailedToSend" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -574,6 +660,11 @@ pub struct RTDSearchMessagesFilterFailedToSendBuilder {
 impl RTDSearchMessagesFilterFailedToSendBuilder {
   pub fn build(&self) -> SearchMessagesFilterFailedToSend { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterFailedToSend> for SearchMessagesFilterFailedToSend {
@@ -596,11 +687,15 @@ pub struct SearchMessagesFilterMention {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterMention {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterMention" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -627,6 +722,11 @@ pub struct RTDSearchMessagesFilterMentionBuilder {
 impl RTDSearchMessagesFilterMentionBuilder {
   pub fn build(&self) -> SearchMessagesFilterMention { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterMention> for SearchMessagesFilterMention {
@@ -649,11 +749,15 @@ pub struct SearchMessagesFilterMissedCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterMissedCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterMissedCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -680,6 +784,11 @@ pub struct RTDSearchMessagesFilterMissedCallBuilder {
 impl RTDSearchMessagesFilterMissedCallBuilder {
   pub fn build(&self) -> SearchMessagesFilterMissedCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterMissedCall> for SearchMessagesFilterMissedCall {
@@ -702,11 +811,15 @@ pub struct SearchMessagesFilterPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -733,6 +846,11 @@ pub struct RTDSearchMessagesFilterPhotoBuilder {
 impl RTDSearchMessagesFilterPhotoBuilder {
   pub fn build(&self) -> SearchMessagesFilterPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterPhoto> for SearchMessagesFilterPhoto {
@@ -755,11 +873,15 @@ pub struct SearchMessagesFilterPhotoAndVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterPhotoAndVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterPhotoAndVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -786,6 +908,11 @@ pub struct RTDSearchMessagesFilterPhotoAndVideoBuilder {
 impl RTDSearchMessagesFilterPhotoAndVideoBuilder {
   pub fn build(&self) -> SearchMessagesFilterPhotoAndVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterPhotoAndVideo> for SearchMessagesFilterPhotoAndVideo {
@@ -808,11 +935,15 @@ pub struct SearchMessagesFilterUnreadMention {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterUnreadMention {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterUnreadMention" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -839,6 +970,11 @@ pub struct RTDSearchMessagesFilterUnreadMentionBuilder {
 impl RTDSearchMessagesFilterUnreadMentionBuilder {
   pub fn build(&self) -> SearchMessagesFilterUnreadMention { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterUnreadMention> for SearchMessagesFilterUnreadMention {
@@ -861,11 +997,15 @@ pub struct SearchMessagesFilterUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -892,6 +1032,11 @@ pub struct RTDSearchMessagesFilterUrlBuilder {
 impl RTDSearchMessagesFilterUrlBuilder {
   pub fn build(&self) -> SearchMessagesFilterUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterUrl> for SearchMessagesFilterUrl {
@@ -914,11 +1059,15 @@ pub struct SearchMessagesFilterVideo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterVideo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVideo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -945,6 +1094,11 @@ pub struct RTDSearchMessagesFilterVideoBuilder {
 impl RTDSearchMessagesFilterVideoBuilder {
   pub fn build(&self) -> SearchMessagesFilterVideo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVideo> for SearchMessagesFilterVideo {
@@ -967,11 +1121,15 @@ pub struct SearchMessagesFilterVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -998,6 +1156,11 @@ pub struct RTDSearchMessagesFilterVideoNoteBuilder {
 impl RTDSearchMessagesFilterVideoNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVideoNote> for SearchMessagesFilterVideoNote {
@@ -1020,11 +1183,15 @@ pub struct SearchMessagesFilterVoiceAndVideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterVoiceAndVideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVoiceAndVideoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1051,6 +1218,11 @@ pub struct RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
 impl RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVoiceAndVideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVoiceAndVideoNote> for SearchMessagesFilterVoiceAndVideoNote {
@@ -1073,11 +1245,15 @@ pub struct SearchMessagesFilterVoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SearchMessagesFilterVoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVoiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1104,6 +1280,11 @@ pub struct RTDSearchMessagesFilterVoiceNoteBuilder {
 impl RTDSearchMessagesFilterVoiceNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVoiceNote> for SearchMessagesFilterVoiceNote {
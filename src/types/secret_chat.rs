@@ -11,6 +11,9 @@ pub struct SecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Secret chat identifier
   id: i64,
   /// Identifier of the chat partner
@@ -22,7 +25,7 @@ pub struct SecretChat {
   /// Current message Time To Live setting (self-destruct timer) for the chat, in seconds
   ttl: i64,
   /// Hash of the currently used key for comparison with the hash of the chat partner's key. This is a string of 36 little-endian bytes, which must be split into groups of 2 bits, each denoting a pixel of one of 4 colors FFFFFF, D5E6F3, 2D5775, and 2F99C9. The pixels must be used to make a 12x12 square image filled from left to right, top to bottom. Alternatively, the first 32 bytes of the hash can be converted to the hexadecimal format and printed as 32 2-digit hex numbers
-  key_hash: String,
+  key_hash: TdBytes,
   /// Secret chat layer; determines features supported by the chat partner's application. Video notes are supported if the layer >= 66; nested text entities and underline and strikethrough entities are supported if the layer >= 101
   layer: i64,
   
@@ -30,6 +33,7 @@ pub struct SecretChat {
 
 impl RObject for SecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -53,7 +57,7 @@ impl SecretChat {
 
   pub fn ttl(&self) -> i64 { self.ttl }
 
-  pub fn key_hash(&self) -> &String { &self.key_hash }
+  pub fn key_hash(&self) -> &TdBytes { &self.key_hash }
 
   pub fn layer(&self) -> i64 { self.layer }
 
@@ -67,6 +71,11 @@ pub struct RTDSecretChatBuilder {
 impl RTDSecretChatBuilder {
   pub fn build(&self) -> SecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -98,8 +107,8 @@ impl RTDSecretChatBuilder {
   }
 
    
-  pub fn key_hash<T: AsRef<str>>(&mut self, key_hash: T) -> &mut Self {
-    self.inner.key_hash = key_hash.as_ref().to_string();
+  pub fn key_hash<T: AsRef<[u8]>>(&mut self, key_hash: T) -> &mut Self {
+    self.inner.key_hash = TdBytes::from(key_hash.as_ref());
     self
   }
 
@@ -18,6 +18,9 @@ pub trait TDSecretChatState: Debug + RObject {}
 #[serde(untagged)]
 pub enum SecretChatState {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The secret chat is closed
   Closed(SecretChatStateClosed),
   /// The secret chat is not yet created; waiting for the other user to get online
@@ -54,12 +57,23 @@ impl RObject for SecretChatState {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      SecretChatState::Closed(t) => t.extra(),
+      SecretChatState::Pending(t) => t.extra(),
+      SecretChatState::Ready(t) => t.extra(),
+
+      SecretChatState::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl SecretChatState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SecretChatState::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let SecretChatState::Unknown { .. } = self { true } else { false } }
 
   pub fn is_closed(&self) -> bool { if let SecretChatState::Closed(_) = self { true } else { false } }
   pub fn is_pending(&self) -> bool { if let SecretChatState::Pending(_) = self { true } else { false } }
@@ -99,11 +113,15 @@ pub struct SecretChatStateClosed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SecretChatStateClosed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChatStateClosed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -130,6 +148,11 @@ pub struct RTDSecretChatStateClosedBuilder {
 impl RTDSecretChatStateClosedBuilder {
   pub fn build(&self) -> SecretChatStateClosed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SecretChatStateClosed> for SecretChatStateClosed {
@@ -152,11 +175,15 @@ pub struct SecretChatStatePending {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SecretChatStatePending {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChatStatePending" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -183,6 +210,11 @@ pub struct RTDSecretChatStatePendingBuilder {
 impl RTDSecretChatStatePendingBuilder {
   pub fn build(&self) -> SecretChatStatePending { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SecretChatStatePending> for SecretChatStatePending {
@@ -205,11 +237,15 @@ pub struct SecretChatStateReady {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SecretChatStateReady {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChatStateReady" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -236,6 +272,11 @@ pub struct RTDSecretChatStateReadyBuilder {
 impl RTDSecretChatStateReadyBuilder {
   pub fn build(&self) -> SecretChatStateReady { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SecretChatStateReady> for SecretChatStateReady {
@@ -11,8 +11,11 @@ pub struct Session {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Session identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// True, if this session is the current session
   is_current: bool,
   /// True, if a password is needed to complete authorization of the session
@@ -46,6 +49,7 @@ pub struct Session {
 
 impl RObject for Session {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "session" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,7 +63,7 @@ impl Session {
     RTDSessionBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn is_current(&self) -> bool { self.is_current }
 
@@ -99,8 +103,13 @@ pub struct RTDSessionBuilder {
 impl RTDSessionBuilder {
   pub fn build(&self) -> Session { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -11,6 +11,9 @@ pub struct Sessions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of sessions
   sessions: Vec<Session>,
   
@@ -18,6 +21,7 @@ pub struct Sessions {
 
 impl RObject for Sessions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sessions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDSessionsBuilder {
 impl RTDSessionsBuilder {
   pub fn build(&self) -> Sessions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sessions(&mut self, sessions: Vec<Session>) -> &mut Self {
     self.inner.sessions = sessions;
@@ -11,6 +11,9 @@ pub struct ShippingOption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Shipping option identifier
   id: String,
   /// Option title
@@ -22,6 +25,7 @@ pub struct ShippingOption {
 
 impl RObject for ShippingOption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "shippingOption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDShippingOptionBuilder {
 impl RTDShippingOptionBuilder {
   pub fn build(&self) -> ShippingOption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -18,8 +18,9 @@ pub trait TDStatisticsGraph: Debug + RObject {}
 #[serde(untagged)]
 pub enum StatisticsGraph {
   #[doc(hidden)] _Default(()),
-  /// Loads asynchronous or zoomed in chat statistics graph
-  GetChatStatisticsGraph(GetChatStatisticsGraph),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The graph data to be asynchronously loaded through getChatStatisticsGraph
   Async(StatisticsGraphAsync),
   /// A graph data
@@ -38,7 +39,6 @@ impl<'de> Deserialize<'de> for StatisticsGraph {
     use serde::de::Error;
     rtd_enum_deserialize!(
       StatisticsGraph,
-      (getChatStatisticsGraph, GetChatStatisticsGraph);
       (statisticsGraphAsync, Async);
       (statisticsGraphData, Data);
       (statisticsGraphError, Error);
@@ -50,7 +50,6 @@ impl<'de> Deserialize<'de> for StatisticsGraph {
 impl RObject for StatisticsGraph {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      StatisticsGraph::GetChatStatisticsGraph(t) => t.td_name(),
       StatisticsGraph::Async(t) => t.td_name(),
       StatisticsGraph::Data(t) => t.td_name(),
       StatisticsGraph::Error(t) => t.td_name(),
@@ -58,32 +57,38 @@ impl RObject for StatisticsGraph {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      StatisticsGraph::Async(t) => t.extra(),
+      StatisticsGraph::Data(t) => t.extra(),
+      StatisticsGraph::Error(t) => t.extra(),
+
+      StatisticsGraph::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl StatisticsGraph {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let StatisticsGraph::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let StatisticsGraph::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_get_chat_statistics_graph(&self) -> bool { if let StatisticsGraph::GetChatStatisticsGraph(_) = self { true } else { false } }
   pub fn is_async(&self) -> bool { if let StatisticsGraph::Async(_) = self { true } else { false } }
   pub fn is_data(&self) -> bool { if let StatisticsGraph::Data(_) = self { true } else { false } }
   pub fn is_error(&self) -> bool { if let StatisticsGraph::Error(_) = self { true } else { false } }
 
-  pub fn on_get_chat_statistics_graph<F: FnOnce(&GetChatStatisticsGraph)>(&self, fnc: F) -> &Self { if let StatisticsGraph::GetChatStatisticsGraph(t) = self { fnc(t) }; self }
   pub fn on_async<F: FnOnce(&StatisticsGraphAsync)>(&self, fnc: F) -> &Self { if let StatisticsGraph::Async(t) = self { fnc(t) }; self }
   pub fn on_data<F: FnOnce(&StatisticsGraphData)>(&self, fnc: F) -> &Self { if let StatisticsGraph::Data(t) = self { fnc(t) }; self }
   pub fn on_error<F: FnOnce(&StatisticsGraphError)>(&self, fnc: F) -> &Self { if let StatisticsGraph::Error(t) = self { fnc(t) }; self }
 
-  pub fn as_get_chat_statistics_graph(&self) -> Option<&GetChatStatisticsGraph> { if let StatisticsGraph::GetChatStatisticsGraph(t) = self { return Some(t) } None }
   pub fn as_async(&self) -> Option<&StatisticsGraphAsync> { if let StatisticsGraph::Async(t) = self { return Some(t) } None }
   pub fn as_data(&self) -> Option<&StatisticsGraphData> { if let StatisticsGraph::Data(t) = self { return Some(t) } None }
   pub fn as_error(&self) -> Option<&StatisticsGraphError> { if let StatisticsGraph::Error(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_chat_statistics_graph<T: AsRef<GetChatStatisticsGraph>>(t: T) -> Self { StatisticsGraph::GetChatStatisticsGraph(t.as_ref().clone()) }
-
   pub fn async_<T: AsRef<StatisticsGraphAsync>>(t: T) -> Self { StatisticsGraph::Async(t.as_ref().clone()) }
 
   pub fn data<T: AsRef<StatisticsGraphData>>(t: T) -> Self { StatisticsGraph::Data(t.as_ref().clone()) }
@@ -108,6 +113,9 @@ pub struct StatisticsGraphAsync {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The token to use for data loading
   token: String,
   
@@ -115,6 +123,7 @@ pub struct StatisticsGraphAsync {
 
 impl RObject for StatisticsGraphAsync {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticsGraphAsync" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +152,11 @@ pub struct RTDStatisticsGraphAsyncBuilder {
 impl RTDStatisticsGraphAsyncBuilder {
   pub fn build(&self) -> StatisticsGraphAsync { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -171,6 +185,9 @@ pub struct StatisticsGraphData {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Graph data in JSON format
   json_data: String,
   /// If non-empty, a token which can be used to receive a zoomed in graph
@@ -180,6 +197,7 @@ pub struct StatisticsGraphData {
 
 impl RObject for StatisticsGraphData {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticsGraphData" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -210,6 +228,11 @@ pub struct RTDStatisticsGraphDataBuilder {
 impl RTDStatisticsGraphDataBuilder {
   pub fn build(&self) -> StatisticsGraphData { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn json_data<T: AsRef<str>>(&mut self, json_data: T) -> &mut Self {
     self.inner.json_data = json_data.as_ref().to_string();
@@ -244,6 +267,9 @@ pub struct StatisticsGraphError {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The error message
   error_message: String,
   
@@ -251,6 +277,7 @@ pub struct StatisticsGraphError {
 
 impl RObject for StatisticsGraphError {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticsGraphError" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -279,6 +306,11 @@ pub struct RTDStatisticsGraphErrorBuilder {
 impl RTDStatisticsGraphErrorBuilder {
   pub fn build(&self) -> StatisticsGraphError { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
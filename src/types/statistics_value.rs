@@ -11,6 +11,9 @@ pub struct StatisticsValue {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The value
   value: f32,
   /// The value for the previous day
@@ -22,6 +25,7 @@ pub struct StatisticsValue {
 
 impl RObject for StatisticsValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticsValue" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDStatisticsValueBuilder {
 impl RTDStatisticsValueBuilder {
   pub fn build(&self) -> StatisticsValue { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: f32) -> &mut Self {
     self.inner.value = value;
@@ -11,8 +11,11 @@ pub struct Sticker {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The identifier of the sticker set to which the sticker belongs; 0 if none
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] set_id: i64,
   /// Sticker width; as defined by the sender
   width: i64,
   /// Sticker height; as defined by the sender
@@ -34,6 +37,7 @@ pub struct Sticker {
 
 impl RObject for Sticker {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "sticker" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,7 +51,7 @@ impl Sticker {
     RTDStickerBuilder { inner }
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  pub fn set_id(&self) -> i64 { self.set_id }
 
   pub fn width(&self) -> i64 { self.width }
 
@@ -75,8 +79,13 @@ pub struct RTDStickerBuilder {
 impl RTDStickerBuilder {
   pub fn build(&self) -> Sticker { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
     self
   }
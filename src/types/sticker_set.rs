@@ -11,8 +11,11 @@ pub struct StickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the sticker set
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Title of the sticker set
   title: String,
   /// Name of the sticker set
@@ -40,6 +43,7 @@ pub struct StickerSet {
 
 impl RObject for StickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "stickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -53,7 +57,7 @@ impl StickerSet {
     RTDStickerSetBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn title(&self) -> &String { &self.title }
 
@@ -87,8 +91,13 @@ pub struct RTDStickerSetBuilder {
 impl RTDStickerSetBuilder {
   pub fn build(&self) -> StickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
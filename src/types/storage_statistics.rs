@@ -11,6 +11,9 @@ pub struct StorageStatistics {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Total size of files
   size: i64,
   /// Total number of files
@@ -22,6 +25,7 @@ pub struct StorageStatistics {
 
 impl RObject for StorageStatistics {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatistics" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDStorageStatisticsBuilder {
 impl RTDStorageStatisticsBuilder {
   pub fn build(&self) -> StorageStatistics { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
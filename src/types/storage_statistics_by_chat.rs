@@ -11,6 +11,9 @@ pub struct StorageStatisticsByChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier; 0 if none
   chat_id: i64,
   /// Total size of the files in the chat
@@ -24,6 +27,7 @@ pub struct StorageStatisticsByChat {
 
 impl RObject for StorageStatisticsByChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatisticsByChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDStorageStatisticsByChatBuilder {
 impl RTDStorageStatisticsByChatBuilder {
   pub fn build(&self) -> StorageStatisticsByChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
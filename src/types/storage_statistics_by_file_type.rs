@@ -11,6 +11,9 @@ pub struct StorageStatisticsByFileType {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// File type
   file_type: FileType,
   /// Total size of the files
@@ -22,6 +25,7 @@ pub struct StorageStatisticsByFileType {
 
 impl RObject for StorageStatisticsByFileType {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatisticsByFileType" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDStorageStatisticsByFileTypeBuilder {
 impl RTDStorageStatisticsByFileTypeBuilder {
   pub fn build(&self) -> StorageStatisticsByFileType { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
     self.inner.file_type = file_type.as_ref().clone();
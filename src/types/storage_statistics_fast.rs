@@ -11,6 +11,9 @@ pub struct StorageStatisticsFast {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Approximate total size of files
   files_size: i64,
   /// Approximate number of files
@@ -26,6 +29,7 @@ pub struct StorageStatisticsFast {
 
 impl RObject for StorageStatisticsFast {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatisticsFast" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDStorageStatisticsFastBuilder {
 impl RTDStorageStatisticsFastBuilder {
   pub fn build(&self) -> StorageStatisticsFast { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn files_size(&mut self, files_size: i64) -> &mut Self {
     self.inner.files_size = files_size;
@@ -18,6 +18,9 @@ pub trait TDSuggestedAction: Debug + RObject {}
 #[serde(untagged)]
 pub enum SuggestedAction {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Suggests the user to check authorization phone number and change the phone number if it is inaccessible
   CheckPhoneNumber(SuggestedActionCheckPhoneNumber),
   /// Suggests the user to enable "archive_and_mute_new_chats_from_unknown_users" option
@@ -50,12 +53,22 @@ impl RObject for SuggestedAction {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      SuggestedAction::CheckPhoneNumber(t) => t.extra(),
+      SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.extra(),
+
+      SuggestedAction::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl SuggestedAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SuggestedAction::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let SuggestedAction::Unknown { .. } = self { true } else { false } }
 
   pub fn is_check_phone_number(&self) -> bool { if let SuggestedAction::CheckPhoneNumber(_) = self { true } else { false } }
   pub fn is_enable_archive_and_mute_new_chats(&self) -> bool { if let SuggestedAction::EnableArchiveAndMuteNewChats(_) = self { true } else { false } }
@@ -90,11 +103,15 @@ pub struct SuggestedActionCheckPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SuggestedActionCheckPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionCheckPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,6 +138,11 @@ pub struct RTDSuggestedActionCheckPhoneNumberBuilder {
 impl RTDSuggestedActionCheckPhoneNumberBuilder {
   pub fn build(&self) -> SuggestedActionCheckPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SuggestedActionCheckPhoneNumber> for SuggestedActionCheckPhoneNumber {
@@ -143,11 +165,15 @@ pub struct SuggestedActionEnableArchiveAndMuteNewChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SuggestedActionEnableArchiveAndMuteNewChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionEnableArchiveAndMuteNewChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -174,6 +200,11 @@ pub struct RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
 impl RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
   pub fn build(&self) -> SuggestedActionEnableArchiveAndMuteNewChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SuggestedActionEnableArchiveAndMuteNewChats> for SuggestedActionEnableArchiveAndMuteNewChats {
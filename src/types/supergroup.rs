@@ -11,6 +11,9 @@ pub struct Supergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Supergroup or channel identifier
   id: i64,
   /// Username of the supergroup or channel; empty for private supergroups or channels
@@ -42,6 +45,7 @@ pub struct Supergroup {
 
 impl RObject for Supergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -91,6 +95,11 @@ pub struct RTDSupergroupBuilder {
 impl RTDSupergroupBuilder {
   pub fn build(&self) -> Supergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
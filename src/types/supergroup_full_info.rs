@@ -11,6 +11,9 @@ pub struct SupergroupFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat photo; may be null
   photo: Option<ChatPhoto>,
   /// Contains full information about a supergroup or channel
@@ -42,7 +45,7 @@ pub struct SupergroupFullInfo {
   /// True, if new chat members will have access to old messages. In public or discussion groups and both public and private channels, old messages are always available, so this option affects only private supergroups without a linked chat. The value of this field is only available for chat administrators
   is_all_history_available: bool,
   /// Identifier of the supergroup sticker set; 0 if none
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] sticker_set_id: i64,
   /// Location to which the supergroup is connected; may be null
   location: Option<ChatLocation>,
   /// Invite link for this chat
@@ -56,6 +59,7 @@ pub struct SupergroupFullInfo {
 
 impl RObject for SupergroupFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -99,7 +103,7 @@ impl SupergroupFullInfo {
 
   pub fn is_all_history_available(&self) -> bool { self.is_all_history_available }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
   pub fn location(&self) -> &Option<ChatLocation> { &self.location }
 
@@ -119,6 +123,11 @@ pub struct RTDSupergroupFullInfoBuilder {
 impl RTDSupergroupFullInfoBuilder {
   pub fn build(&self) -> SupergroupFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -210,7 +219,7 @@ impl RTDSupergroupFullInfoBuilder {
   }
 
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
     self
   }
@@ -18,6 +18,9 @@ pub trait TDSupergroupMembersFilter: Debug + RObject {}
 #[serde(untagged)]
 pub enum SupergroupMembersFilter {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Returns the owner and administrators
   Administrators(SupergroupMembersFilterAdministrators),
   /// Returns users banned from the supergroup or channel; can be used only by administrators
@@ -70,12 +73,27 @@ impl RObject for SupergroupMembersFilter {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      SupergroupMembersFilter::Administrators(t) => t.extra(),
+      SupergroupMembersFilter::Banned(t) => t.extra(),
+      SupergroupMembersFilter::Bots(t) => t.extra(),
+      SupergroupMembersFilter::Contacts(t) => t.extra(),
+      SupergroupMembersFilter::Recent(t) => t.extra(),
+      SupergroupMembersFilter::Restricted(t) => t.extra(),
+      SupergroupMembersFilter::Search(t) => t.extra(),
+
+      SupergroupMembersFilter::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl SupergroupMembersFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SupergroupMembersFilter::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let SupergroupMembersFilter::Unknown { .. } = self { true } else { false } }
 
   pub fn is_administrators(&self) -> bool { if let SupergroupMembersFilter::Administrators(_) = self { true } else { false } }
   pub fn is_banned(&self) -> bool { if let SupergroupMembersFilter::Banned(_) = self { true } else { false } }
@@ -135,11 +153,15 @@ pub struct SupergroupMembersFilterAdministrators {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SupergroupMembersFilterAdministrators {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterAdministrators" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -166,6 +188,11 @@ pub struct RTDSupergroupMembersFilterAdministratorsBuilder {
 impl RTDSupergroupMembersFilterAdministratorsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterAdministrators { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SupergroupMembersFilterAdministrators> for SupergroupMembersFilterAdministrators {
@@ -188,6 +215,9 @@ pub struct SupergroupMembersFilterBanned {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   
@@ -195,6 +225,7 @@ pub struct SupergroupMembersFilterBanned {
 
 impl RObject for SupergroupMembersFilterBanned {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterBanned" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -223,6 +254,11 @@ pub struct RTDSupergroupMembersFilterBannedBuilder {
 impl RTDSupergroupMembersFilterBannedBuilder {
   pub fn build(&self) -> SupergroupMembersFilterBanned { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -251,11 +287,15 @@ pub struct SupergroupMembersFilterBots {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SupergroupMembersFilterBots {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterBots" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -282,6 +322,11 @@ pub struct RTDSupergroupMembersFilterBotsBuilder {
 impl RTDSupergroupMembersFilterBotsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterBots { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SupergroupMembersFilterBots> for SupergroupMembersFilterBots {
@@ -304,6 +349,9 @@ pub struct SupergroupMembersFilterContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   
@@ -311,6 +359,7 @@ pub struct SupergroupMembersFilterContacts {
 
 impl RObject for SupergroupMembersFilterContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -339,6 +388,11 @@ pub struct RTDSupergroupMembersFilterContactsBuilder {
 impl RTDSupergroupMembersFilterContactsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -367,11 +421,15 @@ pub struct SupergroupMembersFilterRecent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for SupergroupMembersFilterRecent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterRecent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -398,6 +456,11 @@ pub struct RTDSupergroupMembersFilterRecentBuilder {
 impl RTDSupergroupMembersFilterRecentBuilder {
   pub fn build(&self) -> SupergroupMembersFilterRecent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SupergroupMembersFilterRecent> for SupergroupMembersFilterRecent {
@@ -420,6 +483,9 @@ pub struct SupergroupMembersFilterRestricted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   
@@ -427,6 +493,7 @@ pub struct SupergroupMembersFilterRestricted {
 
 impl RObject for SupergroupMembersFilterRestricted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterRestricted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -455,6 +522,11 @@ pub struct RTDSupergroupMembersFilterRestrictedBuilder {
 impl RTDSupergroupMembersFilterRestrictedBuilder {
   pub fn build(&self) -> SupergroupMembersFilterRestricted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -483,6 +555,9 @@ pub struct SupergroupMembersFilterSearch {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Query to search for
   query: String,
   
@@ -490,6 +565,7 @@ pub struct SupergroupMembersFilterSearch {
 
 impl RObject for SupergroupMembersFilterSearch {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterSearch" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -518,6 +594,11 @@ pub struct RTDSupergroupMembersFilterSearchBuilder {
 impl RTDSupergroupMembersFilterSearchBuilder {
   pub fn build(&self) -> SupergroupMembersFilterSearch { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
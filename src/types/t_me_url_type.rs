@@ -18,6 +18,9 @@ pub trait TDTMeUrlType: Debug + RObject {}
 #[serde(untagged)]
 pub enum TMeUrlType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A chat invite link
   ChatInvite(TMeUrlTypeChatInvite),
   /// A URL linking to a sticker set
@@ -58,12 +61,24 @@ impl RObject for TMeUrlType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      TMeUrlType::ChatInvite(t) => t.extra(),
+      TMeUrlType::StickerSet(t) => t.extra(),
+      TMeUrlType::Supergroup(t) => t.extra(),
+      TMeUrlType::User(t) => t.extra(),
+
+      TMeUrlType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl TMeUrlType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TMeUrlType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let TMeUrlType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_chat_invite(&self) -> bool { if let TMeUrlType::ChatInvite(_) = self { true } else { false } }
   pub fn is_sticker_set(&self) -> bool { if let TMeUrlType::StickerSet(_) = self { true } else { false } }
@@ -108,6 +123,9 @@ pub struct TMeUrlTypeChatInvite {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat invite link info
   info: ChatInviteLinkInfo,
   
@@ -115,6 +133,7 @@ pub struct TMeUrlTypeChatInvite {
 
 impl RObject for TMeUrlTypeChatInvite {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeChatInvite" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,6 +162,11 @@ pub struct RTDTMeUrlTypeChatInviteBuilder {
 impl RTDTMeUrlTypeChatInviteBuilder {
   pub fn build(&self) -> TMeUrlTypeChatInvite { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn info<T: AsRef<ChatInviteLinkInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
@@ -171,13 +195,17 @@ pub struct TMeUrlTypeStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the sticker set
-  sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] sticker_set_id: i64,
   
 }
 
 impl RObject for TMeUrlTypeStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -194,7 +222,7 @@ impl TMeUrlTypeStickerSet {
     RTDTMeUrlTypeStickerSetBuilder { inner }
   }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
 }
 
@@ -206,8 +234,13 @@ pub struct RTDTMeUrlTypeStickerSetBuilder {
 impl RTDTMeUrlTypeStickerSetBuilder {
   pub fn build(&self) -> TMeUrlTypeStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
     self
   }
@@ -234,6 +267,9 @@ pub struct TMeUrlTypeSupergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup or channel
   supergroup_id: i64,
   
@@ -241,6 +277,7 @@ pub struct TMeUrlTypeSupergroup {
 
 impl RObject for TMeUrlTypeSupergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeSupergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -269,6 +306,11 @@ pub struct RTDTMeUrlTypeSupergroupBuilder {
 impl RTDTMeUrlTypeSupergroupBuilder {
   pub fn build(&self) -> TMeUrlTypeSupergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -297,6 +339,9 @@ pub struct TMeUrlTypeUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the user
   user_id: i64,
   
@@ -304,6 +349,7 @@ pub struct TMeUrlTypeUser {
 
 impl RObject for TMeUrlTypeUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -332,6 +378,11 @@ pub struct RTDTMeUrlTypeUserBuilder {
 impl RTDTMeUrlTypeUserBuilder {
   pub fn build(&self) -> TMeUrlTypeUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -11,6 +11,9 @@ pub struct TMeUrls {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// List of URLs
   urls: Vec<TMeUrl>,
   
@@ -18,6 +21,7 @@ pub struct TMeUrls {
 
 impl RObject for TMeUrls {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrls" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDTMeUrlsBuilder {
 impl RTDTMeUrlsBuilder {
   pub fn build(&self) -> TMeUrls { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn urls(&mut self, urls: Vec<TMeUrl>) -> &mut Self {
     self.inner.urls = urls;
@@ -0,0 +1,3272 @@
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// Any object tdlib sends, a response or an update, by its `@type`. Objects of an abstract type are kept as that type, e.g. every update is `TdObject::Update`
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum TdObject {
+  #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
+  /// Contains information about the period of inactivity after which the current user's account will automatically be deleted
+  AccountTtl(AccountTtl),
+  /// Describes an address
+  Address(Address),
+  /// Animated variant of a chat photo in MPEG4 format
+  AnimatedChatPhoto(AnimatedChatPhoto),
+  /// Describes an animation file. The animation must be encoded in GIF or MPEG4 format
+  Animation(Animation),
+  /// Represents a list of animations
+  Animations(Animations),
+  /// Describes an audio file. Audio is usually in MP3 or M4A format
+  Audio(Audio),
+  /// Information about the authentication code that was sent
+  AuthenticationCodeInfo(AuthenticationCodeInfo),
+  /// Provides information about the method by which an authentication code is delivered to the user
+  AuthenticationCodeType(AuthenticationCodeType),
+  /// Represents the current authorization state of the TDLib client
+  AuthorizationState(AuthorizationState),
+  /// Contains auto-download settings
+  AutoDownloadSettings(AutoDownloadSettings),
+  /// Contains auto-download settings presets for the user
+  AutoDownloadSettingsPresets(AutoDownloadSettingsPresets),
+  /// Describes a chat background
+  Background(Background),
+  /// Describes a fill of a background
+  BackgroundFill(BackgroundFill),
+  /// Describes the type of a background
+  BackgroundType(BackgroundType),
+  /// Contains a list of backgrounds
+  Backgrounds(Backgrounds),
+  /// Describes an action associated with a bank card number
+  BankCardActionOpenUrl(BankCardActionOpenUrl),
+  /// Information about a bank card
+  BankCardInfo(BankCardInfo),
+  /// Represents a basic group of 0-200 users (must be upgraded to a supergroup to accommodate more than 200 users)
+  BasicGroup(BasicGroup),
+  /// Contains full information about a basic group
+  BasicGroupFullInfo(BasicGroupFullInfo),
+  /// Represents a command supported by a bot
+  BotCommand(BotCommand),
+  /// Provides information about a bot and its supported commands
+  BotInfo(BotInfo),
+  /// Describes a call
+  Call(Call),
+  /// Describes the reason why a call was discarded
+  CallDiscardReason(CallDiscardReason),
+  /// Contains the call identifier
+  CallId(CallId),
+  /// Describes the exact type of a problem with a call
+  CallProblem(CallProblem),
+  /// Specifies the supported call protocols
+  CallProtocol(CallProtocol),
+  /// Describes a server for relaying call data
+  CallServer(CallServer),
+  /// Describes the type of a call server
+  CallServerType(CallServerType),
+  /// Describes the current call state
+  CallState(CallState),
+  /// Contains a bot's answer to a callback query
+  CallbackQueryAnswer(CallbackQueryAnswer),
+  /// Represents a payload of a callback query
+  CallbackQueryPayload(CallbackQueryPayload),
+  /// Represents result of checking whether the current session can be used to transfer a chat ownership to another user
+  CanTransferOwnershipResult(CanTransferOwnershipResult),
+  /// A chat. (Can be a private chat, basic group, supergroup, or secret chat)
+  Chat(Chat),
+  /// Describes the different types of activity in a chat
+  ChatAction(ChatAction),
+  /// Describes actions which should be possible to do through a chat action bar
+  ChatActionBar(ChatActionBar),
+  /// Contains information about a chat administrator
+  ChatAdministrator(ChatAdministrator),
+  /// Represents a list of chat administrators
+  ChatAdministrators(ChatAdministrators),
+  /// Represents a chat event
+  ChatEvent(ChatEvent),
+  /// Represents a chat event
+  ChatEventAction(ChatEventAction),
+  /// Represents a set of filters used to obtain a chat event log
+  ChatEventLogFilters(ChatEventLogFilters),
+  /// Contains a list of chat events
+  ChatEvents(ChatEvents),
+  /// Represents a filter of user chats
+  ChatFilter(ChatFilter),
+  /// Contains basic information about a chat filter
+  ChatFilterInfo(ChatFilterInfo),
+  /// Contains a chat invite link
+  ChatInviteLink(ChatInviteLink),
+  /// Contains information about a chat invite link
+  ChatInviteLinkInfo(ChatInviteLinkInfo),
+  /// Describes a list of chats
+  ChatList(ChatList),
+  /// Contains a list of chat lists
+  ChatLists(ChatLists),
+  /// Represents a location to which a chat is connected
+  ChatLocation(ChatLocation),
+  /// A user with information about joining/leaving a chat
+  ChatMember(ChatMember),
+  /// Provides information about the status of a member in a chat
+  ChatMemberStatus(ChatMemberStatus),
+  /// Contains a list of chat members
+  ChatMembers(ChatMembers),
+  /// Specifies the kind of chat members to return in searchChatMembers
+  ChatMembersFilter(ChatMembersFilter),
+  /// Describes a chat located nearby
+  ChatNearby(ChatNearby),
+  /// Contains information about notification settings for a chat
+  ChatNotificationSettings(ChatNotificationSettings),
+  /// Describes actions that a user is allowed to take in a chat
+  ChatPermissions(ChatPermissions),
+  /// Describes a chat or user profile photo
+  ChatPhoto(ChatPhoto),
+  /// Contains basic information about the photo of a chat
+  ChatPhotoInfo(ChatPhotoInfo),
+  /// Contains a list of chat or user profile photos
+  ChatPhotos(ChatPhotos),
+  /// Describes a position of a chat in a chat list
+  ChatPosition(ChatPosition),
+  /// Describes the reason why a chat is reported
+  ChatReportReason(ChatReportReason),
+  /// Describes a reason why an external chat is shown in a chat list
+  ChatSource(ChatSource),
+  /// Contains a detailed statistics about a chat
+  ChatStatistics(ChatStatistics),
+  /// Contains statistics about administrator actions done by a user
+  ChatStatisticsAdministratorActionsInfo(ChatStatisticsAdministratorActionsInfo),
+  /// Contains statistics about number of new members invited by a user
+  ChatStatisticsInviterInfo(ChatStatisticsInviterInfo),
+  /// Contains statistics about interactions with a message
+  ChatStatisticsMessageInteractionInfo(ChatStatisticsMessageInteractionInfo),
+  /// Contains statistics about messages sent by a user
+  ChatStatisticsMessageSenderInfo(ChatStatisticsMessageSenderInfo),
+  /// Describes the type of a chat
+  ChatType(ChatType),
+  /// Represents a list of chats
+  Chats(Chats),
+  /// Represents a list of chats located nearby
+  ChatsNearby(ChatsNearby),
+  /// Represents result of checking whether a username can be set for a chat
+  CheckChatUsernameResult(CheckChatUsernameResult),
+  /// Contains information about one website the current user is logged in with Telegram
+  ConnectedWebsite(ConnectedWebsite),
+  /// Contains a list of websites the current user is logged in with Telegram
+  ConnectedWebsites(ConnectedWebsites),
+  /// Describes the current state of the connection to Telegram servers
+  ConnectionState(ConnectionState),
+  /// Describes a user contact
+  Contact(Contact),
+  /// Contains a counter
+  Count(Count),
+  /// Contains the result of a custom request
+  CustomRequestResult(CustomRequestResult),
+  /// Contains database statistics
+  DatabaseStatistics(DatabaseStatistics),
+  /// Represents a date according to the Gregorian calendar
+  Date(Date),
+  /// Represents a date range
+  DateRange(DateRange),
+  /// File with the date it was uploaded
+  DatedFile(DatedFile),
+  /// Contains information about a tg:// deep link
+  DeepLinkInfo(DeepLinkInfo),
+  /// Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, you must specify the correct application platform and upload valid server authentication data at https://my.telegram.org
+  DeviceToken(DeviceToken),
+  /// Describes a document of any type
+  Document(Document),
+  /// Contains information about a message draft
+  DraftMessage(DraftMessage),
+  /// Information about the email address authentication code that was sent
+  EmailAddressAuthenticationCodeInfo(EmailAddressAuthenticationCodeInfo),
+  /// Represents a list of emoji
+  Emojis(Emojis),
+  /// Contains encrypted Telegram Passport data credentials
+  EncryptedCredentials(EncryptedCredentials),
+  /// Contains information about an encrypted Telegram Passport element; for bots only
+  EncryptedPassportElement(EncryptedPassportElement),
+  /// An object of this type can be returned on every function call, in case of an error
+  Error(Error),
+  /// Represents a file
+  File(File),
+  /// Contains a part of a file
+  FilePart(FilePart),
+  /// Represents the type of a file
+  FileType(FileType),
+  /// A text with some entities
+  FormattedText(FormattedText),
+  /// Contains a list of messages found by a search
+  FoundMessages(FoundMessages),
+  /// Describes a game
+  Game(Game),
+  /// Contains one row of the game high score table
+  GameHighScore(GameHighScore),
+  /// Contains a list of game high scores
+  GameHighScores(GameHighScores),
+  /// Contains a list of hashtags
+  Hashtags(Hashtags),
+  /// Contains an HTTP URL
+  HttpUrl(HttpUrl),
+  /// An identity document
+  IdentityDocument(IdentityDocument),
+  /// Represents the result of an ImportContacts request
+  ImportedContacts(ImportedContacts),
+  /// Represents a single button in an inline keyboard
+  InlineKeyboardButton(InlineKeyboardButton),
+  /// Describes the type of an inline keyboard button
+  InlineKeyboardButtonType(InlineKeyboardButtonType),
+  /// Represents a single result of an inline query
+  InlineQueryResult(InlineQueryResult),
+  /// Represents the results of the inline query. Use sendInlineQueryResultMessage to send the result of the query
+  InlineQueryResults(InlineQueryResults),
+  /// Contains information about background to set
+  InputBackground(InputBackground),
+  /// Describes a photo to be set as a user profile or chat photo
+  InputChatPhoto(InputChatPhoto),
+  /// Contains information about the payment method chosen by the user
+  InputCredentials(InputCredentials),
+  /// Points to a file
+  InputFile(InputFile),
+  /// An identity document to be saved to Telegram Passport
+  InputIdentityDocument(InputIdentityDocument),
+  /// Represents a single result of an inline query; for bots only
+  InputInlineQueryResult(InputInlineQueryResult),
+  /// The content of a message to send
+  InputMessageContent(InputMessageContent),
+  /// Contains information about a Telegram Passport element to be saved
+  InputPassportElement(InputPassportElement),
+  /// Contains the description of an error in a Telegram Passport element; for bots only
+  InputPassportElementError(InputPassportElementError),
+  /// Contains the description of an error in a Telegram Passport element; for bots only
+  InputPassportElementErrorSource(InputPassportElementErrorSource),
+  /// A personal document to be saved to Telegram Passport
+  InputPersonalDocument(InputPersonalDocument),
+  /// Describes a sticker that needs to be added to a sticker set
+  InputSticker(InputSticker),
+  /// A thumbnail to be sent along with a file; must be in JPEG or WEBP format for stickers, and less than 200 KB in size
+  InputThumbnail(InputThumbnail),
+  /// Product invoice
+  Invoice(Invoice),
+  /// Represents one member of a JSON object
+  JsonObjectMember(JsonObjectMember),
+  /// Represents a JSON value
+  JsonValue(JsonValue),
+  /// Represents a single button in a bot keyboard
+  KeyboardButton(KeyboardButton),
+  /// Describes a keyboard button type
+  KeyboardButtonType(KeyboardButtonType),
+  /// Portion of the price of a product (e.g., "delivery cost", "tax amount")
+  LabeledPricePart(LabeledPricePart),
+  /// Contains information about a language pack
+  LanguagePackInfo(LanguagePackInfo),
+  /// Represents one language pack string
+  LanguagePackString(LanguagePackString),
+  /// Represents the value of a string in a language pack
+  LanguagePackStringValue(LanguagePackStringValue),
+  /// Contains a list of language pack strings
+  LanguagePackStrings(LanguagePackStrings),
+  /// Represents a local file
+  LocalFile(LocalFile),
+  /// Contains information about the current localization target
+  LocalizationTargetInfo(LocalizationTargetInfo),
+  /// Describes a location on planet Earth
+  Location(Location),
+  /// Describes a stream to which TDLib internal log is written
+  LogStream(LogStream),
+  /// Contains a list of available TDLib internal log tags
+  LogTags(LogTags),
+  /// Contains a TDLib internal log verbosity level
+  LogVerbosityLevel(LogVerbosityLevel),
+  /// Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl
+  LoginUrlInfo(LoginUrlInfo),
+  /// Part of the face, relative to which a mask should be placed
+  MaskPoint(MaskPoint),
+  /// Position on a photo where a mask should be placed
+  MaskPosition(MaskPosition),
+  /// Describes a message
+  Message(Message),
+  /// Contains the content of a message
+  MessageContent(MessageContent),
+  /// Options to be used when a message content is copied without a link to the original message
+  MessageCopyOptions(MessageCopyOptions),
+  /// Contains information about a forwarded message
+  MessageForwardInfo(MessageForwardInfo),
+  /// Contains information about the origin of a forwarded message
+  MessageForwardOrigin(MessageForwardOrigin),
+  /// Contains information about a link to a message in a chat
+  MessageLinkInfo(MessageLinkInfo),
+  /// Contains information about the time when a scheduled message will be sent
+  MessageSchedulingState(MessageSchedulingState),
+  /// Options to be used when a message is sent
+  MessageSendOptions(MessageSendOptions),
+  /// Contains information about the sending state of the message
+  MessageSendingState(MessageSendingState),
+  /// Contains a list of messages
+  Messages(Messages),
+  /// Thumbnail image of a very poor quality and low resolution
+  Minithumbnail(Minithumbnail),
+  /// A full list of available network statistic entries
+  NetworkStatistics(NetworkStatistics),
+  /// Contains statistics about network usage
+  NetworkStatisticsEntry(NetworkStatisticsEntry),
+  /// Represents the type of a network
+  NetworkType(NetworkType),
+  /// Contains information about a notification
+  Notification(Notification),
+  /// Describes a group of notifications
+  NotificationGroup(NotificationGroup),
+  /// Describes the type of notifications in a notification group
+  NotificationGroupType(NotificationGroupType),
+  /// Describes the types of chats to which notification settings are applied
+  NotificationSettingsScope(NotificationSettingsScope),
+  /// Contains detailed information about a notification
+  NotificationType(NotificationType),
+  /// An object of this type is returned on a successful function call for certain functions
+  Ok(Ok),
+  /// Represents the value of an option
+  OptionValue(OptionValue),
+  /// Order information
+  OrderInfo(OrderInfo),
+  /// Describes a block of an instant view web page
+  PageBlock(PageBlock),
+  /// Contains a caption of an instant view web page block, consisting of a text and a trailing credit
+  PageBlockCaption(PageBlockCaption),
+  /// Describes a horizontal alignment of a table cell content
+  PageBlockHorizontalAlignment(PageBlockHorizontalAlignment),
+  /// Describes an item of a list page block
+  PageBlockListItem(PageBlockListItem),
+  /// Contains information about a related article
+  PageBlockRelatedArticle(PageBlockRelatedArticle),
+  /// Represents a cell of a table
+  PageBlockTableCell(PageBlockTableCell),
+  /// Describes a Vertical alignment of a table cell content
+  PageBlockVerticalAlignment(PageBlockVerticalAlignment),
+  /// Contains information about a Telegram Passport authorization form that was requested
+  PassportAuthorizationForm(PassportAuthorizationForm),
+  /// Contains information about a Telegram Passport element
+  PassportElement(PassportElement),
+  /// Contains the description of an error in a Telegram Passport element
+  PassportElementError(PassportElementError),
+  /// Contains the description of an error in a Telegram Passport element
+  PassportElementErrorSource(PassportElementErrorSource),
+  /// Contains the type of a Telegram Passport element
+  PassportElementType(PassportElementType),
+  /// Contains information about saved Telegram Passport elements
+  PassportElements(PassportElements),
+  /// Contains information about a Telegram Passport elements and corresponding errors
+  PassportElementsWithErrors(PassportElementsWithErrors),
+  /// Contains a description of the required Telegram Passport element that was requested by a service
+  PassportRequiredElement(PassportRequiredElement),
+  /// Contains information about a Telegram Passport element that was requested by a service
+  PassportSuitableElement(PassportSuitableElement),
+  /// Represents the current state of 2-step verification
+  PasswordState(PasswordState),
+  /// Contains information about an invoice payment form
+  PaymentForm(PaymentForm),
+  /// Contains information about a successful payment
+  PaymentReceipt(PaymentReceipt),
+  /// Contains the result of a payment request
+  PaymentResult(PaymentResult),
+  /// Stripe payment provider
+  PaymentsProviderStripe(PaymentsProviderStripe),
+  /// Contains the user's personal details
+  PersonalDetails(PersonalDetails),
+  /// A personal document, containing some information about a user
+  PersonalDocument(PersonalDocument),
+  /// Contains settings for the authentication of the user's phone number
+  PhoneNumberAuthenticationSettings(PhoneNumberAuthenticationSettings),
+  /// Describes a photo
+  Photo(Photo),
+  /// Photo description
+  PhotoSize(PhotoSize),
+  /// Describes a poll
+  Poll(Poll),
+  /// Describes one answer option of a poll
+  PollOption(PollOption),
+  /// Describes the type of a poll
+  PollType(PollType),
+  /// Describes a user profile photo
+  ProfilePhoto(ProfilePhoto),
+  /// Represents a list of proxy servers
+  Proxies(Proxies),
+  /// Contains information about a proxy server
+  Proxy(Proxy),
+  /// Describes the type of a proxy server
+  ProxyType(ProxyType),
+  /// Describes a type of public chats
+  PublicChatType(PublicChatType),
+  /// Contains a public HTTPS link to a message in a supergroup or channel with a username
+  PublicMessageLink(PublicMessageLink),
+  /// Contains content of a push message notification
+  PushMessageContent(PushMessageContent),
+  /// Contains a globally unique push receiver identifier, which can be used to identify which account has received a push notification
+  PushReceiverId(PushReceiverId),
+  /// Describes a recommended chat filter
+  RecommendedChatFilter(RecommendedChatFilter),
+  /// Contains a list of recommended chat filters
+  RecommendedChatFilters(RecommendedChatFilters),
+  /// Contains information about the current recovery email address
+  RecoveryEmailAddress(RecoveryEmailAddress),
+  /// Represents a remote file
+  RemoteFile(RemoteFile),
+  /// Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
+  ReplyMarkup(ReplyMarkup),
+  /// Describes a text object inside an instant-view web page
+  RichText(RichText),
+  /// Contains information about saved card credentials
+  SavedCredentials(SavedCredentials),
+  /// Contains information about notification settings for several chats
+  ScopeNotificationSettings(ScopeNotificationSettings),
+  /// Represents a filter for message search results
+  SearchMessagesFilter(SearchMessagesFilter),
+  /// Contains a value representing a number of seconds
+  Seconds(Seconds),
+  /// Represents a secret chat
+  SecretChat(SecretChat),
+  /// Describes the current secret chat state
+  SecretChatState(SecretChatState),
+  /// Contains information about one session in a Telegram application used by the current user. Sessions should be shown to the user in the returned order
+  Session(Session),
+  /// Contains a list of sessions
+  Sessions(Sessions),
+  /// One shipping option
+  ShippingOption(ShippingOption),
+  /// Describes a statistics graph
+  StatisticsGraph(StatisticsGraph),
+  /// A statistics value
+  StatisticsValue(StatisticsValue),
+  /// Describes a sticker
+  Sticker(Sticker),
+  /// Represents a sticker set
+  StickerSet(StickerSet),
+  /// Represents short information about a sticker set
+  StickerSetInfo(StickerSetInfo),
+  /// Represents a list of sticker sets
+  StickerSets(StickerSets),
+  /// Represents a list of stickers
+  Stickers(Stickers),
+  /// Contains the exact storage usage statistics split by chats and file type
+  StorageStatistics(StorageStatistics),
+  /// Contains the storage usage statistics for a specific chat
+  StorageStatisticsByChat(StorageStatisticsByChat),
+  /// Contains the storage usage statistics for a specific file type
+  StorageStatisticsByFileType(StorageStatisticsByFileType),
+  /// Contains approximate storage usage statistics, excluding files of unknown file type
+  StorageStatisticsFast(StorageStatisticsFast),
+  /// Describes an action suggested to the current user
+  SuggestedAction(SuggestedAction),
+  /// Represents a supergroup or channel with zero or more members (subscribers in the case of channels). From the point of view of the system, a channel is a special kind of a supergroup: only administrators can post and see the list of members, and posts from all administrators use the name and photo of the channel instead of individual names and profile photos. Unlike supergroups, channels can have an unlimited number of subscribers
+  Supergroup(Supergroup),
+  /// Contains full information about a supergroup or channel
+  SupergroupFullInfo(SupergroupFullInfo),
+  /// Specifies the kind of chat members to return in getSupergroupMembers
+  SupergroupMembersFilter(SupergroupMembersFilter),
+  /// Represents a URL linking to an internal Telegram entity
+  TMeUrl(TMeUrl),
+  /// Describes the type of a URL linking to an internal Telegram entity
+  TMeUrlType(TMeUrlType),
+  /// Contains a list of t.me URLs
+  TMeUrls(TMeUrls),
+  /// Contains parameters for TDLib initialization
+  TdlibParameters(TdlibParameters),
+  /// Returns information about the availability of a temporary password, which can be used for payments
+  TemporaryPasswordState(TemporaryPasswordState),
+  /// Contains Telegram terms of service
+  TermsOfService(TermsOfService),
+  /// A simple object containing a sequence of bytes; for testing only
+  TestBytes(TestBytes),
+  /// A simple object containing a number; for testing only
+  TestInt(TestInt),
+  /// A simple object containing a string; for testing only
+  TestString(TestString),
+  /// A simple object containing a vector of numbers; for testing only
+  TestVectorInt(TestVectorInt),
+  /// A simple object containing a vector of objects that hold a number; for testing only
+  TestVectorIntObject(TestVectorIntObject),
+  /// A simple object containing a vector of strings; for testing only
+  TestVectorString(TestVectorString),
+  /// A simple object containing a vector of objects that hold a string; for testing only
+  TestVectorStringObject(TestVectorStringObject),
+  /// Contains some text
+  Text(Text),
+  /// Contains a list of text entities
+  TextEntities(TextEntities),
+  /// Represents a part of the text that needs to be formatted in some unusual way
+  TextEntity(TextEntity),
+  /// Represents a part of the text which must be formatted differently
+  TextEntityType(TextEntityType),
+  /// Describes the way the text should be parsed for TextEntities
+  TextParseMode(TextParseMode),
+  /// Represents a thumbnail
+  Thumbnail(Thumbnail),
+  /// Describes format of the thumbnail
+  ThumbnailFormat(ThumbnailFormat),
+  /// Represents the categories of chats for which a list of frequently used chats can be retrieved
+  TopChatCategory(TopChatCategory),
+  /// Contains notifications about data changes
+  Update(Update),
+  /// Contains a list of updates
+  Updates(Updates),
+  /// Represents a user
+  User(User),
+  /// Contains full information about a user
+  UserFullInfo(UserFullInfo),
+  /// Describes available user privacy settings
+  UserPrivacySetting(UserPrivacySetting),
+  /// Represents a single rule for managing privacy settings
+  UserPrivacySettingRule(UserPrivacySettingRule),
+  /// A list of privacy rules. Rules are matched in the specified order. The first matched rule defines the privacy setting for a given user. If no rule matches, the action is not allowed
+  UserPrivacySettingRules(UserPrivacySettingRules),
+  /// Describes the last time the user was online
+  UserStatus(UserStatus),
+  /// Represents the type of a user. The following types are possible: regular users, deleted users and bots
+  UserType(UserType),
+  /// Represents a list of users
+  Users(Users),
+  /// Contains a temporary identifier of validated order information, which is stored for one hour. Also contains the available shipping options
+  ValidatedOrderInfo(ValidatedOrderInfo),
+  /// Describes a venue
+  Venue(Venue),
+  /// Describes a video file
+  Video(Video),
+  /// Describes a video note. The video must be equal in width and height, cropped to a circle, and stored in MPEG4 format
+  VideoNote(VideoNote),
+  /// Describes a voice note. The voice note must be encoded with the Opus codec, and stored inside an OGG container. Voice notes can have only a single audio channel
+  VoiceNote(VoiceNote),
+  /// Describes a web page preview
+  WebPage(WebPage),
+  /// Describes an instant view page for a web page
+  WebPageInstantView(WebPageInstantView),
+
+}
+
+impl Default for TdObject {
+  fn default() -> Self { TdObject::_Default(()) }
+}
+
+impl<'de> Deserialize<'de> for TdObject {
+  fn deserialize<D>(deserializer: D) -> Result<TdObject, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    let value: serde_json::Value = Deserialize::deserialize(deserializer)?;
+    let td_type = match value.get("@type").map(|t| t.as_str()) {
+      Some(Some(td_type)) => td_type.to_string(),
+      Some(None) => return Err(D::Error::custom(format!("TdObject -> @type is not a string: {}", value))),
+      None => return Err(D::Error::custom(format!("TdObject -> missing field `@type`: {}", value))),
+    };
+    match td_object_parser(&td_type) {
+      Some(parser) => parser(value).map_err(|e| D::Error::custom(format!("TdObject({}) -> {}", td_type, e))),
+      None => Ok(TdObject::Unknown { td_type, raw: value }),
+    }
+  }
+}
+
+type TdObjectParser = fn(serde_json::Value) -> Result<TdObject, serde_path_to_error::Error<serde_json::Error>>;
+
+// a parser function per type instead of one match deserializing every type, that needs too much stack
+fn td_object_parser(td_type: &str) -> Option<TdObjectParser> {
+  Some(match td_type {
+    "accountTtl" => |v| serde_path_to_error::deserialize(v).map(TdObject::AccountTtl),
+    "address" => |v| serde_path_to_error::deserialize(v).map(TdObject::Address),
+    "animatedChatPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::AnimatedChatPhoto),
+    "animation" => |v| serde_path_to_error::deserialize(v).map(TdObject::Animation),
+    "animations" => |v| serde_path_to_error::deserialize(v).map(TdObject::Animations),
+    "audio" => |v| serde_path_to_error::deserialize(v).map(TdObject::Audio),
+    "authenticationCodeInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthenticationCodeInfo),
+    "authenticationCodeTypeCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthenticationCodeType),
+    "authenticationCodeTypeFlashCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthenticationCodeType),
+    "authenticationCodeTypeSms" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthenticationCodeType),
+    "authenticationCodeTypeTelegramMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthenticationCodeType),
+    "authorizationStateClosed" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateClosing" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateLoggingOut" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateReady" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitCode" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitEncryptionKey" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitOtherDeviceConfirmation" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitPassword" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "authorizationStateWaitTdlibParameters" => |v| serde_path_to_error::deserialize(v).map(TdObject::AuthorizationState),
+    "autoDownloadSettings" => |v| serde_path_to_error::deserialize(v).map(TdObject::AutoDownloadSettings),
+    "autoDownloadSettingsPresets" => |v| serde_path_to_error::deserialize(v).map(TdObject::AutoDownloadSettingsPresets),
+    "background" => |v| serde_path_to_error::deserialize(v).map(TdObject::Background),
+    "backgroundFillGradient" => |v| serde_path_to_error::deserialize(v).map(TdObject::BackgroundFill),
+    "backgroundFillSolid" => |v| serde_path_to_error::deserialize(v).map(TdObject::BackgroundFill),
+    "backgroundTypeFill" => |v| serde_path_to_error::deserialize(v).map(TdObject::BackgroundType),
+    "backgroundTypePattern" => |v| serde_path_to_error::deserialize(v).map(TdObject::BackgroundType),
+    "backgroundTypeWallpaper" => |v| serde_path_to_error::deserialize(v).map(TdObject::BackgroundType),
+    "backgrounds" => |v| serde_path_to_error::deserialize(v).map(TdObject::Backgrounds),
+    "bankCardActionOpenUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::BankCardActionOpenUrl),
+    "bankCardInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::BankCardInfo),
+    "basicGroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::BasicGroup),
+    "basicGroupFullInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::BasicGroupFullInfo),
+    "botCommand" => |v| serde_path_to_error::deserialize(v).map(TdObject::BotCommand),
+    "botInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::BotInfo),
+    "call" => |v| serde_path_to_error::deserialize(v).map(TdObject::Call),
+    "callDiscardReasonDeclined" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallDiscardReason),
+    "callDiscardReasonDisconnected" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallDiscardReason),
+    "callDiscardReasonEmpty" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallDiscardReason),
+    "callDiscardReasonHungUp" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallDiscardReason),
+    "callDiscardReasonMissed" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallDiscardReason),
+    "callId" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallId),
+    "callProblemDistortedSpeech" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProblemDropped" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProblemEcho" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProblemInterruptions" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProblemNoise" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProblemSilentLocal" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProblemSilentRemote" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProblem),
+    "callProtocol" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallProtocol),
+    "callServer" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallServer),
+    "callServerTypeTelegramReflector" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallServerType),
+    "callServerTypeWebrtc" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallServerType),
+    "callStateDiscarded" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallState),
+    "callStateError" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallState),
+    "callStateExchangingKeys" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallState),
+    "callStateHangingUp" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallState),
+    "callStatePending" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallState),
+    "callStateReady" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallState),
+    "callbackQueryAnswer" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallbackQueryAnswer),
+    "callbackQueryPayloadData" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallbackQueryPayload),
+    "callbackQueryPayloadGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::CallbackQueryPayload),
+    "canTransferOwnershipResultOk" => |v| serde_path_to_error::deserialize(v).map(TdObject::CanTransferOwnershipResult),
+    "canTransferOwnershipResultPasswordNeeded" => |v| serde_path_to_error::deserialize(v).map(TdObject::CanTransferOwnershipResult),
+    "canTransferOwnershipResultPasswordTooFresh" => |v| serde_path_to_error::deserialize(v).map(TdObject::CanTransferOwnershipResult),
+    "canTransferOwnershipResultSessionTooFresh" => |v| serde_path_to_error::deserialize(v).map(TdObject::CanTransferOwnershipResult),
+    "chat" => |v| serde_path_to_error::deserialize(v).map(TdObject::Chat),
+    "chatActionBarAddContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatActionBar),
+    "chatActionBarReportAddBlock" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatActionBar),
+    "chatActionBarReportSpam" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatActionBar),
+    "chatActionBarReportUnrelatedLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatActionBar),
+    "chatActionBarSharePhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatActionBar),
+    "chatActionCancel" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionChoosingContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionChoosingLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionRecordingVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionRecordingVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionRecordingVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionStartPlayingGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionTyping" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionUploadingDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionUploadingPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionUploadingVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionUploadingVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatActionUploadingVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAction),
+    "chatAdministrator" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAdministrator),
+    "chatAdministrators" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatAdministrators),
+    "chatEvent" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEvent),
+    "chatEventDescriptionChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventInvitesToggled" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventIsAllHistoryAvailableToggled" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventLinkedChatChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventLocationChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventLogFilters" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventLogFilters),
+    "chatEventMemberInvited" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMemberJoined" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMemberLeft" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMemberPromoted" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMemberRestricted" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMessageDeleted" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMessageEdited" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMessagePinned" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventMessageUnpinned" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventPermissionsChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventPhotoChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventPollStopped" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventSignMessagesToggled" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventSlowModeDelayChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventStickerSetChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventTitleChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEventUsernameChanged" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEventAction),
+    "chatEvents" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatEvents),
+    "chatFilter" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatFilter),
+    "chatFilterInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatFilterInfo),
+    "chatInviteLink" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatInviteLink),
+    "chatInviteLinkInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatInviteLinkInfo),
+    "chatListArchive" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatList),
+    "chatListFilter" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatList),
+    "chatListMain" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatList),
+    "chatLists" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatLists),
+    "chatLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatLocation),
+    "chatMember" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMember),
+    "chatMemberStatusAdministrator" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMemberStatus),
+    "chatMemberStatusBanned" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMemberStatus),
+    "chatMemberStatusCreator" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMemberStatus),
+    "chatMemberStatusLeft" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMemberStatus),
+    "chatMemberStatusMember" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMemberStatus),
+    "chatMemberStatusRestricted" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMemberStatus),
+    "chatMembers" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembers),
+    "chatMembersFilterAdministrators" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembersFilter),
+    "chatMembersFilterBanned" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembersFilter),
+    "chatMembersFilterBots" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembersFilter),
+    "chatMembersFilterContacts" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembersFilter),
+    "chatMembersFilterMembers" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembersFilter),
+    "chatMembersFilterRestricted" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatMembersFilter),
+    "chatNearby" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatNearby),
+    "chatNotificationSettings" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatNotificationSettings),
+    "chatPermissions" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatPermissions),
+    "chatPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatPhoto),
+    "chatPhotoInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatPhotoInfo),
+    "chatPhotos" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatPhotos),
+    "chatPosition" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatPosition),
+    "chatReportReasonChildAbuse" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatReportReasonCopyright" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatReportReasonCustom" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatReportReasonPornography" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatReportReasonSpam" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatReportReasonUnrelatedLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatReportReasonViolence" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatReportReason),
+    "chatSourceMtprotoProxy" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatSource),
+    "chatSourcePublicServiceAnnouncement" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatSource),
+    "chatStatisticsAdministratorActionsInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatStatisticsAdministratorActionsInfo),
+    "chatStatisticsChannel" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatStatistics),
+    "chatStatisticsInviterInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatStatisticsInviterInfo),
+    "chatStatisticsMessageInteractionInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatStatisticsMessageInteractionInfo),
+    "chatStatisticsMessageSenderInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatStatisticsMessageSenderInfo),
+    "chatStatisticsSupergroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatStatistics),
+    "chatTypeBasicGroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatType),
+    "chatTypePrivate" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatType),
+    "chatTypeSecret" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatType),
+    "chatTypeSupergroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatType),
+    "chats" => |v| serde_path_to_error::deserialize(v).map(TdObject::Chats),
+    "chatsNearby" => |v| serde_path_to_error::deserialize(v).map(TdObject::ChatsNearby),
+    "checkChatUsernameResultOk" => |v| serde_path_to_error::deserialize(v).map(TdObject::CheckChatUsernameResult),
+    "checkChatUsernameResultPublicChatsTooMuch" => |v| serde_path_to_error::deserialize(v).map(TdObject::CheckChatUsernameResult),
+    "checkChatUsernameResultPublicGroupsUnavailable" => |v| serde_path_to_error::deserialize(v).map(TdObject::CheckChatUsernameResult),
+    "checkChatUsernameResultUsernameInvalid" => |v| serde_path_to_error::deserialize(v).map(TdObject::CheckChatUsernameResult),
+    "checkChatUsernameResultUsernameOccupied" => |v| serde_path_to_error::deserialize(v).map(TdObject::CheckChatUsernameResult),
+    "connectedWebsite" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectedWebsite),
+    "connectedWebsites" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectedWebsites),
+    "connectionStateConnecting" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectionState),
+    "connectionStateConnectingToProxy" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectionState),
+    "connectionStateReady" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectionState),
+    "connectionStateUpdating" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectionState),
+    "connectionStateWaitingForNetwork" => |v| serde_path_to_error::deserialize(v).map(TdObject::ConnectionState),
+    "contact" => |v| serde_path_to_error::deserialize(v).map(TdObject::Contact),
+    "count" => |v| serde_path_to_error::deserialize(v).map(TdObject::Count),
+    "customRequestResult" => |v| serde_path_to_error::deserialize(v).map(TdObject::CustomRequestResult),
+    "databaseStatistics" => |v| serde_path_to_error::deserialize(v).map(TdObject::DatabaseStatistics),
+    "date" => |v| serde_path_to_error::deserialize(v).map(TdObject::Date),
+    "dateRange" => |v| serde_path_to_error::deserialize(v).map(TdObject::DateRange),
+    "datedFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::DatedFile),
+    "deepLinkInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeepLinkInfo),
+    "deviceTokenApplePush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenApplePushVoIP" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenBlackBerryPush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenFirebaseCloudMessaging" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenMicrosoftPush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenMicrosoftPushVoIP" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenSimplePush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenTizenPush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenUbuntuPush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenWebPush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "deviceTokenWindowsPush" => |v| serde_path_to_error::deserialize(v).map(TdObject::DeviceToken),
+    "document" => |v| serde_path_to_error::deserialize(v).map(TdObject::Document),
+    "draftMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::DraftMessage),
+    "emailAddressAuthenticationCodeInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::EmailAddressAuthenticationCodeInfo),
+    "emojis" => |v| serde_path_to_error::deserialize(v).map(TdObject::Emojis),
+    "encryptedCredentials" => |v| serde_path_to_error::deserialize(v).map(TdObject::EncryptedCredentials),
+    "encryptedPassportElement" => |v| serde_path_to_error::deserialize(v).map(TdObject::EncryptedPassportElement),
+    "error" => |v| serde_path_to_error::deserialize(v).map(TdObject::Error),
+    "file" => |v| serde_path_to_error::deserialize(v).map(TdObject::File),
+    "filePart" => |v| serde_path_to_error::deserialize(v).map(TdObject::FilePart),
+    "fileTypeAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeNone" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeProfilePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeSecret" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeSecretThumbnail" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeSecure" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeSticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeThumbnail" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeUnknown" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "fileTypeWallpaper" => |v| serde_path_to_error::deserialize(v).map(TdObject::FileType),
+    "formattedText" => |v| serde_path_to_error::deserialize(v).map(TdObject::FormattedText),
+    "foundMessages" => |v| serde_path_to_error::deserialize(v).map(TdObject::FoundMessages),
+    "game" => |v| serde_path_to_error::deserialize(v).map(TdObject::Game),
+    "gameHighScore" => |v| serde_path_to_error::deserialize(v).map(TdObject::GameHighScore),
+    "gameHighScores" => |v| serde_path_to_error::deserialize(v).map(TdObject::GameHighScores),
+    "hashtags" => |v| serde_path_to_error::deserialize(v).map(TdObject::Hashtags),
+    "httpUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::HttpUrl),
+    "identityDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::IdentityDocument),
+    "importedContacts" => |v| serde_path_to_error::deserialize(v).map(TdObject::ImportedContacts),
+    "inlineKeyboardButton" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButton),
+    "inlineKeyboardButtonTypeBuy" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButtonType),
+    "inlineKeyboardButtonTypeCallback" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButtonType),
+    "inlineKeyboardButtonTypeCallbackGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButtonType),
+    "inlineKeyboardButtonTypeLoginUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButtonType),
+    "inlineKeyboardButtonTypeSwitchInline" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButtonType),
+    "inlineKeyboardButtonTypeUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineKeyboardButtonType),
+    "inlineQueryResultAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultArticle" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultSticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultVenue" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResultVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResult),
+    "inlineQueryResults" => |v| serde_path_to_error::deserialize(v).map(TdObject::InlineQueryResults),
+    "inputBackgroundLocal" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputBackground),
+    "inputBackgroundRemote" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputBackground),
+    "inputChatPhotoAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputChatPhoto),
+    "inputChatPhotoPrevious" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputChatPhoto),
+    "inputChatPhotoStatic" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputChatPhoto),
+    "inputCredentialsAndroidPay" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputCredentials),
+    "inputCredentialsApplePay" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputCredentials),
+    "inputCredentialsNew" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputCredentials),
+    "inputCredentialsSaved" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputCredentials),
+    "inputFileGenerated" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputFile),
+    "inputFileId" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputFile),
+    "inputFileLocal" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputFile),
+    "inputFileRemote" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputFile),
+    "inputIdentityDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputIdentityDocument),
+    "inputInlineQueryResultAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultArticle" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultSticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultVenue" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputInlineQueryResultVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputInlineQueryResult),
+    "inputMessageAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageDice" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageForwarded" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageInvoice" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessagePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessagePoll" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageSticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageText" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageVenue" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputMessageVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputMessageContent),
+    "inputPassportElementAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementBankStatement" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementDriverLicense" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementEmailAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementError" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementError),
+    "inputPassportElementErrorSourceDataField" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceFiles" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceFrontSide" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceReverseSide" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceSelfie" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceTranslationFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceTranslationFiles" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementErrorSourceUnspecified" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElementErrorSource),
+    "inputPassportElementIdentityCard" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementInternalPassport" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementPassport" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementPassportRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementPersonalDetails" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementRentalAgreement" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementTemporaryRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPassportElementUtilityBill" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPassportElement),
+    "inputPersonalDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputPersonalDocument),
+    "inputStickerAnimated" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputSticker),
+    "inputStickerStatic" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputSticker),
+    "inputThumbnail" => |v| serde_path_to_error::deserialize(v).map(TdObject::InputThumbnail),
+    "invoice" => |v| serde_path_to_error::deserialize(v).map(TdObject::Invoice),
+    "jsonObjectMember" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonObjectMember),
+    "jsonValueArray" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonValue),
+    "jsonValueBoolean" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonValue),
+    "jsonValueNull" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonValue),
+    "jsonValueNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonValue),
+    "jsonValueObject" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonValue),
+    "jsonValueString" => |v| serde_path_to_error::deserialize(v).map(TdObject::JsonValue),
+    "keyboardButton" => |v| serde_path_to_error::deserialize(v).map(TdObject::KeyboardButton),
+    "keyboardButtonTypeRequestLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::KeyboardButtonType),
+    "keyboardButtonTypeRequestPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::KeyboardButtonType),
+    "keyboardButtonTypeRequestPoll" => |v| serde_path_to_error::deserialize(v).map(TdObject::KeyboardButtonType),
+    "keyboardButtonTypeText" => |v| serde_path_to_error::deserialize(v).map(TdObject::KeyboardButtonType),
+    "labeledPricePart" => |v| serde_path_to_error::deserialize(v).map(TdObject::LabeledPricePart),
+    "languagePackInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::LanguagePackInfo),
+    "languagePackString" => |v| serde_path_to_error::deserialize(v).map(TdObject::LanguagePackString),
+    "languagePackStringValueDeleted" => |v| serde_path_to_error::deserialize(v).map(TdObject::LanguagePackStringValue),
+    "languagePackStringValueOrdinary" => |v| serde_path_to_error::deserialize(v).map(TdObject::LanguagePackStringValue),
+    "languagePackStringValuePluralized" => |v| serde_path_to_error::deserialize(v).map(TdObject::LanguagePackStringValue),
+    "languagePackStrings" => |v| serde_path_to_error::deserialize(v).map(TdObject::LanguagePackStrings),
+    "localFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::LocalFile),
+    "localizationTargetInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::LocalizationTargetInfo),
+    "location" => |v| serde_path_to_error::deserialize(v).map(TdObject::Location),
+    "logStreamDefault" => |v| serde_path_to_error::deserialize(v).map(TdObject::LogStream),
+    "logStreamEmpty" => |v| serde_path_to_error::deserialize(v).map(TdObject::LogStream),
+    "logStreamFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::LogStream),
+    "logTags" => |v| serde_path_to_error::deserialize(v).map(TdObject::LogTags),
+    "logVerbosityLevel" => |v| serde_path_to_error::deserialize(v).map(TdObject::LogVerbosityLevel),
+    "loginUrlInfoOpen" => |v| serde_path_to_error::deserialize(v).map(TdObject::LoginUrlInfo),
+    "loginUrlInfoRequestConfirmation" => |v| serde_path_to_error::deserialize(v).map(TdObject::LoginUrlInfo),
+    "maskPointChin" => |v| serde_path_to_error::deserialize(v).map(TdObject::MaskPoint),
+    "maskPointEyes" => |v| serde_path_to_error::deserialize(v).map(TdObject::MaskPoint),
+    "maskPointForehead" => |v| serde_path_to_error::deserialize(v).map(TdObject::MaskPoint),
+    "maskPointMouth" => |v| serde_path_to_error::deserialize(v).map(TdObject::MaskPoint),
+    "maskPosition" => |v| serde_path_to_error::deserialize(v).map(TdObject::MaskPosition),
+    "message" => |v| serde_path_to_error::deserialize(v).map(TdObject::Message),
+    "messageAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageBasicGroupChatCreate" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatAddMembers" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatChangePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatChangeTitle" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatDeleteMember" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatDeletePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatJoinByLink" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatSetTtl" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatUpgradeFrom" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageChatUpgradeTo" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageContactRegistered" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageCopyOptions" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageCopyOptions),
+    "messageCustomServiceAction" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageDice" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageExpiredPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageExpiredVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageForwardInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageForwardInfo),
+    "messageForwardOriginChannel" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageForwardOrigin),
+    "messageForwardOriginHiddenUser" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageForwardOrigin),
+    "messageForwardOriginUser" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageForwardOrigin),
+    "messageGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageGameScore" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageInvoice" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageLinkInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageLinkInfo),
+    "messageLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePassportDataReceived" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePassportDataSent" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePaymentSuccessful" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePaymentSuccessfulBot" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePinMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messagePoll" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageSchedulingStateSendAtDate" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageSchedulingState),
+    "messageSchedulingStateSendWhenOnline" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageSchedulingState),
+    "messageScreenshotTaken" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageSendOptions" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageSendOptions),
+    "messageSendingStateFailed" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageSendingState),
+    "messageSendingStatePending" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageSendingState),
+    "messageSticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageSupergroupChatCreate" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageText" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageUnsupported" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageVenue" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messageWebsiteConnected" => |v| serde_path_to_error::deserialize(v).map(TdObject::MessageContent),
+    "messages" => |v| serde_path_to_error::deserialize(v).map(TdObject::Messages),
+    "minithumbnail" => |v| serde_path_to_error::deserialize(v).map(TdObject::Minithumbnail),
+    "networkStatistics" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkStatistics),
+    "networkStatisticsEntryCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkStatisticsEntry),
+    "networkStatisticsEntryFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkStatisticsEntry),
+    "networkTypeMobile" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkType),
+    "networkTypeMobileRoaming" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkType),
+    "networkTypeNone" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkType),
+    "networkTypeOther" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkType),
+    "networkTypeWiFi" => |v| serde_path_to_error::deserialize(v).map(TdObject::NetworkType),
+    "notification" => |v| serde_path_to_error::deserialize(v).map(TdObject::Notification),
+    "notificationGroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationGroup),
+    "notificationGroupTypeCalls" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationGroupType),
+    "notificationGroupTypeMentions" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationGroupType),
+    "notificationGroupTypeMessages" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationGroupType),
+    "notificationGroupTypeSecretChat" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationGroupType),
+    "notificationSettingsScopeChannelChats" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationSettingsScope),
+    "notificationSettingsScopeGroupChats" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationSettingsScope),
+    "notificationSettingsScopePrivateChats" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationSettingsScope),
+    "notificationTypeNewCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationType),
+    "notificationTypeNewMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationType),
+    "notificationTypeNewPushMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationType),
+    "notificationTypeNewSecretChat" => |v| serde_path_to_error::deserialize(v).map(TdObject::NotificationType),
+    "ok" => |v| serde_path_to_error::deserialize(v).map(TdObject::Ok),
+    "optionValueBoolean" => |v| serde_path_to_error::deserialize(v).map(TdObject::OptionValue),
+    "optionValueEmpty" => |v| serde_path_to_error::deserialize(v).map(TdObject::OptionValue),
+    "optionValueInteger" => |v| serde_path_to_error::deserialize(v).map(TdObject::OptionValue),
+    "optionValueString" => |v| serde_path_to_error::deserialize(v).map(TdObject::OptionValue),
+    "orderInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::OrderInfo),
+    "pageBlockAnchor" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockAuthorDate" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockBlockQuote" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockCaption" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockCaption),
+    "pageBlockChatLink" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockCollage" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockCover" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockDetails" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockDivider" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockEmbedded" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockEmbeddedPost" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockFooter" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockHeader" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockHorizontalAlignmentCenter" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockHorizontalAlignment),
+    "pageBlockHorizontalAlignmentLeft" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockHorizontalAlignment),
+    "pageBlockHorizontalAlignmentRight" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockHorizontalAlignment),
+    "pageBlockKicker" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockList" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockListItem" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockListItem),
+    "pageBlockMap" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockParagraph" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockPreformatted" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockPullQuote" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockRelatedArticle" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockRelatedArticle),
+    "pageBlockRelatedArticles" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockSlideshow" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockSubheader" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockSubtitle" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockTable" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockTableCell" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockTableCell),
+    "pageBlockTitle" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockVerticalAlignmentBottom" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockVerticalAlignment),
+    "pageBlockVerticalAlignmentMiddle" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockVerticalAlignment),
+    "pageBlockVerticalAlignmentTop" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlockVerticalAlignment),
+    "pageBlockVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "pageBlockVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::PageBlock),
+    "passportAuthorizationForm" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportAuthorizationForm),
+    "passportElementAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementBankStatement" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementDriverLicense" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementEmailAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementError" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementError),
+    "passportElementErrorSourceDataField" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceFiles" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceFrontSide" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceReverseSide" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceSelfie" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceTranslationFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceTranslationFiles" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementErrorSourceUnspecified" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementErrorSource),
+    "passportElementIdentityCard" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementInternalPassport" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementPassport" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementPassportRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementPersonalDetails" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementRentalAgreement" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementTemporaryRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElementTypeAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeBankStatement" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeDriverLicense" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeEmailAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeIdentityCard" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeInternalPassport" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypePassport" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypePassportRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypePersonalDetails" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypePhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeRentalAgreement" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeTemporaryRegistration" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementTypeUtilityBill" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementType),
+    "passportElementUtilityBill" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElement),
+    "passportElements" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElements),
+    "passportElementsWithErrors" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportElementsWithErrors),
+    "passportRequiredElement" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportRequiredElement),
+    "passportSuitableElement" => |v| serde_path_to_error::deserialize(v).map(TdObject::PassportSuitableElement),
+    "passwordState" => |v| serde_path_to_error::deserialize(v).map(TdObject::PasswordState),
+    "paymentForm" => |v| serde_path_to_error::deserialize(v).map(TdObject::PaymentForm),
+    "paymentReceipt" => |v| serde_path_to_error::deserialize(v).map(TdObject::PaymentReceipt),
+    "paymentResult" => |v| serde_path_to_error::deserialize(v).map(TdObject::PaymentResult),
+    "paymentsProviderStripe" => |v| serde_path_to_error::deserialize(v).map(TdObject::PaymentsProviderStripe),
+    "personalDetails" => |v| serde_path_to_error::deserialize(v).map(TdObject::PersonalDetails),
+    "personalDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::PersonalDocument),
+    "phoneNumberAuthenticationSettings" => |v| serde_path_to_error::deserialize(v).map(TdObject::PhoneNumberAuthenticationSettings),
+    "photo" => |v| serde_path_to_error::deserialize(v).map(TdObject::Photo),
+    "photoSize" => |v| serde_path_to_error::deserialize(v).map(TdObject::PhotoSize),
+    "poll" => |v| serde_path_to_error::deserialize(v).map(TdObject::Poll),
+    "pollOption" => |v| serde_path_to_error::deserialize(v).map(TdObject::PollOption),
+    "pollTypeQuiz" => |v| serde_path_to_error::deserialize(v).map(TdObject::PollType),
+    "pollTypeRegular" => |v| serde_path_to_error::deserialize(v).map(TdObject::PollType),
+    "profilePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::ProfilePhoto),
+    "proxies" => |v| serde_path_to_error::deserialize(v).map(TdObject::Proxies),
+    "proxy" => |v| serde_path_to_error::deserialize(v).map(TdObject::Proxy),
+    "proxyTypeHttp" => |v| serde_path_to_error::deserialize(v).map(TdObject::ProxyType),
+    "proxyTypeMtproto" => |v| serde_path_to_error::deserialize(v).map(TdObject::ProxyType),
+    "proxyTypeSocks5" => |v| serde_path_to_error::deserialize(v).map(TdObject::ProxyType),
+    "publicChatTypeHasUsername" => |v| serde_path_to_error::deserialize(v).map(TdObject::PublicChatType),
+    "publicChatTypeIsLocationBased" => |v| serde_path_to_error::deserialize(v).map(TdObject::PublicChatType),
+    "publicMessageLink" => |v| serde_path_to_error::deserialize(v).map(TdObject::PublicMessageLink),
+    "pushMessageContentAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentBasicGroupChatCreate" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentChatAddMembers" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentChatChangePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentChatChangeTitle" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentChatDeleteMember" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentChatJoinByLink" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentContact" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentContactRegistered" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentGame" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentGameScore" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentHidden" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentInvoice" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentLocation" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentMediaAlbum" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentMessageForwards" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentPoll" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentScreenshotTaken" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentSticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentText" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushMessageContentVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushMessageContent),
+    "pushReceiverId" => |v| serde_path_to_error::deserialize(v).map(TdObject::PushReceiverId),
+    "recommendedChatFilter" => |v| serde_path_to_error::deserialize(v).map(TdObject::RecommendedChatFilter),
+    "recommendedChatFilters" => |v| serde_path_to_error::deserialize(v).map(TdObject::RecommendedChatFilters),
+    "recoveryEmailAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::RecoveryEmailAddress),
+    "remoteFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::RemoteFile),
+    "replyMarkupForceReply" => |v| serde_path_to_error::deserialize(v).map(TdObject::ReplyMarkup),
+    "replyMarkupInlineKeyboard" => |v| serde_path_to_error::deserialize(v).map(TdObject::ReplyMarkup),
+    "replyMarkupRemoveKeyboard" => |v| serde_path_to_error::deserialize(v).map(TdObject::ReplyMarkup),
+    "replyMarkupShowKeyboard" => |v| serde_path_to_error::deserialize(v).map(TdObject::ReplyMarkup),
+    "richTextAnchor" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextAnchorLink" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextBold" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextEmailAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextFixed" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextIcon" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextItalic" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextMarked" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextPlain" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextReference" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextStrikethrough" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextSubscript" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextSuperscript" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextUnderline" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTextUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "richTexts" => |v| serde_path_to_error::deserialize(v).map(TdObject::RichText),
+    "savedCredentials" => |v| serde_path_to_error::deserialize(v).map(TdObject::SavedCredentials),
+    "scopeNotificationSettings" => |v| serde_path_to_error::deserialize(v).map(TdObject::ScopeNotificationSettings),
+    "searchMessagesFilterAnimation" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterAudio" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterChatPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterDocument" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterEmpty" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterFailedToSend" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterMention" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterMissedCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterPhotoAndVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterUnreadMention" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterVideo" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterVoiceAndVideoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "searchMessagesFilterVoiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::SearchMessagesFilter),
+    "seconds" => |v| serde_path_to_error::deserialize(v).map(TdObject::Seconds),
+    "secretChat" => |v| serde_path_to_error::deserialize(v).map(TdObject::SecretChat),
+    "secretChatStateClosed" => |v| serde_path_to_error::deserialize(v).map(TdObject::SecretChatState),
+    "secretChatStatePending" => |v| serde_path_to_error::deserialize(v).map(TdObject::SecretChatState),
+    "secretChatStateReady" => |v| serde_path_to_error::deserialize(v).map(TdObject::SecretChatState),
+    "session" => |v| serde_path_to_error::deserialize(v).map(TdObject::Session),
+    "sessions" => |v| serde_path_to_error::deserialize(v).map(TdObject::Sessions),
+    "shippingOption" => |v| serde_path_to_error::deserialize(v).map(TdObject::ShippingOption),
+    "statisticsGraphAsync" => |v| serde_path_to_error::deserialize(v).map(TdObject::StatisticsGraph),
+    "statisticsGraphData" => |v| serde_path_to_error::deserialize(v).map(TdObject::StatisticsGraph),
+    "statisticsGraphError" => |v| serde_path_to_error::deserialize(v).map(TdObject::StatisticsGraph),
+    "statisticsValue" => |v| serde_path_to_error::deserialize(v).map(TdObject::StatisticsValue),
+    "sticker" => |v| serde_path_to_error::deserialize(v).map(TdObject::Sticker),
+    "stickerSet" => |v| serde_path_to_error::deserialize(v).map(TdObject::StickerSet),
+    "stickerSetInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::StickerSetInfo),
+    "stickerSets" => |v| serde_path_to_error::deserialize(v).map(TdObject::StickerSets),
+    "stickers" => |v| serde_path_to_error::deserialize(v).map(TdObject::Stickers),
+    "storageStatistics" => |v| serde_path_to_error::deserialize(v).map(TdObject::StorageStatistics),
+    "storageStatisticsByChat" => |v| serde_path_to_error::deserialize(v).map(TdObject::StorageStatisticsByChat),
+    "storageStatisticsByFileType" => |v| serde_path_to_error::deserialize(v).map(TdObject::StorageStatisticsByFileType),
+    "storageStatisticsFast" => |v| serde_path_to_error::deserialize(v).map(TdObject::StorageStatisticsFast),
+    "suggestedActionCheckPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::SuggestedAction),
+    "suggestedActionEnableArchiveAndMuteNewChats" => |v| serde_path_to_error::deserialize(v).map(TdObject::SuggestedAction),
+    "supergroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::Supergroup),
+    "supergroupFullInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupFullInfo),
+    "supergroupMembersFilterAdministrators" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "supergroupMembersFilterBanned" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "supergroupMembersFilterBots" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "supergroupMembersFilterContacts" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "supergroupMembersFilterRecent" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "supergroupMembersFilterRestricted" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "supergroupMembersFilterSearch" => |v| serde_path_to_error::deserialize(v).map(TdObject::SupergroupMembersFilter),
+    "tMeUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::TMeUrl),
+    "tMeUrlTypeChatInvite" => |v| serde_path_to_error::deserialize(v).map(TdObject::TMeUrlType),
+    "tMeUrlTypeStickerSet" => |v| serde_path_to_error::deserialize(v).map(TdObject::TMeUrlType),
+    "tMeUrlTypeSupergroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::TMeUrlType),
+    "tMeUrlTypeUser" => |v| serde_path_to_error::deserialize(v).map(TdObject::TMeUrlType),
+    "tMeUrls" => |v| serde_path_to_error::deserialize(v).map(TdObject::TMeUrls),
+    "tdlibParameters" => |v| serde_path_to_error::deserialize(v).map(TdObject::TdlibParameters),
+    "temporaryPasswordState" => |v| serde_path_to_error::deserialize(v).map(TdObject::TemporaryPasswordState),
+    "termsOfService" => |v| serde_path_to_error::deserialize(v).map(TdObject::TermsOfService),
+    "testBytes" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestBytes),
+    "testInt" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestInt),
+    "testString" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestString),
+    "testVectorInt" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestVectorInt),
+    "testVectorIntObject" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestVectorIntObject),
+    "testVectorString" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestVectorString),
+    "testVectorStringObject" => |v| serde_path_to_error::deserialize(v).map(TdObject::TestVectorStringObject),
+    "text" => |v| serde_path_to_error::deserialize(v).map(TdObject::Text),
+    "textEntities" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntities),
+    "textEntity" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntity),
+    "textEntityTypeBankCardNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeBold" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeBotCommand" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeCashtag" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeCode" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeEmailAddress" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeHashtag" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeItalic" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeMention" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeMentionName" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypePhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypePre" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypePreCode" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeStrikethrough" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeTextUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeUnderline" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textEntityTypeUrl" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextEntityType),
+    "textParseModeHTML" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextParseMode),
+    "textParseModeMarkdown" => |v| serde_path_to_error::deserialize(v).map(TdObject::TextParseMode),
+    "thumbnail" => |v| serde_path_to_error::deserialize(v).map(TdObject::Thumbnail),
+    "thumbnailFormatGif" => |v| serde_path_to_error::deserialize(v).map(TdObject::ThumbnailFormat),
+    "thumbnailFormatJpeg" => |v| serde_path_to_error::deserialize(v).map(TdObject::ThumbnailFormat),
+    "thumbnailFormatMpeg4" => |v| serde_path_to_error::deserialize(v).map(TdObject::ThumbnailFormat),
+    "thumbnailFormatPng" => |v| serde_path_to_error::deserialize(v).map(TdObject::ThumbnailFormat),
+    "thumbnailFormatTgs" => |v| serde_path_to_error::deserialize(v).map(TdObject::ThumbnailFormat),
+    "thumbnailFormatWebp" => |v| serde_path_to_error::deserialize(v).map(TdObject::ThumbnailFormat),
+    "topChatCategoryBots" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "topChatCategoryCalls" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "topChatCategoryChannels" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "topChatCategoryForwardChats" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "topChatCategoryGroups" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "topChatCategoryInlineBots" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "topChatCategoryUsers" => |v| serde_path_to_error::deserialize(v).map(TdObject::TopChatCategory),
+    "updateActiveNotifications" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateAnimationSearchParameters" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateAuthorizationState" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateBasicGroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateBasicGroupFullInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateCall" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatActionBar" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatDefaultDisableNotification" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatDraftMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatFilters" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatHasScheduledMessages" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatIsMarkedAsUnread" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatLastMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatNotificationSettings" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatOnlineMemberCount" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatPermissions" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatPhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatPinnedMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatPosition" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatReadInbox" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatReadOutbox" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatReplyMarkup" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatTitle" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateChatUnreadMentionCount" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateConnectionState" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateDeleteMessages" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateDiceEmojis" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateFavoriteStickers" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateFile" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateFileGenerationStart" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateFileGenerationStop" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateHavePendingNotifications" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateInstalledStickerSets" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateLanguagePackStrings" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageContent" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageContentOpened" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageEdited" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageLiveLocationViewed" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageMentionRead" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageSendAcknowledged" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageSendFailed" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageSendSucceeded" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateMessageViews" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewCallSignalingData" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewCallbackQuery" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewChat" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewChosenInlineResult" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewCustomEvent" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewCustomQuery" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewInlineCallbackQuery" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewInlineQuery" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewMessage" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewPreCheckoutQuery" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNewShippingQuery" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNotification" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateNotificationGroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateOption" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updatePoll" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updatePollAnswer" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateRecentStickers" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateSavedAnimations" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateScopeNotificationSettings" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateSecretChat" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateSelectedBackground" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateServiceNotification" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateStickerSet" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateSuggestedActions" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateSupergroup" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateSupergroupFullInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateTermsOfService" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateTrendingStickerSets" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUnreadChatCount" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUnreadMessageCount" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUser" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUserChatAction" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUserFullInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUserPrivacySettingRules" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUserStatus" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updateUsersNearby" => |v| serde_path_to_error::deserialize(v).map(TdObject::Update),
+    "updates" => |v| serde_path_to_error::deserialize(v).map(TdObject::Updates),
+    "user" => |v| serde_path_to_error::deserialize(v).map(TdObject::User),
+    "userFullInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserFullInfo),
+    "userPrivacySettingAllowCalls" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingAllowChatInvites" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingAllowFindingByPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingAllowPeerToPeerCalls" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingRuleAllowAll" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleAllowChatMembers" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleAllowContacts" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleAllowUsers" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleRestrictAll" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleRestrictChatMembers" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleRestrictContacts" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRuleRestrictUsers" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRule),
+    "userPrivacySettingRules" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySettingRules),
+    "userPrivacySettingShowLinkInForwardedMessages" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingShowPhoneNumber" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingShowProfilePhoto" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userPrivacySettingShowStatus" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserPrivacySetting),
+    "userStatusEmpty" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserStatus),
+    "userStatusLastMonth" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserStatus),
+    "userStatusLastWeek" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserStatus),
+    "userStatusOffline" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserStatus),
+    "userStatusOnline" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserStatus),
+    "userStatusRecently" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserStatus),
+    "userTypeBot" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserType),
+    "userTypeDeleted" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserType),
+    "userTypeRegular" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserType),
+    "userTypeUnknown" => |v| serde_path_to_error::deserialize(v).map(TdObject::UserType),
+    "users" => |v| serde_path_to_error::deserialize(v).map(TdObject::Users),
+    "validatedOrderInfo" => |v| serde_path_to_error::deserialize(v).map(TdObject::ValidatedOrderInfo),
+    "venue" => |v| serde_path_to_error::deserialize(v).map(TdObject::Venue),
+    "video" => |v| serde_path_to_error::deserialize(v).map(TdObject::Video),
+    "videoNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::VideoNote),
+    "voiceNote" => |v| serde_path_to_error::deserialize(v).map(TdObject::VoiceNote),
+    "webPage" => |v| serde_path_to_error::deserialize(v).map(TdObject::WebPage),
+    "webPageInstantView" => |v| serde_path_to_error::deserialize(v).map(TdObject::WebPageInstantView),
+    _ => return None,
+  })
+}
+
+impl RObject for TdObject {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+      TdObject::AccountTtl(t) => t.td_name(),
+      TdObject::Address(t) => t.td_name(),
+      TdObject::AnimatedChatPhoto(t) => t.td_name(),
+      TdObject::Animation(t) => t.td_name(),
+      TdObject::Animations(t) => t.td_name(),
+      TdObject::Audio(t) => t.td_name(),
+      TdObject::AuthenticationCodeInfo(t) => t.td_name(),
+      TdObject::AuthenticationCodeType(t) => t.td_name(),
+      TdObject::AuthorizationState(t) => t.td_name(),
+      TdObject::AutoDownloadSettings(t) => t.td_name(),
+      TdObject::AutoDownloadSettingsPresets(t) => t.td_name(),
+      TdObject::Background(t) => t.td_name(),
+      TdObject::BackgroundFill(t) => t.td_name(),
+      TdObject::BackgroundType(t) => t.td_name(),
+      TdObject::Backgrounds(t) => t.td_name(),
+      TdObject::BankCardActionOpenUrl(t) => t.td_name(),
+      TdObject::BankCardInfo(t) => t.td_name(),
+      TdObject::BasicGroup(t) => t.td_name(),
+      TdObject::BasicGroupFullInfo(t) => t.td_name(),
+      TdObject::BotCommand(t) => t.td_name(),
+      TdObject::BotInfo(t) => t.td_name(),
+      TdObject::Call(t) => t.td_name(),
+      TdObject::CallDiscardReason(t) => t.td_name(),
+      TdObject::CallId(t) => t.td_name(),
+      TdObject::CallProblem(t) => t.td_name(),
+      TdObject::CallProtocol(t) => t.td_name(),
+      TdObject::CallServer(t) => t.td_name(),
+      TdObject::CallServerType(t) => t.td_name(),
+      TdObject::CallState(t) => t.td_name(),
+      TdObject::CallbackQueryAnswer(t) => t.td_name(),
+      TdObject::CallbackQueryPayload(t) => t.td_name(),
+      TdObject::CanTransferOwnershipResult(t) => t.td_name(),
+      TdObject::Chat(t) => t.td_name(),
+      TdObject::ChatAction(t) => t.td_name(),
+      TdObject::ChatActionBar(t) => t.td_name(),
+      TdObject::ChatAdministrator(t) => t.td_name(),
+      TdObject::ChatAdministrators(t) => t.td_name(),
+      TdObject::ChatEvent(t) => t.td_name(),
+      TdObject::ChatEventAction(t) => t.td_name(),
+      TdObject::ChatEventLogFilters(t) => t.td_name(),
+      TdObject::ChatEvents(t) => t.td_name(),
+      TdObject::ChatFilter(t) => t.td_name(),
+      TdObject::ChatFilterInfo(t) => t.td_name(),
+      TdObject::ChatInviteLink(t) => t.td_name(),
+      TdObject::ChatInviteLinkInfo(t) => t.td_name(),
+      TdObject::ChatList(t) => t.td_name(),
+      TdObject::ChatLists(t) => t.td_name(),
+      TdObject::ChatLocation(t) => t.td_name(),
+      TdObject::ChatMember(t) => t.td_name(),
+      TdObject::ChatMemberStatus(t) => t.td_name(),
+      TdObject::ChatMembers(t) => t.td_name(),
+      TdObject::ChatMembersFilter(t) => t.td_name(),
+      TdObject::ChatNearby(t) => t.td_name(),
+      TdObject::ChatNotificationSettings(t) => t.td_name(),
+      TdObject::ChatPermissions(t) => t.td_name(),
+      TdObject::ChatPhoto(t) => t.td_name(),
+      TdObject::ChatPhotoInfo(t) => t.td_name(),
+      TdObject::ChatPhotos(t) => t.td_name(),
+      TdObject::ChatPosition(t) => t.td_name(),
+      TdObject::ChatReportReason(t) => t.td_name(),
+      TdObject::ChatSource(t) => t.td_name(),
+      TdObject::ChatStatistics(t) => t.td_name(),
+      TdObject::ChatStatisticsAdministratorActionsInfo(t) => t.td_name(),
+      TdObject::ChatStatisticsInviterInfo(t) => t.td_name(),
+      TdObject::ChatStatisticsMessageInteractionInfo(t) => t.td_name(),
+      TdObject::ChatStatisticsMessageSenderInfo(t) => t.td_name(),
+      TdObject::ChatType(t) => t.td_name(),
+      TdObject::Chats(t) => t.td_name(),
+      TdObject::ChatsNearby(t) => t.td_name(),
+      TdObject::CheckChatUsernameResult(t) => t.td_name(),
+      TdObject::ConnectedWebsite(t) => t.td_name(),
+      TdObject::ConnectedWebsites(t) => t.td_name(),
+      TdObject::ConnectionState(t) => t.td_name(),
+      TdObject::Contact(t) => t.td_name(),
+      TdObject::Count(t) => t.td_name(),
+      TdObject::CustomRequestResult(t) => t.td_name(),
+      TdObject::DatabaseStatistics(t) => t.td_name(),
+      TdObject::Date(t) => t.td_name(),
+      TdObject::DateRange(t) => t.td_name(),
+      TdObject::DatedFile(t) => t.td_name(),
+      TdObject::DeepLinkInfo(t) => t.td_name(),
+      TdObject::DeviceToken(t) => t.td_name(),
+      TdObject::Document(t) => t.td_name(),
+      TdObject::DraftMessage(t) => t.td_name(),
+      TdObject::EmailAddressAuthenticationCodeInfo(t) => t.td_name(),
+      TdObject::Emojis(t) => t.td_name(),
+      TdObject::EncryptedCredentials(t) => t.td_name(),
+      TdObject::EncryptedPassportElement(t) => t.td_name(),
+      TdObject::Error(t) => t.td_name(),
+      TdObject::File(t) => t.td_name(),
+      TdObject::FilePart(t) => t.td_name(),
+      TdObject::FileType(t) => t.td_name(),
+      TdObject::FormattedText(t) => t.td_name(),
+      TdObject::FoundMessages(t) => t.td_name(),
+      TdObject::Game(t) => t.td_name(),
+      TdObject::GameHighScore(t) => t.td_name(),
+      TdObject::GameHighScores(t) => t.td_name(),
+      TdObject::Hashtags(t) => t.td_name(),
+      TdObject::HttpUrl(t) => t.td_name(),
+      TdObject::IdentityDocument(t) => t.td_name(),
+      TdObject::ImportedContacts(t) => t.td_name(),
+      TdObject::InlineKeyboardButton(t) => t.td_name(),
+      TdObject::InlineKeyboardButtonType(t) => t.td_name(),
+      TdObject::InlineQueryResult(t) => t.td_name(),
+      TdObject::InlineQueryResults(t) => t.td_name(),
+      TdObject::InputBackground(t) => t.td_name(),
+      TdObject::InputChatPhoto(t) => t.td_name(),
+      TdObject::InputCredentials(t) => t.td_name(),
+      TdObject::InputFile(t) => t.td_name(),
+      TdObject::InputIdentityDocument(t) => t.td_name(),
+      TdObject::InputInlineQueryResult(t) => t.td_name(),
+      TdObject::InputMessageContent(t) => t.td_name(),
+      TdObject::InputPassportElement(t) => t.td_name(),
+      TdObject::InputPassportElementError(t) => t.td_name(),
+      TdObject::InputPassportElementErrorSource(t) => t.td_name(),
+      TdObject::InputPersonalDocument(t) => t.td_name(),
+      TdObject::InputSticker(t) => t.td_name(),
+      TdObject::InputThumbnail(t) => t.td_name(),
+      TdObject::Invoice(t) => t.td_name(),
+      TdObject::JsonObjectMember(t) => t.td_name(),
+      TdObject::JsonValue(t) => t.td_name(),
+      TdObject::KeyboardButton(t) => t.td_name(),
+      TdObject::KeyboardButtonType(t) => t.td_name(),
+      TdObject::LabeledPricePart(t) => t.td_name(),
+      TdObject::LanguagePackInfo(t) => t.td_name(),
+      TdObject::LanguagePackString(t) => t.td_name(),
+      TdObject::LanguagePackStringValue(t) => t.td_name(),
+      TdObject::LanguagePackStrings(t) => t.td_name(),
+      TdObject::LocalFile(t) => t.td_name(),
+      TdObject::LocalizationTargetInfo(t) => t.td_name(),
+      TdObject::Location(t) => t.td_name(),
+      TdObject::LogStream(t) => t.td_name(),
+      TdObject::LogTags(t) => t.td_name(),
+      TdObject::LogVerbosityLevel(t) => t.td_name(),
+      TdObject::LoginUrlInfo(t) => t.td_name(),
+      TdObject::MaskPoint(t) => t.td_name(),
+      TdObject::MaskPosition(t) => t.td_name(),
+      TdObject::Message(t) => t.td_name(),
+      TdObject::MessageContent(t) => t.td_name(),
+      TdObject::MessageCopyOptions(t) => t.td_name(),
+      TdObject::MessageForwardInfo(t) => t.td_name(),
+      TdObject::MessageForwardOrigin(t) => t.td_name(),
+      TdObject::MessageLinkInfo(t) => t.td_name(),
+      TdObject::MessageSchedulingState(t) => t.td_name(),
+      TdObject::MessageSendOptions(t) => t.td_name(),
+      TdObject::MessageSendingState(t) => t.td_name(),
+      TdObject::Messages(t) => t.td_name(),
+      TdObject::Minithumbnail(t) => t.td_name(),
+      TdObject::NetworkStatistics(t) => t.td_name(),
+      TdObject::NetworkStatisticsEntry(t) => t.td_name(),
+      TdObject::NetworkType(t) => t.td_name(),
+      TdObject::Notification(t) => t.td_name(),
+      TdObject::NotificationGroup(t) => t.td_name(),
+      TdObject::NotificationGroupType(t) => t.td_name(),
+      TdObject::NotificationSettingsScope(t) => t.td_name(),
+      TdObject::NotificationType(t) => t.td_name(),
+      TdObject::Ok(t) => t.td_name(),
+      TdObject::OptionValue(t) => t.td_name(),
+      TdObject::OrderInfo(t) => t.td_name(),
+      TdObject::PageBlock(t) => t.td_name(),
+      TdObject::PageBlockCaption(t) => t.td_name(),
+      TdObject::PageBlockHorizontalAlignment(t) => t.td_name(),
+      TdObject::PageBlockListItem(t) => t.td_name(),
+      TdObject::PageBlockRelatedArticle(t) => t.td_name(),
+      TdObject::PageBlockTableCell(t) => t.td_name(),
+      TdObject::PageBlockVerticalAlignment(t) => t.td_name(),
+      TdObject::PassportAuthorizationForm(t) => t.td_name(),
+      TdObject::PassportElement(t) => t.td_name(),
+      TdObject::PassportElementError(t) => t.td_name(),
+      TdObject::PassportElementErrorSource(t) => t.td_name(),
+      TdObject::PassportElementType(t) => t.td_name(),
+      TdObject::PassportElements(t) => t.td_name(),
+      TdObject::PassportElementsWithErrors(t) => t.td_name(),
+      TdObject::PassportRequiredElement(t) => t.td_name(),
+      TdObject::PassportSuitableElement(t) => t.td_name(),
+      TdObject::PasswordState(t) => t.td_name(),
+      TdObject::PaymentForm(t) => t.td_name(),
+      TdObject::PaymentReceipt(t) => t.td_name(),
+      TdObject::PaymentResult(t) => t.td_name(),
+      TdObject::PaymentsProviderStripe(t) => t.td_name(),
+      TdObject::PersonalDetails(t) => t.td_name(),
+      TdObject::PersonalDocument(t) => t.td_name(),
+      TdObject::PhoneNumberAuthenticationSettings(t) => t.td_name(),
+      TdObject::Photo(t) => t.td_name(),
+      TdObject::PhotoSize(t) => t.td_name(),
+      TdObject::Poll(t) => t.td_name(),
+      TdObject::PollOption(t) => t.td_name(),
+      TdObject::PollType(t) => t.td_name(),
+      TdObject::ProfilePhoto(t) => t.td_name(),
+      TdObject::Proxies(t) => t.td_name(),
+      TdObject::Proxy(t) => t.td_name(),
+      TdObject::ProxyType(t) => t.td_name(),
+      TdObject::PublicChatType(t) => t.td_name(),
+      TdObject::PublicMessageLink(t) => t.td_name(),
+      TdObject::PushMessageContent(t) => t.td_name(),
+      TdObject::PushReceiverId(t) => t.td_name(),
+      TdObject::RecommendedChatFilter(t) => t.td_name(),
+      TdObject::RecommendedChatFilters(t) => t.td_name(),
+      TdObject::RecoveryEmailAddress(t) => t.td_name(),
+      TdObject::RemoteFile(t) => t.td_name(),
+      TdObject::ReplyMarkup(t) => t.td_name(),
+      TdObject::RichText(t) => t.td_name(),
+      TdObject::SavedCredentials(t) => t.td_name(),
+      TdObject::ScopeNotificationSettings(t) => t.td_name(),
+      TdObject::SearchMessagesFilter(t) => t.td_name(),
+      TdObject::Seconds(t) => t.td_name(),
+      TdObject::SecretChat(t) => t.td_name(),
+      TdObject::SecretChatState(t) => t.td_name(),
+      TdObject::Session(t) => t.td_name(),
+      TdObject::Sessions(t) => t.td_name(),
+      TdObject::ShippingOption(t) => t.td_name(),
+      TdObject::StatisticsGraph(t) => t.td_name(),
+      TdObject::StatisticsValue(t) => t.td_name(),
+      TdObject::Sticker(t) => t.td_name(),
+      TdObject::StickerSet(t) => t.td_name(),
+      TdObject::StickerSetInfo(t) => t.td_name(),
+      TdObject::StickerSets(t) => t.td_name(),
+      TdObject::Stickers(t) => t.td_name(),
+      TdObject::StorageStatistics(t) => t.td_name(),
+      TdObject::StorageStatisticsByChat(t) => t.td_name(),
+      TdObject::StorageStatisticsByFileType(t) => t.td_name(),
+      TdObject::StorageStatisticsFast(t) => t.td_name(),
+      TdObject::SuggestedAction(t) => t.td_name(),
+      TdObject::Supergroup(t) => t.td_name(),
+      TdObject::SupergroupFullInfo(t) => t.td_name(),
+      TdObject::SupergroupMembersFilter(t) => t.td_name(),
+      TdObject::TMeUrl(t) => t.td_name(),
+      TdObject::TMeUrlType(t) => t.td_name(),
+      TdObject::TMeUrls(t) => t.td_name(),
+      TdObject::TdlibParameters(t) => t.td_name(),
+      TdObject::TemporaryPasswordState(t) => t.td_name(),
+      TdObject::TermsOfService(t) => t.td_name(),
+      TdObject::TestBytes(t) => t.td_name(),
+      TdObject::TestInt(t) => t.td_name(),
+      TdObject::TestString(t) => t.td_name(),
+      TdObject::TestVectorInt(t) => t.td_name(),
+      TdObject::TestVectorIntObject(t) => t.td_name(),
+      TdObject::TestVectorString(t) => t.td_name(),
+      TdObject::TestVectorStringObject(t) => t.td_name(),
+      TdObject::Text(t) => t.td_name(),
+      TdObject::TextEntities(t) => t.td_name(),
+      TdObject::TextEntity(t) => t.td_name(),
+      TdObject::TextEntityType(t) => t.td_name(),
+      TdObject::TextParseMode(t) => t.td_name(),
+      TdObject::Thumbnail(t) => t.td_name(),
+      TdObject::ThumbnailFormat(t) => t.td_name(),
+      TdObject::TopChatCategory(t) => t.td_name(),
+      TdObject::Update(t) => t.td_name(),
+      TdObject::Updates(t) => t.td_name(),
+      TdObject::User(t) => t.td_name(),
+      TdObject::UserFullInfo(t) => t.td_name(),
+      TdObject::UserPrivacySetting(t) => t.td_name(),
+      TdObject::UserPrivacySettingRule(t) => t.td_name(),
+      TdObject::UserPrivacySettingRules(t) => t.td_name(),
+      TdObject::UserStatus(t) => t.td_name(),
+      TdObject::UserType(t) => t.td_name(),
+      TdObject::Users(t) => t.td_name(),
+      TdObject::ValidatedOrderInfo(t) => t.td_name(),
+      TdObject::Venue(t) => t.td_name(),
+      TdObject::Video(t) => t.td_name(),
+      TdObject::VideoNote(t) => t.td_name(),
+      TdObject::VoiceNote(t) => t.td_name(),
+      TdObject::WebPage(t) => t.td_name(),
+      TdObject::WebPageInstantView(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      TdObject::AccountTtl(t) => t.extra(),
+      TdObject::Address(t) => t.extra(),
+      TdObject::AnimatedChatPhoto(t) => t.extra(),
+      TdObject::Animation(t) => t.extra(),
+      TdObject::Animations(t) => t.extra(),
+      TdObject::Audio(t) => t.extra(),
+      TdObject::AuthenticationCodeInfo(t) => t.extra(),
+      TdObject::AuthenticationCodeType(t) => t.extra(),
+      TdObject::AuthorizationState(t) => t.extra(),
+      TdObject::AutoDownloadSettings(t) => t.extra(),
+      TdObject::AutoDownloadSettingsPresets(t) => t.extra(),
+      TdObject::Background(t) => t.extra(),
+      TdObject::BackgroundFill(t) => t.extra(),
+      TdObject::BackgroundType(t) => t.extra(),
+      TdObject::Backgrounds(t) => t.extra(),
+      TdObject::BankCardActionOpenUrl(t) => t.extra(),
+      TdObject::BankCardInfo(t) => t.extra(),
+      TdObject::BasicGroup(t) => t.extra(),
+      TdObject::BasicGroupFullInfo(t) => t.extra(),
+      TdObject::BotCommand(t) => t.extra(),
+      TdObject::BotInfo(t) => t.extra(),
+      TdObject::Call(t) => t.extra(),
+      TdObject::CallDiscardReason(t) => t.extra(),
+      TdObject::CallId(t) => t.extra(),
+      TdObject::CallProblem(t) => t.extra(),
+      TdObject::CallProtocol(t) => t.extra(),
+      TdObject::CallServer(t) => t.extra(),
+      TdObject::CallServerType(t) => t.extra(),
+      TdObject::CallState(t) => t.extra(),
+      TdObject::CallbackQueryAnswer(t) => t.extra(),
+      TdObject::CallbackQueryPayload(t) => t.extra(),
+      TdObject::CanTransferOwnershipResult(t) => t.extra(),
+      TdObject::Chat(t) => t.extra(),
+      TdObject::ChatAction(t) => t.extra(),
+      TdObject::ChatActionBar(t) => t.extra(),
+      TdObject::ChatAdministrator(t) => t.extra(),
+      TdObject::ChatAdministrators(t) => t.extra(),
+      TdObject::ChatEvent(t) => t.extra(),
+      TdObject::ChatEventAction(t) => t.extra(),
+      TdObject::ChatEventLogFilters(t) => t.extra(),
+      TdObject::ChatEvents(t) => t.extra(),
+      TdObject::ChatFilter(t) => t.extra(),
+      TdObject::ChatFilterInfo(t) => t.extra(),
+      TdObject::ChatInviteLink(t) => t.extra(),
+      TdObject::ChatInviteLinkInfo(t) => t.extra(),
+      TdObject::ChatList(t) => t.extra(),
+      TdObject::ChatLists(t) => t.extra(),
+      TdObject::ChatLocation(t) => t.extra(),
+      TdObject::ChatMember(t) => t.extra(),
+      TdObject::ChatMemberStatus(t) => t.extra(),
+      TdObject::ChatMembers(t) => t.extra(),
+      TdObject::ChatMembersFilter(t) => t.extra(),
+      TdObject::ChatNearby(t) => t.extra(),
+      TdObject::ChatNotificationSettings(t) => t.extra(),
+      TdObject::ChatPermissions(t) => t.extra(),
+      TdObject::ChatPhoto(t) => t.extra(),
+      TdObject::ChatPhotoInfo(t) => t.extra(),
+      TdObject::ChatPhotos(t) => t.extra(),
+      TdObject::ChatPosition(t) => t.extra(),
+      TdObject::ChatReportReason(t) => t.extra(),
+      TdObject::ChatSource(t) => t.extra(),
+      TdObject::ChatStatistics(t) => t.extra(),
+      TdObject::ChatStatisticsAdministratorActionsInfo(t) => t.extra(),
+      TdObject::ChatStatisticsInviterInfo(t) => t.extra(),
+      TdObject::ChatStatisticsMessageInteractionInfo(t) => t.extra(),
+      TdObject::ChatStatisticsMessageSenderInfo(t) => t.extra(),
+      TdObject::ChatType(t) => t.extra(),
+      TdObject::Chats(t) => t.extra(),
+      TdObject::ChatsNearby(t) => t.extra(),
+      TdObject::CheckChatUsernameResult(t) => t.extra(),
+      TdObject::ConnectedWebsite(t) => t.extra(),
+      TdObject::ConnectedWebsites(t) => t.extra(),
+      TdObject::ConnectionState(t) => t.extra(),
+      TdObject::Contact(t) => t.extra(),
+      TdObject::Count(t) => t.extra(),
+      TdObject::CustomRequestResult(t) => t.extra(),
+      TdObject::DatabaseStatistics(t) => t.extra(),
+      TdObject::Date(t) => t.extra(),
+      TdObject::DateRange(t) => t.extra(),
+      TdObject::DatedFile(t) => t.extra(),
+      TdObject::DeepLinkInfo(t) => t.extra(),
+      TdObject::DeviceToken(t) => t.extra(),
+      TdObject::Document(t) => t.extra(),
+      TdObject::DraftMessage(t) => t.extra(),
+      TdObject::EmailAddressAuthenticationCodeInfo(t) => t.extra(),
+      TdObject::Emojis(t) => t.extra(),
+      TdObject::EncryptedCredentials(t) => t.extra(),
+      TdObject::EncryptedPassportElement(t) => t.extra(),
+      TdObject::Error(t) => t.extra(),
+      TdObject::File(t) => t.extra(),
+      TdObject::FilePart(t) => t.extra(),
+      TdObject::FileType(t) => t.extra(),
+      TdObject::FormattedText(t) => t.extra(),
+      TdObject::FoundMessages(t) => t.extra(),
+      TdObject::Game(t) => t.extra(),
+      TdObject::GameHighScore(t) => t.extra(),
+      TdObject::GameHighScores(t) => t.extra(),
+      TdObject::Hashtags(t) => t.extra(),
+      TdObject::HttpUrl(t) => t.extra(),
+      TdObject::IdentityDocument(t) => t.extra(),
+      TdObject::ImportedContacts(t) => t.extra(),
+      TdObject::InlineKeyboardButton(t) => t.extra(),
+      TdObject::InlineKeyboardButtonType(t) => t.extra(),
+      TdObject::InlineQueryResult(t) => t.extra(),
+      TdObject::InlineQueryResults(t) => t.extra(),
+      TdObject::InputBackground(t) => t.extra(),
+      TdObject::InputChatPhoto(t) => t.extra(),
+      TdObject::InputCredentials(t) => t.extra(),
+      TdObject::InputFile(t) => t.extra(),
+      TdObject::InputIdentityDocument(t) => t.extra(),
+      TdObject::InputInlineQueryResult(t) => t.extra(),
+      TdObject::InputMessageContent(t) => t.extra(),
+      TdObject::InputPassportElement(t) => t.extra(),
+      TdObject::InputPassportElementError(t) => t.extra(),
+      TdObject::InputPassportElementErrorSource(t) => t.extra(),
+      TdObject::InputPersonalDocument(t) => t.extra(),
+      TdObject::InputSticker(t) => t.extra(),
+      TdObject::InputThumbnail(t) => t.extra(),
+      TdObject::Invoice(t) => t.extra(),
+      TdObject::JsonObjectMember(t) => t.extra(),
+      TdObject::JsonValue(t) => t.extra(),
+      TdObject::KeyboardButton(t) => t.extra(),
+      TdObject::KeyboardButtonType(t) => t.extra(),
+      TdObject::LabeledPricePart(t) => t.extra(),
+      TdObject::LanguagePackInfo(t) => t.extra(),
+      TdObject::LanguagePackString(t) => t.extra(),
+      TdObject::LanguagePackStringValue(t) => t.extra(),
+      TdObject::LanguagePackStrings(t) => t.extra(),
+      TdObject::LocalFile(t) => t.extra(),
+      TdObject::LocalizationTargetInfo(t) => t.extra(),
+      TdObject::Location(t) => t.extra(),
+      TdObject::LogStream(t) => t.extra(),
+      TdObject::LogTags(t) => t.extra(),
+      TdObject::LogVerbosityLevel(t) => t.extra(),
+      TdObject::LoginUrlInfo(t) => t.extra(),
+      TdObject::MaskPoint(t) => t.extra(),
+      TdObject::MaskPosition(t) => t.extra(),
+      TdObject::Message(t) => t.extra(),
+      TdObject::MessageContent(t) => t.extra(),
+      TdObject::MessageCopyOptions(t) => t.extra(),
+      TdObject::MessageForwardInfo(t) => t.extra(),
+      TdObject::MessageForwardOrigin(t) => t.extra(),
+      TdObject::MessageLinkInfo(t) => t.extra(),
+      TdObject::MessageSchedulingState(t) => t.extra(),
+      TdObject::MessageSendOptions(t) => t.extra(),
+      TdObject::MessageSendingState(t) => t.extra(),
+      TdObject::Messages(t) => t.extra(),
+      TdObject::Minithumbnail(t) => t.extra(),
+      TdObject::NetworkStatistics(t) => t.extra(),
+      TdObject::NetworkStatisticsEntry(t) => t.extra(),
+      TdObject::NetworkType(t) => t.extra(),
+      TdObject::Notification(t) => t.extra(),
+      TdObject::NotificationGroup(t) => t.extra(),
+      TdObject::NotificationGroupType(t) => t.extra(),
+      TdObject::NotificationSettingsScope(t) => t.extra(),
+      TdObject::NotificationType(t) => t.extra(),
+      TdObject::Ok(t) => t.extra(),
+      TdObject::OptionValue(t) => t.extra(),
+      TdObject::OrderInfo(t) => t.extra(),
+      TdObject::PageBlock(t) => t.extra(),
+      TdObject::PageBlockCaption(t) => t.extra(),
+      TdObject::PageBlockHorizontalAlignment(t) => t.extra(),
+      TdObject::PageBlockListItem(t) => t.extra(),
+      TdObject::PageBlockRelatedArticle(t) => t.extra(),
+      TdObject::PageBlockTableCell(t) => t.extra(),
+      TdObject::PageBlockVerticalAlignment(t) => t.extra(),
+      TdObject::PassportAuthorizationForm(t) => t.extra(),
+      TdObject::PassportElement(t) => t.extra(),
+      TdObject::PassportElementError(t) => t.extra(),
+      TdObject::PassportElementErrorSource(t) => t.extra(),
+      TdObject::PassportElementType(t) => t.extra(),
+      TdObject::PassportElements(t) => t.extra(),
+      TdObject::PassportElementsWithErrors(t) => t.extra(),
+      TdObject::PassportRequiredElement(t) => t.extra(),
+      TdObject::PassportSuitableElement(t) => t.extra(),
+      TdObject::PasswordState(t) => t.extra(),
+      TdObject::PaymentForm(t) => t.extra(),
+      TdObject::PaymentReceipt(t) => t.extra(),
+      TdObject::PaymentResult(t) => t.extra(),
+      TdObject::PaymentsProviderStripe(t) => t.extra(),
+      TdObject::PersonalDetails(t) => t.extra(),
+      TdObject::PersonalDocument(t) => t.extra(),
+      TdObject::PhoneNumberAuthenticationSettings(t) => t.extra(),
+      TdObject::Photo(t) => t.extra(),
+      TdObject::PhotoSize(t) => t.extra(),
+      TdObject::Poll(t) => t.extra(),
+      TdObject::PollOption(t) => t.extra(),
+      TdObject::PollType(t) => t.extra(),
+      TdObject::ProfilePhoto(t) => t.extra(),
+      TdObject::Proxies(t) => t.extra(),
+      TdObject::Proxy(t) => t.extra(),
+      TdObject::ProxyType(t) => t.extra(),
+      TdObject::PublicChatType(t) => t.extra(),
+      TdObject::PublicMessageLink(t) => t.extra(),
+      TdObject::PushMessageContent(t) => t.extra(),
+      TdObject::PushReceiverId(t) => t.extra(),
+      TdObject::RecommendedChatFilter(t) => t.extra(),
+      TdObject::RecommendedChatFilters(t) => t.extra(),
+      TdObject::RecoveryEmailAddress(t) => t.extra(),
+      TdObject::RemoteFile(t) => t.extra(),
+      TdObject::ReplyMarkup(t) => t.extra(),
+      TdObject::RichText(t) => t.extra(),
+      TdObject::SavedCredentials(t) => t.extra(),
+      TdObject::ScopeNotificationSettings(t) => t.extra(),
+      TdObject::SearchMessagesFilter(t) => t.extra(),
+      TdObject::Seconds(t) => t.extra(),
+      TdObject::SecretChat(t) => t.extra(),
+      TdObject::SecretChatState(t) => t.extra(),
+      TdObject::Session(t) => t.extra(),
+      TdObject::Sessions(t) => t.extra(),
+      TdObject::ShippingOption(t) => t.extra(),
+      TdObject::StatisticsGraph(t) => t.extra(),
+      TdObject::StatisticsValue(t) => t.extra(),
+      TdObject::Sticker(t) => t.extra(),
+      TdObject::StickerSet(t) => t.extra(),
+      TdObject::StickerSetInfo(t) => t.extra(),
+      TdObject::StickerSets(t) => t.extra(),
+      TdObject::Stickers(t) => t.extra(),
+      TdObject::StorageStatistics(t) => t.extra(),
+      TdObject::StorageStatisticsByChat(t) => t.extra(),
+      TdObject::StorageStatisticsByFileType(t) => t.extra(),
+      TdObject::StorageStatisticsFast(t) => t.extra(),
+      TdObject::SuggestedAction(t) => t.extra(),
+      TdObject::Supergroup(t) => t.extra(),
+      TdObject::SupergroupFullInfo(t) => t.extra(),
+      TdObject::SupergroupMembersFilter(t) => t.extra(),
+      TdObject::TMeUrl(t) => t.extra(),
+      TdObject::TMeUrlType(t) => t.extra(),
+      TdObject::TMeUrls(t) => t.extra(),
+      TdObject::TdlibParameters(t) => t.extra(),
+      TdObject::TemporaryPasswordState(t) => t.extra(),
+      TdObject::TermsOfService(t) => t.extra(),
+      TdObject::TestBytes(t) => t.extra(),
+      TdObject::TestInt(t) => t.extra(),
+      TdObject::TestString(t) => t.extra(),
+      TdObject::TestVectorInt(t) => t.extra(),
+      TdObject::TestVectorIntObject(t) => t.extra(),
+      TdObject::TestVectorString(t) => t.extra(),
+      TdObject::TestVectorStringObject(t) => t.extra(),
+      TdObject::Text(t) => t.extra(),
+      TdObject::TextEntities(t) => t.extra(),
+      TdObject::TextEntity(t) => t.extra(),
+      TdObject::TextEntityType(t) => t.extra(),
+      TdObject::TextParseMode(t) => t.extra(),
+      TdObject::Thumbnail(t) => t.extra(),
+      TdObject::ThumbnailFormat(t) => t.extra(),
+      TdObject::TopChatCategory(t) => t.extra(),
+      TdObject::Update(t) => t.extra(),
+      TdObject::Updates(t) => t.extra(),
+      TdObject::User(t) => t.extra(),
+      TdObject::UserFullInfo(t) => t.extra(),
+      TdObject::UserPrivacySetting(t) => t.extra(),
+      TdObject::UserPrivacySettingRule(t) => t.extra(),
+      TdObject::UserPrivacySettingRules(t) => t.extra(),
+      TdObject::UserStatus(t) => t.extra(),
+      TdObject::UserType(t) => t.extra(),
+      TdObject::Users(t) => t.extra(),
+      TdObject::ValidatedOrderInfo(t) => t.extra(),
+      TdObject::Venue(t) => t.extra(),
+      TdObject::Video(t) => t.extra(),
+      TdObject::VideoNote(t) => t.extra(),
+      TdObject::VoiceNote(t) => t.extra(),
+      TdObject::WebPage(t) => t.extra(),
+      TdObject::WebPageInstantView(t) => t.extra(),
+
+      TdObject::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+impl TdObject {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TdObject::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let TdObject::Unknown { .. } = self { true } else { false } }
+
+  pub fn is_account_ttl(&self) -> bool { if let TdObject::AccountTtl(_) = self { true } else { false } }
+  pub fn is_address(&self) -> bool { if let TdObject::Address(_) = self { true } else { false } }
+  pub fn is_animated_chat_photo(&self) -> bool { if let TdObject::AnimatedChatPhoto(_) = self { true } else { false } }
+  pub fn is_animation(&self) -> bool { if let TdObject::Animation(_) = self { true } else { false } }
+  pub fn is_animations(&self) -> bool { if let TdObject::Animations(_) = self { true } else { false } }
+  pub fn is_audio(&self) -> bool { if let TdObject::Audio(_) = self { true } else { false } }
+  pub fn is_authentication_code_info(&self) -> bool { if let TdObject::AuthenticationCodeInfo(_) = self { true } else { false } }
+  pub fn is_authentication_code_type(&self) -> bool { if let TdObject::AuthenticationCodeType(_) = self { true } else { false } }
+  pub fn is_authorization_state(&self) -> bool { if let TdObject::AuthorizationState(_) = self { true } else { false } }
+  pub fn is_auto_download_settings(&self) -> bool { if let TdObject::AutoDownloadSettings(_) = self { true } else { false } }
+  pub fn is_auto_download_settings_presets(&self) -> bool { if let TdObject::AutoDownloadSettingsPresets(_) = self { true } else { false } }
+  pub fn is_background(&self) -> bool { if let TdObject::Background(_) = self { true } else { false } }
+  pub fn is_background_fill(&self) -> bool { if let TdObject::BackgroundFill(_) = self { true } else { false } }
+  pub fn is_background_type(&self) -> bool { if let TdObject::BackgroundType(_) = self { true } else { false } }
+  pub fn is_backgrounds(&self) -> bool { if let TdObject::Backgrounds(_) = self { true } else { false } }
+  pub fn is_bank_card_action_open_url(&self) -> bool { if let TdObject::BankCardActionOpenUrl(_) = self { true } else { false } }
+  pub fn is_bank_card_info(&self) -> bool { if let TdObject::BankCardInfo(_) = self { true } else { false } }
+  pub fn is_basic_group(&self) -> bool { if let TdObject::BasicGroup(_) = self { true } else { false } }
+  pub fn is_basic_group_full_info(&self) -> bool { if let TdObject::BasicGroupFullInfo(_) = self { true } else { false } }
+  pub fn is_bot_command(&self) -> bool { if let TdObject::BotCommand(_) = self { true } else { false } }
+  pub fn is_bot_info(&self) -> bool { if let TdObject::BotInfo(_) = self { true } else { false } }
+  pub fn is_call(&self) -> bool { if let TdObject::Call(_) = self { true } else { false } }
+  pub fn is_call_discard_reason(&self) -> bool { if let TdObject::CallDiscardReason(_) = self { true } else { false } }
+  pub fn is_call_id(&self) -> bool { if let TdObject::CallId(_) = self { true } else { false } }
+  pub fn is_call_problem(&self) -> bool { if let TdObject::CallProblem(_) = self { true } else { false } }
+  pub fn is_call_protocol(&self) -> bool { if let TdObject::CallProtocol(_) = self { true } else { false } }
+  pub fn is_call_server(&self) -> bool { if let TdObject::CallServer(_) = self { true } else { false } }
+  pub fn is_call_server_type(&self) -> bool { if let TdObject::CallServerType(_) = self { true } else { false } }
+  pub fn is_call_state(&self) -> bool { if let TdObject::CallState(_) = self { true } else { false } }
+  pub fn is_callback_query_answer(&self) -> bool { if let TdObject::CallbackQueryAnswer(_) = self { true } else { false } }
+  pub fn is_callback_query_payload(&self) -> bool { if let TdObject::CallbackQueryPayload(_) = self { true } else { false } }
+  pub fn is_can_transfer_ownership_result(&self) -> bool { if let TdObject::CanTransferOwnershipResult(_) = self { true } else { false } }
+  pub fn is_chat(&self) -> bool { if let TdObject::Chat(_) = self { true } else { false } }
+  pub fn is_chat_action(&self) -> bool { if let TdObject::ChatAction(_) = self { true } else { false } }
+  pub fn is_chat_action_bar(&self) -> bool { if let TdObject::ChatActionBar(_) = self { true } else { false } }
+  pub fn is_chat_administrator(&self) -> bool { if let TdObject::ChatAdministrator(_) = self { true } else { false } }
+  pub fn is_chat_administrators(&self) -> bool { if let TdObject::ChatAdministrators(_) = self { true } else { false } }
+  pub fn is_chat_event(&self) -> bool { if let TdObject::ChatEvent(_) = self { true } else { false } }
+  pub fn is_chat_event_action(&self) -> bool { if let TdObject::ChatEventAction(_) = self { true } else { false } }
+  pub fn is_chat_event_log_filters(&self) -> bool { if let TdObject::ChatEventLogFilters(_) = self { true } else { false } }
+  pub fn is_chat_events(&self) -> bool { if let TdObject::ChatEvents(_) = self { true } else { false } }
+  pub fn is_chat_filter(&self) -> bool { if let TdObject::ChatFilter(_) = self { true } else { false } }
+  pub fn is_chat_filter_info(&self) -> bool { if let TdObject::ChatFilterInfo(_) = self { true } else { false } }
+  pub fn is_chat_invite_link(&self) -> bool { if let TdObject::ChatInviteLink(_) = self { true } else { false } }
+  pub fn is_chat_invite_link_info(&self) -> bool { if let TdObject::ChatInviteLinkInfo(_) = self { true } else { false } }
+  pub fn is_chat_list(&self) -> bool { if let TdObject::ChatList(_) = self { true } else { false } }
+  pub fn is_chat_lists(&self) -> bool { if let TdObject::ChatLists(_) = self { true } else { false } }
+  pub fn is_chat_location(&self) -> bool { if let TdObject::ChatLocation(_) = self { true } else { false } }
+  pub fn is_chat_member(&self) -> bool { if let TdObject::ChatMember(_) = self { true } else { false } }
+  pub fn is_chat_member_status(&self) -> bool { if let TdObject::ChatMemberStatus(_) = self { true } else { false } }
+  pub fn is_chat_members(&self) -> bool { if let TdObject::ChatMembers(_) = self { true } else { false } }
+  pub fn is_chat_members_filter(&self) -> bool { if let TdObject::ChatMembersFilter(_) = self { true } else { false } }
+  pub fn is_chat_nearby(&self) -> bool { if let TdObject::ChatNearby(_) = self { true } else { false } }
+  pub fn is_chat_notification_settings(&self) -> bool { if let TdObject::ChatNotificationSettings(_) = self { true } else { false } }
+  pub fn is_chat_permissions(&self) -> bool { if let TdObject::ChatPermissions(_) = self { true } else { false } }
+  pub fn is_chat_photo(&self) -> bool { if let TdObject::ChatPhoto(_) = self { true } else { false } }
+  pub fn is_chat_photo_info(&self) -> bool { if let TdObject::ChatPhotoInfo(_) = self { true } else { false } }
+  pub fn is_chat_photos(&self) -> bool { if let TdObject::ChatPhotos(_) = self { true } else { false } }
+  pub fn is_chat_position(&self) -> bool { if let TdObject::ChatPosition(_) = self { true } else { false } }
+  pub fn is_chat_report_reason(&self) -> bool { if let TdObject::ChatReportReason(_) = self { true } else { false } }
+  pub fn is_chat_source(&self) -> bool { if let TdObject::ChatSource(_) = self { true } else { false } }
+  pub fn is_chat_statistics(&self) -> bool { if let TdObject::ChatStatistics(_) = self { true } else { false } }
+  pub fn is_chat_statistics_administrator_actions_info(&self) -> bool { if let TdObject::ChatStatisticsAdministratorActionsInfo(_) = self { true } else { false } }
+  pub fn is_chat_statistics_inviter_info(&self) -> bool { if let TdObject::ChatStatisticsInviterInfo(_) = self { true } else { false } }
+  pub fn is_chat_statistics_message_interaction_info(&self) -> bool { if let TdObject::ChatStatisticsMessageInteractionInfo(_) = self { true } else { false } }
+  pub fn is_chat_statistics_message_sender_info(&self) -> bool { if let TdObject::ChatStatisticsMessageSenderInfo(_) = self { true } else { false } }
+  pub fn is_chat_type(&self) -> bool { if let TdObject::ChatType(_) = self { true } else { false } }
+  pub fn is_chats(&self) -> bool { if let TdObject::Chats(_) = self { true } else { false } }
+  pub fn is_chats_nearby(&self) -> bool { if let TdObject::ChatsNearby(_) = self { true } else { false } }
+  pub fn is_check_chat_username_result(&self) -> bool { if let TdObject::CheckChatUsernameResult(_) = self { true } else { false } }
+  pub fn is_connected_website(&self) -> bool { if let TdObject::ConnectedWebsite(_) = self { true } else { false } }
+  pub fn is_connected_websites(&self) -> bool { if let TdObject::ConnectedWebsites(_) = self { true } else { false } }
+  pub fn is_connection_state(&self) -> bool { if let TdObject::ConnectionState(_) = self { true } else { false } }
+  pub fn is_contact(&self) -> bool { if let TdObject::Contact(_) = self { true } else { false } }
+  pub fn is_count(&self) -> bool { if let TdObject::Count(_) = self { true } else { false } }
+  pub fn is_custom_request_result(&self) -> bool { if let TdObject::CustomRequestResult(_) = self { true } else { false } }
+  pub fn is_database_statistics(&self) -> bool { if let TdObject::DatabaseStatistics(_) = self { true } else { false } }
+  pub fn is_date(&self) -> bool { if let TdObject::Date(_) = self { true } else { false } }
+  pub fn is_date_range(&self) -> bool { if let TdObject::DateRange(_) = self { true } else { false } }
+  pub fn is_dated_file(&self) -> bool { if let TdObject::DatedFile(_) = self { true } else { false } }
+  pub fn is_deep_link_info(&self) -> bool { if let TdObject::DeepLinkInfo(_) = self { true } else { false } }
+  pub fn is_device_token(&self) -> bool { if let TdObject::DeviceToken(_) = self { true } else { false } }
+  pub fn is_document(&self) -> bool { if let TdObject::Document(_) = self { true } else { false } }
+  pub fn is_draft_message(&self) -> bool { if let TdObject::DraftMessage(_) = self { true } else { false } }
+  pub fn is_email_address_authentication_code_info(&self) -> bool { if let TdObject::EmailAddressAuthenticationCodeInfo(_) = self { true } else { false } }
+  pub fn is_emojis(&self) -> bool { if let TdObject::Emojis(_) = self { true } else { false } }
+  pub fn is_encrypted_credentials(&self) -> bool { if let TdObject::EncryptedCredentials(_) = self { true } else { false } }
+  pub fn is_encrypted_passport_element(&self) -> bool { if let TdObject::EncryptedPassportElement(_) = self { true } else { false } }
+  pub fn is_error(&self) -> bool { if let TdObject::Error(_) = self { true } else { false } }
+  pub fn is_file(&self) -> bool { if let TdObject::File(_) = self { true } else { false } }
+  pub fn is_file_part(&self) -> bool { if let TdObject::FilePart(_) = self { true } else { false } }
+  pub fn is_file_type(&self) -> bool { if let TdObject::FileType(_) = self { true } else { false } }
+  pub fn is_formatted_text(&self) -> bool { if let TdObject::FormattedText(_) = self { true } else { false } }
+  pub fn is_found_messages(&self) -> bool { if let TdObject::FoundMessages(_) = self { true } else { false } }
+  pub fn is_game(&self) -> bool { if let TdObject::Game(_) = self { true } else { false } }
+  pub fn is_game_high_score(&self) -> bool { if let TdObject::GameHighScore(_) = self { true } else { false } }
+  pub fn is_game_high_scores(&self) -> bool { if let TdObject::GameHighScores(_) = self { true } else { false } }
+  pub fn is_hashtags(&self) -> bool { if let TdObject::Hashtags(_) = self { true } else { false } }
+  pub fn is_http_url(&self) -> bool { if let TdObject::HttpUrl(_) = self { true } else { false } }
+  pub fn is_identity_document(&self) -> bool { if let TdObject::IdentityDocument(_) = self { true } else { false } }
+  pub fn is_imported_contacts(&self) -> bool { if let TdObject::ImportedContacts(_) = self { true } else { false } }
+  pub fn is_inline_keyboard_button(&self) -> bool { if let TdObject::InlineKeyboardButton(_) = self { true } else { false } }
+  pub fn is_inline_keyboard_button_type(&self) -> bool { if let TdObject::InlineKeyboardButtonType(_) = self { true } else { false } }
+  pub fn is_inline_query_result(&self) -> bool { if let TdObject::InlineQueryResult(_) = self { true } else { false } }
+  pub fn is_inline_query_results(&self) -> bool { if let TdObject::InlineQueryResults(_) = self { true } else { false } }
+  pub fn is_input_background(&self) -> bool { if let TdObject::InputBackground(_) = self { true } else { false } }
+  pub fn is_input_chat_photo(&self) -> bool { if let TdObject::InputChatPhoto(_) = self { true } else { false } }
+  pub fn is_input_credentials(&self) -> bool { if let TdObject::InputCredentials(_) = self { true } else { false } }
+  pub fn is_input_file(&self) -> bool { if let TdObject::InputFile(_) = self { true } else { false } }
+  pub fn is_input_identity_document(&self) -> bool { if let TdObject::InputIdentityDocument(_) = self { true } else { false } }
+  pub fn is_input_inline_query_result(&self) -> bool { if let TdObject::InputInlineQueryResult(_) = self { true } else { false } }
+  pub fn is_input_message_content(&self) -> bool { if let TdObject::InputMessageContent(_) = self { true } else { false } }
+  pub fn is_input_passport_element(&self) -> bool { if let TdObject::InputPassportElement(_) = self { true } else { false } }
+  pub fn is_input_passport_element_error(&self) -> bool { if let TdObject::InputPassportElementError(_) = self { true } else { false } }
+  pub fn is_input_passport_element_error_source(&self) -> bool { if let TdObject::InputPassportElementErrorSource(_) = self { true } else { false } }
+  pub fn is_input_personal_document(&self) -> bool { if let TdObject::InputPersonalDocument(_) = self { true } else { false } }
+  pub fn is_input_sticker(&self) -> bool { if let TdObject::InputSticker(_) = self { true } else { false } }
+  pub fn is_input_thumbnail(&self) -> bool { if let TdObject::InputThumbnail(_) = self { true } else { false } }
+  pub fn is_invoice(&self) -> bool { if let TdObject::Invoice(_) = self { true } else { false } }
+  pub fn is_json_object_member(&self) -> bool { if let TdObject::JsonObjectMember(_) = self { true } else { false } }
+  pub fn is_json_value(&self) -> bool { if let TdObject::JsonValue(_) = self { true } else { false } }
+  pub fn is_keyboard_button(&self) -> bool { if let TdObject::KeyboardButton(_) = self { true } else { false } }
+  pub fn is_keyboard_button_type(&self) -> bool { if let TdObject::KeyboardButtonType(_) = self { true } else { false } }
+  pub fn is_labeled_price_part(&self) -> bool { if let TdObject::LabeledPricePart(_) = self { true } else { false } }
+  pub fn is_language_pack_info(&self) -> bool { if let TdObject::LanguagePackInfo(_) = self { true } else { false } }
+  pub fn is_language_pack_string(&self) -> bool { if let TdObject::LanguagePackString(_) = self { true } else { false } }
+  pub fn is_language_pack_string_value(&self) -> bool { if let TdObject::LanguagePackStringValue(_) = self { true } else { false } }
+  pub fn is_language_pack_strings(&self) -> bool { if let TdObject::LanguagePackStrings(_) = self { true } else { false } }
+  pub fn is_local_file(&self) -> bool { if let TdObject::LocalFile(_) = self { true } else { false } }
+  pub fn is_localization_target_info(&self) -> bool { if let TdObject::LocalizationTargetInfo(_) = self { true } else { false } }
+  pub fn is_location(&self) -> bool { if let TdObject::Location(_) = self { true } else { false } }
+  pub fn is_log_stream(&self) -> bool { if let TdObject::LogStream(_) = self { true } else { false } }
+  pub fn is_log_tags(&self) -> bool { if let TdObject::LogTags(_) = self { true } else { false } }
+  pub fn is_log_verbosity_level(&self) -> bool { if let TdObject::LogVerbosityLevel(_) = self { true } else { false } }
+  pub fn is_login_url_info(&self) -> bool { if let TdObject::LoginUrlInfo(_) = self { true } else { false } }
+  pub fn is_mask_point(&self) -> bool { if let TdObject::MaskPoint(_) = self { true } else { false } }
+  pub fn is_mask_position(&self) -> bool { if let TdObject::MaskPosition(_) = self { true } else { false } }
+  pub fn is_message(&self) -> bool { if let TdObject::Message(_) = self { true } else { false } }
+  pub fn is_message_content(&self) -> bool { if let TdObject::MessageContent(_) = self { true } else { false } }
+  pub fn is_message_copy_options(&self) -> bool { if let TdObject::MessageCopyOptions(_) = self { true } else { false } }
+  pub fn is_message_forward_info(&self) -> bool { if let TdObject::MessageForwardInfo(_) = self { true } else { false } }
+  pub fn is_message_forward_origin(&self) -> bool { if let TdObject::MessageForwardOrigin(_) = self { true } else { false } }
+  pub fn is_message_link_info(&self) -> bool { if let TdObject::MessageLinkInfo(_) = self { true } else { false } }
+  pub fn is_message_scheduling_state(&self) -> bool { if let TdObject::MessageSchedulingState(_) = self { true } else { false } }
+  pub fn is_message_send_options(&self) -> bool { if let TdObject::MessageSendOptions(_) = self { true } else { false } }
+  pub fn is_message_sending_state(&self) -> bool { if let TdObject::MessageSendingState(_) = self { true } else { false } }
+  pub fn is_messages(&self) -> bool { if let TdObject::Messages(_) = self { true } else { false } }
+  pub fn is_minithumbnail(&self) -> bool { if let TdObject::Minithumbnail(_) = self { true } else { false } }
+  pub fn is_network_statistics(&self) -> bool { if let TdObject::NetworkStatistics(_) = self { true } else { false } }
+  pub fn is_network_statistics_entry(&self) -> bool { if let TdObject::NetworkStatisticsEntry(_) = self { true } else { false } }
+  pub fn is_network_type(&self) -> bool { if let TdObject::NetworkType(_) = self { true } else { false } }
+  pub fn is_notification(&self) -> bool { if let TdObject::Notification(_) = self { true } else { false } }
+  pub fn is_notification_group(&self) -> bool { if let TdObject::NotificationGroup(_) = self { true } else { false } }
+  pub fn is_notification_group_type(&self) -> bool { if let TdObject::NotificationGroupType(_) = self { true } else { false } }
+  pub fn is_notification_settings_scope(&self) -> bool { if let TdObject::NotificationSettingsScope(_) = self { true } else { false } }
+  pub fn is_notification_type(&self) -> bool { if let TdObject::NotificationType(_) = self { true } else { false } }
+  pub fn is_ok(&self) -> bool { if let TdObject::Ok(_) = self { true } else { false } }
+  pub fn is_option_value(&self) -> bool { if let TdObject::OptionValue(_) = self { true } else { false } }
+  pub fn is_order_info(&self) -> bool { if let TdObject::OrderInfo(_) = self { true } else { false } }
+  pub fn is_page_block(&self) -> bool { if let TdObject::PageBlock(_) = self { true } else { false } }
+  pub fn is_page_block_caption(&self) -> bool { if let TdObject::PageBlockCaption(_) = self { true } else { false } }
+  pub fn is_page_block_horizontal_alignment(&self) -> bool { if let TdObject::PageBlockHorizontalAlignment(_) = self { true } else { false } }
+  pub fn is_page_block_list_item(&self) -> bool { if let TdObject::PageBlockListItem(_) = self { true } else { false } }
+  pub fn is_page_block_related_article(&self) -> bool { if let TdObject::PageBlockRelatedArticle(_) = self { true } else { false } }
+  pub fn is_page_block_table_cell(&self) -> bool { if let TdObject::PageBlockTableCell(_) = self { true } else { false } }
+  pub fn is_page_block_vertical_alignment(&self) -> bool { if let TdObject::PageBlockVerticalAlignment(_) = self { true } else { false } }
+  pub fn is_passport_authorization_form(&self) -> bool { if let TdObject::PassportAuthorizationForm(_) = self { true } else { false } }
+  pub fn is_passport_element(&self) -> bool { if let TdObject::PassportElement(_) = self { true } else { false } }
+  pub fn is_passport_element_error(&self) -> bool { if let TdObject::PassportElementError(_) = self { true } else { false } }
+  pub fn is_passport_element_error_source(&self) -> bool { if let TdObject::PassportElementErrorSource(_) = self { true } else { false } }
+  pub fn is_passport_element_type(&self) -> bool { if let TdObject::PassportElementType(_) = self { true } else { false } }
+  pub fn is_passport_elements(&self) -> bool { if let TdObject::PassportElements(_) = self { true } else { false } }
+  pub fn is_passport_elements_with_errors(&self) -> bool { if let TdObject::PassportElementsWithErrors(_) = self { true } else { false } }
+  pub fn is_passport_required_element(&self) -> bool { if let TdObject::PassportRequiredElement(_) = self { true } else { false } }
+  pub fn is_passport_suitable_element(&self) -> bool { if let TdObject::PassportSuitableElement(_) = self { true } else { false } }
+  pub fn is_password_state(&self) -> bool { if let TdObject::PasswordState(_) = self { true } else { false } }
+  pub fn is_payment_form(&self) -> bool { if let TdObject::PaymentForm(_) = self { true } else { false } }
+  pub fn is_payment_receipt(&self) -> bool { if let TdObject::PaymentReceipt(_) = self { true } else { false } }
+  pub fn is_payment_result(&self) -> bool { if let TdObject::PaymentResult(_) = self { true } else { false } }
+  pub fn is_payments_provider_stripe(&self) -> bool { if let TdObject::PaymentsProviderStripe(_) = self { true } else { false } }
+  pub fn is_personal_details(&self) -> bool { if let TdObject::PersonalDetails(_) = self { true } else { false } }
+  pub fn is_personal_document(&self) -> bool { if let TdObject::PersonalDocument(_) = self { true } else { false } }
+  pub fn is_phone_number_authentication_settings(&self) -> bool { if let TdObject::PhoneNumberAuthenticationSettings(_) = self { true } else { false } }
+  pub fn is_photo(&self) -> bool { if let TdObject::Photo(_) = self { true } else { false } }
+  pub fn is_photo_size(&self) -> bool { if let TdObject::PhotoSize(_) = self { true } else { false } }
+  pub fn is_poll(&self) -> bool { if let TdObject::Poll(_) = self { true } else { false } }
+  pub fn is_poll_option(&self) -> bool { if let TdObject::PollOption(_) = self { true } else { false } }
+  pub fn is_poll_type(&self) -> bool { if let TdObject::PollType(_) = self { true } else { false } }
+  pub fn is_profile_photo(&self) -> bool { if let TdObject::ProfilePhoto(_) = self { true } else { false } }
+  pub fn is_proxies(&self) -> bool { if let TdObject::Proxies(_) = self { true } else { false } }
+  pub fn is_proxy(&self) -> bool { if let TdObject::Proxy(_) = self { true } else { false } }
+  pub fn is_proxy_type(&self) -> bool { if let TdObject::ProxyType(_) = self { true } else { false } }
+  pub fn is_public_chat_type(&self) -> bool { if let TdObject::PublicChatType(_) = self { true } else { false } }
+  pub fn is_public_message_link(&self) -> bool { if let TdObject::PublicMessageLink(_) = self { true } else { false } }
+  pub fn is_push_message_content(&self) -> bool { if let TdObject::PushMessageContent(_) = self { true } else { false } }
+  pub fn is_push_receiver_id(&self) -> bool { if let TdObject::PushReceiverId(_) = self { true } else { false } }
+  pub fn is_recommended_chat_filter(&self) -> bool { if let TdObject::RecommendedChatFilter(_) = self { true } else { false } }
+  pub fn is_recommended_chat_filters(&self) -> bool { if let TdObject::RecommendedChatFilters(_) = self { true } else { false } }
+  pub fn is_recovery_email_address(&self) -> bool { if let TdObject::RecoveryEmailAddress(_) = self { true } else { false } }
+  pub fn is_remote_file(&self) -> bool { if let TdObject::RemoteFile(_) = self { true } else { false } }
+  pub fn is_reply_markup(&self) -> bool { if let TdObject::ReplyMarkup(_) = self { true } else { false } }
+  pub fn is_rich_text(&self) -> bool { if let TdObject::RichText(_) = self { true } else { false } }
+  pub fn is_saved_credentials(&self) -> bool { if let TdObject::SavedCredentials(_) = self { true } else { false } }
+  pub fn is_scope_notification_settings(&self) -> bool { if let TdObject::ScopeNotificationSettings(_) = self { true } else { false } }
+  pub fn is_search_messages_filter(&self) -> bool { if let TdObject::SearchMessagesFilter(_) = self { true } else { false } }
+  pub fn is_seconds(&self) -> bool { if let TdObject::Seconds(_) = self { true } else { false } }
+  pub fn is_secret_chat(&self) -> bool { if let TdObject::SecretChat(_) = self { true } else { false } }
+  pub fn is_secret_chat_state(&self) -> bool { if let TdObject::SecretChatState(_) = self { true } else { false } }
+  pub fn is_session(&self) -> bool { if let TdObject::Session(_) = self { true } else { false } }
+  pub fn is_sessions(&self) -> bool { if let TdObject::Sessions(_) = self { true } else { false } }
+  pub fn is_shipping_option(&self) -> bool { if let TdObject::ShippingOption(_) = self { true } else { false } }
+  pub fn is_statistics_graph(&self) -> bool { if let TdObject::StatisticsGraph(_) = self { true } else { false } }
+  pub fn is_statistics_value(&self) -> bool { if let TdObject::StatisticsValue(_) = self { true } else { false } }
+  pub fn is_sticker(&self) -> bool { if let TdObject::Sticker(_) = self { true } else { false } }
+  pub fn is_sticker_set(&self) -> bool { if let TdObject::StickerSet(_) = self { true } else { false } }
+  pub fn is_sticker_set_info(&self) -> bool { if let TdObject::StickerSetInfo(_) = self { true } else { false } }
+  pub fn is_sticker_sets(&self) -> bool { if let TdObject::StickerSets(_) = self { true } else { false } }
+  pub fn is_stickers(&self) -> bool { if let TdObject::Stickers(_) = self { true } else { false } }
+  pub fn is_storage_statistics(&self) -> bool { if let TdObject::StorageStatistics(_) = self { true } else { false } }
+  pub fn is_storage_statistics_by_chat(&self) -> bool { if let TdObject::StorageStatisticsByChat(_) = self { true } else { false } }
+  pub fn is_storage_statistics_by_file_type(&self) -> bool { if let TdObject::StorageStatisticsByFileType(_) = self { true } else { false } }
+  pub fn is_storage_statistics_fast(&self) -> bool { if let TdObject::StorageStatisticsFast(_) = self { true } else { false } }
+  pub fn is_suggested_action(&self) -> bool { if let TdObject::SuggestedAction(_) = self { true } else { false } }
+  pub fn is_supergroup(&self) -> bool { if let TdObject::Supergroup(_) = self { true } else { false } }
+  pub fn is_supergroup_full_info(&self) -> bool { if let TdObject::SupergroupFullInfo(_) = self { true } else { false } }
+  pub fn is_supergroup_members_filter(&self) -> bool { if let TdObject::SupergroupMembersFilter(_) = self { true } else { false } }
+  pub fn is_t_me_url(&self) -> bool { if let TdObject::TMeUrl(_) = self { true } else { false } }
+  pub fn is_t_me_url_type(&self) -> bool { if let TdObject::TMeUrlType(_) = self { true } else { false } }
+  pub fn is_t_me_urls(&self) -> bool { if let TdObject::TMeUrls(_) = self { true } else { false } }
+  pub fn is_tdlib_parameters(&self) -> bool { if let TdObject::TdlibParameters(_) = self { true } else { false } }
+  pub fn is_temporary_password_state(&self) -> bool { if let TdObject::TemporaryPasswordState(_) = self { true } else { false } }
+  pub fn is_terms_of_service(&self) -> bool { if let TdObject::TermsOfService(_) = self { true } else { false } }
+  pub fn is_test_bytes(&self) -> bool { if let TdObject::TestBytes(_) = self { true } else { false } }
+  pub fn is_test_int(&self) -> bool { if let TdObject::TestInt(_) = self { true } else { false } }
+  pub fn is_test_string(&self) -> bool { if let TdObject::TestString(_) = self { true } else { false } }
+  pub fn is_test_vector_int(&self) -> bool { if let TdObject::TestVectorInt(_) = self { true } else { false } }
+  pub fn is_test_vector_int_object(&self) -> bool { if let TdObject::TestVectorIntObject(_) = self { true } else { false } }
+  pub fn is_test_vector_string(&self) -> bool { if let TdObject::TestVectorString(_) = self { true } else { false } }
+  pub fn is_test_vector_string_object(&self) -> bool { if let TdObject::TestVectorStringObject(_) = self { true } else { false } }
+  pub fn is_text(&self) -> bool { if let TdObject::Text(_) = self { true } else { false } }
+  pub fn is_text_entities(&self) -> bool { if let TdObject::TextEntities(_) = self { true } else { false } }
+  pub fn is_text_entity(&self) -> bool { if let TdObject::TextEntity(_) = self { true } else { false } }
+  pub fn is_text_entity_type(&self) -> bool { if let TdObject::TextEntityType(_) = self { true } else { false } }
+  pub fn is_text_parse_mode(&self) -> bool { if let TdObject::TextParseMode(_) = self { true } else { false } }
+  pub fn is_thumbnail(&self) -> bool { if let TdObject::Thumbnail(_) = self { true } else { false } }
+  pub fn is_thumbnail_format(&self) -> bool { if let TdObject::ThumbnailFormat(_) = self { true } else { false } }
+  pub fn is_top_chat_category(&self) -> bool { if let TdObject::TopChatCategory(_) = self { true } else { false } }
+  pub fn is_update(&self) -> bool { if let TdObject::Update(_) = self { true } else { false } }
+  pub fn is_updates(&self) -> bool { if let TdObject::Updates(_) = self { true } else { false } }
+  pub fn is_user(&self) -> bool { if let TdObject::User(_) = self { true } else { false } }
+  pub fn is_user_full_info(&self) -> bool { if let TdObject::UserFullInfo(_) = self { true } else { false } }
+  pub fn is_user_privacy_setting(&self) -> bool { if let TdObject::UserPrivacySetting(_) = self { true } else { false } }
+  pub fn is_user_privacy_setting_rule(&self) -> bool { if let TdObject::UserPrivacySettingRule(_) = self { true } else { false } }
+  pub fn is_user_privacy_setting_rules(&self) -> bool { if let TdObject::UserPrivacySettingRules(_) = self { true } else { false } }
+  pub fn is_user_status(&self) -> bool { if let TdObject::UserStatus(_) = self { true } else { false } }
+  pub fn is_user_type(&self) -> bool { if let TdObject::UserType(_) = self { true } else { false } }
+  pub fn is_users(&self) -> bool { if let TdObject::Users(_) = self { true } else { false } }
+  pub fn is_validated_order_info(&self) -> bool { if let TdObject::ValidatedOrderInfo(_) = self { true } else { false } }
+  pub fn is_venue(&self) -> bool { if let TdObject::Venue(_) = self { true } else { false } }
+  pub fn is_video(&self) -> bool { if let TdObject::Video(_) = self { true } else { false } }
+  pub fn is_video_note(&self) -> bool { if let TdObject::VideoNote(_) = self { true } else { false } }
+  pub fn is_voice_note(&self) -> bool { if let TdObject::VoiceNote(_) = self { true } else { false } }
+  pub fn is_web_page(&self) -> bool { if let TdObject::WebPage(_) = self { true } else { false } }
+  pub fn is_web_page_instant_view(&self) -> bool { if let TdObject::WebPageInstantView(_) = self { true } else { false } }
+
+  pub fn on_account_ttl<F: FnOnce(&AccountTtl)>(&self, fnc: F) -> &Self { if let TdObject::AccountTtl(t) = self { fnc(t) }; self }
+  pub fn on_address<F: FnOnce(&Address)>(&self, fnc: F) -> &Self { if let TdObject::Address(t) = self { fnc(t) }; self }
+  pub fn on_animated_chat_photo<F: FnOnce(&AnimatedChatPhoto)>(&self, fnc: F) -> &Self { if let TdObject::AnimatedChatPhoto(t) = self { fnc(t) }; self }
+  pub fn on_animation<F: FnOnce(&Animation)>(&self, fnc: F) -> &Self { if let TdObject::Animation(t) = self { fnc(t) }; self }
+  pub fn on_animations<F: FnOnce(&Animations)>(&self, fnc: F) -> &Self { if let TdObject::Animations(t) = self { fnc(t) }; self }
+  pub fn on_audio<F: FnOnce(&Audio)>(&self, fnc: F) -> &Self { if let TdObject::Audio(t) = self { fnc(t) }; self }
+  pub fn on_authentication_code_info<F: FnOnce(&AuthenticationCodeInfo)>(&self, fnc: F) -> &Self { if let TdObject::AuthenticationCodeInfo(t) = self { fnc(t) }; self }
+  pub fn on_authentication_code_type<F: FnOnce(&AuthenticationCodeType)>(&self, fnc: F) -> &Self { if let TdObject::AuthenticationCodeType(t) = self { fnc(t) }; self }
+  pub fn on_authorization_state<F: FnOnce(&AuthorizationState)>(&self, fnc: F) -> &Self { if let TdObject::AuthorizationState(t) = self { fnc(t) }; self }
+  pub fn on_auto_download_settings<F: FnOnce(&AutoDownloadSettings)>(&self, fnc: F) -> &Self { if let TdObject::AutoDownloadSettings(t) = self { fnc(t) }; self }
+  pub fn on_auto_download_settings_presets<F: FnOnce(&AutoDownloadSettingsPresets)>(&self, fnc: F) -> &Self { if let TdObject::AutoDownloadSettingsPresets(t) = self { fnc(t) }; self }
+  pub fn on_background<F: FnOnce(&Background)>(&self, fnc: F) -> &Self { if let TdObject::Background(t) = self { fnc(t) }; self }
+  pub fn on_background_fill<F: FnOnce(&BackgroundFill)>(&self, fnc: F) -> &Self { if let TdObject::BackgroundFill(t) = self { fnc(t) }; self }
+  pub fn on_background_type<F: FnOnce(&BackgroundType)>(&self, fnc: F) -> &Self { if let TdObject::BackgroundType(t) = self { fnc(t) }; self }
+  pub fn on_backgrounds<F: FnOnce(&Backgrounds)>(&self, fnc: F) -> &Self { if let TdObject::Backgrounds(t) = self { fnc(t) }; self }
+  pub fn on_bank_card_action_open_url<F: FnOnce(&BankCardActionOpenUrl)>(&self, fnc: F) -> &Self { if let TdObject::BankCardActionOpenUrl(t) = self { fnc(t) }; self }
+  pub fn on_bank_card_info<F: FnOnce(&BankCardInfo)>(&self, fnc: F) -> &Self { if let TdObject::BankCardInfo(t) = self { fnc(t) }; self }
+  pub fn on_basic_group<F: FnOnce(&BasicGroup)>(&self, fnc: F) -> &Self { if let TdObject::BasicGroup(t) = self { fnc(t) }; self }
+  pub fn on_basic_group_full_info<F: FnOnce(&BasicGroupFullInfo)>(&self, fnc: F) -> &Self { if let TdObject::BasicGroupFullInfo(t) = self { fnc(t) }; self }
+  pub fn on_bot_command<F: FnOnce(&BotCommand)>(&self, fnc: F) -> &Self { if let TdObject::BotCommand(t) = self { fnc(t) }; self }
+  pub fn on_bot_info<F: FnOnce(&BotInfo)>(&self, fnc: F) -> &Self { if let TdObject::BotInfo(t) = self { fnc(t) }; self }
+  pub fn on_call<F: FnOnce(&Call)>(&self, fnc: F) -> &Self { if let TdObject::Call(t) = self { fnc(t) }; self }
+  pub fn on_call_discard_reason<F: FnOnce(&CallDiscardReason)>(&self, fnc: F) -> &Self { if let TdObject::CallDiscardReason(t) = self { fnc(t) }; self }
+  pub fn on_call_id<F: FnOnce(&CallId)>(&self, fnc: F) -> &Self { if let TdObject::CallId(t) = self { fnc(t) }; self }
+  pub fn on_call_problem<F: FnOnce(&CallProblem)>(&self, fnc: F) -> &Self { if let TdObject::CallProblem(t) = self { fnc(t) }; self }
+  pub fn on_call_protocol<F: FnOnce(&CallProtocol)>(&self, fnc: F) -> &Self { if let TdObject::CallProtocol(t) = self { fnc(t) }; self }
+  pub fn on_call_server<F: FnOnce(&CallServer)>(&self, fnc: F) -> &Self { if let TdObject::CallServer(t) = self { fnc(t) }; self }
+  pub fn on_call_server_type<F: FnOnce(&CallServerType)>(&self, fnc: F) -> &Self { if let TdObject::CallServerType(t) = self { fnc(t) }; self }
+  pub fn on_call_state<F: FnOnce(&CallState)>(&self, fnc: F) -> &Self { if let TdObject::CallState(t) = self { fnc(t) }; self }
+  pub fn on_callback_query_answer<F: FnOnce(&CallbackQueryAnswer)>(&self, fnc: F) -> &Self { if let TdObject::CallbackQueryAnswer(t) = self { fnc(t) }; self }
+  pub fn on_callback_query_payload<F: FnOnce(&CallbackQueryPayload)>(&self, fnc: F) -> &Self { if let TdObject::CallbackQueryPayload(t) = self { fnc(t) }; self }
+  pub fn on_can_transfer_ownership_result<F: FnOnce(&CanTransferOwnershipResult)>(&self, fnc: F) -> &Self { if let TdObject::CanTransferOwnershipResult(t) = self { fnc(t) }; self }
+  pub fn on_chat<F: FnOnce(&Chat)>(&self, fnc: F) -> &Self { if let TdObject::Chat(t) = self { fnc(t) }; self }
+  pub fn on_chat_action<F: FnOnce(&ChatAction)>(&self, fnc: F) -> &Self { if let TdObject::ChatAction(t) = self { fnc(t) }; self }
+  pub fn on_chat_action_bar<F: FnOnce(&ChatActionBar)>(&self, fnc: F) -> &Self { if let TdObject::ChatActionBar(t) = self { fnc(t) }; self }
+  pub fn on_chat_administrator<F: FnOnce(&ChatAdministrator)>(&self, fnc: F) -> &Self { if let TdObject::ChatAdministrator(t) = self { fnc(t) }; self }
+  pub fn on_chat_administrators<F: FnOnce(&ChatAdministrators)>(&self, fnc: F) -> &Self { if let TdObject::ChatAdministrators(t) = self { fnc(t) }; self }
+  pub fn on_chat_event<F: FnOnce(&ChatEvent)>(&self, fnc: F) -> &Self { if let TdObject::ChatEvent(t) = self { fnc(t) }; self }
+  pub fn on_chat_event_action<F: FnOnce(&ChatEventAction)>(&self, fnc: F) -> &Self { if let TdObject::ChatEventAction(t) = self { fnc(t) }; self }
+  pub fn on_chat_event_log_filters<F: FnOnce(&ChatEventLogFilters)>(&self, fnc: F) -> &Self { if let TdObject::ChatEventLogFilters(t) = self { fnc(t) }; self }
+  pub fn on_chat_events<F: FnOnce(&ChatEvents)>(&self, fnc: F) -> &Self { if let TdObject::ChatEvents(t) = self { fnc(t) }; self }
+  pub fn on_chat_filter<F: FnOnce(&ChatFilter)>(&self, fnc: F) -> &Self { if let TdObject::ChatFilter(t) = self { fnc(t) }; self }
+  pub fn on_chat_filter_info<F: FnOnce(&ChatFilterInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatFilterInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_invite_link<F: FnOnce(&ChatInviteLink)>(&self, fnc: F) -> &Self { if let TdObject::ChatInviteLink(t) = self { fnc(t) }; self }
+  pub fn on_chat_invite_link_info<F: FnOnce(&ChatInviteLinkInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatInviteLinkInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_list<F: FnOnce(&ChatList)>(&self, fnc: F) -> &Self { if let TdObject::ChatList(t) = self { fnc(t) }; self }
+  pub fn on_chat_lists<F: FnOnce(&ChatLists)>(&self, fnc: F) -> &Self { if let TdObject::ChatLists(t) = self { fnc(t) }; self }
+  pub fn on_chat_location<F: FnOnce(&ChatLocation)>(&self, fnc: F) -> &Self { if let TdObject::ChatLocation(t) = self { fnc(t) }; self }
+  pub fn on_chat_member<F: FnOnce(&ChatMember)>(&self, fnc: F) -> &Self { if let TdObject::ChatMember(t) = self { fnc(t) }; self }
+  pub fn on_chat_member_status<F: FnOnce(&ChatMemberStatus)>(&self, fnc: F) -> &Self { if let TdObject::ChatMemberStatus(t) = self { fnc(t) }; self }
+  pub fn on_chat_members<F: FnOnce(&ChatMembers)>(&self, fnc: F) -> &Self { if let TdObject::ChatMembers(t) = self { fnc(t) }; self }
+  pub fn on_chat_members_filter<F: FnOnce(&ChatMembersFilter)>(&self, fnc: F) -> &Self { if let TdObject::ChatMembersFilter(t) = self { fnc(t) }; self }
+  pub fn on_chat_nearby<F: FnOnce(&ChatNearby)>(&self, fnc: F) -> &Self { if let TdObject::ChatNearby(t) = self { fnc(t) }; self }
+  pub fn on_chat_notification_settings<F: FnOnce(&ChatNotificationSettings)>(&self, fnc: F) -> &Self { if let TdObject::ChatNotificationSettings(t) = self { fnc(t) }; self }
+  pub fn on_chat_permissions<F: FnOnce(&ChatPermissions)>(&self, fnc: F) -> &Self { if let TdObject::ChatPermissions(t) = self { fnc(t) }; self }
+  pub fn on_chat_photo<F: FnOnce(&ChatPhoto)>(&self, fnc: F) -> &Self { if let TdObject::ChatPhoto(t) = self { fnc(t) }; self }
+  pub fn on_chat_photo_info<F: FnOnce(&ChatPhotoInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatPhotoInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_photos<F: FnOnce(&ChatPhotos)>(&self, fnc: F) -> &Self { if let TdObject::ChatPhotos(t) = self { fnc(t) }; self }
+  pub fn on_chat_position<F: FnOnce(&ChatPosition)>(&self, fnc: F) -> &Self { if let TdObject::ChatPosition(t) = self { fnc(t) }; self }
+  pub fn on_chat_report_reason<F: FnOnce(&ChatReportReason)>(&self, fnc: F) -> &Self { if let TdObject::ChatReportReason(t) = self { fnc(t) }; self }
+  pub fn on_chat_source<F: FnOnce(&ChatSource)>(&self, fnc: F) -> &Self { if let TdObject::ChatSource(t) = self { fnc(t) }; self }
+  pub fn on_chat_statistics<F: FnOnce(&ChatStatistics)>(&self, fnc: F) -> &Self { if let TdObject::ChatStatistics(t) = self { fnc(t) }; self }
+  pub fn on_chat_statistics_administrator_actions_info<F: FnOnce(&ChatStatisticsAdministratorActionsInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatStatisticsAdministratorActionsInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_statistics_inviter_info<F: FnOnce(&ChatStatisticsInviterInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatStatisticsInviterInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_statistics_message_interaction_info<F: FnOnce(&ChatStatisticsMessageInteractionInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatStatisticsMessageInteractionInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_statistics_message_sender_info<F: FnOnce(&ChatStatisticsMessageSenderInfo)>(&self, fnc: F) -> &Self { if let TdObject::ChatStatisticsMessageSenderInfo(t) = self { fnc(t) }; self }
+  pub fn on_chat_type<F: FnOnce(&ChatType)>(&self, fnc: F) -> &Self { if let TdObject::ChatType(t) = self { fnc(t) }; self }
+  pub fn on_chats<F: FnOnce(&Chats)>(&self, fnc: F) -> &Self { if let TdObject::Chats(t) = self { fnc(t) }; self }
+  pub fn on_chats_nearby<F: FnOnce(&ChatsNearby)>(&self, fnc: F) -> &Self { if let TdObject::ChatsNearby(t) = self { fnc(t) }; self }
+  pub fn on_check_chat_username_result<F: FnOnce(&CheckChatUsernameResult)>(&self, fnc: F) -> &Self { if let TdObject::CheckChatUsernameResult(t) = self { fnc(t) }; self }
+  pub fn on_connected_website<F: FnOnce(&ConnectedWebsite)>(&self, fnc: F) -> &Self { if let TdObject::ConnectedWebsite(t) = self { fnc(t) }; self }
+  pub fn on_connected_websites<F: FnOnce(&ConnectedWebsites)>(&self, fnc: F) -> &Self { if let TdObject::ConnectedWebsites(t) = self { fnc(t) }; self }
+  pub fn on_connection_state<F: FnOnce(&ConnectionState)>(&self, fnc: F) -> &Self { if let TdObject::ConnectionState(t) = self { fnc(t) }; self }
+  pub fn on_contact<F: FnOnce(&Contact)>(&self, fnc: F) -> &Self { if let TdObject::Contact(t) = self { fnc(t) }; self }
+  pub fn on_count<F: FnOnce(&Count)>(&self, fnc: F) -> &Self { if let TdObject::Count(t) = self { fnc(t) }; self }
+  pub fn on_custom_request_result<F: FnOnce(&CustomRequestResult)>(&self, fnc: F) -> &Self { if let TdObject::CustomRequestResult(t) = self { fnc(t) }; self }
+  pub fn on_database_statistics<F: FnOnce(&DatabaseStatistics)>(&self, fnc: F) -> &Self { if let TdObject::DatabaseStatistics(t) = self { fnc(t) }; self }
+  pub fn on_date<F: FnOnce(&Date)>(&self, fnc: F) -> &Self { if let TdObject::Date(t) = self { fnc(t) }; self }
+  pub fn on_date_range<F: FnOnce(&DateRange)>(&self, fnc: F) -> &Self { if let TdObject::DateRange(t) = self { fnc(t) }; self }
+  pub fn on_dated_file<F: FnOnce(&DatedFile)>(&self, fnc: F) -> &Self { if let TdObject::DatedFile(t) = self { fnc(t) }; self }
+  pub fn on_deep_link_info<F: FnOnce(&DeepLinkInfo)>(&self, fnc: F) -> &Self { if let TdObject::DeepLinkInfo(t) = self { fnc(t) }; self }
+  pub fn on_device_token<F: FnOnce(&DeviceToken)>(&self, fnc: F) -> &Self { if let TdObject::DeviceToken(t) = self { fnc(t) }; self }
+  pub fn on_document<F: FnOnce(&Document)>(&self, fnc: F) -> &Self { if let TdObject::Document(t) = self { fnc(t) }; self }
+  pub fn on_draft_message<F: FnOnce(&DraftMessage)>(&self, fnc: F) -> &Self { if let TdObject::DraftMessage(t) = self { fnc(t) }; self }
+  pub fn on_email_address_authentication_code_info<F: FnOnce(&EmailAddressAuthenticationCodeInfo)>(&self, fnc: F) -> &Self { if let TdObject::EmailAddressAuthenticationCodeInfo(t) = self { fnc(t) }; self }
+  pub fn on_emojis<F: FnOnce(&Emojis)>(&self, fnc: F) -> &Self { if let TdObject::Emojis(t) = self { fnc(t) }; self }
+  pub fn on_encrypted_credentials<F: FnOnce(&EncryptedCredentials)>(&self, fnc: F) -> &Self { if let TdObject::EncryptedCredentials(t) = self { fnc(t) }; self }
+  pub fn on_encrypted_passport_element<F: FnOnce(&EncryptedPassportElement)>(&self, fnc: F) -> &Self { if let TdObject::EncryptedPassportElement(t) = self { fnc(t) }; self }
+  pub fn on_error<F: FnOnce(&Error)>(&self, fnc: F) -> &Self { if let TdObject::Error(t) = self { fnc(t) }; self }
+  pub fn on_file<F: FnOnce(&File)>(&self, fnc: F) -> &Self { if let TdObject::File(t) = self { fnc(t) }; self }
+  pub fn on_file_part<F: FnOnce(&FilePart)>(&self, fnc: F) -> &Self { if let TdObject::FilePart(t) = self { fnc(t) }; self }
+  pub fn on_file_type<F: FnOnce(&FileType)>(&self, fnc: F) -> &Self { if let TdObject::FileType(t) = self { fnc(t) }; self }
+  pub fn on_formatted_text<F: FnOnce(&FormattedText)>(&self, fnc: F) -> &Self { if let TdObject::FormattedText(t) = self { fnc(t) }; self }
+  pub fn on_found_messages<F: FnOnce(&FoundMessages)>(&self, fnc: F) -> &Self { if let TdObject::FoundMessages(t) = self { fnc(t) }; self }
+  pub fn on_game<F: FnOnce(&Game)>(&self, fnc: F) -> &Self { if let TdObject::Game(t) = self { fnc(t) }; self }
+  pub fn on_game_high_score<F: FnOnce(&GameHighScore)>(&self, fnc: F) -> &Self { if let TdObject::GameHighScore(t) = self { fnc(t) }; self }
+  pub fn on_game_high_scores<F: FnOnce(&GameHighScores)>(&self, fnc: F) -> &Self { if let TdObject::GameHighScores(t) = self { fnc(t) }; self }
+  pub fn on_hashtags<F: FnOnce(&Hashtags)>(&self, fnc: F) -> &Self { if let TdObject::Hashtags(t) = self { fnc(t) }; self }
+  pub fn on_http_url<F: FnOnce(&HttpUrl)>(&self, fnc: F) -> &Self { if let TdObject::HttpUrl(t) = self { fnc(t) }; self }
+  pub fn on_identity_document<F: FnOnce(&IdentityDocument)>(&self, fnc: F) -> &Self { if let TdObject::IdentityDocument(t) = self { fnc(t) }; self }
+  pub fn on_imported_contacts<F: FnOnce(&ImportedContacts)>(&self, fnc: F) -> &Self { if let TdObject::ImportedContacts(t) = self { fnc(t) }; self }
+  pub fn on_inline_keyboard_button<F: FnOnce(&InlineKeyboardButton)>(&self, fnc: F) -> &Self { if let TdObject::InlineKeyboardButton(t) = self { fnc(t) }; self }
+  pub fn on_inline_keyboard_button_type<F: FnOnce(&InlineKeyboardButtonType)>(&self, fnc: F) -> &Self { if let TdObject::InlineKeyboardButtonType(t) = self { fnc(t) }; self }
+  pub fn on_inline_query_result<F: FnOnce(&InlineQueryResult)>(&self, fnc: F) -> &Self { if let TdObject::InlineQueryResult(t) = self { fnc(t) }; self }
+  pub fn on_inline_query_results<F: FnOnce(&InlineQueryResults)>(&self, fnc: F) -> &Self { if let TdObject::InlineQueryResults(t) = self { fnc(t) }; self }
+  pub fn on_input_background<F: FnOnce(&InputBackground)>(&self, fnc: F) -> &Self { if let TdObject::InputBackground(t) = self { fnc(t) }; self }
+  pub fn on_input_chat_photo<F: FnOnce(&InputChatPhoto)>(&self, fnc: F) -> &Self { if let TdObject::InputChatPhoto(t) = self { fnc(t) }; self }
+  pub fn on_input_credentials<F: FnOnce(&InputCredentials)>(&self, fnc: F) -> &Self { if let TdObject::InputCredentials(t) = self { fnc(t) }; self }
+  pub fn on_input_file<F: FnOnce(&InputFile)>(&self, fnc: F) -> &Self { if let TdObject::InputFile(t) = self { fnc(t) }; self }
+  pub fn on_input_identity_document<F: FnOnce(&InputIdentityDocument)>(&self, fnc: F) -> &Self { if let TdObject::InputIdentityDocument(t) = self { fnc(t) }; self }
+  pub fn on_input_inline_query_result<F: FnOnce(&InputInlineQueryResult)>(&self, fnc: F) -> &Self { if let TdObject::InputInlineQueryResult(t) = self { fnc(t) }; self }
+  pub fn on_input_message_content<F: FnOnce(&InputMessageContent)>(&self, fnc: F) -> &Self { if let TdObject::InputMessageContent(t) = self { fnc(t) }; self }
+  pub fn on_input_passport_element<F: FnOnce(&InputPassportElement)>(&self, fnc: F) -> &Self { if let TdObject::InputPassportElement(t) = self { fnc(t) }; self }
+  pub fn on_input_passport_element_error<F: FnOnce(&InputPassportElementError)>(&self, fnc: F) -> &Self { if let TdObject::InputPassportElementError(t) = self { fnc(t) }; self }
+  pub fn on_input_passport_element_error_source<F: FnOnce(&InputPassportElementErrorSource)>(&self, fnc: F) -> &Self { if let TdObject::InputPassportElementErrorSource(t) = self { fnc(t) }; self }
+  pub fn on_input_personal_document<F: FnOnce(&InputPersonalDocument)>(&self, fnc: F) -> &Self { if let TdObject::InputPersonalDocument(t) = self { fnc(t) }; self }
+  pub fn on_input_sticker<F: FnOnce(&InputSticker)>(&self, fnc: F) -> &Self { if let TdObject::InputSticker(t) = self { fnc(t) }; self }
+  pub fn on_input_thumbnail<F: FnOnce(&InputThumbnail)>(&self, fnc: F) -> &Self { if let TdObject::InputThumbnail(t) = self { fnc(t) }; self }
+  pub fn on_invoice<F: FnOnce(&Invoice)>(&self, fnc: F) -> &Self { if let TdObject::Invoice(t) = self { fnc(t) }; self }
+  pub fn on_json_object_member<F: FnOnce(&JsonObjectMember)>(&self, fnc: F) -> &Self { if let TdObject::JsonObjectMember(t) = self { fnc(t) }; self }
+  pub fn on_json_value<F: FnOnce(&JsonValue)>(&self, fnc: F) -> &Self { if let TdObject::JsonValue(t) = self { fnc(t) }; self }
+  pub fn on_keyboard_button<F: FnOnce(&KeyboardButton)>(&self, fnc: F) -> &Self { if let TdObject::KeyboardButton(t) = self { fnc(t) }; self }
+  pub fn on_keyboard_button_type<F: FnOnce(&KeyboardButtonType)>(&self, fnc: F) -> &Self { if let TdObject::KeyboardButtonType(t) = self { fnc(t) }; self }
+  pub fn on_labeled_price_part<F: FnOnce(&LabeledPricePart)>(&self, fnc: F) -> &Self { if let TdObject::LabeledPricePart(t) = self { fnc(t) }; self }
+  pub fn on_language_pack_info<F: FnOnce(&LanguagePackInfo)>(&self, fnc: F) -> &Self { if let TdObject::LanguagePackInfo(t) = self { fnc(t) }; self }
+  pub fn on_language_pack_string<F: FnOnce(&LanguagePackString)>(&self, fnc: F) -> &Self { if let TdObject::LanguagePackString(t) = self { fnc(t) }; self }
+  pub fn on_language_pack_string_value<F: FnOnce(&LanguagePackStringValue)>(&self, fnc: F) -> &Self { if let TdObject::LanguagePackStringValue(t) = self { fnc(t) }; self }
+  pub fn on_language_pack_strings<F: FnOnce(&LanguagePackStrings)>(&self, fnc: F) -> &Self { if let TdObject::LanguagePackStrings(t) = self { fnc(t) }; self }
+  pub fn on_local_file<F: FnOnce(&LocalFile)>(&self, fnc: F) -> &Self { if let TdObject::LocalFile(t) = self { fnc(t) }; self }
+  pub fn on_localization_target_info<F: FnOnce(&LocalizationTargetInfo)>(&self, fnc: F) -> &Self { if let TdObject::LocalizationTargetInfo(t) = self { fnc(t) }; self }
+  pub fn on_location<F: FnOnce(&Location)>(&self, fnc: F) -> &Self { if let TdObject::Location(t) = self { fnc(t) }; self }
+  pub fn on_log_stream<F: FnOnce(&LogStream)>(&self, fnc: F) -> &Self { if let TdObject::LogStream(t) = self { fnc(t) }; self }
+  pub fn on_log_tags<F: FnOnce(&LogTags)>(&self, fnc: F) -> &Self { if let TdObject::LogTags(t) = self { fnc(t) }; self }
+  pub fn on_log_verbosity_level<F: FnOnce(&LogVerbosityLevel)>(&self, fnc: F) -> &Self { if let TdObject::LogVerbosityLevel(t) = self { fnc(t) }; self }
+  pub fn on_login_url_info<F: FnOnce(&LoginUrlInfo)>(&self, fnc: F) -> &Self { if let TdObject::LoginUrlInfo(t) = self { fnc(t) }; self }
+  pub fn on_mask_point<F: FnOnce(&MaskPoint)>(&self, fnc: F) -> &Self { if let TdObject::MaskPoint(t) = self { fnc(t) }; self }
+  pub fn on_mask_position<F: FnOnce(&MaskPosition)>(&self, fnc: F) -> &Self { if let TdObject::MaskPosition(t) = self { fnc(t) }; self }
+  pub fn on_message<F: FnOnce(&Message)>(&self, fnc: F) -> &Self { if let TdObject::Message(t) = self { fnc(t) }; self }
+  pub fn on_message_content<F: FnOnce(&MessageContent)>(&self, fnc: F) -> &Self { if let TdObject::MessageContent(t) = self { fnc(t) }; self }
+  pub fn on_message_copy_options<F: FnOnce(&MessageCopyOptions)>(&self, fnc: F) -> &Self { if let TdObject::MessageCopyOptions(t) = self { fnc(t) }; self }
+  pub fn on_message_forward_info<F: FnOnce(&MessageForwardInfo)>(&self, fnc: F) -> &Self { if let TdObject::MessageForwardInfo(t) = self { fnc(t) }; self }
+  pub fn on_message_forward_origin<F: FnOnce(&MessageForwardOrigin)>(&self, fnc: F) -> &Self { if let TdObject::MessageForwardOrigin(t) = self { fnc(t) }; self }
+  pub fn on_message_link_info<F: FnOnce(&MessageLinkInfo)>(&self, fnc: F) -> &Self { if let TdObject::MessageLinkInfo(t) = self { fnc(t) }; self }
+  pub fn on_message_scheduling_state<F: FnOnce(&MessageSchedulingState)>(&self, fnc: F) -> &Self { if let TdObject::MessageSchedulingState(t) = self { fnc(t) }; self }
+  pub fn on_message_send_options<F: FnOnce(&MessageSendOptions)>(&self, fnc: F) -> &Self { if let TdObject::MessageSendOptions(t) = self { fnc(t) }; self }
+  pub fn on_message_sending_state<F: FnOnce(&MessageSendingState)>(&self, fnc: F) -> &Self { if let TdObject::MessageSendingState(t) = self { fnc(t) }; self }
+  pub fn on_messages<F: FnOnce(&Messages)>(&self, fnc: F) -> &Self { if let TdObject::Messages(t) = self { fnc(t) }; self }
+  pub fn on_minithumbnail<F: FnOnce(&Minithumbnail)>(&self, fnc: F) -> &Self { if let TdObject::Minithumbnail(t) = self { fnc(t) }; self }
+  pub fn on_network_statistics<F: FnOnce(&NetworkStatistics)>(&self, fnc: F) -> &Self { if let TdObject::NetworkStatistics(t) = self { fnc(t) }; self }
+  pub fn on_network_statistics_entry<F: FnOnce(&NetworkStatisticsEntry)>(&self, fnc: F) -> &Self { if let TdObject::NetworkStatisticsEntry(t) = self { fnc(t) }; self }
+  pub fn on_network_type<F: FnOnce(&NetworkType)>(&self, fnc: F) -> &Self { if let TdObject::NetworkType(t) = self { fnc(t) }; self }
+  pub fn on_notification<F: FnOnce(&Notification)>(&self, fnc: F) -> &Self { if let TdObject::Notification(t) = self { fnc(t) }; self }
+  pub fn on_notification_group<F: FnOnce(&NotificationGroup)>(&self, fnc: F) -> &Self { if let TdObject::NotificationGroup(t) = self { fnc(t) }; self }
+  pub fn on_notification_group_type<F: FnOnce(&NotificationGroupType)>(&self, fnc: F) -> &Self { if let TdObject::NotificationGroupType(t) = self { fnc(t) }; self }
+  pub fn on_notification_settings_scope<F: FnOnce(&NotificationSettingsScope)>(&self, fnc: F) -> &Self { if let TdObject::NotificationSettingsScope(t) = self { fnc(t) }; self }
+  pub fn on_notification_type<F: FnOnce(&NotificationType)>(&self, fnc: F) -> &Self { if let TdObject::NotificationType(t) = self { fnc(t) }; self }
+  pub fn on_ok<F: FnOnce(&Ok)>(&self, fnc: F) -> &Self { if let TdObject::Ok(t) = self { fnc(t) }; self }
+  pub fn on_option_value<F: FnOnce(&OptionValue)>(&self, fnc: F) -> &Self { if let TdObject::OptionValue(t) = self { fnc(t) }; self }
+  pub fn on_order_info<F: FnOnce(&OrderInfo)>(&self, fnc: F) -> &Self { if let TdObject::OrderInfo(t) = self { fnc(t) }; self }
+  pub fn on_page_block<F: FnOnce(&PageBlock)>(&self, fnc: F) -> &Self { if let TdObject::PageBlock(t) = self { fnc(t) }; self }
+  pub fn on_page_block_caption<F: FnOnce(&PageBlockCaption)>(&self, fnc: F) -> &Self { if let TdObject::PageBlockCaption(t) = self { fnc(t) }; self }
+  pub fn on_page_block_horizontal_alignment<F: FnOnce(&PageBlockHorizontalAlignment)>(&self, fnc: F) -> &Self { if let TdObject::PageBlockHorizontalAlignment(t) = self { fnc(t) }; self }
+  pub fn on_page_block_list_item<F: FnOnce(&PageBlockListItem)>(&self, fnc: F) -> &Self { if let TdObject::PageBlockListItem(t) = self { fnc(t) }; self }
+  pub fn on_page_block_related_article<F: FnOnce(&PageBlockRelatedArticle)>(&self, fnc: F) -> &Self { if let TdObject::PageBlockRelatedArticle(t) = self { fnc(t) }; self }
+  pub fn on_page_block_table_cell<F: FnOnce(&PageBlockTableCell)>(&self, fnc: F) -> &Self { if let TdObject::PageBlockTableCell(t) = self { fnc(t) }; self }
+  pub fn on_page_block_vertical_alignment<F: FnOnce(&PageBlockVerticalAlignment)>(&self, fnc: F) -> &Self { if let TdObject::PageBlockVerticalAlignment(t) = self { fnc(t) }; self }
+  pub fn on_passport_authorization_form<F: FnOnce(&PassportAuthorizationForm)>(&self, fnc: F) -> &Self { if let TdObject::PassportAuthorizationForm(t) = self { fnc(t) }; self }
+  pub fn on_passport_element<F: FnOnce(&PassportElement)>(&self, fnc: F) -> &Self { if let TdObject::PassportElement(t) = self { fnc(t) }; self }
+  pub fn on_passport_element_error<F: FnOnce(&PassportElementError)>(&self, fnc: F) -> &Self { if let TdObject::PassportElementError(t) = self { fnc(t) }; self }
+  pub fn on_passport_element_error_source<F: FnOnce(&PassportElementErrorSource)>(&self, fnc: F) -> &Self { if let TdObject::PassportElementErrorSource(t) = self { fnc(t) }; self }
+  pub fn on_passport_element_type<F: FnOnce(&PassportElementType)>(&self, fnc: F) -> &Self { if let TdObject::PassportElementType(t) = self { fnc(t) }; self }
+  pub fn on_passport_elements<F: FnOnce(&PassportElements)>(&self, fnc: F) -> &Self { if let TdObject::PassportElements(t) = self { fnc(t) }; self }
+  pub fn on_passport_elements_with_errors<F: FnOnce(&PassportElementsWithErrors)>(&self, fnc: F) -> &Self { if let TdObject::PassportElementsWithErrors(t) = self { fnc(t) }; self }
+  pub fn on_passport_required_element<F: FnOnce(&PassportRequiredElement)>(&self, fnc: F) -> &Self { if let TdObject::PassportRequiredElement(t) = self { fnc(t) }; self }
+  pub fn on_passport_suitable_element<F: FnOnce(&PassportSuitableElement)>(&self, fnc: F) -> &Self { if let TdObject::PassportSuitableElement(t) = self { fnc(t) }; self }
+  pub fn on_password_state<F: FnOnce(&PasswordState)>(&self, fnc: F) -> &Self { if let TdObject::PasswordState(t) = self { fnc(t) }; self }
+  pub fn on_payment_form<F: FnOnce(&PaymentForm)>(&self, fnc: F) -> &Self { if let TdObject::PaymentForm(t) = self { fnc(t) }; self }
+  pub fn on_payment_receipt<F: FnOnce(&PaymentReceipt)>(&self, fnc: F) -> &Self { if let TdObject::PaymentReceipt(t) = self { fnc(t) }; self }
+  pub fn on_payment_result<F: FnOnce(&PaymentResult)>(&self, fnc: F) -> &Self { if let TdObject::PaymentResult(t) = self { fnc(t) }; self }
+  pub fn on_payments_provider_stripe<F: FnOnce(&PaymentsProviderStripe)>(&self, fnc: F) -> &Self { if let TdObject::PaymentsProviderStripe(t) = self { fnc(t) }; self }
+  pub fn on_personal_details<F: FnOnce(&PersonalDetails)>(&self, fnc: F) -> &Self { if let TdObject::PersonalDetails(t) = self { fnc(t) }; self }
+  pub fn on_personal_document<F: FnOnce(&PersonalDocument)>(&self, fnc: F) -> &Self { if let TdObject::PersonalDocument(t) = self { fnc(t) }; self }
+  pub fn on_phone_number_authentication_settings<F: FnOnce(&PhoneNumberAuthenticationSettings)>(&self, fnc: F) -> &Self { if let TdObject::PhoneNumberAuthenticationSettings(t) = self { fnc(t) }; self }
+  pub fn on_photo<F: FnOnce(&Photo)>(&self, fnc: F) -> &Self { if let TdObject::Photo(t) = self { fnc(t) }; self }
+  pub fn on_photo_size<F: FnOnce(&PhotoSize)>(&self, fnc: F) -> &Self { if let TdObject::PhotoSize(t) = self { fnc(t) }; self }
+  pub fn on_poll<F: FnOnce(&Poll)>(&self, fnc: F) -> &Self { if let TdObject::Poll(t) = self { fnc(t) }; self }
+  pub fn on_poll_option<F: FnOnce(&PollOption)>(&self, fnc: F) -> &Self { if let TdObject::PollOption(t) = self { fnc(t) }; self }
+  pub fn on_poll_type<F: FnOnce(&PollType)>(&self, fnc: F) -> &Self { if let TdObject::PollType(t) = self { fnc(t) }; self }
+  pub fn on_profile_photo<F: FnOnce(&ProfilePhoto)>(&self, fnc: F) -> &Self { if let TdObject::ProfilePhoto(t) = self { fnc(t) }; self }
+  pub fn on_proxies<F: FnOnce(&Proxies)>(&self, fnc: F) -> &Self { if let TdObject::Proxies(t) = self { fnc(t) }; self }
+  pub fn on_proxy<F: FnOnce(&Proxy)>(&self, fnc: F) -> &Self { if let TdObject::Proxy(t) = self { fnc(t) }; self }
+  pub fn on_proxy_type<F: FnOnce(&ProxyType)>(&self, fnc: F) -> &Self { if let TdObject::ProxyType(t) = self { fnc(t) }; self }
+  pub fn on_public_chat_type<F: FnOnce(&PublicChatType)>(&self, fnc: F) -> &Self { if let TdObject::PublicChatType(t) = self { fnc(t) }; self }
+  pub fn on_public_message_link<F: FnOnce(&PublicMessageLink)>(&self, fnc: F) -> &Self { if let TdObject::PublicMessageLink(t) = self { fnc(t) }; self }
+  pub fn on_push_message_content<F: FnOnce(&PushMessageContent)>(&self, fnc: F) -> &Self { if let TdObject::PushMessageContent(t) = self { fnc(t) }; self }
+  pub fn on_push_receiver_id<F: FnOnce(&PushReceiverId)>(&self, fnc: F) -> &Self { if let TdObject::PushReceiverId(t) = self { fnc(t) }; self }
+  pub fn on_recommended_chat_filter<F: FnOnce(&RecommendedChatFilter)>(&self, fnc: F) -> &Self { if let TdObject::RecommendedChatFilter(t) = self { fnc(t) }; self }
+  pub fn on_recommended_chat_filters<F: FnOnce(&RecommendedChatFilters)>(&self, fnc: F) -> &Self { if let TdObject::RecommendedChatFilters(t) = self { fnc(t) }; self }
+  pub fn on_recovery_email_address<F: FnOnce(&RecoveryEmailAddress)>(&self, fnc: F) -> &Self { if let TdObject::RecoveryEmailAddress(t) = self { fnc(t) }; self }
+  pub fn on_remote_file<F: FnOnce(&RemoteFile)>(&self, fnc: F) -> &Self { if let TdObject::RemoteFile(t) = self { fnc(t) }; self }
+  pub fn on_reply_markup<F: FnOnce(&ReplyMarkup)>(&self, fnc: F) -> &Self { if let TdObject::ReplyMarkup(t) = self { fnc(t) }; self }
+  pub fn on_rich_text<F: FnOnce(&RichText)>(&self, fnc: F) -> &Self { if let TdObject::RichText(t) = self { fnc(t) }; self }
+  pub fn on_saved_credentials<F: FnOnce(&SavedCredentials)>(&self, fnc: F) -> &Self { if let TdObject::SavedCredentials(t) = self { fnc(t) }; self }
+  pub fn on_scope_notification_settings<F: FnOnce(&ScopeNotificationSettings)>(&self, fnc: F) -> &Self { if let TdObject::ScopeNotificationSettings(t) = self { fnc(t) }; self }
+  pub fn on_search_messages_filter<F: FnOnce(&SearchMessagesFilter)>(&self, fnc: F) -> &Self { if let TdObject::SearchMessagesFilter(t) = self { fnc(t) }; self }
+  pub fn on_seconds<F: FnOnce(&Seconds)>(&self, fnc: F) -> &Self { if let TdObject::Seconds(t) = self { fnc(t) }; self }
+  pub fn on_secret_chat<F: FnOnce(&SecretChat)>(&self, fnc: F) -> &Self { if let TdObject::SecretChat(t) = self { fnc(t) }; self }
+  pub fn on_secret_chat_state<F: FnOnce(&SecretChatState)>(&self, fnc: F) -> &Self { if let TdObject::SecretChatState(t) = self { fnc(t) }; self }
+  pub fn on_session<F: FnOnce(&Session)>(&self, fnc: F) -> &Self { if let TdObject::Session(t) = self { fnc(t) }; self }
+  pub fn on_sessions<F: FnOnce(&Sessions)>(&self, fnc: F) -> &Self { if let TdObject::Sessions(t) = self { fnc(t) }; self }
+  pub fn on_shipping_option<F: FnOnce(&ShippingOption)>(&self, fnc: F) -> &Self { if let TdObject::ShippingOption(t) = self { fnc(t) }; self }
+  pub fn on_statistics_graph<F: FnOnce(&StatisticsGraph)>(&self, fnc: F) -> &Self { if let TdObject::StatisticsGraph(t) = self { fnc(t) }; self }
+  pub fn on_statistics_value<F: FnOnce(&StatisticsValue)>(&self, fnc: F) -> &Self { if let TdObject::StatisticsValue(t) = self { fnc(t) }; self }
+  pub fn on_sticker<F: FnOnce(&Sticker)>(&self, fnc: F) -> &Self { if let TdObject::Sticker(t) = self { fnc(t) }; self }
+  pub fn on_sticker_set<F: FnOnce(&StickerSet)>(&self, fnc: F) -> &Self { if let TdObject::StickerSet(t) = self { fnc(t) }; self }
+  pub fn on_sticker_set_info<F: FnOnce(&StickerSetInfo)>(&self, fnc: F) -> &Self { if let TdObject::StickerSetInfo(t) = self { fnc(t) }; self }
+  pub fn on_sticker_sets<F: FnOnce(&StickerSets)>(&self, fnc: F) -> &Self { if let TdObject::StickerSets(t) = self { fnc(t) }; self }
+  pub fn on_stickers<F: FnOnce(&Stickers)>(&self, fnc: F) -> &Self { if let TdObject::Stickers(t) = self { fnc(t) }; self }
+  pub fn on_storage_statistics<F: FnOnce(&StorageStatistics)>(&self, fnc: F) -> &Self { if let TdObject::StorageStatistics(t) = self { fnc(t) }; self }
+  pub fn on_storage_statistics_by_chat<F: FnOnce(&StorageStatisticsByChat)>(&self, fnc: F) -> &Self { if let TdObject::StorageStatisticsByChat(t) = self { fnc(t) }; self }
+  pub fn on_storage_statistics_by_file_type<F: FnOnce(&StorageStatisticsByFileType)>(&self, fnc: F) -> &Self { if let TdObject::StorageStatisticsByFileType(t) = self { fnc(t) }; self }
+  pub fn on_storage_statistics_fast<F: FnOnce(&StorageStatisticsFast)>(&self, fnc: F) -> &Self { if let TdObject::StorageStatisticsFast(t) = self { fnc(t) }; self }
+  pub fn on_suggested_action<F: FnOnce(&SuggestedAction)>(&self, fnc: F) -> &Self { if let TdObject::SuggestedAction(t) = self { fnc(t) }; self }
+  pub fn on_supergroup<F: FnOnce(&Supergroup)>(&self, fnc: F) -> &Self { if let TdObject::Supergroup(t) = self { fnc(t) }; self }
+  pub fn on_supergroup_full_info<F: FnOnce(&SupergroupFullInfo)>(&self, fnc: F) -> &Self { if let TdObject::SupergroupFullInfo(t) = self { fnc(t) }; self }
+  pub fn on_supergroup_members_filter<F: FnOnce(&SupergroupMembersFilter)>(&self, fnc: F) -> &Self { if let TdObject::SupergroupMembersFilter(t) = self { fnc(t) }; self }
+  pub fn on_t_me_url<F: FnOnce(&TMeUrl)>(&self, fnc: F) -> &Self { if let TdObject::TMeUrl(t) = self { fnc(t) }; self }
+  pub fn on_t_me_url_type<F: FnOnce(&TMeUrlType)>(&self, fnc: F) -> &Self { if let TdObject::TMeUrlType(t) = self { fnc(t) }; self }
+  pub fn on_t_me_urls<F: FnOnce(&TMeUrls)>(&self, fnc: F) -> &Self { if let TdObject::TMeUrls(t) = self { fnc(t) }; self }
+  pub fn on_tdlib_parameters<F: FnOnce(&TdlibParameters)>(&self, fnc: F) -> &Self { if let TdObject::TdlibParameters(t) = self { fnc(t) }; self }
+  pub fn on_temporary_password_state<F: FnOnce(&TemporaryPasswordState)>(&self, fnc: F) -> &Self { if let TdObject::TemporaryPasswordState(t) = self { fnc(t) }; self }
+  pub fn on_terms_of_service<F: FnOnce(&TermsOfService)>(&self, fnc: F) -> &Self { if let TdObject::TermsOfService(t) = self { fnc(t) }; self }
+  pub fn on_test_bytes<F: FnOnce(&TestBytes)>(&self, fnc: F) -> &Self { if let TdObject::TestBytes(t) = self { fnc(t) }; self }
+  pub fn on_test_int<F: FnOnce(&TestInt)>(&self, fnc: F) -> &Self { if let TdObject::TestInt(t) = self { fnc(t) }; self }
+  pub fn on_test_string<F: FnOnce(&TestString)>(&self, fnc: F) -> &Self { if let TdObject::TestString(t) = self { fnc(t) }; self }
+  pub fn on_test_vector_int<F: FnOnce(&TestVectorInt)>(&self, fnc: F) -> &Self { if let TdObject::TestVectorInt(t) = self { fnc(t) }; self }
+  pub fn on_test_vector_int_object<F: FnOnce(&TestVectorIntObject)>(&self, fnc: F) -> &Self { if let TdObject::TestVectorIntObject(t) = self { fnc(t) }; self }
+  pub fn on_test_vector_string<F: FnOnce(&TestVectorString)>(&self, fnc: F) -> &Self { if let TdObject::TestVectorString(t) = self { fnc(t) }; self }
+  pub fn on_test_vector_string_object<F: FnOnce(&TestVectorStringObject)>(&self, fnc: F) -> &Self { if let TdObject::TestVectorStringObject(t) = self { fnc(t) }; self }
+  pub fn on_text<F: FnOnce(&Text)>(&self, fnc: F) -> &Self { if let TdObject::Text(t) = self { fnc(t) }; self }
+  pub fn on_text_entities<F: FnOnce(&TextEntities)>(&self, fnc: F) -> &Self { if let TdObject::TextEntities(t) = self { fnc(t) }; self }
+  pub fn on_text_entity<F: FnOnce(&TextEntity)>(&self, fnc: F) -> &Self { if let TdObject::TextEntity(t) = self { fnc(t) }; self }
+  pub fn on_text_entity_type<F: FnOnce(&TextEntityType)>(&self, fnc: F) -> &Self { if let TdObject::TextEntityType(t) = self { fnc(t) }; self }
+  pub fn on_text_parse_mode<F: FnOnce(&TextParseMode)>(&self, fnc: F) -> &Self { if let TdObject::TextParseMode(t) = self { fnc(t) }; self }
+  pub fn on_thumbnail<F: FnOnce(&Thumbnail)>(&self, fnc: F) -> &Self { if let TdObject::Thumbnail(t) = self { fnc(t) }; self }
+  pub fn on_thumbnail_format<F: FnOnce(&ThumbnailFormat)>(&self, fnc: F) -> &Self { if let TdObject::ThumbnailFormat(t) = self { fnc(t) }; self }
+  pub fn on_top_chat_category<F: FnOnce(&TopChatCategory)>(&self, fnc: F) -> &Self { if let TdObject::TopChatCategory(t) = self { fnc(t) }; self }
+  pub fn on_update<F: FnOnce(&Update)>(&self, fnc: F) -> &Self { if let TdObject::Update(t) = self { fnc(t) }; self }
+  pub fn on_updates<F: FnOnce(&Updates)>(&self, fnc: F) -> &Self { if let TdObject::Updates(t) = self { fnc(t) }; self }
+  pub fn on_user<F: FnOnce(&User)>(&self, fnc: F) -> &Self { if let TdObject::User(t) = self { fnc(t) }; self }
+  pub fn on_user_full_info<F: FnOnce(&UserFullInfo)>(&self, fnc: F) -> &Self { if let TdObject::UserFullInfo(t) = self { fnc(t) }; self }
+  pub fn on_user_privacy_setting<F: FnOnce(&UserPrivacySetting)>(&self, fnc: F) -> &Self { if let TdObject::UserPrivacySetting(t) = self { fnc(t) }; self }
+  pub fn on_user_privacy_setting_rule<F: FnOnce(&UserPrivacySettingRule)>(&self, fnc: F) -> &Self { if let TdObject::UserPrivacySettingRule(t) = self { fnc(t) }; self }
+  pub fn on_user_privacy_setting_rules<F: FnOnce(&UserPrivacySettingRules)>(&self, fnc: F) -> &Self { if let TdObject::UserPrivacySettingRules(t) = self { fnc(t) }; self }
+  pub fn on_user_status<F: FnOnce(&UserStatus)>(&self, fnc: F) -> &Self { if let TdObject::UserStatus(t) = self { fnc(t) }; self }
+  pub fn on_user_type<F: FnOnce(&UserType)>(&self, fnc: F) -> &Self { if let TdObject::UserType(t) = self { fnc(t) }; self }
+  pub fn on_users<F: FnOnce(&Users)>(&self, fnc: F) -> &Self { if let TdObject::Users(t) = self { fnc(t) }; self }
+  pub fn on_validated_order_info<F: FnOnce(&ValidatedOrderInfo)>(&self, fnc: F) -> &Self { if let TdObject::ValidatedOrderInfo(t) = self { fnc(t) }; self }
+  pub fn on_venue<F: FnOnce(&Venue)>(&self, fnc: F) -> &Self { if let TdObject::Venue(t) = self { fnc(t) }; self }
+  pub fn on_video<F: FnOnce(&Video)>(&self, fnc: F) -> &Self { if let TdObject::Video(t) = self { fnc(t) }; self }
+  pub fn on_video_note<F: FnOnce(&VideoNote)>(&self, fnc: F) -> &Self { if let TdObject::VideoNote(t) = self { fnc(t) }; self }
+  pub fn on_voice_note<F: FnOnce(&VoiceNote)>(&self, fnc: F) -> &Self { if let TdObject::VoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_web_page<F: FnOnce(&WebPage)>(&self, fnc: F) -> &Self { if let TdObject::WebPage(t) = self { fnc(t) }; self }
+  pub fn on_web_page_instant_view<F: FnOnce(&WebPageInstantView)>(&self, fnc: F) -> &Self { if let TdObject::WebPageInstantView(t) = self { fnc(t) }; self }
+
+  pub fn as_account_ttl(&self) -> Option<&AccountTtl> { if let TdObject::AccountTtl(t) = self { return Some(t) } None }
+  pub fn as_address(&self) -> Option<&Address> { if let TdObject::Address(t) = self { return Some(t) } None }
+  pub fn as_animated_chat_photo(&self) -> Option<&AnimatedChatPhoto> { if let TdObject::AnimatedChatPhoto(t) = self { return Some(t) } None }
+  pub fn as_animation(&self) -> Option<&Animation> { if let TdObject::Animation(t) = self { return Some(t) } None }
+  pub fn as_animations(&self) -> Option<&Animations> { if let TdObject::Animations(t) = self { return Some(t) } None }
+  pub fn as_audio(&self) -> Option<&Audio> { if let TdObject::Audio(t) = self { return Some(t) } None }
+  pub fn as_authentication_code_info(&self) -> Option<&AuthenticationCodeInfo> { if let TdObject::AuthenticationCodeInfo(t) = self { return Some(t) } None }
+  pub fn as_authentication_code_type(&self) -> Option<&AuthenticationCodeType> { if let TdObject::AuthenticationCodeType(t) = self { return Some(t) } None }
+  pub fn as_authorization_state(&self) -> Option<&AuthorizationState> { if let TdObject::AuthorizationState(t) = self { return Some(t) } None }
+  pub fn as_auto_download_settings(&self) -> Option<&AutoDownloadSettings> { if let TdObject::AutoDownloadSettings(t) = self { return Some(t) } None }
+  pub fn as_auto_download_settings_presets(&self) -> Option<&AutoDownloadSettingsPresets> { if let TdObject::AutoDownloadSettingsPresets(t) = self { return Some(t) } None }
+  pub fn as_background(&self) -> Option<&Background> { if let TdObject::Background(t) = self { return Some(t) } None }
+  pub fn as_background_fill(&self) -> Option<&BackgroundFill> { if let TdObject::BackgroundFill(t) = self { return Some(t) } None }
+  pub fn as_background_type(&self) -> Option<&BackgroundType> { if let TdObject::BackgroundType(t) = self { return Some(t) } None }
+  pub fn as_backgrounds(&self) -> Option<&Backgrounds> { if let TdObject::Backgrounds(t) = self { return Some(t) } None }
+  pub fn as_bank_card_action_open_url(&self) -> Option<&BankCardActionOpenUrl> { if let TdObject::BankCardActionOpenUrl(t) = self { return Some(t) } None }
+  pub fn as_bank_card_info(&self) -> Option<&BankCardInfo> { if let TdObject::BankCardInfo(t) = self { return Some(t) } None }
+  pub fn as_basic_group(&self) -> Option<&BasicGroup> { if let TdObject::BasicGroup(t) = self { return Some(t) } None }
+  pub fn as_basic_group_full_info(&self) -> Option<&BasicGroupFullInfo> { if let TdObject::BasicGroupFullInfo(t) = self { return Some(t) } None }
+  pub fn as_bot_command(&self) -> Option<&BotCommand> { if let TdObject::BotCommand(t) = self { return Some(t) } None }
+  pub fn as_bot_info(&self) -> Option<&BotInfo> { if let TdObject::BotInfo(t) = self { return Some(t) } None }
+  pub fn as_call(&self) -> Option<&Call> { if let TdObject::Call(t) = self { return Some(t) } None }
+  pub fn as_call_discard_reason(&self) -> Option<&CallDiscardReason> { if let TdObject::CallDiscardReason(t) = self { return Some(t) } None }
+  pub fn as_call_id(&self) -> Option<&CallId> { if let TdObject::CallId(t) = self { return Some(t) } None }
+  pub fn as_call_problem(&self) -> Option<&CallProblem> { if let TdObject::CallProblem(t) = self { return Some(t) } None }
+  pub fn as_call_protocol(&self) -> Option<&CallProtocol> { if let TdObject::CallProtocol(t) = self { return Some(t) } None }
+  pub fn as_call_server(&self) -> Option<&CallServer> { if let TdObject::CallServer(t) = self { return Some(t) } None }
+  pub fn as_call_server_type(&self) -> Option<&CallServerType> { if let TdObject::CallServerType(t) = self { return Some(t) } None }
+  pub fn as_call_state(&self) -> Option<&CallState> { if let TdObject::CallState(t) = self { return Some(t) } None }
+  pub fn as_callback_query_answer(&self) -> Option<&CallbackQueryAnswer> { if let TdObject::CallbackQueryAnswer(t) = self { return Some(t) } None }
+  pub fn as_callback_query_payload(&self) -> Option<&CallbackQueryPayload> { if let TdObject::CallbackQueryPayload(t) = self { return Some(t) } None }
+  pub fn as_can_transfer_ownership_result(&self) -> Option<&CanTransferOwnershipResult> { if let TdObject::CanTransferOwnershipResult(t) = self { return Some(t) } None }
+  pub fn as_chat(&self) -> Option<&Chat> { if let TdObject::Chat(t) = self { return Some(t) } None }
+  pub fn as_chat_action(&self) -> Option<&ChatAction> { if let TdObject::ChatAction(t) = self { return Some(t) } None }
+  pub fn as_chat_action_bar(&self) -> Option<&ChatActionBar> { if let TdObject::ChatActionBar(t) = self { return Some(t) } None }
+  pub fn as_chat_administrator(&self) -> Option<&ChatAdministrator> { if let TdObject::ChatAdministrator(t) = self { return Some(t) } None }
+  pub fn as_chat_administrators(&self) -> Option<&ChatAdministrators> { if let TdObject::ChatAdministrators(t) = self { return Some(t) } None }
+  pub fn as_chat_event(&self) -> Option<&ChatEvent> { if let TdObject::ChatEvent(t) = self { return Some(t) } None }
+  pub fn as_chat_event_action(&self) -> Option<&ChatEventAction> { if let TdObject::ChatEventAction(t) = self { return Some(t) } None }
+  pub fn as_chat_event_log_filters(&self) -> Option<&ChatEventLogFilters> { if let TdObject::ChatEventLogFilters(t) = self { return Some(t) } None }
+  pub fn as_chat_events(&self) -> Option<&ChatEvents> { if let TdObject::ChatEvents(t) = self { return Some(t) } None }
+  pub fn as_chat_filter(&self) -> Option<&ChatFilter> { if let TdObject::ChatFilter(t) = self { return Some(t) } None }
+  pub fn as_chat_filter_info(&self) -> Option<&ChatFilterInfo> { if let TdObject::ChatFilterInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_invite_link(&self) -> Option<&ChatInviteLink> { if let TdObject::ChatInviteLink(t) = self { return Some(t) } None }
+  pub fn as_chat_invite_link_info(&self) -> Option<&ChatInviteLinkInfo> { if let TdObject::ChatInviteLinkInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_list(&self) -> Option<&ChatList> { if let TdObject::ChatList(t) = self { return Some(t) } None }
+  pub fn as_chat_lists(&self) -> Option<&ChatLists> { if let TdObject::ChatLists(t) = self { return Some(t) } None }
+  pub fn as_chat_location(&self) -> Option<&ChatLocation> { if let TdObject::ChatLocation(t) = self { return Some(t) } None }
+  pub fn as_chat_member(&self) -> Option<&ChatMember> { if let TdObject::ChatMember(t) = self { return Some(t) } None }
+  pub fn as_chat_member_status(&self) -> Option<&ChatMemberStatus> { if let TdObject::ChatMemberStatus(t) = self { return Some(t) } None }
+  pub fn as_chat_members(&self) -> Option<&ChatMembers> { if let TdObject::ChatMembers(t) = self { return Some(t) } None }
+  pub fn as_chat_members_filter(&self) -> Option<&ChatMembersFilter> { if let TdObject::ChatMembersFilter(t) = self { return Some(t) } None }
+  pub fn as_chat_nearby(&self) -> Option<&ChatNearby> { if let TdObject::ChatNearby(t) = self { return Some(t) } None }
+  pub fn as_chat_notification_settings(&self) -> Option<&ChatNotificationSettings> { if let TdObject::ChatNotificationSettings(t) = self { return Some(t) } None }
+  pub fn as_chat_permissions(&self) -> Option<&ChatPermissions> { if let TdObject::ChatPermissions(t) = self { return Some(t) } None }
+  pub fn as_chat_photo(&self) -> Option<&ChatPhoto> { if let TdObject::ChatPhoto(t) = self { return Some(t) } None }
+  pub fn as_chat_photo_info(&self) -> Option<&ChatPhotoInfo> { if let TdObject::ChatPhotoInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_photos(&self) -> Option<&ChatPhotos> { if let TdObject::ChatPhotos(t) = self { return Some(t) } None }
+  pub fn as_chat_position(&self) -> Option<&ChatPosition> { if let TdObject::ChatPosition(t) = self { return Some(t) } None }
+  pub fn as_chat_report_reason(&self) -> Option<&ChatReportReason> { if let TdObject::ChatReportReason(t) = self { return Some(t) } None }
+  pub fn as_chat_source(&self) -> Option<&ChatSource> { if let TdObject::ChatSource(t) = self { return Some(t) } None }
+  pub fn as_chat_statistics(&self) -> Option<&ChatStatistics> { if let TdObject::ChatStatistics(t) = self { return Some(t) } None }
+  pub fn as_chat_statistics_administrator_actions_info(&self) -> Option<&ChatStatisticsAdministratorActionsInfo> { if let TdObject::ChatStatisticsAdministratorActionsInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_statistics_inviter_info(&self) -> Option<&ChatStatisticsInviterInfo> { if let TdObject::ChatStatisticsInviterInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_statistics_message_interaction_info(&self) -> Option<&ChatStatisticsMessageInteractionInfo> { if let TdObject::ChatStatisticsMessageInteractionInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_statistics_message_sender_info(&self) -> Option<&ChatStatisticsMessageSenderInfo> { if let TdObject::ChatStatisticsMessageSenderInfo(t) = self { return Some(t) } None }
+  pub fn as_chat_type(&self) -> Option<&ChatType> { if let TdObject::ChatType(t) = self { return Some(t) } None }
+  pub fn as_chats(&self) -> Option<&Chats> { if let TdObject::Chats(t) = self { return Some(t) } None }
+  pub fn as_chats_nearby(&self) -> Option<&ChatsNearby> { if let TdObject::ChatsNearby(t) = self { return Some(t) } None }
+  pub fn as_check_chat_username_result(&self) -> Option<&CheckChatUsernameResult> { if let TdObject::CheckChatUsernameResult(t) = self { return Some(t) } None }
+  pub fn as_connected_website(&self) -> Option<&ConnectedWebsite> { if let TdObject::ConnectedWebsite(t) = self { return Some(t) } None }
+  pub fn as_connected_websites(&self) -> Option<&ConnectedWebsites> { if let TdObject::ConnectedWebsites(t) = self { return Some(t) } None }
+  pub fn as_connection_state(&self) -> Option<&ConnectionState> { if let TdObject::ConnectionState(t) = self { return Some(t) } None }
+  pub fn as_contact(&self) -> Option<&Contact> { if let TdObject::Contact(t) = self { return Some(t) } None }
+  pub fn as_count(&self) -> Option<&Count> { if let TdObject::Count(t) = self { return Some(t) } None }
+  pub fn as_custom_request_result(&self) -> Option<&CustomRequestResult> { if let TdObject::CustomRequestResult(t) = self { return Some(t) } None }
+  pub fn as_database_statistics(&self) -> Option<&DatabaseStatistics> { if let TdObject::DatabaseStatistics(t) = self { return Some(t) } None }
+  pub fn as_date(&self) -> Option<&Date> { if let TdObject::Date(t) = self { return Some(t) } None }
+  pub fn as_date_range(&self) -> Option<&DateRange> { if let TdObject::DateRange(t) = self { return Some(t) } None }
+  pub fn as_dated_file(&self) -> Option<&DatedFile> { if let TdObject::DatedFile(t) = self { return Some(t) } None }
+  pub fn as_deep_link_info(&self) -> Option<&DeepLinkInfo> { if let TdObject::DeepLinkInfo(t) = self { return Some(t) } None }
+  pub fn as_device_token(&self) -> Option<&DeviceToken> { if let TdObject::DeviceToken(t) = self { return Some(t) } None }
+  pub fn as_document(&self) -> Option<&Document> { if let TdObject::Document(t) = self { return Some(t) } None }
+  pub fn as_draft_message(&self) -> Option<&DraftMessage> { if let TdObject::DraftMessage(t) = self { return Some(t) } None }
+  pub fn as_email_address_authentication_code_info(&self) -> Option<&EmailAddressAuthenticationCodeInfo> { if let TdObject::EmailAddressAuthenticationCodeInfo(t) = self { return Some(t) } None }
+  pub fn as_emojis(&self) -> Option<&Emojis> { if let TdObject::Emojis(t) = self { return Some(t) } None }
+  pub fn as_encrypted_credentials(&self) -> Option<&EncryptedCredentials> { if let TdObject::EncryptedCredentials(t) = self { return Some(t) } None }
+  pub fn as_encrypted_passport_element(&self) -> Option<&EncryptedPassportElement> { if let TdObject::EncryptedPassportElement(t) = self { return Some(t) } None }
+  pub fn as_error(&self) -> Option<&Error> { if let TdObject::Error(t) = self { return Some(t) } None }
+  pub fn as_file(&self) -> Option<&File> { if let TdObject::File(t) = self { return Some(t) } None }
+  pub fn as_file_part(&self) -> Option<&FilePart> { if let TdObject::FilePart(t) = self { return Some(t) } None }
+  pub fn as_file_type(&self) -> Option<&FileType> { if let TdObject::FileType(t) = self { return Some(t) } None }
+  pub fn as_formatted_text(&self) -> Option<&FormattedText> { if let TdObject::FormattedText(t) = self { return Some(t) } None }
+  pub fn as_found_messages(&self) -> Option<&FoundMessages> { if let TdObject::FoundMessages(t) = self { return Some(t) } None }
+  pub fn as_game(&self) -> Option<&Game> { if let TdObject::Game(t) = self { return Some(t) } None }
+  pub fn as_game_high_score(&self) -> Option<&GameHighScore> { if let TdObject::GameHighScore(t) = self { return Some(t) } None }
+  pub fn as_game_high_scores(&self) -> Option<&GameHighScores> { if let TdObject::GameHighScores(t) = self { return Some(t) } None }
+  pub fn as_hashtags(&self) -> Option<&Hashtags> { if let TdObject::Hashtags(t) = self { return Some(t) } None }
+  pub fn as_http_url(&self) -> Option<&HttpUrl> { if let TdObject::HttpUrl(t) = self { return Some(t) } None }
+  pub fn as_identity_document(&self) -> Option<&IdentityDocument> { if let TdObject::IdentityDocument(t) = self { return Some(t) } None }
+  pub fn as_imported_contacts(&self) -> Option<&ImportedContacts> { if let TdObject::ImportedContacts(t) = self { return Some(t) } None }
+  pub fn as_inline_keyboard_button(&self) -> Option<&InlineKeyboardButton> { if let TdObject::InlineKeyboardButton(t) = self { return Some(t) } None }
+  pub fn as_inline_keyboard_button_type(&self) -> Option<&InlineKeyboardButtonType> { if let TdObject::InlineKeyboardButtonType(t) = self { return Some(t) } None }
+  pub fn as_inline_query_result(&self) -> Option<&InlineQueryResult> { if let TdObject::InlineQueryResult(t) = self { return Some(t) } None }
+  pub fn as_inline_query_results(&self) -> Option<&InlineQueryResults> { if let TdObject::InlineQueryResults(t) = self { return Some(t) } None }
+  pub fn as_input_background(&self) -> Option<&InputBackground> { if let TdObject::InputBackground(t) = self { return Some(t) } None }
+  pub fn as_input_chat_photo(&self) -> Option<&InputChatPhoto> { if let TdObject::InputChatPhoto(t) = self { return Some(t) } None }
+  pub fn as_input_credentials(&self) -> Option<&InputCredentials> { if let TdObject::InputCredentials(t) = self { return Some(t) } None }
+  pub fn as_input_file(&self) -> Option<&InputFile> { if let TdObject::InputFile(t) = self { return Some(t) } None }
+  pub fn as_input_identity_document(&self) -> Option<&InputIdentityDocument> { if let TdObject::InputIdentityDocument(t) = self { return Some(t) } None }
+  pub fn as_input_inline_query_result(&self) -> Option<&InputInlineQueryResult> { if let TdObject::InputInlineQueryResult(t) = self { return Some(t) } None }
+  pub fn as_input_message_content(&self) -> Option<&InputMessageContent> { if let TdObject::InputMessageContent(t) = self { return Some(t) } None }
+  pub fn as_input_passport_element(&self) -> Option<&InputPassportElement> { if let TdObject::InputPassportElement(t) = self { return Some(t) } None }
+  pub fn as_input_passport_element_error(&self) -> Option<&InputPassportElementError> { if let TdObject::InputPassportElementError(t) = self { return Some(t) } None }
+  pub fn as_input_passport_element_error_source(&self) -> Option<&InputPassportElementErrorSource> { if let TdObject::InputPassportElementErrorSource(t) = self { return Some(t) } None }
+  pub fn as_input_personal_document(&self) -> Option<&InputPersonalDocument> { if let TdObject::InputPersonalDocument(t) = self { return Some(t) } None }
+  pub fn as_input_sticker(&self) -> Option<&InputSticker> { if let TdObject::InputSticker(t) = self { return Some(t) } None }
+  pub fn as_input_thumbnail(&self) -> Option<&InputThumbnail> { if let TdObject::InputThumbnail(t) = self { return Some(t) } None }
+  pub fn as_invoice(&self) -> Option<&Invoice> { if let TdObject::Invoice(t) = self { return Some(t) } None }
+  pub fn as_json_object_member(&self) -> Option<&JsonObjectMember> { if let TdObject::JsonObjectMember(t) = self { return Some(t) } None }
+  pub fn as_json_value(&self) -> Option<&JsonValue> { if let TdObject::JsonValue(t) = self { return Some(t) } None }
+  pub fn as_keyboard_button(&self) -> Option<&KeyboardButton> { if let TdObject::KeyboardButton(t) = self { return Some(t) } None }
+  pub fn as_keyboard_button_type(&self) -> Option<&KeyboardButtonType> { if let TdObject::KeyboardButtonType(t) = self { return Some(t) } None }
+  pub fn as_labeled_price_part(&self) -> Option<&LabeledPricePart> { if let TdObject::LabeledPricePart(t) = self { return Some(t) } None }
+  pub fn as_language_pack_info(&self) -> Option<&LanguagePackInfo> { if let TdObject::LanguagePackInfo(t) = self { return Some(t) } None }
+  pub fn as_language_pack_string(&self) -> Option<&LanguagePackString> { if let TdObject::LanguagePackString(t) = self { return Some(t) } None }
+  pub fn as_language_pack_string_value(&self) -> Option<&LanguagePackStringValue> { if let TdObject::LanguagePackStringValue(t) = self { return Some(t) } None }
+  pub fn as_language_pack_strings(&self) -> Option<&LanguagePackStrings> { if let TdObject::LanguagePackStrings(t) = self { return Some(t) } None }
+  pub fn as_local_file(&self) -> Option<&LocalFile> { if let TdObject::LocalFile(t) = self { return Some(t) } None }
+  pub fn as_localization_target_info(&self) -> Option<&LocalizationTargetInfo> { if let TdObject::LocalizationTargetInfo(t) = self { return Some(t) } None }
+  pub fn as_location(&self) -> Option<&Location> { if let TdObject::Location(t) = self { return Some(t) } None }
+  pub fn as_log_stream(&self) -> Option<&LogStream> { if let TdObject::LogStream(t) = self { return Some(t) } None }
+  pub fn as_log_tags(&self) -> Option<&LogTags> { if let TdObject::LogTags(t) = self { return Some(t) } None }
+  pub fn as_log_verbosity_level(&self) -> Option<&LogVerbosityLevel> { if let TdObject::LogVerbosityLevel(t) = self { return Some(t) } None }
+  pub fn as_login_url_info(&self) -> Option<&LoginUrlInfo> { if let TdObject::LoginUrlInfo(t) = self { return Some(t) } None }
+  pub fn as_mask_point(&self) -> Option<&MaskPoint> { if let TdObject::MaskPoint(t) = self { return Some(t) } None }
+  pub fn as_mask_position(&self) -> Option<&MaskPosition> { if let TdObject::MaskPosition(t) = self { return Some(t) } None }
+  pub fn as_message(&self) -> Option<&Message> { if let TdObject::Message(t) = self { return Some(t) } None }
+  pub fn as_message_content(&self) -> Option<&MessageContent> { if let TdObject::MessageContent(t) = self { return Some(t) } None }
+  pub fn as_message_copy_options(&self) -> Option<&MessageCopyOptions> { if let TdObject::MessageCopyOptions(t) = self { return Some(t) } None }
+  pub fn as_message_forward_info(&self) -> Option<&MessageForwardInfo> { if let TdObject::MessageForwardInfo(t) = self { return Some(t) } None }
+  pub fn as_message_forward_origin(&self) -> Option<&MessageForwardOrigin> { if let TdObject::MessageForwardOrigin(t) = self { return Some(t) } None }
+  pub fn as_message_link_info(&self) -> Option<&MessageLinkInfo> { if let TdObject::MessageLinkInfo(t) = self { return Some(t) } None }
+  pub fn as_message_scheduling_state(&self) -> Option<&MessageSchedulingState> { if let TdObject::MessageSchedulingState(t) = self { return Some(t) } None }
+  pub fn as_message_send_options(&self) -> Option<&MessageSendOptions> { if let TdObject::MessageSendOptions(t) = self { return Some(t) } None }
+  pub fn as_message_sending_state(&self) -> Option<&MessageSendingState> { if let TdObject::MessageSendingState(t) = self { return Some(t) } None }
+  pub fn as_messages(&self) -> Option<&Messages> { if let TdObject::Messages(t) = self { return Some(t) } None }
+  pub fn as_minithumbnail(&self) -> Option<&Minithumbnail> { if let TdObject::Minithumbnail(t) = self { return Some(t) } None }
+  pub fn as_network_statistics(&self) -> Option<&NetworkStatistics> { if let TdObject::NetworkStatistics(t) = self { return Some(t) } None }
+  pub fn as_network_statistics_entry(&self) -> Option<&NetworkStatisticsEntry> { if let TdObject::NetworkStatisticsEntry(t) = self { return Some(t) } None }
+  pub fn as_network_type(&self) -> Option<&NetworkType> { if let TdObject::NetworkType(t) = self { return Some(t) } None }
+  pub fn as_notification(&self) -> Option<&Notification> { if let TdObject::Notification(t) = self { return Some(t) } None }
+  pub fn as_notification_group(&self) -> Option<&NotificationGroup> { if let TdObject::NotificationGroup(t) = self { return Some(t) } None }
+  pub fn as_notification_group_type(&self) -> Option<&NotificationGroupType> { if let TdObject::NotificationGroupType(t) = self { return Some(t) } None }
+  pub fn as_notification_settings_scope(&self) -> Option<&NotificationSettingsScope> { if let TdObject::NotificationSettingsScope(t) = self { return Some(t) } None }
+  pub fn as_notification_type(&self) -> Option<&NotificationType> { if let TdObject::NotificationType(t) = self { return Some(t) } None }
+  pub fn as_ok(&self) -> Option<&Ok> { if let TdObject::Ok(t) = self { return Some(t) } None }
+  pub fn as_option_value(&self) -> Option<&OptionValue> { if let TdObject::OptionValue(t) = self { return Some(t) } None }
+  pub fn as_order_info(&self) -> Option<&OrderInfo> { if let TdObject::OrderInfo(t) = self { return Some(t) } None }
+  pub fn as_page_block(&self) -> Option<&PageBlock> { if let TdObject::PageBlock(t) = self { return Some(t) } None }
+  pub fn as_page_block_caption(&self) -> Option<&PageBlockCaption> { if let TdObject::PageBlockCaption(t) = self { return Some(t) } None }
+  pub fn as_page_block_horizontal_alignment(&self) -> Option<&PageBlockHorizontalAlignment> { if let TdObject::PageBlockHorizontalAlignment(t) = self { return Some(t) } None }
+  pub fn as_page_block_list_item(&self) -> Option<&PageBlockListItem> { if let TdObject::PageBlockListItem(t) = self { return Some(t) } None }
+  pub fn as_page_block_related_article(&self) -> Option<&PageBlockRelatedArticle> { if let TdObject::PageBlockRelatedArticle(t) = self { return Some(t) } None }
+  pub fn as_page_block_table_cell(&self) -> Option<&PageBlockTableCell> { if let TdObject::PageBlockTableCell(t) = self { return Some(t) } None }
+  pub fn as_page_block_vertical_alignment(&self) -> Option<&PageBlockVerticalAlignment> { if let TdObject::PageBlockVerticalAlignment(t) = self { return Some(t) } None }
+  pub fn as_passport_authorization_form(&self) -> Option<&PassportAuthorizationForm> { if let TdObject::PassportAuthorizationForm(t) = self { return Some(t) } None }
+  pub fn as_passport_element(&self) -> Option<&PassportElement> { if let TdObject::PassportElement(t) = self { return Some(t) } None }
+  pub fn as_passport_element_error(&self) -> Option<&PassportElementError> { if let TdObject::PassportElementError(t) = self { return Some(t) } None }
+  pub fn as_passport_element_error_source(&self) -> Option<&PassportElementErrorSource> { if let TdObject::PassportElementErrorSource(t) = self { return Some(t) } None }
+  pub fn as_passport_element_type(&self) -> Option<&PassportElementType> { if let TdObject::PassportElementType(t) = self { return Some(t) } None }
+  pub fn as_passport_elements(&self) -> Option<&PassportElements> { if let TdObject::PassportElements(t) = self { return Some(t) } None }
+  pub fn as_passport_elements_with_errors(&self) -> Option<&PassportElementsWithErrors> { if let TdObject::PassportElementsWithErrors(t) = self { return Some(t) } None }
+  pub fn as_passport_required_element(&self) -> Option<&PassportRequiredElement> { if let TdObject::PassportRequiredElement(t) = self { return Some(t) } None }
+  pub fn as_passport_suitable_element(&self) -> Option<&PassportSuitableElement> { if let TdObject::PassportSuitableElement(t) = self { return Some(t) } None }
+  pub fn as_password_state(&self) -> Option<&PasswordState> { if let TdObject::PasswordState(t) = self { return Some(t) } None }
+  pub fn as_payment_form(&self) -> Option<&PaymentForm> { if let TdObject::PaymentForm(t) = self { return Some(t) } None }
+  pub fn as_payment_receipt(&self) -> Option<&PaymentReceipt> { if let TdObject::PaymentReceipt(t) = self { return Some(t) } None }
+  pub fn as_payment_result(&self) -> Option<&PaymentResult> { if let TdObject::PaymentResult(t) = self { return Some(t) } None }
+  pub fn as_payments_provider_stripe(&self) -> Option<&PaymentsProviderStripe> { if let TdObject::PaymentsProviderStripe(t) = self { return Some(t) } None }
+  pub fn as_personal_details(&self) -> Option<&PersonalDetails> { if let TdObject::PersonalDetails(t) = self { return Some(t) } None }
+  pub fn as_personal_document(&self) -> Option<&PersonalDocument> { if let TdObject::PersonalDocument(t) = self { return Some(t) } None }
+  pub fn as_phone_number_authentication_settings(&self) -> Option<&PhoneNumberAuthenticationSettings> { if let TdObject::PhoneNumberAuthenticationSettings(t) = self { return Some(t) } None }
+  pub fn as_photo(&self) -> Option<&Photo> { if let TdObject::Photo(t) = self { return Some(t) } None }
+  pub fn as_photo_size(&self) -> Option<&PhotoSize> { if let TdObject::PhotoSize(t) = self { return Some(t) } None }
+  pub fn as_poll(&self) -> Option<&Poll> { if let TdObject::Poll(t) = self { return Some(t) } None }
+  pub fn as_poll_option(&self) -> Option<&PollOption> { if let TdObject::PollOption(t) = self { return Some(t) } None }
+  pub fn as_poll_type(&self) -> Option<&PollType> { if let TdObject::PollType(t) = self { return Some(t) } None }
+  pub fn as_profile_photo(&self) -> Option<&ProfilePhoto> { if let TdObject::ProfilePhoto(t) = self { return Some(t) } None }
+  pub fn as_proxies(&self) -> Option<&Proxies> { if let TdObject::Proxies(t) = self { return Some(t) } None }
+  pub fn as_proxy(&self) -> Option<&Proxy> { if let TdObject::Proxy(t) = self { return Some(t) } None }
+  pub fn as_proxy_type(&self) -> Option<&ProxyType> { if let TdObject::ProxyType(t) = self { return Some(t) } None }
+  pub fn as_public_chat_type(&self) -> Option<&PublicChatType> { if let TdObject::PublicChatType(t) = self { return Some(t) } None }
+  pub fn as_public_message_link(&self) -> Option<&PublicMessageLink> { if let TdObject::PublicMessageLink(t) = self { return Some(t) } None }
+  pub fn as_push_message_content(&self) -> Option<&PushMessageContent> { if let TdObject::PushMessageContent(t) = self { return Some(t) } None }
+  pub fn as_push_receiver_id(&self) -> Option<&PushReceiverId> { if let TdObject::PushReceiverId(t) = self { return Some(t) } None }
+  pub fn as_recommended_chat_filter(&self) -> Option<&RecommendedChatFilter> { if let TdObject::RecommendedChatFilter(t) = self { return Some(t) } None }
+  pub fn as_recommended_chat_filters(&self) -> Option<&RecommendedChatFilters> { if let TdObject::RecommendedChatFilters(t) = self { return Some(t) } None }
+  pub fn as_recovery_email_address(&self) -> Option<&RecoveryEmailAddress> { if let TdObject::RecoveryEmailAddress(t) = self { return Some(t) } None }
+  pub fn as_remote_file(&self) -> Option<&RemoteFile> { if let TdObject::RemoteFile(t) = self { return Some(t) } None }
+  pub fn as_reply_markup(&self) -> Option<&ReplyMarkup> { if let TdObject::ReplyMarkup(t) = self { return Some(t) } None }
+  pub fn as_rich_text(&self) -> Option<&RichText> { if let TdObject::RichText(t) = self { return Some(t) } None }
+  pub fn as_saved_credentials(&self) -> Option<&SavedCredentials> { if let TdObject::SavedCredentials(t) = self { return Some(t) } None }
+  pub fn as_scope_notification_settings(&self) -> Option<&ScopeNotificationSettings> { if let TdObject::ScopeNotificationSettings(t) = self { return Some(t) } None }
+  pub fn as_search_messages_filter(&self) -> Option<&SearchMessagesFilter> { if let TdObject::SearchMessagesFilter(t) = self { return Some(t) } None }
+  pub fn as_seconds(&self) -> Option<&Seconds> { if let TdObject::Seconds(t) = self { return Some(t) } None }
+  pub fn as_secret_chat(&self) -> Option<&SecretChat> { if let TdObject::SecretChat(t) = self { return Some(t) } None }
+  pub fn as_secret_chat_state(&self) -> Option<&SecretChatState> { if let TdObject::SecretChatState(t) = self { return Some(t) } None }
+  pub fn as_session(&self) -> Option<&Session> { if let TdObject::Session(t) = self { return Some(t) } None }
+  pub fn as_sessions(&self) -> Option<&Sessions> { if let TdObject::Sessions(t) = self { return Some(t) } None }
+  pub fn as_shipping_option(&self) -> Option<&ShippingOption> { if let TdObject::ShippingOption(t) = self { return Some(t) } None }
+  pub fn as_statistics_graph(&self) -> Option<&StatisticsGraph> { if let TdObject::StatisticsGraph(t) = self { return Some(t) } None }
+  pub fn as_statistics_value(&self) -> Option<&StatisticsValue> { if let TdObject::StatisticsValue(t) = self { return Some(t) } None }
+  pub fn as_sticker(&self) -> Option<&Sticker> { if let TdObject::Sticker(t) = self { return Some(t) } None }
+  pub fn as_sticker_set(&self) -> Option<&StickerSet> { if let TdObject::StickerSet(t) = self { return Some(t) } None }
+  pub fn as_sticker_set_info(&self) -> Option<&StickerSetInfo> { if let TdObject::StickerSetInfo(t) = self { return Some(t) } None }
+  pub fn as_sticker_sets(&self) -> Option<&StickerSets> { if let TdObject::StickerSets(t) = self { return Some(t) } None }
+  pub fn as_stickers(&self) -> Option<&Stickers> { if let TdObject::Stickers(t) = self { return Some(t) } None }
+  pub fn as_storage_statistics(&self) -> Option<&StorageStatistics> { if let TdObject::StorageStatistics(t) = self { return Some(t) } None }
+  pub fn as_storage_statistics_by_chat(&self) -> Option<&StorageStatisticsByChat> { if let TdObject::StorageStatisticsByChat(t) = self { return Some(t) } None }
+  pub fn as_storage_statistics_by_file_type(&self) -> Option<&StorageStatisticsByFileType> { if let TdObject::StorageStatisticsByFileType(t) = self { return Some(t) } None }
+  pub fn as_storage_statistics_fast(&self) -> Option<&StorageStatisticsFast> { if let TdObject::StorageStatisticsFast(t) = self { return Some(t) } None }
+  pub fn as_suggested_action(&self) -> Option<&SuggestedAction> { if let TdObject::SuggestedAction(t) = self { return Some(t) } None }
+  pub fn as_supergroup(&self) -> Option<&Supergroup> { if let TdObject::Supergroup(t) = self { return Some(t) } None }
+  pub fn as_supergroup_full_info(&self) -> Option<&SupergroupFullInfo> { if let TdObject::SupergroupFullInfo(t) = self { return Some(t) } None }
+  pub fn as_supergroup_members_filter(&self) -> Option<&SupergroupMembersFilter> { if let TdObject::SupergroupMembersFilter(t) = self { return Some(t) } None }
+  pub fn as_t_me_url(&self) -> Option<&TMeUrl> { if let TdObject::TMeUrl(t) = self { return Some(t) } None }
+  pub fn as_t_me_url_type(&self) -> Option<&TMeUrlType> { if let TdObject::TMeUrlType(t) = self { return Some(t) } None }
+  pub fn as_t_me_urls(&self) -> Option<&TMeUrls> { if let TdObject::TMeUrls(t) = self { return Some(t) } None }
+  pub fn as_tdlib_parameters(&self) -> Option<&TdlibParameters> { if let TdObject::TdlibParameters(t) = self { return Some(t) } None }
+  pub fn as_temporary_password_state(&self) -> Option<&TemporaryPasswordState> { if let TdObject::TemporaryPasswordState(t) = self { return Some(t) } None }
+  pub fn as_terms_of_service(&self) -> Option<&TermsOfService> { if let TdObject::TermsOfService(t) = self { return Some(t) } None }
+  pub fn as_test_bytes(&self) -> Option<&TestBytes> { if let TdObject::TestBytes(t) = self { return Some(t) } None }
+  pub fn as_test_int(&self) -> Option<&TestInt> { if let TdObject::TestInt(t) = self { return Some(t) } None }
+  pub fn as_test_string(&self) -> Option<&TestString> { if let TdObject::TestString(t) = self { return Some(t) } None }
+  pub fn as_test_vector_int(&self) -> Option<&TestVectorInt> { if let TdObject::TestVectorInt(t) = self { return Some(t) } None }
+  pub fn as_test_vector_int_object(&self) -> Option<&TestVectorIntObject> { if let TdObject::TestVectorIntObject(t) = self { return Some(t) } None }
+  pub fn as_test_vector_string(&self) -> Option<&TestVectorString> { if let TdObject::TestVectorString(t) = self { return Some(t) } None }
+  pub fn as_test_vector_string_object(&self) -> Option<&TestVectorStringObject> { if let TdObject::TestVectorStringObject(t) = self { return Some(t) } None }
+  pub fn as_text(&self) -> Option<&Text> { if let TdObject::Text(t) = self { return Some(t) } None }
+  pub fn as_text_entities(&self) -> Option<&TextEntities> { if let TdObject::TextEntities(t) = self { return Some(t) } None }
+  pub fn as_text_entity(&self) -> Option<&TextEntity> { if let TdObject::TextEntity(t) = self { return Some(t) } None }
+  pub fn as_text_entity_type(&self) -> Option<&TextEntityType> { if let TdObject::TextEntityType(t) = self { return Some(t) } None }
+  pub fn as_text_parse_mode(&self) -> Option<&TextParseMode> { if let TdObject::TextParseMode(t) = self { return Some(t) } None }
+  pub fn as_thumbnail(&self) -> Option<&Thumbnail> { if let TdObject::Thumbnail(t) = self { return Some(t) } None }
+  pub fn as_thumbnail_format(&self) -> Option<&ThumbnailFormat> { if let TdObject::ThumbnailFormat(t) = self { return Some(t) } None }
+  pub fn as_top_chat_category(&self) -> Option<&TopChatCategory> { if let TdObject::TopChatCategory(t) = self { return Some(t) } None }
+  pub fn as_update(&self) -> Option<&Update> { if let TdObject::Update(t) = self { return Some(t) } None }
+  pub fn as_updates(&self) -> Option<&Updates> { if let TdObject::Updates(t) = self { return Some(t) } None }
+  pub fn as_user(&self) -> Option<&User> { if let TdObject::User(t) = self { return Some(t) } None }
+  pub fn as_user_full_info(&self) -> Option<&UserFullInfo> { if let TdObject::UserFullInfo(t) = self { return Some(t) } None }
+  pub fn as_user_privacy_setting(&self) -> Option<&UserPrivacySetting> { if let TdObject::UserPrivacySetting(t) = self { return Some(t) } None }
+  pub fn as_user_privacy_setting_rule(&self) -> Option<&UserPrivacySettingRule> { if let TdObject::UserPrivacySettingRule(t) = self { return Some(t) } None }
+  pub fn as_user_privacy_setting_rules(&self) -> Option<&UserPrivacySettingRules> { if let TdObject::UserPrivacySettingRules(t) = self { return Some(t) } None }
+  pub fn as_user_status(&self) -> Option<&UserStatus> { if let TdObject::UserStatus(t) = self { return Some(t) } None }
+  pub fn as_user_type(&self) -> Option<&UserType> { if let TdObject::UserType(t) = self { return Some(t) } None }
+  pub fn as_users(&self) -> Option<&Users> { if let TdObject::Users(t) = self { return Some(t) } None }
+  pub fn as_validated_order_info(&self) -> Option<&ValidatedOrderInfo> { if let TdObject::ValidatedOrderInfo(t) = self { return Some(t) } None }
+  pub fn as_venue(&self) -> Option<&Venue> { if let TdObject::Venue(t) = self { return Some(t) } None }
+  pub fn as_video(&self) -> Option<&Video> { if let TdObject::Video(t) = self { return Some(t) } None }
+  pub fn as_video_note(&self) -> Option<&VideoNote> { if let TdObject::VideoNote(t) = self { return Some(t) } None }
+  pub fn as_voice_note(&self) -> Option<&VoiceNote> { if let TdObject::VoiceNote(t) = self { return Some(t) } None }
+  pub fn as_web_page(&self) -> Option<&WebPage> { if let TdObject::WebPage(t) = self { return Some(t) } None }
+  pub fn as_web_page_instant_view(&self) -> Option<&WebPageInstantView> { if let TdObject::WebPageInstantView(t) = self { return Some(t) } None }
+
+
+
+  pub fn account_ttl<T: AsRef<AccountTtl>>(t: T) -> Self { TdObject::AccountTtl(t.as_ref().clone()) }
+
+  pub fn address<T: AsRef<Address>>(t: T) -> Self { TdObject::Address(t.as_ref().clone()) }
+
+  pub fn animated_chat_photo<T: AsRef<AnimatedChatPhoto>>(t: T) -> Self { TdObject::AnimatedChatPhoto(t.as_ref().clone()) }
+
+  pub fn animation<T: AsRef<Animation>>(t: T) -> Self { TdObject::Animation(t.as_ref().clone()) }
+
+  pub fn animations<T: AsRef<Animations>>(t: T) -> Self { TdObject::Animations(t.as_ref().clone()) }
+
+  pub fn audio<T: AsRef<Audio>>(t: T) -> Self { TdObject::Audio(t.as_ref().clone()) }
+
+  pub fn authentication_code_info<T: AsRef<AuthenticationCodeInfo>>(t: T) -> Self { TdObject::AuthenticationCodeInfo(t.as_ref().clone()) }
+
+  pub fn authentication_code_type<T: AsRef<AuthenticationCodeType>>(t: T) -> Self { TdObject::AuthenticationCodeType(t.as_ref().clone()) }
+
+  pub fn authorization_state<T: AsRef<AuthorizationState>>(t: T) -> Self { TdObject::AuthorizationState(t.as_ref().clone()) }
+
+  pub fn auto_download_settings<T: AsRef<AutoDownloadSettings>>(t: T) -> Self { TdObject::AutoDownloadSettings(t.as_ref().clone()) }
+
+  pub fn auto_download_settings_presets<T: AsRef<AutoDownloadSettingsPresets>>(t: T) -> Self { TdObject::AutoDownloadSettingsPresets(t.as_ref().clone()) }
+
+  pub fn background<T: AsRef<Background>>(t: T) -> Self { TdObject::Background(t.as_ref().clone()) }
+
+  pub fn background_fill<T: AsRef<BackgroundFill>>(t: T) -> Self { TdObject::BackgroundFill(t.as_ref().clone()) }
+
+  pub fn background_type<T: AsRef<BackgroundType>>(t: T) -> Self { TdObject::BackgroundType(t.as_ref().clone()) }
+
+  pub fn backgrounds<T: AsRef<Backgrounds>>(t: T) -> Self { TdObject::Backgrounds(t.as_ref().clone()) }
+
+  pub fn bank_card_action_open_url<T: AsRef<BankCardActionOpenUrl>>(t: T) -> Self { TdObject::BankCardActionOpenUrl(t.as_ref().clone()) }
+
+  pub fn bank_card_info<T: AsRef<BankCardInfo>>(t: T) -> Self { TdObject::BankCardInfo(t.as_ref().clone()) }
+
+  pub fn basic_group<T: AsRef<BasicGroup>>(t: T) -> Self { TdObject::BasicGroup(t.as_ref().clone()) }
+
+  pub fn basic_group_full_info<T: AsRef<BasicGroupFullInfo>>(t: T) -> Self { TdObject::BasicGroupFullInfo(t.as_ref().clone()) }
+
+  pub fn bot_command<T: AsRef<BotCommand>>(t: T) -> Self { TdObject::BotCommand(t.as_ref().clone()) }
+
+  pub fn bot_info<T: AsRef<BotInfo>>(t: T) -> Self { TdObject::BotInfo(t.as_ref().clone()) }
+
+  pub fn call<T: AsRef<Call>>(t: T) -> Self { TdObject::Call(t.as_ref().clone()) }
+
+  pub fn call_discard_reason<T: AsRef<CallDiscardReason>>(t: T) -> Self { TdObject::CallDiscardReason(t.as_ref().clone()) }
+
+  pub fn call_id<T: AsRef<CallId>>(t: T) -> Self { TdObject::CallId(t.as_ref().clone()) }
+
+  pub fn call_problem<T: AsRef<CallProblem>>(t: T) -> Self { TdObject::CallProblem(t.as_ref().clone()) }
+
+  pub fn call_protocol<T: AsRef<CallProtocol>>(t: T) -> Self { TdObject::CallProtocol(t.as_ref().clone()) }
+
+  pub fn call_server<T: AsRef<CallServer>>(t: T) -> Self { TdObject::CallServer(t.as_ref().clone()) }
+
+  pub fn call_server_type<T: AsRef<CallServerType>>(t: T) -> Self { TdObject::CallServerType(t.as_ref().clone()) }
+
+  pub fn call_state<T: AsRef<CallState>>(t: T) -> Self { TdObject::CallState(t.as_ref().clone()) }
+
+  pub fn callback_query_answer<T: AsRef<CallbackQueryAnswer>>(t: T) -> Self { TdObject::CallbackQueryAnswer(t.as_ref().clone()) }
+
+  pub fn callback_query_payload<T: AsRef<CallbackQueryPayload>>(t: T) -> Self { TdObject::CallbackQueryPayload(t.as_ref().clone()) }
+
+  pub fn can_transfer_ownership_result<T: AsRef<CanTransferOwnershipResult>>(t: T) -> Self { TdObject::CanTransferOwnershipResult(t.as_ref().clone()) }
+
+  pub fn chat<T: AsRef<Chat>>(t: T) -> Self { TdObject::Chat(t.as_ref().clone()) }
+
+  pub fn chat_action<T: AsRef<ChatAction>>(t: T) -> Self { TdObject::ChatAction(t.as_ref().clone()) }
+
+  pub fn chat_action_bar<T: AsRef<ChatActionBar>>(t: T) -> Self { TdObject::ChatActionBar(t.as_ref().clone()) }
+
+  pub fn chat_administrator<T: AsRef<ChatAdministrator>>(t: T) -> Self { TdObject::ChatAdministrator(t.as_ref().clone()) }
+
+  pub fn chat_administrators<T: AsRef<ChatAdministrators>>(t: T) -> Self { TdObject::ChatAdministrators(t.as_ref().clone()) }
+
+  pub fn chat_event<T: AsRef<ChatEvent>>(t: T) -> Self { TdObject::ChatEvent(t.as_ref().clone()) }
+
+  pub fn chat_event_action<T: AsRef<ChatEventAction>>(t: T) -> Self { TdObject::ChatEventAction(t.as_ref().clone()) }
+
+  pub fn chat_event_log_filters<T: AsRef<ChatEventLogFilters>>(t: T) -> Self { TdObject::ChatEventLogFilters(t.as_ref().clone()) }
+
+  pub fn chat_events<T: AsRef<ChatEvents>>(t: T) -> Self { TdObject::ChatEvents(t.as_ref().clone()) }
+
+  pub fn chat_filter<T: AsRef<ChatFilter>>(t: T) -> Self { TdObject::ChatFilter(t.as_ref().clone()) }
+
+  pub fn chat_filter_info<T: AsRef<ChatFilterInfo>>(t: T) -> Self { TdObject::ChatFilterInfo(t.as_ref().clone()) }
+
+  pub fn chat_invite_link<T: AsRef<ChatInviteLink>>(t: T) -> Self { TdObject::ChatInviteLink(t.as_ref().clone()) }
+
+  pub fn chat_invite_link_info<T: AsRef<ChatInviteLinkInfo>>(t: T) -> Self { TdObject::ChatInviteLinkInfo(t.as_ref().clone()) }
+
+  pub fn chat_list<T: AsRef<ChatList>>(t: T) -> Self { TdObject::ChatList(t.as_ref().clone()) }
+
+  pub fn chat_lists<T: AsRef<ChatLists>>(t: T) -> Self { TdObject::ChatLists(t.as_ref().clone()) }
+
+  pub fn chat_location<T: AsRef<ChatLocation>>(t: T) -> Self { TdObject::ChatLocation(t.as_ref().clone()) }
+
+  pub fn chat_member<T: AsRef<ChatMember>>(t: T) -> Self { TdObject::ChatMember(t.as_ref().clone()) }
+
+  pub fn chat_member_status<T: AsRef<ChatMemberStatus>>(t: T) -> Self { TdObject::ChatMemberStatus(t.as_ref().clone()) }
+
+  pub fn chat_members<T: AsRef<ChatMembers>>(t: T) -> Self { TdObject::ChatMembers(t.as_ref().clone()) }
+
+  pub fn chat_members_filter<T: AsRef<ChatMembersFilter>>(t: T) -> Self { TdObject::ChatMembersFilter(t.as_ref().clone()) }
+
+  pub fn chat_nearby<T: AsRef<ChatNearby>>(t: T) -> Self { TdObject::ChatNearby(t.as_ref().clone()) }
+
+  pub fn chat_notification_settings<T: AsRef<ChatNotificationSettings>>(t: T) -> Self { TdObject::ChatNotificationSettings(t.as_ref().clone()) }
+
+  pub fn chat_permissions<T: AsRef<ChatPermissions>>(t: T) -> Self { TdObject::ChatPermissions(t.as_ref().clone()) }
+
+  pub fn chat_photo<T: AsRef<ChatPhoto>>(t: T) -> Self { TdObject::ChatPhoto(t.as_ref().clone()) }
+
+  pub fn chat_photo_info<T: AsRef<ChatPhotoInfo>>(t: T) -> Self { TdObject::ChatPhotoInfo(t.as_ref().clone()) }
+
+  pub fn chat_photos<T: AsRef<ChatPhotos>>(t: T) -> Self { TdObject::ChatPhotos(t.as_ref().clone()) }
+
+  pub fn chat_position<T: AsRef<ChatPosition>>(t: T) -> Self { TdObject::ChatPosition(t.as_ref().clone()) }
+
+  pub fn chat_report_reason<T: AsRef<ChatReportReason>>(t: T) -> Self { TdObject::ChatReportReason(t.as_ref().clone()) }
+
+  pub fn chat_source<T: AsRef<ChatSource>>(t: T) -> Self { TdObject::ChatSource(t.as_ref().clone()) }
+
+  pub fn chat_statistics<T: AsRef<ChatStatistics>>(t: T) -> Self { TdObject::ChatStatistics(t.as_ref().clone()) }
+
+  pub fn chat_statistics_administrator_actions_info<T: AsRef<ChatStatisticsAdministratorActionsInfo>>(t: T) -> Self { TdObject::ChatStatisticsAdministratorActionsInfo(t.as_ref().clone()) }
+
+  pub fn chat_statistics_inviter_info<T: AsRef<ChatStatisticsInviterInfo>>(t: T) -> Self { TdObject::ChatStatisticsInviterInfo(t.as_ref().clone()) }
+
+  pub fn chat_statistics_message_interaction_info<T: AsRef<ChatStatisticsMessageInteractionInfo>>(t: T) -> Self { TdObject::ChatStatisticsMessageInteractionInfo(t.as_ref().clone()) }
+
+  pub fn chat_statistics_message_sender_info<T: AsRef<ChatStatisticsMessageSenderInfo>>(t: T) -> Self { TdObject::ChatStatisticsMessageSenderInfo(t.as_ref().clone()) }
+
+  pub fn chat_type<T: AsRef<ChatType>>(t: T) -> Self { TdObject::ChatType(t.as_ref().clone()) }
+
+  pub fn chats<T: AsRef<Chats>>(t: T) -> Self { TdObject::Chats(t.as_ref().clone()) }
+
+  pub fn chats_nearby<T: AsRef<ChatsNearby>>(t: T) -> Self { TdObject::ChatsNearby(t.as_ref().clone()) }
+
+  pub fn check_chat_username_result<T: AsRef<CheckChatUsernameResult>>(t: T) -> Self { TdObject::CheckChatUsernameResult(t.as_ref().clone()) }
+
+  pub fn connected_website<T: AsRef<ConnectedWebsite>>(t: T) -> Self { TdObject::ConnectedWebsite(t.as_ref().clone()) }
+
+  pub fn connected_websites<T: AsRef<ConnectedWebsites>>(t: T) -> Self { TdObject::ConnectedWebsites(t.as_ref().clone()) }
+
+  pub fn connection_state<T: AsRef<ConnectionState>>(t: T) -> Self { TdObject::ConnectionState(t.as_ref().clone()) }
+
+  pub fn contact<T: AsRef<Contact>>(t: T) -> Self { TdObject::Contact(t.as_ref().clone()) }
+
+  pub fn count<T: AsRef<Count>>(t: T) -> Self { TdObject::Count(t.as_ref().clone()) }
+
+  pub fn custom_request_result<T: AsRef<CustomRequestResult>>(t: T) -> Self { TdObject::CustomRequestResult(t.as_ref().clone()) }
+
+  pub fn database_statistics<T: AsRef<DatabaseStatistics>>(t: T) -> Self { TdObject::DatabaseStatistics(t.as_ref().clone()) }
+
+  pub fn date<T: AsRef<Date>>(t: T) -> Self { TdObject::Date(t.as_ref().clone()) }
+
+  pub fn date_range<T: AsRef<DateRange>>(t: T) -> Self { TdObject::DateRange(t.as_ref().clone()) }
+
+  pub fn dated_file<T: AsRef<DatedFile>>(t: T) -> Self { TdObject::DatedFile(t.as_ref().clone()) }
+
+  pub fn deep_link_info<T: AsRef<DeepLinkInfo>>(t: T) -> Self { TdObject::DeepLinkInfo(t.as_ref().clone()) }
+
+  pub fn device_token<T: AsRef<DeviceToken>>(t: T) -> Self { TdObject::DeviceToken(t.as_ref().clone()) }
+
+  pub fn document<T: AsRef<Document>>(t: T) -> Self { TdObject::Document(t.as_ref().clone()) }
+
+  pub fn draft_message<T: AsRef<DraftMessage>>(t: T) -> Self { TdObject::DraftMessage(t.as_ref().clone()) }
+
+  pub fn email_address_authentication_code_info<T: AsRef<EmailAddressAuthenticationCodeInfo>>(t: T) -> Self { TdObject::EmailAddressAuthenticationCodeInfo(t.as_ref().clone()) }
+
+  pub fn emojis<T: AsRef<Emojis>>(t: T) -> Self { TdObject::Emojis(t.as_ref().clone()) }
+
+  pub fn encrypted_credentials<T: AsRef<EncryptedCredentials>>(t: T) -> Self { TdObject::EncryptedCredentials(t.as_ref().clone()) }
+
+  pub fn encrypted_passport_element<T: AsRef<EncryptedPassportElement>>(t: T) -> Self { TdObject::EncryptedPassportElement(t.as_ref().clone()) }
+
+  pub fn error<T: AsRef<Error>>(t: T) -> Self { TdObject::Error(t.as_ref().clone()) }
+
+  pub fn file<T: AsRef<File>>(t: T) -> Self { TdObject::File(t.as_ref().clone()) }
+
+  pub fn file_part<T: AsRef<FilePart>>(t: T) -> Self { TdObject::FilePart(t.as_ref().clone()) }
+
+  pub fn file_type<T: AsRef<FileType>>(t: T) -> Self { TdObject::FileType(t.as_ref().clone()) }
+
+  pub fn formatted_text<T: AsRef<FormattedText>>(t: T) -> Self { TdObject::FormattedText(t.as_ref().clone()) }
+
+  pub fn found_messages<T: AsRef<FoundMessages>>(t: T) -> Self { TdObject::FoundMessages(t.as_ref().clone()) }
+
+  pub fn game<T: AsRef<Game>>(t: T) -> Self { TdObject::Game(t.as_ref().clone()) }
+
+  pub fn game_high_score<T: AsRef<GameHighScore>>(t: T) -> Self { TdObject::GameHighScore(t.as_ref().clone()) }
+
+  pub fn game_high_scores<T: AsRef<GameHighScores>>(t: T) -> Self { TdObject::GameHighScores(t.as_ref().clone()) }
+
+  pub fn hashtags<T: AsRef<Hashtags>>(t: T) -> Self { TdObject::Hashtags(t.as_ref().clone()) }
+
+  pub fn http_url<T: AsRef<HttpUrl>>(t: T) -> Self { TdObject::HttpUrl(t.as_ref().clone()) }
+
+  pub fn identity_document<T: AsRef<IdentityDocument>>(t: T) -> Self { TdObject::IdentityDocument(t.as_ref().clone()) }
+
+  pub fn imported_contacts<T: AsRef<ImportedContacts>>(t: T) -> Self { TdObject::ImportedContacts(t.as_ref().clone()) }
+
+  pub fn inline_keyboard_button<T: AsRef<InlineKeyboardButton>>(t: T) -> Self { TdObject::InlineKeyboardButton(t.as_ref().clone()) }
+
+  pub fn inline_keyboard_button_type<T: AsRef<InlineKeyboardButtonType>>(t: T) -> Self { TdObject::InlineKeyboardButtonType(t.as_ref().clone()) }
+
+  pub fn inline_query_result<T: AsRef<InlineQueryResult>>(t: T) -> Self { TdObject::InlineQueryResult(t.as_ref().clone()) }
+
+  pub fn inline_query_results<T: AsRef<InlineQueryResults>>(t: T) -> Self { TdObject::InlineQueryResults(t.as_ref().clone()) }
+
+  pub fn input_background<T: AsRef<InputBackground>>(t: T) -> Self { TdObject::InputBackground(t.as_ref().clone()) }
+
+  pub fn input_chat_photo<T: AsRef<InputChatPhoto>>(t: T) -> Self { TdObject::InputChatPhoto(t.as_ref().clone()) }
+
+  pub fn input_credentials<T: AsRef<InputCredentials>>(t: T) -> Self { TdObject::InputCredentials(t.as_ref().clone()) }
+
+  pub fn input_file<T: AsRef<InputFile>>(t: T) -> Self { TdObject::InputFile(t.as_ref().clone()) }
+
+  pub fn input_identity_document<T: AsRef<InputIdentityDocument>>(t: T) -> Self { TdObject::InputIdentityDocument(t.as_ref().clone()) }
+
+  pub fn input_inline_query_result<T: AsRef<InputInlineQueryResult>>(t: T) -> Self { TdObject::InputInlineQueryResult(t.as_ref().clone()) }
+
+  pub fn input_message_content<T: AsRef<InputMessageContent>>(t: T) -> Self { TdObject::InputMessageContent(t.as_ref().clone()) }
+
+  pub fn input_passport_element<T: AsRef<InputPassportElement>>(t: T) -> Self { TdObject::InputPassportElement(t.as_ref().clone()) }
+
+  pub fn input_passport_element_error<T: AsRef<InputPassportElementError>>(t: T) -> Self { TdObject::InputPassportElementError(t.as_ref().clone()) }
+
+  pub fn input_passport_element_error_source<T: AsRef<InputPassportElementErrorSource>>(t: T) -> Self { TdObject::InputPassportElementErrorSource(t.as_ref().clone()) }
+
+  pub fn input_personal_document<T: AsRef<InputPersonalDocument>>(t: T) -> Self { TdObject::InputPersonalDocument(t.as_ref().clone()) }
+
+  pub fn input_sticker<T: AsRef<InputSticker>>(t: T) -> Self { TdObject::InputSticker(t.as_ref().clone()) }
+
+  pub fn input_thumbnail<T: AsRef<InputThumbnail>>(t: T) -> Self { TdObject::InputThumbnail(t.as_ref().clone()) }
+
+  pub fn invoice<T: AsRef<Invoice>>(t: T) -> Self { TdObject::Invoice(t.as_ref().clone()) }
+
+  pub fn json_object_member<T: AsRef<JsonObjectMember>>(t: T) -> Self { TdObject::JsonObjectMember(t.as_ref().clone()) }
+
+  pub fn json_value<T: AsRef<JsonValue>>(t: T) -> Self { TdObject::JsonValue(t.as_ref().clone()) }
+
+  pub fn keyboard_button<T: AsRef<KeyboardButton>>(t: T) -> Self { TdObject::KeyboardButton(t.as_ref().clone()) }
+
+  pub fn keyboard_button_type<T: AsRef<KeyboardButtonType>>(t: T) -> Self { TdObject::KeyboardButtonType(t.as_ref().clone()) }
+
+  pub fn labeled_price_part<T: AsRef<LabeledPricePart>>(t: T) -> Self { TdObject::LabeledPricePart(t.as_ref().clone()) }
+
+  pub fn language_pack_info<T: AsRef<LanguagePackInfo>>(t: T) -> Self { TdObject::LanguagePackInfo(t.as_ref().clone()) }
+
+  pub fn language_pack_string<T: AsRef<LanguagePackString>>(t: T) -> Self { TdObject::LanguagePackString(t.as_ref().clone()) }
+
+  pub fn language_pack_string_value<T: AsRef<LanguagePackStringValue>>(t: T) -> Self { TdObject::LanguagePackStringValue(t.as_ref().clone()) }
+
+  pub fn language_pack_strings<T: AsRef<LanguagePackStrings>>(t: T) -> Self { TdObject::LanguagePackStrings(t.as_ref().clone()) }
+
+  pub fn local_file<T: AsRef<LocalFile>>(t: T) -> Self { TdObject::LocalFile(t.as_ref().clone()) }
+
+  pub fn localization_target_info<T: AsRef<LocalizationTargetInfo>>(t: T) -> Self { TdObject::LocalizationTargetInfo(t.as_ref().clone()) }
+
+  pub fn location<T: AsRef<Location>>(t: T) -> Self { TdObject::Location(t.as_ref().clone()) }
+
+  pub fn log_stream<T: AsRef<LogStream>>(t: T) -> Self { TdObject::LogStream(t.as_ref().clone()) }
+
+  pub fn log_tags<T: AsRef<LogTags>>(t: T) -> Self { TdObject::LogTags(t.as_ref().clone()) }
+
+  pub fn log_verbosity_level<T: AsRef<LogVerbosityLevel>>(t: T) -> Self { TdObject::LogVerbosityLevel(t.as_ref().clone()) }
+
+  pub fn login_url_info<T: AsRef<LoginUrlInfo>>(t: T) -> Self { TdObject::LoginUrlInfo(t.as_ref().clone()) }
+
+  pub fn mask_point<T: AsRef<MaskPoint>>(t: T) -> Self { TdObject::MaskPoint(t.as_ref().clone()) }
+
+  pub fn mask_position<T: AsRef<MaskPosition>>(t: T) -> Self { TdObject::MaskPosition(t.as_ref().clone()) }
+
+  pub fn message<T: AsRef<Message>>(t: T) -> Self { TdObject::Message(t.as_ref().clone()) }
+
+  pub fn message_content<T: AsRef<MessageContent>>(t: T) -> Self { TdObject::MessageContent(t.as_ref().clone()) }
+
+  pub fn message_copy_options<T: AsRef<MessageCopyOptions>>(t: T) -> Self { TdObject::MessageCopyOptions(t.as_ref().clone()) }
+
+  pub fn message_forward_info<T: AsRef<MessageForwardInfo>>(t: T) -> Self { TdObject::MessageForwardInfo(t.as_ref().clone()) }
+
+  pub fn message_forward_origin<T: AsRef<MessageForwardOrigin>>(t: T) -> Self { TdObject::MessageForwardOrigin(t.as_ref().clone()) }
+
+  pub fn message_link_info<T: AsRef<MessageLinkInfo>>(t: T) -> Self { TdObject::MessageLinkInfo(t.as_ref().clone()) }
+
+  pub fn message_scheduling_state<T: AsRef<MessageSchedulingState>>(t: T) -> Self { TdObject::MessageSchedulingState(t.as_ref().clone()) }
+
+  pub fn message_send_options<T: AsRef<MessageSendOptions>>(t: T) -> Self { TdObject::MessageSendOptions(t.as_ref().clone()) }
+
+  pub fn message_sending_state<T: AsRef<MessageSendingState>>(t: T) -> Self { TdObject::MessageSendingState(t.as_ref().clone()) }
+
+  pub fn messages<T: AsRef<Messages>>(t: T) -> Self { TdObject::Messages(t.as_ref().clone()) }
+
+  pub fn minithumbnail<T: AsRef<Minithumbnail>>(t: T) -> Self { TdObject::Minithumbnail(t.as_ref().clone()) }
+
+  pub fn network_statistics<T: AsRef<NetworkStatistics>>(t: T) -> Self { TdObject::NetworkStatistics(t.as_ref().clone()) }
+
+  pub fn network_statistics_entry<T: AsRef<NetworkStatisticsEntry>>(t: T) -> Self { TdObject::NetworkStatisticsEntry(t.as_ref().clone()) }
+
+  pub fn network_type<T: AsRef<NetworkType>>(t: T) -> Self { TdObject::NetworkType(t.as_ref().clone()) }
+
+  pub fn notification<T: AsRef<Notification>>(t: T) -> Self { TdObject::Notification(t.as_ref().clone()) }
+
+  pub fn notification_group<T: AsRef<NotificationGroup>>(t: T) -> Self { TdObject::NotificationGroup(t.as_ref().clone()) }
+
+  pub fn notification_group_type<T: AsRef<NotificationGroupType>>(t: T) -> Self { TdObject::NotificationGroupType(t.as_ref().clone()) }
+
+  pub fn notification_settings_scope<T: AsRef<NotificationSettingsScope>>(t: T) -> Self { TdObject::NotificationSettingsScope(t.as_ref().clone()) }
+
+  pub fn notification_type<T: AsRef<NotificationType>>(t: T) -> Self { TdObject::NotificationType(t.as_ref().clone()) }
+
+  pub fn ok<T: AsRef<Ok>>(t: T) -> Self { TdObject::Ok(t.as_ref().clone()) }
+
+  pub fn option_value<T: AsRef<OptionValue>>(t: T) -> Self { TdObject::OptionValue(t.as_ref().clone()) }
+
+  pub fn order_info<T: AsRef<OrderInfo>>(t: T) -> Self { TdObject::OrderInfo(t.as_ref().clone()) }
+
+  pub fn page_block<T: AsRef<PageBlock>>(t: T) -> Self { TdObject::PageBlock(t.as_ref().clone()) }
+
+  pub fn page_block_caption<T: AsRef<PageBlockCaption>>(t: T) -> Self { TdObject::PageBlockCaption(t.as_ref().clone()) }
+
+  pub fn page_block_horizontal_alignment<T: AsRef<PageBlockHorizontalAlignment>>(t: T) -> Self { TdObject::PageBlockHorizontalAlignment(t.as_ref().clone()) }
+
+  pub fn page_block_list_item<T: AsRef<PageBlockListItem>>(t: T) -> Self { TdObject::PageBlockListItem(t.as_ref().clone()) }
+
+  pub fn page_block_related_article<T: AsRef<PageBlockRelatedArticle>>(t: T) -> Self { TdObject::PageBlockRelatedArticle(t.as_ref().clone()) }
+
+  pub fn page_block_table_cell<T: AsRef<PageBlockTableCell>>(t: T) -> Self { TdObject::PageBlockTableCell(t.as_ref().clone()) }
+
+  pub fn page_block_vertical_alignment<T: AsRef<PageBlockVerticalAlignment>>(t: T) -> Self { TdObject::PageBlockVerticalAlignment(t.as_ref().clone()) }
+
+  pub fn passport_authorization_form<T: AsRef<PassportAuthorizationForm>>(t: T) -> Self { TdObject::PassportAuthorizationForm(t.as_ref().clone()) }
+
+  pub fn passport_element<T: AsRef<PassportElement>>(t: T) -> Self { TdObject::PassportElement(t.as_ref().clone()) }
+
+  pub fn passport_element_error<T: AsRef<PassportElementError>>(t: T) -> Self { TdObject::PassportElementError(t.as_ref().clone()) }
+
+  pub fn passport_element_error_source<T: AsRef<PassportElementErrorSource>>(t: T) -> Self { TdObject::PassportElementErrorSource(t.as_ref().clone()) }
+
+  pub fn passport_element_type<T: AsRef<PassportElementType>>(t: T) -> Self { TdObject::PassportElementType(t.as_ref().clone()) }
+
+  pub fn passport_elements<T: AsRef<PassportElements>>(t: T) -> Self { TdObject::PassportElements(t.as_ref().clone()) }
+
+  pub fn passport_elements_with_errors<T: AsRef<PassportElementsWithErrors>>(t: T) -> Self { TdObject::PassportElementsWithErrors(t.as_ref().clone()) }
+
+  pub fn passport_required_element<T: AsRef<PassportRequiredElement>>(t: T) -> Self { TdObject::PassportRequiredElement(t.as_ref().clone()) }
+
+  pub fn passport_suitable_element<T: AsRef<PassportSuitableElement>>(t: T) -> Self { TdObject::PassportSuitableElement(t.as_ref().clone()) }
+
+  pub fn password_state<T: AsRef<PasswordState>>(t: T) -> Self { TdObject::PasswordState(t.as_ref().clone()) }
+
+  pub fn payment_form<T: AsRef<PaymentForm>>(t: T) -> Self { TdObject::PaymentForm(t.as_ref().clone()) }
+
+  pub fn payment_receipt<T: AsRef<PaymentReceipt>>(t: T) -> Self { TdObject::PaymentReceipt(t.as_ref().clone()) }
+
+  pub fn payment_result<T: AsRef<PaymentResult>>(t: T) -> Self { TdObject::PaymentResult(t.as_ref().clone()) }
+
+  pub fn payments_provider_stripe<T: AsRef<PaymentsProviderStripe>>(t: T) -> Self { TdObject::PaymentsProviderStripe(t.as_ref().clone()) }
+
+  pub fn personal_details<T: AsRef<PersonalDetails>>(t: T) -> Self { TdObject::PersonalDetails(t.as_ref().clone()) }
+
+  pub fn personal_document<T: AsRef<PersonalDocument>>(t: T) -> Self { TdObject::PersonalDocument(t.as_ref().clone()) }
+
+  pub fn phone_number_authentication_settings<T: AsRef<PhoneNumberAuthenticationSettings>>(t: T) -> Self { TdObject::PhoneNumberAuthenticationSettings(t.as_ref().clone()) }
+
+  pub fn photo<T: AsRef<Photo>>(t: T) -> Self { TdObject::Photo(t.as_ref().clone()) }
+
+  pub fn photo_size<T: AsRef<PhotoSize>>(t: T) -> Self { TdObject::PhotoSize(t.as_ref().clone()) }
+
+  pub fn poll<T: AsRef<Poll>>(t: T) -> Self { TdObject::Poll(t.as_ref().clone()) }
+
+  pub fn poll_option<T: AsRef<PollOption>>(t: T) -> Self { TdObject::PollOption(t.as_ref().clone()) }
+
+  pub fn poll_type<T: AsRef<PollType>>(t: T) -> Self { TdObject::PollType(t.as_ref().clone()) }
+
+  pub fn profile_photo<T: AsRef<ProfilePhoto>>(t: T) -> Self { TdObject::ProfilePhoto(t.as_ref().clone()) }
+
+  pub fn proxies<T: AsRef<Proxies>>(t: T) -> Self { TdObject::Proxies(t.as_ref().clone()) }
+
+  pub fn proxy<T: AsRef<Proxy>>(t: T) -> Self { TdObject::Proxy(t.as_ref().clone()) }
+
+  pub fn proxy_type<T: AsRef<ProxyType>>(t: T) -> Self { TdObject::ProxyType(t.as_ref().clone()) }
+
+  pub fn public_chat_type<T: AsRef<PublicChatType>>(t: T) -> Self { TdObject::PublicChatType(t.as_ref().clone()) }
+
+  pub fn public_message_link<T: AsRef<PublicMessageLink>>(t: T) -> Self { TdObject::PublicMessageLink(t.as_ref().clone()) }
+
+  pub fn push_message_content<T: AsRef<PushMessageContent>>(t: T) -> Self { TdObject::PushMessageContent(t.as_ref().clone()) }
+
+  pub fn push_receiver_id<T: AsRef<PushReceiverId>>(t: T) -> Self { TdObject::PushReceiverId(t.as_ref().clone()) }
+
+  pub fn recommended_chat_filter<T: AsRef<RecommendedChatFilter>>(t: T) -> Self { TdObject::RecommendedChatFilter(t.as_ref().clone()) }
+
+  pub fn recommended_chat_filters<T: AsRef<RecommendedChatFilters>>(t: T) -> Self { TdObject::RecommendedChatFilters(t.as_ref().clone()) }
+
+  pub fn recovery_email_address<T: AsRef<RecoveryEmailAddress>>(t: T) -> Self { TdObject::RecoveryEmailAddress(t.as_ref().clone()) }
+
+  pub fn remote_file<T: AsRef<RemoteFile>>(t: T) -> Self { TdObject::RemoteFile(t.as_ref().clone()) }
+
+  pub fn reply_markup<T: AsRef<ReplyMarkup>>(t: T) -> Self { TdObject::ReplyMarkup(t.as_ref().clone()) }
+
+  pub fn rich_text<T: AsRef<RichText>>(t: T) -> Self { TdObject::RichText(t.as_ref().clone()) }
+
+  pub fn saved_credentials<T: AsRef<SavedCredentials>>(t: T) -> Self { TdObject::SavedCredentials(t.as_ref().clone()) }
+
+  pub fn scope_notification_settings<T: AsRef<ScopeNotificationSettings>>(t: T) -> Self { TdObject::ScopeNotificationSettings(t.as_ref().clone()) }
+
+  pub fn search_messages_filter<T: AsRef<SearchMessagesFilter>>(t: T) -> Self { TdObject::SearchMessagesFilter(t.as_ref().clone()) }
+
+  pub fn seconds<T: AsRef<Seconds>>(t: T) -> Self { TdObject::Seconds(t.as_ref().clone()) }
+
+  pub fn secret_chat<T: AsRef<SecretChat>>(t: T) -> Self { TdObject::SecretChat(t.as_ref().clone()) }
+
+  pub fn secret_chat_state<T: AsRef<SecretChatState>>(t: T) -> Self { TdObject::SecretChatState(t.as_ref().clone()) }
+
+  pub fn session<T: AsRef<Session>>(t: T) -> Self { TdObject::Session(t.as_ref().clone()) }
+
+  pub fn sessions<T: AsRef<Sessions>>(t: T) -> Self { TdObject::Sessions(t.as_ref().clone()) }
+
+  pub fn shipping_option<T: AsRef<ShippingOption>>(t: T) -> Self { TdObject::ShippingOption(t.as_ref().clone()) }
+
+  pub fn statistics_graph<T: AsRef<StatisticsGraph>>(t: T) -> Self { TdObject::StatisticsGraph(t.as_ref().clone()) }
+
+  pub fn statistics_value<T: AsRef<StatisticsValue>>(t: T) -> Self { TdObject::StatisticsValue(t.as_ref().clone()) }
+
+  pub fn sticker<T: AsRef<Sticker>>(t: T) -> Self { TdObject::Sticker(t.as_ref().clone()) }
+
+  pub fn sticker_set<T: AsRef<StickerSet>>(t: T) -> Self { TdObject::StickerSet(t.as_ref().clone()) }
+
+  pub fn sticker_set_info<T: AsRef<StickerSetInfo>>(t: T) -> Self { TdObject::StickerSetInfo(t.as_ref().clone()) }
+
+  pub fn sticker_sets<T: AsRef<StickerSets>>(t: T) -> Self { TdObject::StickerSets(t.as_ref().clone()) }
+
+  pub fn stickers<T: AsRef<Stickers>>(t: T) -> Self { TdObject::Stickers(t.as_ref().clone()) }
+
+  pub fn storage_statistics<T: AsRef<StorageStatistics>>(t: T) -> Self { TdObject::StorageStatistics(t.as_ref().clone()) }
+
+  pub fn storage_statistics_by_chat<T: AsRef<StorageStatisticsByChat>>(t: T) -> Self { TdObject::StorageStatisticsByChat(t.as_ref().clone()) }
+
+  pub fn storage_statistics_by_file_type<T: AsRef<StorageStatisticsByFileType>>(t: T) -> Self { TdObject::StorageStatisticsByFileType(t.as_ref().clone()) }
+
+  pub fn storage_statistics_fast<T: AsRef<StorageStatisticsFast>>(t: T) -> Self { TdObject::StorageStatisticsFast(t.as_ref().clone()) }
+
+  pub fn suggested_action<T: AsRef<SuggestedAction>>(t: T) -> Self { TdObject::SuggestedAction(t.as_ref().clone()) }
+
+  pub fn supergroup<T: AsRef<Supergroup>>(t: T) -> Self { TdObject::Supergroup(t.as_ref().clone()) }
+
+  pub fn supergroup_full_info<T: AsRef<SupergroupFullInfo>>(t: T) -> Self { TdObject::SupergroupFullInfo(t.as_ref().clone()) }
+
+  pub fn supergroup_members_filter<T: AsRef<SupergroupMembersFilter>>(t: T) -> Self { TdObject::SupergroupMembersFilter(t.as_ref().clone()) }
+
+  pub fn t_me_url<T: AsRef<TMeUrl>>(t: T) -> Self { TdObject::TMeUrl(t.as_ref().clone()) }
+
+  pub fn t_me_url_type<T: AsRef<TMeUrlType>>(t: T) -> Self { TdObject::TMeUrlType(t.as_ref().clone()) }
+
+  pub fn t_me_urls<T: AsRef<TMeUrls>>(t: T) -> Self { TdObject::TMeUrls(t.as_ref().clone()) }
+
+  pub fn tdlib_parameters<T: AsRef<TdlibParameters>>(t: T) -> Self { TdObject::TdlibParameters(t.as_ref().clone()) }
+
+  pub fn temporary_password_state<T: AsRef<TemporaryPasswordState>>(t: T) -> Self { TdObject::TemporaryPasswordState(t.as_ref().clone()) }
+
+  pub fn terms_of_service<T: AsRef<TermsOfService>>(t: T) -> Self { TdObject::TermsOfService(t.as_ref().clone()) }
+
+  pub fn test_bytes<T: AsRef<TestBytes>>(t: T) -> Self { TdObject::TestBytes(t.as_ref().clone()) }
+
+  pub fn test_int<T: AsRef<TestInt>>(t: T) -> Self { TdObject::TestInt(t.as_ref().clone()) }
+
+  pub fn test_string<T: AsRef<TestString>>(t: T) -> Self { TdObject::TestString(t.as_ref().clone()) }
+
+  pub fn test_vector_int<T: AsRef<TestVectorInt>>(t: T) -> Self { TdObject::TestVectorInt(t.as_ref().clone()) }
+
+  pub fn test_vector_int_object<T: AsRef<TestVectorIntObject>>(t: T) -> Self { TdObject::TestVectorIntObject(t.as_ref().clone()) }
+
+  pub fn test_vector_string<T: AsRef<TestVectorString>>(t: T) -> Self { TdObject::TestVectorString(t.as_ref().clone()) }
+
+  pub fn test_vector_string_object<T: AsRef<TestVectorStringObject>>(t: T) -> Self { TdObject::TestVectorStringObject(t.as_ref().clone()) }
+
+  pub fn text<T: AsRef<Text>>(t: T) -> Self { TdObject::Text(t.as_ref().clone()) }
+
+  pub fn text_entities<T: AsRef<TextEntities>>(t: T) -> Self { TdObject::TextEntities(t.as_ref().clone()) }
+
+  pub fn text_entity<T: AsRef<TextEntity>>(t: T) -> Self { TdObject::TextEntity(t.as_ref().clone()) }
+
+  pub fn text_entity_type<T: AsRef<TextEntityType>>(t: T) -> Self { TdObject::TextEntityType(t.as_ref().clone()) }
+
+  pub fn text_parse_mode<T: AsRef<TextParseMode>>(t: T) -> Self { TdObject::TextParseMode(t.as_ref().clone()) }
+
+  pub fn thumbnail<T: AsRef<Thumbnail>>(t: T) -> Self { TdObject::Thumbnail(t.as_ref().clone()) }
+
+  pub fn thumbnail_format<T: AsRef<ThumbnailFormat>>(t: T) -> Self { TdObject::ThumbnailFormat(t.as_ref().clone()) }
+
+  pub fn top_chat_category<T: AsRef<TopChatCategory>>(t: T) -> Self { TdObject::TopChatCategory(t.as_ref().clone()) }
+
+  pub fn update<T: AsRef<Update>>(t: T) -> Self { TdObject::Update(t.as_ref().clone()) }
+
+  pub fn updates<T: AsRef<Updates>>(t: T) -> Self { TdObject::Updates(t.as_ref().clone()) }
+
+  pub fn user<T: AsRef<User>>(t: T) -> Self { TdObject::User(t.as_ref().clone()) }
+
+  pub fn user_full_info<T: AsRef<UserFullInfo>>(t: T) -> Self { TdObject::UserFullInfo(t.as_ref().clone()) }
+
+  pub fn user_privacy_setting<T: AsRef<UserPrivacySetting>>(t: T) -> Self { TdObject::UserPrivacySetting(t.as_ref().clone()) }
+
+  pub fn user_privacy_setting_rule<T: AsRef<UserPrivacySettingRule>>(t: T) -> Self { TdObject::UserPrivacySettingRule(t.as_ref().clone()) }
+
+  pub fn user_privacy_setting_rules<T: AsRef<UserPrivacySettingRules>>(t: T) -> Self { TdObject::UserPrivacySettingRules(t.as_ref().clone()) }
+
+  pub fn user_status<T: AsRef<UserStatus>>(t: T) -> Self { TdObject::UserStatus(t.as_ref().clone()) }
+
+  pub fn user_type<T: AsRef<UserType>>(t: T) -> Self { TdObject::UserType(t.as_ref().clone()) }
+
+  pub fn users<T: AsRef<Users>>(t: T) -> Self { TdObject::Users(t.as_ref().clone()) }
+
+  pub fn validated_order_info<T: AsRef<ValidatedOrderInfo>>(t: T) -> Self { TdObject::ValidatedOrderInfo(t.as_ref().clone()) }
+
+  pub fn venue<T: AsRef<Venue>>(t: T) -> Self { TdObject::Venue(t.as_ref().clone()) }
+
+  pub fn video<T: AsRef<Video>>(t: T) -> Self { TdObject::Video(t.as_ref().clone()) }
+
+  pub fn video_note<T: AsRef<VideoNote>>(t: T) -> Self { TdObject::VideoNote(t.as_ref().clone()) }
+
+  pub fn voice_note<T: AsRef<VoiceNote>>(t: T) -> Self { TdObject::VoiceNote(t.as_ref().clone()) }
+
+  pub fn web_page<T: AsRef<WebPage>>(t: T) -> Self { TdObject::WebPage(t.as_ref().clone()) }
+
+  pub fn web_page_instant_view<T: AsRef<WebPageInstantView>>(t: T) -> Self { TdObject::WebPageInstantView(t.as_ref().clone()) }
+
+}
+
+impl AsRef<TdObject> for TdObject {
+  fn as_ref(&self) -> &TdObject { self }
+}
+
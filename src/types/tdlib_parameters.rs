@@ -11,6 +11,9 @@ pub struct TdlibParameters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// If set to true, the Telegram test environment will be used instead of the production environment
   use_test_dc: bool,
   /// The path to the directory for the persistent database; if empty, the current working directory will be used
@@ -46,6 +49,7 @@ pub struct TdlibParameters {
 
 impl RObject for TdlibParameters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "tdlibParameters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -99,6 +103,11 @@ pub struct RTDTdlibParametersBuilder {
 impl RTDTdlibParametersBuilder {
   pub fn build(&self) -> TdlibParameters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn use_test_dc(&mut self, use_test_dc: bool) -> &mut Self {
     self.inner.use_test_dc = use_test_dc;
@@ -11,6 +11,9 @@ pub struct TemporaryPasswordState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if a temporary password is available
   has_password: bool,
   /// Time left before the temporary password expires, in seconds
@@ -20,6 +23,7 @@ pub struct TemporaryPasswordState {
 
 impl RObject for TemporaryPasswordState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "temporaryPasswordState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDTemporaryPasswordStateBuilder {
 impl RTDTemporaryPasswordStateBuilder {
   pub fn build(&self) -> TemporaryPasswordState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn has_password(&mut self, has_password: bool) -> &mut Self {
     self.inner.has_password = has_password;
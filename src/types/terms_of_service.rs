@@ -11,6 +11,9 @@ pub struct TermsOfService {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Text of the terms of service
   text: FormattedText,
   /// The minimum age of a user to be able to accept the terms; 0 if any
@@ -22,6 +25,7 @@ pub struct TermsOfService {
 
 impl RObject for TermsOfService {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "termsOfService" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDTermsOfServiceBuilder {
 impl RTDTermsOfServiceBuilder {
   pub fn build(&self) -> TermsOfService { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -11,13 +11,17 @@ pub struct TestBytes {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Bytes
-  value: String,
+  value: TdBytes,
   
 }
 
 impl RObject for TestBytes {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testBytes" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -31,7 +35,7 @@ impl TestBytes {
     RTDTestBytesBuilder { inner }
   }
 
-  pub fn value(&self) -> &String { &self.value }
+  pub fn value(&self) -> &TdBytes { &self.value }
 
 }
 
@@ -43,9 +47,14 @@ pub struct RTDTestBytesBuilder {
 impl RTDTestBytesBuilder {
   pub fn build(&self) -> TestBytes { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
-    self.inner.value = value.as_ref().to_string();
+  pub fn value<T: AsRef<[u8]>>(&mut self, value: T) -> &mut Self {
+    self.inner.value = TdBytes::from(value.as_ref());
     self
   }
 
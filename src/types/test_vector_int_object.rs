@@ -11,6 +11,9 @@ pub struct TestVectorIntObject {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Vector of objects
   value: Vec<TestInt>,
   
@@ -18,6 +21,7 @@ pub struct TestVectorIntObject {
 
 impl RObject for TestVectorIntObject {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testVectorIntObject" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDTestVectorIntObjectBuilder {
 impl RTDTestVectorIntObjectBuilder {
   pub fn build(&self) -> TestVectorIntObject { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: Vec<TestInt>) -> &mut Self {
     self.inner.value = value;
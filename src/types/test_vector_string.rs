@@ -11,6 +11,9 @@ pub struct TestVectorString {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Vector of strings
   value: Vec<String>,
   
@@ -18,6 +21,7 @@ pub struct TestVectorString {
 
 impl RObject for TestVectorString {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "testVectorString" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDTestVectorStringBuilder {
 impl RTDTestVectorStringBuilder {
   pub fn build(&self) -> TestVectorString { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: Vec<String>) -> &mut Self {
     self.inner.value = value;
@@ -11,6 +11,9 @@ pub struct TextEntity {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Offset of the entity, in UTF-16 code units
   offset: i64,
   /// Length of the entity, in UTF-16 code units
@@ -22,6 +25,7 @@ pub struct TextEntity {
 
 impl RObject for TextEntity {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntity" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -51,6 +55,11 @@ pub struct RTDTextEntityBuilder {
 impl RTDTextEntityBuilder {
   pub fn build(&self) -> TextEntity { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
@@ -18,6 +18,9 @@ pub trait TDTextEntityType: Debug + RObject {}
 #[serde(untagged)]
 pub enum TextEntityType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A bank card number. The getBankCardInfo method can be used to get information about the bank card
   BankCardNumber(TextEntityTypeBankCardNumber),
   /// A bold text
@@ -110,12 +113,37 @@ impl RObject for TextEntityType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      TextEntityType::BankCardNumber(t) => t.extra(),
+      TextEntityType::Bold(t) => t.extra(),
+      TextEntityType::BotCommand(t) => t.extra(),
+      TextEntityType::Cashtag(t) => t.extra(),
+      TextEntityType::Code(t) => t.extra(),
+      TextEntityType::EmailAddress(t) => t.extra(),
+      TextEntityType::Hashtag(t) => t.extra(),
+      TextEntityType::Italic(t) => t.extra(),
+      TextEntityType::Mention(t) => t.extra(),
+      TextEntityType::MentionName(t) => t.extra(),
+      TextEntityType::PhoneNumber(t) => t.extra(),
+      TextEntityType::Pre(t) => t.extra(),
+      TextEntityType::PreCode(t) => t.extra(),
+      TextEntityType::Strikethrough(t) => t.extra(),
+      TextEntityType::TextUrl(t) => t.extra(),
+      TextEntityType::Underline(t) => t.extra(),
+      TextEntityType::Url(t) => t.extra(),
+
+      TextEntityType::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl TextEntityType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextEntityType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let TextEntityType::Unknown { .. } = self { true } else { false } }
 
   pub fn is_bank_card_number(&self) -> bool { if let TextEntityType::BankCardNumber(_) = self { true } else { false } }
   pub fn is_bold(&self) -> bool { if let TextEntityType::Bold(_) = self { true } else { false } }
@@ -225,11 +253,15 @@ pub struct TextEntityTypeBankCardNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeBankCardNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBankCardNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -256,6 +288,11 @@ pub struct RTDTextEntityTypeBankCardNumberBuilder {
 impl RTDTextEntityTypeBankCardNumberBuilder {
   pub fn build(&self) -> TextEntityTypeBankCardNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeBankCardNumber> for TextEntityTypeBankCardNumber {
@@ -278,11 +315,15 @@ pub struct TextEntityTypeBold {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeBold {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBold" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -309,6 +350,11 @@ pub struct RTDTextEntityTypeBoldBuilder {
 impl RTDTextEntityTypeBoldBuilder {
   pub fn build(&self) -> TextEntityTypeBold { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeBold> for TextEntityTypeBold {
@@ -331,11 +377,15 @@ pub struct TextEntityTypeBotCommand {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeBotCommand {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBotCommand" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -362,6 +412,11 @@ pub struct RTDTextEntityTypeBotCommandBuilder {
 impl RTDTextEntityTypeBotCommandBuilder {
   pub fn build(&self) -> TextEntityTypeBotCommand { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeBotCommand> for TextEntityTypeBotCommand {
@@ -384,11 +439,15 @@ pub struct TextEntityTypeCashtag {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeCashtag {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeCashtag" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -415,6 +474,11 @@ pub struct RTDTextEntityTypeCashtagBuilder {
 impl RTDTextEntityTypeCashtagBuilder {
   pub fn build(&self) -> TextEntityTypeCashtag { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeCashtag> for TextEntityTypeCashtag {
@@ -437,11 +501,15 @@ pub struct TextEntityTypeCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -468,6 +536,11 @@ pub struct RTDTextEntityTypeCodeBuilder {
 impl RTDTextEntityTypeCodeBuilder {
   pub fn build(&self) -> TextEntityTypeCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeCode> for TextEntityTypeCode {
@@ -490,11 +563,15 @@ pub struct TextEntityTypeEmailAddress {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeEmailAddress {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeEmailAddress" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -521,6 +598,11 @@ pub struct RTDTextEntityTypeEmailAddressBuilder {
 impl RTDTextEntityTypeEmailAddressBuilder {
   pub fn build(&self) -> TextEntityTypeEmailAddress { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeEmailAddress> for TextEntityTypeEmailAddress {
@@ -543,11 +625,15 @@ pub struct TextEntityTypeHashtag {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeHashtag {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeHashtag" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -574,6 +660,11 @@ pub struct RTDTextEntityTypeHashtagBuilder {
 impl RTDTextEntityTypeHashtagBuilder {
   pub fn build(&self) -> TextEntityTypeHashtag { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeHashtag> for TextEntityTypeHashtag {
@@ -596,11 +687,15 @@ pub struct TextEntityTypeItalic {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeItalic {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeItalic" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -627,6 +722,11 @@ pub struct RTDTextEntityTypeItalicBuilder {
 impl RTDTextEntityTypeItalicBuilder {
   pub fn build(&self) -> TextEntityTypeItalic { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeItalic> for TextEntityTypeItalic {
@@ -649,11 +749,15 @@ pub struct TextEntityTypeMention {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeMention {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeMention" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -680,6 +784,11 @@ pub struct RTDTextEntityTypeMentionBuilder {
 impl RTDTextEntityTypeMentionBuilder {
   pub fn build(&self) -> TextEntityTypeMention { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeMention> for TextEntityTypeMention {
@@ -702,6 +811,9 @@ pub struct TextEntityTypeMentionName {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the mentioned user
   user_id: i64,
   
@@ -709,6 +821,7 @@ pub struct TextEntityTypeMentionName {
 
 impl RObject for TextEntityTypeMentionName {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeMentionName" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -737,6 +850,11 @@ pub struct RTDTextEntityTypeMentionNameBuilder {
 impl RTDTextEntityTypeMentionNameBuilder {
   pub fn build(&self) -> TextEntityTypeMentionName { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -765,11 +883,15 @@ pub struct TextEntityTypePhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypePhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypePhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -796,6 +918,11 @@ pub struct RTDTextEntityTypePhoneNumberBuilder {
 impl RTDTextEntityTypePhoneNumberBuilder {
   pub fn build(&self) -> TextEntityTypePhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypePhoneNumber> for TextEntityTypePhoneNumber {
@@ -818,11 +945,15 @@ pub struct TextEntityTypePre {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypePre {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypePre" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -849,6 +980,11 @@ pub struct RTDTextEntityTypePreBuilder {
 impl RTDTextEntityTypePreBuilder {
   pub fn build(&self) -> TextEntityTypePre { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypePre> for TextEntityTypePre {
@@ -871,6 +1007,9 @@ pub struct TextEntityTypePreCode {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Programming language of the code; as defined by the sender
   language: String,
   
@@ -878,6 +1017,7 @@ pub struct TextEntityTypePreCode {
 
 impl RObject for TextEntityTypePreCode {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypePreCode" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -906,6 +1046,11 @@ pub struct RTDTextEntityTypePreCodeBuilder {
 impl RTDTextEntityTypePreCodeBuilder {
   pub fn build(&self) -> TextEntityTypePreCode { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language<T: AsRef<str>>(&mut self, language: T) -> &mut Self {
     self.inner.language = language.as_ref().to_string();
@@ -934,11 +1079,15 @@ pub struct TextEntityTypeStrikethrough {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeStrikethrough {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeStrikethrough" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -965,6 +1114,11 @@ pub struct RTDTextEntityTypeStrikethroughBuilder {
 impl RTDTextEntityTypeStrikethroughBuilder {
   pub fn build(&self) -> TextEntityTypeStrikethrough { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeStrikethrough> for TextEntityTypeStrikethrough {
@@ -987,6 +1141,9 @@ pub struct TextEntityTypeTextUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// HTTP or tg:// URL to be opened when the link is clicked
   url: String,
   
@@ -994,6 +1151,7 @@ pub struct TextEntityTypeTextUrl {
 
 impl RObject for TextEntityTypeTextUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeTextUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1022,6 +1180,11 @@ pub struct RTDTextEntityTypeTextUrlBuilder {
 impl RTDTextEntityTypeTextUrlBuilder {
   pub fn build(&self) -> TextEntityTypeTextUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1050,11 +1213,15 @@ pub struct TextEntityTypeUnderline {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeUnderline {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeUnderline" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1081,6 +1248,11 @@ pub struct RTDTextEntityTypeUnderlineBuilder {
 impl RTDTextEntityTypeUnderlineBuilder {
   pub fn build(&self) -> TextEntityTypeUnderline { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeUnderline> for TextEntityTypeUnderline {
@@ -1103,11 +1275,15 @@ pub struct TextEntityTypeUrl {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextEntityTypeUrl {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeUrl" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1134,6 +1310,11 @@ pub struct RTDTextEntityTypeUrlBuilder {
 impl RTDTextEntityTypeUrlBuilder {
   pub fn build(&self) -> TextEntityTypeUrl { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeUrl> for TextEntityTypeUrl {
@@ -18,6 +18,9 @@ pub trait TDTextParseMode: Debug + RObject {}
 #[serde(untagged)]
 pub enum TextParseMode {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The text uses HTML-style formatting. The same as Telegram Bot API "HTML" parse mode
   HTML(TextParseModeHTML),
   /// The text uses Markdown-style formatting
@@ -50,12 +53,22 @@ impl RObject for TextParseMode {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      TextParseMode::HTML(t) => t.extra(),
+      TextParseMode::Markdown(t) => t.extra(),
+
+      TextParseMode::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl TextParseMode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextParseMode::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let TextParseMode::Unknown { .. } = self { true } else { false } }
 
   pub fn is_h_t_m_l(&self) -> bool { if let TextParseMode::HTML(_) = self { true } else { false } }
   pub fn is_markdown(&self) -> bool { if let TextParseMode::Markdown(_) = self { true } else { false } }
@@ -90,11 +103,15 @@ pub struct TextParseModeHTML {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TextParseModeHTML {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textParseModeHTML" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,6 +138,11 @@ pub struct RTDTextParseModeHTMLBuilder {
 impl RTDTextParseModeHTMLBuilder {
   pub fn build(&self) -> TextParseModeHTML { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextParseModeHTML> for TextParseModeHTML {
@@ -143,6 +165,9 @@ pub struct TextParseModeMarkdown {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Version of the parser: 0 or 1  Telegram Bot API "Markdown" parse mode, 2  Telegram Bot API "MarkdownV2" parse mode
   version: i64,
   
@@ -150,6 +175,7 @@ pub struct TextParseModeMarkdown {
 
 impl RObject for TextParseModeMarkdown {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "textParseModeMarkdown" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -178,6 +204,11 @@ pub struct RTDTextParseModeMarkdownBuilder {
 impl RTDTextParseModeMarkdownBuilder {
   pub fn build(&self) -> TextParseModeMarkdown { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn version(&mut self, version: i64) -> &mut Self {
     self.inner.version = version;
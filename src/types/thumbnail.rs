@@ -11,6 +11,9 @@ pub struct Thumbnail {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Thumbnail format
   format: ThumbnailFormat,
   /// Thumbnail width
@@ -24,6 +27,7 @@ pub struct Thumbnail {
 
 impl RObject for Thumbnail {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnail" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -55,6 +59,11 @@ pub struct RTDThumbnailBuilder {
 impl RTDThumbnailBuilder {
   pub fn build(&self) -> Thumbnail { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn format<T: AsRef<ThumbnailFormat>>(&mut self, format: T) -> &mut Self {
     self.inner.format = format.as_ref().clone();
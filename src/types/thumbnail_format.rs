@@ -18,6 +18,9 @@ pub trait TDThumbnailFormat: Debug + RObject {}
 #[serde(untagged)]
 pub enum ThumbnailFormat {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The thumbnail is in static GIF format. It will be used only for some bot inline results
   Gif(ThumbnailFormatGif),
   /// The thumbnail is in JPEG format
@@ -66,12 +69,26 @@ impl RObject for ThumbnailFormat {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      ThumbnailFormat::Gif(t) => t.extra(),
+      ThumbnailFormat::Jpeg(t) => t.extra(),
+      ThumbnailFormat::Mpeg4(t) => t.extra(),
+      ThumbnailFormat::Png(t) => t.extra(),
+      ThumbnailFormat::Tgs(t) => t.extra(),
+      ThumbnailFormat::Webp(t) => t.extra(),
+
+      ThumbnailFormat::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl ThumbnailFormat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ThumbnailFormat::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let ThumbnailFormat::Unknown { .. } = self { true } else { false } }
 
   pub fn is_gif(&self) -> bool { if let ThumbnailFormat::Gif(_) = self { true } else { false } }
   pub fn is_jpeg(&self) -> bool { if let ThumbnailFormat::Jpeg(_) = self { true } else { false } }
@@ -126,11 +143,15 @@ pub struct ThumbnailFormatGif {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ThumbnailFormatGif {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatGif" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,6 +178,11 @@ pub struct RTDThumbnailFormatGifBuilder {
 impl RTDThumbnailFormatGifBuilder {
   pub fn build(&self) -> ThumbnailFormatGif { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatGif> for ThumbnailFormatGif {
@@ -179,11 +205,15 @@ pub struct ThumbnailFormatJpeg {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ThumbnailFormatJpeg {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatJpeg" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -210,6 +240,11 @@ pub struct RTDThumbnailFormatJpegBuilder {
 impl RTDThumbnailFormatJpegBuilder {
   pub fn build(&self) -> ThumbnailFormatJpeg { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatJpeg> for ThumbnailFormatJpeg {
@@ -232,11 +267,15 @@ pub struct ThumbnailFormatMpeg4 {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ThumbnailFormatMpeg4 {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatMpeg4" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,6 +302,11 @@ pub struct RTDThumbnailFormatMpeg4Builder {
 impl RTDThumbnailFormatMpeg4Builder {
   pub fn build(&self) -> ThumbnailFormatMpeg4 { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatMpeg4> for ThumbnailFormatMpeg4 {
@@ -285,11 +329,15 @@ pub struct ThumbnailFormatPng {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ThumbnailFormatPng {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatPng" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -316,6 +364,11 @@ pub struct RTDThumbnailFormatPngBuilder {
 impl RTDThumbnailFormatPngBuilder {
   pub fn build(&self) -> ThumbnailFormatPng { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatPng> for ThumbnailFormatPng {
@@ -338,11 +391,15 @@ pub struct ThumbnailFormatTgs {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ThumbnailFormatTgs {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatTgs" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -369,6 +426,11 @@ pub struct RTDThumbnailFormatTgsBuilder {
 impl RTDThumbnailFormatTgsBuilder {
   pub fn build(&self) -> ThumbnailFormatTgs { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatTgs> for ThumbnailFormatTgs {
@@ -391,11 +453,15 @@ pub struct ThumbnailFormatWebp {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for ThumbnailFormatWebp {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatWebp" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -422,6 +488,11 @@ pub struct RTDThumbnailFormatWebpBuilder {
 impl RTDThumbnailFormatWebpBuilder {
   pub fn build(&self) -> ThumbnailFormatWebp { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatWebp> for ThumbnailFormatWebp {
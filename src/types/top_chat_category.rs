@@ -18,6 +18,9 @@ pub trait TDTopChatCategory: Debug + RObject {}
 #[serde(untagged)]
 pub enum TopChatCategory {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A category containing frequently used private chats with bot users
   Bots(TopChatCategoryBots),
   /// A category containing frequently used chats used for calls
@@ -70,12 +73,27 @@ impl RObject for TopChatCategory {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      TopChatCategory::Bots(t) => t.extra(),
+      TopChatCategory::Calls(t) => t.extra(),
+      TopChatCategory::Channels(t) => t.extra(),
+      TopChatCategory::ForwardChats(t) => t.extra(),
+      TopChatCategory::Groups(t) => t.extra(),
+      TopChatCategory::InlineBots(t) => t.extra(),
+      TopChatCategory::Users(t) => t.extra(),
+
+      TopChatCategory::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl TopChatCategory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TopChatCategory::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let TopChatCategory::Unknown { .. } = self { true } else { false } }
 
   pub fn is_bots(&self) -> bool { if let TopChatCategory::Bots(_) = self { true } else { false } }
   pub fn is_calls(&self) -> bool { if let TopChatCategory::Calls(_) = self { true } else { false } }
@@ -135,11 +153,15 @@ pub struct TopChatCategoryBots {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryBots {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryBots" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -166,6 +188,11 @@ pub struct RTDTopChatCategoryBotsBuilder {
 impl RTDTopChatCategoryBotsBuilder {
   pub fn build(&self) -> TopChatCategoryBots { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryBots> for TopChatCategoryBots {
@@ -188,11 +215,15 @@ pub struct TopChatCategoryCalls {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryCalls {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryCalls" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -219,6 +250,11 @@ pub struct RTDTopChatCategoryCallsBuilder {
 impl RTDTopChatCategoryCallsBuilder {
   pub fn build(&self) -> TopChatCategoryCalls { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryCalls> for TopChatCategoryCalls {
@@ -241,11 +277,15 @@ pub struct TopChatCategoryChannels {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryChannels {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryChannels" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -272,6 +312,11 @@ pub struct RTDTopChatCategoryChannelsBuilder {
 impl RTDTopChatCategoryChannelsBuilder {
   pub fn build(&self) -> TopChatCategoryChannels { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryChannels> for TopChatCategoryChannels {
@@ -294,11 +339,15 @@ pub struct TopChatCategoryForwardChats {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryForwardChats {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryForwardChats" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -325,6 +374,11 @@ pub struct RTDTopChatCategoryForwardChatsBuilder {
 impl RTDTopChatCategoryForwardChatsBuilder {
   pub fn build(&self) -> TopChatCategoryForwardChats { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryForwardChats> for TopChatCategoryForwardChats {
@@ -347,11 +401,15 @@ pub struct TopChatCategoryGroups {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryGroups {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryGroups" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -378,6 +436,11 @@ pub struct RTDTopChatCategoryGroupsBuilder {
 impl RTDTopChatCategoryGroupsBuilder {
   pub fn build(&self) -> TopChatCategoryGroups { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryGroups> for TopChatCategoryGroups {
@@ -400,11 +463,15 @@ pub struct TopChatCategoryInlineBots {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryInlineBots {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryInlineBots" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -431,6 +498,11 @@ pub struct RTDTopChatCategoryInlineBotsBuilder {
 impl RTDTopChatCategoryInlineBotsBuilder {
   pub fn build(&self) -> TopChatCategoryInlineBots { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryInlineBots> for TopChatCategoryInlineBots {
@@ -453,11 +525,15 @@ pub struct TopChatCategoryUsers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for TopChatCategoryUsers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryUsers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -484,6 +560,11 @@ pub struct RTDTopChatCategoryUsersBuilder {
 impl RTDTopChatCategoryUsersBuilder {
   pub fn build(&self) -> TopChatCategoryUsers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryUsers> for TopChatCategoryUsers {
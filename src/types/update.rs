@@ -18,8 +18,9 @@ pub trait TDUpdate: Debug + RObject {}
 #[serde(untagged)]
 pub enum Update {
   #[doc(hidden)] _Default(()),
-  /// Does nothing and ensures that the Update object is used; for testing only. This is an offline method. Can be called before authorization
-  TestUseUpdate(TestUseUpdate),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update
   ActiveNotifications(UpdateActiveNotifications),
   /// The parameters of animation search through GetOption("animation_search_bot_username") bot has changed
@@ -190,7 +191,6 @@ impl<'de> Deserialize<'de> for Update {
     use serde::de::Error;
     rtd_enum_deserialize!(
       Update,
-      (testUseUpdate, TestUseUpdate);
       (updateActiveNotifications, ActiveNotifications);
       (updateAnimationSearchParameters, AnimationSearchParameters);
       (updateAuthorizationState, AuthorizationState);
@@ -278,7 +278,6 @@ impl<'de> Deserialize<'de> for Update {
 impl RObject for Update {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      Update::TestUseUpdate(t) => t.td_name(),
       Update::ActiveNotifications(t) => t.td_name(),
       Update::AnimationSearchParameters(t) => t.td_name(),
       Update::AuthorizationState(t) => t.td_name(),
@@ -362,14 +361,100 @@ impl RObject for Update {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      Update::ActiveNotifications(t) => t.extra(),
+      Update::AnimationSearchParameters(t) => t.extra(),
+      Update::AuthorizationState(t) => t.extra(),
+      Update::BasicGroup(t) => t.extra(),
+      Update::BasicGroupFullInfo(t) => t.extra(),
+      Update::Call(t) => t.extra(),
+      Update::ChatActionBar(t) => t.extra(),
+      Update::ChatDefaultDisableNotification(t) => t.extra(),
+      Update::ChatDraftMessage(t) => t.extra(),
+      Update::ChatFilters(t) => t.extra(),
+      Update::ChatHasScheduledMessages(t) => t.extra(),
+      Update::ChatIsMarkedAsUnread(t) => t.extra(),
+      Update::ChatLastMessage(t) => t.extra(),
+      Update::ChatNotificationSettings(t) => t.extra(),
+      Update::ChatOnlineMemberCount(t) => t.extra(),
+      Update::ChatPermissions(t) => t.extra(),
+      Update::ChatPhoto(t) => t.extra(),
+      Update::ChatPinnedMessage(t) => t.extra(),
+      Update::ChatPosition(t) => t.extra(),
+      Update::ChatReadInbox(t) => t.extra(),
+      Update::ChatReadOutbox(t) => t.extra(),
+      Update::ChatReplyMarkup(t) => t.extra(),
+      Update::ChatTitle(t) => t.extra(),
+      Update::ChatUnreadMentionCount(t) => t.extra(),
+      Update::ConnectionState(t) => t.extra(),
+      Update::DeleteMessages(t) => t.extra(),
+      Update::DiceEmojis(t) => t.extra(),
+      Update::FavoriteStickers(t) => t.extra(),
+      Update::File(t) => t.extra(),
+      Update::FileGenerationStart(t) => t.extra(),
+      Update::FileGenerationStop(t) => t.extra(),
+      Update::HavePendingNotifications(t) => t.extra(),
+      Update::InstalledStickerSets(t) => t.extra(),
+      Update::LanguagePackStrings(t) => t.extra(),
+      Update::MessageContent(t) => t.extra(),
+      Update::MessageContentOpened(t) => t.extra(),
+      Update::MessageEdited(t) => t.extra(),
+      Update::MessageLiveLocationViewed(t) => t.extra(),
+      Update::MessageMentionRead(t) => t.extra(),
+      Update::MessageSendAcknowledged(t) => t.extra(),
+      Update::MessageSendFailed(t) => t.extra(),
+      Update::MessageSendSucceeded(t) => t.extra(),
+      Update::MessageViews(t) => t.extra(),
+      Update::NewCallSignalingData(t) => t.extra(),
+      Update::NewCallbackQuery(t) => t.extra(),
+      Update::NewChat(t) => t.extra(),
+      Update::NewChosenInlineResult(t) => t.extra(),
+      Update::NewCustomEvent(t) => t.extra(),
+      Update::NewCustomQuery(t) => t.extra(),
+      Update::NewInlineCallbackQuery(t) => t.extra(),
+      Update::NewInlineQuery(t) => t.extra(),
+      Update::NewMessage(t) => t.extra(),
+      Update::NewPreCheckoutQuery(t) => t.extra(),
+      Update::NewShippingQuery(t) => t.extra(),
+      Update::Notification(t) => t.extra(),
+      Update::NotificationGroup(t) => t.extra(),
+      Update::Option(t) => t.extra(),
+      Update::Poll(t) => t.extra(),
+      Update::PollAnswer(t) => t.extra(),
+      Update::RecentStickers(t) => t.extra(),
+      Update::SavedAnimations(t) => t.extra(),
+      Update::ScopeNotificationSettings(t) => t.extra(),
+      Update::SecretChat(t) => t.extra(),
+      Update::SelectedBackground(t) => t.extra(),
+      Update::ServiceNotification(t) => t.extra(),
+      Update::StickerSet(t) => t.extra(),
+      Update::SuggestedActions(t) => t.extra(),
+      Update::Supergroup(t) => t.extra(),
+      Update::SupergroupFullInfo(t) => t.extra(),
+      Update::TermsOfService(t) => t.extra(),
+      Update::TrendingStickerSets(t) => t.extra(),
+      Update::UnreadChatCount(t) => t.extra(),
+      Update::UnreadMessageCount(t) => t.extra(),
+      Update::User(t) => t.extra(),
+      Update::UserChatAction(t) => t.extra(),
+      Update::UserFullInfo(t) => t.extra(),
+      Update::UserPrivacySettingRules(t) => t.extra(),
+      Update::UserStatus(t) => t.extra(),
+      Update::UsersNearby(t) => t.extra(),
+
+      Update::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl Update {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let Update::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let Update::Unknown { .. } = self { true } else { false } }
 
-  pub fn is_test_use_update(&self) -> bool { if let Update::TestUseUpdate(_) = self { true } else { false } }
   pub fn is_active_notifications(&self) -> bool { if let Update::ActiveNotifications(_) = self { true } else { false } }
   pub fn is_animation_search_parameters(&self) -> bool { if let Update::AnimationSearchParameters(_) = self { true } else { false } }
   pub fn is_authorization_state(&self) -> bool { if let Update::AuthorizationState(_) = self { true } else { false } }
@@ -450,7 +535,6 @@ impl Update {
   pub fn is_user_status(&self) -> bool { if let Update::UserStatus(_) = self { true } else { false } }
   pub fn is_users_nearby(&self) -> bool { if let Update::UsersNearby(_) = self { true } else { false } }
 
-  pub fn on_test_use_update<F: FnOnce(&TestUseUpdate)>(&self, fnc: F) -> &Self { if let Update::TestUseUpdate(t) = self { fnc(t) }; self }
   pub fn on_active_notifications<F: FnOnce(&UpdateActiveNotifications)>(&self, fnc: F) -> &Self { if let Update::ActiveNotifications(t) = self { fnc(t) }; self }
   pub fn on_animation_search_parameters<F: FnOnce(&UpdateAnimationSearchParameters)>(&self, fnc: F) -> &Self { if let Update::AnimationSearchParameters(t) = self { fnc(t) }; self }
   pub fn on_authorization_state<F: FnOnce(&UpdateAuthorizationState)>(&self, fnc: F) -> &Self { if let Update::AuthorizationState(t) = self { fnc(t) }; self }
@@ -531,7 +615,6 @@ impl Update {
   pub fn on_user_status<F: FnOnce(&UpdateUserStatus)>(&self, fnc: F) -> &Self { if let Update::UserStatus(t) = self { fnc(t) }; self }
   pub fn on_users_nearby<F: FnOnce(&UpdateUsersNearby)>(&self, fnc: F) -> &Self { if let Update::UsersNearby(t) = self { fnc(t) }; self }
 
-  pub fn as_test_use_update(&self) -> Option<&TestUseUpdate> { if let Update::TestUseUpdate(t) = self { return Some(t) } None }
   pub fn as_active_notifications(&self) -> Option<&UpdateActiveNotifications> { if let Update::ActiveNotifications(t) = self { return Some(t) } None }
   pub fn as_animation_search_parameters(&self) -> Option<&UpdateAnimationSearchParameters> { if let Update::AnimationSearchParameters(t) = self { return Some(t) } None }
   pub fn as_authorization_state(&self) -> Option<&UpdateAuthorizationState> { if let Update::AuthorizationState(t) = self { return Some(t) } None }
@@ -614,8 +697,6 @@ impl Update {
 
 
 
-  pub fn test_use_update<T: AsRef<TestUseUpdate>>(t: T) -> Self { Update::TestUseUpdate(t.as_ref().clone()) }
-
   pub fn active_notifications<T: AsRef<UpdateActiveNotifications>>(t: T) -> Self { Update::ActiveNotifications(t.as_ref().clone()) }
 
   pub fn animation_search_parameters<T: AsRef<UpdateAnimationSearchParameters>>(t: T) -> Self { Update::AnimationSearchParameters(t.as_ref().clone()) }
@@ -792,6 +873,9 @@ pub struct UpdateActiveNotifications {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Lists of active notification groups
   groups: Vec<NotificationGroup>,
   
@@ -799,6 +883,7 @@ pub struct UpdateActiveNotifications {
 
 impl RObject for UpdateActiveNotifications {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateActiveNotifications" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -827,6 +912,11 @@ pub struct RTDUpdateActiveNotificationsBuilder {
 impl RTDUpdateActiveNotificationsBuilder {
   pub fn build(&self) -> UpdateActiveNotifications { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn groups(&mut self, groups: Vec<NotificationGroup>) -> &mut Self {
     self.inner.groups = groups;
@@ -855,6 +945,9 @@ pub struct UpdateAnimationSearchParameters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Name of the animation search provider
   provider: String,
   /// The new list of emojis suggested for searching
@@ -864,6 +957,7 @@ pub struct UpdateAnimationSearchParameters {
 
 impl RObject for UpdateAnimationSearchParameters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateAnimationSearchParameters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -894,6 +988,11 @@ pub struct RTDUpdateAnimationSearchParametersBuilder {
 impl RTDUpdateAnimationSearchParametersBuilder {
   pub fn build(&self) -> UpdateAnimationSearchParameters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn provider<T: AsRef<str>>(&mut self, provider: T) -> &mut Self {
     self.inner.provider = provider.as_ref().to_string();
@@ -928,6 +1027,9 @@ pub struct UpdateAuthorizationState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New authorization state
   authorization_state: AuthorizationState,
   
@@ -935,6 +1037,7 @@ pub struct UpdateAuthorizationState {
 
 impl RObject for UpdateAuthorizationState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateAuthorizationState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -963,6 +1066,11 @@ pub struct RTDUpdateAuthorizationStateBuilder {
 impl RTDUpdateAuthorizationStateBuilder {
   pub fn build(&self) -> UpdateAuthorizationState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn authorization_state<T: AsRef<AuthorizationState>>(&mut self, authorization_state: T) -> &mut Self {
     self.inner.authorization_state = authorization_state.as_ref().clone();
@@ -991,6 +1099,9 @@ pub struct UpdateBasicGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about the group
   basic_group: BasicGroup,
   
@@ -998,6 +1109,7 @@ pub struct UpdateBasicGroup {
 
 impl RObject for UpdateBasicGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateBasicGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1026,6 +1138,11 @@ pub struct RTDUpdateBasicGroupBuilder {
 impl RTDUpdateBasicGroupBuilder {
   pub fn build(&self) -> UpdateBasicGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group<T: AsRef<BasicGroup>>(&mut self, basic_group: T) -> &mut Self {
     self.inner.basic_group = basic_group.as_ref().clone();
@@ -1054,6 +1171,9 @@ pub struct UpdateBasicGroupFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of a basic group
   basic_group_id: i64,
   /// New full information about the group
@@ -1063,6 +1183,7 @@ pub struct UpdateBasicGroupFullInfo {
 
 impl RObject for UpdateBasicGroupFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateBasicGroupFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1093,6 +1214,11 @@ pub struct RTDUpdateBasicGroupFullInfoBuilder {
 impl RTDUpdateBasicGroupFullInfoBuilder {
   pub fn build(&self) -> UpdateBasicGroupFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
@@ -1127,6 +1253,9 @@ pub struct UpdateCall {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about a call
   call: Call,
   
@@ -1134,6 +1263,7 @@ pub struct UpdateCall {
 
 impl RObject for UpdateCall {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateCall" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1162,6 +1292,11 @@ pub struct RTDUpdateCallBuilder {
 impl RTDUpdateCallBuilder {
   pub fn build(&self) -> UpdateCall { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call<T: AsRef<Call>>(&mut self, call: T) -> &mut Self {
     self.inner.call = call.as_ref().clone();
@@ -1190,6 +1325,9 @@ pub struct UpdateChatActionBar {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new value of the action bar; may be null
@@ -1199,6 +1337,7 @@ pub struct UpdateChatActionBar {
 
 impl RObject for UpdateChatActionBar {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatActionBar" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1229,6 +1368,11 @@ pub struct RTDUpdateChatActionBarBuilder {
 impl RTDUpdateChatActionBarBuilder {
   pub fn build(&self) -> UpdateChatActionBar { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1263,6 +1407,9 @@ pub struct UpdateChatDefaultDisableNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new default_disable_notification value
@@ -1272,6 +1419,7 @@ pub struct UpdateChatDefaultDisableNotification {
 
 impl RObject for UpdateChatDefaultDisableNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatDefaultDisableNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1302,6 +1450,11 @@ pub struct RTDUpdateChatDefaultDisableNotificationBuilder {
 impl RTDUpdateChatDefaultDisableNotificationBuilder {
   pub fn build(&self) -> UpdateChatDefaultDisableNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1336,6 +1489,9 @@ pub struct UpdateChatDraftMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new draft message; may be null
@@ -1347,6 +1503,7 @@ pub struct UpdateChatDraftMessage {
 
 impl RObject for UpdateChatDraftMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatDraftMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1379,6 +1536,11 @@ pub struct RTDUpdateChatDraftMessageBuilder {
 impl RTDUpdateChatDraftMessageBuilder {
   pub fn build(&self) -> UpdateChatDraftMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1419,6 +1581,9 @@ pub struct UpdateChatFilters {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new list of chat filters
   chat_filters: Vec<ChatFilterInfo>,
   
@@ -1426,6 +1591,7 @@ pub struct UpdateChatFilters {
 
 impl RObject for UpdateChatFilters {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatFilters" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1454,6 +1620,11 @@ pub struct RTDUpdateChatFiltersBuilder {
 impl RTDUpdateChatFiltersBuilder {
   pub fn build(&self) -> UpdateChatFilters { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filters(&mut self, chat_filters: Vec<ChatFilterInfo>) -> &mut Self {
     self.inner.chat_filters = chat_filters;
@@ -1482,6 +1653,9 @@ pub struct UpdateChatHasScheduledMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New value of has_scheduled_messages
@@ -1491,6 +1665,7 @@ pub struct UpdateChatHasScheduledMessages {
 
 impl RObject for UpdateChatHasScheduledMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatHasScheduledMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1521,6 +1696,11 @@ pub struct RTDUpdateChatHasScheduledMessagesBuilder {
 impl RTDUpdateChatHasScheduledMessagesBuilder {
   pub fn build(&self) -> UpdateChatHasScheduledMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1555,6 +1735,9 @@ pub struct UpdateChatIsMarkedAsUnread {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New value of is_marked_as_unread
@@ -1564,6 +1747,7 @@ pub struct UpdateChatIsMarkedAsUnread {
 
 impl RObject for UpdateChatIsMarkedAsUnread {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatIsMarkedAsUnread" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1594,6 +1778,11 @@ pub struct RTDUpdateChatIsMarkedAsUnreadBuilder {
 impl RTDUpdateChatIsMarkedAsUnreadBuilder {
   pub fn build(&self) -> UpdateChatIsMarkedAsUnread { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1628,6 +1817,9 @@ pub struct UpdateChatLastMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new last message in the chat; may be null
@@ -1639,6 +1831,7 @@ pub struct UpdateChatLastMessage {
 
 impl RObject for UpdateChatLastMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatLastMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1671,6 +1864,11 @@ pub struct RTDUpdateChatLastMessageBuilder {
 impl RTDUpdateChatLastMessageBuilder {
   pub fn build(&self) -> UpdateChatLastMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1711,6 +1909,9 @@ pub struct UpdateChatNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new notification settings
@@ -1720,6 +1921,7 @@ pub struct UpdateChatNotificationSettings {
 
 impl RObject for UpdateChatNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1750,6 +1952,11 @@ pub struct RTDUpdateChatNotificationSettingsBuilder {
 impl RTDUpdateChatNotificationSettingsBuilder {
   pub fn build(&self) -> UpdateChatNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1784,6 +1991,9 @@ pub struct UpdateChatOnlineMemberCount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat
   chat_id: i64,
   /// New number of online members in the chat, or 0 if unknown
@@ -1793,6 +2003,7 @@ pub struct UpdateChatOnlineMemberCount {
 
 impl RObject for UpdateChatOnlineMemberCount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatOnlineMemberCount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1823,6 +2034,11 @@ pub struct RTDUpdateChatOnlineMemberCountBuilder {
 impl RTDUpdateChatOnlineMemberCountBuilder {
   pub fn build(&self) -> UpdateChatOnlineMemberCount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1857,6 +2073,9 @@ pub struct UpdateChatPermissions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new chat permissions
@@ -1866,6 +2085,7 @@ pub struct UpdateChatPermissions {
 
 impl RObject for UpdateChatPermissions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPermissions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1896,6 +2116,11 @@ pub struct RTDUpdateChatPermissionsBuilder {
 impl RTDUpdateChatPermissionsBuilder {
   pub fn build(&self) -> UpdateChatPermissions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -1930,6 +2155,9 @@ pub struct UpdateChatPhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new chat photo; may be null
@@ -1939,6 +2167,7 @@ pub struct UpdateChatPhoto {
 
 impl RObject for UpdateChatPhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1969,6 +2198,11 @@ pub struct RTDUpdateChatPhotoBuilder {
 impl RTDUpdateChatPhotoBuilder {
   pub fn build(&self) -> UpdateChatPhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2003,6 +2237,9 @@ pub struct UpdateChatPinnedMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new identifier of the pinned message; 0 if there is no pinned message in the chat
@@ -2012,6 +2249,7 @@ pub struct UpdateChatPinnedMessage {
 
 impl RObject for UpdateChatPinnedMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPinnedMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2042,6 +2280,11 @@ pub struct RTDUpdateChatPinnedMessageBuilder {
 impl RTDUpdateChatPinnedMessageBuilder {
   pub fn build(&self) -> UpdateChatPinnedMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2076,6 +2319,9 @@ pub struct UpdateChatPosition {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// New chat position. If new order is 0, then the chat needs to be removed from the list
@@ -2085,6 +2331,7 @@ pub struct UpdateChatPosition {
 
 impl RObject for UpdateChatPosition {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPosition" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2115,6 +2362,11 @@ pub struct RTDUpdateChatPositionBuilder {
 impl RTDUpdateChatPositionBuilder {
   pub fn build(&self) -> UpdateChatPosition { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2149,6 +2401,9 @@ pub struct UpdateChatReadInbox {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of the last read incoming message
@@ -2160,6 +2415,7 @@ pub struct UpdateChatReadInbox {
 
 impl RObject for UpdateChatReadInbox {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatReadInbox" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2192,6 +2448,11 @@ pub struct RTDUpdateChatReadInboxBuilder {
 impl RTDUpdateChatReadInboxBuilder {
   pub fn build(&self) -> UpdateChatReadInbox { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2232,6 +2493,9 @@ pub struct UpdateChatReadOutbox {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of last read outgoing message
@@ -2241,6 +2505,7 @@ pub struct UpdateChatReadOutbox {
 
 impl RObject for UpdateChatReadOutbox {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatReadOutbox" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2271,6 +2536,11 @@ pub struct RTDUpdateChatReadOutboxBuilder {
 impl RTDUpdateChatReadOutboxBuilder {
   pub fn build(&self) -> UpdateChatReadOutbox { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2305,6 +2575,9 @@ pub struct UpdateChatReplyMarkup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat
@@ -2314,6 +2587,7 @@ pub struct UpdateChatReplyMarkup {
 
 impl RObject for UpdateChatReplyMarkup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatReplyMarkup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2344,6 +2618,11 @@ pub struct RTDUpdateChatReplyMarkupBuilder {
 impl RTDUpdateChatReplyMarkupBuilder {
   pub fn build(&self) -> UpdateChatReplyMarkup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2378,6 +2657,9 @@ pub struct UpdateChatTitle {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The new chat title
@@ -2387,6 +2669,7 @@ pub struct UpdateChatTitle {
 
 impl RObject for UpdateChatTitle {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatTitle" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2417,6 +2700,11 @@ pub struct RTDUpdateChatTitleBuilder {
 impl RTDUpdateChatTitleBuilder {
   pub fn build(&self) -> UpdateChatTitle { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2451,6 +2739,9 @@ pub struct UpdateChatUnreadMentionCount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// The number of unread mention messages left in the chat
@@ -2460,6 +2751,7 @@ pub struct UpdateChatUnreadMentionCount {
 
 impl RObject for UpdateChatUnreadMentionCount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatUnreadMentionCount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2490,6 +2782,11 @@ pub struct RTDUpdateChatUnreadMentionCountBuilder {
 impl RTDUpdateChatUnreadMentionCountBuilder {
   pub fn build(&self) -> UpdateChatUnreadMentionCount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2524,6 +2821,9 @@ pub struct UpdateConnectionState {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new connection state
   state: ConnectionState,
   
@@ -2531,6 +2831,7 @@ pub struct UpdateConnectionState {
 
 impl RObject for UpdateConnectionState {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateConnectionState" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2559,6 +2860,11 @@ pub struct RTDUpdateConnectionStateBuilder {
 impl RTDUpdateConnectionStateBuilder {
   pub fn build(&self) -> UpdateConnectionState { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn state<T: AsRef<ConnectionState>>(&mut self, state: T) -> &mut Self {
     self.inner.state = state.as_ref().clone();
@@ -2587,6 +2893,9 @@ pub struct UpdateDeleteMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifiers of the deleted messages
@@ -2600,6 +2909,7 @@ pub struct UpdateDeleteMessages {
 
 impl RObject for UpdateDeleteMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateDeleteMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2634,6 +2944,11 @@ pub struct RTDUpdateDeleteMessagesBuilder {
 impl RTDUpdateDeleteMessagesBuilder {
   pub fn build(&self) -> UpdateDeleteMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -2680,6 +2995,9 @@ pub struct UpdateDiceEmojis {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new list of supported dice emojis
   emojis: Vec<String>,
   
@@ -2687,6 +3005,7 @@ pub struct UpdateDiceEmojis {
 
 impl RObject for UpdateDiceEmojis {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateDiceEmojis" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2715,6 +3034,11 @@ pub struct RTDUpdateDiceEmojisBuilder {
 impl RTDUpdateDiceEmojisBuilder {
   pub fn build(&self) -> UpdateDiceEmojis { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
     self.inner.emojis = emojis;
@@ -2743,6 +3067,9 @@ pub struct UpdateFavoriteStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new list of file identifiers of favorite stickers
   sticker_ids: Vec<i64>,
   
@@ -2750,6 +3077,7 @@ pub struct UpdateFavoriteStickers {
 
 impl RObject for UpdateFavoriteStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFavoriteStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2778,6 +3106,11 @@ pub struct RTDUpdateFavoriteStickersBuilder {
 impl RTDUpdateFavoriteStickersBuilder {
   pub fn build(&self) -> UpdateFavoriteStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_ids(&mut self, sticker_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_ids = sticker_ids;
@@ -2806,6 +3139,9 @@ pub struct UpdateFile {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about the file
   file: File,
   
@@ -2813,6 +3149,7 @@ pub struct UpdateFile {
 
 impl RObject for UpdateFile {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFile" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2841,6 +3178,11 @@ pub struct RTDUpdateFileBuilder {
 impl RTDUpdateFileBuilder {
   pub fn build(&self) -> UpdateFile { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
@@ -2869,8 +3211,11 @@ pub struct UpdateFileGenerationStart {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier for the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] generation_id: i64,
   /// The path to a file from which a new file is generated; may be empty
   original_path: String,
   /// The path to a file that should be created and where the new file should be generated
@@ -2882,6 +3227,7 @@ pub struct UpdateFileGenerationStart {
 
 impl RObject for UpdateFileGenerationStart {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFileGenerationStart" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2898,7 +3244,7 @@ impl UpdateFileGenerationStart {
     RTDUpdateFileGenerationStartBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn original_path(&self) -> &String { &self.original_path }
 
@@ -2916,8 +3262,13 @@ pub struct RTDUpdateFileGenerationStartBuilder {
 impl RTDUpdateFileGenerationStartBuilder {
   pub fn build(&self) -> UpdateFileGenerationStart { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
@@ -2962,13 +3313,17 @@ pub struct UpdateFileGenerationStop {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique identifier for the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] generation_id: i64,
   
 }
 
 impl RObject for UpdateFileGenerationStop {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFileGenerationStop" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2985,7 +3340,7 @@ impl UpdateFileGenerationStop {
     RTDUpdateFileGenerationStopBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
 }
 
@@ -2997,8 +3352,13 @@ pub struct RTDUpdateFileGenerationStopBuilder {
 impl RTDUpdateFileGenerationStopBuilder {
   pub fn build(&self) -> UpdateFileGenerationStop { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
@@ -3025,6 +3385,9 @@ pub struct UpdateHavePendingNotifications {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if there are some delayed notification updates, which will be sent soon
   have_delayed_notifications: bool,
   /// True, if there can be some yet unreceived notifications, which are being fetched from the server
@@ -3034,6 +3397,7 @@ pub struct UpdateHavePendingNotifications {
 
 impl RObject for UpdateHavePendingNotifications {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateHavePendingNotifications" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3064,6 +3428,11 @@ pub struct RTDUpdateHavePendingNotificationsBuilder {
 impl RTDUpdateHavePendingNotificationsBuilder {
   pub fn build(&self) -> UpdateHavePendingNotifications { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn have_delayed_notifications(&mut self, have_delayed_notifications: bool) -> &mut Self {
     self.inner.have_delayed_notifications = have_delayed_notifications;
@@ -3098,15 +3467,19 @@ pub struct UpdateInstalledStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the list of installed mask sticker sets was updated
   is_masks: bool,
   /// The new list of installed ordinary sticker sets
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64_str")] sticker_set_ids: Vec<i64>,
   
 }
 
 impl RObject for UpdateInstalledStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateInstalledStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3125,7 +3498,7 @@ impl UpdateInstalledStickerSets {
 
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -3137,6 +3510,11 @@ pub struct RTDUpdateInstalledStickerSetsBuilder {
 impl RTDUpdateInstalledStickerSetsBuilder {
   pub fn build(&self) -> UpdateInstalledStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -3144,7 +3522,7 @@ impl RTDUpdateInstalledStickerSetsBuilder {
   }
 
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -3171,6 +3549,9 @@ pub struct UpdateLanguagePackStrings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Localization target to which the language pack belongs
   localization_target: String,
   /// Identifier of the updated language pack
@@ -3182,6 +3563,7 @@ pub struct UpdateLanguagePackStrings {
 
 impl RObject for UpdateLanguagePackStrings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateLanguagePackStrings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3214,6 +3596,11 @@ pub struct RTDUpdateLanguagePackStringsBuilder {
 impl RTDUpdateLanguagePackStringsBuilder {
   pub fn build(&self) -> UpdateLanguagePackStrings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn localization_target<T: AsRef<str>>(&mut self, localization_target: T) -> &mut Self {
     self.inner.localization_target = localization_target.as_ref().to_string();
@@ -3254,6 +3641,9 @@ pub struct UpdateMessageContent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Message identifier
@@ -3265,6 +3655,7 @@ pub struct UpdateMessageContent {
 
 impl RObject for UpdateMessageContent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageContent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3297,6 +3688,11 @@ pub struct RTDUpdateMessageContentBuilder {
 impl RTDUpdateMessageContentBuilder {
   pub fn build(&self) -> UpdateMessageContent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3337,6 +3733,9 @@ pub struct UpdateMessageContentOpened {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Message identifier
@@ -3346,6 +3745,7 @@ pub struct UpdateMessageContentOpened {
 
 impl RObject for UpdateMessageContentOpened {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageContentOpened" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3376,6 +3776,11 @@ pub struct RTDUpdateMessageContentOpenedBuilder {
 impl RTDUpdateMessageContentOpenedBuilder {
   pub fn build(&self) -> UpdateMessageContentOpened { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3410,6 +3815,9 @@ pub struct UpdateMessageEdited {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Message identifier
@@ -3423,6 +3831,7 @@ pub struct UpdateMessageEdited {
 
 impl RObject for UpdateMessageEdited {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageEdited" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3457,6 +3866,11 @@ pub struct RTDUpdateMessageEditedBuilder {
 impl RTDUpdateMessageEditedBuilder {
   pub fn build(&self) -> UpdateMessageEdited { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3503,6 +3917,9 @@ pub struct UpdateMessageLiveLocationViewed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the chat with the live location message
   chat_id: i64,
   /// Identifier of the message with live location
@@ -3512,6 +3929,7 @@ pub struct UpdateMessageLiveLocationViewed {
 
 impl RObject for UpdateMessageLiveLocationViewed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageLiveLocationViewed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3542,6 +3960,11 @@ pub struct RTDUpdateMessageLiveLocationViewedBuilder {
 impl RTDUpdateMessageLiveLocationViewedBuilder {
   pub fn build(&self) -> UpdateMessageLiveLocationViewed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3576,6 +3999,9 @@ pub struct UpdateMessageMentionRead {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Message identifier
@@ -3587,6 +4013,7 @@ pub struct UpdateMessageMentionRead {
 
 impl RObject for UpdateMessageMentionRead {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageMentionRead" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3619,6 +4046,11 @@ pub struct RTDUpdateMessageMentionReadBuilder {
 impl RTDUpdateMessageMentionReadBuilder {
   pub fn build(&self) -> UpdateMessageMentionRead { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3659,6 +4091,9 @@ pub struct UpdateMessageSendAcknowledged {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat identifier of the sent message
   chat_id: i64,
   /// A temporary message identifier
@@ -3668,6 +4103,7 @@ pub struct UpdateMessageSendAcknowledged {
 
 impl RObject for UpdateMessageSendAcknowledged {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageSendAcknowledged" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3698,6 +4134,11 @@ pub struct RTDUpdateMessageSendAcknowledgedBuilder {
 impl RTDUpdateMessageSendAcknowledgedBuilder {
   pub fn build(&self) -> UpdateMessageSendAcknowledged { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3732,6 +4173,9 @@ pub struct UpdateMessageSendFailed {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Contains information about the message which failed to send
   message: Message,
   /// The previous temporary message identifier
@@ -3745,6 +4189,7 @@ pub struct UpdateMessageSendFailed {
 
 impl RObject for UpdateMessageSendFailed {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageSendFailed" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3779,6 +4224,11 @@ pub struct RTDUpdateMessageSendFailedBuilder {
 impl RTDUpdateMessageSendFailedBuilder {
   pub fn build(&self) -> UpdateMessageSendFailed { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -3825,6 +4275,9 @@ pub struct UpdateMessageSendSucceeded {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Information about the sent message. Usually only the message identifier, date, and content are changed, but almost all other fields can also change
   message: Message,
   /// The previous temporary message identifier
@@ -3834,6 +4287,7 @@ pub struct UpdateMessageSendSucceeded {
 
 impl RObject for UpdateMessageSendSucceeded {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageSendSucceeded" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3864,6 +4318,11 @@ pub struct RTDUpdateMessageSendSucceededBuilder {
 impl RTDUpdateMessageSendSucceededBuilder {
   pub fn build(&self) -> UpdateMessageSendSucceeded { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -3898,6 +4357,9 @@ pub struct UpdateMessageViews {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Message identifier
@@ -3909,6 +4371,7 @@ pub struct UpdateMessageViews {
 
 impl RObject for UpdateMessageViews {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageViews" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3941,6 +4404,11 @@ pub struct RTDUpdateMessageViewsBuilder {
 impl RTDUpdateMessageViewsBuilder {
   pub fn build(&self) -> UpdateMessageViews { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -3981,15 +4449,19 @@ pub struct UpdateNewCallSignalingData {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The call identifier
   call_id: i64,
   /// The data
-  data: String,
+  data: TdBytes,
   
 }
 
 impl RObject for UpdateNewCallSignalingData {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCallSignalingData" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4008,7 +4480,7 @@ impl UpdateNewCallSignalingData {
 
   pub fn call_id(&self) -> i64 { self.call_id }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &TdBytes { &self.data }
 
 }
 
@@ -4020,6 +4492,11 @@ pub struct RTDUpdateNewCallSignalingDataBuilder {
 impl RTDUpdateNewCallSignalingDataBuilder {
   pub fn build(&self) -> UpdateNewCallSignalingData { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -4027,8 +4504,8 @@ impl RTDUpdateNewCallSignalingDataBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = TdBytes::from(data.as_ref());
     self
   }
 
@@ -4054,8 +4531,11 @@ pub struct UpdateNewCallbackQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Identifier of the chat where the query was sent
@@ -4063,7 +4543,7 @@ pub struct UpdateNewCallbackQuery {
   /// Identifier of the message, from which the query originated
   message_id: i64,
   /// Identifier that uniquely corresponds to the chat to which the message was sent
-  chat_instance: isize,
+  #[serde(with = "crate::types::_common::int64_str")] chat_instance: i64,
   /// Query payload
   payload: CallbackQueryPayload,
   
@@ -4071,6 +4551,7 @@ pub struct UpdateNewCallbackQuery {
 
 impl RObject for UpdateNewCallbackQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCallbackQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4087,7 +4568,7 @@ impl UpdateNewCallbackQuery {
     RTDUpdateNewCallbackQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -4095,7 +4576,7 @@ impl UpdateNewCallbackQuery {
 
   pub fn message_id(&self) -> i64 { self.message_id }
 
-  pub fn chat_instance(&self) -> isize { self.chat_instance }
+  pub fn chat_instance(&self) -> i64 { self.chat_instance }
 
   pub fn payload(&self) -> &CallbackQueryPayload { &self.payload }
 
@@ -4109,8 +4590,13 @@ pub struct RTDUpdateNewCallbackQueryBuilder {
 impl RTDUpdateNewCallbackQueryBuilder {
   pub fn build(&self) -> UpdateNewCallbackQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -4134,7 +4620,7 @@ impl RTDUpdateNewCallbackQueryBuilder {
   }
 
    
-  pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+  pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
     self.inner.chat_instance = chat_instance;
     self
   }
@@ -4167,6 +4653,9 @@ pub struct UpdateNewChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat
   chat: Chat,
   
@@ -4174,6 +4663,7 @@ pub struct UpdateNewChat {
 
 impl RObject for UpdateNewChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4202,6 +4692,11 @@ pub struct RTDUpdateNewChatBuilder {
 impl RTDUpdateNewChatBuilder {
   pub fn build(&self) -> UpdateNewChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat<T: AsRef<Chat>>(&mut self, chat: T) -> &mut Self {
     self.inner.chat = chat.as_ref().clone();
@@ -4230,6 +4725,9 @@ pub struct UpdateNewChosenInlineResult {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// User location; may be null
@@ -4245,6 +4743,7 @@ pub struct UpdateNewChosenInlineResult {
 
 impl RObject for UpdateNewChosenInlineResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewChosenInlineResult" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4281,6 +4780,11 @@ pub struct RTDUpdateNewChosenInlineResultBuilder {
 impl RTDUpdateNewChosenInlineResultBuilder {
   pub fn build(&self) -> UpdateNewChosenInlineResult { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
@@ -4333,6 +4837,9 @@ pub struct UpdateNewCustomEvent {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A JSON-serialized event
   event: String,
   
@@ -4340,6 +4847,7 @@ pub struct UpdateNewCustomEvent {
 
 impl RObject for UpdateNewCustomEvent {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCustomEvent" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4368,6 +4876,11 @@ pub struct RTDUpdateNewCustomEventBuilder {
 impl RTDUpdateNewCustomEventBuilder {
   pub fn build(&self) -> UpdateNewCustomEvent { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn event<T: AsRef<str>>(&mut self, event: T) -> &mut Self {
     self.inner.event = event.as_ref().to_string();
@@ -4396,8 +4909,11 @@ pub struct UpdateNewCustomQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// JSON-serialized query data
   data: String,
   /// Query timeout
@@ -4407,6 +4923,7 @@ pub struct UpdateNewCustomQuery {
 
 impl RObject for UpdateNewCustomQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCustomQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4423,7 +4940,7 @@ impl UpdateNewCustomQuery {
     RTDUpdateNewCustomQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn data(&self) -> &String { &self.data }
 
@@ -4439,8 +4956,13 @@ pub struct RTDUpdateNewCustomQueryBuilder {
 impl RTDUpdateNewCustomQueryBuilder {
   pub fn build(&self) -> UpdateNewCustomQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -4479,14 +5001,17 @@ pub struct UpdateNewInlineCallbackQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Identifier of the inline message, from which the query originated
   inline_message_id: String,
   /// An identifier uniquely corresponding to the chat a message was sent to
-  chat_instance: isize,
+  #[serde(with = "crate::types::_common::int64_str")] chat_instance: i64,
   /// Query payload
   payload: CallbackQueryPayload,
   
@@ -4494,6 +5019,7 @@ pub struct UpdateNewInlineCallbackQuery {
 
 impl RObject for UpdateNewInlineCallbackQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewInlineCallbackQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4510,13 +5036,13 @@ impl UpdateNewInlineCallbackQuery {
     RTDUpdateNewInlineCallbackQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
 
-  pub fn chat_instance(&self) -> isize { self.chat_instance }
+  pub fn chat_instance(&self) -> i64 { self.chat_instance }
 
   pub fn payload(&self) -> &CallbackQueryPayload { &self.payload }
 
@@ -4530,8 +5056,13 @@ pub struct RTDUpdateNewInlineCallbackQueryBuilder {
 impl RTDUpdateNewInlineCallbackQueryBuilder {
   pub fn build(&self) -> UpdateNewInlineCallbackQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -4549,7 +5080,7 @@ impl RTDUpdateNewInlineCallbackQueryBuilder {
   }
 
    
-  pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+  pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
     self.inner.chat_instance = chat_instance;
     self
   }
@@ -4582,8 +5113,11 @@ pub struct UpdateNewInlineQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// User location; may be null
@@ -4597,6 +5131,7 @@ pub struct UpdateNewInlineQuery {
 
 impl RObject for UpdateNewInlineQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewInlineQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4613,7 +5148,7 @@ impl UpdateNewInlineQuery {
     RTDUpdateNewInlineQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -4633,8 +5168,13 @@ pub struct RTDUpdateNewInlineQueryBuilder {
 impl RTDUpdateNewInlineQueryBuilder {
   pub fn build(&self) -> UpdateNewInlineQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -4685,6 +5225,9 @@ pub struct UpdateNewMessage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new message
   message: Message,
   
@@ -4692,6 +5235,7 @@ pub struct UpdateNewMessage {
 
 impl RObject for UpdateNewMessage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewMessage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4720,6 +5264,11 @@ pub struct RTDUpdateNewMessageBuilder {
 impl RTDUpdateNewMessageBuilder {
   pub fn build(&self) -> UpdateNewMessage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -4748,8 +5297,11 @@ pub struct UpdateNewPreCheckoutQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Currency for the product price
@@ -4757,7 +5309,7 @@ pub struct UpdateNewPreCheckoutQuery {
   /// Total price for the product, in the minimal quantity of the currency
   total_amount: i64,
   /// Invoice payload
-  invoice_payload: String,
+  invoice_payload: TdBytes,
   /// Identifier of a shipping option chosen by the user; may be empty if not applicable
   shipping_option_id: String,
   /// Information about the order; may be null
@@ -4767,6 +5319,7 @@ pub struct UpdateNewPreCheckoutQuery {
 
 impl RObject for UpdateNewPreCheckoutQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewPreCheckoutQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4783,7 +5336,7 @@ impl UpdateNewPreCheckoutQuery {
     RTDUpdateNewPreCheckoutQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -4791,7 +5344,7 @@ impl UpdateNewPreCheckoutQuery {
 
   pub fn total_amount(&self) -> i64 { self.total_amount }
 
-  pub fn invoice_payload(&self) -> &String { &self.invoice_payload }
+  pub fn invoice_payload(&self) -> &TdBytes { &self.invoice_payload }
 
   pub fn shipping_option_id(&self) -> &String { &self.shipping_option_id }
 
@@ -4807,8 +5360,13 @@ pub struct RTDUpdateNewPreCheckoutQueryBuilder {
 impl RTDUpdateNewPreCheckoutQueryBuilder {
   pub fn build(&self) -> UpdateNewPreCheckoutQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -4832,8 +5390,8 @@ impl RTDUpdateNewPreCheckoutQueryBuilder {
   }
 
    
-  pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-    self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+  pub fn invoice_payload<T: AsRef<[u8]>>(&mut self, invoice_payload: T) -> &mut Self {
+    self.inner.invoice_payload = TdBytes::from(invoice_payload.as_ref());
     self
   }
 
@@ -4871,8 +5429,11 @@ pub struct UpdateNewShippingQuery {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Invoice payload
@@ -4884,6 +5445,7 @@ pub struct UpdateNewShippingQuery {
 
 impl RObject for UpdateNewShippingQuery {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewShippingQuery" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4900,7 +5462,7 @@ impl UpdateNewShippingQuery {
     RTDUpdateNewShippingQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -4918,8 +5480,13 @@ pub struct RTDUpdateNewShippingQueryBuilder {
 impl RTDUpdateNewShippingQueryBuilder {
   pub fn build(&self) -> UpdateNewShippingQuery { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -4964,6 +5531,9 @@ pub struct UpdateNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique notification group identifier
   notification_group_id: i64,
   /// Changed notification
@@ -4973,6 +5543,7 @@ pub struct UpdateNotification {
 
 impl RObject for UpdateNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5003,6 +5574,11 @@ pub struct RTDUpdateNotificationBuilder {
 impl RTDUpdateNotificationBuilder {
   pub fn build(&self) -> UpdateNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
@@ -5037,6 +5613,9 @@ pub struct UpdateNotificationGroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique notification group identifier
   notification_group_id: i64,
   /// New type of the notification group
@@ -5058,6 +5637,7 @@ pub struct UpdateNotificationGroup {
 
 impl RObject for UpdateNotificationGroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNotificationGroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5100,6 +5680,11 @@ pub struct RTDUpdateNotificationGroupBuilder {
 impl RTDUpdateNotificationGroupBuilder {
   pub fn build(&self) -> UpdateNotificationGroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
@@ -5170,6 +5755,9 @@ pub struct UpdateOption {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The option name
   name: String,
   /// The new option value
@@ -5179,6 +5767,7 @@ pub struct UpdateOption {
 
 impl RObject for UpdateOption {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateOption" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5209,6 +5798,11 @@ pub struct RTDUpdateOptionBuilder {
 impl RTDUpdateOptionBuilder {
   pub fn build(&self) -> UpdateOption { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -5243,6 +5837,9 @@ pub struct UpdatePoll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about the poll
   poll: Poll,
   
@@ -5250,6 +5847,7 @@ pub struct UpdatePoll {
 
 impl RObject for UpdatePoll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updatePoll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5278,6 +5876,11 @@ pub struct RTDUpdatePollBuilder {
 impl RTDUpdatePollBuilder {
   pub fn build(&self) -> UpdatePoll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
     self.inner.poll = poll.as_ref().clone();
@@ -5306,8 +5909,11 @@ pub struct UpdatePollAnswer {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Unique poll identifier
-  poll_id: isize,
+  #[serde(with = "crate::types::_common::int64_str")] poll_id: i64,
   /// The user, who changed the answer to the poll
   user_id: i64,
   /// 0-based identifiers of answer options, chosen by the user
@@ -5317,6 +5923,7 @@ pub struct UpdatePollAnswer {
 
 impl RObject for UpdatePollAnswer {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updatePollAnswer" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5333,7 +5940,7 @@ impl UpdatePollAnswer {
     RTDUpdatePollAnswerBuilder { inner }
   }
 
-  pub fn poll_id(&self) -> isize { self.poll_id }
+  pub fn poll_id(&self) -> i64 { self.poll_id }
 
   pub fn user_id(&self) -> i64 { self.user_id }
 
@@ -5349,8 +5956,13 @@ pub struct RTDUpdatePollAnswerBuilder {
 impl RTDUpdatePollAnswerBuilder {
   pub fn build(&self) -> UpdatePollAnswer { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn poll_id(&mut self, poll_id: isize) -> &mut Self {
+  pub fn poll_id(&mut self, poll_id: i64) -> &mut Self {
     self.inner.poll_id = poll_id;
     self
   }
@@ -5389,6 +6001,9 @@ pub struct UpdateRecentStickers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the list of stickers attached to photo or video files was updated, otherwise the list of sent stickers is updated
   is_attached: bool,
   /// The new list of file identifiers of recently used stickers
@@ -5398,6 +6013,7 @@ pub struct UpdateRecentStickers {
 
 impl RObject for UpdateRecentStickers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateRecentStickers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5428,6 +6044,11 @@ pub struct RTDUpdateRecentStickersBuilder {
 impl RTDUpdateRecentStickersBuilder {
   pub fn build(&self) -> UpdateRecentStickers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -5462,6 +6083,9 @@ pub struct UpdateSavedAnimations {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new list of file identifiers of saved animations
   animation_ids: Vec<i64>,
   
@@ -5469,6 +6093,7 @@ pub struct UpdateSavedAnimations {
 
 impl RObject for UpdateSavedAnimations {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSavedAnimations" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5497,6 +6122,11 @@ pub struct RTDUpdateSavedAnimationsBuilder {
 impl RTDUpdateSavedAnimationsBuilder {
   pub fn build(&self) -> UpdateSavedAnimations { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation_ids(&mut self, animation_ids: Vec<i64>) -> &mut Self {
     self.inner.animation_ids = animation_ids;
@@ -5525,6 +6155,9 @@ pub struct UpdateScopeNotificationSettings {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Types of chats for which notification settings were updated
   scope: NotificationSettingsScope,
   /// The new notification settings
@@ -5534,6 +6167,7 @@ pub struct UpdateScopeNotificationSettings {
 
 impl RObject for UpdateScopeNotificationSettings {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateScopeNotificationSettings" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5564,6 +6198,11 @@ pub struct RTDUpdateScopeNotificationSettingsBuilder {
 impl RTDUpdateScopeNotificationSettingsBuilder {
   pub fn build(&self) -> UpdateScopeNotificationSettings { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -5598,6 +6237,9 @@ pub struct UpdateSecretChat {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about the secret chat
   secret_chat: SecretChat,
   
@@ -5605,6 +6247,7 @@ pub struct UpdateSecretChat {
 
 impl RObject for UpdateSecretChat {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSecretChat" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5633,6 +6276,11 @@ pub struct RTDUpdateSecretChatBuilder {
 impl RTDUpdateSecretChatBuilder {
   pub fn build(&self) -> UpdateSecretChat { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret_chat<T: AsRef<SecretChat>>(&mut self, secret_chat: T) -> &mut Self {
     self.inner.secret_chat = secret_chat.as_ref().clone();
@@ -5661,6 +6309,9 @@ pub struct UpdateSelectedBackground {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if background for dark theme has changed
   for_dark_theme: bool,
   /// The new selected background; may be null
@@ -5670,6 +6321,7 @@ pub struct UpdateSelectedBackground {
 
 impl RObject for UpdateSelectedBackground {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSelectedBackground" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5700,6 +6352,11 @@ pub struct RTDUpdateSelectedBackgroundBuilder {
 impl RTDUpdateSelectedBackgroundBuilder {
   pub fn build(&self) -> UpdateSelectedBackground { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn for_dark_theme(&mut self, for_dark_theme: bool) -> &mut Self {
     self.inner.for_dark_theme = for_dark_theme;
@@ -5734,6 +6391,9 @@ pub struct UpdateServiceNotification {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Notification type. If type begins with "AUTH_KEY_DROP_", then two buttons "Cancel" and "Log out" should be shown under notification; if user presses the second, all local data should be destroyed using Destroy method
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// Notification content
@@ -5743,6 +6403,7 @@ pub struct UpdateServiceNotification {
 
 impl RObject for UpdateServiceNotification {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateServiceNotification" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5773,6 +6434,11 @@ pub struct RTDUpdateServiceNotificationBuilder {
 impl RTDUpdateServiceNotificationBuilder {
   pub fn build(&self) -> UpdateServiceNotification { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -5807,6 +6473,9 @@ pub struct UpdateStickerSet {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The sticker set
   sticker_set: StickerSet,
   
@@ -5814,6 +6483,7 @@ pub struct UpdateStickerSet {
 
 impl RObject for UpdateStickerSet {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateStickerSet" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5842,6 +6512,11 @@ pub struct RTDUpdateStickerSetBuilder {
 impl RTDUpdateStickerSetBuilder {
   pub fn build(&self) -> UpdateStickerSet { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_set<T: AsRef<StickerSet>>(&mut self, sticker_set: T) -> &mut Self {
     self.inner.sticker_set = sticker_set.as_ref().clone();
@@ -5870,6 +6545,9 @@ pub struct UpdateSuggestedActions {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Added suggested actions
   added_actions: Vec<SuggestedAction>,
   /// Removed suggested actions
@@ -5879,6 +6557,7 @@ pub struct UpdateSuggestedActions {
 
 impl RObject for UpdateSuggestedActions {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSuggestedActions" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5909,6 +6588,11 @@ pub struct RTDUpdateSuggestedActionsBuilder {
 impl RTDUpdateSuggestedActionsBuilder {
   pub fn build(&self) -> UpdateSuggestedActions { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn added_actions(&mut self, added_actions: Vec<SuggestedAction>) -> &mut Self {
     self.inner.added_actions = added_actions;
@@ -5943,6 +6627,9 @@ pub struct UpdateSupergroup {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about the supergroup
   supergroup: Supergroup,
   
@@ -5950,6 +6637,7 @@ pub struct UpdateSupergroup {
 
 impl RObject for UpdateSupergroup {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSupergroup" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5978,6 +6666,11 @@ pub struct RTDUpdateSupergroupBuilder {
 impl RTDUpdateSupergroupBuilder {
   pub fn build(&self) -> UpdateSupergroup { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup<T: AsRef<Supergroup>>(&mut self, supergroup: T) -> &mut Self {
     self.inner.supergroup = supergroup.as_ref().clone();
@@ -6006,6 +6699,9 @@ pub struct UpdateSupergroupFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the supergroup or channel
   supergroup_id: i64,
   /// New full information about the supergroup
@@ -6015,6 +6711,7 @@ pub struct UpdateSupergroupFullInfo {
 
 impl RObject for UpdateSupergroupFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSupergroupFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6045,6 +6742,11 @@ pub struct RTDUpdateSupergroupFullInfoBuilder {
 impl RTDUpdateSupergroupFullInfoBuilder {
   pub fn build(&self) -> UpdateSupergroupFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
@@ -6079,6 +6781,9 @@ pub struct UpdateTermsOfService {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Identifier of the terms of service
   terms_of_service_id: String,
   /// The new terms of service
@@ -6088,6 +6793,7 @@ pub struct UpdateTermsOfService {
 
 impl RObject for UpdateTermsOfService {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateTermsOfService" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6118,6 +6824,11 @@ pub struct RTDUpdateTermsOfServiceBuilder {
 impl RTDUpdateTermsOfServiceBuilder {
   pub fn build(&self) -> UpdateTermsOfService { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn terms_of_service_id<T: AsRef<str>>(&mut self, terms_of_service_id: T) -> &mut Self {
     self.inner.terms_of_service_id = terms_of_service_id.as_ref().to_string();
@@ -6152,6 +6863,9 @@ pub struct UpdateTrendingStickerSets {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The prefix of the list of trending sticker sets with the newest trending sticker sets
   sticker_sets: StickerSets,
   
@@ -6159,6 +6873,7 @@ pub struct UpdateTrendingStickerSets {
 
 impl RObject for UpdateTrendingStickerSets {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateTrendingStickerSets" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6187,6 +6902,11 @@ pub struct RTDUpdateTrendingStickerSetsBuilder {
 impl RTDUpdateTrendingStickerSetsBuilder {
   pub fn build(&self) -> UpdateTrendingStickerSets { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_sets<T: AsRef<StickerSets>>(&mut self, sticker_sets: T) -> &mut Self {
     self.inner.sticker_sets = sticker_sets.as_ref().clone();
@@ -6215,6 +6935,9 @@ pub struct UpdateUnreadChatCount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat list with changed number of unread messages
   chat_list: ChatList,
   /// Approximate total number of chats in the chat list
@@ -6232,6 +6955,7 @@ pub struct UpdateUnreadChatCount {
 
 impl RObject for UpdateUnreadChatCount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUnreadChatCount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6270,6 +6994,11 @@ pub struct RTDUpdateUnreadChatCountBuilder {
 impl RTDUpdateUnreadChatCountBuilder {
   pub fn build(&self) -> UpdateUnreadChatCount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -6328,6 +7057,9 @@ pub struct UpdateUnreadMessageCount {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat list with changed number of unread messages
   chat_list: ChatList,
   /// Total number of unread messages
@@ -6339,6 +7071,7 @@ pub struct UpdateUnreadMessageCount {
 
 impl RObject for UpdateUnreadMessageCount {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUnreadMessageCount" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6371,6 +7104,11 @@ pub struct RTDUpdateUnreadMessageCountBuilder {
 impl RTDUpdateUnreadMessageCountBuilder {
   pub fn build(&self) -> UpdateUnreadMessageCount { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -6411,6 +7149,9 @@ pub struct UpdateUser {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// New data about the user
   user: User,
   
@@ -6418,6 +7159,7 @@ pub struct UpdateUser {
 
 impl RObject for UpdateUser {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUser" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6446,6 +7188,11 @@ pub struct RTDUpdateUserBuilder {
 impl RTDUpdateUserBuilder {
   pub fn build(&self) -> UpdateUser { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user<T: AsRef<User>>(&mut self, user: T) -> &mut Self {
     self.inner.user = user.as_ref().clone();
@@ -6474,6 +7221,9 @@ pub struct UpdateUserChatAction {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Chat identifier
   chat_id: i64,
   /// Identifier of a user performing an action
@@ -6485,6 +7235,7 @@ pub struct UpdateUserChatAction {
 
 impl RObject for UpdateUserChatAction {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserChatAction" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6517,6 +7268,11 @@ pub struct RTDUpdateUserChatActionBuilder {
 impl RTDUpdateUserChatActionBuilder {
   pub fn build(&self) -> UpdateUserChatAction { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -6557,6 +7313,9 @@ pub struct UpdateUserFullInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// New full information about the user
@@ -6566,6 +7325,7 @@ pub struct UpdateUserFullInfo {
 
 impl RObject for UpdateUserFullInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserFullInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6596,6 +7356,11 @@ pub struct RTDUpdateUserFullInfoBuilder {
 impl RTDUpdateUserFullInfoBuilder {
   pub fn build(&self) -> UpdateUserFullInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -6630,6 +7395,9 @@ pub struct UpdateUserPrivacySettingRules {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The privacy setting
   setting: UserPrivacySetting,
   /// New privacy rules
@@ -6639,6 +7407,7 @@ pub struct UpdateUserPrivacySettingRules {
 
 impl RObject for UpdateUserPrivacySettingRules {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserPrivacySettingRules" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6669,6 +7438,11 @@ pub struct RTDUpdateUserPrivacySettingRulesBuilder {
 impl RTDUpdateUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> UpdateUserPrivacySettingRules { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -6703,6 +7477,9 @@ pub struct UpdateUserStatus {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   user_id: i64,
   /// New status of the user
@@ -6712,6 +7489,7 @@ pub struct UpdateUserStatus {
 
 impl RObject for UpdateUserStatus {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserStatus" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6742,6 +7520,11 @@ pub struct RTDUpdateUserStatusBuilder {
 impl RTDUpdateUserStatusBuilder {
   pub fn build(&self) -> UpdateUserStatus { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
@@ -6776,6 +7559,9 @@ pub struct UpdateUsersNearby {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The new list of users nearby
   users_nearby: Vec<ChatNearby>,
   
@@ -6783,6 +7569,7 @@ pub struct UpdateUsersNearby {
 
 impl RObject for UpdateUsersNearby {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUsersNearby" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6811,6 +7598,11 @@ pub struct RTDUpdateUsersNearbyBuilder {
 impl RTDUpdateUsersNearbyBuilder {
   pub fn build(&self) -> UpdateUsersNearby { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
     self.inner.users_nearby = users_nearby;
@@ -11,6 +11,9 @@ pub struct User {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// User identifier
   id: i64,
   /// First name of the user
@@ -48,6 +51,7 @@ pub struct User {
 
 impl RObject for User {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "user" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -103,6 +107,11 @@ pub struct RTDUserBuilder {
 impl RTDUserBuilder {
   pub fn build(&self) -> User { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
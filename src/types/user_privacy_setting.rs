@@ -18,6 +18,9 @@ pub trait TDUserPrivacySetting: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserPrivacySetting {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A privacy setting for managing whether the user can be called
   AllowCalls(UserPrivacySettingAllowCalls),
   /// A privacy setting for managing whether the user can be invited to chats
@@ -74,12 +77,28 @@ impl RObject for UserPrivacySetting {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      UserPrivacySetting::AllowCalls(t) => t.extra(),
+      UserPrivacySetting::AllowChatInvites(t) => t.extra(),
+      UserPrivacySetting::AllowFindingByPhoneNumber(t) => t.extra(),
+      UserPrivacySetting::AllowPeerToPeerCalls(t) => t.extra(),
+      UserPrivacySetting::ShowLinkInForwardedMessages(t) => t.extra(),
+      UserPrivacySetting::ShowPhoneNumber(t) => t.extra(),
+      UserPrivacySetting::ShowProfilePhoto(t) => t.extra(),
+      UserPrivacySetting::ShowStatus(t) => t.extra(),
+
+      UserPrivacySetting::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl UserPrivacySetting {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserPrivacySetting::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let UserPrivacySetting::Unknown { .. } = self { true } else { false } }
 
   pub fn is_allow_calls(&self) -> bool { if let UserPrivacySetting::AllowCalls(_) = self { true } else { false } }
   pub fn is_allow_chat_invites(&self) -> bool { if let UserPrivacySetting::AllowChatInvites(_) = self { true } else { false } }
@@ -144,11 +163,15 @@ pub struct UserPrivacySettingAllowCalls {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingAllowCalls {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowCalls" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -175,6 +198,11 @@ pub struct RTDUserPrivacySettingAllowCallsBuilder {
 impl RTDUserPrivacySettingAllowCallsBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowCalls { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowCalls> for UserPrivacySettingAllowCalls {
@@ -197,11 +225,15 @@ pub struct UserPrivacySettingAllowChatInvites {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingAllowChatInvites {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowChatInvites" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -228,6 +260,11 @@ pub struct RTDUserPrivacySettingAllowChatInvitesBuilder {
 impl RTDUserPrivacySettingAllowChatInvitesBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowChatInvites { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowChatInvites> for UserPrivacySettingAllowChatInvites {
@@ -250,11 +287,15 @@ pub struct UserPrivacySettingAllowFindingByPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingAllowFindingByPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowFindingByPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -281,6 +322,11 @@ pub struct RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
 impl RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowFindingByPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowFindingByPhoneNumber> for UserPrivacySettingAllowFindingByPhoneNumber {
@@ -303,11 +349,15 @@ pub struct UserPrivacySettingAllowPeerToPeerCalls {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingAllowPeerToPeerCalls {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowPeerToPeerCalls" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -334,6 +384,11 @@ pub struct RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
 impl RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowPeerToPeerCalls { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowPeerToPeerCalls> for UserPrivacySettingAllowPeerToPeerCalls {
@@ -356,11 +411,15 @@ pub struct UserPrivacySettingShowLinkInForwardedMessages {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingShowLinkInForwardedMessages {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowLinkInForwardedMessages" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -387,6 +446,11 @@ pub struct RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
 impl RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
   pub fn build(&self) -> UserPrivacySettingShowLinkInForwardedMessages { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowLinkInForwardedMessages> for UserPrivacySettingShowLinkInForwardedMessages {
@@ -409,11 +473,15 @@ pub struct UserPrivacySettingShowPhoneNumber {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingShowPhoneNumber {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowPhoneNumber" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -440,6 +508,11 @@ pub struct RTDUserPrivacySettingShowPhoneNumberBuilder {
 impl RTDUserPrivacySettingShowPhoneNumberBuilder {
   pub fn build(&self) -> UserPrivacySettingShowPhoneNumber { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowPhoneNumber> for UserPrivacySettingShowPhoneNumber {
@@ -462,11 +535,15 @@ pub struct UserPrivacySettingShowProfilePhoto {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingShowProfilePhoto {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowProfilePhoto" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -493,6 +570,11 @@ pub struct RTDUserPrivacySettingShowProfilePhotoBuilder {
 impl RTDUserPrivacySettingShowProfilePhotoBuilder {
   pub fn build(&self) -> UserPrivacySettingShowProfilePhoto { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowProfilePhoto> for UserPrivacySettingShowProfilePhoto {
@@ -515,11 +597,15 @@ pub struct UserPrivacySettingShowStatus {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingShowStatus {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowStatus" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -546,6 +632,11 @@ pub struct RTDUserPrivacySettingShowStatusBuilder {
 impl RTDUserPrivacySettingShowStatusBuilder {
   pub fn build(&self) -> UserPrivacySettingShowStatus { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowStatus> for UserPrivacySettingShowStatus {
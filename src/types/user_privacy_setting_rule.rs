@@ -18,6 +18,9 @@ pub trait TDUserPrivacySettingRule: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserPrivacySettingRule {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A rule to allow all users to do something
   AllowAll(UserPrivacySettingRuleAllowAll),
   /// A rule to allow all members of certain specified basic groups and supergroups to doing something
@@ -74,12 +77,28 @@ impl RObject for UserPrivacySettingRule {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      UserPrivacySettingRule::AllowAll(t) => t.extra(),
+      UserPrivacySettingRule::AllowChatMembers(t) => t.extra(),
+      UserPrivacySettingRule::AllowContacts(t) => t.extra(),
+      UserPrivacySettingRule::AllowUsers(t) => t.extra(),
+      UserPrivacySettingRule::RestrictAll(t) => t.extra(),
+      UserPrivacySettingRule::RestrictChatMembers(t) => t.extra(),
+      UserPrivacySettingRule::RestrictContacts(t) => t.extra(),
+      UserPrivacySettingRule::RestrictUsers(t) => t.extra(),
+
+      UserPrivacySettingRule::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl UserPrivacySettingRule {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserPrivacySettingRule::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let UserPrivacySettingRule::Unknown { .. } = self { true } else { false } }
 
   pub fn is_allow_all(&self) -> bool { if let UserPrivacySettingRule::AllowAll(_) = self { true } else { false } }
   pub fn is_allow_chat_members(&self) -> bool { if let UserPrivacySettingRule::AllowChatMembers(_) = self { true } else { false } }
@@ -144,11 +163,15 @@ pub struct UserPrivacySettingRuleAllowAll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingRuleAllowAll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowAll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -175,6 +198,11 @@ pub struct RTDUserPrivacySettingRuleAllowAllBuilder {
 impl RTDUserPrivacySettingRuleAllowAllBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowAll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleAllowAll> for UserPrivacySettingRuleAllowAll {
@@ -197,6 +225,9 @@ pub struct UserPrivacySettingRuleAllowChatMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat identifiers, total number of chats in all rules must not exceed 20
   chat_ids: Vec<i64>,
   
@@ -204,6 +235,7 @@ pub struct UserPrivacySettingRuleAllowChatMembers {
 
 impl RObject for UserPrivacySettingRuleAllowChatMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowChatMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -232,6 +264,11 @@ pub struct RTDUserPrivacySettingRuleAllowChatMembersBuilder {
 impl RTDUserPrivacySettingRuleAllowChatMembersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowChatMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
@@ -260,11 +297,15 @@ pub struct UserPrivacySettingRuleAllowContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingRuleAllowContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -291,6 +332,11 @@ pub struct RTDUserPrivacySettingRuleAllowContactsBuilder {
 impl RTDUserPrivacySettingRuleAllowContactsBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleAllowContacts> for UserPrivacySettingRuleAllowContacts {
@@ -313,6 +359,9 @@ pub struct UserPrivacySettingRuleAllowUsers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The user identifiers, total number of users in all rules must not exceed 1000
   user_ids: Vec<i64>,
   
@@ -320,6 +369,7 @@ pub struct UserPrivacySettingRuleAllowUsers {
 
 impl RObject for UserPrivacySettingRuleAllowUsers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowUsers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -348,6 +398,11 @@ pub struct RTDUserPrivacySettingRuleAllowUsersBuilder {
 impl RTDUserPrivacySettingRuleAllowUsersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowUsers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -376,11 +431,15 @@ pub struct UserPrivacySettingRuleRestrictAll {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingRuleRestrictAll {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictAll" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -407,6 +466,11 @@ pub struct RTDUserPrivacySettingRuleRestrictAllBuilder {
 impl RTDUserPrivacySettingRuleRestrictAllBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictAll { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictAll> for UserPrivacySettingRuleRestrictAll {
@@ -429,6 +493,9 @@ pub struct UserPrivacySettingRuleRestrictChatMembers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The chat identifiers, total number of chats in all rules must not exceed 20
   chat_ids: Vec<i64>,
   
@@ -436,6 +503,7 @@ pub struct UserPrivacySettingRuleRestrictChatMembers {
 
 impl RObject for UserPrivacySettingRuleRestrictChatMembers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictChatMembers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -464,6 +532,11 @@ pub struct RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
 impl RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictChatMembers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
@@ -492,11 +565,15 @@ pub struct UserPrivacySettingRuleRestrictContacts {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserPrivacySettingRuleRestrictContacts {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictContacts" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -523,6 +600,11 @@ pub struct RTDUserPrivacySettingRuleRestrictContactsBuilder {
 impl RTDUserPrivacySettingRuleRestrictContactsBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictContacts { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictContacts> for UserPrivacySettingRuleRestrictContacts {
@@ -545,6 +627,9 @@ pub struct UserPrivacySettingRuleRestrictUsers {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// The user identifiers, total number of users in all rules must not exceed 1000
   user_ids: Vec<i64>,
   
@@ -552,6 +637,7 @@ pub struct UserPrivacySettingRuleRestrictUsers {
 
 impl RObject for UserPrivacySettingRuleRestrictUsers {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictUsers" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -580,6 +666,11 @@ pub struct RTDUserPrivacySettingRuleRestrictUsersBuilder {
 impl RTDUserPrivacySettingRuleRestrictUsersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictUsers { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -11,6 +11,9 @@ pub struct UserPrivacySettingRules {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// A list of rules
   rules: Vec<UserPrivacySettingRule>,
   
@@ -18,6 +21,7 @@ pub struct UserPrivacySettingRules {
 
 impl RObject for UserPrivacySettingRules {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRules" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -43,6 +47,11 @@ pub struct RTDUserPrivacySettingRulesBuilder {
 impl RTDUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> UserPrivacySettingRules { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rules(&mut self, rules: Vec<UserPrivacySettingRule>) -> &mut Self {
     self.inner.rules = rules;
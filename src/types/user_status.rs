@@ -18,6 +18,9 @@ pub trait TDUserStatus: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserStatus {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The user status was never changed
   Empty(UserStatusEmpty),
   /// The user is offline, but was online last month
@@ -66,12 +69,26 @@ impl RObject for UserStatus {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      UserStatus::Empty(t) => t.extra(),
+      UserStatus::LastMonth(t) => t.extra(),
+      UserStatus::LastWeek(t) => t.extra(),
+      UserStatus::Offline(t) => t.extra(),
+      UserStatus::Online(t) => t.extra(),
+      UserStatus::Recently(t) => t.extra(),
+
+      UserStatus::Unknown { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl UserStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserStatus::_Default(_) = self { true } else { false } }
+  pub fn is_unknown(&self) -> bool { if let UserStatus::Unknown { .. } = self { true } else { false } }
 
   pub fn is_empty(&self) -> bool { if let UserStatus::Empty(_) = self { true } else { false } }
   pub fn is_last_month(&self) -> bool { if let UserStatus::LastMonth(_) = self { true } else { false } }
@@ -126,11 +143,15 @@ pub struct UserStatusEmpty {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserStatusEmpty {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusEmpty" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,6 +178,11 @@ pub struct RTDUserStatusEmptyBuilder {
 impl RTDUserStatusEmptyBuilder {
   pub fn build(&self) -> UserStatusEmpty { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusEmpty> for UserStatusEmpty {
@@ -179,11 +205,15 @@ pub struct UserStatusLastMonth {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserStatusLastMonth {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusLastMonth" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -210,6 +240,11 @@ pub struct RTDUserStatusLastMonthBuilder {
 impl RTDUserStatusLastMonthBuilder {
   pub fn build(&self) -> UserStatusLastMonth { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusLastMonth> for UserStatusLastMonth {
@@ -232,11 +267,15 @@ pub struct UserStatusLastWeek {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserStatusLastWeek {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusLastWeek" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,6 +302,11 @@ pub struct RTDUserStatusLastWeekBuilder {
 impl RTDUserStatusLastWeekBuilder {
   pub fn build(&self) -> UserStatusLastWeek { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusLastWeek> for UserStatusLastWeek {
@@ -285,6 +329,9 @@ pub struct UserStatusOffline {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Point in time (Unix timestamp) when the user was last online
   was_online: i64,
   
@@ -292,6 +339,7 @@ pub struct UserStatusOffline {
 
 impl RObject for UserStatusOffline {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusOffline" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -320,6 +368,11 @@ pub struct RTDUserStatusOfflineBuilder {
 impl RTDUserStatusOfflineBuilder {
   pub fn build(&self) -> UserStatusOffline { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn was_online(&mut self, was_online: i64) -> &mut Self {
     self.inner.was_online = was_online;
@@ -348,6 +401,9 @@ pub struct UserStatusOnline {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Point in time (Unix timestamp) when the user's online status will expire
   expires: i64,
   
@@ -355,6 +411,7 @@ pub struct UserStatusOnline {
 
 impl RObject for UserStatusOnline {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusOnline" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -383,6 +440,11 @@ pub struct RTDUserStatusOnlineBuilder {
 impl RTDUserStatusOnlineBuilder {
   pub fn build(&self) -> UserStatusOnline { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn expires(&mut self, expires: i64) -> &mut Self {
     self.inner.expires = expires;
@@ -411,11 +473,15 @@ pub struct UserStatusRecently {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserStatusRecently {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusRecently" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -442,6 +508,11 @@ pub struct RTDUserStatusRecentlyBuilder {
 impl RTDUserStatusRecentlyBuilder {
   pub fn build(&self) -> UserStatusRecently { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusRecently> for UserStatusRecently {
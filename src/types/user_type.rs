@@ -18,6 +18,9 @@ pub trait TDUserType: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserType {
   #[doc(hidden)] _Default(()),
+  /// Object with an `@type` this crate doesn't know, usually sent by a newer tdlib
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  UnknownType { td_type: String, raw: serde_json::Value },
   /// A bot (see https://core.telegram.org/bots)
   Bot(UserTypeBot),
   /// A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user
@@ -37,6 +40,7 @@ impl<'de> Deserialize<'de> for UserType {
   fn deserialize<D>(deserializer: D) -> Result<UserType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
+      @unknown UnknownType,
       UserType,
       (userTypeBot, Bot);
       (userTypeDeleted, Deleted);
@@ -58,12 +62,24 @@ impl RObject for UserType {
       _ => "-1",
     }
   }
+  fn extra(&self) -> Option<&serde_json::Value> {
+    match self {
+      UserType::Bot(t) => t.extra(),
+      UserType::Deleted(t) => t.extra(),
+      UserType::Regular(t) => t.extra(),
+      UserType::Unknown(t) => t.extra(),
+
+      UserType::UnknownType { raw, .. } => raw.get("@extra"),
+      _ => None,
+    }
+  }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 impl UserType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserType::_Default(_) = self { true } else { false } }
+  pub fn is_unknown_type(&self) -> bool { if let UserType::UnknownType { .. } = self { true } else { false } }
 
   pub fn is_bot(&self) -> bool { if let UserType::Bot(_) = self { true } else { false } }
   pub fn is_deleted(&self) -> bool { if let UserType::Deleted(_) = self { true } else { false } }
@@ -108,6 +124,9 @@ pub struct UserTypeBot {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// True, if the bot can be invited to basic group and supergroup chats
   can_join_groups: bool,
   /// True, if the bot can read all messages in basic group or supergroup chats and not just those addressed to the bot. In private and channel chats a bot can always read all messages
@@ -123,6 +142,7 @@ pub struct UserTypeBot {
 
 impl RObject for UserTypeBot {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeBot" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -159,6 +179,11 @@ pub struct RTDUserTypeBotBuilder {
 impl RTDUserTypeBotBuilder {
   pub fn build(&self) -> UserTypeBot { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_join_groups(&mut self, can_join_groups: bool) -> &mut Self {
     self.inner.can_join_groups = can_join_groups;
@@ -211,11 +236,15 @@ pub struct UserTypeDeleted {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserTypeDeleted {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeDeleted" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -242,6 +271,11 @@ pub struct RTDUserTypeDeletedBuilder {
 impl RTDUserTypeDeletedBuilder {
   pub fn build(&self) -> UserTypeDeleted { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserTypeDeleted> for UserTypeDeleted {
@@ -264,11 +298,15 @@ pub struct UserTypeRegular {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserTypeRegular {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeRegular" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -295,6 +333,11 @@ pub struct RTDUserTypeRegularBuilder {
 impl RTDUserTypeRegularBuilder {
   pub fn build(&self) -> UserTypeRegular { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserTypeRegular> for UserTypeRegular {
@@ -317,11 +360,15 @@ pub struct UserTypeUnknown {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   
 }
 
 impl RObject for UserTypeUnknown {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeUnknown" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -348,6 +395,11 @@ pub struct RTDUserTypeUnknownBuilder {
 impl RTDUserTypeUnknownBuilder {
   pub fn build(&self) -> UserTypeUnknown { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserTypeUnknown> for UserTypeUnknown {
@@ -11,6 +11,9 @@ pub struct ValidatedOrderInfo {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Temporary identifier of the order information
   order_info_id: String,
   /// Available shipping options
@@ -20,6 +23,7 @@ pub struct ValidatedOrderInfo {
 
 impl RObject for ValidatedOrderInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "validatedOrderInfo" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -47,6 +51,11 @@ pub struct RTDValidatedOrderInfoBuilder {
 impl RTDValidatedOrderInfoBuilder {
   pub fn build(&self) -> ValidatedOrderInfo { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn order_info_id<T: AsRef<str>>(&mut self, order_info_id: T) -> &mut Self {
     self.inner.order_info_id = order_info_id.as_ref().to_string();
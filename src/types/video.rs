@@ -11,6 +11,9 @@ pub struct Video {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Duration of the video, in seconds; as defined by the sender
   duration: i64,
   /// Video width; as defined by the sender
@@ -36,6 +39,7 @@ pub struct Video {
 
 impl RObject for Video {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "video" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -79,6 +83,11 @@ pub struct RTDVideoBuilder {
 impl RTDVideoBuilder {
   pub fn build(&self) -> Video { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
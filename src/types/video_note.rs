@@ -11,6 +11,9 @@ pub struct VideoNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Duration of the video, in seconds; as defined by the sender
   duration: i64,
   /// Video width and height; as defined by the sender
@@ -26,6 +29,7 @@ pub struct VideoNote {
 
 impl RObject for VideoNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "videoNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDVideoNoteBuilder {
 impl RTDVideoNoteBuilder {
   pub fn build(&self) -> VideoNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -11,10 +11,13 @@ pub struct VoiceNote {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Duration of the voice note, in seconds; as defined by the sender
   duration: i64,
   /// A waveform representation of the voice note in 5-bit format
-  waveform: String,
+  waveform: TdBytes,
   /// MIME type of the file; as defined by the sender
   mime_type: String,
   /// File containing the voice note
@@ -24,6 +27,7 @@ pub struct VoiceNote {
 
 impl RObject for VoiceNote {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "voiceNote" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -39,7 +43,7 @@ impl VoiceNote {
 
   pub fn duration(&self) -> i64 { self.duration }
 
-  pub fn waveform(&self) -> &String { &self.waveform }
+  pub fn waveform(&self) -> &TdBytes { &self.waveform }
 
   pub fn mime_type(&self) -> &String { &self.mime_type }
 
@@ -55,6 +59,11 @@ pub struct RTDVoiceNoteBuilder {
 impl RTDVoiceNoteBuilder {
   pub fn build(&self) -> VoiceNote { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -62,8 +71,8 @@ impl RTDVoiceNoteBuilder {
   }
 
    
-  pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
-    self.inner.waveform = waveform.as_ref().to_string();
+  pub fn waveform<T: AsRef<[u8]>>(&mut self, waveform: T) -> &mut Self {
+    self.inner.waveform = TdBytes::from(waveform.as_ref());
     self
   }
 
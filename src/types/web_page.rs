@@ -11,6 +11,9 @@ pub struct WebPage {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Original URL of the link
   url: String,
   /// URL to display
@@ -58,6 +61,7 @@ pub struct WebPage {
 
 impl RObject for WebPage {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "webPage" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -123,6 +127,11 @@ pub struct RTDWebPageBuilder {
 impl RTDWebPageBuilder {
   pub fn build(&self) -> WebPage { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
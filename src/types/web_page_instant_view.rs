@@ -11,6 +11,9 @@ pub struct WebPageInstantView {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"), skip_serializing_if = "Option::is_none")]
+  extra: Option<serde_json::Value>,
   /// Content of the web page
   page_blocks: Vec<PageBlock>,
   /// Number of the instant view views; 0 if unknown
@@ -26,6 +29,7 @@ pub struct WebPageInstantView {
 
 impl RObject for WebPageInstantView {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "webPageInstantView" }
+  fn extra(&self) -> Option<&serde_json::Value> { self.extra.as_ref() }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -59,6 +63,11 @@ pub struct RTDWebPageInstantViewBuilder {
 impl RTDWebPageInstantViewBuilder {
   pub fn build(&self) -> WebPageInstantView { self.inner.clone() }
 
+  pub fn extra<T: Into<serde_json::Value>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
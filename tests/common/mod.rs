@@ -0,0 +1,94 @@
+//! Fixtures shared by the tests of async apis: a blocking executor and an in-process tdlib
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+use rtdlib::client::Transport;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+  fn wake(self: Arc<Self>) { self.0.unpark() }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+  fn wake(self: Arc<Self>) {}
+}
+
+/// Run `fut` on the current thread, parking it until the future is woken
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+  let mut fut = Box::pin(fut);
+  let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+  let mut cx = Context::from_waker(&waker);
+  loop {
+    if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+      return output;
+    }
+    thread::park();
+  }
+}
+
+/// Poll `fut` once, `None` while it is pending
+pub fn poll_once<F: Future + Unpin>(fut: &mut F) -> Option<F::Output> {
+  let waker = Waker::from(Arc::new(NoopWaker));
+  match Pin::new(fut).poll(&mut Context::from_waker(&waker)) {
+    Poll::Ready(output) => Some(output),
+    Poll::Pending => None,
+  }
+}
+
+type Respond = Box<dyn Fn(&serde_json::Value) -> Vec<String> + Send + Sync>;
+
+/// In-process tdlib, every request is answered with the jsons `respond` returns for it
+pub struct FakeTdlib {
+  queue: Mutex<VecDeque<String>>,
+  ready: Condvar,
+  respond: Respond,
+  sent: Mutex<Vec<String>>,
+}
+
+impl FakeTdlib {
+  pub fn new<F: Fn(&serde_json::Value) -> Vec<String> + Send + Sync + 'static>(respond: F) -> Self {
+    FakeTdlib { queue: Mutex::new(VecDeque::new()), ready: Condvar::new(), respond: Box::new(respond), sent: Mutex::new(vec![]) }
+  }
+
+  /// Queue `json` as if tdlib sent it
+  pub fn push<S: Into<String>>(&self, json: S) {
+    self.queue.lock().unwrap().push_back(json.into());
+    self.ready.notify_all();
+  }
+
+  /// `@type` of every request sent so far
+  pub fn sent(&self) -> Vec<String> { self.sent.lock().unwrap().clone() }
+}
+
+impl Transport for FakeTdlib {
+  fn send(&self, request: &str) {
+    let request: serde_json::Value = serde_json::from_str(request).unwrap();
+    self.sent.lock().unwrap().push(request["@type"].as_str().unwrap().to_string());
+    for response in (self.respond)(&request) {
+      self.push(response);
+    }
+  }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    let queue = self.queue.lock().unwrap();
+    let (mut queue, _) = self.ready.wait_timeout_while(queue, Duration::from_secs_f64(timeout), |q| q.is_empty()).unwrap();
+    queue.pop_front()
+  }
+}
+
+/// `response` json carrying the `@extra` of `request`
+pub fn reply(request: &serde_json::Value, response: &str) -> String {
+  let mut response: serde_json::Value = serde_json::from_str(response).unwrap();
+  response["@extra"] = request["@extra"].clone();
+  response.to_string()
+}
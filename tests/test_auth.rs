@@ -0,0 +1,131 @@
+mod common;
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc;
+
+use rtdlib::auth::{AuthHandler, AuthRequest, AuthStep, Authenticator, Login};
+use rtdlib::client::Client;
+use rtdlib::errors::{RTDError, TdError};
+use rtdlib::types::*;
+
+use common::{reply, FakeTdlib};
+
+struct ScriptedHandler {
+  login: Login,
+  codes: Vec<&'static str>,
+}
+
+impl AuthHandler for ScriptedHandler {
+  fn tdlib_parameters(&mut self) -> TdlibParameters {
+    TdlibParameters::builder().database_directory("/tmp/td").api_id(123).api_hash("abc").build()
+  }
+
+  fn login(&mut self) -> Login { self.login.clone() }
+
+  fn code(&mut self, _state: &AuthorizationStateWaitCode) -> String { self.codes.remove(0).to_string() }
+
+  fn password(&mut self, state: &AuthorizationStateWaitPassword) -> String { format!("pass-{}", state.password_hint()) }
+
+  fn registration(&mut self, _state: &AuthorizationStateWaitRegistration) -> (String, String) {
+    ("Jo".to_string(), "Ho".to_string())
+  }
+}
+
+fn state(json: &str) -> Update {
+  Update::from_json(format!(r#"{{"@type":"updateAuthorizationState","authorization_state":{}}}"#, json)).expect("Json fail")
+}
+
+fn request_json(step: Option<AuthStep>) -> String {
+  match step {
+    Some(AuthStep::Send(request)) => request.to_json().unwrap(),
+    other => panic!("expected request, got {:?}", other),
+  }
+}
+
+#[test]
+fn test_user_login_flow() {
+  let mut auth = Authenticator::new(ScriptedHandler { login: Login::PhoneNumber("+100".to_string()), codes: vec!["11111", "22222"] });
+
+  let json = request_json(auth.update(&state(r#"{"@type":"authorizationStateWaitTdlibParameters"}"#)));
+  assert!(json.starts_with(r#"{"@type":"setTdlibParameters""#));
+
+  let json = request_json(auth.update(&state(r#"{"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}"#)));
+  assert_eq!(r#"{"@type":"checkDatabaseEncryptionKey","encryption_key":""}"#, json);
+
+  let json = request_json(auth.update(&state(r#"{"@type":"authorizationStateWaitPhoneNumber"}"#)));
+  assert!(json.starts_with(r#"{"@type":"setAuthenticationPhoneNumber","phone_number":"+100""#));
+
+  let code_state = state(r#"{"@type":"authorizationStateWaitCode","code_info":{"@type":"authenticationCodeInfo","phone_number":"+100","type":{"@type":"authenticationCodeTypeSms","length":5},"next_type":null,"timeout":60}}"#);
+  let json = request_json(auth.update(&code_state));
+  assert_eq!(r#"{"@type":"checkAuthenticationCode","code":"11111"}"#, json);
+
+  // wrong code, tdlib doesn't send a new state, the handler is asked again
+  let retry = auth.retry(RTDError::Td(TdError::PhoneCodeInvalid { code: 400, message: "PHONE_CODE_INVALID".to_string() })).unwrap();
+  assert_eq!(r#"{"@type":"checkAuthenticationCode","code":"22222"}"#, request_json(Some(retry)));
+
+  let json = request_json(auth.update(&state(r#"{"@type":"authorizationStateWaitPassword","password_hint":"hint","has_recovery_email_address":false,"recovery_email_address_pattern":""}"#)));
+  assert_eq!(r#"{"@type":"checkAuthenticationPassword","password":"pass-hint"}"#, json);
+
+  let json = request_json(auth.update(&state(r#"{"@type":"authorizationStateWaitRegistration","terms_of_service":{"@type":"termsOfService","text":{"@type":"formattedText","text":"","entities":[]},"min_user_age":0,"show_popup":false}}"#)));
+  assert_eq!(r#"{"@type":"registerUser","first_name":"Jo","last_name":"Ho"}"#, json);
+
+  match auth.update(&state(r#"{"@type":"authorizationStateReady"}"#)) {
+    Some(AuthStep::Ready) => {}
+    other => panic!("expected ready, got {:?}", other),
+  }
+  assert!(auth.state().unwrap().is_ready());
+}
+
+#[test]
+fn test_bot_login_flow() {
+  let mut auth = Authenticator::new(ScriptedHandler { login: Login::BotToken("123:abc".to_string()), codes: vec![] });
+  match auth.update(&state(r#"{"@type":"authorizationStateWaitPhoneNumber"}"#)) {
+    Some(AuthStep::Send(AuthRequest::CheckAuthenticationBotToken(t))) => assert_eq!("123:abc", t.token()),
+    other => panic!("expected bot token, got {:?}", other),
+  }
+  assert!(auth.retry(RTDError::Td(TdError::Unauthorized { code: 401, message: "ACCESS_TOKEN_INVALID".to_string() })).is_err());
+}
+
+#[test]
+fn test_ignore_other_updates() {
+  let mut auth = Authenticator::new(ScriptedHandler { login: Login::QrCode, codes: vec![] });
+  let update = Update::from_json(r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.6.0"}}"#).expect("Json fail");
+  assert!(auth.update(&update).is_none());
+  match auth.update(&state(r#"{"@type":"authorizationStateClosed"}"#)) {
+    Some(AuthStep::Closed) => {}
+    other => panic!("expected closed, got {:?}", other),
+  }
+}
+
+/// Sends the first of `states`, then answers every request with `ok` and the next state
+fn fake_tdlib(states: Vec<&'static str>) -> FakeTdlib {
+  let states = Mutex::new(VecDeque::from(states));
+  let next_state = move || states.lock().unwrap().pop_front()
+    .map(|state| format!(r#"{{"@type":"updateAuthorizationState","authorization_state":{}}}"#, state));
+  let first = next_state();
+  let tdlib = FakeTdlib::new(move |request| std::iter::once(reply(request, r#"{"@type":"ok"}"#)).chain(next_state()).collect());
+  tdlib.push(first.unwrap());
+  tdlib
+}
+
+#[test]
+fn test_run_blocking() {
+  let tdlib = Arc::new(fake_tdlib(vec![
+    r#"{"@type":"authorizationStateWaitTdlibParameters"}"#,
+    r#"{"@type":"authorizationStateWaitPhoneNumber"}"#,
+    r#"{"@type":"authorizationStateReady"}"#,
+  ]));
+  tdlib.push(r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.6.0"}}"#);
+  let (sender, updates) = mpsc::channel();
+  let client = Client::new(tdlib.clone(), sender);
+
+  let mut auth = Authenticator::new(ScriptedHandler { login: Login::BotToken("123:abc".to_string()), codes: vec![] });
+  let mut others = vec![];
+  auth.run(&client, &updates, |update| others.push(update)).unwrap();
+
+  assert!(auth.state().unwrap().is_ready());
+  assert_eq!(vec!["setTdlibParameters", "checkAuthenticationBotToken"], tdlib.sent());
+  assert_eq!(1, others.len());
+  assert!(others[0].is_option());
+}
@@ -0,0 +1,29 @@
+use rtdlib::types::*;
+
+#[test]
+fn test_bytes_base64() {
+  let cases: [(&[u8], &str); 4] = [(b"", ""), (b"a", "YQ=="), (b"ab", "YWI="), (b"abc", "YWJj")];
+  for (raw, text) in cases.iter() {
+    let value = TestBytes::builder().value(raw).build();
+    let json = value.to_json().unwrap();
+    assert!(json.contains(&format!(r#""value":"{}""#, text)));
+    assert_eq!(*raw, TestBytes::from_json(json).unwrap().value().as_slice());
+    assert_eq!(*raw, TdBytes::from_base64(text).unwrap().as_slice());
+  }
+}
+
+#[test]
+fn test_bytes_invalid() {
+  assert!(TestBytes::from_json(r#"{"@type":"testBytes","value":"Y*=="}"#).is_err());
+  assert!(TestBytes::from_json(r#"{"@type":"testBytes","value":"YWJjZ"}"#).is_err());
+  // url safe and unpadded input is accepted
+  assert_eq!(vec![0xfb, 0xff], TdBytes::from_base64("-_8").unwrap().into_vec());
+}
+
+#[test]
+fn test_bytes_reflector_peer_tag() {
+  let json = r#"{"@type":"callServerTypeTelegramReflector","peer_tag":"AAEC/w=="}"#;
+  let reflector = CallServerTypeTelegramReflector::from_json(json).unwrap();
+  assert_eq!(&[0u8, 1, 2, 255][..], reflector.peer_tag().as_slice());
+  assert_eq!(json, reflector.to_json().unwrap());
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use rtdlib::callback_data::CallbackCodec;
+use rtdlib::keyboard::InlineKeyboard;
+use rtdlib::types::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Action {
+  Open,
+  Vote(i64),
+  Page { offset: u32, query: Option<String> },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Data {
+  chat_id: i64,
+  action: Action,
+  selected: Vec<bool>,
+  tags: BTreeMap<String, u8>,
+  score: f32,
+  mark: char,
+}
+
+fn data() -> Data {
+  Data {
+    chat_id: -1_001_234_567_890,
+    action: Action::Page { offset: 300, query: Some("cats".to_string()) },
+    selected: vec![true, false],
+    tags: vec![("a".to_string(), 1)].into_iter().collect(),
+    score: 0.5,
+    mark: 'é',
+  }
+}
+
+#[test]
+fn test_callback_data_round_trip() {
+  let codec = CallbackCodec::new(1);
+  let bytes = codec.encode(&data()).unwrap();
+  assert_eq!(1, bytes[0]);
+  assert!(bytes.len() < 32);
+  assert_eq!(data(), codec.decode::<Data>(&bytes).unwrap());
+
+  for action in [Action::Open, Action::Vote(-3), Action::Page { offset: 0, query: None }] {
+    let bytes = codec.encode(&action).unwrap();
+    assert_eq!(action, codec.decode::<Action>(&bytes).unwrap());
+  }
+  assert_eq!(vec![1, 0], codec.encode(&Action::Open).unwrap());
+}
+
+#[test]
+fn test_callback_data_errors() {
+  let codec = CallbackCodec::new(1);
+  let err = codec.encode(&"x".repeat(64)).unwrap_err();
+  assert!(format!("{}", err).contains("at most 64"));
+
+  let bytes = codec.encode(&data()).unwrap();
+  let err = CallbackCodec::new(2).decode::<Data>(&bytes).unwrap_err();
+  assert_eq!("callback data has schema version 1, expected 2", format!("{}", err));
+
+  assert!(codec.decode::<Data>(&bytes[..bytes.len() - 1]).is_err());
+  assert!(codec.decode::<Action>(&[1, 0, 0]).is_err());
+  assert!(codec.decode::<Action>(&[1, 9]).is_err());
+  assert!(codec.decode::<Action>(&[]).is_err());
+}
+
+#[test]
+fn test_callback_data_query() {
+  let codec = CallbackCodec::new(3);
+  let markup = InlineKeyboard::new().callback("Vote", codec.encode(&Action::Vote(7)).unwrap()).build().unwrap();
+  let button = &markup.as_inline_keyboard().unwrap().rows()[0][0];
+  let data = button.type_().as_callback().unwrap().data();
+
+  // tdlib sends the data back in the query payload
+  let payload = CallbackQueryPayload::data(CallbackQueryPayloadData::builder().data(data).build());
+  let query = UpdateNewCallbackQuery::builder().id(1).chat_id(2).message_id(3).payload(payload).build();
+  let query = UpdateNewCallbackQuery::from_json(query.to_json().unwrap()).unwrap();
+  assert_eq!(Action::Vote(7), codec.decode_query::<Action>(&query).unwrap());
+
+  let game = CallbackQueryPayload::game(CallbackQueryPayloadGame::builder().game_short_name("game").build());
+  assert!(codec.decode_payload::<Action>(&game).is_err());
+}
+
+#[test]
+fn test_callback_data_base64() {
+  // every padding length survives the json round trip of the button
+  let codec = CallbackCodec::new(0);
+  for len in 0..12 {
+    let text: String = (0..len).map(|n| char::from(b'a' + n as u8 * 7 % 26)).collect();
+    let markup = InlineKeyboard::new().callback("b", codec.encode(&text).unwrap()).build().unwrap();
+    let markup = ReplyMarkup::from_json(markup.to_json().unwrap()).unwrap();
+    let data = markup.as_inline_keyboard().unwrap().rows()[0][0].type_().as_callback().unwrap().data().clone();
+    let payload = CallbackQueryPayload::data(CallbackQueryPayloadData::builder().data(data).build());
+    assert_eq!(text, codec.decode_payload::<String>(&payload).unwrap());
+  }
+}
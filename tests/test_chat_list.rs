@@ -0,0 +1,56 @@
+use rtdlib::chat_list::{ChatListIndex, ChatListKey, FIRST_PAGE_ORDER};
+use rtdlib::types::*;
+
+fn position(key: ChatListKey, order: i64, is_pinned: bool) -> ChatPosition {
+  ChatPosition::builder().list(key.chat_list()).order(order).is_pinned(is_pinned).build()
+}
+
+fn update_position(chat_id: i64, position: ChatPosition) -> Update {
+  Update::chat_position(UpdateChatPosition::builder().chat_id(chat_id).position(position).build())
+}
+
+#[test]
+fn test_chat_list_order() {
+  let mut index = ChatListIndex::new();
+  index.update(&update_position(1, position(ChatListKey::Main, 10, false)));
+  index.update(&update_position(2, position(ChatListKey::Main, 30, false)));
+  index.update(&update_position(3, position(ChatListKey::Main, 10, false)));
+  index.update(&update_position(4, position(ChatListKey::Main, 100, true)));
+  index.update(&update_position(5, position(ChatListKey::Filter(7), 5, false)));
+
+  // equal orders are sorted by descending chat id
+  assert_eq!(vec![4, 2, 3, 1], index.chat_ids(&ChatListKey::Main).collect::<Vec<_>>());
+  assert_eq!(vec![4], index.pinned_chat_ids(&ChatListKey::Main).collect::<Vec<_>>());
+  assert_eq!(vec![5], index.chat_ids(&ChatListKey::Filter(7)).collect::<Vec<_>>());
+  assert!(index.is_empty(&ChatListKey::Archive));
+
+  let last_message = UpdateChatLastMessage::builder().chat_id(1).positions(vec![position(ChatListKey::Main, 50, false)]).build();
+  assert!(index.update(&Update::chat_last_message(last_message)));
+  assert_eq!(vec![4, 1, 2, 3], index.chat_ids(&ChatListKey::Main).collect::<Vec<_>>());
+
+  index.update(&update_position(2, position(ChatListKey::Main, 0, false)));
+  index.update(&update_position(2, position(ChatListKey::Archive, 20, false)));
+  assert_eq!(vec![4, 1, 3], index.chat_ids(&ChatListKey::Main).collect::<Vec<_>>());
+  assert_eq!(vec![ChatListKey::Archive], index.chat_lists(2));
+  assert!(index.position(2, &ChatListKey::Main).is_none());
+}
+
+#[test]
+fn test_chat_list_pages() {
+  let mut index = ChatListIndex::new();
+  let first = index.next_page(&ChatListKey::Archive, 20);
+  assert_eq!(FIRST_PAGE_ORDER, first.offset_order());
+  assert_eq!(0, first.offset_chat_id());
+  assert!(first.chat_list().is_archive());
+
+  index.update(&update_position(1, position(ChatListKey::Archive, 10, false)));
+  index.update(&update_position(2, position(ChatListKey::Archive, 30, false)));
+  let next = index.next_page(&ChatListKey::Archive, 20);
+  assert_eq!(10, next.offset_order());
+  assert_eq!(1, next.offset_chat_id());
+  assert_eq!(20, next.limit());
+
+  let json: serde_json::Value = serde_json::from_str(&next.to_json().unwrap()).unwrap();
+  assert_eq!("10", json["offset_order"]);
+  assert_eq!("chatListArchive", json["chat_list"]["@type"]);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use rtdlib::client::Client;
+use rtdlib::errors::{RTDError, TdError};
+use rtdlib::types::*;
+
+use common::{block_on, poll_once, reply, FakeTdlib};
+
+/// Answers `getChatMessageCount` with a count, never answers `getMe` and everything else with an error
+fn fake_tdlib() -> FakeTdlib {
+  FakeTdlib::new(|request| match request["@type"].as_str().unwrap() {
+    "getChatMessageCount" => vec![reply(request, r#"{"@type":"count","count":7}"#)],
+    "getMe" => vec![],
+    _ => vec![reply(request, r#"{"@type":"error","code":400,"message":"CHAT_NOT_FOUND"}"#)],
+  })
+}
+
+#[test]
+fn test_client_response() {
+  let (sender, _receiver) = mpsc::channel();
+  let client = Client::new(fake_tdlib(), sender);
+  let count = block_on(client.send(GetChatMessageCount::builder().chat_id(1).build())).unwrap();
+  assert_eq!(7, count.count());
+}
+
+#[test]
+fn test_client_error() {
+  let (sender, _receiver) = mpsc::channel();
+  let client = Client::new(fake_tdlib(), sender);
+  match block_on(client.send(GetChat::builder().chat_id(1).build())) {
+    Err(RTDError::Td(TdError::Other { code, message })) => {
+      assert_eq!(400, code);
+      assert_eq!("CHAT_NOT_FOUND", message);
+    }
+    other => panic!("unexpected {:?}", other),
+  }
+}
+
+#[test]
+fn test_client_updates() {
+  let transport = Arc::new(fake_tdlib());
+  let (sender, receiver) = mpsc::channel();
+  let _client = Client::new(transport.clone(), sender);
+  transport.push(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#);
+  let update = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+  assert!(update.is_authorization_state());
+}
+
+#[test]
+fn test_client_drops_stray_responses() {
+  let transport = Arc::new(fake_tdlib());
+  let (sender, receiver) = mpsc::channel();
+  let client = Client::new(transport.clone(), sender);
+  let (error_sender, errors) = mpsc::channel();
+  client.on_error(move |err| { let _ = error_sender.send(err.to_string()); });
+  transport.push(r#"{"@type":"ok","@extra":"not-mine"}"#);
+  transport.push(r#"{"@type":"error","code":400,"message":"Bad request","@extra":1}"#);
+  transport.push(r#"{"@type":"updateAuthorizationState","authorization_state":1}"#);
+  transport.push(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateReady"}}"#);
+  let update = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+  assert!(update.is_authorization_state());
+  assert!(receiver.try_recv().is_err());
+  let err = errors.recv_timeout(Duration::from_secs(5)).unwrap();
+  assert!(err.contains("updateAuthorizationState"), "{}", err);
+}
+
+#[test]
+fn test_client_dropped_request() {
+  let transport = Arc::new(fake_tdlib());
+  let (sender, receiver) = mpsc::channel();
+  let client = Client::new(transport.clone(), sender);
+  {
+    let mut fut = Box::pin(client.send(GetMe::builder().build()));
+    assert!(poll_once(&mut fut).is_none());
+  }
+  // the slot of the dropped request is gone, so its `@extra` is no longer taken as a response
+  transport.push(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateReady"},"@extra":"0"}"#);
+  let update = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+  assert!(update.is_authorization_state());
+}
@@ -0,0 +1,58 @@
+use rtdlib::state::StateStore;
+use rtdlib::text::{MessageContentExt, NoNames};
+use rtdlib::types::*;
+
+fn text(text: &str) -> FormattedText {
+  FormattedText::builder().text(text).entities(vec![]).build()
+}
+
+fn user(id: i64, first_name: &str, last_name: &str) -> User {
+  User::builder()
+    .id(id)
+    .first_name(first_name)
+    .last_name(last_name)
+    .status(UserStatus::empty(UserStatusEmpty::builder().build()))
+    .type_(UserType::regular(UserTypeRegular::builder().build()))
+    .build()
+}
+
+#[test]
+fn test_content_text() {
+  let message = MessageContent::message_text(MessageText::builder().text(text("\nhello\nworld")).build());
+  assert_eq!("\nhello\nworld", message.to_plain_text(&NoNames));
+  assert_eq!("hello", message.summary(&NoNames));
+
+  let photo = MessageContent::message_photo(MessagePhoto::builder().photo(Photo::builder().build()).caption(text("sunset")).build());
+  assert_eq!("sunset", photo.to_plain_text(&NoNames));
+  assert_eq!("Photo, sunset", photo.summary(&NoNames));
+
+  let options = ["yes", "no"].iter().map(|option| PollOption::builder().text(option).build()).collect();
+  let poll = MessageContent::message_poll(MessagePoll::builder().poll(Poll::builder().question("Lunch?").options(options).build()).build());
+  assert_eq!("Lunch?\nyes\nno", poll.to_plain_text(&NoNames));
+  assert_eq!("Poll: Lunch?", poll.summary(&NoNames));
+
+  let venue = Venue::builder().title("Cafe").address("Main st. 1").build();
+  let venue = MessageContent::message_venue(MessageVenue::builder().venue(venue).build());
+  assert_eq!("Cafe\nMain st. 1", venue.to_plain_text(&NoNames));
+  assert_eq!("Venue: Cafe", venue.summary(&NoNames));
+}
+
+#[test]
+fn test_content_service() {
+  let mut store = StateStore::new();
+  store.apply(&Update::user(UpdateUser::builder().user(user(1, "Ann", "Lee")).build()));
+  store.apply(&Update::user(UpdateUser::builder().user(user(2, "Bob", "")).build()));
+
+  let members = MessageContent::message_chat_add_members(MessageChatAddMembers::builder().member_user_ids(vec![1, 2, 3]).build());
+  assert_eq!("Ann Lee, Bob and user 3 joined the group", members.summary(&store));
+  assert_eq!("user 1, user 2 and user 3 joined the group", members.to_plain_text(&NoNames));
+
+  let ttl = MessageContent::message_chat_set_ttl(MessageChatSetTtl::builder().ttl(86400).build());
+  assert_eq!("Self-destruct timer set to 1 day", ttl.summary(&NoNames));
+
+  let call = MessageCall::builder().duration(65).discard_reason(CallDiscardReason::hung_up(CallDiscardReasonHungUp::builder().build())).build();
+  assert_eq!("Call (1:05)", MessageContent::message_call(call).summary(&NoNames));
+
+  let payment = MessagePaymentSuccessful::builder().currency("USD").total_amount(1205).build();
+  assert_eq!("Payment of 12.05 USD", MessageContent::message_payment_successful(payment).summary(&NoNames));
+}
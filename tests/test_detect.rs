@@ -0,0 +1,61 @@
+use rtdlib::text::detect::{self, BotCommand, EntityKind};
+
+fn found(text: &str) -> Vec<(EntityKind, &str)> {
+  let kinds: Vec<EntityKind> = detect::detect(text).iter().map(|entity| EntityKind::of(entity.type_()).unwrap()).collect();
+  let mut texts = vec![];
+  for kind in [EntityKind::Mention, EntityKind::Hashtag, EntityKind::Cashtag, EntityKind::BotCommand, EntityKind::Url,
+    EntityKind::EmailAddress, EntityKind::PhoneNumber, EntityKind::BankCardNumber] {
+    texts.extend(detect::detect_kind(text, kind).into_iter().map(|t| (kind, t)));
+  }
+  texts.sort_by_key(|(_, t)| t.as_ptr());
+  assert_eq!(kinds, texts.iter().map(|(kind, _)| *kind).collect::<Vec<_>>());
+  texts
+}
+
+#[test]
+fn test_detect_tags() {
+  assert_eq!(vec![
+    (EntityKind::Mention, "@durov"),
+    (EntityKind::Hashtag, "#rust_lang"),
+    (EntityKind::Hashtag, "#тег"),
+    (EntityKind::Cashtag, "$USD"),
+  ], found("hi @durov, @abc #rust_lang #тег #123 $USD $usd a@bcdefg x#tag"));
+}
+
+#[test]
+fn test_detect_bot_commands() {
+  assert_eq!(vec![
+    (EntityKind::BotCommand, "/start"),
+    (EntityKind::BotCommand, "/help@my_bot"),
+  ], found("/start now, /help@my_bot and not a/path or /x/y"));
+
+  assert_eq!(Some(BotCommand { command: "start", bot_username: Some("my_bot"), args: "deep link" }), detect::bot_command("/start@my_bot  deep link "));
+  assert_eq!(Some(BotCommand { command: "help", bot_username: None, args: "" }), detect::bot_command("/help"));
+  assert_eq!(None, detect::bot_command("say /help"));
+}
+
+#[test]
+fn test_detect_urls_and_emails() {
+  assert_eq!(vec![
+    (EntityKind::Url, "https://example.com/a_(b)?q=1#frag"),
+    (EntityKind::Url, "telegram.org"),
+    (EntityKind::Url, "www.example.test:8080/path"),
+    (EntityKind::EmailAddress, "john.doe+tg@mail.example.com"),
+  ], found("see (https://example.com/a_(b)?q=1#frag), telegram.org. www.example.test:8080/path! file.txt john.doe+tg@mail.example.com."));
+}
+
+#[test]
+fn test_detect_numbers() {
+  assert_eq!(vec![
+    (EntityKind::PhoneNumber, "+1 (234) 567-8901"),
+    (EntityKind::BankCardNumber, "4111 1111 1111 1111"),
+  ], found("call +1 (234) 567-8901, card 4111 1111 1111 1111, not 4111 1111 1111 1112 or +12"));
+}
+
+#[test]
+fn test_detect_utf16_offsets() {
+  let entities = detect::detect("😀 #tag");
+  assert_eq!(1, entities.len());
+  assert_eq!(3, entities[0].offset());
+  assert_eq!(4, entities[0].length());
+}
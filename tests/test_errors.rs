@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use rtdlib::errors::{RTDError, TdError};
+use rtdlib::types::Error;
+
+fn td_error(code: i64, message: &str) -> TdError {
+  Error::builder().code(code).message(message).build().into()
+}
+
+#[test]
+fn test_flood_wait() {
+  assert_eq!(TdError::FloodWait { code: 420, retry_after: Duration::from_secs(35) }, td_error(420, "FLOOD_WAIT_35"));
+  assert_eq!(TdError::FloodWait { code: 429, retry_after: Duration::from_secs(7) }, td_error(429, "Too Many Requests: retry after 7"));
+  assert_eq!(420, td_error(420, "FLOOD_WAIT_35").code());
+}
+
+#[test]
+fn test_well_known_errors() {
+  assert_eq!(TdError::PhoneCodeInvalid { code: 400, message: "PHONE_CODE_INVALID".to_string() }, td_error(400, "PHONE_CODE_INVALID"));
+  assert_eq!(TdError::ChatWriteForbidden { code: 403, message: "CHAT_WRITE_FORBIDDEN".to_string() }, td_error(403, "CHAT_WRITE_FORBIDDEN"));
+  assert_eq!(TdError::ChatWriteForbidden { code: 400, message: "Have no write access to the chat".to_string() }, td_error(400, "Have no write access to the chat"));
+  assert_eq!(TdError::Unauthorized { code: 401, message: "Unauthorized".to_string() }, td_error(401, "Unauthorized"));
+  assert_eq!(TdError::NotFound { code: 404, message: "Not Found".to_string() }, td_error(404, "Not Found"));
+  assert_eq!(TdError::Cancelled { code: 500, message: "Request aborted".to_string() }, td_error(500, "Request aborted"));
+  assert_eq!(TdError::Cancelled { code: 400, message: "Canceled".to_string() }, td_error(400, "Canceled"));
+  assert_eq!(TdError::Other { code: 400, message: "CHAT_NOT_FOUND".to_string() }, td_error(400, "CHAT_NOT_FOUND"));
+  assert_eq!(TdError::Other { code: 400, message: "Can't cancel the request".to_string() }, td_error(400, "Can't cancel the request"));
+}
+
+#[test]
+fn test_code_is_kept() {
+  assert_eq!(400, td_error(400, "PHONE_CODE_INVALID").code());
+  assert_eq!(403, td_error(403, "CHAT_WRITE_FORBIDDEN").code());
+  assert_eq!(406, td_error(406, "Request aborted").code());
+}
+
+#[test]
+fn test_message_is_kept() {
+  assert_eq!("Have no write access to the chat", td_error(400, "Have no write access to the chat").message());
+  assert_eq!("PHONE_CODE_INVALID", td_error(400, "PHONE_CODE_INVALID").message());
+  assert_eq!("FLOOD_WAIT_7", td_error(429, "Too Many Requests: retry after 7").message());
+}
+
+#[test]
+fn test_rtd_error_from_td() {
+  let err: RTDError = Error::builder().code(400).message("PHONE_CODE_INVALID").build().into();
+  match err {
+    RTDError::Td(TdError::PhoneCodeInvalid { code: 400, .. }) => {}
+    other => panic!("unexpected {:?}", other),
+  }
+}
+
+#[test]
+fn test_context_chain() {
+  use std::error::Error as StdError;
+  use rtdlib::errors::RTDResultExt;
+
+  let result: Result<(), RTDError> = Err(RTDError::message(format!("unexpected @type {}", "updateFoo")));
+  let err = result.context("field message").unwrap_err();
+  assert_eq!("field message: unexpected @type updateFoo", err.to_string());
+  assert_eq!("unexpected @type updateFoo", err.root().to_string());
+  assert!(err.source().is_some());
+}
+
+#[test]
+fn test_enum_deserialize_error() {
+  use rtdlib::types::Update;
+
+  let json = r#"{"@type":"updateNewMessage","message":{"@type":"message","content":{"@type":"messageText"}}}"#;
+  let err = Update::from_json(json).unwrap_err().to_string();
+  assert!(err.contains("Update::NewMessage(updateNewMessage) -> "), "{}", err);
+
+  let json = r#"{"@type":"updateChatLastMessage","chat_id":1,"last_message":{"@type":"message","id":1},"positions":[]}"#;
+  let err = Update::from_json(json).unwrap_err().to_string();
+  assert!(err.contains("Update::ChatLastMessage(updateChatLastMessage) -> last_message: missing field"), "{}", err);
+
+  let err = Update::from_json(r#"{"chat_id":1}"#).unwrap_err().to_string();
+  assert!(err.contains("Update -> missing field `@type`"), "{}", err);
+}
+
+#[test]
+fn test_deserialize_error_path() {
+  use rtdlib::types::{Chat, Update};
+
+  let json = r#"{"@type":"updateNewMessage","message":{"@type":"message","id":1,"content":{"@type":"messageText","text":{"@type":"formattedText","text":1,"entities":[]}}}}"#;
+  let err = Update::from_json(json).unwrap_err().to_string();
+  assert!(err.contains("Update::NewMessage(updateNewMessage) -> message.content: MessageContent::MessageText(messageText) -> text.text: invalid type"), "{}", err);
+
+  let json = r#"{"@type":"updateChatPosition","chat_id":1,"position":{"@type":"chatPosition","list":{"@type":"chatListMain"},"order":"x","is_pinned":false}}"#;
+  let err = Update::from_json(json).unwrap_err().to_string();
+  assert!(err.contains("position.order: "), "{}", err);
+
+  let err = rtdlib::types::from_json::<Chat>(r#"{"@type":"chat","id":1,"positions":[{"@type":"chatPosition","order":true}]}"#).unwrap_err();
+  assert!(err.to_string().starts_with("field positions[0].order: "), "{}", err);
+}
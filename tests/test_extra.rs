@@ -0,0 +1,33 @@
+use rtdlib::types::*;
+use serde_json::json;
+
+#[test]
+fn test_extra() {
+  let get_me = GetMe::builder().extra("req-1").build();
+  assert_eq!(Some(&json!("req-1")), get_me.extra());
+  assert_eq!(r#"{"@type":"getMe","@extra":"req-1"}"#, get_me.to_json().unwrap());
+
+  let json = r#"{"@type":"authorizationStateWaitTdlibParameters","@extra":"req-2"}"#;
+  let state = AuthorizationState::from_json(json).expect("Json fail");
+  assert_eq!(Some(&json!("req-2")), state.extra());
+  assert_eq!(json, state.to_json().unwrap());
+
+  let state = AuthorizationState::from_json(r#"{"@type":"authorizationStateReady"}"#).expect("Json fail");
+  assert_eq!(None, state.extra());
+}
+
+#[test]
+fn test_extra_any_value() {
+  let get_me = GetMe::builder().extra(7).build();
+  assert_eq!(r#"{"@type":"getMe","@extra":7}"#, get_me.to_json().unwrap());
+
+  let json = r#"{"@type":"count","@extra":{"id":7,"retry":true},"count":3}"#;
+  let count = Count::from_json(json).expect("Json fail");
+  assert_eq!(Some(&json!({"id": 7, "retry": true})), count.extra());
+  assert_eq!(json, count.to_json().unwrap());
+
+  let json = r#"{"@type":"authorizationStateWaitSomething","@extra":42}"#;
+  let state = AuthorizationState::from_json(json).expect("Json fail");
+  assert!(state.is_unknown());
+  assert_eq!(Some(&json!(42)), state.extra());
+}
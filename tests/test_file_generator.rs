@@ -0,0 +1,112 @@
+use std::io::Write;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use rtdlib::errors::RTDError;
+use rtdlib::file_generator::{FileGenerators, GenerationOutput, GenerationRequest, MAX_PART_SIZE};
+use rtdlib::types::*;
+
+fn start(generation_id: i64, conversion: &str) -> Update {
+  Update::file_generation_start(
+    UpdateFileGenerationStart::builder()
+      .generation_id(generation_id)
+      .original_path("/tmp/in.txt")
+      .destination_path("/tmp/out")
+      .conversion(conversion)
+      .build()
+  )
+}
+
+/// Requests of a generation until it finishes
+fn collect(receiver: &Receiver<GenerationRequest>) -> Vec<GenerationRequest> {
+  let mut requests = vec![];
+  loop {
+    let request = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    let done = matches!(request, GenerationRequest::Finish(_));
+    requests.push(request);
+    if done { return requests }
+  }
+}
+
+fn upper(start: &UpdateFileGenerationStart, output: &mut GenerationOutput) -> rtdlib::errors::RTDResult<()> {
+  output.set_expected_size(start.original_path().len() as i64);
+  output.write_all(start.original_path().to_uppercase().as_bytes())?;
+  Ok(())
+}
+
+#[test]
+fn test_file_generator_stream() {
+  let (sender, receiver) = mpsc::channel();
+  let mut generators = FileGenerators::new(sender);
+  generators.register("upper", upper);
+  generators.register("big", |_: &UpdateFileGenerationStart, output: &mut GenerationOutput| {
+    output.write_all(&vec![7; MAX_PART_SIZE + 1])?;
+    Ok(())
+  });
+
+  assert!(generators.update(&start(1, "upper:v1")));
+  let requests = collect(&receiver);
+  assert_eq!(3, requests.len());
+  match &requests[0] {
+    GenerationRequest::WritePart(t) => assert_eq!(b"/TMP/IN.TXT", t.data().as_slice()),
+    other => panic!("unexpected {:?}", other),
+  }
+  match &requests[1] {
+    GenerationRequest::Progress(t) => assert_eq!((11, 11), (t.expected_size(), t.local_prefix_size())),
+    other => panic!("unexpected {:?}", other),
+  }
+  let json = requests[2].to_json().unwrap();
+  assert!(json.contains(r#""@type":"finishFileGeneration""#) && json.contains(r#""error":null"#));
+
+  generators.update(&start(2, "big"));
+  let offsets: Vec<i64> = collect(&receiver).iter().filter_map(|r| match r {
+    GenerationRequest::WritePart(t) => Some(t.offset()),
+    _ => None,
+  }).collect();
+  assert_eq!(vec![0, MAX_PART_SIZE as i64], offsets);
+  assert!(!generators.update(&Update::option(UpdateOption::builder().name("x").build())));
+}
+
+#[test]
+fn test_file_generator_errors() {
+  let (sender, receiver) = mpsc::channel();
+  let mut generators = FileGenerators::new(sender);
+  generators.register("fail", |_: &UpdateFileGenerationStart, _: &mut GenerationOutput| Err(RTDError::custom("no codec")));
+
+  for (id, conversion, message) in [(1, "fail", "no codec"), (2, "missing", "no file generator")] {
+    generators.update(&start(id, conversion));
+    match collect(&receiver).pop().unwrap() {
+      GenerationRequest::Finish(t) => {
+        assert_eq!(id, t.generation_id());
+        let error = t.error().as_ref().unwrap();
+        assert_eq!(400, error.code());
+        assert!(error.message().contains(message));
+      }
+      other => panic!("unexpected {:?}", other),
+    }
+  }
+}
+
+#[test]
+fn test_file_generator_stop() {
+  let (sender, receiver) = mpsc::channel();
+  let (started, wait_started) = mpsc::channel();
+  let mut generators = FileGenerators::new(sender);
+  generators.register("slow", move |_: &UpdateFileGenerationStart, output: &mut GenerationOutput| {
+    started.send(()).unwrap();
+    while !output.is_stopped() {
+      std::thread::sleep(Duration::from_millis(5));
+    }
+    output.write_all(b"late")?;
+    Ok(())
+  });
+
+  generators.update(&start(5, "slow"));
+  wait_started.recv_timeout(Duration::from_secs(5)).unwrap();
+  assert!(generators.is_running(5));
+  generators.update(&Update::file_generation_stop(UpdateFileGenerationStop::builder().generation_id(5).build()));
+  assert!(!generators.is_running(5));
+  // a stopped generation sends nothing more, not even finish
+  drop(generators);
+  assert_eq!(Err(mpsc::RecvTimeoutError::Disconnected), receiver.recv_timeout(Duration::from_secs(5)).map(|_| ()));
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use rtdlib::client::Client;
+use rtdlib::file_manager::{FileManager, FileRequest, Progress};
+use rtdlib::types::*;
+
+use common::{block_on, poll_once, reply, FakeTdlib};
+
+fn file(id: i64, downloaded: i64, active: bool, completed: bool) -> Update {
+  let local = LocalFile::builder()
+    .path(if completed { format!("/tmp/{}", id) } else { String::new() })
+    .can_be_downloaded(true)
+    .is_downloading_active(active)
+    .is_downloading_completed(completed)
+    .downloaded_size(downloaded)
+    .build();
+  let remote = RemoteFile::builder().id(format!("remote{}", id)).unique_id(format!("unique{}", id)).build();
+  let file = File::builder().id(id).size(100).local(local).remote(remote).build();
+  Update::file(UpdateFile::builder().file(file).build())
+}
+
+fn update_file(update: &Update) -> &File { update.as_file().unwrap().file() }
+
+fn downloads(requests: &[FileRequest]) -> Vec<(i64, i64)> {
+  requests.iter().filter_map(|r| match r {
+    FileRequest::Download(t) => Some((t.file_id(), t.priority())),
+    _ => None,
+  }).collect()
+}
+
+#[test]
+fn test_file_manager_download() {
+  let manager = FileManager::new();
+  let progress = Arc::new(Mutex::new(vec![]));
+  let seen = progress.clone();
+  manager.on_progress(move |p: &Progress| seen.lock().unwrap().push(p.fraction()));
+
+  let mut transfer = manager.download(1, 40);
+  let mut shared = manager.download(1, 1);
+  assert_eq!(vec![(1, 32)], downloads(&manager.take_requests()));
+
+  manager.update(&file(1, 0, false, false));
+  manager.update(&file(1, 50, true, false));
+  assert!(poll_once(&mut transfer).is_none());
+  manager.update(&file(2, 50, true, false));
+  manager.update(&file(1, 100, false, true));
+
+  assert_eq!("/tmp/1", poll_once(&mut transfer).unwrap().unwrap().local().path());
+  assert_eq!(1, poll_once(&mut shared).unwrap().unwrap().id());
+  assert_eq!(vec![Some(0.0), Some(0.5), Some(1.0)], *progress.lock().unwrap());
+
+  // completed files resolve without a request
+  let mut again = manager.download(1, 1);
+  assert!(again.is_done());
+  assert!(poll_once(&mut again).unwrap().is_ok());
+  assert!(manager.take_requests().is_empty());
+}
+
+#[test]
+fn test_file_manager_limit_and_cancel() {
+  let manager = FileManager::new().max_downloads(2);
+  let mut first = manager.download(1, 1);
+  let _second = manager.download(2, 1);
+  let _low = manager.download(3, 1);
+  let _high = manager.download(4, 5);
+  assert_eq!(vec![(1, 1), (2, 1)], downloads(&manager.take_requests()));
+
+  // the queued download with the highest priority starts when a slot is free
+  assert!(manager.cancel(1));
+  let requests = manager.take_requests();
+  assert!(matches!(&requests[0], FileRequest::CancelDownload(t) if t.file_id() == 1));
+  assert_eq!(vec![(4, 5)], downloads(&requests));
+  assert!(format!("{}", poll_once(&mut first).unwrap().unwrap_err()).contains("cancelled"));
+
+  manager.update(&file(2, 100, false, true));
+  assert_eq!(vec![(3, 1)], downloads(&manager.take_requests()));
+  assert!(!manager.cancel(2));
+}
+
+#[test]
+fn test_file_manager_retry() {
+  let manager = FileManager::new().retries(1);
+  let mut transfer = manager.download(1, 1);
+  assert_eq!(1, manager.take_requests().len());
+
+  // stopped after it started, the download is sent again once
+  manager.update(&file(1, 10, true, false));
+  manager.update(&file(1, 10, false, false));
+  assert_eq!(vec![(1, 1)], downloads(&manager.take_requests()));
+  assert!(poll_once(&mut transfer).is_none());
+
+  manager.failed(1, rtdlib::errors::RTDError::message("FILE_REFERENCE_EXPIRED"));
+  let err = poll_once(&mut transfer).unwrap().unwrap_err();
+  assert!(format!("{}", err).contains("FILE_REFERENCE_EXPIRED"));
+  assert!(manager.take_requests().is_empty());
+}
+
+#[test]
+fn test_file_manager_upload() {
+  let manager = FileManager::new();
+  let uploading = |size: i64, active: bool, completed: bool| {
+    let remote = RemoteFile::builder().is_uploading_active(active).is_uploading_completed(completed).uploaded_size(size).build();
+    File::builder().id(9).size(10).local(LocalFile::builder().path("/tmp/up").build()).remote(remote).build()
+  };
+  let mut transfer = manager.upload(&uploading(0, true, false));
+  manager.update_file(&uploading(5, true, false));
+  assert!(poll_once(&mut transfer).is_none());
+  manager.update_file(&uploading(10, false, true));
+  assert!(poll_once(&mut transfer).unwrap().unwrap().remote().is_uploading_completed());
+
+  let mut stopped = manager.upload(&uploading(0, true, false));
+  manager.update_file(&uploading(3, false, false));
+  assert!(poll_once(&mut stopped).unwrap().is_err());
+}
+
+#[test]
+fn test_file_manager_listener_reentrant() {
+  let manager = FileManager::new();
+  let seen = Arc::new(Mutex::new(vec![]));
+  let inner = manager.clone();
+  let seen_inner = seen.clone();
+  manager.on_progress(move |p: &Progress| {
+    assert!(inner.file(p.file_id).is_some());
+    let seen = seen_inner.clone();
+    inner.on_progress(move |p: &Progress| seen.lock().unwrap().push(p.transferred));
+  });
+  let _transfer = manager.download(1, 1);
+  manager.update(&file(1, 10, true, false));
+  manager.update(&file(1, 20, true, false));
+  assert_eq!(vec![20], *seen.lock().unwrap());
+}
+
+#[test]
+fn test_file_manager_download_path_queued() {
+  let (requested, requests) = mpsc::channel();
+  let requested = Mutex::new(requested);
+  // answers `downloadFile` with the file not downloaded yet and reports the requested file ids
+  let tdlib = FakeTdlib::new(move |request| {
+    let file_id = request["file_id"].as_i64().unwrap();
+    requested.lock().unwrap().send(file_id).unwrap();
+    vec![reply(request, &update_file(&file(file_id, 0, false, false)).to_json().unwrap())]
+  });
+  let (updates, _updates) = mpsc::channel();
+  let client = Client::new(tdlib, updates);
+  let manager = FileManager::new().max_downloads(1);
+  let _first = manager.download(1, 1);
+
+  let downloader = manager.clone();
+  let path = thread::spawn(move || block_on(downloader.download_path(&client, 2, 1)));
+
+  // the download of file 2 waits for file 1, it is requested once file 1 completed
+  let timeout = Duration::from_secs(5);
+  assert_eq!(1, requests.recv_timeout(timeout).unwrap());
+  manager.update(&file(1, 100, false, true));
+  assert_eq!(2, requests.recv_timeout(timeout).unwrap());
+  manager.update(&file(2, 100, false, true));
+  assert_eq!("/tmp/2", path.join().unwrap().unwrap());
+}
@@ -0,0 +1,23 @@
+use rtdlib::types::*;
+
+fn decode_response<Fnc: RFunction>(_fnc: &Fnc, json: &str) -> Fnc::Response {
+  serde_json::from_str(json).expect("Json fail")
+}
+
+#[test]
+fn test_function_response() {
+  let get_count = GetChatMessageCount::builder().chat_id(1).return_local(true).build();
+  let count = decode_response(&get_count, r#"{"@type":"count","count":3}"#);
+  assert_eq!("count", count.td_name());
+  assert_eq!(3, count.count());
+
+  let get_option = GetOption::builder().name("version").build();
+  let value = decode_response(&&get_option, r#"{"@type":"optionValueString","value":"1.6.0"}"#);
+  assert!(value.is_string());
+
+  let create_password = CreateTemporaryPassword::builder().password("secret").valid_for(60).build();
+  let state = decode_response(&create_password, r#"{"@type":"temporaryPasswordState","has_password":true,"valid_for":60}"#);
+  assert_eq!("temporaryPasswordState", state.td_name());
+  assert!(state.has_password());
+  assert_eq!(60, state.valid_for());
+}
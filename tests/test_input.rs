@@ -0,0 +1,64 @@
+use rtdlib::text::markdown;
+use rtdlib::types::*;
+
+fn json(content: &InputMessageContent) -> serde_json::Value {
+  serde_json::from_str(&content.to_json().unwrap()).unwrap()
+}
+
+#[test]
+fn test_input_text() {
+  let content = InputMessageContent::text("hello");
+  let value = json(&content);
+  assert_eq!("inputMessageText", value["@type"]);
+  assert_eq!("formattedText", value["text"]["@type"]);
+  assert_eq!("hello", value["text"]["text"]);
+  assert_eq!(true, value["clear_draft"]);
+
+  let send = SendMessage::builder().chat_id(1).input_message_content(content).build();
+  assert_eq!("hello", send.input_message_content().as_input_message_text().unwrap().text().text());
+}
+
+#[test]
+fn test_input_caption() {
+  let caption = markdown::parse("*cat* photo").unwrap();
+  let content = InputMessageContent::photo(InputFile::path("/tmp/cat.jpg")).caption(&caption);
+  let photo = content.as_input_message_photo().unwrap();
+  assert_eq!("/tmp/cat.jpg", photo.photo().as_local().unwrap().path());
+  assert_eq!(caption.to_json().unwrap(), photo.caption().to_json().unwrap());
+  assert_eq!("inputFileLocal", json(&content)["photo"]["@type"]);
+  assert!(json(&content)["thumbnail"].is_null());
+
+  let thumbnail = InputThumbnail::builder().thumbnail(InputFile::path("/tmp/cat.thumb.jpg")).width(90).height(60).build();
+  let mut photo = InputMessagePhoto::builder();
+  photo.photo(InputFile::path("/tmp/cat.jpg")).thumbnail(&thumbnail);
+  let content = InputMessageContent::input_message_photo(photo.build()).caption("cat");
+  assert_eq!(60, content.as_input_message_photo().unwrap().thumbnail().as_ref().unwrap().height());
+
+  let video = InputMessageContent::video(InputFile::file_id(3)).caption("clip");
+  let video = video.as_input_message_video().unwrap();
+  assert!(video.supports_streaming());
+  assert_eq!(3, video.video().as_id().unwrap().id());
+  assert_eq!("clip", video.caption().text());
+
+  // content without caption is unchanged
+  let dice = InputMessageContent::dice("🎲");
+  assert_eq!(json(&dice), json(&dice.clone().caption("ignored")));
+}
+
+#[test]
+fn test_input_other() {
+  let poll = json(&InputMessageContent::poll("Lunch?", &["yes", "no"]));
+  assert_eq!("inputMessagePoll", poll["@type"]);
+  assert_eq!(serde_json::json!(["yes", "no"]), poll["options"]);
+  assert_eq!(true, poll["is_anonymous"]);
+  assert_eq!("pollTypeRegular", poll["type"]["@type"]);
+  assert_eq!(false, poll["type"]["allow_multiple_answers"]);
+
+  let venue = json(&InputMessageContent::venue(1.5, 2.5, "Cafe", "Main st. 1"));
+  assert_eq!("venue", venue["venue"]["@type"]);
+  assert_eq!("location", venue["venue"]["location"]["@type"]);
+  assert_eq!(2.5, venue["venue"]["location"]["longitude"]);
+
+  let document = InputMessageContent::document(InputFile::remote_id("AgAD"));
+  assert_eq!("AgAD", document.as_input_message_document().unwrap().document().as_remote().unwrap().id());
+}
@@ -0,0 +1,161 @@
+use rtdlib::instant_view::{self, Format, InstantViewRenderer};
+use rtdlib::types::*;
+
+fn plain(text: &str) -> RichText {
+  RichText::plain(RichTextPlain::builder().text(text).build())
+}
+
+fn texts(texts: Vec<RichText>) -> RichText {
+  RichText::rich_texts(RichTexts::builder().texts(texts).build())
+}
+
+fn cell(text: &str, colspan: i64, rowspan: i64, align: PageBlockHorizontalAlignment) -> PageBlockTableCell {
+  PageBlockTableCell::builder()
+    .text(plain(text))
+    .is_header(false)
+    .colspan(colspan)
+    .rowspan(rowspan)
+    .align(align)
+    .valign(PageBlockVerticalAlignment::top(PageBlockVerticalAlignmentTop::builder().build()))
+    .build()
+}
+
+fn left() -> PageBlockHorizontalAlignment { PageBlockHorizontalAlignment::left(PageBlockHorizontalAlignmentLeft::builder().build()) }
+
+fn center() -> PageBlockHorizontalAlignment { PageBlockHorizontalAlignment::center(PageBlockHorizontalAlignmentCenter::builder().build()) }
+
+fn view() -> WebPageInstantView {
+  let paragraph = texts(vec![
+    plain("Read "),
+    RichText::from_json(r#"{"@type":"richTextBold","text":{"@type":"richTextPlain","text":"the <docs>"}}"#).unwrap(),
+    plain(" at "),
+    RichText::from_json(r#"{"@type":"richTextUrl","text":{"@type":"richTextPlain","text":"core"},"url":"https://core.telegram.org","is_cached":false}"#).unwrap(),
+  ]);
+  let table = PageBlockTable::builder()
+    .caption(plain("Limits"))
+    .cells(vec![
+      vec![cell("Name", 1, 2, left()), cell("Value", 2, 1, center())],
+      vec![cell("min", 1, 1, left()), cell("max", 1, 1, left())],
+      vec![cell("size", 1, 1, left()), cell("1", 1, 1, left()), cell("10", 1, 1, left())],
+    ])
+    .is_bordered(true)
+    .is_striped(false)
+    .build();
+  let list = PageBlockList::builder()
+    .items(vec![PageBlockListItem::builder()
+      .label("1.")
+      .page_blocks(vec![PageBlock::paragraph(PageBlockParagraph::builder().text(plain("first\nline")).build())])
+      .build()])
+    .build();
+  WebPageInstantView::builder()
+    .page_blocks(vec![
+      PageBlock::title(PageBlockTitle::builder().title(plain("Guide")).build()),
+      PageBlock::author_date(PageBlockAuthorDate::builder().author(plain("Ann")).publish_date(1_600_000_000).build()),
+      PageBlock::paragraph(PageBlockParagraph::builder().text(paragraph).build()),
+      PageBlock::list(list),
+      PageBlock::table(table),
+    ])
+    .view_count(1)
+    .version(2)
+    .is_rtl(false)
+    .is_full(true)
+    .build()
+}
+
+#[test]
+fn test_instant_view_html() {
+  let html = instant_view::to_html(&view());
+  assert!(html.starts_with("<!DOCTYPE html>\n<html dir=\"ltr\">"));
+  assert!(html.contains("<title>Guide</title>"));
+  assert!(html.contains("<h1>Guide</h1>"));
+  assert!(html.contains("<address>Ann <time>2020-09-13</time></address>"));
+  assert!(html.contains("<p>Read <b>the &lt;docs&gt;</b> at <a href=\"https://core.telegram.org\">core</a></p>"));
+  assert!(html.contains("<table class=\"bordered\">\n<caption>Limits</caption>\n"));
+  assert!(html.contains("<tr><td rowspan=\"2\">Name</td><td colspan=\"2\" style=\"text-align:center\">Value</td></tr>"));
+}
+
+#[test]
+fn test_instant_view_markdown() {
+  let markdown = instant_view::to_markdown(&view());
+  let expected = "# Guide\n\nAnn · 2020-09-13\n\nRead **the \\<docs\\>** at [core](https://core.telegram.org)\n\n\
+    1. first\n   line\n\nLimits\n\n| Name | Value |  |\n| --- | :---: | --- |\n|  | min | max |\n| size | 1 | 10 |\n";
+  assert_eq!(expected, markdown);
+}
+
+#[test]
+fn test_instant_view_plain_text() {
+  let text = instant_view::to_plain_text(&view());
+  assert!(text.starts_with("Guide\n\nAnn · 2020-09-13\n\nRead the <docs> at core\n\n1. first\n   line\n\n"));
+  assert!(text.ends_with("Name | Value | \n | min | max\nsize | 1 | 10\n"));
+}
+
+#[test]
+fn test_instant_view_media() {
+  let file = File::builder().id(7).size(1).expected_size(1)
+    .local(LocalFile::builder().path("").build())
+    .remote(RemoteFile::builder().id("remote").build())
+    .build();
+  let size = PhotoSize::builder().type_("x").photo(file).width(10).height(20).build();
+  let photo = PageBlockPhoto::builder()
+    .photo(Photo::builder().sizes(vec![size]).build())
+    .caption(PageBlockCaption::builder().text(plain("Cat")).credit(plain("Bob")).build())
+    .url("")
+    .build();
+  let blocks = vec![PageBlock::photo(photo)];
+
+  // files that aren't downloaded have no source by default
+  let markdown = InstantViewRenderer::new(Format::Markdown).render_blocks(&blocks);
+  assert_eq!("[Photo]\n\nCat — Bob\n", markdown);
+
+  let mut renderer = InstantViewRenderer::new(Format::Markdown);
+  renderer.media(|file| Some(format!("media/{}.jpg", file.remote().id())));
+  assert_eq!("![Photo](media/remote.jpg)\n\nCat — Bob\n", renderer.render_blocks(&blocks));
+
+  let mut renderer = InstantViewRenderer::new(Format::Html);
+  renderer.media(|file| Some(format!("media/{}.jpg", file.remote().id())));
+  assert_eq!(
+    "<figure><img src=\"media/remote.jpg\" width=\"10\" height=\"20\"><figcaption>Cat<cite>Bob</cite></figcaption></figure>\n",
+    renderer.render_blocks(&blocks)
+  );
+}
+
+#[test]
+fn test_instant_view_table_grid() {
+  let rows = vec![
+    vec![cell("a", 2, 2, left()), cell("b", 1, 1, left())],
+    vec![cell("c", 1, 1, left())],
+    vec![cell("d", 1, 1, left())],
+  ];
+  let grid = instant_view::table_grid(&rows);
+  let names: Vec<Vec<String>> = grid.iter()
+    .map(|row| row.iter().map(|cell| cell.and_then(|c| c.text().as_ref()).map(instant_view::plain_text).unwrap_or_default()).collect())
+    .collect();
+  assert_eq!(vec![vec!["a", "", "b"], vec!["", "", "c"], vec!["d", "", ""]], names);
+}
+
+#[test]
+fn test_instant_view_markdown_escape() {
+  let text = texts(vec![
+    RichText::from_json(r#"{"@type":"richTextUrl","text":{"@type":"richTextPlain","text":"wiki"},"url":"https://en.wikipedia.org/wiki/Rust (language)","is_cached":false}"#).unwrap(),
+    plain(" "),
+    RichText::from_json(r#"{"@type":"richTextFixed","text":{"@type":"richTextPlain","text":"a `b` c"}}"#).unwrap(),
+    plain(" "),
+    RichText::from_json(r#"{"@type":"richTextFixed","text":{"@type":"richTextPlain","text":"`"}}"#).unwrap(),
+  ]);
+  let blocks = vec![PageBlock::paragraph(PageBlockParagraph::builder().text(text).build())];
+  let markdown = InstantViewRenderer::new(Format::Markdown).render_blocks(&blocks);
+  assert_eq!("[wiki](https://en.wikipedia.org/wiki/Rust%20%28language%29) ``a `b` c`` `` ` ``\n", markdown);
+}
+
+#[test]
+fn test_instant_view_table_grid_clamp() {
+  let rows = vec![
+    vec![cell("a", 1, 1_000_000_000, left()), cell("b", 1_000_000_000, 1, left())],
+    vec![cell("c", 1, 1, left())],
+  ];
+  let grid = instant_view::table_grid(&rows);
+  assert_eq!(2, grid.len());
+  assert_eq!(1001, grid[0].len());
+  assert!(grid[1][0].is_none());
+  assert!(grid[1][1].is_some());
+}
@@ -0,0 +1,17 @@
+use rtdlib::types::*;
+
+#[test]
+fn test_int64_as_string() {
+  let json = r#"{"@type":"chatPosition","list":{"@type":"chatListMain"},"order":"9221294780217032704","is_pinned":false,"source":null}"#;
+  let position = ChatPosition::from_json(json).expect("Json fail");
+  assert_eq!(9221294780217032704, position.order());
+  assert_eq!(json, position.to_json().unwrap());
+
+  let position = ChatPosition::from_json(r#"{"@type":"chatPosition","list":{"@type":"chatListMain"},"order":42,"is_pinned":false}"#).expect("Json fail");
+  assert_eq!(42, position.order());
+
+  let reorder = ReorderInstalledStickerSets::builder().is_masks(false).sticker_set_ids(vec![1, 9221294780217032704]).build();
+  assert_eq!(r#"{"@type":"reorderInstalledStickerSets","is_masks":false,"sticker_set_ids":["1","9221294780217032704"]}"#, reorder.to_json().unwrap());
+  let reorder = ReorderInstalledStickerSets::from_json(r#"{"@type":"reorderInstalledStickerSets","is_masks":false,"sticker_set_ids":["1",2]}"#).expect("Json fail");
+  assert_eq!(&vec![1, 2], reorder.sticker_set_ids());
+}
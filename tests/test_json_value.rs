@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use rtdlib::types::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+  test: bool,
+  emojies_send_dice: Vec<String>,
+  chat_read_mark_size_threshold: i64,
+  ratio: f64,
+  extra: Option<String>,
+}
+
+#[test]
+fn test_json_value_round_trip() {
+  let value = json!({
+    "a": [1, -2, 0.25, 1e300, "x", null, true],
+    "b": {"nested": {}},
+    "big": 9_007_199_254_740_992_i64,
+  });
+  let json_value = JsonValue::try_from(&value).unwrap();
+  assert!(json_value.is_object());
+  // tdlib json survives serialization as well
+  let json_value = JsonValue::from_json(json_value.to_json().unwrap()).unwrap();
+  assert_eq!(value, Value::try_from(&json_value).unwrap());
+}
+
+#[test]
+fn test_json_value_errors() {
+  // 2^53 + 1 isn't a double
+  assert!(JsonValue::try_from(json!([9_007_199_254_740_993_i64])).is_err());
+  assert!(JsonValue::try_from(json!(u64::MAX)).is_err());
+
+  let nan = JsonValue::number(JsonValueNumber::builder().value(f64::NAN).build());
+  assert!(Value::try_from(nan).is_err());
+
+  let member = JsonObjectMember::builder().key("k").value(JsonValue::null(JsonValueNull::builder().build())).build();
+  let twice = JsonValue::object(JsonValueObject::builder().members(vec![member.clone(), member]).build());
+  assert!(Value::try_from(&twice).is_err());
+}
+
+#[test]
+fn test_json_value_decode() {
+  let tdlib = r#"{"@type":"jsonValueObject","members":[
+    {"@type":"jsonObjectMember","key":"test","value":{"@type":"jsonValueBoolean","value":true}},
+    {"@type":"jsonObjectMember","key":"emojies_send_dice","value":{"@type":"jsonValueArray","values":[{"@type":"jsonValueString","value":"🎲"}]}},
+    {"@type":"jsonObjectMember","key":"chat_read_mark_size_threshold","value":{"@type":"jsonValueNumber","value":100}},
+    {"@type":"jsonObjectMember","key":"ratio","value":{"@type":"jsonValueNumber","value":0.1}}
+  ]}"#;
+  let config: Config = JsonValue::from_json(tdlib).unwrap().decode().unwrap();
+  assert_eq!(Config {
+    test: true,
+    emojies_send_dice: vec!["🎲".to_string()],
+    chat_read_mark_size_threshold: 100,
+    ratio: 0.1,
+    extra: None,
+  }, config);
+
+  let encoded = JsonValue::encode(&config).unwrap();
+  assert_eq!(config, encoded.decode::<Config>().unwrap());
+  assert!(JsonValue::from_json(tdlib).unwrap().decode::<Vec<i64>>().is_err());
+}
@@ -0,0 +1,90 @@
+use rtdlib::errors::RTDError;
+use rtdlib::keyboard::{self, InlineKeyboard, ReplyKeyboard};
+use rtdlib::types::*;
+
+fn round_trip(markup: &ReplyMarkup) -> ReplyMarkup {
+  ReplyMarkup::from_json(markup.to_json().unwrap()).unwrap()
+}
+
+#[test]
+fn test_inline_keyboard() {
+  let markup = InlineKeyboard::new()
+    .callback("Yes", "vote:1")
+    .callback("No", "vote:0")
+    .row()
+    .url("Site", "https://example.com")
+    .switch_inline("Share", "query", false)
+    .row()
+    .row()
+    .buy("Pay")
+    .build()
+    .unwrap();
+
+  let markup = round_trip(&markup);
+  let rows = markup.as_inline_keyboard().unwrap().rows();
+  assert_eq!(vec![2, 2, 1], rows.iter().map(Vec::len).collect::<Vec<_>>());
+  assert_eq!(b"vote:1", rows[0][0].type_().as_callback().unwrap().data().as_slice());
+  // callback data is base64 encoded like tdlib expects bytes
+  assert!(markup.to_json().unwrap().contains(r#""data":"dm90ZTox""#));
+  assert_eq!("https://example.com", rows[1][0].type_().as_url().unwrap().url());
+  assert!(rows[2][0].type_().is_buy());
+  keyboard::validate(&markup).unwrap();
+}
+
+#[test]
+fn test_inline_keyboard_columns() {
+  let mut keyboard = InlineKeyboard::new();
+  keyboard.columns(3);
+  for n in 0..7 {
+    keyboard.callback(n.to_string(), n.to_string());
+  }
+  let markup = keyboard.build().unwrap();
+  let rows = markup.as_inline_keyboard().unwrap().rows();
+  assert_eq!(vec![3, 3, 1], rows.iter().map(Vec::len).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_inline_keyboard_limits() {
+  let err = InlineKeyboard::new().callback("Big", "x".repeat(65)).build().unwrap_err();
+  assert!(format!("{}", err).contains("row 0 button 0"));
+  assert!(matches!(err.root(), RTDError::Message(message) if message.contains("65 bytes")));
+
+  assert!(InlineKeyboard::new().callback("Empty", "").build().is_err());
+  assert!(InlineKeyboard::new().callback("Max", "x".repeat(64)).build().is_ok());
+  assert!(InlineKeyboard::new().build().is_err());
+  assert!(InlineKeyboard::new().login_url("Login", "http://example.com", 1).build().is_err());
+
+  let mut row = InlineKeyboard::new();
+  (0..9).for_each(|n| { row.callback("n", n.to_string()); });
+  assert!(row.build().is_err());
+
+  let mut many = InlineKeyboard::new();
+  many.columns(5);
+  (0..101).for_each(|n| { many.callback("n", n.to_string()); });
+  assert!(many.build().is_err());
+}
+
+#[test]
+fn test_reply_keyboard() {
+  let markup = ReplyKeyboard::new()
+    .text("Hello")
+    .request_location("Where")
+    .row()
+    .request_poll("Poll", false, true)
+    .resize_keyboard(true)
+    .one_time(true)
+    .build()
+    .unwrap();
+
+  let markup = round_trip(&markup);
+  let keyboard = markup.as_show_keyboard().unwrap();
+  assert!(keyboard.resize_keyboard() && keyboard.one_time() && !keyboard.is_personal());
+  assert_eq!(vec![2, 1], keyboard.rows().iter().map(Vec::len).collect::<Vec<_>>());
+  assert!(keyboard.rows()[0][1].type_().is_request_location());
+
+  assert!(ReplyKeyboard::new().request_poll("Poll", true, true).build().is_err());
+  assert!(ReplyKeyboard::new().text("").build().is_err());
+
+  assert!(round_trip(&keyboard::remove_keyboard(true)).as_remove_keyboard().unwrap().is_personal());
+  assert!(round_trip(&keyboard::force_reply(false)).is_force_reply());
+}
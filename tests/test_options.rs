@@ -0,0 +1,41 @@
+use rtdlib::options::Options;
+use rtdlib::types::*;
+
+fn update(name: &str, value: OptionValue) -> Update {
+  let json = UpdateOption::builder().name(name).value(value).build().to_json().unwrap();
+  Update::from_json(json).unwrap()
+}
+
+#[test]
+fn test_options_update() {
+  let mut options = Options::new();
+  assert_eq!(None, options.my_id());
+  assert!(options.update(&update("my_id", OptionValue::from(42))));
+  assert!(options.update(&update("online", OptionValue::from(true))));
+  assert!(options.update(&update("version", OptionValue::from("1.6.0"))));
+  assert!(options.update(&update("x_custom", OptionValue::from("value"))));
+  assert!(!options.update(&Update::connection_state(UpdateConnectionState::builder().build())));
+
+  assert_eq!(Some(42), options.my_id());
+  assert_eq!(Some(true), options.online());
+  assert_eq!(Some("1.6.0"), options.version());
+  assert_eq!(Some("value"), options.string("x_custom"));
+  // a value of another type isn't returned
+  assert_eq!(None, options.boolean("my_id"));
+
+  options.update(&update("online", OptionValue::empty(OptionValueEmpty::builder().build())));
+  assert_eq!(None, options.online());
+  assert_eq!(3, options.iter().count());
+}
+
+#[test]
+fn test_options_set() {
+  let request = Options::set_use_quick_ack(true);
+  assert_eq!("use_quick_ack", request.name());
+  assert!(request.value().as_boolean().unwrap().value());
+  let json = Options::set_message_unload_delay(60).to_json().unwrap();
+  assert!(json.contains(r#""value":{"@type":"optionValueInteger","value":60}"#));
+
+  assert_eq!("v", Options::set("x_custom", "v").value().as_string().unwrap().value());
+  assert!(Options::reset("online").value().is_empty());
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use rtdlib::router::{filters, UpdateRouter};
+use rtdlib::types::*;
+
+use common::block_on;
+
+fn new_message(chat_id: i64, outgoing: bool, text: &str) -> Update {
+  let content = MessageContent::message_text(
+    MessageText::builder().text(FormattedText::builder().text(text).entities(vec![]).build()).build()
+  );
+  let message = Message::builder().id(1).chat_id(chat_id).is_outgoing(outgoing).content(content).build();
+  Update::new_message(UpdateNewMessage::builder().message(message).build())
+}
+
+fn chat_title(chat_id: i64, title: &str) -> Update {
+  Update::chat_title(UpdateChatTitle::builder().chat_id(chat_id).title(title).build())
+}
+
+#[test]
+fn test_route_by_type() {
+  let log = Rc::new(RefCell::new(vec![]));
+  let mut router = UpdateRouter::new();
+  let l = log.clone();
+  router.on::<UpdateNewMessage, _>(move |u| l.borrow_mut().push(format!("message {}", u.message().chat_id())));
+  let l = log.clone();
+  router.on::<UpdateChatTitle, _>(move |u| l.borrow_mut().push(format!("title {}", u.title())));
+  let l = log.clone();
+  router.on::<Update, _>(move |u| l.borrow_mut().push(format!("any {}", u.td_name())));
+
+  router.dispatch(&new_message(1, false, "hi"));
+  router.dispatch(&chat_title(2, "rust"));
+  assert_eq!(vec!["message 1", "any updateNewMessage", "title rust", "any updateChatTitle"], *log.borrow());
+}
+
+#[test]
+fn test_priority_and_filters() {
+  let log = Rc::new(RefCell::new(vec![]));
+  let mut router = UpdateRouter::new();
+  let l = log.clone();
+  router.on::<UpdateNewMessage, _>(move |_| l.borrow_mut().push("low")).priority(-1);
+  let l = log.clone();
+  router.on::<UpdateNewMessage, _>(move |_| l.borrow_mut().push("incoming in chat 1"))
+    .when(filters::in_chat(1))
+    .when(filters::outgoing(false));
+  let l = log.clone();
+  router.on::<UpdateNewMessage, _>(move |_| l.borrow_mut().push("high text")).priority(10)
+    .when(filters::content(MessageContent::is_message_text));
+  let l = log.clone();
+  router.on::<UpdateNewMessage, _>(move |_| l.borrow_mut().push("hello"))
+    .filter(|u| u.message().content().as_message_text().is_some_and(|t| t.text().text() == "hello"));
+
+  router.dispatch(&new_message(1, false, "hi"));
+  assert_eq!(vec!["high text", "incoming in chat 1", "low"], *log.borrow());
+
+  log.borrow_mut().clear();
+  router.dispatch(&new_message(2, true, "hello"));
+  assert_eq!(vec!["high text", "hello", "low"], *log.borrow());
+
+  log.borrow_mut().clear();
+  router.dispatch(&chat_title(1, "rust"));
+  assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn test_async_handler() {
+  let log = Arc::new(Mutex::new(vec![]));
+  let mut router = UpdateRouter::new();
+  let l = log.clone();
+  router.on_async::<UpdateChatTitle, _, _>(move |u| {
+    let l = l.clone();
+    async move { l.lock().unwrap().push(format!("async {}", u.title())) }
+  });
+  let l = log.clone();
+  router.on::<UpdateChatTitle, _>(move |u| l.lock().unwrap().push(format!("sync {}", u.title()))).priority(-1);
+
+  block_on(router.dispatch_async(&chat_title(1, "a")));
+  assert_eq!(vec!["async a", "sync a"], *log.lock().unwrap());
+
+  log.lock().unwrap().clear();
+  let futures = router.dispatch(&chat_title(1, "b"));
+  assert_eq!(vec!["sync b"], *log.lock().unwrap());
+  assert_eq!(1, futures.len());
+  for fut in futures { block_on(fut) }
+  assert_eq!(vec!["sync b", "async b"], *log.lock().unwrap());
+}
@@ -0,0 +1,114 @@
+use std::sync::{Arc, Mutex};
+
+use rtdlib::state::{StateChange, StateStore};
+use rtdlib::types::*;
+
+fn chat(id: i64) -> Chat {
+  Chat::builder()
+    .id(id)
+    .type_(ChatType::private(ChatTypePrivate::builder().user_id(id).build()))
+    .title("alice")
+    .permissions(ChatPermissions::builder().build())
+    .notification_settings(ChatNotificationSettings::builder().build())
+    .build()
+}
+
+fn main_position(order: i64) -> ChatPosition {
+  ChatPosition::builder().list(ChatList::main(ChatListMain::builder().build())).order(order).build()
+}
+
+fn archive_position(order: i64) -> ChatPosition {
+  ChatPosition::builder().list(ChatList::archive(ChatListArchive::builder().build())).order(order).build()
+}
+
+fn draft(reply_to_message_id: i64) -> DraftMessage {
+  let text = InputMessageText::builder().text(FormattedText::builder().text("draft").entities(vec![]).build()).build();
+  DraftMessage::builder().reply_to_message_id(reply_to_message_id).input_message_text(InputMessageContent::input_message_text(text)).build()
+}
+
+fn user(id: i64) -> User {
+  User::builder()
+    .id(id)
+    .first_name("alice")
+    .status(UserStatus::empty(UserStatusEmpty::builder().build()))
+    .type_(UserType::regular(UserTypeRegular::builder().build()))
+    .build()
+}
+
+// updates as tdlib sends them, so the store is checked against a recorded json stream
+fn recorded(updates: Vec<Update>) -> Vec<Update> {
+  updates.iter()
+    .map(|update| update.to_json().unwrap())
+    .map(|json| Update::from_json(json).unwrap())
+    .collect()
+}
+
+#[test]
+fn test_state_chat() {
+  let updates = recorded(vec![
+    Update::new_chat(UpdateNewChat::builder().chat(chat(1)).build()),
+    Update::chat_title(UpdateChatTitle::builder().chat_id(1).title("bob").build()),
+    Update::chat_read_inbox(UpdateChatReadInbox::builder().chat_id(1).last_read_inbox_message_id(10).unread_count(3).build()),
+    Update::chat_draft_message(UpdateChatDraftMessage::builder().chat_id(1).draft_message(draft(10)).build()),
+    Update::chat_title(UpdateChatTitle::builder().chat_id(2).title("unknown").build()),
+  ]);
+
+  let mut store = StateStore::new();
+  let changes = store.apply_all(&updates);
+  assert_eq!(vec![StateChange::Chat(1); 4], changes);
+  assert!(store.chat(2).is_none());
+
+  let chat = store.chat(1).unwrap();
+  assert_eq!("bob", chat.title());
+  assert_eq!(10, chat.last_read_inbox_message_id());
+  assert_eq!(3, chat.unread_count());
+  assert_eq!(10, chat.draft_message().as_ref().unwrap().reply_to_message_id());
+}
+
+#[test]
+fn test_state_clear_option() {
+  let mut store = StateStore::new();
+  store.apply(&Update::new_chat(UpdateNewChat::builder().chat(chat(1)).build()));
+  store.apply(&Update::chat_draft_message(UpdateChatDraftMessage::builder().chat_id(1).draft_message(draft(0)).build()));
+  assert!(store.chat(1).unwrap().draft_message().is_some());
+
+  store.apply(&Update::chat_draft_message(UpdateChatDraftMessage::builder().chat_id(1).build()));
+  assert!(store.chat(1).unwrap().draft_message().is_none());
+}
+
+#[test]
+fn test_state_positions() {
+  let mut store = StateStore::new();
+  store.apply(&Update::new_chat(UpdateNewChat::builder().chat(chat(1)).build()));
+  store.apply(&Update::chat_position(UpdateChatPosition::builder().chat_id(1).position(main_position(5)).build()));
+  store.apply(&Update::chat_position(UpdateChatPosition::builder().chat_id(1).position(archive_position(7)).build()));
+  store.apply(&Update::chat_last_message(UpdateChatLastMessage::builder().chat_id(1).positions(vec![main_position(9)]).build()));
+
+  let orders: Vec<i64> = store.chat(1).unwrap().positions().iter().map(|p| p.order()).collect();
+  assert_eq!(vec![7, 9], orders);
+
+  store.apply(&Update::chat_position(UpdateChatPosition::builder().chat_id(1).position(archive_position(0)).build()));
+  let positions = store.chat(1).unwrap().positions();
+  assert_eq!(1, positions.len());
+  assert!(positions[0].list().is_main());
+}
+
+#[test]
+fn test_state_user_and_listener() {
+  let changes = Arc::new(Mutex::new(vec![]));
+  let mut store = StateStore::new();
+  let seen = changes.clone();
+  store.subscribe(move |store, change| {
+    if let StateChange::User(id) = change {
+      seen.lock().unwrap().push(store.user(id).unwrap().status().is_online());
+    }
+  });
+
+  store.apply(&Update::user(UpdateUser::builder().user(user(5)).build()));
+  let online = UserStatus::online(UserStatusOnline::builder().expires(100).build());
+  store.apply(&Update::user_status(UpdateUserStatus::builder().user_id(5).status(online).build()));
+  assert_eq!(None, store.apply(&Update::user_status(UpdateUserStatus::builder().user_id(6).build())));
+
+  assert_eq!(vec![false, true], *changes.lock().unwrap());
+  assert_eq!("alice", store.user(5).unwrap().first_name());
+}
@@ -0,0 +1,36 @@
+use rtdlib::types::*;
+
+#[test]
+fn test_td_object_dispatch() {
+  let update = TdObject::from_json(r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.6.0"}}"#).unwrap();
+  assert_eq!("updateOption", update.td_name());
+  assert_eq!("version", update.as_update().unwrap().as_option().unwrap().name());
+
+  let response = TdObject::from_json(r#"{"@type":"count","count":7,"@extra":1}"#).unwrap();
+  assert_eq!(7, response.as_count().unwrap().count());
+  assert_eq!(Some(&serde_json::json!(1)), response.extra());
+
+  let error = TdObject::from_json(r#"{"@type":"error","code":400,"message":"CHAT_NOT_FOUND"}"#).unwrap();
+  assert_eq!(400, error.as_error().unwrap().code());
+
+  // objects of an abstract type are kept as that type
+  let state = TdObject::from_json(r#"{"@type":"authorizationStateReady"}"#).unwrap();
+  assert!(state.as_authorization_state().unwrap().is_ready());
+  assert!(TdObject::from_json(r#"{"@type":"ok"}"#).unwrap().is_ok());
+
+  let json = r#"{"@type":"someNewObject","field":1}"#;
+  let unknown = TdObject::from_json(json).unwrap();
+  assert!(unknown.is_unknown());
+  assert_eq!(json, unknown.to_json().unwrap());
+}
+
+#[test]
+fn test_td_object_no_functions() {
+  // requests aren't objects tdlib sends
+  assert!(TdObject::from_json(r#"{"@type":"getOption","name":"version"}"#).unwrap().is_unknown());
+  assert!(OptionValue::from_json(r#"{"@type":"getOption","name":"version"}"#).unwrap().is_unknown());
+  assert!(JsonValue::from_json(r#"{"@type":"getApplicationConfig"}"#).unwrap().is_unknown());
+  assert!(AuthorizationState::from_json(r#"{"@type":"getAuthorizationState"}"#).unwrap().is_unknown());
+  assert!(LogStream::from_json(r#"{"@type":"getLogStream"}"#).unwrap().is_unknown());
+  assert!(Update::from_json(r#"{"@type":"testUseUpdate"}"#).unwrap().is_unknown());
+}
@@ -0,0 +1,267 @@
+use rtdlib::text::{entities, html, markdown, FormattedTextExt};
+use rtdlib::types::*;
+
+fn entity(offset: i64, length: i64, type_: TextEntityType) -> TextEntity {
+  TextEntity::builder().offset(offset).length(length).type_(type_).build()
+}
+
+fn formatted(text: &str, entities: Vec<TextEntity>) -> FormattedText {
+  FormattedText::builder().text(text).entities(entities).build()
+}
+
+fn bold() -> TextEntityType { TextEntityType::bold(TextEntityTypeBold::builder().build()) }
+fn italic() -> TextEntityType { TextEntityType::italic(TextEntityTypeItalic::builder().build()) }
+fn underline() -> TextEntityType { TextEntityType::underline(TextEntityTypeUnderline::builder().build()) }
+fn strikethrough() -> TextEntityType { TextEntityType::strikethrough(TextEntityTypeStrikethrough::builder().build()) }
+fn code() -> TextEntityType { TextEntityType::code(TextEntityTypeCode::builder().build()) }
+fn pre() -> TextEntityType { TextEntityType::pre(TextEntityTypePre::builder().build()) }
+fn pre_code(language: &str) -> TextEntityType { TextEntityType::pre_code(TextEntityTypePreCode::builder().language(language).build()) }
+fn text_url(url: &str) -> TextEntityType { TextEntityType::text_url(TextEntityTypeTextUrl::builder().url(url).build()) }
+fn mention_name(user_id: i64) -> TextEntityType { TextEntityType::mention_name(TextEntityTypeMentionName::builder().user_id(user_id).build()) }
+
+fn json(entities: &[TextEntity]) -> serde_json::Value {
+  serde_json::to_value(entities).unwrap()
+}
+
+#[test]
+fn test_markdown_render() {
+  // the emoji is two UTF-16 code units
+  let text = formatted("😀 bold and link", vec![
+    entity(3, 4, bold()),
+    entity(12, 4, text_url("http://example.com/a_(b)")),
+  ]);
+  assert_eq!("😀 *bold* and [link](http://example.com/a_(b\\))", markdown::render(&text));
+
+  let text = formatted("a_b *c*", vec![entity(0, 7, italic()), entity(2, 1, underline())]);
+  assert_eq!("_a\\___b__ \\*c\\*_", markdown::render(&text));
+  let text = formatted("ab", vec![entity(0, 1, italic()), entity(1, 1, underline())]);
+  assert_eq!("_a_\r__b__", markdown::render(&text));
+
+  let text = formatted("fn main() {}", vec![entity(0, 12, pre_code("rust")), entity(0, 2, bold())]);
+  assert_eq!("```rust\nfn main() {}```", markdown::render(&text));
+}
+
+#[test]
+fn test_markdown_overlapping() {
+  let text = formatted("abcdef", vec![entity(0, 4, bold()), entity(2, 4, italic())]);
+  let rendered = markdown::render(&text);
+  assert_eq!("*ab_cd_*_ef_", rendered);
+  let parsed = markdown::parse(&rendered).unwrap();
+  assert_eq!(json(&[entity(0, 4, bold()), entity(2, 2, italic()), entity(4, 2, italic())]), json(parsed.entities()));
+}
+
+#[test]
+fn test_markdown_parse() {
+  let parsed = markdown::parse("*bold _italic_* [😀 x](tg://user?id=5) `a\\`b` ```py\nprint()```").unwrap();
+  assert_eq!("bold italic 😀 x a`b print()", parsed.text());
+  assert_eq!(json(&[
+    entity(0, 11, bold()),
+    entity(5, 6, italic()),
+    entity(12, 4, mention_name(5)),
+    entity(17, 3, code()),
+    entity(21, 7, pre_code("py")),
+  ]), json(parsed.entities()));
+
+  assert!(markdown::parse("*unclosed").is_err());
+  assert!(markdown::parse("[link] text").is_err());
+  assert!(markdown::parse("`code").is_err());
+}
+
+#[test]
+fn test_html() {
+  let text = formatted("<a> & 😀 code", vec![
+    entity(0, 3, text_url("http://x.y/?a=1&b=\"2\"")),
+    entity(6, 2, underline()),
+    entity(9, 4, pre_code("rust")),
+  ]);
+  let rendered = html::render(&text);
+  assert_eq!("<a href=\"http://x.y/?a=1&amp;b=&quot;2&quot;\">&lt;a&gt;</a> &amp; <u>😀</u> <pre><code class=\"language-rust\">code</code></pre>", rendered);
+  let parsed = html::parse(&rendered).unwrap();
+  assert_eq!(text.text(), parsed.text());
+  assert_eq!(json(text.entities()), json(parsed.entities()));
+
+  let parsed = html::parse("<B>b</B><strong>s</strong><em>e</em><a href='tg://user?id=7'>m</a>&#x1F600;&unknown;<pre>p</pre>").unwrap();
+  assert_eq!("bsem😀&unknown;p", parsed.text());
+  assert_eq!(json(&[
+    entity(0, 1, bold()),
+    entity(1, 1, bold()),
+    entity(2, 1, italic()),
+    entity(3, 1, mention_name(7)),
+    entity(15, 1, pre()),
+  ]), json(parsed.entities()));
+
+  assert!(html::parse("<b>unclosed").is_err());
+  assert!(html::parse("<b><i>crossed</b></i>").is_err());
+  assert!(html::parse("<blink>x</blink>").is_err());
+}
+
+#[test]
+fn test_entity_text() {
+  // 🏳️‍🌈 is 6 UTF-16 code units, é is 1
+  let text = formatted("🏳️‍🌈 #tag é @user", vec![
+    entity(7, 4, bold()),
+    entity(14, 5, bold()),
+    entity(1, 2, bold()),
+    entity(17, 9, bold()),
+  ]);
+  assert_eq!(Some("#tag"), text.entity_text(&text.entities()[0]));
+  assert_eq!(Some("@user"), text.entity_text(&text.entities()[1]));
+  // inside the surrogate pair of 🏳
+  assert_eq!(None, text.entity_text(&text.entities()[2]));
+  assert_eq!(None, text.entity_text(&text.entities()[3]));
+  let texts: Vec<&str> = text.entity_texts().map(|(_, text)| text).collect();
+  assert_eq!(vec!["#tag", "@user"], texts);
+
+  assert!(text.validate_entities().is_err());
+  assert!(formatted(text.text(), text.entities()[..2].to_vec()).validate_entities().is_ok());
+  assert!(formatted("ab", vec![entity(1, 0, bold())]).validate_entities().is_err());
+  assert!(formatted("ab", vec![entity(-1, 2, bold())]).validate_entities().is_err());
+}
+
+#[test]
+fn test_entity_ranges() {
+  let text = "é😀 #tag";
+  assert_eq!(8, entities::utf16_len(text));
+  assert_eq!(Some(2), entities::byte_index(text, 1));
+  assert_eq!(None, entities::byte_index(text, 2));
+  assert_eq!(Some(6), entities::byte_index(text, 3));
+  assert_eq!(Some(text.len()), entities::byte_index(text, 8));
+  assert_eq!(None, entities::byte_index(text, 9));
+  assert_eq!(Some(4), entities::utf16_offset(text, 7));
+  assert_eq!(None, entities::utf16_offset(text, 3));
+
+  let from_bytes = entities::entity_from_bytes(text, 7..11, bold()).unwrap();
+  assert_eq!((4, 4), (from_bytes.offset(), from_bytes.length()));
+  let from_chars = entities::entity_from_chars(text, 3..7, bold()).unwrap();
+  assert_eq!((4, 4), (from_chars.offset(), from_chars.length()));
+  assert_eq!(Some("#tag"), entities::entity_text(text, &from_chars));
+  let emoji = entities::entity_from_chars(text, 1..2, bold()).unwrap();
+  assert_eq!((1, 2), (emoji.offset(), emoji.length()));
+
+  assert!(entities::entity_from_bytes(text, 1..4, bold()).is_err());
+  assert!(entities::entity_from_bytes(text, 0..20, bold()).is_err());
+  assert!(entities::entity_from_chars(text, 3..8, bold()).is_err());
+}
+
+/// xorshift, the property tests are reproducible without a random crate
+struct Rng(u64);
+
+impl Rng {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+
+  fn below(&mut self, n: usize) -> usize { (self.next() % n as u64) as usize }
+
+  fn pick<T: Clone>(&mut self, items: &[T]) -> T { items[self.below(items.len())].clone() }
+}
+
+const ALPHABET: &[char] = &[
+  'a', 'b', ' ', '\n', '\r', '*', '_', '~', '`', '[', ']', '(', ')', '\\', '<', '>', '&', '"', '.', '!',
+  'é', '中', '😀', '🏳', '\u{1F1FA}',
+];
+
+fn random_type(rng: &mut Rng) -> TextEntityType {
+  match rng.below(9) {
+    0 => bold(),
+    1 => italic(),
+    2 => underline(),
+    3 => strikethrough(),
+    4 => code(),
+    5 => pre(),
+    6 => pre_code(rng.pick(&["rust", "c++", "py"])),
+    7 => text_url(rng.pick(&["http://a.b/c", "https://x.y/(a)\\b", "http://q?a=1&b=\"2\">"])),
+    _ => mention_name(rng.below(1000) as i64 + 1),
+  }
+}
+
+/// Random text and UTF-16 offsets of its char boundaries
+fn random_text(rng: &mut Rng) -> (String, Vec<i64>) {
+  let mut text = String::new();
+  let mut boundaries = vec![0];
+  for _ in 0..rng.below(24) {
+    let c = rng.pick(ALPHABET);
+    text.push(c);
+    boundaries.push(boundaries.last().unwrap() + c.len_utf16() as i64);
+  }
+  (text, boundaries)
+}
+
+/// Entities on disjoint ranges, markup can express them exactly
+fn random_disjoint(rng: &mut Rng) -> FormattedText {
+  let (text, boundaries) = random_text(rng);
+  let mut entities = vec![];
+  let mut from = 0;
+  while from + 1 < boundaries.len() {
+    let start = from + rng.below(boundaries.len() - from);
+    if start + 1 >= boundaries.len() {
+      break;
+    }
+    let end = start + 1 + rng.below(boundaries.len() - start - 1);
+    entities.push(entity(boundaries[start], boundaries[end] - boundaries[start], random_type(rng)));
+    from = end;
+  }
+  formatted(&text, entities)
+}
+
+/// Entities on any ranges, nested, overlapping or duplicated
+fn random_overlapping(rng: &mut Rng) -> FormattedText {
+  let (text, boundaries) = random_text(rng);
+  let mut entities = vec![];
+  for _ in 0..rng.below(6) {
+    let start = rng.below(boundaries.len());
+    let end = start + rng.below(boundaries.len() - start);
+    entities.push(entity(boundaries[start], boundaries[end] - boundaries[start], random_type(rng)));
+  }
+  formatted(&text, entities)
+}
+
+fn assert_in_bounds(text: &FormattedText) {
+  let boundaries: Vec<i64> = text.text().char_indices()
+    .map(|(index, _)| text.text()[..index].encode_utf16().count() as i64)
+    .chain(std::iter::once(text.text().encode_utf16().count() as i64))
+    .collect();
+  for entity in text.entities() {
+    assert!(entity.length() > 0);
+    assert!(boundaries.contains(&entity.offset()), "{:?}", text);
+    assert!(boundaries.contains(&(entity.offset() + entity.length())), "{:?}", text);
+  }
+}
+
+#[test]
+fn test_property_disjoint_round_trip() {
+  let mut rng = Rng(0x5eed_1234_abcd_0001);
+  for _ in 0..2000 {
+    let text = random_disjoint(&mut rng);
+    let from_markdown = markdown::parse(markdown::render(&text)).unwrap();
+    let from_html = html::parse(html::render(&text)).unwrap();
+    assert_eq!(text.text(), from_markdown.text(), "{}", markdown::render(&text));
+    assert_eq!(json(text.entities()), json(from_markdown.entities()), "{:?}", markdown::render(&text));
+    assert_eq!(text.text(), from_html.text(), "{}", html::render(&text));
+    assert_eq!(json(text.entities()), json(from_html.entities()), "{}", html::render(&text));
+  }
+}
+
+#[test]
+fn test_property_overlapping_stable() {
+  let mut rng = Rng(0x5eed_1234_abcd_0002);
+  for _ in 0..2000 {
+    let text = random_overlapping(&mut rng);
+    let rendered = markdown::render(&text);
+    let from_markdown = markdown::parse(&rendered).unwrap();
+    assert_eq!(text.text(), from_markdown.text(), "{:?}", rendered);
+    assert_eq!(rendered, markdown::render(&from_markdown), "{:?}", text);
+    assert_in_bounds(&from_markdown);
+
+    let rendered = html::render(&text);
+    let from_html = html::parse(&rendered).unwrap();
+    assert_eq!(text.text(), from_html.text(), "{:?}", rendered);
+    assert_eq!(rendered, html::render(&from_html), "{:?}", text);
+
+    // both formats resolve overlapping entities the same way
+    assert_eq!(json(from_markdown.entities()), json(from_html.entities()), "{:?}", text);
+  }
+}
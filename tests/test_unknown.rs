@@ -0,0 +1,27 @@
+use rtdlib::types::*;
+
+#[test]
+fn test_unknown_type() {
+  let json = r#"{"@type":"updateSomethingNew","chat_id":1,"value":{"@type":"somethingNew","flag":true}}"#;
+  let update = Update::from_json(json).expect("Json fail");
+  assert!(update.is_unknown());
+  match &update {
+    Update::Unknown { td_type, raw } => {
+      assert_eq!("updateSomethingNew", td_type);
+      assert_eq!(1, raw["chat_id"]);
+    }
+    _ => panic!("expected unknown update"),
+  }
+  assert_eq!(json, update.to_json().unwrap());
+
+  let json = r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitSomething","@extra":"1"}}"#;
+  let state = UpdateAuthorizationState::from_json(json).expect("Json fail");
+  assert!(state.authorization_state().is_unknown());
+  assert_eq!(Some(&serde_json::json!("1")), state.authorization_state().extra());
+  let rjson: serde_json::Value = serde_json::from_str(&state.to_json().unwrap()).unwrap();
+  assert_eq!(serde_json::from_str::<serde_json::Value>(json).unwrap(), rjson);
+
+  let file_type = FileType::from_json(r#"{"@type":"fileTypeSomething"}"#).expect("Json fail");
+  assert!(file_type.is_unknown_type());
+  assert!(!file_type.is_unknown());
+}